@@ -92,6 +92,8 @@ fn configure_validation(builder: Builder) -> Builder {
             ("CreateCollection.optimizers_config", ""),
             ("CreateCollection.vectors_config", ""),
             ("CreateCollection.quantization_config", ""),
+            ("CreateCollection.expiry_config", ""),
+            ("CreateCollection.strict_mode_config", ""),
             ("UpdateCollection.collection_name", "length(min = 1, max = 255)"),
             ("UpdateCollection.optimizers_config", ""),
             ("UpdateCollection.params", ""),
@@ -99,13 +101,34 @@ fn configure_validation(builder: Builder) -> Builder {
             ("UpdateCollection.hnsw_config", ""),
             ("UpdateCollection.vectors_config", ""),
             ("UpdateCollection.quantization_config", ""),
+            ("UpdateCollection.create_vectors_config", ""),
             ("DeleteCollection.collection_name", "length(min = 1, max = 255)"),
             ("DeleteCollection.timeout", "custom = \"crate::grpc::validate::validate_u64_range_min_1\""),
+            ("RenameCollection.collection_name", "length(min = 1, max = 255)"),
+            ("RenameCollection.new_collection_name", "length(min = 1, max = 255), custom = \"common::validation::validate_collection_name\""),
+            ("RenameCollection.timeout", "custom = \"crate::grpc::validate::validate_u64_range_min_1\""),
+            ("CloneCollection.collection_name", "length(min = 1, max = 255)"),
+            ("CloneCollection.new_collection_name", "length(min = 1, max = 255), custom = \"common::validation::validate_collection_name\""),
+            ("CloneCollection.vectors_config", ""),
+            ("CloneCollection.hnsw_config", ""),
+            ("CloneCollection.quantization_config", ""),
+            ("CloneCollection.timeout", "custom = \"crate::grpc::validate::validate_u64_range_min_1\""),
             ("CollectionConfig.params", ""),
             ("CollectionConfig.hnsw_config", ""),
             ("CollectionConfig.optimizers_config", ""),
             ("CollectionConfig.quantization_config", ""),
             ("CollectionParams.vectors_config", ""),
+            ("CollectionParams.expiry_config", ""),
+            ("CollectionParamsDiff.expiry_config", ""),
+            ("CollectionParamsDiff.strict_mode_config", ""),
+            ("ExpiryConfig.ttl", "custom = \"crate::grpc::validate::validate_u64_range_min_1\""),
+            ("StrictModeConfig.max_query_limit", "custom = \"crate::grpc::validate::validate_u64_range_min_1\""),
+            ("StrictModeConfig.max_hnsw_ef", "custom = \"crate::grpc::validate::validate_u64_range_min_1\""),
+            ("StrictModeConfig.max_search_batch_size", "custom = \"crate::grpc::validate::validate_u64_range_min_1\""),
+            ("StrictModeConfig.max_update_points", "custom = \"crate::grpc::validate::validate_u64_range_min_1\""),
+            ("StrictModeConfig.max_payload_size", "custom = \"crate::grpc::validate::validate_u64_range_min_1\""),
+            ("StrictModeConfig.max_histogram_buckets", "custom = \"crate::grpc::validate::validate_u64_range_min_1\""),
+            ("StrictModeConfig.write_rate_limit", "custom = \"crate::grpc::validate::validate_u64_range_min_1\""),
             ("ChangeAliases.timeout", "custom = \"crate::grpc::validate::validate_u64_range_min_1\""),
             ("ListCollectionAliasesRequest.collection_name", "length(min = 1, max = 255)"),
             ("HnswConfigDiff.ef_construct", "custom = \"crate::grpc::validate::validate_u64_range_min_4\""),
@@ -145,6 +168,8 @@ fn configure_validation(builder: Builder) -> Builder {
             ("InitiateShardTransferRequest.collection_name", "length(min = 1, max = 255)"),
             ("WaitForShardStateRequest.collection_name", "length(min = 1, max = 255)"),
             ("WaitForShardStateRequest.timeout", "range(min = 1)"),
+            ("WaitForOperationRequest.collection_name", "length(min = 1, max = 255)"),
+            ("WaitForOperationRequest.timeout", "range(min = 1)"),
         ], &[])
         // Service: points.proto
         .validates(&[
@@ -214,6 +239,13 @@ fn configure_validation(builder: Builder) -> Builder {
             ("DiscoverBatchPoints.timeout", "custom = \"crate::grpc::validate::validate_u64_range_min_1\""),
             ("CountPoints.collection_name", "length(min = 1, max = 255)"),
             ("CountPoints.filter", ""),
+            ("AggregatePoints.collection_name", "length(min = 1, max = 255)"),
+            ("AggregatePoints.filter", ""),
+            ("SearchTextPoints.collection_name", "length(min = 1, max = 255)"),
+            ("SearchTextPoints.query", "length(min = 1)"),
+            ("SearchTextPoints.filter", ""),
+            ("SearchTextPoints.limit", "range(min = 1)"),
+            ("StreamShardChanges.collection_name", "length(min = 1, max = 255)"),
             ("GeoPolygon.exterior", "custom = \"crate::grpc::validate::validate_geo_polygon_exterior\""),
             ("GeoPolygon.interiors", "custom = \"crate::grpc::validate::validate_geo_polygon_interiors\""),
             ("Filter.should", ""),
@@ -230,6 +262,10 @@ fn configure_validation(builder: Builder) -> Builder {
             ("DiscoveryQuery.target", ""),
             ("DiscoveryQuery.context", ""),
             ("ContextQuery.context", ""),
+            ("FeedbackItem.example", ""),
+            ("FeedbackQuery.target", ""),
+            ("FeedbackQuery.feedback", ""),
+            ("FeedbackQuery.context", ""),
         ], &[])
         .type_attribute(".", "#[derive(serde::Serialize)]")
         // Service: points_internal_service.proto
@@ -256,6 +292,8 @@ fn configure_validation(builder: Builder) -> Builder {
             ("ScrollPointsInternal.scroll_points", ""),
             ("GetPointsInternal.get_points", ""),
             ("CountPointsInternal.count_points", ""),
+            ("AggregatePointsInternal.aggregate_points", ""),
+            ("SearchTextPointsInternal.search_text_points", ""),
             ("SyncPointsInternal.sync_points", ""),
             ("SyncPoints.collection_name", "length(min = 1, max = 255)"),
         ], &[])
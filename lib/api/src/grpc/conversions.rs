@@ -2,6 +2,8 @@ use std::collections::{HashMap, HashSet};
 use std::time::Instant;
 
 use chrono::{NaiveDateTime, Timelike};
+use segment::data_types::distinct::{hash_value, DistinctCounter, HyperLogLog};
+use segment::data_types::keyword_index::KeywordIndexType;
 use segment::data_types::text_index::TextIndexType;
 use segment::data_types::vectors::VectorElementType;
 use segment::types::default_quantization_ignore_value;
@@ -11,6 +13,7 @@ use uuid::Uuid;
 use super::qdrant::{BinaryQuantization, CompressionRatio, GeoLineString, GroupId, SparseIndices};
 use crate::grpc::models::{CollectionsResponse, VersionInfo};
 use crate::grpc::qdrant::condition::ConditionOneOf;
+use crate::grpc::qdrant::geo_shape_condition::Shape;
 use crate::grpc::qdrant::payload_index_params::IndexParams;
 use crate::grpc::qdrant::point_id::PointIdOptions;
 use crate::grpc::qdrant::r#match::MatchValue;
@@ -19,14 +22,16 @@ use crate::grpc::qdrant::vectors::VectorsOptions;
 use crate::grpc::qdrant::with_payload_selector::SelectorOptions;
 use crate::grpc::qdrant::{
     shard_key, with_vectors_selector, CollectionDescription, CollectionOperationResponse,
-    Condition, Distance, FieldCondition, Filter, GeoBoundingBox, GeoPoint, GeoPolygon, GeoRadius,
-    HasIdCondition, HealthCheckReply, HnswConfigDiff, IsEmptyCondition, IsNullCondition,
-    ListCollectionsResponse, ListValue, Match, NamedVectors, NestedCondition,
-    PayloadExcludeSelector, PayloadIncludeSelector, PayloadIndexParams, PayloadSchemaInfo,
-    PayloadSchemaType, PointId, ProductQuantization, QuantizationConfig, QuantizationSearchParams,
-    QuantizationType, Range, RepeatedIntegers, RepeatedStrings, ScalarQuantization, ScoredPoint,
-    SearchParams, ShardKey, Struct, TextIndexParams, TokenizerType, Value, ValuesCount, Vector,
-    Vectors, VectorsSelector, WithPayloadSelector, WithVectorsSelector,
+    Condition, Distance, DistinctValues, FieldCondition, Filter, FuzzyText, GeoBoundingBox,
+    GeoDistance, GeoPoint, GeoPolygon, GeoRadius, GeoShapeCondition, GeoShapeRelation,
+    HasIdCondition, HealthCheckReply, HistogramParams, HnswConfigDiff, IsEmptyCondition,
+    IsNullCondition, KeywordIndexParams, ListCollectionsResponse, ListValue, Match, NamedVectors,
+    NestedCondition, OrderBy, PayloadExcludeSelector, PayloadIncludeSelector, PayloadIndexParams,
+    PayloadSchemaInfo, PayloadSchemaType, PointId, ProductQuantization, QuantizationConfig,
+    QuantizationSearchParams, QuantizationType, Range, RepeatedIntegers, RepeatedStrings,
+    ScalarQuantization, ScoredPoint, SearchParams, ShardKey, Struct, TextIndexParams, TextStats,
+    TokenizerType, Value, ValuesCount, Vector, Vectors, VectorsSelector, WithPayloadSelector,
+    WithVectorsSelector,
 };
 
 pub fn payload_to_proto(payload: segment::types::Payload) -> HashMap<String, Value> {
@@ -199,6 +204,17 @@ impl From<segment::data_types::text_index::TextIndexParams> for PayloadIndexPara
     }
 }
 
+impl From<segment::data_types::keyword_index::KeywordIndexParams> for PayloadIndexParams {
+    fn from(params: segment::data_types::keyword_index::KeywordIndexParams) -> Self {
+        PayloadIndexParams {
+            index_params: Some(IndexParams::KeywordIndexParams(KeywordIndexParams {
+                lowercase: params.lowercase,
+                nfkc: params.nfkc,
+            })),
+        }
+    }
+}
+
 impl From<segment::types::PayloadIndexInfo> for PayloadSchemaInfo {
     fn from(schema: segment::types::PayloadIndexInfo) -> Self {
         PayloadSchemaInfo {
@@ -212,6 +228,9 @@ impl From<segment::types::PayloadIndexInfo> for PayloadSchemaInfo {
             }
             .into(),
             params: schema.params.map(|params| match params {
+                segment::types::PayloadSchemaParams::Keyword(keyword_index_params) => {
+                    keyword_index_params.into()
+                }
                 segment::types::PayloadSchemaParams::Text(text_index_params) => {
                     text_index_params.into()
                 }
@@ -261,6 +280,19 @@ impl TryFrom<PayloadIndexParams> for segment::data_types::text_index::TextIndexP
             Some(IndexParams::TextIndexParams(text_index_params)) => {
                 Ok(text_index_params.try_into()?)
             }
+            Some(IndexParams::KeywordIndexParams(_)) => Err(Status::invalid_argument(
+                "keyword index params can't be used for text index",
+            )),
+        }
+    }
+}
+
+impl From<KeywordIndexParams> for segment::data_types::keyword_index::KeywordIndexParams {
+    fn from(params: KeywordIndexParams) -> Self {
+        segment::data_types::keyword_index::KeywordIndexParams {
+            r#type: KeywordIndexType::Keyword,
+            lowercase: params.lowercase,
+            nfkc: params.nfkc,
         }
     }
 }
@@ -273,6 +305,9 @@ impl TryFrom<IndexParams> for segment::types::PayloadSchemaParams {
             IndexParams::TextIndexParams(text_index_params) => Ok(
                 segment::types::PayloadSchemaParams::Text(text_index_params.try_into()?),
             ),
+            IndexParams::KeywordIndexParams(keyword_index_params) => Ok(
+                segment::types::PayloadSchemaParams::Keyword(keyword_index_params.into()),
+            ),
         }
     }
 }
@@ -436,21 +471,41 @@ impl From<segment::data_types::vectors::Vector> for Vector {
             segment::data_types::vectors::Vector::Dense(vector) => Self {
                 data: vector,
                 indices: None,
+                packed: vec![],
             },
             segment::data_types::vectors::Vector::Sparse(vector) => Self {
                 data: vector.values,
                 indices: Some(SparseIndices {
                     data: vector.indices,
                 }),
+                packed: vec![],
             },
         }
     }
 }
 
+/// Dense vector values, either given as is or as a bit-packed binary vector.
+///
+/// Binary vectors are expanded into one value per byte, as accepted by the binary vector storage.
+pub fn dense_or_packed(data: Vec<VectorElementType>, packed: Vec<u8>) -> Vec<VectorElementType> {
+    if packed.is_empty() {
+        data
+    } else {
+        packed.into_iter().map(VectorElementType::from).collect()
+    }
+}
+
+impl Vector {
+    /// Dense vector values, see [`dense_or_packed`]
+    pub fn into_dense(self) -> Vec<VectorElementType> {
+        dense_or_packed(self.data, self.packed)
+    }
+}
+
 impl From<Vector> for segment::data_types::vectors::Vector {
     fn from(vector: Vector) -> Self {
         match vector.indices {
-            None => segment::data_types::vectors::Vector::Dense(vector.data),
+            None => segment::data_types::vectors::Vector::Dense(vector.into_dense()),
             Some(indices) => segment::data_types::vectors::Vector::Sparse(
                 sparse::common::sparse_vector::SparseVector {
                     values: vector.data,
@@ -503,6 +558,8 @@ impl From<segment::types::ScoredPoint> for ScoredPoint {
             version: point.version,
             vectors: point.vector.map(|v| v.into()),
             shard_key: point.shard_key.map(convert_shard_key_to_grpc),
+            geo_distance: point.geo_distance,
+            lookup: vec![],
         }
     }
 }
@@ -540,7 +597,7 @@ impl TryFrom<Vectors> for segment::data_types::vectors::VectorStruct {
         match vectors.vectors_options {
             Some(vectors_options) => Ok(match vectors_options {
                 VectorsOptions::Vector(vector) => {
-                    segment::data_types::vectors::VectorStruct::Single(vector.data)
+                    segment::data_types::vectors::VectorStruct::Single(vector.into_dense())
                 }
                 VectorsOptions::Vectors(vectors) => {
                     segment::data_types::vectors::VectorStruct::Multi(vectors.into())
@@ -924,12 +981,14 @@ impl TryFrom<FieldCondition> for segment::types::FieldCondition {
             geo_radius,
             values_count,
             geo_polygon,
+            geo_shape,
         } = value;
 
         let geo_bounding_box =
             geo_bounding_box.map_or_else(|| Ok(None), |g| g.try_into().map(Some))?;
         let geo_radius = geo_radius.map_or_else(|| Ok(None), |g| g.try_into().map(Some))?;
         let geo_polygon = geo_polygon.map_or_else(|| Ok(None), |g| g.try_into().map(Some))?;
+        let geo_shape = geo_shape.map_or_else(|| Ok(None), |g| g.try_into().map(Some))?;
         Ok(Self {
             key,
             r#match: r#match.map_or_else(|| Ok(None), |m| m.try_into().map(Some))?,
@@ -937,6 +996,7 @@ impl TryFrom<FieldCondition> for segment::types::FieldCondition {
             geo_bounding_box,
             geo_radius,
             geo_polygon,
+            geo_shape,
             values_count: values_count.map(Into::into),
         })
     }
@@ -951,12 +1011,14 @@ impl From<segment::types::FieldCondition> for FieldCondition {
             geo_bounding_box,
             geo_radius,
             geo_polygon,
+            geo_shape,
             values_count,
         } = value;
 
         let geo_bounding_box = geo_bounding_box.map(Into::into);
         let geo_radius = geo_radius.map(Into::into);
         let geo_polygon = geo_polygon.map(Into::into);
+        let geo_shape = geo_shape.map(Into::into);
         Self {
             key,
             r#match: r#match.map(Into::into),
@@ -964,11 +1026,241 @@ impl From<segment::types::FieldCondition> for FieldCondition {
             geo_bounding_box,
             geo_radius,
             geo_polygon,
+            geo_shape,
             values_count: values_count.map(Into::into),
         }
     }
 }
 
+impl TryFrom<GeoShapeCondition> for segment::types::GeoShapeCondition {
+    type Error = Status;
+
+    fn try_from(value: GeoShapeCondition) -> Result<Self, Self::Error> {
+        let GeoShapeCondition { relation, shape } = value;
+
+        let relation = match GeoShapeRelation::from_i32(relation) {
+            Some(GeoShapeRelation::Intersects) => segment::types::GeoShapeRelation::Intersects,
+            Some(GeoShapeRelation::Within) => segment::types::GeoShapeRelation::Within,
+            Some(GeoShapeRelation::Contains) => segment::types::GeoShapeRelation::Contains,
+            None => {
+                return Err(Status::invalid_argument(format!(
+                    "Unknown geo shape relation: {relation}"
+                )))
+            }
+        };
+        let shape = match shape {
+            Some(Shape::Point(point)) => segment::types::GeoShapeQuery::Point(point.into()),
+            Some(Shape::BoundingBox(bounding_box)) => {
+                segment::types::GeoShapeQuery::BoundingBox(bounding_box.try_into()?)
+            }
+            Some(Shape::Polygon(polygon)) => {
+                segment::types::GeoShapeQuery::Polygon(polygon.try_into()?)
+            }
+            None => return Err(Status::invalid_argument("Malformed GeoShapeCondition type")),
+        };
+        Ok(Self { relation, shape })
+    }
+}
+
+impl From<segment::types::GeoShapeCondition> for GeoShapeCondition {
+    fn from(value: segment::types::GeoShapeCondition) -> Self {
+        let relation = match value.relation {
+            segment::types::GeoShapeRelation::Intersects => GeoShapeRelation::Intersects,
+            segment::types::GeoShapeRelation::Within => GeoShapeRelation::Within,
+            segment::types::GeoShapeRelation::Contains => GeoShapeRelation::Contains,
+        };
+        let shape = match value.shape {
+            segment::types::GeoShapeQuery::Point(point) => Shape::Point(point.into()),
+            segment::types::GeoShapeQuery::BoundingBox(bounding_box) => {
+                Shape::BoundingBox(bounding_box.into())
+            }
+            segment::types::GeoShapeQuery::Polygon(polygon) => Shape::Polygon(polygon.into()),
+        };
+        Self {
+            relation: relation as i32,
+            shape: Some(shape),
+        }
+    }
+}
+
+impl TryFrom<GeoDistance> for segment::types::GeoDistance {
+    type Error = Status;
+
+    fn try_from(value: GeoDistance) -> Result<Self, Self::Error> {
+        let GeoDistance { key, origin } = value;
+        let origin = origin.ok_or_else(|| Status::invalid_argument("Geo origin is missing"))?;
+        Ok(Self {
+            key,
+            origin: origin.into(),
+        })
+    }
+}
+
+impl From<segment::types::GeoDistance> for GeoDistance {
+    fn from(value: segment::types::GeoDistance) -> Self {
+        Self {
+            key: value.key,
+            origin: Some(value.origin.into()),
+        }
+    }
+}
+
+impl TryFrom<OrderBy> for segment::types::OrderBy {
+    type Error = Status;
+
+    fn try_from(value: OrderBy) -> Result<Self, Self::Error> {
+        let OrderBy {
+            geo_distance,
+            start_from,
+        } = value;
+        match geo_distance {
+            Some(geo_distance) => Ok(Self {
+                geo_distance: geo_distance.try_into()?,
+                start_from,
+            }),
+            None => Err(Status::invalid_argument("Malformed OrderBy type")),
+        }
+    }
+}
+
+impl From<segment::types::OrderBy> for OrderBy {
+    fn from(value: segment::types::OrderBy) -> Self {
+        let segment::types::OrderBy {
+            geo_distance,
+            start_from,
+        } = value;
+        Self {
+            geo_distance: Some(geo_distance.into()),
+            start_from,
+        }
+    }
+}
+
+impl TryFrom<HistogramParams> for segment::data_types::aggregation::HistogramParams {
+    type Error = Status;
+
+    fn try_from(value: HistogramParams) -> Result<Self, Self::Error> {
+        let HistogramParams {
+            interval,
+            offset,
+            max_buckets,
+        } = value;
+        let params = Self {
+            interval,
+            offset: offset.unwrap_or_default(),
+            max_buckets: max_buckets.map(|v| v as usize),
+        };
+        segment::data_types::aggregation::validate_histogram_params(&params)
+            .map_err(|err| Status::invalid_argument(err.to_string()))?;
+        Ok(params)
+    }
+}
+
+impl From<segment::data_types::aggregation::HistogramParams> for HistogramParams {
+    fn from(value: segment::data_types::aggregation::HistogramParams) -> Self {
+        Self {
+            interval: value.interval,
+            offset: Some(value.offset),
+            max_buckets: value.max_buckets.map(|v| v as u64),
+        }
+    }
+}
+
+impl From<DistinctCounter> for DistinctValues {
+    fn from(value: DistinctCounter) -> Self {
+        match value {
+            DistinctCounter::Values(values) => {
+                let mut keywords = Vec::new();
+                let mut integers = Vec::new();
+                // Only keyword and integer values are ever collected
+                for value in values {
+                    match value {
+                        segment::types::ValueVariants::Keyword(keyword) => keywords.push(keyword),
+                        segment::types::ValueVariants::Integer(integer) => integers.push(integer),
+                        segment::types::ValueVariants::Bool(_) => {}
+                    }
+                }
+                Self {
+                    keywords,
+                    integers,
+                    sketch: None,
+                }
+            }
+            DistinctCounter::Sketch(sketch) => Self {
+                keywords: vec![],
+                integers: vec![],
+                sketch: Some(sketch.registers().to_vec()),
+            },
+        }
+    }
+}
+
+impl TryFrom<DistinctValues> for DistinctCounter {
+    type Error = Status;
+
+    fn try_from(value: DistinctValues) -> Result<Self, Self::Error> {
+        let DistinctValues {
+            keywords,
+            integers,
+            sketch,
+        } = value;
+        let values = keywords
+            .into_iter()
+            .map(segment::types::ValueVariants::Keyword)
+            .chain(
+                integers
+                    .into_iter()
+                    .map(segment::types::ValueVariants::Integer),
+            );
+        match sketch {
+            None => Ok(DistinctCounter::Values(values.collect())),
+            Some(registers) => {
+                let mut sketch = HyperLogLog::from_registers(registers).ok_or_else(|| {
+                    Status::invalid_argument("Malformed distinct values sketch".to_string())
+                })?;
+                values.for_each(|value| sketch.add_hash(hash_value(&value)));
+                Ok(DistinctCounter::Sketch(sketch))
+            }
+        }
+    }
+}
+
+impl From<segment::data_types::text_index::TextStats> for TextStats {
+    fn from(value: segment::data_types::text_index::TextStats) -> Self {
+        let segment::data_types::text_index::TextStats {
+            documents_count,
+            tokens_count,
+            document_frequencies,
+        } = value;
+        Self {
+            documents_count: documents_count as u64,
+            tokens_count: tokens_count as u64,
+            document_frequencies: document_frequencies
+                .into_iter()
+                .map(|(token, frequency)| (token, frequency as u64))
+                .collect(),
+        }
+    }
+}
+
+impl From<TextStats> for segment::data_types::text_index::TextStats {
+    fn from(value: TextStats) -> Self {
+        let TextStats {
+            documents_count,
+            tokens_count,
+            document_frequencies,
+        } = value;
+        Self {
+            documents_count: documents_count as usize,
+            tokens_count: tokens_count as usize,
+            document_frequencies: document_frequencies
+                .into_iter()
+                .map(|(token, frequency)| (token, frequency as usize))
+                .collect(),
+        }
+    }
+}
+
 impl TryFrom<GeoBoundingBox> for segment::types::GeoBoundingBox {
     type Error = Status;
 
@@ -1139,6 +1431,29 @@ impl TryFrom<Match> for segment::types::Match {
                 MatchValue::ExceptKeywords(ints) => {
                     segment::types::Match::Except(ints.strings.into())
                 }
+                MatchValue::Prefix(prefix) => {
+                    segment::types::Match::Prefix(segment::types::MatchPrefix { prefix })
+                }
+                MatchValue::Wildcard(wildcard) => {
+                    segment::types::Match::Wildcard(segment::types::MatchWildcard { wildcard })
+                }
+                MatchValue::Regex(regex) => {
+                    segment::types::Match::Regex(segment::types::MatchRegex { regex })
+                }
+                MatchValue::FuzzyText(FuzzyText { text, fuzzy }) => {
+                    let fuzzy = u8::try_from(fuzzy)
+                        .ok()
+                        .filter(|fuzzy| (1..=segment::types::MatchText::MAX_FUZZY).contains(fuzzy))
+                        .ok_or_else(|| {
+                            Status::invalid_argument(
+                                "Fuzzy text match edit distance must be 1 or 2",
+                            )
+                        })?;
+                    segment::types::Match::Text(segment::types::MatchText {
+                        text,
+                        fuzzy: Some(fuzzy),
+                    })
+                }
             }),
             _ => Err(Status::invalid_argument("Malformed Match condition")),
         }
@@ -1153,9 +1468,13 @@ impl From<segment::types::Match> for Match {
                 segment::types::ValueVariants::Integer(int) => MatchValue::Integer(int),
                 segment::types::ValueVariants::Bool(flag) => MatchValue::Boolean(flag),
             },
-            segment::types::Match::Text(segment::types::MatchText { text }) => {
-                MatchValue::Text(text)
-            }
+            segment::types::Match::Text(segment::types::MatchText { text, fuzzy }) => match fuzzy {
+                Some(fuzzy) => MatchValue::FuzzyText(FuzzyText {
+                    text,
+                    fuzzy: u32::from(fuzzy),
+                }),
+                None => MatchValue::Text(text),
+            },
             segment::types::Match::Any(any) => match any.any {
                 segment::types::AnyVariants::Keywords(strings) => {
                     MatchValue::Keywords(RepeatedStrings { strings })
@@ -1172,6 +1491,15 @@ impl From<segment::types::Match> for Match {
                     MatchValue::ExceptIntegers(RepeatedIntegers { integers })
                 }
             },
+            segment::types::Match::Prefix(segment::types::MatchPrefix { prefix }) => {
+                MatchValue::Prefix(prefix)
+            }
+            segment::types::Match::Wildcard(segment::types::MatchWildcard { wildcard }) => {
+                MatchValue::Wildcard(wildcard)
+            }
+            segment::types::Match::Regex(segment::types::MatchRegex { regex }) => {
+                MatchValue::Regex(regex)
+            }
         };
         Self {
             match_value: Some(match_value),
@@ -1213,6 +1541,8 @@ impl TryFrom<Distance> for segment::types::Distance {
             Distance::Euclid => segment::types::Distance::Euclid,
             Distance::Dot => segment::types::Distance::Dot,
             Distance::Manhattan => segment::types::Distance::Manhattan,
+            Distance::Hamming => segment::types::Distance::Hamming,
+            Distance::Jaccard => segment::types::Distance::Jaccard,
         })
     }
 }
@@ -1,5 +1,4 @@
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct VectorParams {
@@ -22,8 +21,7 @@ pub struct VectorParams {
     #[prost(bool, optional, tag = "5")]
     pub on_disk: ::core::option::Option<bool>,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct VectorParamsDiff {
@@ -39,8 +37,7 @@ pub struct VectorParamsDiff {
     #[prost(bool, optional, tag = "3")]
     pub on_disk: ::core::option::Option<bool>,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct VectorParamsMap {
@@ -48,20 +45,15 @@ pub struct VectorParamsMap {
     #[validate]
     pub map: ::std::collections::HashMap<::prost::alloc::string::String, VectorParams>,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct VectorParamsDiffMap {
     #[prost(map = "string, message", tag = "1")]
     #[validate]
-    pub map: ::std::collections::HashMap<
-        ::prost::alloc::string::String,
-        VectorParamsDiff,
-    >,
+    pub map: ::std::collections::HashMap<::prost::alloc::string::String, VectorParamsDiff>,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct VectorsConfig {
@@ -81,8 +73,7 @@ pub mod vectors_config {
         ParamsMap(super::VectorParamsMap),
     }
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct VectorsConfigDiff {
@@ -109,19 +100,24 @@ pub struct SparseVectorParams {
     /// Configuration of sparse index
     #[prost(message, optional, tag = "1")]
     pub index: ::core::option::Option<SparseIndexConfig>,
+    /// Remove dimensions with absolute weight below this threshold from upserted vectors, applies to vectors upserted afterwards
+    #[prost(float, optional, tag = "2")]
+    pub prune_threshold: ::core::option::Option<f32>,
+    /// Keep only this number of dimensions with the largest absolute weights in upserted vectors, applies to vectors upserted afterwards
+    #[prost(uint64, optional, tag = "3")]
+    pub max_dims: ::core::option::Option<u64>,
+    /// Datatype of the stored weights
+    #[prost(enumeration = "SparseVectorDatatype", optional, tag = "4")]
+    pub datatype: ::core::option::Option<i32>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SparseVectorConfig {
     #[prost(map = "string, message", tag = "1")]
-    pub map: ::std::collections::HashMap<
-        ::prost::alloc::string::String,
-        SparseVectorParams,
-    >,
+    pub map: ::std::collections::HashMap<::prost::alloc::string::String, SparseVectorParams>,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetCollectionInfoRequest {
@@ -130,8 +126,7 @@ pub struct GetCollectionInfoRequest {
     #[validate(length(min = 1, max = 255))]
     pub collection_name: ::prost::alloc::string::String,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListCollectionsRequest {}
@@ -172,8 +167,7 @@ pub struct OptimizerStatus {
     #[prost(string, tag = "2")]
     pub error: ::prost::alloc::string::String,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct HnswConfigDiff {
@@ -219,9 +213,12 @@ pub struct SparseIndexConfig {
     /// Store inverted index on disk. If set to false, the index will be stored in RAM.
     #[prost(bool, optional, tag = "2")]
     pub on_disk: ::core::option::Option<bool>,
+    ///
+    /// Store posting lists of the on-disk index in compressed format. Ids are delta encoded and weights are quantized.
+    #[prost(bool, optional, tag = "3")]
+    pub compressed: ::core::option::Option<bool>,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct WalConfigDiff {
@@ -233,8 +230,7 @@ pub struct WalConfigDiff {
     #[prost(uint64, optional, tag = "2")]
     pub wal_segments_ahead: ::core::option::Option<u64>,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct OptimizersConfigDiff {
@@ -300,8 +296,7 @@ pub struct OptimizersConfigDiff {
     #[prost(uint64, optional, tag = "8")]
     pub max_optimization_threads: ::core::option::Option<u64>,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ScalarQuantization {
@@ -316,8 +311,7 @@ pub struct ScalarQuantization {
     #[prost(bool, optional, tag = "3")]
     pub always_ram: ::core::option::Option<bool>,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ProductQuantization {
@@ -328,8 +322,7 @@ pub struct ProductQuantization {
     #[prost(bool, optional, tag = "2")]
     pub always_ram: ::core::option::Option<bool>,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct BinaryQuantization {
@@ -337,8 +330,7 @@ pub struct BinaryQuantization {
     #[prost(bool, optional, tag = "1")]
     pub always_ram: ::core::option::Option<bool>,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QuantizationConfig {
@@ -360,13 +352,11 @@ pub mod quantization_config {
         Binary(super::BinaryQuantization),
     }
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Disabled {}
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QuantizationConfigDiff {
@@ -390,8 +380,7 @@ pub mod quantization_config_diff {
         Binary(super::BinaryQuantization),
     }
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateCollection {
@@ -446,9 +435,19 @@ pub struct CreateCollection {
     /// Configuration for sparse vectors
     #[prost(message, optional, tag = "16")]
     pub sparse_vectors_config: ::core::option::Option<SparseVectorConfig>,
+    /// Configuration of points expiry
+    #[prost(message, optional, tag = "17")]
+    #[validate]
+    pub expiry_config: ::core::option::Option<ExpiryConfig>,
+    /// Schema, which payload of upserted points and payload updates are validated against
+    #[prost(message, optional, tag = "18")]
+    pub payload_schema: ::core::option::Option<PayloadSchema>,
+    /// Limits of requests to the collection
+    #[prost(message, optional, tag = "19")]
+    #[validate]
+    pub strict_mode_config: ::core::option::Option<StrictModeConfig>,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpdateCollection {
@@ -483,9 +482,18 @@ pub struct UpdateCollection {
     /// New sparse vector parameters
     #[prost(message, optional, tag = "8")]
     pub sparse_vectors_config: ::core::option::Option<SparseVectorConfig>,
+    /// New vectors to add to the collection, existing points have no values for them
+    #[prost(message, optional, tag = "9")]
+    #[validate]
+    pub create_vectors_config: ::core::option::Option<VectorsConfig>,
+    /// New sparse vectors to add to the collection, existing points have no values for them
+    #[prost(message, optional, tag = "10")]
+    pub create_sparse_vectors_config: ::core::option::Option<SparseVectorConfig>,
+    /// Names of vectors or sparse vectors to remove from the collection along with their data
+    #[prost(string, repeated, tag = "11")]
+    pub delete_vectors: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DeleteCollection {
@@ -498,6 +506,61 @@ pub struct DeleteCollection {
     #[validate(custom = "crate::grpc::validate::validate_u64_range_min_1")]
     pub timeout: ::core::option::Option<u64>,
 }
+#[derive(validator::Validate, serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RenameCollection {
+    /// Name of the collection
+    #[prost(string, tag = "1")]
+    #[validate(length(min = 1, max = 255))]
+    pub collection_name: ::prost::alloc::string::String,
+    /// New name of the collection
+    #[prost(string, tag = "2")]
+    #[validate(
+        length(min = 1, max = 255),
+        custom = "common::validation::validate_collection_name"
+    )]
+    pub new_collection_name: ::prost::alloc::string::String,
+    /// If true - existing collection with the new name is replaced. Default is false
+    #[prost(bool, optional, tag = "3")]
+    pub replace_existing: ::core::option::Option<bool>,
+    /// Wait timeout for operation commit in seconds, if not specified - default value will be supplied
+    #[prost(uint64, optional, tag = "4")]
+    #[validate(custom = "crate::grpc::validate::validate_u64_range_min_1")]
+    pub timeout: ::core::option::Option<u64>,
+}
+#[derive(validator::Validate, serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CloneCollection {
+    /// Name of the source collection
+    #[prost(string, tag = "1")]
+    #[validate(length(min = 1, max = 255))]
+    pub collection_name: ::prost::alloc::string::String,
+    /// Name of the new collection
+    #[prost(string, tag = "2")]
+    #[validate(
+        length(min = 1, max = 255),
+        custom = "common::validation::validate_collection_name"
+    )]
+    pub new_collection_name: ::prost::alloc::string::String,
+    /// Vector parameters to change in the copy
+    #[prost(message, optional, tag = "3")]
+    #[validate]
+    pub vectors_config: ::core::option::Option<VectorsConfigDiff>,
+    /// HNSW parameters to change in the copy
+    #[prost(message, optional, tag = "4")]
+    #[validate]
+    pub hnsw_config: ::core::option::Option<HnswConfigDiff>,
+    /// Quantization configuration of the copy
+    #[prost(message, optional, tag = "5")]
+    #[validate]
+    pub quantization_config: ::core::option::Option<QuantizationConfigDiff>,
+    /// Wait timeout for operation commit in seconds, if not specified - default value will be supplied
+    #[prost(uint64, optional, tag = "6")]
+    #[validate(custom = "crate::grpc::validate::validate_u64_range_min_1")]
+    pub timeout: ::core::option::Option<u64>,
+}
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -509,8 +572,116 @@ pub struct CollectionOperationResponse {
     #[prost(double, tag = "2")]
     pub time: f64,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExpiryConfig {
+    /// Time-to-live in seconds of points upserted without an explicit `expire_at` payload value
+    #[prost(uint64, optional, tag = "1")]
+    #[validate(custom = "crate::grpc::validate::validate_u64_range_min_1")]
+    pub ttl: ::core::option::Option<u64>,
+}
+#[derive(validator::Validate, serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PayloadEnumValue {
+    #[prost(oneof = "payload_enum_value::Variant", tags = "1, 2, 3")]
+    pub variant: ::core::option::Option<payload_enum_value::Variant>,
+}
+/// Nested message and enum types in `PayloadEnumValue`.
+pub mod payload_enum_value {
+    #[derive(serde::Serialize)]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Variant {
+        /// String value
+        #[prost(string, tag = "1")]
+        Keyword(::prost::alloc::string::String),
+        /// Integer value
+        #[prost(int64, tag = "2")]
+        Integer(i64),
+        /// Boolean value
+        #[prost(bool, tag = "3")]
+        Boolean(bool),
+    }
+}
+#[derive(validator::Validate, serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PayloadValueSchema {
+    /// Type of the value
+    #[prost(enumeration = "PayloadValueType", tag = "1")]
+    pub r#type: i32,
+    /// Schema of array elements, only for array type
+    #[prost(message, optional, boxed, tag = "2")]
+    pub items: ::core::option::Option<::prost::alloc::boxed::Box<PayloadValueSchema>>,
+    /// Schemas of object fields, only for object type
+    #[prost(map = "string, message", tag = "3")]
+    pub properties: ::std::collections::HashMap<::prost::alloc::string::String, PayloadValueSchema>,
+    /// Fields, which the object must have, only for object type
+    #[prost(string, repeated, tag = "4")]
+    pub required: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// If not empty - value must be one of these values
+    #[prost(message, repeated, tag = "5")]
+    pub enum_values: ::prost::alloc::vec::Vec<PayloadEnumValue>,
+}
+#[derive(validator::Validate, serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PayloadSchema {
+    /// Schemas of payload fields, fields which are not listed are not validated
+    #[prost(map = "string, message", tag = "1")]
+    pub properties: ::std::collections::HashMap<::prost::alloc::string::String, PayloadValueSchema>,
+    /// Fields, which payload of each point must have
+    #[prost(string, repeated, tag = "2")]
+    pub required: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// If true - values of indexed fields, which can't be stored in the payload index, are rejected
+    #[prost(bool, optional, tag = "3")]
+    pub strict: ::core::option::Option<bool>,
+}
+#[derive(validator::Validate, serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StrictModeConfig {
+    /// Max `limit` of search, recommend, discover, group and scroll requests
+    #[prost(uint64, optional, tag = "1")]
+    #[validate(custom = "crate::grpc::validate::validate_u64_range_min_1")]
+    pub max_query_limit: ::core::option::Option<u64>,
+    /// Max `offset` of search, recommend and discover requests
+    #[prost(uint64, optional, tag = "2")]
+    pub max_query_offset: ::core::option::Option<u64>,
+    /// Max `hnsw_ef` search parameter
+    #[prost(uint64, optional, tag = "3")]
+    #[validate(custom = "crate::grpc::validate::validate_u64_range_min_1")]
+    pub max_hnsw_ef: ::core::option::Option<u64>,
+    /// If false - exact search requests are rejected
+    #[prost(bool, optional, tag = "4")]
+    pub search_allow_exact: ::core::option::Option<bool>,
+    /// If false - filters on fields, which have no suitable payload index, are rejected
+    #[prost(bool, optional, tag = "5")]
+    pub unindexed_filtering: ::core::option::Option<bool>,
+    /// Max number of requests in a batch search, recommend, discover or query request
+    #[prost(uint64, optional, tag = "6")]
+    #[validate(custom = "crate::grpc::validate::validate_u64_range_min_1")]
+    pub max_search_batch_size: ::core::option::Option<u64>,
+    /// Max number of points listed in a single update
+    #[prost(uint64, optional, tag = "9")]
+    #[validate(custom = "crate::grpc::validate::validate_u64_range_min_1")]
+    pub max_update_points: ::core::option::Option<u64>,
+    /// Max size of the payload of a single point in bytes
+    #[prost(uint64, optional, tag = "7")]
+    #[validate(custom = "crate::grpc::validate::validate_u64_range_min_1")]
+    pub max_payload_size: ::core::option::Option<u64>,
+    /// Max number of histogram buckets of aggregate requests
+    #[prost(uint64, optional, tag = "10")]
+    #[validate(custom = "crate::grpc::validate::validate_u64_range_min_1")]
+    pub max_histogram_buckets: ::core::option::Option<u64>,
+    /// Max number of update requests per minute
+    #[prost(uint64, optional, tag = "8")]
+    #[validate(custom = "crate::grpc::validate::validate_u64_range_min_1")]
+    pub write_rate_limit: ::core::option::Option<u64>,
+}
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CollectionParams {
@@ -539,9 +710,21 @@ pub struct CollectionParams {
     /// Configuration for sparse vectors
     #[prost(message, optional, tag = "10")]
     pub sparse_vectors_config: ::core::option::Option<SparseVectorConfig>,
+    /// If true - shards are automatically moved between peers to keep the cluster balanced
+    #[prost(bool, optional, tag = "11")]
+    pub auto_rebalance: ::core::option::Option<bool>,
+    /// Configuration of points expiry
+    #[prost(message, optional, tag = "12")]
+    #[validate]
+    pub expiry_config: ::core::option::Option<ExpiryConfig>,
+    /// Schema, which payload of upserted points and payload updates are validated against
+    #[prost(message, optional, tag = "13")]
+    pub payload_schema: ::core::option::Option<PayloadSchema>,
+    /// Limits of requests to the collection
+    #[prost(message, optional, tag = "14")]
+    pub strict_mode_config: ::core::option::Option<StrictModeConfig>,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CollectionParamsDiff {
@@ -557,9 +740,22 @@ pub struct CollectionParamsDiff {
     /// Fan-out every read request to these many additional remote nodes (and return first available response)
     #[prost(uint32, optional, tag = "4")]
     pub read_fan_out_factor: ::core::option::Option<u32>,
+    /// If true - shards are automatically moved between peers to keep the cluster balanced
+    #[prost(bool, optional, tag = "5")]
+    pub auto_rebalance: ::core::option::Option<bool>,
+    /// Configuration of points expiry
+    #[prost(message, optional, tag = "6")]
+    #[validate]
+    pub expiry_config: ::core::option::Option<ExpiryConfig>,
+    /// Schema, which payload of upserted points and payload updates are validated against
+    #[prost(message, optional, tag = "7")]
+    pub payload_schema: ::core::option::Option<PayloadSchema>,
+    /// Limits of requests to the collection
+    #[prost(message, optional, tag = "8")]
+    #[validate]
+    pub strict_mode_config: ::core::option::Option<StrictModeConfig>,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CollectionConfig {
@@ -602,8 +798,19 @@ pub struct TextIndexParams {
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct KeywordIndexParams {
+    /// If true - keywords are lowercased on indexing and querying
+    #[prost(bool, optional, tag = "1")]
+    pub lowercase: ::core::option::Option<bool>,
+    /// If true - keywords are normalized to Unicode NFKC form on indexing and querying
+    #[prost(bool, optional, tag = "2")]
+    pub nfkc: ::core::option::Option<bool>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PayloadIndexParams {
-    #[prost(oneof = "payload_index_params::IndexParams", tags = "1")]
+    #[prost(oneof = "payload_index_params::IndexParams", tags = "1, 2")]
     pub index_params: ::core::option::Option<payload_index_params::IndexParams>,
 }
 /// Nested message and enum types in `PayloadIndexParams`.
@@ -615,6 +822,9 @@ pub mod payload_index_params {
         /// Parameters for text index
         #[prost(message, tag = "1")]
         TextIndexParams(super::TextIndexParams),
+        /// Parameters for keyword index
+        #[prost(message, tag = "2")]
+        KeywordIndexParams(super::KeywordIndexParams),
     }
 }
 #[derive(serde::Serialize)]
@@ -652,10 +862,8 @@ pub struct CollectionInfo {
     pub config: ::core::option::Option<CollectionConfig>,
     /// Collection data types
     #[prost(map = "string, message", tag = "8")]
-    pub payload_schema: ::std::collections::HashMap<
-        ::prost::alloc::string::String,
-        PayloadSchemaInfo,
-    >,
+    pub payload_schema:
+        ::std::collections::HashMap<::prost::alloc::string::String, PayloadSchemaInfo>,
     /// Approximate number of points in the collection
     #[prost(uint64, optional, tag = "9")]
     pub points_count: ::core::option::Option<u64>,
@@ -663,8 +871,7 @@ pub struct CollectionInfo {
     #[prost(uint64, optional, tag = "10")]
     pub indexed_vectors_count: ::core::option::Option<u64>,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ChangeAliases {
@@ -727,13 +934,11 @@ pub struct DeleteAlias {
     #[prost(string, tag = "1")]
     pub alias_name: ::prost::alloc::string::String,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListAliasesRequest {}
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListCollectionAliasesRequest {
@@ -763,8 +968,7 @@ pub struct ListAliasesResponse {
     #[prost(double, tag = "2")]
     pub time: f64,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CollectionClusterInfoRequest {
@@ -861,6 +1065,9 @@ pub struct CollectionClusterInfoResponse {
     /// Shard transfers
     #[prost(message, repeated, tag = "5")]
     pub shard_transfers: ::prost::alloc::vec::Vec<ShardTransferInfo>,
+    /// Problems with the placement of shard replicas
+    #[prost(string, repeated, tag = "6")]
+    pub warnings: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -876,8 +1083,7 @@ pub struct MoveShard {
     #[prost(enumeration = "ShardTransferMethod", optional, tag = "4")]
     pub method: ::core::option::Option<i32>,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Replica {
@@ -911,8 +1117,7 @@ pub struct DeleteShardKey {
     #[prost(message, optional, tag = "1")]
     pub shard_key: ::core::option::Option<ShardKey>,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpdateCollectionClusterSetupRequest {
@@ -928,9 +1133,7 @@ pub struct UpdateCollectionClusterSetupRequest {
         tags = "2, 3, 4, 5, 7, 8"
     )]
     #[validate]
-    pub operation: ::core::option::Option<
-        update_collection_cluster_setup_request::Operation,
-    >,
+    pub operation: ::core::option::Option<update_collection_cluster_setup_request::Operation>,
 }
 /// Nested message and enum types in `UpdateCollectionClusterSetupRequest`.
 pub mod update_collection_cluster_setup_request {
@@ -1001,8 +1204,9 @@ pub struct DeleteShardKeyResponse {
     #[prost(bool, tag = "1")]
     pub result: bool,
 }
-#[derive(serde::Serialize)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(
+    serde::Serialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration,
+)]
 #[repr(i32)]
 pub enum Distance {
     UnknownDistance = 0,
@@ -1010,6 +1214,10 @@ pub enum Distance {
     Euclid = 2,
     Dot = 3,
     Manhattan = 4,
+    /// Bit-packed binary vectors, dimension is the number of bits
+    Hamming = 5,
+    /// Bit-packed binary vectors, dimension is the number of bits
+    Jaccard = 6,
 }
 impl Distance {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -1023,6 +1231,8 @@ impl Distance {
             Distance::Euclid => "Euclid",
             Distance::Dot => "Dot",
             Distance::Manhattan => "Manhattan",
+            Distance::Hamming => "Hamming",
+            Distance::Jaccard => "Jaccard",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -1033,12 +1243,15 @@ impl Distance {
             "Euclid" => Some(Self::Euclid),
             "Dot" => Some(Self::Dot),
             "Manhattan" => Some(Self::Manhattan),
+            "Hamming" => Some(Self::Hamming),
+            "Jaccard" => Some(Self::Jaccard),
             _ => None,
         }
     }
 }
-#[derive(serde::Serialize)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(
+    serde::Serialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration,
+)]
 #[repr(i32)]
 pub enum CollectionStatus {
     UnknownCollectionStatus = 0,
@@ -1073,8 +1286,9 @@ impl CollectionStatus {
         }
     }
 }
-#[derive(serde::Serialize)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(
+    serde::Serialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration,
+)]
 #[repr(i32)]
 pub enum PayloadSchemaType {
     UnknownType = 0,
@@ -1115,8 +1329,40 @@ impl PayloadSchemaType {
         }
     }
 }
-#[derive(serde::Serialize)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(
+    serde::Serialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration,
+)]
+#[repr(i32)]
+pub enum SparseVectorDatatype {
+    SparseFloat32 = 0,
+    SparseFloat16 = 1,
+    SparseUint8 = 2,
+}
+impl SparseVectorDatatype {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            SparseVectorDatatype::SparseFloat32 => "SparseFloat32",
+            SparseVectorDatatype::SparseFloat16 => "SparseFloat16",
+            SparseVectorDatatype::SparseUint8 => "SparseUint8",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SparseFloat32" => Some(Self::SparseFloat32),
+            "SparseFloat16" => Some(Self::SparseFloat16),
+            "SparseUint8" => Some(Self::SparseUint8),
+            _ => None,
+        }
+    }
+}
+#[derive(
+    serde::Serialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration,
+)]
 #[repr(i32)]
 pub enum QuantizationType {
     UnknownQuantization = 0,
@@ -1142,8 +1388,9 @@ impl QuantizationType {
         }
     }
 }
-#[derive(serde::Serialize)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(
+    serde::Serialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration,
+)]
 #[repr(i32)]
 pub enum CompressionRatio {
     X4 = 0,
@@ -1178,8 +1425,9 @@ impl CompressionRatio {
         }
     }
 }
-#[derive(serde::Serialize)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(
+    serde::Serialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration,
+)]
 #[repr(i32)]
 pub enum ShardingMethod {
     /// Auto-sharding based on record ids
@@ -1207,8 +1455,52 @@ impl ShardingMethod {
         }
     }
 }
-#[derive(serde::Serialize)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(
+    serde::Serialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration,
+)]
+#[repr(i32)]
+pub enum PayloadValueType {
+    Unknown = 0,
+    String = 1,
+    Integer = 2,
+    Float = 3,
+    Bool = 4,
+    Array = 5,
+    Object = 6,
+}
+impl PayloadValueType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            PayloadValueType::Unknown => "PayloadValueTypeUnknown",
+            PayloadValueType::String => "PayloadValueTypeString",
+            PayloadValueType::Integer => "PayloadValueTypeInteger",
+            PayloadValueType::Float => "PayloadValueTypeFloat",
+            PayloadValueType::Bool => "PayloadValueTypeBool",
+            PayloadValueType::Array => "PayloadValueTypeArray",
+            PayloadValueType::Object => "PayloadValueTypeObject",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "PayloadValueTypeUnknown" => Some(Self::Unknown),
+            "PayloadValueTypeString" => Some(Self::String),
+            "PayloadValueTypeInteger" => Some(Self::Integer),
+            "PayloadValueTypeFloat" => Some(Self::Float),
+            "PayloadValueTypeBool" => Some(Self::Bool),
+            "PayloadValueTypeArray" => Some(Self::Array),
+            "PayloadValueTypeObject" => Some(Self::Object),
+            _ => None,
+        }
+    }
+}
+#[derive(
+    serde::Serialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration,
+)]
 #[repr(i32)]
 pub enum TokenizerType {
     Unknown = 0,
@@ -1243,8 +1535,9 @@ impl TokenizerType {
         }
     }
 }
-#[derive(serde::Serialize)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(
+    serde::Serialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration,
+)]
 #[repr(i32)]
 pub enum ReplicaState {
     /// Active and sound
@@ -1288,8 +1581,9 @@ impl ReplicaState {
         }
     }
 }
-#[derive(serde::Serialize)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(
+    serde::Serialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration,
+)]
 #[repr(i32)]
 pub enum ShardTransferMethod {
     StreamRecords = 0,
@@ -1318,8 +1612,8 @@ impl ShardTransferMethod {
 /// Generated client implementations.
 pub mod collections_client {
     #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
-    use tonic::codegen::*;
     use tonic::codegen::http::Uri;
+    use tonic::codegen::*;
     #[derive(Debug, Clone)]
     pub struct CollectionsClient<T> {
         inner: tonic::client::Grpc<T>,
@@ -1363,9 +1657,8 @@ pub mod collections_client {
                     <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
                 >,
             >,
-            <T as tonic::codegen::Service<
-                http::Request<tonic::body::BoxBody>,
-            >>::Error: Into<StdError> + Send + Sync,
+            <T as tonic::codegen::Service<http::Request<tonic::body::BoxBody>>>::Error:
+                Into<StdError> + Send + Sync,
         {
             CollectionsClient::new(InterceptedService::new(inner, interceptor))
         }
@@ -1405,23 +1698,19 @@ pub mod collections_client {
         pub async fn get(
             &mut self,
             request: impl tonic::IntoRequest<super::GetCollectionInfoRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::GetCollectionInfoResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::GetCollectionInfoResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static("/qdrant.Collections/Get");
             let mut req = request.into_request();
-            req.extensions_mut().insert(GrpcMethod::new("qdrant.Collections", "Get"));
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Collections", "Get"));
             self.inner.unary(req, path, codec).await
         }
         ///
@@ -1429,23 +1718,19 @@ pub mod collections_client {
         pub async fn list(
             &mut self,
             request: impl tonic::IntoRequest<super::ListCollectionsRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::ListCollectionsResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::ListCollectionsResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static("/qdrant.Collections/List");
             let mut req = request.into_request();
-            req.extensions_mut().insert(GrpcMethod::new("qdrant.Collections", "List"));
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Collections", "List"));
             self.inner.unary(req, path, codec).await
         }
         ///
@@ -1453,25 +1738,19 @@ pub mod collections_client {
         pub async fn create(
             &mut self,
             request: impl tonic::IntoRequest<super::CreateCollection>,
-        ) -> std::result::Result<
-            tonic::Response<super::CollectionOperationResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::CollectionOperationResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.Collections/Create",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.Collections/Create");
             let mut req = request.into_request();
-            req.extensions_mut().insert(GrpcMethod::new("qdrant.Collections", "Create"));
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Collections", "Create"));
             self.inner.unary(req, path, codec).await
         }
         ///
@@ -1479,25 +1758,19 @@ pub mod collections_client {
         pub async fn update(
             &mut self,
             request: impl tonic::IntoRequest<super::UpdateCollection>,
-        ) -> std::result::Result<
-            tonic::Response<super::CollectionOperationResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::CollectionOperationResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.Collections/Update",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.Collections/Update");
             let mut req = request.into_request();
-            req.extensions_mut().insert(GrpcMethod::new("qdrant.Collections", "Update"));
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Collections", "Update"));
             self.inner.unary(req, path, codec).await
         }
         ///
@@ -1505,25 +1778,59 @@ pub mod collections_client {
         pub async fn delete(
             &mut self,
             request: impl tonic::IntoRequest<super::DeleteCollection>,
-        ) -> std::result::Result<
-            tonic::Response<super::CollectionOperationResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::CollectionOperationResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.Collections/Delete",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.Collections/Delete");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Collections", "Delete"));
+            self.inner.unary(req, path, codec).await
+        }
+        ///
+        /// Rename existing collection
+        pub async fn rename_collection(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RenameCollection>,
+        ) -> std::result::Result<tonic::Response<super::CollectionOperationResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/qdrant.Collections/RenameCollection");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Collections", "RenameCollection"));
+            self.inner.unary(req, path, codec).await
+        }
+        ///
+        /// Create a copy of existing collection
+        pub async fn clone_collection(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CloneCollection>,
+        ) -> std::result::Result<tonic::Response<super::CollectionOperationResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/qdrant.Collections/CloneCollection");
             let mut req = request.into_request();
-            req.extensions_mut().insert(GrpcMethod::new("qdrant.Collections", "Delete"));
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Collections", "CloneCollection"));
             self.inner.unary(req, path, codec).await
         }
         ///
@@ -1531,23 +1838,16 @@ pub mod collections_client {
         pub async fn update_aliases(
             &mut self,
             request: impl tonic::IntoRequest<super::ChangeAliases>,
-        ) -> std::result::Result<
-            tonic::Response<super::CollectionOperationResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::CollectionOperationResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.Collections/UpdateAliases",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.Collections/UpdateAliases");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("qdrant.Collections", "UpdateAliases"));
@@ -1558,26 +1858,22 @@ pub mod collections_client {
         pub async fn list_collection_aliases(
             &mut self,
             request: impl tonic::IntoRequest<super::ListCollectionAliasesRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::ListAliasesResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::ListAliasesResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.Collections/ListCollectionAliases",
-            );
+            let path =
+                http::uri::PathAndQuery::from_static("/qdrant.Collections/ListCollectionAliases");
             let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(GrpcMethod::new("qdrant.Collections", "ListCollectionAliases"));
+            req.extensions_mut().insert(GrpcMethod::new(
+                "qdrant.Collections",
+                "ListCollectionAliases",
+            ));
             self.inner.unary(req, path, codec).await
         }
         ///
@@ -1585,23 +1881,16 @@ pub mod collections_client {
         pub async fn list_aliases(
             &mut self,
             request: impl tonic::IntoRequest<super::ListAliasesRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::ListAliasesResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::ListAliasesResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.Collections/ListAliases",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.Collections/ListAliases");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("qdrant.Collections", "ListAliases"));
@@ -1612,26 +1901,22 @@ pub mod collections_client {
         pub async fn collection_cluster_info(
             &mut self,
             request: impl tonic::IntoRequest<super::CollectionClusterInfoRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::CollectionClusterInfoResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::CollectionClusterInfoResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.Collections/CollectionClusterInfo",
-            );
+            let path =
+                http::uri::PathAndQuery::from_static("/qdrant.Collections/CollectionClusterInfo");
             let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(GrpcMethod::new("qdrant.Collections", "CollectionClusterInfo"));
+            req.extensions_mut().insert(GrpcMethod::new(
+                "qdrant.Collections",
+                "CollectionClusterInfo",
+            ));
             self.inner.unary(req, path, codec).await
         }
         ///
@@ -1643,24 +1928,21 @@ pub mod collections_client {
             tonic::Response<super::UpdateCollectionClusterSetupResponse>,
             tonic::Status,
         > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
                 "/qdrant.Collections/UpdateCollectionClusterSetup",
             );
             let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(
-                    GrpcMethod::new("qdrant.Collections", "UpdateCollectionClusterSetup"),
-                );
+            req.extensions_mut().insert(GrpcMethod::new(
+                "qdrant.Collections",
+                "UpdateCollectionClusterSetup",
+            ));
             self.inner.unary(req, path, codec).await
         }
         ///
@@ -1668,23 +1950,16 @@ pub mod collections_client {
         pub async fn create_shard_key(
             &mut self,
             request: impl tonic::IntoRequest<super::CreateShardKeyRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::CreateShardKeyResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::CreateShardKeyResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.Collections/CreateShardKey",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.Collections/CreateShardKey");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("qdrant.Collections", "CreateShardKey"));
@@ -1695,23 +1970,16 @@ pub mod collections_client {
         pub async fn delete_shard_key(
             &mut self,
             request: impl tonic::IntoRequest<super::DeleteShardKeyRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::DeleteShardKeyResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::DeleteShardKeyResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.Collections/DeleteShardKey",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.Collections/DeleteShardKey");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("qdrant.Collections", "DeleteShardKey"));
@@ -1731,82 +1999,67 @@ pub mod collections_server {
         async fn get(
             &self,
             request: tonic::Request<super::GetCollectionInfoRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::GetCollectionInfoResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::GetCollectionInfoResponse>, tonic::Status>;
         ///
         /// Get list name of all existing collections
         async fn list(
             &self,
             request: tonic::Request<super::ListCollectionsRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::ListCollectionsResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::ListCollectionsResponse>, tonic::Status>;
         ///
         /// Create new collection with given parameters
         async fn create(
             &self,
             request: tonic::Request<super::CreateCollection>,
-        ) -> std::result::Result<
-            tonic::Response<super::CollectionOperationResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::CollectionOperationResponse>, tonic::Status>;
         ///
         /// Update parameters of the existing collection
         async fn update(
             &self,
             request: tonic::Request<super::UpdateCollection>,
-        ) -> std::result::Result<
-            tonic::Response<super::CollectionOperationResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::CollectionOperationResponse>, tonic::Status>;
         ///
         /// Drop collection and all associated data
         async fn delete(
             &self,
             request: tonic::Request<super::DeleteCollection>,
-        ) -> std::result::Result<
-            tonic::Response<super::CollectionOperationResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::CollectionOperationResponse>, tonic::Status>;
+        ///
+        /// Rename existing collection
+        async fn rename_collection(
+            &self,
+            request: tonic::Request<super::RenameCollection>,
+        ) -> std::result::Result<tonic::Response<super::CollectionOperationResponse>, tonic::Status>;
+        ///
+        /// Create a copy of existing collection
+        async fn clone_collection(
+            &self,
+            request: tonic::Request<super::CloneCollection>,
+        ) -> std::result::Result<tonic::Response<super::CollectionOperationResponse>, tonic::Status>;
         ///
         /// Update Aliases of the existing collection
         async fn update_aliases(
             &self,
             request: tonic::Request<super::ChangeAliases>,
-        ) -> std::result::Result<
-            tonic::Response<super::CollectionOperationResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::CollectionOperationResponse>, tonic::Status>;
         ///
         /// Get list of all aliases for a collection
         async fn list_collection_aliases(
             &self,
             request: tonic::Request<super::ListCollectionAliasesRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::ListAliasesResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::ListAliasesResponse>, tonic::Status>;
         ///
         /// Get list of all aliases for all existing collections
         async fn list_aliases(
             &self,
             request: tonic::Request<super::ListAliasesRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::ListAliasesResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::ListAliasesResponse>, tonic::Status>;
         ///
         /// Get cluster information for a collection
         async fn collection_cluster_info(
             &self,
             request: tonic::Request<super::CollectionClusterInfoRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::CollectionClusterInfoResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::CollectionClusterInfoResponse>, tonic::Status>;
         ///
         /// Update cluster setup for a collection
         async fn update_collection_cluster_setup(
@@ -1821,19 +2074,13 @@ pub mod collections_server {
         async fn create_shard_key(
             &self,
             request: tonic::Request<super::CreateShardKeyRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::CreateShardKeyResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::CreateShardKeyResponse>, tonic::Status>;
         ///
         /// Delete shard key
         async fn delete_shard_key(
             &self,
             request: tonic::Request<super::DeleteShardKeyRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::DeleteShardKeyResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::DeleteShardKeyResponse>, tonic::Status>;
     }
     #[derive(Debug)]
     pub struct CollectionsServer<T: Collections> {
@@ -1858,10 +2105,7 @@ pub mod collections_server {
                 max_encoding_message_size: None,
             }
         }
-        pub fn with_interceptor<F>(
-            inner: T,
-            interceptor: F,
-        ) -> InterceptedService<Self, F>
+        pub fn with_interceptor<F>(inner: T, interceptor: F) -> InterceptedService<Self, F>
         where
             F: tonic::service::Interceptor,
         {
@@ -1917,23 +2161,17 @@ pub mod collections_server {
                 "/qdrant.Collections/Get" => {
                     #[allow(non_camel_case_types)]
                     struct GetSvc<T: Collections>(pub Arc<T>);
-                    impl<
-                        T: Collections,
-                    > tonic::server::UnaryService<super::GetCollectionInfoRequest>
-                    for GetSvc<T> {
+                    impl<T: Collections>
+                        tonic::server::UnaryService<super::GetCollectionInfoRequest> for GetSvc<T>
+                    {
                         type Response = super::GetCollectionInfoResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::GetCollectionInfoRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as Collections>::get(&inner, request).await
-                            };
+                            let fut = async move { <T as Collections>::get(&inner, request).await };
                             Box::pin(fut)
                         }
                     }
@@ -1963,23 +2201,16 @@ pub mod collections_server {
                 "/qdrant.Collections/List" => {
                     #[allow(non_camel_case_types)]
                     struct ListSvc<T: Collections>(pub Arc<T>);
-                    impl<
-                        T: Collections,
-                    > tonic::server::UnaryService<super::ListCollectionsRequest>
-                    for ListSvc<T> {
+                    impl<T: Collections> tonic::server::UnaryService<super::ListCollectionsRequest> for ListSvc<T> {
                         type Response = super::ListCollectionsResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::ListCollectionsRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as Collections>::list(&inner, request).await
-                            };
+                            let fut =
+                                async move { <T as Collections>::list(&inner, request).await };
                             Box::pin(fut)
                         }
                     }
@@ -2009,23 +2240,16 @@ pub mod collections_server {
                 "/qdrant.Collections/Create" => {
                     #[allow(non_camel_case_types)]
                     struct CreateSvc<T: Collections>(pub Arc<T>);
-                    impl<
-                        T: Collections,
-                    > tonic::server::UnaryService<super::CreateCollection>
-                    for CreateSvc<T> {
+                    impl<T: Collections> tonic::server::UnaryService<super::CreateCollection> for CreateSvc<T> {
                         type Response = super::CollectionOperationResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::CreateCollection>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as Collections>::create(&inner, request).await
-                            };
+                            let fut =
+                                async move { <T as Collections>::create(&inner, request).await };
                             Box::pin(fut)
                         }
                     }
@@ -2055,23 +2279,16 @@ pub mod collections_server {
                 "/qdrant.Collections/Update" => {
                     #[allow(non_camel_case_types)]
                     struct UpdateSvc<T: Collections>(pub Arc<T>);
-                    impl<
-                        T: Collections,
-                    > tonic::server::UnaryService<super::UpdateCollection>
-                    for UpdateSvc<T> {
+                    impl<T: Collections> tonic::server::UnaryService<super::UpdateCollection> for UpdateSvc<T> {
                         type Response = super::CollectionOperationResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::UpdateCollection>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as Collections>::update(&inner, request).await
-                            };
+                            let fut =
+                                async move { <T as Collections>::update(&inner, request).await };
                             Box::pin(fut)
                         }
                     }
@@ -2101,22 +2318,57 @@ pub mod collections_server {
                 "/qdrant.Collections/Delete" => {
                     #[allow(non_camel_case_types)]
                     struct DeleteSvc<T: Collections>(pub Arc<T>);
-                    impl<
-                        T: Collections,
-                    > tonic::server::UnaryService<super::DeleteCollection>
-                    for DeleteSvc<T> {
+                    impl<T: Collections> tonic::server::UnaryService<super::DeleteCollection> for DeleteSvc<T> {
                         type Response = super::CollectionOperationResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::DeleteCollection>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut =
+                                async move { <T as Collections>::delete(&inner, request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = DeleteSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/qdrant.Collections/RenameCollection" => {
+                    #[allow(non_camel_case_types)]
+                    struct RenameCollectionSvc<T: Collections>(pub Arc<T>);
+                    impl<T: Collections> tonic::server::UnaryService<super::RenameCollection>
+                        for RenameCollectionSvc<T>
+                    {
+                        type Response = super::CollectionOperationResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RenameCollection>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as Collections>::delete(&inner, request).await
+                                <T as Collections>::rename_collection(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -2128,7 +2380,47 @@ pub mod collections_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = DeleteSvc(inner);
+                        let method = RenameCollectionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/qdrant.Collections/CloneCollection" => {
+                    #[allow(non_camel_case_types)]
+                    struct CloneCollectionSvc<T: Collections>(pub Arc<T>);
+                    impl<T: Collections> tonic::server::UnaryService<super::CloneCollection> for CloneCollectionSvc<T> {
+                        type Response = super::CollectionOperationResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CloneCollection>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Collections>::clone_collection(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = CloneCollectionSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2147,15 +2439,9 @@ pub mod collections_server {
                 "/qdrant.Collections/UpdateAliases" => {
                     #[allow(non_camel_case_types)]
                     struct UpdateAliasesSvc<T: Collections>(pub Arc<T>);
-                    impl<
-                        T: Collections,
-                    > tonic::server::UnaryService<super::ChangeAliases>
-                    for UpdateAliasesSvc<T> {
+                    impl<T: Collections> tonic::server::UnaryService<super::ChangeAliases> for UpdateAliasesSvc<T> {
                         type Response = super::CollectionOperationResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::ChangeAliases>,
@@ -2193,23 +2479,19 @@ pub mod collections_server {
                 "/qdrant.Collections/ListCollectionAliases" => {
                     #[allow(non_camel_case_types)]
                     struct ListCollectionAliasesSvc<T: Collections>(pub Arc<T>);
-                    impl<
-                        T: Collections,
-                    > tonic::server::UnaryService<super::ListCollectionAliasesRequest>
-                    for ListCollectionAliasesSvc<T> {
+                    impl<T: Collections>
+                        tonic::server::UnaryService<super::ListCollectionAliasesRequest>
+                        for ListCollectionAliasesSvc<T>
+                    {
                         type Response = super::ListAliasesResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::ListCollectionAliasesRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as Collections>::list_collection_aliases(&inner, request)
-                                    .await
+                                <T as Collections>::list_collection_aliases(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -2240,15 +2522,9 @@ pub mod collections_server {
                 "/qdrant.Collections/ListAliases" => {
                     #[allow(non_camel_case_types)]
                     struct ListAliasesSvc<T: Collections>(pub Arc<T>);
-                    impl<
-                        T: Collections,
-                    > tonic::server::UnaryService<super::ListAliasesRequest>
-                    for ListAliasesSvc<T> {
+                    impl<T: Collections> tonic::server::UnaryService<super::ListAliasesRequest> for ListAliasesSvc<T> {
                         type Response = super::ListAliasesResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::ListAliasesRequest>,
@@ -2286,23 +2562,19 @@ pub mod collections_server {
                 "/qdrant.Collections/CollectionClusterInfo" => {
                     #[allow(non_camel_case_types)]
                     struct CollectionClusterInfoSvc<T: Collections>(pub Arc<T>);
-                    impl<
-                        T: Collections,
-                    > tonic::server::UnaryService<super::CollectionClusterInfoRequest>
-                    for CollectionClusterInfoSvc<T> {
+                    impl<T: Collections>
+                        tonic::server::UnaryService<super::CollectionClusterInfoRequest>
+                        for CollectionClusterInfoSvc<T>
+                    {
                         type Response = super::CollectionClusterInfoResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::CollectionClusterInfoRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as Collections>::collection_cluster_info(&inner, request)
-                                    .await
+                                <T as Collections>::collection_cluster_info(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -2333,28 +2605,19 @@ pub mod collections_server {
                 "/qdrant.Collections/UpdateCollectionClusterSetup" => {
                     #[allow(non_camel_case_types)]
                     struct UpdateCollectionClusterSetupSvc<T: Collections>(pub Arc<T>);
-                    impl<
-                        T: Collections,
-                    > tonic::server::UnaryService<
-                        super::UpdateCollectionClusterSetupRequest,
-                    > for UpdateCollectionClusterSetupSvc<T> {
+                    impl<T: Collections>
+                        tonic::server::UnaryService<super::UpdateCollectionClusterSetupRequest>
+                        for UpdateCollectionClusterSetupSvc<T>
+                    {
                         type Response = super::UpdateCollectionClusterSetupResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<
-                                super::UpdateCollectionClusterSetupRequest,
-                            >,
+                            request: tonic::Request<super::UpdateCollectionClusterSetupRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as Collections>::update_collection_cluster_setup(
-                                        &inner,
-                                        request,
-                                    )
+                                <T as Collections>::update_collection_cluster_setup(&inner, request)
                                     .await
                             };
                             Box::pin(fut)
@@ -2386,15 +2649,11 @@ pub mod collections_server {
                 "/qdrant.Collections/CreateShardKey" => {
                     #[allow(non_camel_case_types)]
                     struct CreateShardKeySvc<T: Collections>(pub Arc<T>);
-                    impl<
-                        T: Collections,
-                    > tonic::server::UnaryService<super::CreateShardKeyRequest>
-                    for CreateShardKeySvc<T> {
+                    impl<T: Collections> tonic::server::UnaryService<super::CreateShardKeyRequest>
+                        for CreateShardKeySvc<T>
+                    {
                         type Response = super::CreateShardKeyResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::CreateShardKeyRequest>,
@@ -2432,15 +2691,11 @@ pub mod collections_server {
                 "/qdrant.Collections/DeleteShardKey" => {
                     #[allow(non_camel_case_types)]
                     struct DeleteShardKeySvc<T: Collections>(pub Arc<T>);
-                    impl<
-                        T: Collections,
-                    > tonic::server::UnaryService<super::DeleteShardKeyRequest>
-                    for DeleteShardKeySvc<T> {
+                    impl<T: Collections> tonic::server::UnaryService<super::DeleteShardKeyRequest>
+                        for DeleteShardKeySvc<T>
+                    {
                         type Response = super::DeleteShardKeyResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::DeleteShardKeyRequest>,
@@ -2475,18 +2730,14 @@ pub mod collections_server {
                     };
                     Box::pin(fut)
                 }
-                _ => {
-                    Box::pin(async move {
-                        Ok(
-                            http::Response::builder()
-                                .status(200)
-                                .header("grpc-status", "12")
-                                .header("content-type", "application/grpc")
-                                .body(empty_body())
-                                .unwrap(),
-                        )
-                    })
-                }
+                _ => Box::pin(async move {
+                    Ok(http::Response::builder()
+                        .status(200)
+                        .header("grpc-status", "12")
+                        .header("content-type", "application/grpc")
+                        .body(empty_body())
+                        .unwrap())
+                }),
             }
         }
     }
@@ -2516,8 +2767,7 @@ pub mod collections_server {
         const NAME: &'static str = "qdrant.Collections";
     }
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetCollectionInfoRequestInternal {
@@ -2526,8 +2776,7 @@ pub struct GetCollectionInfoRequestInternal {
     #[prost(uint32, tag = "2")]
     pub shard_id: u32,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct InitiateShardTransferRequest {
@@ -2539,8 +2788,7 @@ pub struct InitiateShardTransferRequest {
     #[prost(uint32, tag = "2")]
     pub shard_id: u32,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct WaitForShardStateRequest {
@@ -2559,11 +2807,30 @@ pub struct WaitForShardStateRequest {
     #[validate(range(min = 1))]
     pub timeout: u64,
 }
+#[derive(validator::Validate, serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WaitForOperationRequest {
+    /// Name of the collection
+    #[prost(string, tag = "1")]
+    #[validate(length(min = 1, max = 255))]
+    pub collection_name: ::prost::alloc::string::String,
+    /// Id of the shard
+    #[prost(uint32, tag = "2")]
+    pub shard_id: u32,
+    /// Number of the operation in the WAL of the shard
+    #[prost(uint64, tag = "3")]
+    pub operation_id: u64,
+    /// Timeout in seconds
+    #[prost(uint64, tag = "4")]
+    #[validate(range(min = 1))]
+    pub timeout: u64,
+}
 /// Generated client implementations.
 pub mod collections_internal_client {
     #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
-    use tonic::codegen::*;
     use tonic::codegen::http::Uri;
+    use tonic::codegen::*;
     #[derive(Debug, Clone)]
     pub struct CollectionsInternalClient<T> {
         inner: tonic::client::Grpc<T>,
@@ -2607,9 +2874,8 @@ pub mod collections_internal_client {
                     <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
                 >,
             >,
-            <T as tonic::codegen::Service<
-                http::Request<tonic::body::BoxBody>,
-            >>::Error: Into<StdError> + Send + Sync,
+            <T as tonic::codegen::Service<http::Request<tonic::body::BoxBody>>>::Error:
+                Into<StdError> + Send + Sync,
         {
             CollectionsInternalClient::new(InterceptedService::new(inner, interceptor))
         }
@@ -2649,23 +2915,16 @@ pub mod collections_internal_client {
         pub async fn get(
             &mut self,
             request: impl tonic::IntoRequest<super::GetCollectionInfoRequestInternal>,
-        ) -> std::result::Result<
-            tonic::Response<super::GetCollectionInfoResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::GetCollectionInfoResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.CollectionsInternal/Get",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.CollectionsInternal/Get");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("qdrant.CollectionsInternal", "Get"));
@@ -2676,23 +2935,16 @@ pub mod collections_internal_client {
         pub async fn initiate(
             &mut self,
             request: impl tonic::IntoRequest<super::InitiateShardTransferRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::CollectionOperationResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::CollectionOperationResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.CollectionsInternal/Initiate",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.CollectionsInternal/Initiate");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("qdrant.CollectionsInternal", "Initiate"));
@@ -2703,28 +2955,47 @@ pub mod collections_internal_client {
         pub async fn wait_for_shard_state(
             &mut self,
             request: impl tonic::IntoRequest<super::WaitForShardStateRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::CollectionOperationResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::CollectionOperationResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
                 "/qdrant.CollectionsInternal/WaitForShardState",
             );
             let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(
-                    GrpcMethod::new("qdrant.CollectionsInternal", "WaitForShardState"),
-                );
+            req.extensions_mut().insert(GrpcMethod::new(
+                "qdrant.CollectionsInternal",
+                "WaitForShardState",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// *
+        /// Wait for a shard to apply the given operation
+        pub async fn wait_for_operation(
+            &mut self,
+            request: impl tonic::IntoRequest<super::WaitForOperationRequest>,
+        ) -> std::result::Result<tonic::Response<super::CollectionOperationResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/qdrant.CollectionsInternal/WaitForOperation",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "qdrant.CollectionsInternal",
+                "WaitForOperation",
+            ));
             self.inner.unary(req, path, codec).await
         }
     }
@@ -2741,28 +3012,25 @@ pub mod collections_internal_server {
         async fn get(
             &self,
             request: tonic::Request<super::GetCollectionInfoRequestInternal>,
-        ) -> std::result::Result<
-            tonic::Response<super::GetCollectionInfoResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::GetCollectionInfoResponse>, tonic::Status>;
         ///
         /// Initiate shard transfer
         async fn initiate(
             &self,
             request: tonic::Request<super::InitiateShardTransferRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::CollectionOperationResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::CollectionOperationResponse>, tonic::Status>;
         /// *
         /// Wait for a shard to get into the given state
         async fn wait_for_shard_state(
             &self,
             request: tonic::Request<super::WaitForShardStateRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::CollectionOperationResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::CollectionOperationResponse>, tonic::Status>;
+        /// *
+        /// Wait for a shard to apply the given operation
+        async fn wait_for_operation(
+            &self,
+            request: tonic::Request<super::WaitForOperationRequest>,
+        ) -> std::result::Result<tonic::Response<super::CollectionOperationResponse>, tonic::Status>;
     }
     #[derive(Debug)]
     pub struct CollectionsInternalServer<T: CollectionsInternal> {
@@ -2787,10 +3055,7 @@ pub mod collections_internal_server {
                 max_encoding_message_size: None,
             }
         }
-        pub fn with_interceptor<F>(
-            inner: T,
-            interceptor: F,
-        ) -> InterceptedService<Self, F>
+        pub fn with_interceptor<F>(inner: T, interceptor: F) -> InterceptedService<Self, F>
         where
             F: tonic::service::Interceptor,
         {
@@ -2846,21 +3111,15 @@ pub mod collections_internal_server {
                 "/qdrant.CollectionsInternal/Get" => {
                     #[allow(non_camel_case_types)]
                     struct GetSvc<T: CollectionsInternal>(pub Arc<T>);
-                    impl<
-                        T: CollectionsInternal,
-                    > tonic::server::UnaryService<
-                        super::GetCollectionInfoRequestInternal,
-                    > for GetSvc<T> {
+                    impl<T: CollectionsInternal>
+                        tonic::server::UnaryService<super::GetCollectionInfoRequestInternal>
+                        for GetSvc<T>
+                    {
                         type Response = super::GetCollectionInfoResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<
-                                super::GetCollectionInfoRequestInternal,
-                            >,
+                            request: tonic::Request<super::GetCollectionInfoRequestInternal>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
@@ -2895,15 +3154,12 @@ pub mod collections_internal_server {
                 "/qdrant.CollectionsInternal/Initiate" => {
                     #[allow(non_camel_case_types)]
                     struct InitiateSvc<T: CollectionsInternal>(pub Arc<T>);
-                    impl<
-                        T: CollectionsInternal,
-                    > tonic::server::UnaryService<super::InitiateShardTransferRequest>
-                    for InitiateSvc<T> {
+                    impl<T: CollectionsInternal>
+                        tonic::server::UnaryService<super::InitiateShardTransferRequest>
+                        for InitiateSvc<T>
+                    {
                         type Response = super::CollectionOperationResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::InitiateShardTransferRequest>,
@@ -2941,25 +3197,19 @@ pub mod collections_internal_server {
                 "/qdrant.CollectionsInternal/WaitForShardState" => {
                     #[allow(non_camel_case_types)]
                     struct WaitForShardStateSvc<T: CollectionsInternal>(pub Arc<T>);
-                    impl<
-                        T: CollectionsInternal,
-                    > tonic::server::UnaryService<super::WaitForShardStateRequest>
-                    for WaitForShardStateSvc<T> {
+                    impl<T: CollectionsInternal>
+                        tonic::server::UnaryService<super::WaitForShardStateRequest>
+                        for WaitForShardStateSvc<T>
+                    {
                         type Response = super::CollectionOperationResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::WaitForShardStateRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as CollectionsInternal>::wait_for_shard_state(
-                                        &inner,
-                                        request,
-                                    )
+                                <T as CollectionsInternal>::wait_for_shard_state(&inner, request)
                                     .await
                             };
                             Box::pin(fut)
@@ -2988,18 +3238,58 @@ pub mod collections_internal_server {
                     };
                     Box::pin(fut)
                 }
-                _ => {
-                    Box::pin(async move {
-                        Ok(
-                            http::Response::builder()
-                                .status(200)
-                                .header("grpc-status", "12")
-                                .header("content-type", "application/grpc")
-                                .body(empty_body())
-                                .unwrap(),
-                        )
-                    })
+                "/qdrant.CollectionsInternal/WaitForOperation" => {
+                    #[allow(non_camel_case_types)]
+                    struct WaitForOperationSvc<T: CollectionsInternal>(pub Arc<T>);
+                    impl<T: CollectionsInternal>
+                        tonic::server::UnaryService<super::WaitForOperationRequest>
+                        for WaitForOperationSvc<T>
+                    {
+                        type Response = super::CollectionOperationResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::WaitForOperationRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CollectionsInternal>::wait_for_operation(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = WaitForOperationSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
                 }
+                _ => Box::pin(async move {
+                    Ok(http::Response::builder()
+                        .status(200)
+                        .header("grpc-status", "12")
+                        .header("content-type", "application/grpc")
+                        .body(empty_body())
+                        .unwrap())
+                }),
             }
         }
     }
@@ -3025,8 +3315,7 @@ pub mod collections_internal_server {
             write!(f, "{:?}", self.0)
         }
     }
-    impl<T: CollectionsInternal> tonic::server::NamedService
-    for CollectionsInternalServer<T> {
+    impl<T: CollectionsInternal> tonic::server::NamedService for CollectionsInternalServer<T> {
         const NAME: &'static str = "qdrant.CollectionsInternal";
     }
 }
@@ -3105,8 +3394,9 @@ pub struct ListValue {
 /// `Value` type union.
 ///
 ///   The JSON representation for `NullValue` is JSON `null`.
-#[derive(serde::Serialize)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(
+    serde::Serialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration,
+)]
 #[repr(i32)]
 pub enum NullValue {
     /// Null value.
@@ -3142,6 +3432,9 @@ pub struct WriteOrdering {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ReadConsistency {
+    /// Wait until the update with this token is applied, and read only from the replicas which applied it
+    #[prost(string, optional, tag = "3")]
+    pub consistency_token: ::core::option::Option<::prost::alloc::string::String>,
     #[prost(oneof = "read_consistency::Value", tags = "1, 2")]
     pub value: ::core::option::Option<read_consistency::Value>,
 }
@@ -3195,6 +3488,9 @@ pub struct Vector {
     pub data: ::prost::alloc::vec::Vec<f32>,
     #[prost(message, optional, tag = "2")]
     pub indices: ::core::option::Option<SparseIndices>,
+    /// Bit-packed binary vector for `Hamming` and `Jaccard` distances, 8 dimensions per byte. Used instead of `data`
+    #[prost(bytes = "vec", tag = "3")]
+    pub packed: ::prost::alloc::vec::Vec<u8>,
 }
 /// ---------------------------------------------
 /// ----------------- ShardKeySelector ----------
@@ -3207,8 +3503,7 @@ pub struct ShardKeySelector {
     #[prost(message, repeated, tag = "1")]
     pub shard_keys: ::prost::alloc::vec::Vec<ShardKey>,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpsertPoints {
@@ -3228,8 +3523,7 @@ pub struct UpsertPoints {
     #[prost(message, optional, tag = "5")]
     pub shard_key_selector: ::core::option::Option<ShardKeySelector>,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DeletePoints {
@@ -3250,8 +3544,7 @@ pub struct DeletePoints {
     #[prost(message, optional, tag = "5")]
     pub shard_key_selector: ::core::option::Option<ShardKeySelector>,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetPoints {
@@ -3275,8 +3568,7 @@ pub struct GetPoints {
     #[prost(message, optional, tag = "7")]
     pub shard_key_selector: ::core::option::Option<ShardKeySelector>,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpdatePointVectors {
@@ -3308,8 +3600,7 @@ pub struct PointVectors {
     #[prost(message, optional, tag = "2")]
     pub vectors: ::core::option::Option<Vectors>,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DeletePointVectors {
@@ -3333,8 +3624,7 @@ pub struct DeletePointVectors {
     #[prost(message, optional, tag = "6")]
     pub shard_key_selector: ::core::option::Option<ShardKeySelector>,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SetPayloadPoints {
@@ -3358,8 +3648,7 @@ pub struct SetPayloadPoints {
     #[prost(message, optional, tag = "7")]
     pub shard_key_selector: ::core::option::Option<ShardKeySelector>,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DeletePayloadPoints {
@@ -3383,8 +3672,7 @@ pub struct DeletePayloadPoints {
     #[prost(message, optional, tag = "7")]
     pub shard_key_selector: ::core::option::Option<ShardKeySelector>,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ClearPayloadPoints {
@@ -3405,8 +3693,7 @@ pub struct ClearPayloadPoints {
     #[prost(message, optional, tag = "5")]
     pub shard_key_selector: ::core::option::Option<ShardKeySelector>,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateFieldIndexCollection {
@@ -3431,8 +3718,7 @@ pub struct CreateFieldIndexCollection {
     #[prost(message, optional, tag = "6")]
     pub ordering: ::core::option::Option<WriteOrdering>,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DeleteFieldIndexCollection {
@@ -3489,8 +3775,7 @@ pub mod with_payload_selector {
         Exclude(super::PayloadExcludeSelector),
     }
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct NamedVectors {
@@ -3498,8 +3783,7 @@ pub struct NamedVectors {
     #[validate]
     pub vectors: ::std::collections::HashMap<::prost::alloc::string::String, Vector>,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Vectors {
@@ -3548,8 +3832,7 @@ pub mod with_vectors_selector {
         Include(super::VectorsSelector),
     }
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QuantizationSearchParams {
@@ -3573,8 +3856,7 @@ pub struct QuantizationSearchParams {
     #[validate(custom = "crate::grpc::validate::validate_f64_range_min_1")]
     pub oversampling: ::core::option::Option<f64>,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SearchParams {
@@ -3599,8 +3881,7 @@ pub struct SearchParams {
     #[prost(bool, optional, tag = "4")]
     pub indexed_only: ::core::option::Option<bool>,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SearchPoints {
@@ -3650,9 +3931,17 @@ pub struct SearchPoints {
     pub shard_key_selector: ::core::option::Option<ShardKeySelector>,
     #[prost(message, optional, tag = "15")]
     pub sparse_indices: ::core::option::Option<SparseIndices>,
+    /// Return the distance from the given origin to the closest geo point of each result
+    #[prost(message, optional, tag = "16")]
+    pub with_geo_distance: ::core::option::Option<GeoDistance>,
+    /// Attach the linked points of another collection to each result
+    #[prost(message, optional, tag = "17")]
+    pub with_lookup: ::core::option::Option<WithLookup>,
+    /// Bit-packed binary vector for `Hamming` and `Jaccard` distances, 8 dimensions per byte. Used instead of `vector`
+    #[prost(bytes = "vec", tag = "18")]
+    pub packed_vector: ::prost::alloc::vec::Vec<u8>,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SearchBatchPoints {
@@ -3684,11 +3973,27 @@ pub struct WithLookup {
     /// Options for specifying which vectors to include (or not)
     #[prost(message, optional, tag = "3")]
     pub with_vectors: ::core::option::Option<WithVectorsSelector>,
+    /// Payload field which links the result points to the points of the lookup collection. Required for search, recommend and scroll
+    #[prost(message, optional, tag = "4")]
+    pub link: ::core::option::Option<LookupLink>,
 }
-#[derive(validator::Validate)]
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LookupLink {
+    /// Payload key of the result point, which holds the link value(s)
+    #[prost(string, tag = "1")]
+    pub key: ::prost::alloc::string::String,
+    /// Keyword or integer payload key of the lookup collection to match the link value against. If not specified - the link value is used as the point id
+    #[prost(string, optional, tag = "2")]
+    pub target_key: ::core::option::Option<::prost::alloc::string::String>,
+    /// Max number of records to attach per link value, if matched by `target_key`. Default: 10
+    #[prost(uint64, optional, tag = "3")]
+    pub limit: ::core::option::Option<u64>,
+}
+#[derive(validator::Validate, serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SearchPointGroups {
     /// Name of the collection
     #[prost(string, tag = "1")]
@@ -3744,11 +4049,35 @@ pub struct SearchPointGroups {
     pub shard_key_selector: ::core::option::Option<ShardKeySelector>,
     #[prost(message, optional, tag = "16")]
     pub sparse_indices: ::core::option::Option<SparseIndices>,
+    /// Bit-packed binary vector for `Hamming` and `Jaccard` distances, 8 dimensions per byte. Used instead of `vector`
+    #[prost(bytes = "vec", tag = "17")]
+    pub packed_vector: ::prost::alloc::vec::Vec<u8>,
 }
-#[derive(validator::Validate)]
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GeoDistance {
+    /// Payload key with geo points
+    #[prost(string, tag = "1")]
+    pub key: ::prost::alloc::string::String,
+    /// Point to measure distance from
+    #[prost(message, optional, tag = "2")]
+    pub origin: ::core::option::Option<GeoPoint>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OrderBy {
+    /// Order points by ascending distance to the origin
+    #[prost(message, optional, tag = "1")]
+    pub geo_distance: ::core::option::Option<GeoDistance>,
+    /// Skip points closer than this distance in meters, scroll with offset continues from the distance of the offset point
+    #[prost(double, optional, tag = "2")]
+    pub start_from: ::core::option::Option<f64>,
+}
+#[derive(validator::Validate, serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ScrollPoints {
     #[prost(string, tag = "1")]
     #[validate(length(min = 1, max = 255))]
@@ -3776,6 +4105,12 @@ pub struct ScrollPoints {
     /// Specify in which shards to look for the points, if not specified - look in all shards
     #[prost(message, optional, tag = "9")]
     pub shard_key_selector: ::core::option::Option<ShardKeySelector>,
+    /// Order the points by the given criteria instead of ids, ordered scroll continues from the offset point
+    #[prost(message, optional, tag = "10")]
+    pub order_by: ::core::option::Option<OrderBy>,
+    /// Attach the linked points of another collection to each result
+    #[prost(message, optional, tag = "11")]
+    pub with_lookup: ::core::option::Option<WithLookup>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -3790,8 +4125,7 @@ pub struct LookupLocation {
     #[prost(message, optional, tag = "3")]
     pub shard_key_selector: ::core::option::Option<ShardKeySelector>,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RecommendPoints {
@@ -3855,9 +4189,17 @@ pub struct RecommendPoints {
     /// Specify in which shards to look for the points, if not specified - look in all shards
     #[prost(message, optional, tag = "20")]
     pub shard_key_selector: ::core::option::Option<ShardKeySelector>,
+    /// Attach the linked points of another collection to each result
+    #[prost(message, optional, tag = "21")]
+    pub with_lookup: ::core::option::Option<WithLookup>,
+    /// Examples with explicit weights, only used with the `Feedback` strategy
+    #[prost(message, repeated, tag = "22")]
+    pub feedback: ::prost::alloc::vec::Vec<FeedbackExample>,
+    /// Pairs of positive and negative examples, only used with the `Feedback` strategy
+    #[prost(message, repeated, tag = "23")]
+    pub context: ::prost::alloc::vec::Vec<ContextExamplePair>,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RecommendBatchPoints {
@@ -3876,8 +4218,7 @@ pub struct RecommendBatchPoints {
     #[validate(custom = "crate::grpc::validate::validate_u64_range_min_1")]
     pub timeout: ::core::option::Option<u64>,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RecommendPointGroups {
@@ -3950,6 +4291,12 @@ pub struct RecommendPointGroups {
     /// Specify in which shards to look for the points, if not specified - look in all shards
     #[prost(message, optional, tag = "21")]
     pub shard_key_selector: ::core::option::Option<ShardKeySelector>,
+    /// Examples with explicit weights, only used with the `Feedback` strategy
+    #[prost(message, repeated, tag = "22")]
+    pub feedback: ::prost::alloc::vec::Vec<FeedbackExample>,
+    /// Pairs of positive and negative examples, only used with the `Feedback` strategy
+    #[prost(message, repeated, tag = "23")]
+    pub context: ::prost::alloc::vec::Vec<ContextExamplePair>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -3996,10 +4343,19 @@ pub struct ContextExamplePair {
     #[prost(message, optional, tag = "2")]
     pub negative: ::core::option::Option<VectorExample>,
 }
-#[derive(validator::Validate)]
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeedbackExample {
+    #[prost(message, optional, tag = "1")]
+    pub example: ::core::option::Option<VectorExample>,
+    /// Positive weight pulls the results towards the example, negative pushes them away from it
+    #[prost(float, tag = "2")]
+    pub weight: f32,
+}
+#[derive(validator::Validate, serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DiscoverPoints {
     /// name of the collection
     #[prost(string, tag = "1")]
@@ -4049,8 +4405,7 @@ pub struct DiscoverPoints {
     #[prost(message, optional, tag = "14")]
     pub shard_key_selector: ::core::option::Option<ShardKeySelector>,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DiscoverBatchPoints {
@@ -4069,8 +4424,7 @@ pub struct DiscoverBatchPoints {
     #[validate(custom = "crate::grpc::validate::validate_u64_range_min_1")]
     pub timeout: ::core::option::Option<u64>,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CountPoints {
@@ -4091,6 +4445,90 @@ pub struct CountPoints {
     /// Specify in which shards to look for the points, if not specified - look in all shards
     #[prost(message, optional, tag = "5")]
     pub shard_key_selector: ::core::option::Option<ShardKeySelector>,
+    /// If set - count distinct keyword and integer values of this payload field instead of points. Exact count fails beyond 4096 distinct values
+    #[prost(string, optional, tag = "6")]
+    pub distinct: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct HistogramParams {
+    /// Width of each bucket, must be positive
+    #[prost(double, tag = "1")]
+    pub interval: f64,
+    /// Shift of the bucket boundaries. Default: 0
+    #[prost(double, optional, tag = "2")]
+    pub offset: ::core::option::Option<f64>,
+    /// Max number of buckets between the smallest and the largest value, including empty ones. Default: 1000
+    #[prost(uint64, optional, tag = "3")]
+    pub max_buckets: ::core::option::Option<u64>,
+}
+#[derive(validator::Validate, serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AggregatePoints {
+    /// name of the collection
+    #[prost(string, tag = "1")]
+    #[validate(length(min = 1, max = 255))]
+    pub collection_name: ::prost::alloc::string::String,
+    /// Filter conditions - aggregate only those points that satisfy the specified conditions
+    #[prost(message, optional, tag = "2")]
+    #[validate]
+    pub filter: ::core::option::Option<Filter>,
+    /// Payload key with the numeric values to aggregate
+    #[prost(string, tag = "3")]
+    pub key: ::prost::alloc::string::String,
+    /// If specified, also count the values in fixed-width histogram buckets
+    #[prost(message, optional, tag = "4")]
+    pub histogram: ::core::option::Option<HistogramParams>,
+    /// Options for specifying read consistency guarantees
+    #[prost(message, optional, tag = "5")]
+    pub read_consistency: ::core::option::Option<ReadConsistency>,
+    /// Specify in which shards to look for the points, if not specified - look in all shards
+    #[prost(message, optional, tag = "6")]
+    pub shard_key_selector: ::core::option::Option<ShardKeySelector>,
+}
+#[derive(validator::Validate, serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SearchTextPoints {
+    /// name of the collection
+    #[prost(string, tag = "1")]
+    #[validate(length(min = 1, max = 255))]
+    pub collection_name: ::prost::alloc::string::String,
+    /// Payload key with the full-text index to search in
+    #[prost(string, tag = "2")]
+    pub key: ::prost::alloc::string::String,
+    /// Text to search for, tokenized the same way as the indexed text
+    #[prost(string, tag = "3")]
+    #[validate(length(min = 1))]
+    pub query: ::prost::alloc::string::String,
+    /// Filter conditions - return only those points that satisfy the specified conditions
+    #[prost(message, optional, tag = "4")]
+    #[validate]
+    pub filter: ::core::option::Option<Filter>,
+    /// Max number of result
+    #[prost(uint64, tag = "5")]
+    #[validate(range(min = 1))]
+    pub limit: u64,
+    /// Offset of the result
+    #[prost(uint64, optional, tag = "6")]
+    pub offset: ::core::option::Option<u64>,
+    /// Options for specifying which payload to include or not
+    #[prost(message, optional, tag = "7")]
+    pub with_payload: ::core::option::Option<WithPayloadSelector>,
+    /// Options for specifying which vectors to include into response
+    #[prost(message, optional, tag = "8")]
+    pub with_vectors: ::core::option::Option<WithVectorsSelector>,
+    /// If provided - cut off results with worse scores
+    #[prost(float, optional, tag = "9")]
+    pub score_threshold: ::core::option::Option<f32>,
+    /// Options for specifying read consistency guarantees
+    #[prost(message, optional, tag = "10")]
+    pub read_consistency: ::core::option::Option<ReadConsistency>,
+    /// Specify in which shards to look for the points, if not specified - look in all shards
+    #[prost(message, optional, tag = "11")]
+    pub shard_key_selector: ::core::option::Option<ShardKeySelector>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -4119,10 +4557,7 @@ pub mod points_update_operation {
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct SetPayload {
         #[prost(map = "string, message", tag = "1")]
-        pub payload: ::std::collections::HashMap<
-            ::prost::alloc::string::String,
-            super::Value,
-        >,
+        pub payload: ::std::collections::HashMap<::prost::alloc::string::String, super::Value>,
         /// Affected points
         #[prost(message, optional, tag = "2")]
         pub points_selector: ::core::option::Option<super::PointsSelector>,
@@ -4168,8 +4603,7 @@ pub mod points_update_operation {
         #[prost(message, optional, tag = "3")]
         pub shard_key_selector: ::core::option::Option<super::ShardKeySelector>,
     }
-    #[derive(validator::Validate)]
-    #[derive(serde::Serialize)]
+    #[derive(validator::Validate, serde::Serialize)]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct DeletePoints {
@@ -4217,8 +4651,7 @@ pub mod points_update_operation {
         ClearPayload(ClearPayload),
     }
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpdateBatchPoints {
@@ -4256,6 +4689,9 @@ pub struct UpdateResult {
     /// Operation status
     #[prost(enumeration = "UpdateStatus", tag = "2")]
     pub status: i32,
+    /// Token to read the results of this update from any replica
+    #[prost(string, optional, tag = "3")]
+    pub consistency_token: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -4279,6 +4715,12 @@ pub struct ScoredPoint {
     /// Shard key
     #[prost(message, optional, tag = "7")]
     pub shard_key: ::core::option::Option<ShardKey>,
+    /// Distance in meters to the closest geo point of the requested origin
+    #[prost(double, optional, tag = "8")]
+    pub geo_distance: ::core::option::Option<f64>,
+    /// Points of another collection linked to this point, if requested with `with_lookup`
+    #[prost(message, repeated, tag = "9")]
+    pub lookup: ::prost::alloc::vec::Vec<RetrievedPoint>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -4396,6 +4838,56 @@ pub struct CountResult {
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AggregateResponse {
+    #[prost(message, optional, tag = "1")]
+    pub result: ::core::option::Option<AggregateResult>,
+    /// Time spent to process
+    #[prost(double, tag = "2")]
+    pub time: f64,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct HistogramBucket {
+    /// Lower bound of the bucket, inclusive
+    #[prost(double, tag = "1")]
+    pub from: f64,
+    /// Upper bound of the bucket, exclusive
+    #[prost(double, tag = "2")]
+    pub to: f64,
+    /// Number of values in the bucket
+    #[prost(uint64, tag = "3")]
+    pub count: u64,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AggregateResult {
+    /// Number of aggregated values
+    #[prost(uint64, tag = "1")]
+    pub count: u64,
+    /// Smallest value, absent if there are no values
+    #[prost(double, optional, tag = "2")]
+    pub min: ::core::option::Option<f64>,
+    /// Largest value, absent if there are no values
+    #[prost(double, optional, tag = "3")]
+    pub max: ::core::option::Option<f64>,
+    /// Sum of the values
+    #[prost(double, tag = "4")]
+    pub sum: f64,
+    /// Exact sum of the values without a fractional part, absent if it doesn't fit into 64 bits
+    #[prost(sint64, optional, tag = "7")]
+    pub integer_sum: ::core::option::Option<i64>,
+    /// Mean of the values, absent if there are no values
+    #[prost(double, optional, tag = "5")]
+    pub avg: ::core::option::Option<f64>,
+    /// Non-empty buckets ordered by their bounds
+    #[prost(message, repeated, tag = "6")]
+    pub histogram: ::prost::alloc::vec::Vec<HistogramBucket>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RetrievedPoint {
     #[prost(message, optional, tag = "1")]
     pub id: ::core::option::Option<PointId>,
@@ -4406,6 +4898,12 @@ pub struct RetrievedPoint {
     /// Shard key
     #[prost(message, optional, tag = "5")]
     pub shard_key: ::core::option::Option<ShardKey>,
+    /// Distance in meters to the closest geo point of the `order_by` origin
+    #[prost(double, optional, tag = "6")]
+    pub geo_distance: ::core::option::Option<f64>,
+    /// Points of another collection linked to this point, if requested with `with_lookup`
+    #[prost(message, repeated, tag = "7")]
+    pub lookup: ::prost::alloc::vec::Vec<RetrievedPoint>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -4477,10 +4975,80 @@ pub struct UpdateBatchResponse {
     #[prost(double, tag = "2")]
     pub time: f64,
 }
-#[derive(validator::Validate)]
+#[derive(validator::Validate, serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StreamShardChanges {
+    /// Name of the collection
+    #[prost(string, tag = "1")]
+    #[validate(length(min = 1, max = 255))]
+    pub collection_name: ::prost::alloc::string::String,
+    /// Id of the shard
+    #[prost(uint32, tag = "2")]
+    pub shard_id: u32,
+    /// Number of the operation to start streaming from, the oldest retained operation by default
+    #[prost(uint64, optional, tag = "3")]
+    pub from: ::core::option::Option<u64>,
+}
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ShardChange {
+    /// Sequential number of the operation in the WAL of the replica
+    #[prost(uint64, tag = "1")]
+    pub operation_id: u64,
+    #[prost(
+        oneof = "shard_change::Operation",
+        tags = "2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12"
+    )]
+    pub operation: ::core::option::Option<shard_change::Operation>,
+}
+/// Nested message and enum types in `ShardChange`.
+pub mod shard_change {
+    #[derive(serde::Serialize)]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct SyncPoints {
+        #[prost(message, repeated, tag = "1")]
+        pub points: ::prost::alloc::vec::Vec<super::PointStruct>,
+        /// Start of the sync range
+        #[prost(message, optional, tag = "2")]
+        pub from_id: ::core::option::Option<super::PointId>,
+        /// End of the sync range
+        #[prost(message, optional, tag = "3")]
+        pub to_id: ::core::option::Option<super::PointId>,
+    }
+    #[derive(serde::Serialize)]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Operation {
+        #[prost(message, tag = "2")]
+        Upsert(super::UpsertPoints),
+        #[prost(message, tag = "3")]
+        DeletePoints(super::DeletePoints),
+        #[prost(message, tag = "4")]
+        Sync(SyncPoints),
+        #[prost(message, tag = "5")]
+        UpdateVectors(super::UpdatePointVectors),
+        #[prost(message, tag = "6")]
+        DeleteVectors(super::DeletePointVectors),
+        #[prost(message, tag = "7")]
+        SetPayload(super::SetPayloadPoints),
+        #[prost(message, tag = "8")]
+        OverwritePayload(super::SetPayloadPoints),
+        #[prost(message, tag = "9")]
+        DeletePayload(super::DeletePayloadPoints),
+        #[prost(message, tag = "10")]
+        ClearPayload(super::ClearPayloadPoints),
+        #[prost(message, tag = "11")]
+        CreateFieldIndex(super::CreateFieldIndexCollection),
+        #[prost(message, tag = "12")]
+        DeleteFieldIndex(super::DeleteFieldIndexCollection),
+    }
+}
+#[derive(validator::Validate, serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Filter {
     /// At least one of those conditions should match
     #[prost(message, repeated, tag = "1")]
@@ -4495,8 +5063,7 @@ pub struct Filter {
     #[validate]
     pub must_not: ::prost::alloc::vec::Vec<Condition>,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Condition {
@@ -4545,8 +5112,7 @@ pub struct HasIdCondition {
     #[prost(message, repeated, tag = "1")]
     pub has_id: ::prost::alloc::vec::Vec<PointId>,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct NestedCondition {
@@ -4582,12 +5148,18 @@ pub struct FieldCondition {
     /// Check if geo point is within a given polygon
     #[prost(message, optional, tag = "7")]
     pub geo_polygon: ::core::option::Option<GeoPolygon>,
+    /// Check spatial relation of geo points and geo shapes with a given shape
+    #[prost(message, optional, tag = "8")]
+    pub geo_shape: ::core::option::Option<GeoShapeCondition>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Match {
-    #[prost(oneof = "r#match::MatchValue", tags = "1, 2, 3, 4, 5, 6, 7, 8")]
+    #[prost(
+        oneof = "r#match::MatchValue",
+        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12"
+    )]
     pub match_value: ::core::option::Option<r#match::MatchValue>,
 }
 /// Nested message and enum types in `Match`.
@@ -4620,11 +5192,34 @@ pub mod r#match {
         /// Match any other value except those keywords
         #[prost(message, tag = "8")]
         ExceptKeywords(super::RepeatedStrings),
+        /// Match keywords starting with the prefix
+        #[prost(string, tag = "9")]
+        Prefix(::prost::alloc::string::String),
+        /// Match keywords against the wildcard pattern, `*` matches any sequence of characters, `?` matches any single character
+        #[prost(string, tag = "10")]
+        Wildcard(::prost::alloc::string::String),
+        /// Match keywords against the regular expression, the whole keyword must match
+        #[prost(string, tag = "11")]
+        Regex(::prost::alloc::string::String),
+        /// Match text tolerating typos in the query tokens
+        #[prost(message, tag = "12")]
+        FuzzyText(super::FuzzyText),
     }
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FuzzyText {
+    /// Full-text query
+    #[prost(string, tag = "1")]
+    pub text: ::prost::alloc::string::String,
+    /// Maximal Levenshtein edit distance of each query token, 1 or 2
+    #[prost(uint32, tag = "2")]
+    pub fuzzy: u32,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RepeatedStrings {
     #[prost(string, repeated, tag = "1")]
     pub strings: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
@@ -4681,8 +5276,7 @@ pub struct GeoLineString {
 }
 /// For a valid GeoPolygon, both the exterior and interior GeoLineStrings must consist of a minimum of 4 points.
 /// Additionally, the first and last points of each GeoLineString must be the same.
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GeoPolygon {
@@ -4698,6 +5292,30 @@ pub struct GeoPolygon {
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GeoShapeCondition {
+    /// Spatial relation between the stored value and the query shape
+    #[prost(enumeration = "GeoShapeRelation", tag = "1")]
+    pub relation: i32,
+    #[prost(oneof = "geo_shape_condition::Shape", tags = "2, 3, 4")]
+    pub shape: ::core::option::Option<geo_shape_condition::Shape>,
+}
+/// Nested message and enum types in `GeoShapeCondition`.
+pub mod geo_shape_condition {
+    #[derive(serde::Serialize)]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Shape {
+        #[prost(message, tag = "2")]
+        Point(super::GeoPoint),
+        #[prost(message, tag = "3")]
+        BoundingBox(super::GeoBoundingBox),
+        #[prost(message, tag = "4")]
+        Polygon(super::GeoPolygon),
+    }
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ValuesCount {
     #[prost(uint64, optional, tag = "1")]
     pub lt: ::core::option::Option<u64>,
@@ -4713,9 +5331,7 @@ pub struct ValuesCount {
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PointsSelector {
     #[prost(oneof = "points_selector::PointsSelectorOneOf", tags = "1, 2")]
-    pub points_selector_one_of: ::core::option::Option<
-        points_selector::PointsSelectorOneOf,
-    >,
+    pub points_selector_one_of: ::core::option::Option<points_selector::PointsSelectorOneOf>,
 }
 /// Nested message and enum types in `PointsSelector`.
 pub mod points_selector {
@@ -4756,8 +5372,9 @@ pub struct GeoPoint {
     #[prost(double, tag = "2")]
     pub lat: f64,
 }
-#[derive(serde::Serialize)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(
+    serde::Serialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration,
+)]
 #[repr(i32)]
 pub enum WriteOrderingType {
     /// Write operations may be reordered, works faster, default
@@ -4789,8 +5406,9 @@ impl WriteOrderingType {
         }
     }
 }
-#[derive(serde::Serialize)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(
+    serde::Serialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration,
+)]
 #[repr(i32)]
 pub enum ReadConsistencyType {
     /// Send request to all nodes and return points which are present on all of them
@@ -4822,8 +5440,9 @@ impl ReadConsistencyType {
         }
     }
 }
-#[derive(serde::Serialize)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(
+    serde::Serialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration,
+)]
 #[repr(i32)]
 pub enum FieldType {
     Keyword = 0,
@@ -4862,8 +5481,9 @@ impl FieldType {
     }
 }
 /// How to use positive and negative vectors to find the results, default is `AverageVector`:
-#[derive(serde::Serialize)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(
+    serde::Serialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration,
+)]
 #[repr(i32)]
 pub enum RecommendStrategy {
     /// Average positive and negative vectors and create a single query with the formula
@@ -4873,6 +5493,12 @@ pub enum RecommendStrategy {
     /// examples, its score is then chosen from the `max(max_pos_score, max_neg_score)`.
     /// If the `max_neg_score` is chosen then it is squared and negated.
     BestScore = 1,
+    /// Uses relevance feedback objective. The first positive example is the target,
+    /// each candidate is scored by its sigmoid-scaled similarity to the target and to the other examples,
+    /// multiplied by their weights: `1` for positive, `-1` for negative and the given weight for `feedback` examples.
+    /// Candidates closer to the negative side of any `context` pair are penalized.
+    /// Requires at least one positive example.
+    Feedback = 2,
 }
 impl RecommendStrategy {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -4883,6 +5509,7 @@ impl RecommendStrategy {
         match self {
             RecommendStrategy::AverageVector => "AverageVector",
             RecommendStrategy::BestScore => "BestScore",
+            RecommendStrategy::Feedback => "Feedback",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -4890,12 +5517,14 @@ impl RecommendStrategy {
         match value {
             "AverageVector" => Some(Self::AverageVector),
             "BestScore" => Some(Self::BestScore),
+            "Feedback" => Some(Self::Feedback),
             _ => None,
         }
     }
 }
-#[derive(serde::Serialize)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(
+    serde::Serialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration,
+)]
 #[repr(i32)]
 pub enum UpdateStatus {
     UnknownUpdateStatus = 0,
@@ -4926,11 +5555,45 @@ impl UpdateStatus {
         }
     }
 }
+#[derive(
+    serde::Serialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration,
+)]
+#[repr(i32)]
+pub enum GeoShapeRelation {
+    /// Stored value and the query shape have at least one common point
+    Intersects = 0,
+    /// Stored value lies entirely inside the query shape
+    Within = 1,
+    /// Stored value entirely covers the query shape
+    Contains = 2,
+}
+impl GeoShapeRelation {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            GeoShapeRelation::Intersects => "Intersects",
+            GeoShapeRelation::Within => "Within",
+            GeoShapeRelation::Contains => "Contains",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Intersects" => Some(Self::Intersects),
+            "Within" => Some(Self::Within),
+            "Contains" => Some(Self::Contains),
+            _ => None,
+        }
+    }
+}
 /// Generated client implementations.
 pub mod points_client {
     #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
-    use tonic::codegen::*;
     use tonic::codegen::http::Uri;
+    use tonic::codegen::*;
     #[derive(Debug, Clone)]
     pub struct PointsClient<T> {
         inner: tonic::client::Grpc<T>,
@@ -4974,9 +5637,8 @@ pub mod points_client {
                     <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
                 >,
             >,
-            <T as tonic::codegen::Service<
-                http::Request<tonic::body::BoxBody>,
-            >>::Error: Into<StdError> + Send + Sync,
+            <T as tonic::codegen::Service<http::Request<tonic::body::BoxBody>>>::Error:
+                Into<StdError> + Send + Sync,
         {
             PointsClient::new(InterceptedService::new(inner, interceptor))
         }
@@ -5016,23 +5678,19 @@ pub mod points_client {
         pub async fn upsert(
             &mut self,
             request: impl tonic::IntoRequest<super::UpsertPoints>,
-        ) -> std::result::Result<
-            tonic::Response<super::PointsOperationResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::PointsOperationResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static("/qdrant.Points/Upsert");
             let mut req = request.into_request();
-            req.extensions_mut().insert(GrpcMethod::new("qdrant.Points", "Upsert"));
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Points", "Upsert"));
             self.inner.unary(req, path, codec).await
         }
         ///
@@ -5040,23 +5698,19 @@ pub mod points_client {
         pub async fn delete(
             &mut self,
             request: impl tonic::IntoRequest<super::DeletePoints>,
-        ) -> std::result::Result<
-            tonic::Response<super::PointsOperationResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::PointsOperationResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static("/qdrant.Points/Delete");
             let mut req = request.into_request();
-            req.extensions_mut().insert(GrpcMethod::new("qdrant.Points", "Delete"));
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Points", "Delete"));
             self.inner.unary(req, path, codec).await
         }
         ///
@@ -5065,19 +5719,17 @@ pub mod points_client {
             &mut self,
             request: impl tonic::IntoRequest<super::GetPoints>,
         ) -> std::result::Result<tonic::Response<super::GetResponse>, tonic::Status> {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static("/qdrant.Points/Get");
             let mut req = request.into_request();
-            req.extensions_mut().insert(GrpcMethod::new("qdrant.Points", "Get"));
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Points", "Get"));
             self.inner.unary(req, path, codec).await
         }
         ///
@@ -5085,23 +5737,16 @@ pub mod points_client {
         pub async fn update_vectors(
             &mut self,
             request: impl tonic::IntoRequest<super::UpdatePointVectors>,
-        ) -> std::result::Result<
-            tonic::Response<super::PointsOperationResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::PointsOperationResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.Points/UpdateVectors",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.Points/UpdateVectors");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("qdrant.Points", "UpdateVectors"));
@@ -5112,23 +5757,16 @@ pub mod points_client {
         pub async fn delete_vectors(
             &mut self,
             request: impl tonic::IntoRequest<super::DeletePointVectors>,
-        ) -> std::result::Result<
-            tonic::Response<super::PointsOperationResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::PointsOperationResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.Points/DeleteVectors",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.Points/DeleteVectors");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("qdrant.Points", "DeleteVectors"));
@@ -5139,23 +5777,19 @@ pub mod points_client {
         pub async fn set_payload(
             &mut self,
             request: impl tonic::IntoRequest<super::SetPayloadPoints>,
-        ) -> std::result::Result<
-            tonic::Response<super::PointsOperationResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::PointsOperationResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static("/qdrant.Points/SetPayload");
             let mut req = request.into_request();
-            req.extensions_mut().insert(GrpcMethod::new("qdrant.Points", "SetPayload"));
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Points", "SetPayload"));
             self.inner.unary(req, path, codec).await
         }
         ///
@@ -5163,23 +5797,16 @@ pub mod points_client {
         pub async fn overwrite_payload(
             &mut self,
             request: impl tonic::IntoRequest<super::SetPayloadPoints>,
-        ) -> std::result::Result<
-            tonic::Response<super::PointsOperationResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::PointsOperationResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.Points/OverwritePayload",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.Points/OverwritePayload");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("qdrant.Points", "OverwritePayload"));
@@ -5190,23 +5817,16 @@ pub mod points_client {
         pub async fn delete_payload(
             &mut self,
             request: impl tonic::IntoRequest<super::DeletePayloadPoints>,
-        ) -> std::result::Result<
-            tonic::Response<super::PointsOperationResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::PointsOperationResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.Points/DeletePayload",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.Points/DeletePayload");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("qdrant.Points", "DeletePayload"));
@@ -5217,23 +5837,16 @@ pub mod points_client {
         pub async fn clear_payload(
             &mut self,
             request: impl tonic::IntoRequest<super::ClearPayloadPoints>,
-        ) -> std::result::Result<
-            tonic::Response<super::PointsOperationResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::PointsOperationResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.Points/ClearPayload",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.Points/ClearPayload");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("qdrant.Points", "ClearPayload"));
@@ -5244,23 +5857,16 @@ pub mod points_client {
         pub async fn create_field_index(
             &mut self,
             request: impl tonic::IntoRequest<super::CreateFieldIndexCollection>,
-        ) -> std::result::Result<
-            tonic::Response<super::PointsOperationResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::PointsOperationResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.Points/CreateFieldIndex",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.Points/CreateFieldIndex");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("qdrant.Points", "CreateFieldIndex"));
@@ -5271,23 +5877,16 @@ pub mod points_client {
         pub async fn delete_field_index(
             &mut self,
             request: impl tonic::IntoRequest<super::DeleteFieldIndexCollection>,
-        ) -> std::result::Result<
-            tonic::Response<super::PointsOperationResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::PointsOperationResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.Points/DeleteFieldIndex",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.Points/DeleteFieldIndex");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("qdrant.Points", "DeleteFieldIndex"));
@@ -5299,19 +5898,17 @@ pub mod points_client {
             &mut self,
             request: impl tonic::IntoRequest<super::SearchPoints>,
         ) -> std::result::Result<tonic::Response<super::SearchResponse>, tonic::Status> {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static("/qdrant.Points/Search");
             let mut req = request.into_request();
-            req.extensions_mut().insert(GrpcMethod::new("qdrant.Points", "Search"));
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Points", "Search"));
             self.inner.unary(req, path, codec).await
         }
         ///
@@ -5319,25 +5916,19 @@ pub mod points_client {
         pub async fn search_batch(
             &mut self,
             request: impl tonic::IntoRequest<super::SearchBatchPoints>,
-        ) -> std::result::Result<
-            tonic::Response<super::SearchBatchResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::SearchBatchResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.Points/SearchBatch",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.Points/SearchBatch");
             let mut req = request.into_request();
-            req.extensions_mut().insert(GrpcMethod::new("qdrant.Points", "SearchBatch"));
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Points", "SearchBatch"));
             self.inner.unary(req, path, codec).await
         }
         ///
@@ -5345,23 +5936,16 @@ pub mod points_client {
         pub async fn search_groups(
             &mut self,
             request: impl tonic::IntoRequest<super::SearchPointGroups>,
-        ) -> std::result::Result<
-            tonic::Response<super::SearchGroupsResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::SearchGroupsResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.Points/SearchGroups",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.Points/SearchGroups");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("qdrant.Points", "SearchGroups"));
@@ -5373,19 +5957,17 @@ pub mod points_client {
             &mut self,
             request: impl tonic::IntoRequest<super::ScrollPoints>,
         ) -> std::result::Result<tonic::Response<super::ScrollResponse>, tonic::Status> {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static("/qdrant.Points/Scroll");
             let mut req = request.into_request();
-            req.extensions_mut().insert(GrpcMethod::new("qdrant.Points", "Scroll"));
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Points", "Scroll"));
             self.inner.unary(req, path, codec).await
         }
         ///
@@ -5393,23 +5975,18 @@ pub mod points_client {
         pub async fn recommend(
             &mut self,
             request: impl tonic::IntoRequest<super::RecommendPoints>,
-        ) -> std::result::Result<
-            tonic::Response<super::RecommendResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::RecommendResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static("/qdrant.Points/Recommend");
             let mut req = request.into_request();
-            req.extensions_mut().insert(GrpcMethod::new("qdrant.Points", "Recommend"));
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Points", "Recommend"));
             self.inner.unary(req, path, codec).await
         }
         ///
@@ -5417,23 +5994,16 @@ pub mod points_client {
         pub async fn recommend_batch(
             &mut self,
             request: impl tonic::IntoRequest<super::RecommendBatchPoints>,
-        ) -> std::result::Result<
-            tonic::Response<super::RecommendBatchResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::RecommendBatchResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.Points/RecommendBatch",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.Points/RecommendBatch");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("qdrant.Points", "RecommendBatch"));
@@ -5444,23 +6014,16 @@ pub mod points_client {
         pub async fn recommend_groups(
             &mut self,
             request: impl tonic::IntoRequest<super::RecommendPointGroups>,
-        ) -> std::result::Result<
-            tonic::Response<super::RecommendGroupsResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::RecommendGroupsResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.Points/RecommendGroups",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.Points/RecommendGroups");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("qdrant.Points", "RecommendGroups"));
@@ -5485,23 +6048,18 @@ pub mod points_client {
         pub async fn discover(
             &mut self,
             request: impl tonic::IntoRequest<super::DiscoverPoints>,
-        ) -> std::result::Result<
-            tonic::Response<super::DiscoverResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::DiscoverResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static("/qdrant.Points/Discover");
             let mut req = request.into_request();
-            req.extensions_mut().insert(GrpcMethod::new("qdrant.Points", "Discover"));
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Points", "Discover"));
             self.inner.unary(req, path, codec).await
         }
         ///
@@ -5509,23 +6067,16 @@ pub mod points_client {
         pub async fn discover_batch(
             &mut self,
             request: impl tonic::IntoRequest<super::DiscoverBatchPoints>,
-        ) -> std::result::Result<
-            tonic::Response<super::DiscoverBatchResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::DiscoverBatchResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.Points/DiscoverBatch",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.Points/DiscoverBatch");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("qdrant.Points", "DiscoverBatch"));
@@ -5537,19 +6088,55 @@ pub mod points_client {
             &mut self,
             request: impl tonic::IntoRequest<super::CountPoints>,
         ) -> std::result::Result<tonic::Response<super::CountResponse>, tonic::Status> {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static("/qdrant.Points/Count");
             let mut req = request.into_request();
-            req.extensions_mut().insert(GrpcMethod::new("qdrant.Points", "Count"));
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Points", "Count"));
+            self.inner.unary(req, path, codec).await
+        }
+        ///
+        /// Compute min/max/sum/avg/count and a histogram of a numeric payload field over points with given filtering conditions
+        pub async fn aggregate(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AggregatePoints>,
+        ) -> std::result::Result<tonic::Response<super::AggregateResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/qdrant.Points/Aggregate");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Points", "Aggregate"));
+            self.inner.unary(req, path, codec).await
+        }
+        ///
+        /// Rank points by BM25 relevance of a full-text indexed payload field to the query
+        pub async fn search_text(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SearchTextPoints>,
+        ) -> std::result::Result<tonic::Response<super::SearchResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/qdrant.Points/SearchText");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Points", "SearchText"));
             self.inner.unary(req, path, codec).await
         }
         ///
@@ -5557,26 +6144,42 @@ pub mod points_client {
         pub async fn update_batch(
             &mut self,
             request: impl tonic::IntoRequest<super::UpdateBatchPoints>,
+        ) -> std::result::Result<tonic::Response<super::UpdateBatchResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/qdrant.Points/UpdateBatch");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Points", "UpdateBatch"));
+            self.inner.unary(req, path, codec).await
+        }
+        ///
+        /// Stream changes applied to the replica of a shard on this peer, tailing its WAL
+        pub async fn stream_changes(
+            &mut self,
+            request: impl tonic::IntoRequest<super::StreamShardChanges>,
         ) -> std::result::Result<
-            tonic::Response<super::UpdateBatchResponse>,
+            tonic::Response<tonic::codec::Streaming<super::ShardChange>>,
             tonic::Status,
         > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.Points/UpdateBatch",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.Points/StreamChanges");
             let mut req = request.into_request();
-            req.extensions_mut().insert(GrpcMethod::new("qdrant.Points", "UpdateBatch"));
-            self.inner.unary(req, path, codec).await
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Points", "StreamChanges"));
+            self.inner.server_streaming(req, path, codec).await
         }
     }
 }
@@ -5592,19 +6195,13 @@ pub mod points_server {
         async fn upsert(
             &self,
             request: tonic::Request<super::UpsertPoints>,
-        ) -> std::result::Result<
-            tonic::Response<super::PointsOperationResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::PointsOperationResponse>, tonic::Status>;
         ///
         /// Delete points
         async fn delete(
             &self,
             request: tonic::Request<super::DeletePoints>,
-        ) -> std::result::Result<
-            tonic::Response<super::PointsOperationResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::PointsOperationResponse>, tonic::Status>;
         ///
         /// Retrieve points
         async fn get(
@@ -5616,73 +6213,49 @@ pub mod points_server {
         async fn update_vectors(
             &self,
             request: tonic::Request<super::UpdatePointVectors>,
-        ) -> std::result::Result<
-            tonic::Response<super::PointsOperationResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::PointsOperationResponse>, tonic::Status>;
         ///
         /// Delete named vectors for points
         async fn delete_vectors(
             &self,
             request: tonic::Request<super::DeletePointVectors>,
-        ) -> std::result::Result<
-            tonic::Response<super::PointsOperationResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::PointsOperationResponse>, tonic::Status>;
         ///
         /// Set payload for points
         async fn set_payload(
             &self,
             request: tonic::Request<super::SetPayloadPoints>,
-        ) -> std::result::Result<
-            tonic::Response<super::PointsOperationResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::PointsOperationResponse>, tonic::Status>;
         ///
         /// Overwrite payload for points
         async fn overwrite_payload(
             &self,
             request: tonic::Request<super::SetPayloadPoints>,
-        ) -> std::result::Result<
-            tonic::Response<super::PointsOperationResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::PointsOperationResponse>, tonic::Status>;
         ///
         /// Delete specified key payload for points
         async fn delete_payload(
             &self,
             request: tonic::Request<super::DeletePayloadPoints>,
-        ) -> std::result::Result<
-            tonic::Response<super::PointsOperationResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::PointsOperationResponse>, tonic::Status>;
         ///
         /// Remove all payload for specified points
         async fn clear_payload(
             &self,
             request: tonic::Request<super::ClearPayloadPoints>,
-        ) -> std::result::Result<
-            tonic::Response<super::PointsOperationResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::PointsOperationResponse>, tonic::Status>;
         ///
         /// Create index for field in collection
         async fn create_field_index(
             &self,
             request: tonic::Request<super::CreateFieldIndexCollection>,
-        ) -> std::result::Result<
-            tonic::Response<super::PointsOperationResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::PointsOperationResponse>, tonic::Status>;
         ///
         /// Delete field index for collection
         async fn delete_field_index(
             &self,
             request: tonic::Request<super::DeleteFieldIndexCollection>,
-        ) -> std::result::Result<
-            tonic::Response<super::PointsOperationResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::PointsOperationResponse>, tonic::Status>;
         ///
         /// Retrieve closest points based on vector similarity and given filtering conditions
         async fn search(
@@ -5694,19 +6267,13 @@ pub mod points_server {
         async fn search_batch(
             &self,
             request: tonic::Request<super::SearchBatchPoints>,
-        ) -> std::result::Result<
-            tonic::Response<super::SearchBatchResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::SearchBatchResponse>, tonic::Status>;
         ///
         /// Retrieve closest points based on vector similarity and given filtering conditions, grouped by a given field
         async fn search_groups(
             &self,
             request: tonic::Request<super::SearchPointGroups>,
-        ) -> std::result::Result<
-            tonic::Response<super::SearchGroupsResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::SearchGroupsResponse>, tonic::Status>;
         ///
         /// Iterate over all or filtered points
         async fn scroll(
@@ -5718,28 +6285,19 @@ pub mod points_server {
         async fn recommend(
             &self,
             request: tonic::Request<super::RecommendPoints>,
-        ) -> std::result::Result<
-            tonic::Response<super::RecommendResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::RecommendResponse>, tonic::Status>;
         ///
         /// Look for the points which are closer to stored positive examples and at the same time further to negative examples.
         async fn recommend_batch(
             &self,
             request: tonic::Request<super::RecommendBatchPoints>,
-        ) -> std::result::Result<
-            tonic::Response<super::RecommendBatchResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::RecommendBatchResponse>, tonic::Status>;
         ///
         /// Look for the points which are closer to stored positive examples and at the same time further to negative examples, grouped by a given field
         async fn recommend_groups(
             &self,
             request: tonic::Request<super::RecommendPointGroups>,
-        ) -> std::result::Result<
-            tonic::Response<super::RecommendGroupsResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::RecommendGroupsResponse>, tonic::Status>;
         ///
         /// Use context and a target to find the most similar points to the target, constrained by the context.
         ///
@@ -5759,19 +6317,13 @@ pub mod points_server {
         async fn discover(
             &self,
             request: tonic::Request<super::DiscoverPoints>,
-        ) -> std::result::Result<
-            tonic::Response<super::DiscoverResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::DiscoverResponse>, tonic::Status>;
         ///
         /// Batch request points based on { positive, negative } pairs of examples, and/or a target
         async fn discover_batch(
             &self,
             request: tonic::Request<super::DiscoverBatchPoints>,
-        ) -> std::result::Result<
-            tonic::Response<super::DiscoverBatchResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::DiscoverBatchResponse>, tonic::Status>;
         ///
         /// Count points in collection with given filtering conditions
         async fn count(
@@ -5779,14 +6331,33 @@ pub mod points_server {
             request: tonic::Request<super::CountPoints>,
         ) -> std::result::Result<tonic::Response<super::CountResponse>, tonic::Status>;
         ///
+        /// Compute min/max/sum/avg/count and a histogram of a numeric payload field over points with given filtering conditions
+        async fn aggregate(
+            &self,
+            request: tonic::Request<super::AggregatePoints>,
+        ) -> std::result::Result<tonic::Response<super::AggregateResponse>, tonic::Status>;
+        ///
+        /// Rank points by BM25 relevance of a full-text indexed payload field to the query
+        async fn search_text(
+            &self,
+            request: tonic::Request<super::SearchTextPoints>,
+        ) -> std::result::Result<tonic::Response<super::SearchResponse>, tonic::Status>;
+        ///
         /// Perform multiple update operations in one request
         async fn update_batch(
             &self,
             request: tonic::Request<super::UpdateBatchPoints>,
-        ) -> std::result::Result<
-            tonic::Response<super::UpdateBatchResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::UpdateBatchResponse>, tonic::Status>;
+        /// Server streaming response type for the StreamChanges method.
+        type StreamChangesStream: futures_core::Stream<Item = std::result::Result<super::ShardChange, tonic::Status>>
+            + Send
+            + 'static;
+        ///
+        /// Stream changes applied to the replica of a shard on this peer, tailing its WAL
+        async fn stream_changes(
+            &self,
+            request: tonic::Request<super::StreamShardChanges>,
+        ) -> std::result::Result<tonic::Response<Self::StreamChangesStream>, tonic::Status>;
     }
     #[derive(Debug)]
     pub struct PointsServer<T: Points> {
@@ -5811,10 +6382,7 @@ pub mod points_server {
                 max_encoding_message_size: None,
             }
         }
-        pub fn with_interceptor<F>(
-            inner: T,
-            interceptor: F,
-        ) -> InterceptedService<Self, F>
+        pub fn with_interceptor<F>(inner: T, interceptor: F) -> InterceptedService<Self, F>
         where
             F: tonic::service::Interceptor,
         {
@@ -5870,21 +6438,15 @@ pub mod points_server {
                 "/qdrant.Points/Upsert" => {
                     #[allow(non_camel_case_types)]
                     struct UpsertSvc<T: Points>(pub Arc<T>);
-                    impl<T: Points> tonic::server::UnaryService<super::UpsertPoints>
-                    for UpsertSvc<T> {
+                    impl<T: Points> tonic::server::UnaryService<super::UpsertPoints> for UpsertSvc<T> {
                         type Response = super::PointsOperationResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::UpsertPoints>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as Points>::upsert(&inner, request).await
-                            };
+                            let fut = async move { <T as Points>::upsert(&inner, request).await };
                             Box::pin(fut)
                         }
                     }
@@ -5914,21 +6476,15 @@ pub mod points_server {
                 "/qdrant.Points/Delete" => {
                     #[allow(non_camel_case_types)]
                     struct DeleteSvc<T: Points>(pub Arc<T>);
-                    impl<T: Points> tonic::server::UnaryService<super::DeletePoints>
-                    for DeleteSvc<T> {
+                    impl<T: Points> tonic::server::UnaryService<super::DeletePoints> for DeleteSvc<T> {
                         type Response = super::PointsOperationResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::DeletePoints>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as Points>::delete(&inner, request).await
-                            };
+                            let fut = async move { <T as Points>::delete(&inner, request).await };
                             Box::pin(fut)
                         }
                     }
@@ -5958,21 +6514,15 @@ pub mod points_server {
                 "/qdrant.Points/Get" => {
                     #[allow(non_camel_case_types)]
                     struct GetSvc<T: Points>(pub Arc<T>);
-                    impl<T: Points> tonic::server::UnaryService<super::GetPoints>
-                    for GetSvc<T> {
+                    impl<T: Points> tonic::server::UnaryService<super::GetPoints> for GetSvc<T> {
                         type Response = super::GetResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::GetPoints>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as Points>::get(&inner, request).await
-                            };
+                            let fut = async move { <T as Points>::get(&inner, request).await };
                             Box::pin(fut)
                         }
                     }
@@ -6002,23 +6552,16 @@ pub mod points_server {
                 "/qdrant.Points/UpdateVectors" => {
                     #[allow(non_camel_case_types)]
                     struct UpdateVectorsSvc<T: Points>(pub Arc<T>);
-                    impl<
-                        T: Points,
-                    > tonic::server::UnaryService<super::UpdatePointVectors>
-                    for UpdateVectorsSvc<T> {
+                    impl<T: Points> tonic::server::UnaryService<super::UpdatePointVectors> for UpdateVectorsSvc<T> {
                         type Response = super::PointsOperationResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::UpdatePointVectors>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as Points>::update_vectors(&inner, request).await
-                            };
+                            let fut =
+                                async move { <T as Points>::update_vectors(&inner, request).await };
                             Box::pin(fut)
                         }
                     }
@@ -6048,23 +6591,16 @@ pub mod points_server {
                 "/qdrant.Points/DeleteVectors" => {
                     #[allow(non_camel_case_types)]
                     struct DeleteVectorsSvc<T: Points>(pub Arc<T>);
-                    impl<
-                        T: Points,
-                    > tonic::server::UnaryService<super::DeletePointVectors>
-                    for DeleteVectorsSvc<T> {
+                    impl<T: Points> tonic::server::UnaryService<super::DeletePointVectors> for DeleteVectorsSvc<T> {
                         type Response = super::PointsOperationResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::DeletePointVectors>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as Points>::delete_vectors(&inner, request).await
-                            };
+                            let fut =
+                                async move { <T as Points>::delete_vectors(&inner, request).await };
                             Box::pin(fut)
                         }
                     }
@@ -6094,21 +6630,16 @@ pub mod points_server {
                 "/qdrant.Points/SetPayload" => {
                     #[allow(non_camel_case_types)]
                     struct SetPayloadSvc<T: Points>(pub Arc<T>);
-                    impl<T: Points> tonic::server::UnaryService<super::SetPayloadPoints>
-                    for SetPayloadSvc<T> {
+                    impl<T: Points> tonic::server::UnaryService<super::SetPayloadPoints> for SetPayloadSvc<T> {
                         type Response = super::PointsOperationResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::SetPayloadPoints>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as Points>::set_payload(&inner, request).await
-                            };
+                            let fut =
+                                async move { <T as Points>::set_payload(&inner, request).await };
                             Box::pin(fut)
                         }
                     }
@@ -6138,13 +6669,9 @@ pub mod points_server {
                 "/qdrant.Points/OverwritePayload" => {
                     #[allow(non_camel_case_types)]
                     struct OverwritePayloadSvc<T: Points>(pub Arc<T>);
-                    impl<T: Points> tonic::server::UnaryService<super::SetPayloadPoints>
-                    for OverwritePayloadSvc<T> {
+                    impl<T: Points> tonic::server::UnaryService<super::SetPayloadPoints> for OverwritePayloadSvc<T> {
                         type Response = super::PointsOperationResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::SetPayloadPoints>,
@@ -6182,23 +6709,16 @@ pub mod points_server {
                 "/qdrant.Points/DeletePayload" => {
                     #[allow(non_camel_case_types)]
                     struct DeletePayloadSvc<T: Points>(pub Arc<T>);
-                    impl<
-                        T: Points,
-                    > tonic::server::UnaryService<super::DeletePayloadPoints>
-                    for DeletePayloadSvc<T> {
+                    impl<T: Points> tonic::server::UnaryService<super::DeletePayloadPoints> for DeletePayloadSvc<T> {
                         type Response = super::PointsOperationResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::DeletePayloadPoints>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as Points>::delete_payload(&inner, request).await
-                            };
+                            let fut =
+                                async move { <T as Points>::delete_payload(&inner, request).await };
                             Box::pin(fut)
                         }
                     }
@@ -6228,23 +6748,16 @@ pub mod points_server {
                 "/qdrant.Points/ClearPayload" => {
                     #[allow(non_camel_case_types)]
                     struct ClearPayloadSvc<T: Points>(pub Arc<T>);
-                    impl<
-                        T: Points,
-                    > tonic::server::UnaryService<super::ClearPayloadPoints>
-                    for ClearPayloadSvc<T> {
+                    impl<T: Points> tonic::server::UnaryService<super::ClearPayloadPoints> for ClearPayloadSvc<T> {
                         type Response = super::PointsOperationResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::ClearPayloadPoints>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as Points>::clear_payload(&inner, request).await
-                            };
+                            let fut =
+                                async move { <T as Points>::clear_payload(&inner, request).await };
                             Box::pin(fut)
                         }
                     }
@@ -6274,15 +6787,11 @@ pub mod points_server {
                 "/qdrant.Points/CreateFieldIndex" => {
                     #[allow(non_camel_case_types)]
                     struct CreateFieldIndexSvc<T: Points>(pub Arc<T>);
-                    impl<
-                        T: Points,
-                    > tonic::server::UnaryService<super::CreateFieldIndexCollection>
-                    for CreateFieldIndexSvc<T> {
+                    impl<T: Points> tonic::server::UnaryService<super::CreateFieldIndexCollection>
+                        for CreateFieldIndexSvc<T>
+                    {
                         type Response = super::PointsOperationResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::CreateFieldIndexCollection>,
@@ -6320,15 +6829,11 @@ pub mod points_server {
                 "/qdrant.Points/DeleteFieldIndex" => {
                     #[allow(non_camel_case_types)]
                     struct DeleteFieldIndexSvc<T: Points>(pub Arc<T>);
-                    impl<
-                        T: Points,
-                    > tonic::server::UnaryService<super::DeleteFieldIndexCollection>
-                    for DeleteFieldIndexSvc<T> {
+                    impl<T: Points> tonic::server::UnaryService<super::DeleteFieldIndexCollection>
+                        for DeleteFieldIndexSvc<T>
+                    {
                         type Response = super::PointsOperationResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::DeleteFieldIndexCollection>,
@@ -6366,21 +6871,15 @@ pub mod points_server {
                 "/qdrant.Points/Search" => {
                     #[allow(non_camel_case_types)]
                     struct SearchSvc<T: Points>(pub Arc<T>);
-                    impl<T: Points> tonic::server::UnaryService<super::SearchPoints>
-                    for SearchSvc<T> {
+                    impl<T: Points> tonic::server::UnaryService<super::SearchPoints> for SearchSvc<T> {
                         type Response = super::SearchResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::SearchPoints>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as Points>::search(&inner, request).await
-                            };
+                            let fut = async move { <T as Points>::search(&inner, request).await };
                             Box::pin(fut)
                         }
                     }
@@ -6410,21 +6909,16 @@ pub mod points_server {
                 "/qdrant.Points/SearchBatch" => {
                     #[allow(non_camel_case_types)]
                     struct SearchBatchSvc<T: Points>(pub Arc<T>);
-                    impl<T: Points> tonic::server::UnaryService<super::SearchBatchPoints>
-                    for SearchBatchSvc<T> {
+                    impl<T: Points> tonic::server::UnaryService<super::SearchBatchPoints> for SearchBatchSvc<T> {
                         type Response = super::SearchBatchResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::SearchBatchPoints>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as Points>::search_batch(&inner, request).await
-                            };
+                            let fut =
+                                async move { <T as Points>::search_batch(&inner, request).await };
                             Box::pin(fut)
                         }
                     }
@@ -6454,21 +6948,16 @@ pub mod points_server {
                 "/qdrant.Points/SearchGroups" => {
                     #[allow(non_camel_case_types)]
                     struct SearchGroupsSvc<T: Points>(pub Arc<T>);
-                    impl<T: Points> tonic::server::UnaryService<super::SearchPointGroups>
-                    for SearchGroupsSvc<T> {
+                    impl<T: Points> tonic::server::UnaryService<super::SearchPointGroups> for SearchGroupsSvc<T> {
                         type Response = super::SearchGroupsResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::SearchPointGroups>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as Points>::search_groups(&inner, request).await
-                            };
+                            let fut =
+                                async move { <T as Points>::search_groups(&inner, request).await };
                             Box::pin(fut)
                         }
                     }
@@ -6498,21 +6987,15 @@ pub mod points_server {
                 "/qdrant.Points/Scroll" => {
                     #[allow(non_camel_case_types)]
                     struct ScrollSvc<T: Points>(pub Arc<T>);
-                    impl<T: Points> tonic::server::UnaryService<super::ScrollPoints>
-                    for ScrollSvc<T> {
+                    impl<T: Points> tonic::server::UnaryService<super::ScrollPoints> for ScrollSvc<T> {
                         type Response = super::ScrollResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::ScrollPoints>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as Points>::scroll(&inner, request).await
-                            };
+                            let fut = async move { <T as Points>::scroll(&inner, request).await };
                             Box::pin(fut)
                         }
                     }
@@ -6542,21 +7025,16 @@ pub mod points_server {
                 "/qdrant.Points/Recommend" => {
                     #[allow(non_camel_case_types)]
                     struct RecommendSvc<T: Points>(pub Arc<T>);
-                    impl<T: Points> tonic::server::UnaryService<super::RecommendPoints>
-                    for RecommendSvc<T> {
+                    impl<T: Points> tonic::server::UnaryService<super::RecommendPoints> for RecommendSvc<T> {
                         type Response = super::RecommendResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::RecommendPoints>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as Points>::recommend(&inner, request).await
-                            };
+                            let fut =
+                                async move { <T as Points>::recommend(&inner, request).await };
                             Box::pin(fut)
                         }
                     }
@@ -6586,15 +7064,9 @@ pub mod points_server {
                 "/qdrant.Points/RecommendBatch" => {
                     #[allow(non_camel_case_types)]
                     struct RecommendBatchSvc<T: Points>(pub Arc<T>);
-                    impl<
-                        T: Points,
-                    > tonic::server::UnaryService<super::RecommendBatchPoints>
-                    for RecommendBatchSvc<T> {
+                    impl<T: Points> tonic::server::UnaryService<super::RecommendBatchPoints> for RecommendBatchSvc<T> {
                         type Response = super::RecommendBatchResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::RecommendBatchPoints>,
@@ -6632,15 +7104,9 @@ pub mod points_server {
                 "/qdrant.Points/RecommendGroups" => {
                     #[allow(non_camel_case_types)]
                     struct RecommendGroupsSvc<T: Points>(pub Arc<T>);
-                    impl<
-                        T: Points,
-                    > tonic::server::UnaryService<super::RecommendPointGroups>
-                    for RecommendGroupsSvc<T> {
+                    impl<T: Points> tonic::server::UnaryService<super::RecommendPointGroups> for RecommendGroupsSvc<T> {
                         type Response = super::RecommendGroupsResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::RecommendPointGroups>,
@@ -6678,21 +7144,15 @@ pub mod points_server {
                 "/qdrant.Points/Discover" => {
                     #[allow(non_camel_case_types)]
                     struct DiscoverSvc<T: Points>(pub Arc<T>);
-                    impl<T: Points> tonic::server::UnaryService<super::DiscoverPoints>
-                    for DiscoverSvc<T> {
+                    impl<T: Points> tonic::server::UnaryService<super::DiscoverPoints> for DiscoverSvc<T> {
                         type Response = super::DiscoverResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::DiscoverPoints>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as Points>::discover(&inner, request).await
-                            };
+                            let fut = async move { <T as Points>::discover(&inner, request).await };
                             Box::pin(fut)
                         }
                     }
@@ -6722,23 +7182,16 @@ pub mod points_server {
                 "/qdrant.Points/DiscoverBatch" => {
                     #[allow(non_camel_case_types)]
                     struct DiscoverBatchSvc<T: Points>(pub Arc<T>);
-                    impl<
-                        T: Points,
-                    > tonic::server::UnaryService<super::DiscoverBatchPoints>
-                    for DiscoverBatchSvc<T> {
+                    impl<T: Points> tonic::server::UnaryService<super::DiscoverBatchPoints> for DiscoverBatchSvc<T> {
                         type Response = super::DiscoverBatchResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::DiscoverBatchPoints>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as Points>::discover_batch(&inner, request).await
-                            };
+                            let fut =
+                                async move { <T as Points>::discover_batch(&inner, request).await };
                             Box::pin(fut)
                         }
                     }
@@ -6768,21 +7221,15 @@ pub mod points_server {
                 "/qdrant.Points/Count" => {
                     #[allow(non_camel_case_types)]
                     struct CountSvc<T: Points>(pub Arc<T>);
-                    impl<T: Points> tonic::server::UnaryService<super::CountPoints>
-                    for CountSvc<T> {
+                    impl<T: Points> tonic::server::UnaryService<super::CountPoints> for CountSvc<T> {
                         type Response = super::CountResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::CountPoints>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as Points>::count(&inner, request).await
-                            };
+                            let fut = async move { <T as Points>::count(&inner, request).await };
                             Box::pin(fut)
                         }
                     }
@@ -6809,24 +7256,97 @@ pub mod points_server {
                     };
                     Box::pin(fut)
                 }
+                "/qdrant.Points/Aggregate" => {
+                    #[allow(non_camel_case_types)]
+                    struct AggregateSvc<T: Points>(pub Arc<T>);
+                    impl<T: Points> tonic::server::UnaryService<super::AggregatePoints> for AggregateSvc<T> {
+                        type Response = super::AggregateResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AggregatePoints>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut =
+                                async move { <T as Points>::aggregate(&inner, request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = AggregateSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/qdrant.Points/SearchText" => {
+                    #[allow(non_camel_case_types)]
+                    struct SearchTextSvc<T: Points>(pub Arc<T>);
+                    impl<T: Points> tonic::server::UnaryService<super::SearchTextPoints> for SearchTextSvc<T> {
+                        type Response = super::SearchResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SearchTextPoints>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut =
+                                async move { <T as Points>::search_text(&inner, request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SearchTextSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/qdrant.Points/UpdateBatch" => {
                     #[allow(non_camel_case_types)]
                     struct UpdateBatchSvc<T: Points>(pub Arc<T>);
-                    impl<T: Points> tonic::server::UnaryService<super::UpdateBatchPoints>
-                    for UpdateBatchSvc<T> {
+                    impl<T: Points> tonic::server::UnaryService<super::UpdateBatchPoints> for UpdateBatchSvc<T> {
                         type Response = super::UpdateBatchResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::UpdateBatchPoints>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as Points>::update_batch(&inner, request).await
-                            };
+                            let fut =
+                                async move { <T as Points>::update_batch(&inner, request).await };
                             Box::pin(fut)
                         }
                     }
@@ -6853,30 +7373,69 @@ pub mod points_server {
                     };
                     Box::pin(fut)
                 }
-                _ => {
-                    Box::pin(async move {
-                        Ok(
-                            http::Response::builder()
-                                .status(200)
-                                .header("grpc-status", "12")
-                                .header("content-type", "application/grpc")
-                                .body(empty_body())
-                                .unwrap(),
-                        )
-                    })
-                }
-            }
-        }
-    }
-    impl<T: Points> Clone for PointsServer<T> {
-        fn clone(&self) -> Self {
-            let inner = self.inner.clone();
-            Self {
-                inner,
-                accept_compression_encodings: self.accept_compression_encodings,
-                send_compression_encodings: self.send_compression_encodings,
-                max_decoding_message_size: self.max_decoding_message_size,
-                max_encoding_message_size: self.max_encoding_message_size,
+                "/qdrant.Points/StreamChanges" => {
+                    #[allow(non_camel_case_types)]
+                    struct StreamChangesSvc<T: Points>(pub Arc<T>);
+                    impl<T: Points> tonic::server::ServerStreamingService<super::StreamShardChanges>
+                        for StreamChangesSvc<T>
+                    {
+                        type Response = super::ShardChange;
+                        type ResponseStream = T::StreamChangesStream;
+                        type Future =
+                            BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::StreamShardChanges>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut =
+                                async move { <T as Points>::stream_changes(&inner, request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = StreamChangesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => Box::pin(async move {
+                    Ok(http::Response::builder()
+                        .status(200)
+                        .header("grpc-status", "12")
+                        .header("content-type", "application/grpc")
+                        .body(empty_body())
+                        .unwrap())
+                }),
+            }
+        }
+    }
+    impl<T: Points> Clone for PointsServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
             }
         }
     }
@@ -6894,8 +7453,7 @@ pub mod points_server {
         const NAME: &'static str = "qdrant.Points";
     }
 }
-#[derive(serde::Serialize)]
-#[derive(validator::Validate)]
+#[derive(serde::Serialize, validator::Validate)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SyncPoints {
@@ -6917,8 +7475,7 @@ pub struct SyncPoints {
     #[prost(message, optional, tag = "6")]
     pub ordering: ::core::option::Option<WriteOrdering>,
 }
-#[derive(serde::Serialize)]
-#[derive(validator::Validate)]
+#[derive(serde::Serialize, validator::Validate)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SyncPointsInternal {
@@ -6928,8 +7485,7 @@ pub struct SyncPointsInternal {
     #[prost(uint32, optional, tag = "2")]
     pub shard_id: ::core::option::Option<u32>,
 }
-#[derive(serde::Serialize)]
-#[derive(validator::Validate)]
+#[derive(serde::Serialize, validator::Validate)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpsertPointsInternal {
@@ -6939,8 +7495,7 @@ pub struct UpsertPointsInternal {
     #[prost(uint32, optional, tag = "2")]
     pub shard_id: ::core::option::Option<u32>,
 }
-#[derive(serde::Serialize)]
-#[derive(validator::Validate)]
+#[derive(serde::Serialize, validator::Validate)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DeletePointsInternal {
@@ -6950,8 +7505,7 @@ pub struct DeletePointsInternal {
     #[prost(uint32, optional, tag = "2")]
     pub shard_id: ::core::option::Option<u32>,
 }
-#[derive(serde::Serialize)]
-#[derive(validator::Validate)]
+#[derive(serde::Serialize, validator::Validate)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpdateVectorsInternal {
@@ -6961,8 +7515,7 @@ pub struct UpdateVectorsInternal {
     #[prost(uint32, optional, tag = "2")]
     pub shard_id: ::core::option::Option<u32>,
 }
-#[derive(serde::Serialize)]
-#[derive(validator::Validate)]
+#[derive(serde::Serialize, validator::Validate)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DeleteVectorsInternal {
@@ -6972,8 +7525,7 @@ pub struct DeleteVectorsInternal {
     #[prost(uint32, optional, tag = "2")]
     pub shard_id: ::core::option::Option<u32>,
 }
-#[derive(serde::Serialize)]
-#[derive(validator::Validate)]
+#[derive(serde::Serialize, validator::Validate)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SetPayloadPointsInternal {
@@ -6983,8 +7535,7 @@ pub struct SetPayloadPointsInternal {
     #[prost(uint32, optional, tag = "2")]
     pub shard_id: ::core::option::Option<u32>,
 }
-#[derive(serde::Serialize)]
-#[derive(validator::Validate)]
+#[derive(serde::Serialize, validator::Validate)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DeletePayloadPointsInternal {
@@ -6994,8 +7545,7 @@ pub struct DeletePayloadPointsInternal {
     #[prost(uint32, optional, tag = "2")]
     pub shard_id: ::core::option::Option<u32>,
 }
-#[derive(serde::Serialize)]
-#[derive(validator::Validate)]
+#[derive(serde::Serialize, validator::Validate)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ClearPayloadPointsInternal {
@@ -7005,34 +7555,27 @@ pub struct ClearPayloadPointsInternal {
     #[prost(uint32, optional, tag = "2")]
     pub shard_id: ::core::option::Option<u32>,
 }
-#[derive(serde::Serialize)]
-#[derive(validator::Validate)]
+#[derive(serde::Serialize, validator::Validate)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateFieldIndexCollectionInternal {
     #[prost(message, optional, tag = "1")]
     #[validate]
-    pub create_field_index_collection: ::core::option::Option<
-        CreateFieldIndexCollection,
-    >,
+    pub create_field_index_collection: ::core::option::Option<CreateFieldIndexCollection>,
     #[prost(uint32, optional, tag = "2")]
     pub shard_id: ::core::option::Option<u32>,
 }
-#[derive(serde::Serialize)]
-#[derive(validator::Validate)]
+#[derive(serde::Serialize, validator::Validate)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DeleteFieldIndexCollectionInternal {
     #[prost(message, optional, tag = "1")]
     #[validate]
-    pub delete_field_index_collection: ::core::option::Option<
-        DeleteFieldIndexCollection,
-    >,
+    pub delete_field_index_collection: ::core::option::Option<DeleteFieldIndexCollection>,
     #[prost(uint32, optional, tag = "2")]
     pub shard_id: ::core::option::Option<u32>,
 }
-#[derive(serde::Serialize)]
-#[derive(validator::Validate)]
+#[derive(serde::Serialize, validator::Validate)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SearchPointsInternal {
@@ -7042,8 +7585,7 @@ pub struct SearchPointsInternal {
     #[prost(uint32, optional, tag = "2")]
     pub shard_id: ::core::option::Option<u32>,
 }
-#[derive(serde::Serialize)]
-#[derive(validator::Validate)]
+#[derive(serde::Serialize, validator::Validate)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SearchBatchPointsInternal {
@@ -7058,8 +7600,7 @@ pub struct SearchBatchPointsInternal {
     #[prost(uint64, optional, tag = "4")]
     pub timeout: ::core::option::Option<u64>,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RecoQuery {
@@ -7070,8 +7611,7 @@ pub struct RecoQuery {
     #[validate]
     pub negatives: ::prost::alloc::vec::Vec<Vector>,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ContextPair {
@@ -7082,8 +7622,7 @@ pub struct ContextPair {
     #[validate]
     pub negative: ::core::option::Option<Vector>,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DiscoveryQuery {
@@ -7094,8 +7633,7 @@ pub struct DiscoveryQuery {
     #[validate]
     pub context: ::prost::alloc::vec::Vec<ContextPair>,
 }
-#[derive(validator::Validate)]
-#[derive(serde::Serialize)]
+#[derive(validator::Validate, serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ContextQuery {
@@ -7103,11 +7641,36 @@ pub struct ContextQuery {
     #[validate]
     pub context: ::prost::alloc::vec::Vec<ContextPair>,
 }
+#[derive(validator::Validate, serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeedbackItem {
+    #[prost(message, optional, tag = "1")]
+    #[validate]
+    pub example: ::core::option::Option<Vector>,
+    /// Confidence of the example, positive for relevant and negative for irrelevant ones
+    #[prost(float, tag = "2")]
+    pub weight: f32,
+}
+#[derive(validator::Validate, serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeedbackQuery {
+    #[prost(message, optional, tag = "1")]
+    #[validate]
+    pub target: ::core::option::Option<Vector>,
+    #[prost(message, repeated, tag = "2")]
+    #[validate]
+    pub feedback: ::prost::alloc::vec::Vec<FeedbackItem>,
+    #[prost(message, repeated, tag = "3")]
+    #[validate]
+    pub context: ::prost::alloc::vec::Vec<ContextPair>,
+}
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryEnum {
-    #[prost(oneof = "query_enum::Query", tags = "1, 2, 3, 4")]
+    #[prost(oneof = "query_enum::Query", tags = "1, 2, 3, 4, 5")]
     pub query: ::core::option::Option<query_enum::Query>,
 }
 /// Nested message and enum types in `QueryEnum`.
@@ -7128,11 +7691,13 @@ pub mod query_enum {
         /// Use only the context to find points that minimize loss against negative examples
         #[prost(message, tag = "4")]
         Context(super::ContextQuery),
+        /// Score points by sigmoid-scaled similarities to the target and to the examples multiplied by their weights, plus the loss of positive and negative context pairs
+        #[prost(message, tag = "5")]
+        Feedback(super::FeedbackQuery),
     }
 }
 /// This is only used internally, so it makes more sense to add it here rather than in points.proto
-#[derive(serde::Serialize)]
-#[derive(validator::Validate)]
+#[derive(serde::Serialize, validator::Validate)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CoreSearchPoints {
@@ -7163,8 +7728,7 @@ pub struct CoreSearchPoints {
     #[prost(message, optional, tag = "11")]
     pub read_consistency: ::core::option::Option<ReadConsistency>,
 }
-#[derive(serde::Serialize)]
-#[derive(validator::Validate)]
+#[derive(serde::Serialize, validator::Validate)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CoreSearchBatchPointsInternal {
@@ -7179,8 +7743,7 @@ pub struct CoreSearchBatchPointsInternal {
     #[prost(uint64, optional, tag = "4")]
     pub timeout: ::core::option::Option<u64>,
 }
-#[derive(serde::Serialize)]
-#[derive(validator::Validate)]
+#[derive(serde::Serialize, validator::Validate)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ScrollPointsInternal {
@@ -7190,8 +7753,7 @@ pub struct ScrollPointsInternal {
     #[prost(uint32, optional, tag = "2")]
     pub shard_id: ::core::option::Option<u32>,
 }
-#[derive(serde::Serialize)]
-#[derive(validator::Validate)]
+#[derive(serde::Serialize, validator::Validate)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RecommendPointsInternal {
@@ -7201,8 +7763,7 @@ pub struct RecommendPointsInternal {
     #[prost(uint32, optional, tag = "2")]
     pub shard_id: ::core::option::Option<u32>,
 }
-#[derive(serde::Serialize)]
-#[derive(validator::Validate)]
+#[derive(serde::Serialize, validator::Validate)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetPointsInternal {
@@ -7212,8 +7773,7 @@ pub struct GetPointsInternal {
     #[prost(uint32, optional, tag = "2")]
     pub shard_id: ::core::option::Option<u32>,
 }
-#[derive(serde::Serialize)]
-#[derive(validator::Validate)]
+#[derive(serde::Serialize, validator::Validate)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CountPointsInternal {
@@ -7223,11 +7783,82 @@ pub struct CountPointsInternal {
     #[prost(uint32, optional, tag = "2")]
     pub shard_id: ::core::option::Option<u32>,
 }
+#[derive(serde::Serialize, validator::Validate)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AggregatePointsInternal {
+    #[prost(message, optional, tag = "1")]
+    #[validate]
+    pub aggregate_points: ::core::option::Option<AggregatePoints>,
+    #[prost(uint32, optional, tag = "2")]
+    pub shard_id: ::core::option::Option<u32>,
+}
+#[derive(serde::Serialize, validator::Validate)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SearchTextPointsInternal {
+    #[prost(message, optional, tag = "1")]
+    #[validate]
+    pub search_text_points: ::core::option::Option<SearchTextPoints>,
+    #[prost(uint32, optional, tag = "2")]
+    pub shard_id: ::core::option::Option<u32>,
+    /// Statistics of the field over all selected shards to score with
+    #[prost(message, optional, tag = "3")]
+    pub stats: ::core::option::Option<TextStats>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TextStats {
+    /// Number of indexed documents
+    #[prost(uint64, tag = "1")]
+    pub documents_count: u64,
+    /// Total number of tokens in all indexed documents
+    #[prost(uint64, tag = "2")]
+    pub tokens_count: u64,
+    /// Number of documents containing each of the query tokens
+    #[prost(map = "string, uint64", tag = "3")]
+    pub document_frequencies: ::std::collections::HashMap<::prost::alloc::string::String, u64>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TextStatsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub result: ::core::option::Option<TextStats>,
+    /// Time spent to process
+    #[prost(double, tag = "2")]
+    pub time: f64,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DistinctValues {
+    /// Exact distinct keyword values
+    #[prost(string, repeated, tag = "1")]
+    pub keywords: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Exact distinct integer values
+    #[prost(sint64, repeated, tag = "2")]
+    pub integers: ::prost::alloc::vec::Vec<i64>,
+    /// HyperLogLog registers, if the values were too many to collect exactly
+    #[prost(bytes = "vec", optional, tag = "3")]
+    pub sketch: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CountDistinctResponse {
+    #[prost(message, optional, tag = "1")]
+    pub result: ::core::option::Option<DistinctValues>,
+    /// Time spent to process
+    #[prost(double, tag = "2")]
+    pub time: f64,
+}
 /// Generated client implementations.
 pub mod points_internal_client {
     #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
-    use tonic::codegen::*;
     use tonic::codegen::http::Uri;
+    use tonic::codegen::*;
     #[derive(Debug, Clone)]
     pub struct PointsInternalClient<T> {
         inner: tonic::client::Grpc<T>,
@@ -7271,9 +7902,8 @@ pub mod points_internal_client {
                     <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
                 >,
             >,
-            <T as tonic::codegen::Service<
-                http::Request<tonic::body::BoxBody>,
-            >>::Error: Into<StdError> + Send + Sync,
+            <T as tonic::codegen::Service<http::Request<tonic::body::BoxBody>>>::Error:
+                Into<StdError> + Send + Sync,
         {
             PointsInternalClient::new(InterceptedService::new(inner, interceptor))
         }
@@ -7311,23 +7941,16 @@ pub mod points_internal_client {
         pub async fn upsert(
             &mut self,
             request: impl tonic::IntoRequest<super::UpsertPointsInternal>,
-        ) -> std::result::Result<
-            tonic::Response<super::PointsOperationResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::PointsOperationResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.PointsInternal/Upsert",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.PointsInternal/Upsert");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("qdrant.PointsInternal", "Upsert"));
@@ -7336,23 +7959,16 @@ pub mod points_internal_client {
         pub async fn sync(
             &mut self,
             request: impl tonic::IntoRequest<super::SyncPointsInternal>,
-        ) -> std::result::Result<
-            tonic::Response<super::PointsOperationResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::PointsOperationResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.PointsInternal/Sync",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.PointsInternal/Sync");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("qdrant.PointsInternal", "Sync"));
@@ -7361,23 +7977,16 @@ pub mod points_internal_client {
         pub async fn delete(
             &mut self,
             request: impl tonic::IntoRequest<super::DeletePointsInternal>,
-        ) -> std::result::Result<
-            tonic::Response<super::PointsOperationResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::PointsOperationResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.PointsInternal/Delete",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.PointsInternal/Delete");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("qdrant.PointsInternal", "Delete"));
@@ -7386,23 +7995,16 @@ pub mod points_internal_client {
         pub async fn update_vectors(
             &mut self,
             request: impl tonic::IntoRequest<super::UpdateVectorsInternal>,
-        ) -> std::result::Result<
-            tonic::Response<super::PointsOperationResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::PointsOperationResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.PointsInternal/UpdateVectors",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.PointsInternal/UpdateVectors");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("qdrant.PointsInternal", "UpdateVectors"));
@@ -7411,23 +8013,16 @@ pub mod points_internal_client {
         pub async fn delete_vectors(
             &mut self,
             request: impl tonic::IntoRequest<super::DeleteVectorsInternal>,
-        ) -> std::result::Result<
-            tonic::Response<super::PointsOperationResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::PointsOperationResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.PointsInternal/DeleteVectors",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.PointsInternal/DeleteVectors");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("qdrant.PointsInternal", "DeleteVectors"));
@@ -7436,23 +8031,16 @@ pub mod points_internal_client {
         pub async fn set_payload(
             &mut self,
             request: impl tonic::IntoRequest<super::SetPayloadPointsInternal>,
-        ) -> std::result::Result<
-            tonic::Response<super::PointsOperationResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::PointsOperationResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.PointsInternal/SetPayload",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.PointsInternal/SetPayload");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("qdrant.PointsInternal", "SetPayload"));
@@ -7461,23 +8049,17 @@ pub mod points_internal_client {
         pub async fn overwrite_payload(
             &mut self,
             request: impl tonic::IntoRequest<super::SetPayloadPointsInternal>,
-        ) -> std::result::Result<
-            tonic::Response<super::PointsOperationResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::PointsOperationResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.PointsInternal/OverwritePayload",
-            );
+            let path =
+                http::uri::PathAndQuery::from_static("/qdrant.PointsInternal/OverwritePayload");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("qdrant.PointsInternal", "OverwritePayload"));
@@ -7486,23 +8068,16 @@ pub mod points_internal_client {
         pub async fn delete_payload(
             &mut self,
             request: impl tonic::IntoRequest<super::DeletePayloadPointsInternal>,
-        ) -> std::result::Result<
-            tonic::Response<super::PointsOperationResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::PointsOperationResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.PointsInternal/DeletePayload",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.PointsInternal/DeletePayload");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("qdrant.PointsInternal", "DeletePayload"));
@@ -7511,23 +8086,16 @@ pub mod points_internal_client {
         pub async fn clear_payload(
             &mut self,
             request: impl tonic::IntoRequest<super::ClearPayloadPointsInternal>,
-        ) -> std::result::Result<
-            tonic::Response<super::PointsOperationResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::PointsOperationResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.PointsInternal/ClearPayload",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.PointsInternal/ClearPayload");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("qdrant.PointsInternal", "ClearPayload"));
@@ -7536,23 +8104,17 @@ pub mod points_internal_client {
         pub async fn create_field_index(
             &mut self,
             request: impl tonic::IntoRequest<super::CreateFieldIndexCollectionInternal>,
-        ) -> std::result::Result<
-            tonic::Response<super::PointsOperationResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::PointsOperationResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.PointsInternal/CreateFieldIndex",
-            );
+            let path =
+                http::uri::PathAndQuery::from_static("/qdrant.PointsInternal/CreateFieldIndex");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("qdrant.PointsInternal", "CreateFieldIndex"));
@@ -7561,23 +8123,17 @@ pub mod points_internal_client {
         pub async fn delete_field_index(
             &mut self,
             request: impl tonic::IntoRequest<super::DeleteFieldIndexCollectionInternal>,
-        ) -> std::result::Result<
-            tonic::Response<super::PointsOperationResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::PointsOperationResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.PointsInternal/DeleteFieldIndex",
-            );
+            let path =
+                http::uri::PathAndQuery::from_static("/qdrant.PointsInternal/DeleteFieldIndex");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("qdrant.PointsInternal", "DeleteFieldIndex"));
@@ -7587,19 +8143,14 @@ pub mod points_internal_client {
             &mut self,
             request: impl tonic::IntoRequest<super::SearchPointsInternal>,
         ) -> std::result::Result<tonic::Response<super::SearchResponse>, tonic::Status> {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.PointsInternal/Search",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.PointsInternal/Search");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("qdrant.PointsInternal", "Search"));
@@ -7608,23 +8159,16 @@ pub mod points_internal_client {
         pub async fn search_batch(
             &mut self,
             request: impl tonic::IntoRequest<super::SearchBatchPointsInternal>,
-        ) -> std::result::Result<
-            tonic::Response<super::SearchBatchResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::SearchBatchResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.PointsInternal/SearchBatch",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.PointsInternal/SearchBatch");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("qdrant.PointsInternal", "SearchBatch"));
@@ -7633,23 +8177,17 @@ pub mod points_internal_client {
         pub async fn core_search_batch(
             &mut self,
             request: impl tonic::IntoRequest<super::CoreSearchBatchPointsInternal>,
-        ) -> std::result::Result<
-            tonic::Response<super::SearchBatchResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::SearchBatchResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.PointsInternal/CoreSearchBatch",
-            );
+            let path =
+                http::uri::PathAndQuery::from_static("/qdrant.PointsInternal/CoreSearchBatch");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("qdrant.PointsInternal", "CoreSearchBatch"));
@@ -7659,19 +8197,14 @@ pub mod points_internal_client {
             &mut self,
             request: impl tonic::IntoRequest<super::ScrollPointsInternal>,
         ) -> std::result::Result<tonic::Response<super::ScrollResponse>, tonic::Status> {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.PointsInternal/Scroll",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.PointsInternal/Scroll");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("qdrant.PointsInternal", "Scroll"));
@@ -7681,44 +8214,100 @@ pub mod points_internal_client {
             &mut self,
             request: impl tonic::IntoRequest<super::CountPointsInternal>,
         ) -> std::result::Result<tonic::Response<super::CountResponse>, tonic::Status> {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.PointsInternal/Count",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.PointsInternal/Count");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("qdrant.PointsInternal", "Count"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn aggregate(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AggregatePointsInternal>,
+        ) -> std::result::Result<tonic::Response<super::AggregateResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/qdrant.PointsInternal/Aggregate");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.PointsInternal", "Aggregate"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn count_distinct(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CountPointsInternal>,
+        ) -> std::result::Result<tonic::Response<super::CountDistinctResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/qdrant.PointsInternal/CountDistinct");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.PointsInternal", "CountDistinct"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn search_text(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SearchTextPointsInternal>,
+        ) -> std::result::Result<tonic::Response<super::SearchResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/qdrant.PointsInternal/SearchText");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.PointsInternal", "SearchText"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn text_stats(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SearchTextPointsInternal>,
+        ) -> std::result::Result<tonic::Response<super::TextStatsResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/qdrant.PointsInternal/TextStats");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.PointsInternal", "TextStats"));
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn recommend(
             &mut self,
             request: impl tonic::IntoRequest<super::RecommendPointsInternal>,
-        ) -> std::result::Result<
-            tonic::Response<super::RecommendResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::RecommendResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.PointsInternal/Recommend",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.PointsInternal/Recommend");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("qdrant.PointsInternal", "Recommend"));
@@ -7728,21 +8317,17 @@ pub mod points_internal_client {
             &mut self,
             request: impl tonic::IntoRequest<super::GetPointsInternal>,
         ) -> std::result::Result<tonic::Response<super::GetResponse>, tonic::Status> {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.PointsInternal/Get",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.PointsInternal/Get");
             let mut req = request.into_request();
-            req.extensions_mut().insert(GrpcMethod::new("qdrant.PointsInternal", "Get"));
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.PointsInternal", "Get"));
             self.inner.unary(req, path, codec).await
         }
     }
@@ -7757,80 +8342,47 @@ pub mod points_internal_server {
         async fn upsert(
             &self,
             request: tonic::Request<super::UpsertPointsInternal>,
-        ) -> std::result::Result<
-            tonic::Response<super::PointsOperationResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::PointsOperationResponse>, tonic::Status>;
         async fn sync(
             &self,
             request: tonic::Request<super::SyncPointsInternal>,
-        ) -> std::result::Result<
-            tonic::Response<super::PointsOperationResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::PointsOperationResponse>, tonic::Status>;
         async fn delete(
             &self,
             request: tonic::Request<super::DeletePointsInternal>,
-        ) -> std::result::Result<
-            tonic::Response<super::PointsOperationResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::PointsOperationResponse>, tonic::Status>;
         async fn update_vectors(
             &self,
             request: tonic::Request<super::UpdateVectorsInternal>,
-        ) -> std::result::Result<
-            tonic::Response<super::PointsOperationResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::PointsOperationResponse>, tonic::Status>;
         async fn delete_vectors(
             &self,
             request: tonic::Request<super::DeleteVectorsInternal>,
-        ) -> std::result::Result<
-            tonic::Response<super::PointsOperationResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::PointsOperationResponse>, tonic::Status>;
         async fn set_payload(
             &self,
             request: tonic::Request<super::SetPayloadPointsInternal>,
-        ) -> std::result::Result<
-            tonic::Response<super::PointsOperationResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::PointsOperationResponse>, tonic::Status>;
         async fn overwrite_payload(
             &self,
             request: tonic::Request<super::SetPayloadPointsInternal>,
-        ) -> std::result::Result<
-            tonic::Response<super::PointsOperationResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::PointsOperationResponse>, tonic::Status>;
         async fn delete_payload(
             &self,
             request: tonic::Request<super::DeletePayloadPointsInternal>,
-        ) -> std::result::Result<
-            tonic::Response<super::PointsOperationResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::PointsOperationResponse>, tonic::Status>;
         async fn clear_payload(
             &self,
             request: tonic::Request<super::ClearPayloadPointsInternal>,
-        ) -> std::result::Result<
-            tonic::Response<super::PointsOperationResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::PointsOperationResponse>, tonic::Status>;
         async fn create_field_index(
             &self,
             request: tonic::Request<super::CreateFieldIndexCollectionInternal>,
-        ) -> std::result::Result<
-            tonic::Response<super::PointsOperationResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::PointsOperationResponse>, tonic::Status>;
         async fn delete_field_index(
             &self,
             request: tonic::Request<super::DeleteFieldIndexCollectionInternal>,
-        ) -> std::result::Result<
-            tonic::Response<super::PointsOperationResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::PointsOperationResponse>, tonic::Status>;
         async fn search(
             &self,
             request: tonic::Request<super::SearchPointsInternal>,
@@ -7838,17 +8390,11 @@ pub mod points_internal_server {
         async fn search_batch(
             &self,
             request: tonic::Request<super::SearchBatchPointsInternal>,
-        ) -> std::result::Result<
-            tonic::Response<super::SearchBatchResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::SearchBatchResponse>, tonic::Status>;
         async fn core_search_batch(
             &self,
             request: tonic::Request<super::CoreSearchBatchPointsInternal>,
-        ) -> std::result::Result<
-            tonic::Response<super::SearchBatchResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::SearchBatchResponse>, tonic::Status>;
         async fn scroll(
             &self,
             request: tonic::Request<super::ScrollPointsInternal>,
@@ -7857,13 +8403,26 @@ pub mod points_internal_server {
             &self,
             request: tonic::Request<super::CountPointsInternal>,
         ) -> std::result::Result<tonic::Response<super::CountResponse>, tonic::Status>;
+        async fn aggregate(
+            &self,
+            request: tonic::Request<super::AggregatePointsInternal>,
+        ) -> std::result::Result<tonic::Response<super::AggregateResponse>, tonic::Status>;
+        async fn count_distinct(
+            &self,
+            request: tonic::Request<super::CountPointsInternal>,
+        ) -> std::result::Result<tonic::Response<super::CountDistinctResponse>, tonic::Status>;
+        async fn search_text(
+            &self,
+            request: tonic::Request<super::SearchTextPointsInternal>,
+        ) -> std::result::Result<tonic::Response<super::SearchResponse>, tonic::Status>;
+        async fn text_stats(
+            &self,
+            request: tonic::Request<super::SearchTextPointsInternal>,
+        ) -> std::result::Result<tonic::Response<super::TextStatsResponse>, tonic::Status>;
         async fn recommend(
             &self,
             request: tonic::Request<super::RecommendPointsInternal>,
-        ) -> std::result::Result<
-            tonic::Response<super::RecommendResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::RecommendResponse>, tonic::Status>;
         async fn get(
             &self,
             request: tonic::Request<super::GetPointsInternal>,
@@ -7892,10 +8451,7 @@ pub mod points_internal_server {
                 max_encoding_message_size: None,
             }
         }
-        pub fn with_interceptor<F>(
-            inner: T,
-            interceptor: F,
-        ) -> InterceptedService<Self, F>
+        pub fn with_interceptor<F>(inner: T, interceptor: F) -> InterceptedService<Self, F>
         where
             F: tonic::service::Interceptor,
         {
@@ -7951,23 +8507,16 @@ pub mod points_internal_server {
                 "/qdrant.PointsInternal/Upsert" => {
                     #[allow(non_camel_case_types)]
                     struct UpsertSvc<T: PointsInternal>(pub Arc<T>);
-                    impl<
-                        T: PointsInternal,
-                    > tonic::server::UnaryService<super::UpsertPointsInternal>
-                    for UpsertSvc<T> {
+                    impl<T: PointsInternal> tonic::server::UnaryService<super::UpsertPointsInternal> for UpsertSvc<T> {
                         type Response = super::PointsOperationResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::UpsertPointsInternal>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as PointsInternal>::upsert(&inner, request).await
-                            };
+                            let fut =
+                                async move { <T as PointsInternal>::upsert(&inner, request).await };
                             Box::pin(fut)
                         }
                     }
@@ -7997,23 +8546,16 @@ pub mod points_internal_server {
                 "/qdrant.PointsInternal/Sync" => {
                     #[allow(non_camel_case_types)]
                     struct SyncSvc<T: PointsInternal>(pub Arc<T>);
-                    impl<
-                        T: PointsInternal,
-                    > tonic::server::UnaryService<super::SyncPointsInternal>
-                    for SyncSvc<T> {
+                    impl<T: PointsInternal> tonic::server::UnaryService<super::SyncPointsInternal> for SyncSvc<T> {
                         type Response = super::PointsOperationResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::SyncPointsInternal>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as PointsInternal>::sync(&inner, request).await
-                            };
+                            let fut =
+                                async move { <T as PointsInternal>::sync(&inner, request).await };
                             Box::pin(fut)
                         }
                     }
@@ -8043,23 +8585,16 @@ pub mod points_internal_server {
                 "/qdrant.PointsInternal/Delete" => {
                     #[allow(non_camel_case_types)]
                     struct DeleteSvc<T: PointsInternal>(pub Arc<T>);
-                    impl<
-                        T: PointsInternal,
-                    > tonic::server::UnaryService<super::DeletePointsInternal>
-                    for DeleteSvc<T> {
+                    impl<T: PointsInternal> tonic::server::UnaryService<super::DeletePointsInternal> for DeleteSvc<T> {
                         type Response = super::PointsOperationResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::DeletePointsInternal>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as PointsInternal>::delete(&inner, request).await
-                            };
+                            let fut =
+                                async move { <T as PointsInternal>::delete(&inner, request).await };
                             Box::pin(fut)
                         }
                     }
@@ -8089,15 +8624,12 @@ pub mod points_internal_server {
                 "/qdrant.PointsInternal/UpdateVectors" => {
                     #[allow(non_camel_case_types)]
                     struct UpdateVectorsSvc<T: PointsInternal>(pub Arc<T>);
-                    impl<
-                        T: PointsInternal,
-                    > tonic::server::UnaryService<super::UpdateVectorsInternal>
-                    for UpdateVectorsSvc<T> {
+                    impl<T: PointsInternal>
+                        tonic::server::UnaryService<super::UpdateVectorsInternal>
+                        for UpdateVectorsSvc<T>
+                    {
                         type Response = super::PointsOperationResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::UpdateVectorsInternal>,
@@ -8135,15 +8667,12 @@ pub mod points_internal_server {
                 "/qdrant.PointsInternal/DeleteVectors" => {
                     #[allow(non_camel_case_types)]
                     struct DeleteVectorsSvc<T: PointsInternal>(pub Arc<T>);
-                    impl<
-                        T: PointsInternal,
-                    > tonic::server::UnaryService<super::DeleteVectorsInternal>
-                    for DeleteVectorsSvc<T> {
+                    impl<T: PointsInternal>
+                        tonic::server::UnaryService<super::DeleteVectorsInternal>
+                        for DeleteVectorsSvc<T>
+                    {
                         type Response = super::PointsOperationResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::DeleteVectorsInternal>,
@@ -8181,15 +8710,12 @@ pub mod points_internal_server {
                 "/qdrant.PointsInternal/SetPayload" => {
                     #[allow(non_camel_case_types)]
                     struct SetPayloadSvc<T: PointsInternal>(pub Arc<T>);
-                    impl<
-                        T: PointsInternal,
-                    > tonic::server::UnaryService<super::SetPayloadPointsInternal>
-                    for SetPayloadSvc<T> {
+                    impl<T: PointsInternal>
+                        tonic::server::UnaryService<super::SetPayloadPointsInternal>
+                        for SetPayloadSvc<T>
+                    {
                         type Response = super::PointsOperationResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::SetPayloadPointsInternal>,
@@ -8227,23 +8753,19 @@ pub mod points_internal_server {
                 "/qdrant.PointsInternal/OverwritePayload" => {
                     #[allow(non_camel_case_types)]
                     struct OverwritePayloadSvc<T: PointsInternal>(pub Arc<T>);
-                    impl<
-                        T: PointsInternal,
-                    > tonic::server::UnaryService<super::SetPayloadPointsInternal>
-                    for OverwritePayloadSvc<T> {
+                    impl<T: PointsInternal>
+                        tonic::server::UnaryService<super::SetPayloadPointsInternal>
+                        for OverwritePayloadSvc<T>
+                    {
                         type Response = super::PointsOperationResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::SetPayloadPointsInternal>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as PointsInternal>::overwrite_payload(&inner, request)
-                                    .await
+                                <T as PointsInternal>::overwrite_payload(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -8274,15 +8796,12 @@ pub mod points_internal_server {
                 "/qdrant.PointsInternal/DeletePayload" => {
                     #[allow(non_camel_case_types)]
                     struct DeletePayloadSvc<T: PointsInternal>(pub Arc<T>);
-                    impl<
-                        T: PointsInternal,
-                    > tonic::server::UnaryService<super::DeletePayloadPointsInternal>
-                    for DeletePayloadSvc<T> {
+                    impl<T: PointsInternal>
+                        tonic::server::UnaryService<super::DeletePayloadPointsInternal>
+                        for DeletePayloadSvc<T>
+                    {
                         type Response = super::PointsOperationResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::DeletePayloadPointsInternal>,
@@ -8320,15 +8839,12 @@ pub mod points_internal_server {
                 "/qdrant.PointsInternal/ClearPayload" => {
                     #[allow(non_camel_case_types)]
                     struct ClearPayloadSvc<T: PointsInternal>(pub Arc<T>);
-                    impl<
-                        T: PointsInternal,
-                    > tonic::server::UnaryService<super::ClearPayloadPointsInternal>
-                    for ClearPayloadSvc<T> {
+                    impl<T: PointsInternal>
+                        tonic::server::UnaryService<super::ClearPayloadPointsInternal>
+                        for ClearPayloadSvc<T>
+                    {
                         type Response = super::PointsOperationResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::ClearPayloadPointsInternal>,
@@ -8366,26 +8882,19 @@ pub mod points_internal_server {
                 "/qdrant.PointsInternal/CreateFieldIndex" => {
                     #[allow(non_camel_case_types)]
                     struct CreateFieldIndexSvc<T: PointsInternal>(pub Arc<T>);
-                    impl<
-                        T: PointsInternal,
-                    > tonic::server::UnaryService<
-                        super::CreateFieldIndexCollectionInternal,
-                    > for CreateFieldIndexSvc<T> {
+                    impl<T: PointsInternal>
+                        tonic::server::UnaryService<super::CreateFieldIndexCollectionInternal>
+                        for CreateFieldIndexSvc<T>
+                    {
                         type Response = super::PointsOperationResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<
-                                super::CreateFieldIndexCollectionInternal,
-                            >,
+                            request: tonic::Request<super::CreateFieldIndexCollectionInternal>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as PointsInternal>::create_field_index(&inner, request)
-                                    .await
+                                <T as PointsInternal>::create_field_index(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -8416,27 +8925,184 @@ pub mod points_internal_server {
                 "/qdrant.PointsInternal/DeleteFieldIndex" => {
                     #[allow(non_camel_case_types)]
                     struct DeleteFieldIndexSvc<T: PointsInternal>(pub Arc<T>);
-                    impl<
-                        T: PointsInternal,
-                    > tonic::server::UnaryService<
-                        super::DeleteFieldIndexCollectionInternal,
-                    > for DeleteFieldIndexSvc<T> {
+                    impl<T: PointsInternal>
+                        tonic::server::UnaryService<super::DeleteFieldIndexCollectionInternal>
+                        for DeleteFieldIndexSvc<T>
+                    {
                         type Response = super::PointsOperationResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DeleteFieldIndexCollectionInternal>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PointsInternal>::delete_field_index(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = DeleteFieldIndexSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/qdrant.PointsInternal/Search" => {
+                    #[allow(non_camel_case_types)]
+                    struct SearchSvc<T: PointsInternal>(pub Arc<T>);
+                    impl<T: PointsInternal> tonic::server::UnaryService<super::SearchPointsInternal> for SearchSvc<T> {
+                        type Response = super::SearchResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SearchPointsInternal>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut =
+                                async move { <T as PointsInternal>::search(&inner, request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SearchSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/qdrant.PointsInternal/SearchBatch" => {
+                    #[allow(non_camel_case_types)]
+                    struct SearchBatchSvc<T: PointsInternal>(pub Arc<T>);
+                    impl<T: PointsInternal>
+                        tonic::server::UnaryService<super::SearchBatchPointsInternal>
+                        for SearchBatchSvc<T>
+                    {
+                        type Response = super::SearchBatchResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SearchBatchPointsInternal>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PointsInternal>::search_batch(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SearchBatchSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/qdrant.PointsInternal/CoreSearchBatch" => {
+                    #[allow(non_camel_case_types)]
+                    struct CoreSearchBatchSvc<T: PointsInternal>(pub Arc<T>);
+                    impl<T: PointsInternal>
+                        tonic::server::UnaryService<super::CoreSearchBatchPointsInternal>
+                        for CoreSearchBatchSvc<T>
+                    {
+                        type Response = super::SearchBatchResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CoreSearchBatchPointsInternal>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PointsInternal>::core_search_batch(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = CoreSearchBatchSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/qdrant.PointsInternal/Scroll" => {
+                    #[allow(non_camel_case_types)]
+                    struct ScrollSvc<T: PointsInternal>(pub Arc<T>);
+                    impl<T: PointsInternal> tonic::server::UnaryService<super::ScrollPointsInternal> for ScrollSvc<T> {
+                        type Response = super::ScrollResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<
-                                super::DeleteFieldIndexCollectionInternal,
-                            >,
+                            request: tonic::Request<super::ScrollPointsInternal>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as PointsInternal>::delete_field_index(&inner, request)
-                                    .await
-                            };
+                            let fut =
+                                async move { <T as PointsInternal>::scroll(&inner, request).await };
                             Box::pin(fut)
                         }
                     }
@@ -8447,7 +9113,7 @@ pub mod points_internal_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = DeleteFieldIndexSvc(inner);
+                        let method = ScrollSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -8463,26 +9129,19 @@ pub mod points_internal_server {
                     };
                     Box::pin(fut)
                 }
-                "/qdrant.PointsInternal/Search" => {
+                "/qdrant.PointsInternal/Count" => {
                     #[allow(non_camel_case_types)]
-                    struct SearchSvc<T: PointsInternal>(pub Arc<T>);
-                    impl<
-                        T: PointsInternal,
-                    > tonic::server::UnaryService<super::SearchPointsInternal>
-                    for SearchSvc<T> {
-                        type Response = super::SearchResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                    struct CountSvc<T: PointsInternal>(pub Arc<T>);
+                    impl<T: PointsInternal> tonic::server::UnaryService<super::CountPointsInternal> for CountSvc<T> {
+                        type Response = super::CountResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::SearchPointsInternal>,
+                            request: tonic::Request<super::CountPointsInternal>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as PointsInternal>::search(&inner, request).await
-                            };
+                            let fut =
+                                async move { <T as PointsInternal>::count(&inner, request).await };
                             Box::pin(fut)
                         }
                     }
@@ -8493,7 +9152,7 @@ pub mod points_internal_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = SearchSvc(inner);
+                        let method = CountSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -8509,25 +9168,22 @@ pub mod points_internal_server {
                     };
                     Box::pin(fut)
                 }
-                "/qdrant.PointsInternal/SearchBatch" => {
+                "/qdrant.PointsInternal/Aggregate" => {
                     #[allow(non_camel_case_types)]
-                    struct SearchBatchSvc<T: PointsInternal>(pub Arc<T>);
-                    impl<
-                        T: PointsInternal,
-                    > tonic::server::UnaryService<super::SearchBatchPointsInternal>
-                    for SearchBatchSvc<T> {
-                        type Response = super::SearchBatchResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                    struct AggregateSvc<T: PointsInternal>(pub Arc<T>);
+                    impl<T: PointsInternal>
+                        tonic::server::UnaryService<super::AggregatePointsInternal>
+                        for AggregateSvc<T>
+                    {
+                        type Response = super::AggregateResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::SearchBatchPointsInternal>,
+                            request: tonic::Request<super::AggregatePointsInternal>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as PointsInternal>::search_batch(&inner, request).await
+                                <T as PointsInternal>::aggregate(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -8539,7 +9195,7 @@ pub mod points_internal_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = SearchBatchSvc(inner);
+                        let method = AggregateSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -8555,26 +9211,21 @@ pub mod points_internal_server {
                     };
                     Box::pin(fut)
                 }
-                "/qdrant.PointsInternal/CoreSearchBatch" => {
+                "/qdrant.PointsInternal/CountDistinct" => {
                     #[allow(non_camel_case_types)]
-                    struct CoreSearchBatchSvc<T: PointsInternal>(pub Arc<T>);
-                    impl<
-                        T: PointsInternal,
-                    > tonic::server::UnaryService<super::CoreSearchBatchPointsInternal>
-                    for CoreSearchBatchSvc<T> {
-                        type Response = super::SearchBatchResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                    struct CountDistinctSvc<T: PointsInternal>(pub Arc<T>);
+                    impl<T: PointsInternal> tonic::server::UnaryService<super::CountPointsInternal>
+                        for CountDistinctSvc<T>
+                    {
+                        type Response = super::CountDistinctResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::CoreSearchBatchPointsInternal>,
+                            request: tonic::Request<super::CountPointsInternal>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as PointsInternal>::core_search_batch(&inner, request)
-                                    .await
+                                <T as PointsInternal>::count_distinct(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -8586,7 +9237,7 @@ pub mod points_internal_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = CoreSearchBatchSvc(inner);
+                        let method = CountDistinctSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -8602,25 +9253,22 @@ pub mod points_internal_server {
                     };
                     Box::pin(fut)
                 }
-                "/qdrant.PointsInternal/Scroll" => {
+                "/qdrant.PointsInternal/SearchText" => {
                     #[allow(non_camel_case_types)]
-                    struct ScrollSvc<T: PointsInternal>(pub Arc<T>);
-                    impl<
-                        T: PointsInternal,
-                    > tonic::server::UnaryService<super::ScrollPointsInternal>
-                    for ScrollSvc<T> {
-                        type Response = super::ScrollResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                    struct SearchTextSvc<T: PointsInternal>(pub Arc<T>);
+                    impl<T: PointsInternal>
+                        tonic::server::UnaryService<super::SearchTextPointsInternal>
+                        for SearchTextSvc<T>
+                    {
+                        type Response = super::SearchResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::ScrollPointsInternal>,
+                            request: tonic::Request<super::SearchTextPointsInternal>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as PointsInternal>::scroll(&inner, request).await
+                                <T as PointsInternal>::search_text(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -8632,7 +9280,7 @@ pub mod points_internal_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = ScrollSvc(inner);
+                        let method = SearchTextSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -8648,25 +9296,22 @@ pub mod points_internal_server {
                     };
                     Box::pin(fut)
                 }
-                "/qdrant.PointsInternal/Count" => {
+                "/qdrant.PointsInternal/TextStats" => {
                     #[allow(non_camel_case_types)]
-                    struct CountSvc<T: PointsInternal>(pub Arc<T>);
-                    impl<
-                        T: PointsInternal,
-                    > tonic::server::UnaryService<super::CountPointsInternal>
-                    for CountSvc<T> {
-                        type Response = super::CountResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                    struct TextStatsSvc<T: PointsInternal>(pub Arc<T>);
+                    impl<T: PointsInternal>
+                        tonic::server::UnaryService<super::SearchTextPointsInternal>
+                        for TextStatsSvc<T>
+                    {
+                        type Response = super::TextStatsResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::CountPointsInternal>,
+                            request: tonic::Request<super::SearchTextPointsInternal>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as PointsInternal>::count(&inner, request).await
+                                <T as PointsInternal>::text_stats(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -8678,7 +9323,7 @@ pub mod points_internal_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = CountSvc(inner);
+                        let method = TextStatsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -8697,15 +9342,12 @@ pub mod points_internal_server {
                 "/qdrant.PointsInternal/Recommend" => {
                     #[allow(non_camel_case_types)]
                     struct RecommendSvc<T: PointsInternal>(pub Arc<T>);
-                    impl<
-                        T: PointsInternal,
-                    > tonic::server::UnaryService<super::RecommendPointsInternal>
-                    for RecommendSvc<T> {
+                    impl<T: PointsInternal>
+                        tonic::server::UnaryService<super::RecommendPointsInternal>
+                        for RecommendSvc<T>
+                    {
                         type Response = super::RecommendResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::RecommendPointsInternal>,
@@ -8743,23 +9385,16 @@ pub mod points_internal_server {
                 "/qdrant.PointsInternal/Get" => {
                     #[allow(non_camel_case_types)]
                     struct GetSvc<T: PointsInternal>(pub Arc<T>);
-                    impl<
-                        T: PointsInternal,
-                    > tonic::server::UnaryService<super::GetPointsInternal>
-                    for GetSvc<T> {
+                    impl<T: PointsInternal> tonic::server::UnaryService<super::GetPointsInternal> for GetSvc<T> {
                         type Response = super::GetResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::GetPointsInternal>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as PointsInternal>::get(&inner, request).await
-                            };
+                            let fut =
+                                async move { <T as PointsInternal>::get(&inner, request).await };
                             Box::pin(fut)
                         }
                     }
@@ -8786,18 +9421,14 @@ pub mod points_internal_server {
                     };
                     Box::pin(fut)
                 }
-                _ => {
-                    Box::pin(async move {
-                        Ok(
-                            http::Response::builder()
-                                .status(200)
-                                .header("grpc-status", "12")
-                                .header("content-type", "application/grpc")
-                                .body(empty_body())
-                                .unwrap(),
-                        )
-                    })
-                }
+                _ => Box::pin(async move {
+                    Ok(http::Response::builder()
+                        .status(200)
+                        .header("grpc-status", "12")
+                        .header("content-type", "application/grpc")
+                        .body(empty_body())
+                        .unwrap())
+                }),
             }
         }
     }
@@ -8867,8 +9498,8 @@ pub struct WaitOnConsensusCommitResponse {
 /// Generated client implementations.
 pub mod qdrant_internal_client {
     #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
-    use tonic::codegen::*;
     use tonic::codegen::http::Uri;
+    use tonic::codegen::*;
     #[derive(Debug, Clone)]
     pub struct QdrantInternalClient<T> {
         inner: tonic::client::Grpc<T>,
@@ -8912,9 +9543,8 @@ pub mod qdrant_internal_client {
                     <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
                 >,
             >,
-            <T as tonic::codegen::Service<
-                http::Request<tonic::body::BoxBody>,
-            >>::Error: Into<StdError> + Send + Sync,
+            <T as tonic::codegen::Service<http::Request<tonic::body::BoxBody>>>::Error:
+                Into<StdError> + Send + Sync,
         {
             QdrantInternalClient::new(InterceptedService::new(inner, interceptor))
         }
@@ -8954,26 +9584,22 @@ pub mod qdrant_internal_client {
         pub async fn get_consensus_commit(
             &mut self,
             request: impl tonic::IntoRequest<super::GetConsensusCommitRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::GetConsensusCommitResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::GetConsensusCommitResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.QdrantInternal/GetConsensusCommit",
-            );
+            let path =
+                http::uri::PathAndQuery::from_static("/qdrant.QdrantInternal/GetConsensusCommit");
             let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(GrpcMethod::new("qdrant.QdrantInternal", "GetConsensusCommit"));
+            req.extensions_mut().insert(GrpcMethod::new(
+                "qdrant.QdrantInternal",
+                "GetConsensusCommit",
+            ));
             self.inner.unary(req, path, codec).await
         }
         ///
@@ -8981,28 +9607,23 @@ pub mod qdrant_internal_client {
         pub async fn wait_on_consensus_commit(
             &mut self,
             request: impl tonic::IntoRequest<super::WaitOnConsensusCommitRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::WaitOnConsensusCommitResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::WaitOnConsensusCommitResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
                 "/qdrant.QdrantInternal/WaitOnConsensusCommit",
             );
             let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(
-                    GrpcMethod::new("qdrant.QdrantInternal", "WaitOnConsensusCommit"),
-                );
+            req.extensions_mut().insert(GrpcMethod::new(
+                "qdrant.QdrantInternal",
+                "WaitOnConsensusCommit",
+            ));
             self.inner.unary(req, path, codec).await
         }
     }
@@ -9019,19 +9640,13 @@ pub mod qdrant_internal_server {
         async fn get_consensus_commit(
             &self,
             request: tonic::Request<super::GetConsensusCommitRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::GetConsensusCommitResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::GetConsensusCommitResponse>, tonic::Status>;
         ///
         /// Wait until the target node reached the given commit ID.
         async fn wait_on_consensus_commit(
             &self,
             request: tonic::Request<super::WaitOnConsensusCommitRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::WaitOnConsensusCommitResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::WaitOnConsensusCommitResponse>, tonic::Status>;
     }
     #[derive(Debug)]
     pub struct QdrantInternalServer<T: QdrantInternal> {
@@ -9056,10 +9671,7 @@ pub mod qdrant_internal_server {
                 max_encoding_message_size: None,
             }
         }
-        pub fn with_interceptor<F>(
-            inner: T,
-            interceptor: F,
-        ) -> InterceptedService<Self, F>
+        pub fn with_interceptor<F>(inner: T, interceptor: F) -> InterceptedService<Self, F>
         where
             F: tonic::service::Interceptor,
         {
@@ -9115,23 +9727,19 @@ pub mod qdrant_internal_server {
                 "/qdrant.QdrantInternal/GetConsensusCommit" => {
                     #[allow(non_camel_case_types)]
                     struct GetConsensusCommitSvc<T: QdrantInternal>(pub Arc<T>);
-                    impl<
-                        T: QdrantInternal,
-                    > tonic::server::UnaryService<super::GetConsensusCommitRequest>
-                    for GetConsensusCommitSvc<T> {
+                    impl<T: QdrantInternal>
+                        tonic::server::UnaryService<super::GetConsensusCommitRequest>
+                        for GetConsensusCommitSvc<T>
+                    {
                         type Response = super::GetConsensusCommitResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::GetConsensusCommitRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as QdrantInternal>::get_consensus_commit(&inner, request)
-                                    .await
+                                <T as QdrantInternal>::get_consensus_commit(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -9162,25 +9770,19 @@ pub mod qdrant_internal_server {
                 "/qdrant.QdrantInternal/WaitOnConsensusCommit" => {
                     #[allow(non_camel_case_types)]
                     struct WaitOnConsensusCommitSvc<T: QdrantInternal>(pub Arc<T>);
-                    impl<
-                        T: QdrantInternal,
-                    > tonic::server::UnaryService<super::WaitOnConsensusCommitRequest>
-                    for WaitOnConsensusCommitSvc<T> {
+                    impl<T: QdrantInternal>
+                        tonic::server::UnaryService<super::WaitOnConsensusCommitRequest>
+                        for WaitOnConsensusCommitSvc<T>
+                    {
                         type Response = super::WaitOnConsensusCommitResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::WaitOnConsensusCommitRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as QdrantInternal>::wait_on_consensus_commit(
-                                        &inner,
-                                        request,
-                                    )
+                                <T as QdrantInternal>::wait_on_consensus_commit(&inner, request)
                                     .await
                             };
                             Box::pin(fut)
@@ -9209,18 +9811,14 @@ pub mod qdrant_internal_server {
                     };
                     Box::pin(fut)
                 }
-                _ => {
-                    Box::pin(async move {
-                        Ok(
-                            http::Response::builder()
-                                .status(200)
-                                .header("grpc-status", "12")
-                                .header("content-type", "application/grpc")
-                                .body(empty_body())
-                                .unwrap(),
-                        )
-                    })
-                }
+                _ => Box::pin(async move {
+                    Ok(http::Response::builder()
+                        .status(200)
+                        .header("grpc-status", "12")
+                        .header("content-type", "application/grpc")
+                        .body(empty_body())
+                        .unwrap())
+                }),
             }
         }
     }
@@ -9274,9 +9872,19 @@ pub struct Peer {
     pub uri: ::prost::alloc::string::String,
     #[prost(uint64, tag = "2")]
     pub id: u64,
+    #[prost(message, optional, tag = "3")]
+    pub labels: ::core::option::Option<PeerLabels>,
 }
 #[derive(serde::Serialize)]
-#[derive(validator::Validate)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PeerLabels {
+    #[prost(string, optional, tag = "1")]
+    pub zone: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "2")]
+    pub rack: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(serde::Serialize, validator::Validate)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AddPeerToKnownMessage {
@@ -9288,6 +9896,8 @@ pub struct AddPeerToKnownMessage {
     pub port: ::core::option::Option<u32>,
     #[prost(uint64, tag = "3")]
     pub id: u64,
+    #[prost(message, optional, tag = "4")]
+    pub labels: ::core::option::Option<PeerLabels>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -9306,8 +9916,8 @@ pub struct Uri {
 /// Generated client implementations.
 pub mod raft_client {
     #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
-    use tonic::codegen::*;
     use tonic::codegen::http::Uri;
+    use tonic::codegen::*;
     #[derive(Debug, Clone)]
     pub struct RaftClient<T> {
         inner: tonic::client::Grpc<T>,
@@ -9338,10 +9948,7 @@ pub mod raft_client {
             let inner = tonic::client::Grpc::with_origin(inner, origin);
             Self { inner }
         }
-        pub fn with_interceptor<F>(
-            inner: T,
-            interceptor: F,
-        ) -> RaftClient<InterceptedService<T, F>>
+        pub fn with_interceptor<F>(inner: T, interceptor: F) -> RaftClient<InterceptedService<T, F>>
         where
             F: tonic::service::Interceptor,
             T::ResponseBody: Default,
@@ -9351,9 +9958,8 @@ pub mod raft_client {
                     <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
                 >,
             >,
-            <T as tonic::codegen::Service<
-                http::Request<tonic::body::BoxBody>,
-            >>::Error: Into<StdError> + Send + Sync,
+            <T as tonic::codegen::Service<http::Request<tonic::body::BoxBody>>>::Error:
+                Into<StdError> + Send + Sync,
         {
             RaftClient::new(InterceptedService::new(inner, interceptor))
         }
@@ -9393,19 +9999,17 @@ pub mod raft_client {
             &mut self,
             request: impl tonic::IntoRequest<super::RaftMessage>,
         ) -> std::result::Result<tonic::Response<()>, tonic::Status> {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static("/qdrant.Raft/Send");
             let mut req = request.into_request();
-            req.extensions_mut().insert(GrpcMethod::new("qdrant.Raft", "Send"));
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Raft", "Send"));
             self.inner.unary(req, path, codec).await
         }
         /// Send to bootstrap peer
@@ -9414,19 +10018,17 @@ pub mod raft_client {
             &mut self,
             request: impl tonic::IntoRequest<super::PeerId>,
         ) -> std::result::Result<tonic::Response<super::Uri>, tonic::Status> {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static("/qdrant.Raft/WhoIs");
             let mut req = request.into_request();
-            req.extensions_mut().insert(GrpcMethod::new("qdrant.Raft", "WhoIs"));
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Raft", "WhoIs"));
             self.inner.unary(req, path, codec).await
         }
         /// Send to bootstrap peer
@@ -9436,19 +10038,14 @@ pub mod raft_client {
             &mut self,
             request: impl tonic::IntoRequest<super::AddPeerToKnownMessage>,
         ) -> std::result::Result<tonic::Response<super::AllPeers>, tonic::Status> {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.Raft/AddPeerToKnown",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.Raft/AddPeerToKnown");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("qdrant.Raft", "AddPeerToKnown"));
@@ -9463,19 +10060,14 @@ pub mod raft_client {
             &mut self,
             request: impl tonic::IntoRequest<super::PeerId>,
         ) -> std::result::Result<tonic::Response<()>, tonic::Status> {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.Raft/AddPeerAsParticipant",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.Raft/AddPeerAsParticipant");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("qdrant.Raft", "AddPeerAsParticipant"));
@@ -9541,10 +10133,7 @@ pub mod raft_server {
                 max_encoding_message_size: None,
             }
         }
-        pub fn with_interceptor<F>(
-            inner: T,
-            interceptor: F,
-        ) -> InterceptedService<Self, F>
+        pub fn with_interceptor<F>(inner: T, interceptor: F) -> InterceptedService<Self, F>
         where
             F: tonic::service::Interceptor,
         {
@@ -9600,21 +10189,15 @@ pub mod raft_server {
                 "/qdrant.Raft/Send" => {
                     #[allow(non_camel_case_types)]
                     struct SendSvc<T: Raft>(pub Arc<T>);
-                    impl<T: Raft> tonic::server::UnaryService<super::RaftMessage>
-                    for SendSvc<T> {
+                    impl<T: Raft> tonic::server::UnaryService<super::RaftMessage> for SendSvc<T> {
                         type Response = ();
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::RaftMessage>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as Raft>::send(&inner, request).await
-                            };
+                            let fut = async move { <T as Raft>::send(&inner, request).await };
                             Box::pin(fut)
                         }
                     }
@@ -9644,21 +10227,12 @@ pub mod raft_server {
                 "/qdrant.Raft/WhoIs" => {
                     #[allow(non_camel_case_types)]
                     struct WhoIsSvc<T: Raft>(pub Arc<T>);
-                    impl<T: Raft> tonic::server::UnaryService<super::PeerId>
-                    for WhoIsSvc<T> {
+                    impl<T: Raft> tonic::server::UnaryService<super::PeerId> for WhoIsSvc<T> {
                         type Response = super::Uri;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
-                        fn call(
-                            &mut self,
-                            request: tonic::Request<super::PeerId>,
-                        ) -> Self::Future {
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::PeerId>) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as Raft>::who_is(&inner, request).await
-                            };
+                            let fut = async move { <T as Raft>::who_is(&inner, request).await };
                             Box::pin(fut)
                         }
                     }
@@ -9688,15 +10262,9 @@ pub mod raft_server {
                 "/qdrant.Raft/AddPeerToKnown" => {
                     #[allow(non_camel_case_types)]
                     struct AddPeerToKnownSvc<T: Raft>(pub Arc<T>);
-                    impl<
-                        T: Raft,
-                    > tonic::server::UnaryService<super::AddPeerToKnownMessage>
-                    for AddPeerToKnownSvc<T> {
+                    impl<T: Raft> tonic::server::UnaryService<super::AddPeerToKnownMessage> for AddPeerToKnownSvc<T> {
                         type Response = super::AllPeers;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::AddPeerToKnownMessage>,
@@ -9734,17 +10302,10 @@ pub mod raft_server {
                 "/qdrant.Raft/AddPeerAsParticipant" => {
                     #[allow(non_camel_case_types)]
                     struct AddPeerAsParticipantSvc<T: Raft>(pub Arc<T>);
-                    impl<T: Raft> tonic::server::UnaryService<super::PeerId>
-                    for AddPeerAsParticipantSvc<T> {
+                    impl<T: Raft> tonic::server::UnaryService<super::PeerId> for AddPeerAsParticipantSvc<T> {
                         type Response = ();
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
-                        fn call(
-                            &mut self,
-                            request: tonic::Request<super::PeerId>,
-                        ) -> Self::Future {
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::PeerId>) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
                                 <T as Raft>::add_peer_as_participant(&inner, request).await
@@ -9775,18 +10336,14 @@ pub mod raft_server {
                     };
                     Box::pin(fut)
                 }
-                _ => {
-                    Box::pin(async move {
-                        Ok(
-                            http::Response::builder()
-                                .status(200)
-                                .header("grpc-status", "12")
-                                .header("content-type", "application/grpc")
-                                .body(empty_body())
-                                .unwrap(),
-                        )
-                    })
-                }
+                _ => Box::pin(async move {
+                    Ok(http::Response::builder()
+                        .status(200)
+                        .header("grpc-status", "12")
+                        .header("content-type", "application/grpc")
+                        .body(empty_body())
+                        .unwrap())
+                }),
             }
         }
     }
@@ -9816,18 +10373,15 @@ pub mod raft_server {
         const NAME: &'static str = "qdrant.Raft";
     }
 }
-#[derive(serde::Serialize)]
-#[derive(validator::Validate)]
+#[derive(serde::Serialize, validator::Validate)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateFullSnapshotRequest {}
-#[derive(serde::Serialize)]
-#[derive(validator::Validate)]
+#[derive(serde::Serialize, validator::Validate)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListFullSnapshotsRequest {}
-#[derive(serde::Serialize)]
-#[derive(validator::Validate)]
+#[derive(serde::Serialize, validator::Validate)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DeleteFullSnapshotRequest {
@@ -9836,8 +10390,7 @@ pub struct DeleteFullSnapshotRequest {
     #[validate(length(min = 1))]
     pub snapshot_name: ::prost::alloc::string::String,
 }
-#[derive(serde::Serialize)]
-#[derive(validator::Validate)]
+#[derive(serde::Serialize, validator::Validate)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateSnapshotRequest {
@@ -9846,8 +10399,7 @@ pub struct CreateSnapshotRequest {
     #[validate(length(min = 1, max = 255))]
     pub collection_name: ::prost::alloc::string::String,
 }
-#[derive(serde::Serialize)]
-#[derive(validator::Validate)]
+#[derive(serde::Serialize, validator::Validate)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListSnapshotsRequest {
@@ -9856,8 +10408,7 @@ pub struct ListSnapshotsRequest {
     #[validate(length(min = 1, max = 255))]
     pub collection_name: ::prost::alloc::string::String,
 }
-#[derive(serde::Serialize)]
-#[derive(validator::Validate)]
+#[derive(serde::Serialize, validator::Validate)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DeleteSnapshotRequest {
@@ -9916,8 +10467,8 @@ pub struct DeleteSnapshotResponse {
 /// Generated client implementations.
 pub mod snapshots_client {
     #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
-    use tonic::codegen::*;
     use tonic::codegen::http::Uri;
+    use tonic::codegen::*;
     #[derive(Debug, Clone)]
     pub struct SnapshotsClient<T> {
         inner: tonic::client::Grpc<T>,
@@ -9961,9 +10512,8 @@ pub mod snapshots_client {
                     <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
                 >,
             >,
-            <T as tonic::codegen::Service<
-                http::Request<tonic::body::BoxBody>,
-            >>::Error: Into<StdError> + Send + Sync,
+            <T as tonic::codegen::Service<http::Request<tonic::body::BoxBody>>>::Error:
+                Into<StdError> + Send + Sync,
         {
             SnapshotsClient::new(InterceptedService::new(inner, interceptor))
         }
@@ -10003,23 +10553,19 @@ pub mod snapshots_client {
         pub async fn create(
             &mut self,
             request: impl tonic::IntoRequest<super::CreateSnapshotRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::CreateSnapshotResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::CreateSnapshotResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static("/qdrant.Snapshots/Create");
             let mut req = request.into_request();
-            req.extensions_mut().insert(GrpcMethod::new("qdrant.Snapshots", "Create"));
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Snapshots", "Create"));
             self.inner.unary(req, path, codec).await
         }
         ///
@@ -10027,23 +10573,19 @@ pub mod snapshots_client {
         pub async fn list(
             &mut self,
             request: impl tonic::IntoRequest<super::ListSnapshotsRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::ListSnapshotsResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::ListSnapshotsResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static("/qdrant.Snapshots/List");
             let mut req = request.into_request();
-            req.extensions_mut().insert(GrpcMethod::new("qdrant.Snapshots", "List"));
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Snapshots", "List"));
             self.inner.unary(req, path, codec).await
         }
         ///
@@ -10051,23 +10593,19 @@ pub mod snapshots_client {
         pub async fn delete(
             &mut self,
             request: impl tonic::IntoRequest<super::DeleteSnapshotRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::DeleteSnapshotResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::DeleteSnapshotResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static("/qdrant.Snapshots/Delete");
             let mut req = request.into_request();
-            req.extensions_mut().insert(GrpcMethod::new("qdrant.Snapshots", "Delete"));
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Snapshots", "Delete"));
             self.inner.unary(req, path, codec).await
         }
         ///
@@ -10075,23 +10613,16 @@ pub mod snapshots_client {
         pub async fn create_full(
             &mut self,
             request: impl tonic::IntoRequest<super::CreateFullSnapshotRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::CreateSnapshotResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::CreateSnapshotResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.Snapshots/CreateFull",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.Snapshots/CreateFull");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("qdrant.Snapshots", "CreateFull"));
@@ -10102,25 +10633,19 @@ pub mod snapshots_client {
         pub async fn list_full(
             &mut self,
             request: impl tonic::IntoRequest<super::ListFullSnapshotsRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::ListSnapshotsResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::ListSnapshotsResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.Snapshots/ListFull",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.Snapshots/ListFull");
             let mut req = request.into_request();
-            req.extensions_mut().insert(GrpcMethod::new("qdrant.Snapshots", "ListFull"));
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Snapshots", "ListFull"));
             self.inner.unary(req, path, codec).await
         }
         ///
@@ -10128,23 +10653,16 @@ pub mod snapshots_client {
         pub async fn delete_full(
             &mut self,
             request: impl tonic::IntoRequest<super::DeleteFullSnapshotRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::DeleteSnapshotResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::DeleteSnapshotResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.Snapshots/DeleteFull",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.Snapshots/DeleteFull");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("qdrant.Snapshots", "DeleteFull"));
@@ -10164,55 +10682,37 @@ pub mod snapshots_server {
         async fn create(
             &self,
             request: tonic::Request<super::CreateSnapshotRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::CreateSnapshotResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::CreateSnapshotResponse>, tonic::Status>;
         ///
         /// List collection snapshots
         async fn list(
             &self,
             request: tonic::Request<super::ListSnapshotsRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::ListSnapshotsResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::ListSnapshotsResponse>, tonic::Status>;
         ///
         /// Delete collection snapshot
         async fn delete(
             &self,
             request: tonic::Request<super::DeleteSnapshotRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::DeleteSnapshotResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::DeleteSnapshotResponse>, tonic::Status>;
         ///
         /// Create full storage snapshot
         async fn create_full(
             &self,
             request: tonic::Request<super::CreateFullSnapshotRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::CreateSnapshotResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::CreateSnapshotResponse>, tonic::Status>;
         ///
         /// List full storage snapshots
         async fn list_full(
             &self,
             request: tonic::Request<super::ListFullSnapshotsRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::ListSnapshotsResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::ListSnapshotsResponse>, tonic::Status>;
         ///
         /// Delete full storage snapshot
         async fn delete_full(
             &self,
             request: tonic::Request<super::DeleteFullSnapshotRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::DeleteSnapshotResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::DeleteSnapshotResponse>, tonic::Status>;
     }
     #[derive(Debug)]
     pub struct SnapshotsServer<T: Snapshots> {
@@ -10237,10 +10737,7 @@ pub mod snapshots_server {
                 max_encoding_message_size: None,
             }
         }
-        pub fn with_interceptor<F>(
-            inner: T,
-            interceptor: F,
-        ) -> InterceptedService<Self, F>
+        pub fn with_interceptor<F>(inner: T, interceptor: F) -> InterceptedService<Self, F>
         where
             F: tonic::service::Interceptor,
         {
@@ -10296,23 +10793,16 @@ pub mod snapshots_server {
                 "/qdrant.Snapshots/Create" => {
                     #[allow(non_camel_case_types)]
                     struct CreateSvc<T: Snapshots>(pub Arc<T>);
-                    impl<
-                        T: Snapshots,
-                    > tonic::server::UnaryService<super::CreateSnapshotRequest>
-                    for CreateSvc<T> {
+                    impl<T: Snapshots> tonic::server::UnaryService<super::CreateSnapshotRequest> for CreateSvc<T> {
                         type Response = super::CreateSnapshotResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::CreateSnapshotRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as Snapshots>::create(&inner, request).await
-                            };
+                            let fut =
+                                async move { <T as Snapshots>::create(&inner, request).await };
                             Box::pin(fut)
                         }
                     }
@@ -10342,23 +10832,15 @@ pub mod snapshots_server {
                 "/qdrant.Snapshots/List" => {
                     #[allow(non_camel_case_types)]
                     struct ListSvc<T: Snapshots>(pub Arc<T>);
-                    impl<
-                        T: Snapshots,
-                    > tonic::server::UnaryService<super::ListSnapshotsRequest>
-                    for ListSvc<T> {
+                    impl<T: Snapshots> tonic::server::UnaryService<super::ListSnapshotsRequest> for ListSvc<T> {
                         type Response = super::ListSnapshotsResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::ListSnapshotsRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as Snapshots>::list(&inner, request).await
-                            };
+                            let fut = async move { <T as Snapshots>::list(&inner, request).await };
                             Box::pin(fut)
                         }
                     }
@@ -10388,23 +10870,16 @@ pub mod snapshots_server {
                 "/qdrant.Snapshots/Delete" => {
                     #[allow(non_camel_case_types)]
                     struct DeleteSvc<T: Snapshots>(pub Arc<T>);
-                    impl<
-                        T: Snapshots,
-                    > tonic::server::UnaryService<super::DeleteSnapshotRequest>
-                    for DeleteSvc<T> {
+                    impl<T: Snapshots> tonic::server::UnaryService<super::DeleteSnapshotRequest> for DeleteSvc<T> {
                         type Response = super::DeleteSnapshotResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::DeleteSnapshotRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as Snapshots>::delete(&inner, request).await
-                            };
+                            let fut =
+                                async move { <T as Snapshots>::delete(&inner, request).await };
                             Box::pin(fut)
                         }
                     }
@@ -10434,23 +10909,18 @@ pub mod snapshots_server {
                 "/qdrant.Snapshots/CreateFull" => {
                     #[allow(non_camel_case_types)]
                     struct CreateFullSvc<T: Snapshots>(pub Arc<T>);
-                    impl<
-                        T: Snapshots,
-                    > tonic::server::UnaryService<super::CreateFullSnapshotRequest>
-                    for CreateFullSvc<T> {
+                    impl<T: Snapshots> tonic::server::UnaryService<super::CreateFullSnapshotRequest>
+                        for CreateFullSvc<T>
+                    {
                         type Response = super::CreateSnapshotResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::CreateFullSnapshotRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as Snapshots>::create_full(&inner, request).await
-                            };
+                            let fut =
+                                async move { <T as Snapshots>::create_full(&inner, request).await };
                             Box::pin(fut)
                         }
                     }
@@ -10480,23 +10950,16 @@ pub mod snapshots_server {
                 "/qdrant.Snapshots/ListFull" => {
                     #[allow(non_camel_case_types)]
                     struct ListFullSvc<T: Snapshots>(pub Arc<T>);
-                    impl<
-                        T: Snapshots,
-                    > tonic::server::UnaryService<super::ListFullSnapshotsRequest>
-                    for ListFullSvc<T> {
+                    impl<T: Snapshots> tonic::server::UnaryService<super::ListFullSnapshotsRequest> for ListFullSvc<T> {
                         type Response = super::ListSnapshotsResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::ListFullSnapshotsRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as Snapshots>::list_full(&inner, request).await
-                            };
+                            let fut =
+                                async move { <T as Snapshots>::list_full(&inner, request).await };
                             Box::pin(fut)
                         }
                     }
@@ -10526,23 +10989,18 @@ pub mod snapshots_server {
                 "/qdrant.Snapshots/DeleteFull" => {
                     #[allow(non_camel_case_types)]
                     struct DeleteFullSvc<T: Snapshots>(pub Arc<T>);
-                    impl<
-                        T: Snapshots,
-                    > tonic::server::UnaryService<super::DeleteFullSnapshotRequest>
-                    for DeleteFullSvc<T> {
+                    impl<T: Snapshots> tonic::server::UnaryService<super::DeleteFullSnapshotRequest>
+                        for DeleteFullSvc<T>
+                    {
                         type Response = super::DeleteSnapshotResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::DeleteFullSnapshotRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as Snapshots>::delete_full(&inner, request).await
-                            };
+                            let fut =
+                                async move { <T as Snapshots>::delete_full(&inner, request).await };
                             Box::pin(fut)
                         }
                     }
@@ -10569,18 +11027,14 @@ pub mod snapshots_server {
                     };
                     Box::pin(fut)
                 }
-                _ => {
-                    Box::pin(async move {
-                        Ok(
-                            http::Response::builder()
-                                .status(200)
-                                .header("grpc-status", "12")
-                                .header("content-type", "application/grpc")
-                                .body(empty_body())
-                                .unwrap(),
-                        )
-                    })
-                }
+                _ => Box::pin(async move {
+                    Ok(http::Response::builder()
+                        .status(200)
+                        .header("grpc-status", "12")
+                        .header("content-type", "application/grpc")
+                        .body(empty_body())
+                        .unwrap())
+                }),
             }
         }
     }
@@ -10610,8 +11064,7 @@ pub mod snapshots_server {
         const NAME: &'static str = "qdrant.Snapshots";
     }
 }
-#[derive(serde::Serialize)]
-#[derive(validator::Validate)]
+#[derive(serde::Serialize, validator::Validate)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateShardSnapshotRequest {
@@ -10623,8 +11076,7 @@ pub struct CreateShardSnapshotRequest {
     #[prost(uint32, tag = "2")]
     pub shard_id: u32,
 }
-#[derive(serde::Serialize)]
-#[derive(validator::Validate)]
+#[derive(serde::Serialize, validator::Validate)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListShardSnapshotsRequest {
@@ -10636,8 +11088,7 @@ pub struct ListShardSnapshotsRequest {
     #[prost(uint32, tag = "2")]
     pub shard_id: u32,
 }
-#[derive(serde::Serialize)]
-#[derive(validator::Validate)]
+#[derive(serde::Serialize, validator::Validate)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DeleteShardSnapshotRequest {
@@ -10653,8 +11104,7 @@ pub struct DeleteShardSnapshotRequest {
     #[validate(length(min = 1))]
     pub snapshot_name: ::prost::alloc::string::String,
 }
-#[derive(serde::Serialize)]
-#[derive(validator::Validate)]
+#[derive(serde::Serialize, validator::Validate)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RecoverShardSnapshotRequest {
@@ -10701,8 +11151,9 @@ pub struct RecoverSnapshotResponse {
     #[prost(double, tag = "1")]
     pub time: f64,
 }
-#[derive(serde::Serialize)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(
+    serde::Serialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration,
+)]
 #[repr(i32)]
 pub enum ShardSnapshotPriority {
     /// Restore snapshot without *any* additional synchronization
@@ -10741,8 +11192,8 @@ impl ShardSnapshotPriority {
 /// Generated client implementations.
 pub mod shard_snapshots_client {
     #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
-    use tonic::codegen::*;
     use tonic::codegen::http::Uri;
+    use tonic::codegen::*;
     #[derive(Debug, Clone)]
     pub struct ShardSnapshotsClient<T> {
         inner: tonic::client::Grpc<T>,
@@ -10786,9 +11237,8 @@ pub mod shard_snapshots_client {
                     <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
                 >,
             >,
-            <T as tonic::codegen::Service<
-                http::Request<tonic::body::BoxBody>,
-            >>::Error: Into<StdError> + Send + Sync,
+            <T as tonic::codegen::Service<http::Request<tonic::body::BoxBody>>>::Error:
+                Into<StdError> + Send + Sync,
         {
             ShardSnapshotsClient::new(InterceptedService::new(inner, interceptor))
         }
@@ -10828,23 +11278,16 @@ pub mod shard_snapshots_client {
         pub async fn create(
             &mut self,
             request: impl tonic::IntoRequest<super::CreateShardSnapshotRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::CreateSnapshotResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::CreateSnapshotResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.ShardSnapshots/Create",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.ShardSnapshots/Create");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("qdrant.ShardSnapshots", "Create"));
@@ -10855,23 +11298,16 @@ pub mod shard_snapshots_client {
         pub async fn list(
             &mut self,
             request: impl tonic::IntoRequest<super::ListShardSnapshotsRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::ListSnapshotsResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::ListSnapshotsResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.ShardSnapshots/List",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.ShardSnapshots/List");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("qdrant.ShardSnapshots", "List"));
@@ -10882,23 +11318,16 @@ pub mod shard_snapshots_client {
         pub async fn delete(
             &mut self,
             request: impl tonic::IntoRequest<super::DeleteShardSnapshotRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::DeleteSnapshotResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::DeleteSnapshotResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.ShardSnapshots/Delete",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.ShardSnapshots/Delete");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("qdrant.ShardSnapshots", "Delete"));
@@ -10909,23 +11338,16 @@ pub mod shard_snapshots_client {
         pub async fn recover(
             &mut self,
             request: impl tonic::IntoRequest<super::RecoverShardSnapshotRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::RecoverSnapshotResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::RecoverSnapshotResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.ShardSnapshots/Recover",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.ShardSnapshots/Recover");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("qdrant.ShardSnapshots", "Recover"));
@@ -10945,37 +11367,25 @@ pub mod shard_snapshots_server {
         async fn create(
             &self,
             request: tonic::Request<super::CreateShardSnapshotRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::CreateSnapshotResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::CreateSnapshotResponse>, tonic::Status>;
         ///
         /// List shard snapshots
         async fn list(
             &self,
             request: tonic::Request<super::ListShardSnapshotsRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::ListSnapshotsResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::ListSnapshotsResponse>, tonic::Status>;
         ///
         /// Delete shard snapshot
         async fn delete(
             &self,
             request: tonic::Request<super::DeleteShardSnapshotRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::DeleteSnapshotResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::DeleteSnapshotResponse>, tonic::Status>;
         ///
         /// Recover shard snapshot
         async fn recover(
             &self,
             request: tonic::Request<super::RecoverShardSnapshotRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::RecoverSnapshotResponse>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::RecoverSnapshotResponse>, tonic::Status>;
     }
     #[derive(Debug)]
     pub struct ShardSnapshotsServer<T: ShardSnapshots> {
@@ -11000,10 +11410,7 @@ pub mod shard_snapshots_server {
                 max_encoding_message_size: None,
             }
         }
-        pub fn with_interceptor<F>(
-            inner: T,
-            interceptor: F,
-        ) -> InterceptedService<Self, F>
+        pub fn with_interceptor<F>(inner: T, interceptor: F) -> InterceptedService<Self, F>
         where
             F: tonic::service::Interceptor,
         {
@@ -11059,23 +11466,19 @@ pub mod shard_snapshots_server {
                 "/qdrant.ShardSnapshots/Create" => {
                     #[allow(non_camel_case_types)]
                     struct CreateSvc<T: ShardSnapshots>(pub Arc<T>);
-                    impl<
-                        T: ShardSnapshots,
-                    > tonic::server::UnaryService<super::CreateShardSnapshotRequest>
-                    for CreateSvc<T> {
+                    impl<T: ShardSnapshots>
+                        tonic::server::UnaryService<super::CreateShardSnapshotRequest>
+                        for CreateSvc<T>
+                    {
                         type Response = super::CreateSnapshotResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::CreateShardSnapshotRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as ShardSnapshots>::create(&inner, request).await
-                            };
+                            let fut =
+                                async move { <T as ShardSnapshots>::create(&inner, request).await };
                             Box::pin(fut)
                         }
                     }
@@ -11105,23 +11508,19 @@ pub mod shard_snapshots_server {
                 "/qdrant.ShardSnapshots/List" => {
                     #[allow(non_camel_case_types)]
                     struct ListSvc<T: ShardSnapshots>(pub Arc<T>);
-                    impl<
-                        T: ShardSnapshots,
-                    > tonic::server::UnaryService<super::ListShardSnapshotsRequest>
-                    for ListSvc<T> {
+                    impl<T: ShardSnapshots>
+                        tonic::server::UnaryService<super::ListShardSnapshotsRequest>
+                        for ListSvc<T>
+                    {
                         type Response = super::ListSnapshotsResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::ListShardSnapshotsRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as ShardSnapshots>::list(&inner, request).await
-                            };
+                            let fut =
+                                async move { <T as ShardSnapshots>::list(&inner, request).await };
                             Box::pin(fut)
                         }
                     }
@@ -11151,23 +11550,19 @@ pub mod shard_snapshots_server {
                 "/qdrant.ShardSnapshots/Delete" => {
                     #[allow(non_camel_case_types)]
                     struct DeleteSvc<T: ShardSnapshots>(pub Arc<T>);
-                    impl<
-                        T: ShardSnapshots,
-                    > tonic::server::UnaryService<super::DeleteShardSnapshotRequest>
-                    for DeleteSvc<T> {
+                    impl<T: ShardSnapshots>
+                        tonic::server::UnaryService<super::DeleteShardSnapshotRequest>
+                        for DeleteSvc<T>
+                    {
                         type Response = super::DeleteSnapshotResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::DeleteShardSnapshotRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as ShardSnapshots>::delete(&inner, request).await
-                            };
+                            let fut =
+                                async move { <T as ShardSnapshots>::delete(&inner, request).await };
                             Box::pin(fut)
                         }
                     }
@@ -11197,15 +11592,12 @@ pub mod shard_snapshots_server {
                 "/qdrant.ShardSnapshots/Recover" => {
                     #[allow(non_camel_case_types)]
                     struct RecoverSvc<T: ShardSnapshots>(pub Arc<T>);
-                    impl<
-                        T: ShardSnapshots,
-                    > tonic::server::UnaryService<super::RecoverShardSnapshotRequest>
-                    for RecoverSvc<T> {
+                    impl<T: ShardSnapshots>
+                        tonic::server::UnaryService<super::RecoverShardSnapshotRequest>
+                        for RecoverSvc<T>
+                    {
                         type Response = super::RecoverSnapshotResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::RecoverShardSnapshotRequest>,
@@ -11240,18 +11632,14 @@ pub mod shard_snapshots_server {
                     };
                     Box::pin(fut)
                 }
-                _ => {
-                    Box::pin(async move {
-                        Ok(
-                            http::Response::builder()
-                                .status(200)
-                                .header("grpc-status", "12")
-                                .header("content-type", "application/grpc")
-                                .body(empty_body())
-                                .unwrap(),
-                        )
-                    })
-                }
+                _ => Box::pin(async move {
+                    Ok(http::Response::builder()
+                        .status(200)
+                        .header("grpc-status", "12")
+                        .header("content-type", "application/grpc")
+                        .body(empty_body())
+                        .unwrap())
+                }),
             }
         }
     }
@@ -11297,8 +11685,8 @@ pub struct HealthCheckReply {
 /// Generated client implementations.
 pub mod qdrant_client {
     #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
-    use tonic::codegen::*;
     use tonic::codegen::http::Uri;
+    use tonic::codegen::*;
     #[derive(Debug, Clone)]
     pub struct QdrantClient<T> {
         inner: tonic::client::Grpc<T>,
@@ -11342,9 +11730,8 @@ pub mod qdrant_client {
                     <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
                 >,
             >,
-            <T as tonic::codegen::Service<
-                http::Request<tonic::body::BoxBody>,
-            >>::Error: Into<StdError> + Send + Sync,
+            <T as tonic::codegen::Service<http::Request<tonic::body::BoxBody>>>::Error:
+                Into<StdError> + Send + Sync,
         {
             QdrantClient::new(InterceptedService::new(inner, interceptor))
         }
@@ -11382,25 +11769,18 @@ pub mod qdrant_client {
         pub async fn health_check(
             &mut self,
             request: impl tonic::IntoRequest<super::HealthCheckRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::HealthCheckReply>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
+        ) -> std::result::Result<tonic::Response<super::HealthCheckReply>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/qdrant.Qdrant/HealthCheck",
-            );
+            let path = http::uri::PathAndQuery::from_static("/qdrant.Qdrant/HealthCheck");
             let mut req = request.into_request();
-            req.extensions_mut().insert(GrpcMethod::new("qdrant.Qdrant", "HealthCheck"));
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Qdrant", "HealthCheck"));
             self.inner.unary(req, path, codec).await
         }
     }
@@ -11415,10 +11795,7 @@ pub mod qdrant_server {
         async fn health_check(
             &self,
             request: tonic::Request<super::HealthCheckRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::HealthCheckReply>,
-            tonic::Status,
-        >;
+        ) -> std::result::Result<tonic::Response<super::HealthCheckReply>, tonic::Status>;
     }
     #[derive(Debug)]
     pub struct QdrantServer<T: Qdrant> {
@@ -11443,10 +11820,7 @@ pub mod qdrant_server {
                 max_encoding_message_size: None,
             }
         }
-        pub fn with_interceptor<F>(
-            inner: T,
-            interceptor: F,
-        ) -> InterceptedService<Self, F>
+        pub fn with_interceptor<F>(inner: T, interceptor: F) -> InterceptedService<Self, F>
         where
             F: tonic::service::Interceptor,
         {
@@ -11502,23 +11876,16 @@ pub mod qdrant_server {
                 "/qdrant.Qdrant/HealthCheck" => {
                     #[allow(non_camel_case_types)]
                     struct HealthCheckSvc<T: Qdrant>(pub Arc<T>);
-                    impl<
-                        T: Qdrant,
-                    > tonic::server::UnaryService<super::HealthCheckRequest>
-                    for HealthCheckSvc<T> {
+                    impl<T: Qdrant> tonic::server::UnaryService<super::HealthCheckRequest> for HealthCheckSvc<T> {
                         type Response = super::HealthCheckReply;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
                             request: tonic::Request<super::HealthCheckRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as Qdrant>::health_check(&inner, request).await
-                            };
+                            let fut =
+                                async move { <T as Qdrant>::health_check(&inner, request).await };
                             Box::pin(fut)
                         }
                     }
@@ -11545,18 +11912,14 @@ pub mod qdrant_server {
                     };
                     Box::pin(fut)
                 }
-                _ => {
-                    Box::pin(async move {
-                        Ok(
-                            http::Response::builder()
-                                .status(200)
-                                .header("grpc-status", "12")
-                                .header("content-type", "application/grpc")
-                                .body(empty_body())
-                                .unwrap(),
-                        )
-                    })
-                }
+                _ => Box::pin(async move {
+                    Ok(http::Response::builder()
+                        .status(200)
+                        .header("grpc-status", "12")
+                        .header("content-type", "application/grpc")
+                        .body(empty_body())
+                        .unwrap())
+                }),
             }
         }
     }
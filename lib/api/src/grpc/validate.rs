@@ -177,6 +177,7 @@ impl Validate for crate::grpc::qdrant::FieldCondition {
             && self.geo_bounding_box.is_none()
             && self.geo_radius.is_none()
             && self.geo_polygon.is_none()
+            && self.geo_shape.is_none()
             && self.values_count.is_none();
 
         if all_fields_none {
@@ -194,6 +195,14 @@ impl Validate for crate::grpc::qdrant::FieldCondition {
 
 impl Validate for crate::grpc::qdrant::Vector {
     fn validate(&self) -> Result<(), ValidationErrors> {
+        if !self.packed.is_empty() && (!self.data.is_empty() || self.indices.is_some()) {
+            let mut errors = ValidationErrors::new();
+            errors.add(
+                "packed",
+                ValidationError::new("Packed binary vector can't be combined with data or indices"),
+            );
+            return Err(errors);
+        }
         if let Some(indices) = &self.indices {
             sparse::common::sparse_vector::validate_sparse_vector_impl(&indices.data, &self.data)
         } else {
@@ -1,16 +1,18 @@
 use std::cmp;
+use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
 use futures::{future, TryStreamExt as _};
-use segment::types::QuantizationConfig;
 
 use super::Collection;
 use crate::operations::config_diff::*;
 use crate::operations::shard_selector_internal::ShardSelectorInternal;
 use crate::operations::types::*;
 use crate::optimizers_builder::OptimizersConfig;
+use crate::shards::peer_labels::find_shared_failure_domains;
 use crate::shards::replica_set::{Change, ReplicaState};
-use crate::shards::shard::PeerId;
+use crate::shards::shard::{PeerId, ShardId};
 
 impl Collection {
     /// Updates collection params:
@@ -83,6 +85,34 @@ impl Collection {
         Ok(())
     }
 
+    /// Adds new vectors and sparse vectors to the collection:
+    /// Saves new params on disk
+    ///
+    /// After this, `recreate_optimizers_blocking` must be called to add the vectors to existing
+    /// segments and to create new optimizers using the updated configuration.
+    pub async fn create_vectors(
+        &self,
+        vectors: Option<&VectorsConfig>,
+        sparse_vectors: Option<&SparseVectorsConfig>,
+    ) -> CollectionResult<()> {
+        let mut config = self.collection_config.write().await;
+        config.params.create_vectors(vectors, sparse_vectors)?;
+        config.save(&self.path)?;
+        Ok(())
+    }
+
+    /// Removes vectors or sparse vectors from the collection:
+    /// Saves new params on disk
+    ///
+    /// After this, `recreate_optimizers_blocking` must be called to remove the vectors from
+    /// existing segments and to create new optimizers using the updated configuration.
+    pub async fn delete_vectors(&self, vector_names: &[String]) -> CollectionResult<()> {
+        let mut config = self.collection_config.write().await;
+        config.params.delete_vectors(vector_names)?;
+        config.save(&self.path)?;
+        Ok(())
+    }
+
     /// Updates shard optimization params:
     /// Saves new params on disk
     ///
@@ -128,26 +158,7 @@ impl Collection {
     ) -> CollectionResult<()> {
         {
             let mut config = self.collection_config.write().await;
-            match quantization_config_diff {
-                QuantizationConfigDiff::Scalar(scalar) => {
-                    config
-                        .quantization_config
-                        .replace(QuantizationConfig::Scalar(scalar));
-                }
-                QuantizationConfigDiff::Product(product) => {
-                    config
-                        .quantization_config
-                        .replace(QuantizationConfig::Product(product));
-                }
-                QuantizationConfigDiff::Binary(binary) => {
-                    config
-                        .quantization_config
-                        .replace(QuantizationConfig::Binary(binary));
-                }
-                QuantizationConfigDiff::Disabled(_) => {
-                    config.quantization_config = None;
-                }
-            }
+            config.quantization_config = quantization_config_diff.into_config();
         }
         self.collection_config.read().await.save(&self.path)?;
         Ok(())
@@ -270,14 +281,25 @@ impl Collection {
         let count_request = Arc::new(CountRequestInternal {
             filter: None,
             exact: false, // Don't need exact count of unique ids here, only size estimation
+            distinct: None,
         });
         let shard_to_key = shards_holder.get_shard_id_to_key_mapping();
+        let labels_by_id = self.channel_service.id_to_labels.read().clone();
+        let mut warnings = Vec::new();
 
         // extract shards info
         for (shard_id, replica_set) in shards_holder.get_shards() {
             let shard_id = *shard_id;
             let peers = replica_set.peers();
 
+            let mut replica_peers: Vec<_> = peers.keys().copied().collect();
+            replica_peers.sort_unstable();
+            for group in find_shared_failure_domains(&labels_by_id, &replica_peers) {
+                warnings.push(format!(
+                    "Replicas of shard {shard_id} on peers {group:?} share the same zone or rack",
+                ));
+            }
+
             if replica_set.has_local_shard().await {
                 let state = peers
                     .get(&replica_set.this_peer_id())
@@ -319,7 +341,109 @@ impl Collection {
             local_shards,
             remote_shards,
             shard_transfers,
+            warnings,
         };
         Ok(info)
     }
+
+    /// Estimated size of every shard of the collection in bytes, across the whole cluster
+    ///
+    /// Shards with a local replica are measured on disk. Sizes of the other shards are estimated
+    /// from their number of points, requested from remote replicas within `timeout`, and the
+    /// average size of a point in the local shards. Shards whose size can't be estimated are
+    /// omitted.
+    pub async fn shard_sizes(&self, timeout: Duration) -> HashMap<ShardId, u64> {
+        let mut sizes = self.local_shard_sizes().await;
+
+        let points_counts: HashMap<ShardId, u64> = {
+            let shards_holder = self.shards_holder.read().await;
+            let requests = shards_holder
+                .get_shards()
+                .filter(|(shard_id, _)| !sizes.contains_key(shard_id))
+                .map(|(shard_id, replica_set)| async move {
+                    let info = tokio::time::timeout(timeout, replica_set.info(false))
+                        .await
+                        .unwrap_or_else(|_| {
+                            Err(CollectionError::timeout(
+                                timeout.as_secs() as usize,
+                                format!("get number of points in shard {shard_id}"),
+                            ))
+                        });
+                    (*shard_id, info)
+                });
+            future::join_all(requests)
+                .await
+                .into_iter()
+                .filter_map(|(shard_id, info)| match info {
+                    Ok(info) => Some((shard_id, info.points_count? as u64)),
+                    Err(err) => {
+                        log::warn!("Failed to get number of points in shard {shard_id}: {err}");
+                        None
+                    }
+                })
+                .collect()
+        };
+
+        if points_counts.is_empty() {
+            return sizes;
+        }
+
+        let local_points: u64 = {
+            let shards_holder = self.shards_holder.read().await;
+            let requests = shards_holder
+                .get_shards()
+                .filter(|(shard_id, _)| sizes.contains_key(shard_id))
+                .map(|(_, replica_set)| replica_set.info(true));
+            future::join_all(requests)
+                .await
+                .into_iter()
+                .filter_map(|info| info.ok()?.points_count)
+                .sum::<usize>() as u64
+        };
+        if local_points == 0 {
+            return sizes;
+        }
+        let local_bytes: u64 = sizes.values().sum();
+
+        sizes.extend(points_counts.into_iter().map(|(shard_id, points)| {
+            let size = (points as u128 * local_bytes as u128 / local_points as u128) as u64;
+            (shard_id, size)
+        }));
+        sizes
+    }
+
+    /// Size of every local shard on disk, in bytes
+    pub async fn local_shard_sizes(&self) -> HashMap<ShardId, u64> {
+        let shard_paths: Vec<_> = {
+            let shards_holder = self.shards_holder.read().await;
+            let mut shard_paths = Vec::new();
+            for (shard_id, replica_set) in shards_holder.get_shards() {
+                if replica_set.has_local_shard().await {
+                    shard_paths.push((*shard_id, replica_set.shard_path.clone()));
+                }
+            }
+            shard_paths
+        };
+
+        let sizes = tokio::task::spawn_blocking(move || {
+            shard_paths
+                .into_iter()
+                .filter_map(
+                    |(shard_id, shard_path)| match fs_extra::dir::get_size(&shard_path) {
+                        Ok(size) => Some((shard_id, size)),
+                        Err(err) => {
+                            log::warn!("Failed to get size of shard {shard_path:?}: {err}");
+                            None
+                        }
+                    },
+                )
+                .collect()
+        })
+        .await;
+
+        sizes.unwrap_or_else(|err| {
+            log::error!("Failed to get size of local shards: {err}");
+            HashMap::new()
+        })
+    }
 }
@@ -0,0 +1,92 @@
+use std::sync::{Arc, Weak};
+use std::time::Duration;
+
+use segment::types::{PayloadFieldSchema, PayloadSchemaType};
+use tokio::sync::RwLock;
+
+use super::Collection;
+use crate::config::CollectionConfig;
+use crate::operations::expiry;
+use crate::operations::types::CollectionResult;
+use crate::shards::shard_holder::LockedShardHolder;
+
+/// Interval at which the expiry worker looks for expired points to delete
+const EXPIRY_CHECK_INTERVAL: Duration = Duration::from_secs(10);
+
+impl Collection {
+    /// Create a payload index on the expiry field, if points of this collection expire
+    ///
+    /// Expired points are selected by a range condition on every expiry check, so the field must
+    /// be indexed to not scan the whole shard. An existing numeric index is kept as is.
+    pub async fn create_expiry_index(&self) -> CollectionResult<()> {
+        if self.collection_config.read().await.params.expiry.is_none() {
+            return Ok(());
+        }
+
+        let is_indexed = matches!(
+            self.payload_index_schema
+                .read()
+                .schema
+                .get(expiry::EXPIRE_AT_KEY),
+            Some(PayloadFieldSchema::FieldType(
+                PayloadSchemaType::Integer | PayloadSchemaType::Float
+            )),
+        );
+
+        if !is_indexed {
+            self.create_payload_index(
+                expiry::EXPIRE_AT_KEY.to_string(),
+                PayloadFieldSchema::FieldType(PayloadSchemaType::Integer),
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Run the worker deleting expired points in the background, while the collection exists
+    pub(super) fn spawn_expiry_worker(&self) {
+        self.update_runtime.spawn(expiry_worker_fn(
+            Arc::downgrade(&self.shards_holder),
+            Arc::downgrade(&self.collection_config),
+        ));
+    }
+}
+
+/// Periodically deletes points, which are expired according to the collection config
+///
+/// Every shard deletes its expired points from its leader replica through a regular replicated
+/// update, see [`ShardReplicaSet::delete_expired_points`]. Reads exclude expired points regardless,
+/// so results don't depend on whether the points are purged already.
+///
+/// [`ShardReplicaSet::delete_expired_points`]: crate::shards::replica_set::ShardReplicaSet::delete_expired_points
+async fn expiry_worker_fn(
+    shards_holder: Weak<LockedShardHolder>,
+    collection_config: Weak<RwLock<CollectionConfig>>,
+) {
+    loop {
+        tokio::time::sleep(EXPIRY_CHECK_INTERVAL).await;
+
+        // Collection is dropped
+        let (Some(shards_holder), Some(collection_config)) =
+            (shards_holder.upgrade(), collection_config.upgrade())
+        else {
+            return;
+        };
+
+        if collection_config.read().await.params.expiry.is_none() {
+            continue;
+        }
+
+        let now = expiry::current_timestamp();
+
+        for replica_set in shards_holder.read().await.all_shards() {
+            if let Err(err) = replica_set.delete_expired_points(now).await {
+                log::error!(
+                    "Failed to delete expired points of shard {}: {err}",
+                    replica_set.shard_id,
+                );
+            }
+        }
+    }
+}
@@ -1,11 +1,14 @@
 mod collection_ops;
+mod expiry;
 pub mod payload_index_schema;
 mod point_ops;
 mod search;
+mod shard_changes;
 mod shard_transfer;
 mod sharding_keys;
 mod snapshots;
 mod state_management;
+mod strict_mode;
 
 use std::collections::{HashMap, HashSet};
 use std::ops::Deref;
@@ -13,8 +16,9 @@ use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Duration;
 
+use futures::future;
 use segment::common::version::StorageVersion;
-use segment::types::ShardKey;
+use segment::types::{SeqNumberType, ShardKey};
 use semver::Version;
 use tokio::runtime::Handle;
 use tokio::sync::{Mutex, RwLock, RwLockWriteGuard};
@@ -22,7 +26,9 @@ use tokio::sync::{Mutex, RwLock, RwLockWriteGuard};
 use crate::collection::payload_index_schema::PayloadIndexSchema;
 use crate::collection_state::{ShardInfo, State};
 use crate::common::is_ready::IsReady;
+use crate::common::rate_limiting::RateLimiter;
 use crate::config::CollectionConfig;
+use crate::operations::consistency_params::ConsistencyToken;
 use crate::operations::shared_storage_config::SharedStorageConfig;
 use crate::operations::types::{CollectionError, CollectionResult, NodeType};
 use crate::save_on_disk::SaveOnDisk;
@@ -62,6 +68,8 @@ pub struct Collection {
     // Lock is acquired for read on update operation and can be acquired for write externally,
     // which will block all update operations until the lock is released.
     updates_lock: RwLock<()>,
+    // Write rate limiter of the strict mode, created on first use.
+    write_rate_limiter: parking_lot::Mutex<Option<RateLimiter>>,
     // Update runtime handle.
     update_runtime: Handle,
     // Search runtime handle.
@@ -127,7 +135,7 @@ impl Collection {
 
         let payload_index_schema = Self::load_payload_index_schema(path)?;
 
-        Ok(Self {
+        let collection = Self {
             id: name.clone(),
             shards_holder: locked_shard_holder,
             collection_config: shared_collection_config,
@@ -144,9 +152,14 @@ impl Collection {
             init_time: start_time.elapsed(),
             is_initialized: Arc::new(Default::default()),
             updates_lock: RwLock::new(()),
+            write_rate_limiter: parking_lot::Mutex::new(None),
             update_runtime: update_runtime.unwrap_or_else(Handle::current),
             search_runtime: search_runtime.unwrap_or_else(Handle::current),
-        })
+        };
+
+        collection.spawn_expiry_worker();
+
+        Ok(collection)
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -221,7 +234,7 @@ impl Collection {
         let payload_index_schema = Self::load_payload_index_schema(path)
             .expect("Can't load or initialize payload index schema");
 
-        Self {
+        let collection = Self {
             id: collection_id.clone(),
             shards_holder: locked_shard_holder,
             collection_config: shared_collection_config,
@@ -238,9 +251,14 @@ impl Collection {
             init_time: start_time.elapsed(),
             is_initialized: Arc::new(Default::default()),
             updates_lock: RwLock::new(()),
+            write_rate_limiter: parking_lot::Mutex::new(None),
             update_runtime: update_runtime.unwrap_or_else(Handle::current),
             search_runtime: search_runtime.unwrap_or_else(Handle::current),
-        }
+        };
+
+        collection.spawn_expiry_worker();
+
+        collection
     }
 
     /// Check if stored version have consequent version.
@@ -307,6 +325,49 @@ impl Collection {
         replica_set.wait_for_local_state(state, timeout).await
     }
 
+    pub async fn wait_local_shard_operation(
+        &self,
+        shard_id: ShardId,
+        operation_id: SeqNumberType,
+        timeout: Duration,
+    ) -> CollectionResult<()> {
+        let shard_holder_read = self.shards_holder.read().await;
+
+        let replica_set = shard_holder_read
+            .get_shard(&shard_id)
+            .ok_or_else(|| shard_not_found_error(shard_id))?;
+
+        replica_set
+            .wait_for_local_operation(operation_id, timeout)
+            .await
+    }
+
+    /// Wait until the updates of `consistency_token` are applied on at least one active replica
+    ///
+    /// Replicas which did not apply them yet are queried by reads only after the up-to-date ones,
+    /// until they catch up.
+    ///
+    /// Shards which are not present in the collection anymore are ignored.
+    pub async fn wait_for_consistency_token(
+        &self,
+        consistency_token: &ConsistencyToken,
+        timeout: Option<Duration>,
+    ) -> CollectionResult<()> {
+        let timeout = timeout.unwrap_or(self.shared_storage_config.search_timeout);
+        let shard_holder_read = self.shards_holder.read().await;
+
+        let wait_futures = consistency_token
+            .shards()
+            .filter_map(|(shard_id, operations)| {
+                let replica_set = shard_holder_read.get_shard(&shard_id)?;
+                Some(replica_set.wait_for_operations(operations, timeout))
+            });
+
+        future::try_join_all(wait_futures).await?;
+
+        Ok(())
+    }
+
     pub async fn set_shard_replica_state(
         &self,
         shard_id: ShardId,
@@ -2,14 +2,24 @@ use std::sync::Arc;
 
 use futures::{future, TryFutureExt, TryStreamExt as _};
 use itertools::Itertools as _;
-use segment::types::{ShardKey, WithPayload, WithPayloadInterface};
+use ordered_float::OrderedFloat;
+use segment::data_types::aggregation::NumericAggregation;
+use segment::data_types::distinct::DistinctCounter;
+use segment::data_types::text_index::TextStats;
+use segment::types::{
+    Filter, OrderBy, PayloadFieldSchema, PayloadSchemaParams, PayloadSchemaType, PointIdType,
+    ScoredPoint, ShardKey, WithPayload, WithPayloadInterface, WithVector,
+};
 use validator::Validate as _;
 
 use super::Collection;
 use crate::operations::consistency_params::ReadConsistency;
+use crate::operations::expiry;
 use crate::operations::point_ops::WriteOrdering;
 use crate::operations::shard_selector_internal::ShardSelectorInternal;
+use crate::operations::sparse_pruning;
 use crate::operations::types::*;
+use crate::operations::validation;
 use crate::operations::CollectionUpdateOperations;
 use crate::shards::shard::ShardId;
 
@@ -82,12 +92,34 @@ impl Collection {
 
     pub async fn update_from_client(
         &self,
-        operation: CollectionUpdateOperations,
+        mut operation: CollectionUpdateOperations,
         wait: bool,
         ordering: WriteOrdering,
         shard_keys_selection: Option<ShardKey>,
     ) -> CollectionResult<UpdateResult> {
         operation.validate()?;
+
+        if let Some(payload_schema) = &self.collection_config.read().await.params.payload_schema {
+            validation::validate_payload_schema(
+                &operation,
+                payload_schema,
+                &self.payload_index_schema.read().schema,
+            )?;
+        }
+
+        // Expiry time is assigned before the operation is replicated,
+        // so that all replicas expire the point at the same moment
+        let expiry_config = self.collection_config.read().await.params.expiry;
+        if let Some(ttl) = expiry_config.and_then(|config| config.ttl) {
+            expiry::assign_expire_at(&mut operation, expiry::current_timestamp() + ttl);
+        }
+
+        // Sparse vectors are pruned before the operation is replicated,
+        // so that all replicas store the same dimensions
+        if let Some(sparse_vectors) = &self.collection_config.read().await.params.sparse_vectors {
+            sparse_pruning::prune_sparse_vectors(&mut operation, sparse_vectors);
+        }
+
         let _update_lock = self.updates_lock.read().await;
 
         let mut results = {
@@ -131,17 +163,53 @@ impl Collection {
                 first_err
             }
         } else {
+            // Merge consistency tokens of all shards into the result
+            let consistency_token = results
+                .iter()
+                .filter_map(|result| result.as_ref().ok()?.consistency_token.clone())
+                .reduce(|mut token, other| {
+                    token.merge(other);
+                    token
+                });
+
             // At least one result is always present.
-            results.pop().unwrap()
+            results.pop().unwrap().map(|mut result| {
+                result.consistency_token = consistency_token;
+                result
+            })
         }
     }
 
+    /// Extend the filter to exclude expired points, if points of this collection expire
+    ///
+    /// Requests targeting a specific shard come from other peers, which have already done this.
+    pub(super) async fn exclude_expired_points(
+        &self,
+        filter: Option<Filter>,
+        shard_selection: &ShardSelectorInternal,
+    ) -> Option<Filter> {
+        if shard_selection.is_shard_id()
+            || self.collection_config.read().await.params.expiry.is_none()
+        {
+            return filter;
+        }
+
+        Some(expiry::exclude_expired(
+            filter.as_ref(),
+            expiry::current_timestamp(),
+        ))
+    }
+
     pub async fn scroll_by(
         &self,
-        request: ScrollRequestInternal,
+        mut request: ScrollRequestInternal,
         read_consistency: Option<ReadConsistency>,
         shard_selection: &ShardSelectorInternal,
     ) -> CollectionResult<ScrollResult> {
+        request.filter = self
+            .exclude_expired_points(request.filter, shard_selection)
+            .await;
+
         let default_request = ScrollRequestInternal::default();
 
         let offset = request.offset;
@@ -153,6 +221,7 @@ impl Collection {
             .clone()
             .unwrap_or_else(|| default_request.with_payload.clone().unwrap());
         let with_vector = request.with_vector;
+        let mut order_by = request.order_by;
 
         if limit == 0 {
             return Err(CollectionError::BadRequest {
@@ -160,6 +229,17 @@ impl Collection {
             });
         }
 
+        // Requests to a single shard already carry the distance of the offset point,
+        // which may be stored in another shard
+        if let (Some(order_by), Some(offset)) = (&mut order_by, offset) {
+            if !shard_selection.is_shard_id() {
+                let start_from = self
+                    .offset_distance(offset, order_by, read_consistency, shard_selection)
+                    .await?;
+                order_by.start_from = Some(start_from);
+            }
+        }
+
         // Needed to return next page offset.
         let limit = limit + 1;
         let retrieved_points: Vec<_> = {
@@ -174,6 +254,7 @@ impl Collection {
                         &with_payload_interface,
                         &with_vector,
                         request.filter.as_ref(),
+                        order_by.as_ref(),
                         read_consistency,
                         shard_selection.is_shard_id(),
                     )
@@ -190,12 +271,27 @@ impl Collection {
 
             future::try_join_all(scroll_futures).await?
         };
-        let mut points: Vec<_> = retrieved_points
-            .into_iter()
-            .flatten()
-            .sorted_by_key(|point| point.id)
-            .take(limit)
-            .collect();
+
+        let mut points: Vec<_> = if order_by.is_some() {
+            retrieved_points
+                .into_iter()
+                .flatten()
+                .sorted_by_key(|point| {
+                    (
+                        OrderedFloat(point.geo_distance.unwrap_or(f64::MAX)),
+                        point.id,
+                    )
+                })
+                .take(limit)
+                .collect()
+        } else {
+            retrieved_points
+                .into_iter()
+                .flatten()
+                .sorted_by_key(|point| point.id)
+                .take(limit)
+                .collect()
+        };
 
         let next_page_offset = if points.len() < limit {
             // This was the last page
@@ -210,12 +306,53 @@ impl Collection {
         })
     }
 
+    /// Distance from the `order_by` origin to the `offset` point, where the ordered scroll continues
+    async fn offset_distance(
+        &self,
+        offset: PointIdType,
+        order_by: &OrderBy,
+        read_consistency: Option<ReadConsistency>,
+        shard_selection: &ShardSelectorInternal,
+    ) -> CollectionResult<f64> {
+        let key = &order_by.geo_distance.key;
+        let request = PointRequestInternal {
+            ids: vec![offset],
+            with_payload: Some(WithPayloadInterface::Fields(vec![key.clone()])),
+            with_vector: WithVector::Bool(false),
+        };
+        self.retrieve(request, read_consistency, shard_selection)
+            .await?
+            .into_iter()
+            .find_map(|record| {
+                let payload = record.payload?;
+                order_by.geo_distance.payload_distance(&payload)
+            })
+            .ok_or_else(|| {
+                CollectionError::bad_request(format!(
+                    "Offset point {offset} is not found or has no geo point in the field `{key}`",
+                ))
+            })
+    }
+
     pub async fn count(
         &self,
-        request: CountRequestInternal,
+        mut request: CountRequestInternal,
         read_consistency: Option<ReadConsistency>,
         shard_selection: &ShardSelectorInternal,
     ) -> CollectionResult<CountResult> {
+        if request.distinct.is_some() {
+            let counter = self
+                .count_distinct(request, read_consistency, shard_selection)
+                .await?;
+            return Ok(CountResult {
+                count: counter.count(),
+            });
+        }
+
+        request.filter = self
+            .exclude_expired_points(request.filter, shard_selection)
+            .await;
+
         let shards_holder = self.shards_holder.read().await;
         let shards = shards_holder.select_shards(shard_selection)?;
 
@@ -241,6 +378,187 @@ impl Collection {
         Ok(CountResult { count })
     }
 
+    /// Collect distinct values of the `distinct` payload field over all selected shards
+    pub async fn count_distinct(
+        &self,
+        mut request: CountRequestInternal,
+        read_consistency: Option<ReadConsistency>,
+        shard_selection: &ShardSelectorInternal,
+    ) -> CollectionResult<DistinctCounter> {
+        request.filter = self
+            .exclude_expired_points(request.filter, shard_selection)
+            .await;
+
+        let shards_holder = self.shards_holder.read().await;
+        let shards = shards_holder.select_shards(shard_selection)?;
+
+        let request = Arc::new(request);
+        let mut requests: futures::stream::FuturesUnordered<_> = shards
+            .into_iter()
+            .map(|(shard, _shard_key)| {
+                shard.count_distinct(
+                    request.clone(),
+                    read_consistency,
+                    shard_selection.is_shard_id(),
+                )
+            })
+            .collect();
+
+        let mut counter = DistinctCounter::default();
+
+        while let Some(response) = requests.try_next().await? {
+            // Values of each shard may fit into the exact limit, while all of them together don't
+            counter
+                .merge(response, request.exact)
+                .map_err(CollectionError::bad_request)?;
+        }
+
+        Ok(counter)
+    }
+
+    pub async fn aggregate(
+        &self,
+        mut request: AggregateRequestInternal,
+        read_consistency: Option<ReadConsistency>,
+        shard_selection: &ShardSelectorInternal,
+    ) -> CollectionResult<AggregateResult> {
+        request.filter = self
+            .exclude_expired_points(request.filter, shard_selection)
+            .await;
+
+        let shards_holder = self.shards_holder.read().await;
+        let shards = shards_holder.select_shards(shard_selection)?;
+
+        let request = Arc::new(request);
+        let mut requests: futures::stream::FuturesUnordered<_> = shards
+            .into_iter()
+            .map(|(shard, _shard_key)| {
+                shard.aggregate(
+                    request.clone(),
+                    read_consistency,
+                    shard_selection.is_shard_id(),
+                )
+            })
+            .collect();
+
+        let mut aggregation = NumericAggregation::default();
+
+        while let Some(response) = requests.try_next().await? {
+            aggregation.merge(response);
+        }
+
+        // Values of each shard may fit into the histogram, while all of them together don't
+        aggregation
+            .check_histogram(request.histogram.as_ref())
+            .map_err(CollectionError::bad_request)?;
+
+        Ok(AggregateResult::new(
+            aggregation,
+            request.histogram.as_ref(),
+        ))
+    }
+
+    fn check_text_indexed(&self, key: &str) -> CollectionResult<()> {
+        let is_text_indexed = matches!(
+            self.payload_index_schema.read().schema.get(key),
+            Some(
+                PayloadFieldSchema::FieldType(PayloadSchemaType::Text)
+                    | PayloadFieldSchema::FieldParams(PayloadSchemaParams::Text(_))
+            )
+        );
+        if !is_text_indexed {
+            return Err(CollectionError::bad_request(format!(
+                "Text search requires a full-text index on payload field `{key}`"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Statistics of the text field of the request, merged over all selected shards
+    pub async fn text_stats(
+        &self,
+        request: Arc<TextSearchRequestInternal>,
+        read_consistency: Option<ReadConsistency>,
+        shard_selection: &ShardSelectorInternal,
+    ) -> CollectionResult<TextStats> {
+        self.check_text_indexed(&request.key)?;
+
+        let shards_holder = self.shards_holder.read().await;
+        let shards = shards_holder.select_shards(shard_selection)?;
+
+        let mut requests: futures::stream::FuturesUnordered<_> = shards
+            .into_iter()
+            .map(|(shard, _shard_key)| {
+                shard.text_stats(
+                    request.clone(),
+                    read_consistency,
+                    shard_selection.is_shard_id(),
+                )
+            })
+            .collect();
+
+        let mut stats = TextStats::default();
+        while let Some(response) = requests.try_next().await? {
+            stats.merge(response);
+        }
+        Ok(stats)
+    }
+
+    pub async fn search_text(
+        &self,
+        mut request: TextSearchRequestInternal,
+        read_consistency: Option<ReadConsistency>,
+        shard_selection: &ShardSelectorInternal,
+    ) -> CollectionResult<Vec<ScoredPoint>> {
+        self.check_text_indexed(&request.key)?;
+
+        // Shards score with the statistics of the whole collection, so that their scores are comparable
+        if request.stats.is_none() {
+            let stats_request = Arc::new(request.clone());
+            let stats = self
+                .text_stats(stats_request, read_consistency, shard_selection)
+                .await?;
+            request.stats = Some(stats);
+        }
+
+        request.filter = self
+            .exclude_expired_points(request.filter, shard_selection)
+            .await;
+
+        // Shards return the top of the results including the offset, which is applied after merging
+        let offset = request.offset.take().unwrap_or_default();
+        let limit = request.limit;
+        request.limit += offset;
+
+        let shards_holder = self.shards_holder.read().await;
+        let shards = shards_holder.select_shards(shard_selection)?;
+
+        let request = Arc::new(request);
+        let mut requests: futures::stream::FuturesUnordered<_> = shards
+            .into_iter()
+            .map(|(shard, _shard_key)| {
+                shard.search_text(
+                    request.clone(),
+                    read_consistency,
+                    shard_selection.is_shard_id(),
+                )
+            })
+            .collect();
+
+        let mut points = vec![];
+
+        while let Some(response) = requests.try_next().await? {
+            points.extend(response);
+        }
+
+        Ok(points
+            .into_iter()
+            .sorted_by(|a, b| b.cmp(a))
+            .skip(offset)
+            .take(limit)
+            .collect())
+    }
+
     pub async fn retrieve(
         &self,
         request: PointRequestInternal,
@@ -4,7 +4,9 @@ use std::time::Duration;
 
 use futures::{future, TryFutureExt};
 use segment::spaces::tools;
-use segment::types::{ExtendedPointId, Order, ScoredPoint, WithPayloadInterface, WithVector};
+use segment::types::{
+    ExtendedPointId, GeoDistance, Order, ScoredPoint, WithPayloadInterface, WithVector,
+};
 
 use super::Collection;
 use crate::operations::consistency_params::ReadConsistency;
@@ -119,11 +121,17 @@ impl Collection {
 
     async fn do_core_search_batch(
         &self,
-        request: CoreSearchRequestBatch,
+        mut request: CoreSearchRequestBatch,
         read_consistency: Option<ReadConsistency>,
         shard_selection: &ShardSelectorInternal,
         timeout: Option<Duration>,
     ) -> CollectionResult<Vec<Vec<ScoredPoint>>> {
+        for search in &mut request.searches {
+            search.filter = self
+                .exclude_expired_points(search.filter.take(), shard_selection)
+                .await;
+        }
+
         let request = Arc::new(request);
 
         // query all shards concurrently
@@ -154,8 +162,54 @@ impl Collection {
             future::try_join_all(all_searches).await?
         };
 
-        self.merge_from_shards(all_searches_res, request, !shard_selection.is_shard_id())
-            .await
+        let is_client_request = !shard_selection.is_shard_id();
+        let mut results = self
+            .merge_from_shards(all_searches_res, request.clone(), is_client_request)
+            .await?;
+
+        if is_client_request {
+            for (result, search) in results.iter_mut().zip(request.searches.iter()) {
+                if let Some(geo_distance) = &search.with_geo_distance {
+                    self.fill_geo_distance(result, geo_distance, read_consistency, shard_selection)
+                        .await?;
+                }
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// Attach the distance to the closest geo point of the `geo_distance` key to every result
+    async fn fill_geo_distance(
+        &self,
+        search_result: &mut [ScoredPoint],
+        geo_distance: &GeoDistance,
+        read_consistency: Option<ReadConsistency>,
+        shard_selection: &ShardSelectorInternal,
+    ) -> CollectionResult<()> {
+        if search_result.is_empty() {
+            return Ok(());
+        }
+
+        let retrieve_request = PointRequestInternal {
+            ids: search_result.iter().map(|x| x.id).collect(),
+            with_payload: Some(WithPayloadInterface::Fields(vec![geo_distance.key.clone()])),
+            with_vector: WithVector::Bool(false),
+        };
+        let distances: HashMap<ExtendedPointId, f64> = self
+            .retrieve(retrieve_request, read_consistency, shard_selection)
+            .await?
+            .into_iter()
+            .filter_map(|record| {
+                let distance = geo_distance.payload_distance(record.payload.as_ref()?)?;
+                Some((record.id, distance))
+            })
+            .collect();
+
+        for point in search_result {
+            point.geo_distance = distances.get(&point.id).copied();
+        }
+        Ok(())
     }
 
     pub(crate) async fn fill_search_result_with_payload(
@@ -237,6 +291,7 @@ impl Collection {
                     // directly represent distance anymore, so the order is always `LargeBetter`
                     QueryEnum::Discover(_)
                     | QueryEnum::Context(_)
+                    | QueryEnum::Feedback(_)
                     | QueryEnum::RecommendBestScore(_) => Order::LargeBetter,
                 };
 
@@ -0,0 +1,63 @@
+use std::time::Duration;
+
+use futures::future::BoxFuture;
+use segment::types::SeqNumberType;
+
+use crate::collection::Collection;
+use crate::operations::types::{CollectionResult, ShardChange};
+use crate::shards::shard::ShardId;
+use crate::shards::shard_holder::shard_not_found_error;
+
+impl Collection {
+    /// Read up to `limit` operations applied to the local replica of the shard, starting from
+    /// operation `from`, or from the oldest retained one
+    ///
+    /// Returns the number of the operation to continue reading from, along with the changes.
+    /// Operation numbers are specific to the replica, so consumers have to keep reading changes
+    /// from the same peer to be able to resume.
+    pub async fn local_shard_changes(
+        &self,
+        shard_id: ShardId,
+        from: Option<SeqNumberType>,
+        limit: usize,
+    ) -> CollectionResult<(SeqNumberType, Vec<ShardChange>)> {
+        let shard_holder_read = self.shards_holder.read().await;
+
+        let replica_set = shard_holder_read
+            .get_shard(&shard_id)
+            .ok_or_else(|| shard_not_found_error(shard_id))?;
+
+        let (next, operations) = replica_set.read_local_wal(from, limit).await?;
+
+        let changes = operations
+            .into_iter()
+            .map(|(operation_id, operation)| ShardChange {
+                operation_id,
+                operation,
+            })
+            .collect();
+
+        Ok((next, changes))
+    }
+
+    /// Watch for the local replica of the shard to apply operation `operation_id`
+    ///
+    /// The returned future does not borrow the collection, so it can be awaited without holding
+    /// any locks. It resolves to `false` if the operation was not applied within `timeout`.
+    pub async fn watch_local_shard_operation(
+        &self,
+        shard_id: ShardId,
+        operation_id: SeqNumberType,
+        timeout: Duration,
+    ) -> CollectionResult<BoxFuture<'static, bool>> {
+        let shard_holder_read = self.shards_holder.read().await;
+
+        let replica_set = shard_holder_read
+            .get_shard(&shard_id)
+            .ok_or_else(|| shard_not_found_error(shard_id))?;
+
+        replica_set
+            .watch_local_operation(operation_id, timeout)
+            .await
+    }
+}
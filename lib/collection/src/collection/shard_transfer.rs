@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::future::Future;
 use std::path::PathBuf;
 use std::time::Duration;
@@ -11,6 +12,7 @@ use crate::shards::replica_set::ReplicaState;
 use crate::shards::shard::{PeerId, ShardId};
 use crate::shards::shard_holder::ShardHolder;
 use crate::shards::transfer;
+use crate::shards::transfer::helpers::check_transfer_conflicts_strict;
 use crate::shards::transfer::transfer_tasks_pool::TaskResult;
 use crate::shards::transfer::{
     ShardTransfer, ShardTransferConsensus, ShardTransferKey, ShardTransferMethod,
@@ -354,6 +356,65 @@ impl Collection {
         }
     }
 
+    /// Request the given shard moves through consensus
+    ///
+    /// `proposed` transfers were requested before, but are not in the consensus state yet.
+    /// Moves which conflict with existing or proposed transfers, or which would exceed the
+    /// automatic shard transfer limit on either peer, are skipped. Returns the requested moves.
+    pub async fn request_shard_moves(
+        &self,
+        moves: impl IntoIterator<Item = (ShardId, PeerId, PeerId)>,
+        proposed: &[ShardTransfer],
+    ) -> Vec<ShardTransfer> {
+        let shard_holder = self.shards_holder.read().await;
+        let mut transfers = shard_holder.get_transfers(|_| true);
+        transfers.extend(proposed.iter().cloned());
+
+        // Count transfers which are not in the consensus state yet
+        let mut unconfirmed = HashMap::<PeerId, usize>::new();
+        for transfer in proposed {
+            *unconfirmed.entry(transfer.from).or_default() += 1;
+            *unconfirmed.entry(transfer.to).or_default() += 1;
+        }
+        let mut requested = Vec::new();
+
+        for (shard_id, from, to) in moves {
+            let transfer = ShardTransfer {
+                shard_id,
+                from,
+                to,
+                sync: false,
+                method: None,
+            };
+
+            if check_transfer_conflicts_strict(&transfer, transfers.iter()).is_some() {
+                continue;
+            }
+
+            let (_, mut outgoing) = shard_holder.count_shard_transfer_io(&from);
+            outgoing += unconfirmed.get(&from).copied().unwrap_or(0);
+            let (mut incoming, _) = shard_holder.count_shard_transfer_io(&to);
+            incoming += unconfirmed.get(&to).copied().unwrap_or(0);
+            if self.check_auto_shard_transfer_limit(incoming, outgoing) {
+                log::trace!("Postponing shard {shard_id} move from {from} to {to} to stay below transfer limits (incoming: {incoming}, outgoing: {outgoing})");
+                continue;
+            }
+
+            log::debug!(
+                "Rebalancing shard {}:{shard_id} by moving it from peer {from} to {to}",
+                self.name(),
+            );
+
+            *unconfirmed.entry(from).or_default() += 1;
+            *unconfirmed.entry(to).or_default() += 1;
+            transfers.push(transfer.clone());
+            self.request_shard_transfer(transfer.clone());
+            requested.push(transfer);
+        }
+
+        requested
+    }
+
     /// Whether we have reached the automatic shard transfer limit based on the given incoming and
     /// outgoing transfers.
     pub(super) fn check_auto_shard_transfer_limit(&self, incoming: usize, outgoing: usize) -> bool {
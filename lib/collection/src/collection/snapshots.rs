@@ -1,5 +1,6 @@
 use std::collections::HashSet;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 use io::file_operations::read_json;
 use segment::common::version::StorageVersion as _;
@@ -16,7 +17,7 @@ use crate::shards::remote_shard::RemoteShard;
 use crate::shards::replica_set::ShardReplicaSet;
 use crate::shards::shard::{PeerId, ShardId};
 use crate::shards::shard_config::{self, ShardConfig};
-use crate::shards::shard_holder::{ShardKeyMapping, SHARD_KEY_MAPPING_FILE};
+use crate::shards::shard_holder::{LockedShardHolder, ShardKeyMapping, SHARD_KEY_MAPPING_FILE};
 use crate::shards::shard_versioning;
 
 impl Collection {
@@ -283,6 +284,14 @@ impl Collection {
             .await
     }
 
+    /// Shard holder and snapshots directory of the collection
+    ///
+    /// Allows creating and restoring shard snapshots without keeping a reference to the
+    /// collection, e.g. to not block the collections map for the duration of the snapshot.
+    pub fn shard_snapshots_handle(&self) -> (Arc<LockedShardHolder>, PathBuf) {
+        (self.shards_holder.clone(), self.snapshots_path.clone())
+    }
+
     pub async fn create_shard_snapshot(
         &self,
         shard_id: ShardId,
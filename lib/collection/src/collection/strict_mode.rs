@@ -0,0 +1,129 @@
+use segment::index::query_estimator;
+use segment::types::{Filter, PayloadFieldSchema, PayloadKeyType};
+
+use super::Collection;
+use crate::common::rate_limiting::RateLimiter;
+use crate::config::StrictModeConfig;
+use crate::operations::strict_mode::{self, StrictModeVerification};
+use crate::operations::types::{CollectionError, CollectionResult};
+use crate::operations::CollectionUpdateOperations;
+
+impl Collection {
+    /// Check the read request against the strict mode of the collection
+    pub async fn check_strict_mode(
+        &self,
+        request: &impl StrictModeVerification,
+    ) -> CollectionResult<()> {
+        let Some(config) = self.collection_config.read().await.params.strict_mode else {
+            return Ok(());
+        };
+        self.check_strict_mode_request(request, &config).await
+    }
+
+    /// Check the batch of read requests against the strict mode of the collection
+    pub async fn check_strict_mode_batch<'a, R>(
+        &self,
+        requests: impl ExactSizeIterator<Item = &'a R>,
+    ) -> CollectionResult<()>
+    where
+        R: StrictModeVerification + 'a,
+    {
+        let Some(config) = self.collection_config.read().await.params.strict_mode else {
+            return Ok(());
+        };
+        strict_mode::check_batch_size(requests.len(), &config)?;
+
+        for request in requests {
+            self.check_strict_mode_request(request, &config).await?;
+        }
+        Ok(())
+    }
+
+    /// Check the update operation against the strict mode of the collection
+    ///
+    /// Accepted operations consume the write rate limit of the collection.
+    /// The rate limit is tracked by each peer separately.
+    pub async fn check_strict_mode_update(
+        &self,
+        operation: &CollectionUpdateOperations,
+    ) -> CollectionResult<()> {
+        let Some(config) = self.collection_config.read().await.params.strict_mode else {
+            return Ok(());
+        };
+        strict_mode::check_update(operation, &config)?;
+        self.check_strict_mode_filter(strict_mode::update_filter(operation), &config)
+            .await?;
+
+        if let Some(write_rate_limit) = config.write_rate_limit {
+            let mut write_rate_limiter = self.write_rate_limiter.lock();
+            let rate_limiter =
+                write_rate_limiter.get_or_insert_with(|| RateLimiter::per_minute(write_rate_limit));
+            if rate_limiter.capacity() != write_rate_limit {
+                *rate_limiter = RateLimiter::per_minute(write_rate_limit);
+            }
+
+            if !rate_limiter.try_consume() {
+                return Err(CollectionError::bad_request(format!(
+                    "Write rate limit of {write_rate_limit} updates per minute is exceeded, \
+                     limit is set by the strict mode of the collection",
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn check_strict_mode_request(
+        &self,
+        request: &impl StrictModeVerification,
+        config: &StrictModeConfig,
+    ) -> CollectionResult<()> {
+        request.check_strict_mode(config)?;
+        self.check_strict_mode_filter(request.query_filter(), config)
+            .await?;
+
+        if let Some((key, schema_type)) = request.indexed_field() {
+            let is_indexed = self.payload_index_schema.read().schema.get(key)
+                == Some(&PayloadFieldSchema::FieldType(schema_type));
+            strict_mode::check_unindexed_field(key, schema_type, is_indexed, config)?;
+        }
+        Ok(())
+    }
+
+    async fn check_strict_mode_filter(
+        &self,
+        filter: Option<&Filter>,
+        config: &StrictModeConfig,
+    ) -> CollectionResult<()> {
+        let Some(filter) = filter else {
+            return Ok(());
+        };
+        if config.unindexed_filtering != Some(false) {
+            return Ok(());
+        }
+
+        let unindexed_key = self.unindexed_filter_key(filter).await;
+        strict_mode::check_unindexed_filter(unindexed_key.as_deref(), config)
+    }
+
+    /// Find the first field of the filter, which conditions the payload index can't serve
+    ///
+    /// Asks the payload index of a local shard. If this peer has no local shards, only checks
+    /// that every field of the filter has a payload index.
+    async fn unindexed_filter_key(&self, filter: &Filter) -> Option<PayloadKeyType> {
+        for replica_set in self.shards_holder.read().await.all_shards() {
+            if let Ok(unindexed_key) = replica_set.unindexed_filter_key(filter).await {
+                return unindexed_key;
+            }
+        }
+
+        let payload_index_schema = self.payload_index_schema.read();
+        let is_indexed = |key: &str| payload_index_schema.schema.contains_key(key);
+        query_estimator::unindexed_filter_key(
+            filter,
+            None,
+            &|condition| is_indexed(&condition.key),
+            &is_indexed,
+        )
+    }
+}
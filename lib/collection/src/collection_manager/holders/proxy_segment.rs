@@ -4,17 +4,21 @@ use std::path::{Path, PathBuf};
 use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
 
+use ordered_float::OrderedFloat;
 use parking_lot::{RwLock, RwLockUpgradableReadGuard};
-use segment::common::operation_error::{OperationResult, SegmentFailedState};
+use segment::common::operation_error::{OperationError, OperationResult, SegmentFailedState};
+use segment::data_types::aggregation::{HistogramParams, NumericAggregation};
+use segment::data_types::distinct::DistinctCounter;
 use segment::data_types::named_vectors::NamedVectors;
+use segment::data_types::text_index::TextStats;
 use segment::data_types::vectors::{QueryVector, Vector};
 use segment::entry::entry_point::SegmentEntry;
 use segment::index::field_index::CardinalityEstimation;
 use segment::telemetry::SegmentTelemetry;
 use segment::types::{
-    Condition, Filter, Payload, PayloadFieldSchema, PayloadKeyType, PayloadKeyTypeRef, PointIdType,
-    ScoredPoint, SearchParams, SegmentConfig, SegmentInfo, SegmentType, SeqNumberType, WithPayload,
-    WithVector,
+    Condition, Filter, OrderBy, Payload, PayloadFieldSchema, PayloadKeyType, PayloadKeyTypeRef,
+    PointIdType, ScoredPoint, SearchParams, SegmentConfig, SegmentInfo, SegmentType, SeqNumberType,
+    WithPayload, WithVector,
 };
 
 use crate::collection_manager::holders::segment_holder::LockedSegment;
@@ -59,6 +63,11 @@ impl ProxySegment {
         }
     }
 
+    /// Re-read config of the wrapped segment, e.g. after its vectors were changed
+    pub fn refresh_wrapped_config(&mut self) {
+        self.wrapped_config = self.wrapped_segment.get().read().config().clone();
+    }
+
     /// Ensure that write segment have same indexes as wrapped segment
     pub fn replicate_field_indexes(&mut self, op_num: SeqNumberType) -> OperationResult<()> {
         let existing_indexes = self.write_segment.get().read().get_indexed_fields();
@@ -506,6 +515,157 @@ impl SegmentEntry for ProxySegment {
         read_points
     }
 
+    fn read_ordered_filtered<'a>(
+        &'a self,
+        offset: Option<PointIdType>,
+        limit: usize,
+        filter: Option<&'a Filter>,
+        order_by: &'a OrderBy,
+    ) -> OperationResult<Vec<(f64, PointIdType)>> {
+        let deleted_points = self.deleted_points.read();
+        let mut read_points = if deleted_points.is_empty() {
+            self.wrapped_segment
+                .get()
+                .read()
+                .read_ordered_filtered(offset, limit, filter, order_by)?
+        } else {
+            let wrapped_filter =
+                self.add_deleted_points_condition_to_filter(filter, &deleted_points);
+            self.wrapped_segment.get().read().read_ordered_filtered(
+                offset,
+                limit,
+                Some(&wrapped_filter),
+                order_by,
+            )?
+        };
+        let mut write_segment_points = self
+            .write_segment
+            .get()
+            .read()
+            .read_ordered_filtered(offset, limit, filter, order_by)?;
+        read_points.append(&mut write_segment_points);
+        read_points.sort_unstable_by_key(|(value, point_id)| (OrderedFloat(*value), *point_id));
+        read_points.truncate(limit);
+        Ok(read_points)
+    }
+
+    fn aggregate_filtered<'a>(
+        &'a self,
+        filter: Option<&'a Filter>,
+        key: PayloadKeyTypeRef,
+        histogram: Option<&'a HistogramParams>,
+    ) -> OperationResult<NumericAggregation> {
+        let deleted_points = self.deleted_points.read();
+        let mut aggregation = if deleted_points.is_empty() {
+            self.wrapped_segment
+                .get()
+                .read()
+                .aggregate_filtered(filter, key, histogram)?
+        } else {
+            let wrapped_filter =
+                self.add_deleted_points_condition_to_filter(filter, &deleted_points);
+            self.wrapped_segment.get().read().aggregate_filtered(
+                Some(&wrapped_filter),
+                key,
+                histogram,
+            )?
+        };
+        let write_segment_aggregation = self
+            .write_segment
+            .get()
+            .read()
+            .aggregate_filtered(filter, key, histogram)?;
+        aggregation.merge(write_segment_aggregation);
+        Ok(aggregation)
+    }
+
+    fn distinct_filtered<'a>(
+        &'a self,
+        filter: Option<&'a Filter>,
+        key: PayloadKeyTypeRef,
+        exact: bool,
+    ) -> OperationResult<DistinctCounter> {
+        let deleted_points = self.deleted_points.read();
+        let mut counter = if deleted_points.is_empty() {
+            self.wrapped_segment
+                .get()
+                .read()
+                .distinct_filtered(filter, key, exact)?
+        } else {
+            let wrapped_filter =
+                self.add_deleted_points_condition_to_filter(filter, &deleted_points);
+            self.wrapped_segment.get().read().distinct_filtered(
+                Some(&wrapped_filter),
+                key,
+                exact,
+            )?
+        };
+        let write_segment_counter = self
+            .write_segment
+            .get()
+            .read()
+            .distinct_filtered(filter, key, exact)?;
+        counter
+            .merge(write_segment_counter, exact)
+            .map_err(|description| OperationError::ValidationError { description })?;
+        Ok(counter)
+    }
+
+    fn text_stats(&self, key: PayloadKeyTypeRef, query: &str) -> OperationResult<TextStats> {
+        // Statistics are approximate, as documents of deleted points are still counted
+        let mut stats = self.wrapped_segment.get().read().text_stats(key, query)?;
+        let write_segment_stats = self.write_segment.get().read().text_stats(key, query)?;
+        stats.merge(write_segment_stats);
+        Ok(stats)
+    }
+
+    fn search_text(
+        &self,
+        key: PayloadKeyTypeRef,
+        query: &str,
+        stats: &TextStats,
+        filter: Option<&Filter>,
+        top: usize,
+        with_payload: &WithPayload,
+        with_vector: &WithVector,
+    ) -> OperationResult<Vec<ScoredPoint>> {
+        let deleted_points = self.deleted_points.read();
+        let mut wrapped_result = if deleted_points.is_empty() {
+            self.wrapped_segment.get().read().search_text(
+                key,
+                query,
+                stats,
+                filter,
+                top,
+                with_payload,
+                with_vector,
+            )?
+        } else {
+            let wrapped_filter =
+                self.add_deleted_points_condition_to_filter(filter, &deleted_points);
+            self.wrapped_segment.get().read().search_text(
+                key,
+                query,
+                stats,
+                Some(&wrapped_filter),
+                top,
+                with_payload,
+                with_vector,
+            )?
+        };
+        let mut write_result = self.write_segment.get().read().search_text(
+            key,
+            query,
+            stats,
+            filter,
+            top,
+            with_payload,
+            with_vector,
+        )?;
+        wrapped_result.append(&mut write_result);
+        Ok(wrapped_result)
+    }
+
     /// Read points in [from; to) range
     fn read_range(&self, from: Option<PointIdType>, to: Option<PointIdType>) -> Vec<PointIdType> {
         let deleted_points = self.deleted_points.read();
@@ -724,6 +884,11 @@ impl SegmentEntry for ProxySegment {
             .collect()
     }
 
+    fn unindexed_filter_key(&self, filter: &Filter) -> Option<PayloadKeyType> {
+        // Write segment replicates indexes of the wrapped segment, including created and deleted ones
+        self.write_segment.get().read().unindexed_filter_key(filter)
+    }
+
     fn check_error(&self) -> Option<SegmentFailedState> {
         self.write_segment.get().read().check_error()
     }
@@ -9,6 +9,7 @@ use segment::common::operation_time_statistics::{
 };
 use segment::index::sparse_index::sparse_index_config::SparseIndexType;
 use segment::types::{HnswConfig, Indexes, QuantizationConfig, SegmentType, VECTOR_ELEMENT_SIZE};
+use sparse::common::datatype::SparseVectorDatatype;
 
 use crate::collection_manager::holders::segment_holder::{LockedSegmentHolder, SegmentId};
 use crate::collection_manager::optimizers::segment_optimizer::{
@@ -71,6 +72,25 @@ impl ConfigMismatchOptimizer {
             .and_then(|index| index.on_disk)
     }
 
+    fn check_if_sparse_vectors_index_compressed(&self, vector_name: &str) -> bool {
+        self.collection_params
+            .sparse_vectors
+            .as_ref()
+            .and_then(|vector_params| vector_params.get(vector_name))
+            .and_then(|params| params.index)
+            .and_then(|index| index.compressed)
+            .unwrap_or_default()
+    }
+
+    fn get_required_sparse_vectors_datatype(&self, vector_name: &str) -> SparseVectorDatatype {
+        self.collection_params
+            .sparse_vectors
+            .as_ref()
+            .and_then(|vector_params| vector_params.get(vector_name))
+            .and_then(|params| params.datatype)
+            .unwrap_or_default()
+    }
+
     /// Calculates and HNSW config that should be used for a given vector
     /// with current configuration.
     ///
@@ -194,16 +214,34 @@ impl ConfigMismatchOptimizer {
                         .sparse_vector_data
                         .iter()
                         .any(|(vector_name, vector_data)| {
+                            // Rebuild indexed segments if the datatype of the weights changed
+                            if vector_data.is_index_immutable()
+                                && vector_data.datatype
+                                    != self.get_required_sparse_vectors_datatype(vector_name)
+                            {
+                                return true;
+                            }
+
                             let Some(is_required_on_disk) =
                                 self.check_if_sparse_vectors_index_on_disk(vector_name)
                             else {
                                 return false; // Do nothing if not specified
                             };
 
+                            let is_required_compressed =
+                                self.check_if_sparse_vectors_index_compressed(vector_name);
+
                             match vector_data.index.index_type {
                                 SparseIndexType::MutableRam => false, // Do nothing for mutable RAM
                                 SparseIndexType::ImmutableRam => is_required_on_disk, // Rebuild if we require on disk
-                                SparseIndexType::Mmap => !is_required_on_disk, // Rebuild if we require in RAM
+                                SparseIndexType::Mmap => {
+                                    // Rebuild if we require in RAM or compressed
+                                    !is_required_on_disk || is_required_compressed
+                                }
+                                SparseIndexType::CompressedMmap => {
+                                    // Rebuild if we require in RAM or uncompressed
+                                    !is_required_on_disk || !is_required_compressed
+                                }
                             }
                         });
                 (sparse_has_mismatch || dense_has_mismatch).then_some((*idx, vector_size))
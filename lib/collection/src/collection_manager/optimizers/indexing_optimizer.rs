@@ -135,7 +135,7 @@ impl IndexingOptimizer {
                     if let Some(vector_data) = segment_config.vector_data.get(vector_name) {
                         let is_indexed = vector_data.index.is_indexed();
                         let is_on_disk = vector_data.storage_type.is_on_disk();
-                        let storage_size = point_count * vector_data.size * VECTOR_ELEMENT_SIZE;
+                        let storage_size = point_count * vector_data.vector_size_bytes();
 
                         let is_big_for_index = storage_size >= indexing_threshold_kb;
                         let is_big_for_mmap = storage_size >= mmap_threshold_kb;
@@ -221,10 +221,16 @@ pub trait SegmentOptimizer {
                             .and_then(|index_params| index_params.on_disk)
                             .unwrap_or(threshold_is_on_disk);
 
+                        let config_compressed = params
+                            .index
+                            .and_then(|index_params| index_params.compressed)
+                            .unwrap_or_default();
+
                         // If mmap OR index is exceeded
                         let is_big = threshold_is_on_disk || threshold_is_indexed;
 
                         let index_type = match (is_big, config_on_disk) {
+                            (true, true) if config_compressed => SparseIndexType::CompressedMmap, // Big, on disk and compressed
                             (true, true) => SparseIndexType::Mmap, // Big and configured on disk
                             (true, false) => SparseIndexType::ImmutableRam, // Big and not on disk nor reached threshold
                             (false, _) => SparseIndexType::MutableRam,      // Small
@@ -15,7 +15,7 @@ use segment::data_types::vectors::QueryVector;
 use segment::entry::entry_point::SegmentEntry;
 use segment::types::{
     Filter, Indexes, PointIdType, ScoredPoint, SearchParams, SegmentConfig, SeqNumberType,
-    WithPayload, WithPayloadInterface, WithVector, VECTOR_ELEMENT_SIZE,
+    WithPayload, WithPayloadInterface, WithVector,
 };
 use tokio::runtime::Handle;
 use tokio::task::JoinHandle;
@@ -332,6 +332,7 @@ impl SegmentsSearcher {
                             }
                         },
                         shard_key: None,
+                        geo_distance: None,
                     },
                 );
                 point_version.insert(id, version);
@@ -349,6 +350,7 @@ pub enum SearchType {
     RecommendBestScore,
     Discover,
     Context,
+    Feedback,
 }
 
 impl From<&QueryEnum> for SearchType {
@@ -358,6 +360,7 @@ impl From<&QueryEnum> for SearchType {
             QueryEnum::RecommendBestScore(_) => Self::RecommendBestScore,
             QueryEnum::Discover(_) => Self::Discover,
             QueryEnum::Context(_) => Self::Context,
+            QueryEnum::Feedback(_) => Self::Feedback,
         }
     }
 }
@@ -568,14 +571,12 @@ fn is_search_optimized(
         .ok_or_else(vector_name_error)?;
 
     // check only dense vectors because sparse vectors are always indexed
-    let vector_size = segment
+    let vector_size_bytes = segment
         .config()
         .vector_data
         .get(vector_name)
         .ok_or_else(vector_name_error)?
-        .size;
-
-    let vector_size_bytes = vector_size * VECTOR_ELEMENT_SIZE;
+        .vector_size_bytes();
 
     let unindexed_vectors = vector_data_info
         .num_vectors
@@ -673,6 +674,7 @@ mod tests {
             limit: 5,
             score_threshold: None,
             offset: 0,
+            with_geo_distance: None,
         };
 
         let batch_request = CoreSearchRequestBatch {
@@ -11,6 +11,7 @@ fn score_point(id: usize, score: ScoreType, version: SeqNumberType) -> ScoredPoi
         payload: None,
         vector: None,
         shard_key: None,
+        geo_distance: None,
     }
 }
 
@@ -0,0 +1,77 @@
+use std::time::{Duration, Instant};
+
+/// Token bucket rate limiter.
+///
+/// The bucket holds up to `capacity` tokens and is refilled with `capacity` tokens per `period`.
+#[derive(Debug)]
+pub struct RateLimiter {
+    capacity: usize,
+    tokens: f64,
+    tokens_per_sec: f64,
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    pub fn new(capacity: usize, period: Duration) -> Self {
+        Self {
+            capacity,
+            tokens: capacity as f64,
+            tokens_per_sec: capacity as f64 / period.as_secs_f64(),
+            last_refill: Instant::now(),
+        }
+    }
+
+    /// Rate limiter, which allows `capacity` operations per minute
+    pub fn per_minute(capacity: usize) -> Self {
+        Self::new(capacity, Duration::from_secs(60))
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Take one token from the bucket.
+    ///
+    /// Returns false, if the bucket is empty.
+    pub fn try_consume(&mut self) -> bool {
+        self.refill(Instant::now());
+
+        if self.tokens < 1.0 {
+            return false;
+        }
+
+        self.tokens -= 1.0;
+        true
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.last_refill);
+        self.tokens =
+            (self.tokens + elapsed.as_secs_f64() * self.tokens_per_sec).min(self.capacity as f64);
+        self.last_refill = now;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rate_limiter() {
+        let mut limiter = RateLimiter::new(2, Duration::from_secs(1));
+        let start = limiter.last_refill;
+
+        assert!(limiter.try_consume());
+        assert!(limiter.try_consume());
+        assert!(!limiter.try_consume());
+
+        // Half a period refills half of the bucket
+        limiter.refill(start + Duration::from_millis(500));
+        assert!(limiter.tokens >= 1.0);
+        limiter.tokens -= 1.0;
+
+        // Bucket never holds more than its capacity
+        limiter.refill(start + Duration::from_secs(10));
+        assert_eq!(limiter.tokens, 2.0);
+    }
+}
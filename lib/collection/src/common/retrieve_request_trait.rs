@@ -22,9 +22,7 @@ impl RetrieveRequest for RecommendRequestInternal {
     }
 
     fn get_referenced_point_ids(&self) -> Vec<PointIdType> {
-        self.positive
-            .iter()
-            .chain(self.negative.iter())
+        self.examples()
             .filter_map(|example| example.as_point_id())
             .collect()
     }
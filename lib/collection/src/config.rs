@@ -1,4 +1,4 @@
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs::File;
 use std::io::{Read, Write};
 use std::num::NonZeroU32;
@@ -19,6 +19,7 @@ use validator::Validate;
 use wal::WalOptions;
 
 use crate::operations::config_diff::{DiffConfig, QuantizationConfigDiff};
+use crate::operations::payload_schema::PayloadSchema;
 use crate::operations::types::{
     CollectionError, CollectionResult, SparseVectorParams, SparseVectorsConfig, VectorParams,
     VectorParamsDiff, VectorsConfig, VectorsConfigDiff,
@@ -63,6 +64,71 @@ pub enum ShardingMethod {
     Custom,
 }
 
+/// Configuration of points expiry.
+/// Points expire at the unix timestamp (in seconds) stored in their `expire_at` payload field.
+/// Expired points are excluded from search, scroll and count, and are purged in the background.
+/// An integer payload index is created on `expire_at`, unless it is indexed as a number already.
+#[derive(
+    Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, Copy, PartialEq, Eq, Hash, Default,
+)]
+#[serde(rename_all = "snake_case")]
+pub struct ExpiryConfig {
+    /// Time-to-live in seconds of points upserted without an explicit `expire_at` payload value.
+    /// If not set - only points with explicit `expire_at` value expire.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1))]
+    pub ttl: Option<u64>,
+}
+
+/// Strict mode configuration.
+/// Limits the cost of requests to the collection, requests exceeding the limits are rejected.
+/// Limits, which are not set, are not enforced.
+#[derive(
+    Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, Copy, PartialEq, Eq, Hash, Default,
+)]
+#[serde(rename_all = "snake_case")]
+pub struct StrictModeConfig {
+    /// Max `limit` of search, recommend, discover, group and scroll requests
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1))]
+    pub max_query_limit: Option<usize>,
+    /// Max `offset` of search, recommend and discover requests
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_query_offset: Option<usize>,
+    /// Max `hnsw_ef` search parameter
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1))]
+    pub max_hnsw_ef: Option<usize>,
+    /// If false - exact search requests are rejected
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub search_allow_exact: Option<bool>,
+    /// If false - filters on fields, which have no payload index suitable for the condition,
+    /// are rejected
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unindexed_filtering: Option<bool>,
+    /// Max number of requests in a batch search, recommend, discover or query request
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1))]
+    pub max_search_batch_size: Option<usize>,
+    /// Max number of points listed in a single update, e.g. upserted points or points to set
+    /// payload for
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1))]
+    pub max_update_points: Option<usize>,
+    /// Max size of the payload of a single point in bytes, measured as serialized JSON
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1))]
+    pub max_payload_size: Option<usize>,
+    /// Max number of histogram buckets of aggregate requests
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1))]
+    pub max_histogram_buckets: Option<usize>,
+    /// Max number of update requests per minute
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1))]
+    pub write_rate_limit: Option<usize>,
+}
+
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub struct CollectionParams {
@@ -104,6 +170,23 @@ pub struct CollectionParams {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[validate]
     pub sparse_vectors: Option<BTreeMap<String, SparseVectorParams>>,
+    /// If true - shards of this collection are automatically moved between peers
+    /// to keep the number of shards and their size balanced across the cluster.
+    /// Default is false.
+    #[serde(default)]
+    pub auto_rebalance: bool,
+    /// If set - points of this collection expire according to the given configuration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub expiry: Option<ExpiryConfig>,
+    /// If set - payload of upserted points and payload updates are validated against this schema.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub payload_schema: Option<PayloadSchema>,
+    /// If set - requests exceeding the limits of the strict mode are rejected.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub strict_mode: Option<StrictModeConfig>,
 }
 
 impl Anonymize for CollectionParams {
@@ -117,6 +200,10 @@ impl Anonymize for CollectionParams {
             read_fan_out_factor: self.read_fan_out_factor,
             on_disk_payload: self.on_disk_payload,
             sparse_vectors: self.sparse_vectors.anonymize(),
+            auto_rebalance: self.auto_rebalance,
+            expiry: self.expiry,
+            payload_schema: self.payload_schema.clone(),
+            strict_mode: self.strict_mode,
         }
     }
 }
@@ -194,6 +281,10 @@ impl CollectionParams {
             read_fan_out_factor: None,
             on_disk_payload: default_on_disk_payload(),
             sparse_vectors: None,
+            auto_rebalance: false,
+            expiry: None,
+            payload_schema: None,
+            strict_mode: None,
         }
     }
 
@@ -300,7 +391,12 @@ impl CollectionParams {
     ) -> CollectionResult<()> {
         for (vector_name, update_params) in update_vectors.0.iter() {
             let sparse_vector_params = self.get_sparse_vector_params_mut(vector_name)?;
-            let SparseVectorParams { index } = update_params.clone();
+            let SparseVectorParams {
+                index,
+                prune_threshold,
+                max_dims,
+                datatype,
+            } = update_params.clone();
 
             if let Some(index) = index {
                 if let Some(existing_index) = &mut sparse_vector_params.index {
@@ -309,6 +405,95 @@ impl CollectionParams {
                     sparse_vector_params.index = Some(index);
                 }
             }
+            if let Some(prune_threshold) = prune_threshold {
+                sparse_vector_params.prune_threshold = Some(prune_threshold);
+            }
+            if let Some(max_dims) = max_dims {
+                sparse_vector_params.max_dims = Some(max_dims);
+            }
+            if let Some(datatype) = datatype {
+                sparse_vector_params.datatype = Some(datatype);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn contains_vector(&self, vector_name: &str) -> bool {
+        self.vectors.get_params(vector_name).is_some()
+            || self
+                .sparse_vectors
+                .as_ref()
+                .is_some_and(|sparse_vectors| sparse_vectors.contains_key(vector_name))
+    }
+
+    /// Add new vectors and sparse vectors to the collection
+    ///
+    /// Names of new vectors must not be used by existing vectors or sparse vectors.
+    pub fn create_vectors(
+        &mut self,
+        vectors: Option<&VectorsConfig>,
+        sparse_vectors: Option<&SparseVectorsConfig>,
+    ) -> CollectionResult<()> {
+        let dense_names = vectors
+            .into_iter()
+            .flat_map(|vectors| vectors.params_iter().map(|(name, _)| name));
+        let sparse_names = sparse_vectors
+            .into_iter()
+            .flat_map(|sparse_vectors| sparse_vectors.0.keys().map(String::as_str));
+
+        let mut new_names = HashSet::new();
+        for vector_name in dense_names.chain(sparse_names) {
+            if self.contains_vector(vector_name) || !new_names.insert(vector_name) {
+                return Err(CollectionError::BadInput {
+                    description: format!("Vector {vector_name} already exists in the collection"),
+                });
+            }
+        }
+
+        if let Some(vectors) = vectors {
+            for (vector_name, params) in vectors.params_iter() {
+                self.vectors.insert(vector_name.to_string(), params.clone());
+            }
+        }
+        if let Some(sparse_vectors) = sparse_vectors {
+            self.sparse_vectors
+                .get_or_insert_with(Default::default)
+                .extend(sparse_vectors.0.clone());
+        }
+        Ok(())
+    }
+
+    /// Remove vectors or sparse vectors from the collection
+    ///
+    /// At least one vector must remain in the collection.
+    pub fn delete_vectors(&mut self, vector_names: &[String]) -> CollectionResult<()> {
+        let removed_names: HashSet<_> = vector_names.iter().map(String::as_str).collect();
+        if let Some(vector_name) = removed_names
+            .iter()
+            .find(|vector_name| !self.contains_vector(vector_name))
+        {
+            return Err(CollectionError::BadInput {
+                description: format!("Vector {vector_name} doesn't exist in the collection"),
+            });
+        }
+
+        let vectors_count =
+            self.vectors.vectors_num() + self.sparse_vectors.as_ref().map_or(0, BTreeMap::len);
+        if removed_names.len() >= vectors_count {
+            return Err(CollectionError::BadInput {
+                description: "Collection must keep at least one vector".to_string(),
+            });
+        }
+
+        for vector_name in removed_names {
+            if self.vectors.remove(vector_name).is_none() {
+                if let Some(sparse_vectors) = &mut self.sparse_vectors {
+                    sparse_vectors.remove(vector_name);
+                }
+            }
+        }
+        if self.sparse_vectors.as_ref().is_some_and(BTreeMap::is_empty) {
+            self.sparse_vectors = None;
         }
         Ok(())
     }
@@ -363,6 +548,7 @@ impl CollectionParams {
                                     .and_then(|index| index.full_scan_threshold),
                                 index_type: SparseIndexType::MutableRam,
                             },
+                            datatype: params.datatype.unwrap_or_default(),
                         },
                     )
                 })
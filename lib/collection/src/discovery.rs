@@ -111,6 +111,7 @@ fn discovery_into_core_search(
         with_payload: request.with_payload,
         with_vector: request.with_vector,
         score_threshold: None,
+        with_geo_distance: None,
     };
 
     Ok(core_search)
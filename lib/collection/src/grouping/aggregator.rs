@@ -197,6 +197,7 @@ mod unit_tests {
             payload: Some(Payload::from(serde_json::json!({ "docId": payloads }))),
             vector: None,
             shard_key: None,
+            geo_distance: None,
         }
     }
 
@@ -208,6 +209,7 @@ mod unit_tests {
             payload: None,
             vector: None,
             shard_key: None,
+            geo_distance: None,
         }
     }
 
@@ -20,7 +20,7 @@ use crate::operations::types::{
     BaseGroupRequest, CollectionResult, PointGroup, RecommendGroupsRequestInternal,
     RecommendRequestInternal, SearchGroupsRequestInternal, SearchRequestInternal,
 };
-use crate::recommendations::recommend_into_core_search;
+use crate::recommendations::{check_avg_vector_supported, recommend_into_core_search};
 
 const MAX_GET_GROUPS_REQUESTS: usize = 5;
 const MAX_GROUP_FILLING_REQUESTS: usize = 5;
@@ -82,6 +82,11 @@ impl GroupRequest {
         let core_search = match self.source {
             SourceRequest::Search(search_req) => search_req.into(),
             SourceRequest::Recommend(recommend_req) => {
+                check_avg_vector_supported(
+                    &recommend_req,
+                    &collection.collection_config.read().await.params,
+                )?;
+
                 let referenced_vectors = fetch_vectors::resolve_referenced_vectors_batch(
                     &[(recommend_req.clone(), shard_selection)],
                     collection,
@@ -181,6 +186,8 @@ impl From<RecommendGroupsRequestInternal> for GroupRequest {
         let RecommendGroupsRequestInternal {
             positive,
             negative,
+            feedback,
+            context,
             strategy,
             filter,
             params,
@@ -201,6 +208,8 @@ impl From<RecommendGroupsRequestInternal> for GroupRequest {
         let recommend = RecommendRequestInternal {
             positive,
             negative,
+            feedback,
+            context,
             strategy,
             filter,
             params,
@@ -469,6 +478,7 @@ mod tests {
                         payload: None,
                         vector: None,
                         shard_key: None,
+                        geo_distance: None,
                     },
                     ScoredPoint {
                         id: 2.into(),
@@ -477,6 +487,7 @@ mod tests {
                         payload: None,
                         vector: None,
                         shard_key: None,
+                        geo_distance: None,
                     },
                 ],
             ),
@@ -490,6 +501,7 @@ mod tests {
                         payload: None,
                         vector: None,
                         shard_key: None,
+                        geo_distance: None,
                     },
                     ScoredPoint {
                         id: 4.into(),
@@ -498,6 +510,7 @@ mod tests {
                         payload: None,
                         vector: None,
                         shard_key: None,
+                        geo_distance: None,
                     },
                 ],
             ),
@@ -522,6 +535,7 @@ mod tests {
                 payload: Some(payload_a.clone()),
                 vector: None,
                 shard_key: None,
+                geo_distance: None,
             },
             ScoredPoint {
                 id: 2.into(),
@@ -530,6 +544,7 @@ mod tests {
                 payload: Some(payload_a.clone()),
                 vector: None,
                 shard_key: None,
+                geo_distance: None,
             },
             ScoredPoint {
                 id: 3.into(),
@@ -538,6 +553,7 @@ mod tests {
                 payload: Some(payload_b.clone()),
                 vector: None,
                 shard_key: None,
+                geo_distance: None,
             },
             ScoredPoint {
                 id: 4.into(),
@@ -546,6 +562,7 @@ mod tests {
                 payload: Some(payload_b.clone()),
                 vector: None,
                 shard_key: None,
+                geo_distance: None,
             },
         ];
 
@@ -1,26 +1,40 @@
 pub mod types;
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use futures::Future;
 use itertools::Itertools;
 use schemars::JsonSchema;
-use segment::types::{PointIdType, WithPayloadInterface, WithVector};
+use segment::data_types::groups::GroupId;
+use segment::types::{
+    AnyVariants, Condition, FieldCondition, Filter, Payload, PayloadContainer, PayloadKeyType,
+    PointIdType, WithPayload, WithPayloadInterface, WithVector,
+};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use tokio::sync::RwLockReadGuard;
 use types::PseudoId;
+use validator::Validate;
 
 use crate::collection::Collection;
 use crate::operations::consistency_params::ReadConsistency;
 use crate::operations::shard_selector_internal::ShardSelectorInternal;
-use crate::operations::types::{CollectionError, CollectionResult, PointRequestInternal, Record};
+use crate::operations::types::{
+    CollectionError, CollectionResult, PointRequestInternal, Record, ScrollRequestInternal,
+};
 
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
 pub struct WithLookup {
     /// Name of the collection to use for points lookup
     #[serde(rename = "collection")]
     pub collection_name: String,
 
+    /// Payload field which links the result points to the points of the lookup collection.
+    /// Required for search, recommend and scroll. Groups are always linked by the group id.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub link: Option<LookupLink>,
+
     /// Options for specifying which payload to include (or not)
     #[serde(default = "default_with_payload")]
     pub with_payload: Option<WithPayloadInterface>,
@@ -31,6 +45,28 @@ pub struct WithLookup {
     pub with_vectors: Option<WithVector>,
 }
 
+/// Defines how a point is linked to the points of another collection
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
+#[serde(rename_all = "snake_case")]
+pub struct LookupLink {
+    /// Payload key of the result point, which holds the link value(s)
+    #[validate(length(min = 1))]
+    pub key: PayloadKeyType,
+
+    /// Keyword or integer payload key of the lookup collection to match the link value against.
+    /// If not specified, the link value is used as the id of the point in the lookup collection.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 1))]
+    pub target_key: Option<PayloadKeyType>,
+
+    /// Max number of records to attach per link value, if matched by `target_key`. Default: 10
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1))]
+    pub limit: Option<usize>,
+}
+
+const DEFAULT_LOOKUP_LINK_LIMIT: usize = 10;
+
 const fn default_with_payload() -> Option<WithPayloadInterface> {
     Some(WithPayloadInterface::Bool(true))
 }
@@ -76,3 +112,253 @@ where
 
     Ok(result)
 }
+
+/// Looks up records of another collection, which are linked to the given points through
+/// the payload field defined in `request.link`.
+///
+/// Link values are read from the given payloads. If a payload is not provided or does not
+/// contain the link key, the key is retrieved from the source `collection`.
+///
+/// Returns the looked up records for each point which has at least one match.
+pub async fn lookup_links<'a, F, Fut>(
+    request: WithLookup,
+    collection: &Collection,
+    points: Vec<(PointIdType, Option<&Payload>)>,
+    collection_by_name: F,
+    read_consistency: Option<ReadConsistency>,
+    shard_selection: &ShardSelectorInternal,
+) -> CollectionResult<HashMap<PointIdType, Vec<Record>>>
+where
+    F: FnOnce(String) -> Fut,
+    Fut: Future<Output = Option<RwLockReadGuard<'a, Collection>>>,
+{
+    let Some(link) = request.link.clone() else {
+        return Err(CollectionError::bad_request(format!(
+            "Lookup in collection {} requires `link` to be specified",
+            request.collection_name,
+        )));
+    };
+
+    let mut link_values: HashMap<PointIdType, Vec<PseudoId>> = HashMap::new();
+    let mut missing_ids = Vec::new();
+
+    for (point_id, payload) in points {
+        match payload.and_then(|payload| payload.get_value_opt(&link.key)) {
+            Some(values) => {
+                link_values.insert(point_id, values_to_pseudo_ids(values));
+            }
+            None => missing_ids.push(point_id),
+        }
+    }
+
+    if !missing_ids.is_empty() {
+        let link_request = PointRequestInternal {
+            ids: missing_ids,
+            with_payload: Some(WithPayloadInterface::Fields(vec![link.key.clone()])),
+            with_vector: WithVector::Bool(false),
+        };
+
+        let records = collection
+            .retrieve(link_request, read_consistency, shard_selection)
+            .await?;
+
+        for record in records {
+            let values = record
+                .payload
+                .as_ref()
+                .map(|payload| values_to_pseudo_ids(payload.get_value(&link.key)))
+                .unwrap_or_default();
+            link_values.insert(record.id, values);
+        }
+    }
+
+    let unique_values = link_values
+        .values()
+        .flatten()
+        .unique()
+        .cloned()
+        .collect_vec();
+
+    if unique_values.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    // Linked points may be located in any shard of the lookup collection
+    let lookups: HashMap<PseudoId, Vec<Record>> = match &link.target_key {
+        None => lookup_ids(
+            request,
+            unique_values,
+            collection_by_name,
+            read_consistency,
+            &ShardSelectorInternal::All,
+        )
+        .await?
+        .into_iter()
+        .map(|(value, record)| (value, vec![record]))
+        .collect(),
+        Some(target_key) => {
+            lookup_matches(
+                request.clone(),
+                target_key,
+                link.limit.unwrap_or(DEFAULT_LOOKUP_LINK_LIMIT),
+                unique_values,
+                collection_by_name,
+                read_consistency,
+            )
+            .await?
+        }
+    };
+
+    let result = link_values
+        .into_iter()
+        .filter_map(|(point_id, values)| {
+            let records = values
+                .iter()
+                .filter_map(|value| lookups.get(value))
+                .flatten()
+                .cloned()
+                .collect_vec();
+            (!records.is_empty()).then_some((point_id, records))
+        })
+        .collect();
+
+    Ok(result)
+}
+
+/// Finds records of the lookup collection, which have one of the `values` in `target_key` field.
+///
+/// All values are matched by a single scroll, which is paged until every value has `limit`
+/// records or there are no more matching points.
+async fn lookup_matches<'a, F, Fut>(
+    request: WithLookup,
+    target_key: &PayloadKeyType,
+    limit: usize,
+    values: Vec<PseudoId>,
+    collection_by_name: F,
+    read_consistency: Option<ReadConsistency>,
+) -> CollectionResult<HashMap<PseudoId, Vec<Record>>>
+where
+    F: FnOnce(String) -> Fut,
+    Fut: Future<Output = Option<RwLockReadGuard<'a, Collection>>>,
+{
+    let collection = collection_by_name(request.collection_name.clone())
+        .await
+        .ok_or(CollectionError::NotFound {
+            what: format!("Collection {}", request.collection_name),
+        })?;
+
+    let requested_values: HashSet<PseudoId> = values.into_iter().collect();
+    let mut keywords = Vec::new();
+    let mut integers = Vec::new();
+    for value in &requested_values {
+        match GroupId::from(value.clone()) {
+            GroupId::String(keyword) => keywords.push(keyword),
+            GroupId::NumberI64(number) => integers.push(number),
+            // Integer payload values are always stored as i64
+            GroupId::NumberU64(number) => integers.extend(i64::try_from(number).ok()),
+        }
+    }
+    let conditions = [
+        (!keywords.is_empty()).then_some(AnyVariants::Keywords(keywords)),
+        (!integers.is_empty()).then_some(AnyVariants::Integers(integers)),
+    ]
+    .into_iter()
+    .flatten()
+    .map(|any| Condition::Field(FieldCondition::new_match(target_key.clone(), any.into())))
+    .collect_vec();
+    if conditions.is_empty() {
+        return Ok(HashMap::new());
+    }
+    let filter = Filter {
+        should: Some(conditions),
+        must: None,
+        must_not: None,
+    };
+
+    // The whole payload is required to assign records to the link values,
+    // requested payload selection is applied afterwards
+    let with_payload = WithPayload::from(
+        request
+            .with_payload
+            .as_ref()
+            .unwrap_or(&WithPayloadInterface::Bool(true)),
+    );
+    let with_vector = request.with_vectors.clone().unwrap_or_default();
+
+    let mut result: HashMap<PseudoId, Vec<Record>> = HashMap::new();
+    let mut offset = None;
+    loop {
+        let scroll_request = ScrollRequestInternal {
+            offset,
+            limit: Some(limit * requested_values.len()),
+            filter: Some(filter.clone()),
+            with_payload: Some(WithPayloadInterface::Bool(true)),
+            with_vector: with_vector.clone(),
+            order_by: None,
+        };
+        let page = collection
+            .scroll_by(
+                scroll_request,
+                read_consistency,
+                &ShardSelectorInternal::All,
+            )
+            .await?;
+
+        for record in page.points {
+            let matched_values = record
+                .payload
+                .as_ref()
+                .map(|payload| values_to_pseudo_ids(payload.get_value(target_key)))
+                .unwrap_or_default();
+            let record = select_payload(record, &with_payload);
+            // Records may also hold values, which are not linked to any of the result points
+            let linked_values = matched_values
+                .into_iter()
+                .unique()
+                .filter(|value| requested_values.contains(value));
+            for value in linked_values {
+                let records = result.entry(value).or_default();
+                if records.len() < limit {
+                    records.push(record.clone());
+                }
+            }
+        }
+
+        let saturated = requested_values.iter().all(|value| {
+            result
+                .get(value)
+                .map_or(false, |records| records.len() >= limit)
+        });
+        match page.next_page_offset {
+            Some(next_offset) if !saturated => offset = Some(next_offset),
+            _ => break,
+        }
+    }
+
+    Ok(result)
+}
+
+/// Apply payload selection of the lookup request to the record, retrieved with the whole payload
+fn select_payload(mut record: Record, with_payload: &WithPayload) -> Record {
+    record.payload = match (with_payload.enable, record.payload) {
+        (false, _) | (_, None) => None,
+        (true, Some(payload)) => Some(match &with_payload.payload_selector {
+            Some(selector) => selector.process(payload),
+            None => payload,
+        }),
+    };
+    record
+}
+
+/// Converts payload values into link values, skipping the ones which can't be used as a link
+fn values_to_pseudo_ids<'v>(values: impl IntoIterator<Item = &'v Value>) -> Vec<PseudoId> {
+    values
+        .into_iter()
+        .flat_map(|value| match value {
+            Value::Array(array) => array.iter().collect(),
+            _ => vec![value],
+        })
+        .filter_map(|value| GroupId::try_from(value).ok())
+        .map(PseudoId::from)
+        .collect()
+}
@@ -7,6 +7,7 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use super::WithLookup;
+use crate::operations::types::Record;
 
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 #[serde(untagged)]
@@ -20,6 +21,7 @@ impl From<WithLookupInterface> for WithLookup {
         match with_lookup {
             WithLookupInterface::Collection(collection_name) => Self {
                 collection_name,
+                link: None,
                 with_payload: Some(true.into()),
                 with_vectors: Some(false.into()),
             },
@@ -28,6 +30,16 @@ impl From<WithLookupInterface> for WithLookup {
     }
 }
 
+/// Result point together with the records of another collection, which are linked to it
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct PointWithLookup<T> {
+    #[serde(flatten)]
+    pub point: T,
+    /// Records that have been looked up using the link of the point
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lookup: Option<Vec<Record>>,
+}
+
 /// A value that can be used as a temporary ID
 #[derive(Debug, Eq, PartialEq, Clone, Hash)]
 pub enum PseudoId {
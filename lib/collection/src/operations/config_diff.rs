@@ -2,13 +2,16 @@ use std::num::NonZeroU32;
 
 use merge::Merge;
 use schemars::JsonSchema;
-use segment::types::{BinaryQuantization, HnswConfig, ProductQuantization, ScalarQuantization};
+use segment::types::{
+    BinaryQuantization, HnswConfig, ProductQuantization, QuantizationConfig, ScalarQuantization,
+};
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use validator::{Validate, ValidationErrors};
 
-use crate::config::{CollectionParams, WalConfig};
+use crate::config::{CollectionParams, ExpiryConfig, StrictModeConfig, WalConfig};
+use crate::operations::payload_schema::PayloadSchema;
 use crate::operations::types::CollectionResult;
 use crate::optimizers_builder::OptimizersConfig;
 
@@ -93,7 +96,9 @@ pub struct WalConfigDiff {
     pub wal_segments_ahead: Option<usize>,
 }
 
-#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Merge, PartialEq, Eq, Hash)]
+#[derive(
+    Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, Merge, PartialEq, Eq, Hash,
+)]
 pub struct CollectionParamsDiff {
     /// Number of replicas for each shard
     pub replication_factor: Option<NonZeroU32>,
@@ -107,6 +112,25 @@ pub struct CollectionParamsDiff {
     /// Note: those payload values that are involved in filtering and are indexed - remain in RAM.
     #[serde(default)]
     pub on_disk_payload: Option<bool>,
+    /// If true - shards of this collection are automatically moved between peers
+    /// to keep the number of shards and their size balanced across the cluster.
+    #[serde(default)]
+    pub auto_rebalance: Option<bool>,
+    /// Configuration of points expiry.
+    /// Points expire at the unix timestamp (in seconds) stored in their `expire_at` payload field.
+    #[serde(default)]
+    #[validate]
+    pub expiry: Option<ExpiryConfig>,
+    /// Schema, which payload of upserted points and payload updates are validated against.
+    /// To disable validation, set an empty schema.
+    #[serde(default)]
+    #[validate]
+    pub payload_schema: Option<PayloadSchema>,
+    /// Limits of requests to the collection, requests exceeding the limits are rejected.
+    /// To disable strict mode, set an empty config.
+    #[serde(default)]
+    #[validate]
+    pub strict_mode: Option<StrictModeConfig>,
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, Merge)]
@@ -307,6 +331,16 @@ impl QuantizationConfigDiff {
     pub fn new_disabled() -> Self {
         QuantizationConfigDiff::Disabled(Disabled::Disabled)
     }
+
+    /// Quantization config, which replaces the current one
+    pub fn into_config(self) -> Option<QuantizationConfig> {
+        match self {
+            QuantizationConfigDiff::Scalar(scalar) => Some(QuantizationConfig::Scalar(scalar)),
+            QuantizationConfigDiff::Product(product) => Some(QuantizationConfig::Product(product)),
+            QuantizationConfigDiff::Binary(binary) => Some(QuantizationConfig::Binary(binary)),
+            QuantizationConfigDiff::Disabled(_) => None,
+        }
+    }
 }
 
 impl Validate for QuantizationConfigDiff {
@@ -349,6 +383,10 @@ mod tests {
             write_consistency_factor: Some(NonZeroU32::new(2).unwrap()),
             read_fan_out_factor: None,
             on_disk_payload: None,
+            auto_rebalance: None,
+            expiry: None,
+            payload_schema: None,
+            strict_mode: None,
         };
 
         let new_params = diff.update(&params).unwrap();
@@ -1,13 +1,21 @@
 use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
 
 use api::grpc::qdrant::{
     read_consistency, ReadConsistency as ReadConsistencyGrpc,
     ReadConsistencyType as ReadConsistencyTypeGrpc,
 };
+use schemars::gen::SchemaGenerator;
+use schemars::schema::Schema;
 use schemars::JsonSchema;
+use segment::types::SeqNumberType;
 use serde::{Deserialize, Serialize};
 use validator::{Validate, ValidationError as ValidatorError, ValidationErrors};
 
+use crate::shards::shard::{PeerId, ShardId};
+
 /// Read consistency parameter
 ///
 /// Defines how many replicas should be queried to get the result
@@ -57,7 +65,138 @@ impl ReadConsistency {
     pub fn try_from_optional(
         consistency: Option<ReadConsistencyGrpc>,
     ) -> Result<Option<Self>, tonic::Status> {
-        consistency.map(TryFrom::try_from).transpose()
+        match consistency {
+            // Only the consistency token is specified, use default read consistency
+            Some(ReadConsistencyGrpc {
+                value: None,
+                consistency_token: Some(_),
+            }) => Ok(None),
+            consistency => consistency.map(TryFrom::try_from).transpose(),
+        }
+    }
+}
+
+/// Token of a write operation, which allows to read its results from any replica
+///
+/// Holds the number of the operation in the WAL of every replica which has acknowledged it.
+/// Serialized as an opaque string, e.g. `0:1=15,2=17;3:1=9` for shard `0` written as operation
+/// `15` on peer `1` and as operation `17` on peer `2`, and shard `3` written on peer `1` only.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct ConsistencyToken {
+    shards: BTreeMap<ShardId, BTreeMap<PeerId, SeqNumberType>>,
+}
+
+impl ConsistencyToken {
+    /// Token of a single shard
+    pub fn new(shard_id: ShardId, operations: BTreeMap<PeerId, SeqNumberType>) -> Self {
+        Self {
+            shards: BTreeMap::from([(shard_id, operations)]),
+        }
+    }
+
+    pub fn try_from_optional(
+        consistency: Option<&ReadConsistencyGrpc>,
+    ) -> Result<Option<Self>, tonic::Status> {
+        consistency
+            .and_then(|consistency| consistency.consistency_token.as_deref())
+            .map(|token| token.parse().map_err(tonic::Status::invalid_argument))
+            .transpose()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.shards.is_empty()
+    }
+
+    /// Operation numbers by peer, for every shard
+    pub fn shards(&self) -> impl Iterator<Item = (ShardId, &BTreeMap<PeerId, SeqNumberType>)> {
+        self.shards
+            .iter()
+            .map(|(shard_id, operations)| (*shard_id, operations))
+    }
+
+    /// Combine with the token of another write, keeping the latest operation of every replica
+    pub fn merge(&mut self, other: ConsistencyToken) {
+        for (shard_id, operations) in other.shards {
+            let shard = self.shards.entry(shard_id).or_default();
+            for (peer_id, operation_id) in operations {
+                let shard_operation = shard.entry(peer_id).or_default();
+                *shard_operation = (*shard_operation).max(operation_id);
+            }
+        }
+    }
+}
+
+impl fmt::Display for ConsistencyToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (shard_id, operations)) in self.shards.iter().enumerate() {
+            if i > 0 {
+                write!(f, ";")?;
+            }
+            write!(f, "{shard_id}:")?;
+            for (j, (peer_id, operation_id)) in operations.iter().enumerate() {
+                if j > 0 {
+                    write!(f, ",")?;
+                }
+                write!(f, "{peer_id}={operation_id}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for ConsistencyToken {
+    type Err = String;
+
+    fn from_str(token: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid consistency token: {token}");
+
+        let mut shards = BTreeMap::new();
+        for shard in token.split(';').filter(|shard| !shard.is_empty()) {
+            let (shard_id, operations) = shard.split_once(':').ok_or_else(invalid)?;
+            let shard_id: ShardId = shard_id.parse().map_err(|_| invalid())?;
+
+            let operations = operations
+                .split(',')
+                .map(|operation| {
+                    let (peer_id, operation_id) = operation.split_once('=')?;
+                    Some((peer_id.parse().ok()?, operation_id.parse().ok()?))
+                })
+                .collect::<Option<BTreeMap<PeerId, SeqNumberType>>>()
+                .ok_or_else(invalid)?;
+
+            shards.insert(shard_id, operations);
+        }
+
+        Ok(Self { shards })
+    }
+}
+
+impl TryFrom<String> for ConsistencyToken {
+    type Error = String;
+
+    fn try_from(token: String) -> Result<Self, Self::Error> {
+        token.parse()
+    }
+}
+
+impl From<ConsistencyToken> for String {
+    fn from(token: ConsistencyToken) -> Self {
+        token.to_string()
+    }
+}
+
+impl JsonSchema for ConsistencyToken {
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn schema_name() -> String {
+        "ConsistencyToken".to_string()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        String::json_schema(gen)
     }
 }
 
@@ -103,7 +242,10 @@ impl From<ReadConsistency> for ReadConsistencyGrpc {
             ReadConsistency::Type(consistency) => read_consistency::Value::Type(consistency.into()),
         };
 
-        ReadConsistencyGrpc { value: Some(value) }
+        ReadConsistencyGrpc {
+            value: Some(value),
+            consistency_token: None,
+        }
     }
 }
 
@@ -243,4 +385,21 @@ mod tests {
         let schema_str = serde_json::to_string_pretty(&schema).unwrap();
         println!("{schema_str}")
     }
+
+    #[test]
+    fn test_consistency_token() {
+        let mut token = ConsistencyToken::new(0, BTreeMap::from([(1, 15), (2, 17)]));
+        token.merge(ConsistencyToken::new(3, BTreeMap::from([(1, 9)])));
+        token.merge(ConsistencyToken::new(0, BTreeMap::from([(1, 12), (2, 18)])));
+
+        let json = serde_json::to_string(&token).unwrap();
+        assert_eq!(json, "\"0:1=15,2=18;3:1=9\"");
+
+        let parsed: ConsistencyToken = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, token);
+
+        assert!("".parse::<ConsistencyToken>().unwrap().is_empty());
+        assert!("0:1".parse::<ConsistencyToken>().is_err());
+        assert!("x:1=2".parse::<ConsistencyToken>().is_err());
+    }
 }
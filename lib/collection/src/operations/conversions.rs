@@ -14,25 +14,28 @@ use api::grpc::qdrant::{CreateShardKey, SearchPoints};
 use common::types::ScoreType;
 use itertools::Itertools;
 use segment::data_types::vectors::{Named, NamedQuery, Vector, VectorStruct, DEFAULT_VECTOR_NAME};
-use segment::types::{Distance, QuantizationConfig};
+use segment::types::{Distance, QuantizationConfig, ScoredPoint, ValueVariants};
 use segment::vector_storage::query::context_query::{ContextPair, ContextQuery};
 use segment::vector_storage::query::discovery_query::DiscoveryQuery;
+use segment::vector_storage::query::feedback_query::{FeedbackItem, FeedbackQuery};
 use segment::vector_storage::query::reco_query::RecoQuery;
+use sparse::common::datatype::SparseVectorDatatype;
 use tonic::Status;
+use validator::Validate;
 
 use super::consistency_params::ReadConsistency;
 use super::types::{
-    BaseGroupRequest, ContextExamplePair, CoreSearchRequest, DiscoverRequestInternal, GroupsResult,
-    PointGroup, QueryEnum, RecommendExample, RecommendGroupsRequestInternal, RecommendStrategy,
-    SearchGroupsRequestInternal, SparseIndexParams, SparseVectorParams, VectorParamsDiff,
-    VectorsConfigDiff,
+    BaseGroupRequest, ContextExamplePair, CoreSearchRequest, DiscoverRequestInternal,
+    FeedbackExample, GroupsResult, PointGroup, QueryEnum, RecommendExample,
+    RecommendGroupsRequestInternal, RecommendStrategy, SearchGroupsRequestInternal,
+    SparseIndexParams, SparseVectorParams, VectorParamsDiff, VectorsConfigDiff,
 };
 use crate::config::{
     default_replication_factor, default_write_consistency_factor, CollectionConfig,
-    CollectionParams, ShardingMethod, WalConfig,
+    CollectionParams, ExpiryConfig, ShardingMethod, StrictModeConfig, WalConfig,
 };
-use crate::lookup::types::WithLookupInterface;
-use crate::lookup::WithLookup;
+use crate::lookup::types::{PointWithLookup, WithLookupInterface};
+use crate::lookup::{LookupLink, WithLookup};
 use crate::operations::cluster_ops::{
     AbortTransferOperation, ClusterOperations, CreateShardingKey, CreateShardingKeyOperation,
     DropReplicaOperation, DropShardingKey, DropShardingKeyOperation, MoveShard, MoveShardOperation,
@@ -42,6 +45,7 @@ use crate::operations::config_diff::{
     CollectionParamsDiff, HnswConfigDiff, OptimizersConfigDiff, QuantizationConfigDiff,
     WalConfigDiff,
 };
+use crate::operations::payload_schema::{PayloadSchema, PayloadValueSchema, PayloadValueType};
 use crate::operations::point_ops::PointsSelector::PointIdsSelector;
 use crate::operations::point_ops::{
     Batch, FilterSelector, PointIdsList, PointStruct, PointsSelector, WriteOrdering,
@@ -49,12 +53,14 @@ use crate::operations::point_ops::{
 use crate::operations::shard_key_selector::ShardKeySelector;
 use crate::operations::shard_selector_internal::ShardSelectorInternal;
 use crate::operations::types::{
-    AliasDescription, CollectionClusterInfo, CollectionInfo, CollectionStatus, CountResult,
-    LocalShardInfo, LookupLocation, OptimizersStatus, RecommendRequestInternal, Record,
-    RemoteShardInfo, SearchRequestInternal, ShardTransferInfo, UpdateResult, UpdateStatus,
-    VectorParams, VectorsConfig,
+    AggregateResult, AliasDescription, CollectionClusterInfo, CollectionInfo, CollectionStatus,
+    CountResult, HistogramBucket, LocalShardInfo, LookupLocation, OptimizersStatus,
+    RecommendRequestInternal, Record, RemoteShardInfo, SearchRequestInternal, ShardTransferInfo,
+    UpdateResult, UpdateStatus, VectorParams, VectorsConfig,
 };
+use crate::operations::validation;
 use crate::optimizers_builder::OptimizersConfig;
+use crate::shards::peer_labels::PeerLabels;
 use crate::shards::remote_shard::{CollectionCoreSearchRequest, CollectionSearchRequest};
 use crate::shards::replica_set::ReplicaState;
 use crate::shards::transfer::ShardTransferMethod;
@@ -77,6 +83,35 @@ pub fn sharding_method_from_proto(sharding_method: i32) -> Result<ShardingMethod
     }
 }
 
+pub fn sparse_vector_datatype_to_proto(datatype: SparseVectorDatatype) -> i32 {
+    match datatype {
+        SparseVectorDatatype::Float32 => {
+            api::grpc::qdrant::SparseVectorDatatype::SparseFloat32 as i32
+        }
+        SparseVectorDatatype::Float16 => {
+            api::grpc::qdrant::SparseVectorDatatype::SparseFloat16 as i32
+        }
+        SparseVectorDatatype::Uint8 => api::grpc::qdrant::SparseVectorDatatype::SparseUint8 as i32,
+    }
+}
+
+pub fn sparse_vector_datatype_from_proto(datatype: i32) -> Result<SparseVectorDatatype, Status> {
+    match api::grpc::qdrant::SparseVectorDatatype::from_i32(datatype) {
+        Some(api::grpc::qdrant::SparseVectorDatatype::SparseFloat32) => {
+            Ok(SparseVectorDatatype::Float32)
+        }
+        Some(api::grpc::qdrant::SparseVectorDatatype::SparseFloat16) => {
+            Ok(SparseVectorDatatype::Float16)
+        }
+        Some(api::grpc::qdrant::SparseVectorDatatype::SparseUint8) => {
+            Ok(SparseVectorDatatype::Uint8)
+        }
+        None => Err(Status::invalid_argument(format!(
+            "Cannot convert sparse vector datatype: {datatype}"
+        ))),
+    }
+}
+
 pub fn write_ordering_to_proto(ordering: WriteOrdering) -> api::grpc::qdrant::WriteOrdering {
     api::grpc::qdrant::WriteOrdering {
         r#type: match ordering {
@@ -138,6 +173,7 @@ pub fn try_record_from_grpc(
         payload,
         vector,
         shard_key: convert_shard_key_from_grpc_opt(point.shard_key),
+        geo_distance: point.geo_distance,
     })
 }
 
@@ -173,23 +209,7 @@ pub fn try_discover_request_from_grpc(
 
     let target = target.map(TryInto::try_into).transpose()?;
 
-    let context = context
-        .into_iter()
-        .map(|pair| {
-            match (
-                pair.positive.map(|p| p.try_into()),
-                pair.negative.map(|n| n.try_into()),
-            ) {
-                (Some(Ok(positive)), Some(Ok(negative))) => {
-                    Ok(ContextExamplePair { positive, negative })
-                }
-                (Some(Err(e)), _) | (_, Some(Err(e))) => Err(e),
-                (None, _) | (_, None) => Err(Status::invalid_argument(
-                    "Both positive and negative are required in a context pair",
-                )),
-            }
-        })
-        .try_collect()?;
+    let context = context.into_iter().map(TryInto::try_into).try_collect()?;
 
     let request = DiscoverRequestInternal {
         target,
@@ -256,6 +276,192 @@ impl From<api::grpc::qdrant::WalConfigDiff> for WalConfigDiff {
     }
 }
 
+impl From<api::grpc::qdrant::ExpiryConfig> for ExpiryConfig {
+    fn from(value: api::grpc::qdrant::ExpiryConfig) -> Self {
+        Self { ttl: value.ttl }
+    }
+}
+
+impl From<ExpiryConfig> for api::grpc::qdrant::ExpiryConfig {
+    fn from(value: ExpiryConfig) -> Self {
+        Self { ttl: value.ttl }
+    }
+}
+
+impl From<api::grpc::qdrant::StrictModeConfig> for StrictModeConfig {
+    fn from(value: api::grpc::qdrant::StrictModeConfig) -> Self {
+        Self {
+            max_query_limit: value.max_query_limit.map(|v| v as usize),
+            max_query_offset: value.max_query_offset.map(|v| v as usize),
+            max_hnsw_ef: value.max_hnsw_ef.map(|v| v as usize),
+            search_allow_exact: value.search_allow_exact,
+            unindexed_filtering: value.unindexed_filtering,
+            max_search_batch_size: value.max_search_batch_size.map(|v| v as usize),
+            max_update_points: value.max_update_points.map(|v| v as usize),
+            max_payload_size: value.max_payload_size.map(|v| v as usize),
+            max_histogram_buckets: value.max_histogram_buckets.map(|v| v as usize),
+            write_rate_limit: value.write_rate_limit.map(|v| v as usize),
+        }
+    }
+}
+
+impl From<StrictModeConfig> for api::grpc::qdrant::StrictModeConfig {
+    fn from(value: StrictModeConfig) -> Self {
+        Self {
+            max_query_limit: value.max_query_limit.map(|v| v as u64),
+            max_query_offset: value.max_query_offset.map(|v| v as u64),
+            max_hnsw_ef: value.max_hnsw_ef.map(|v| v as u64),
+            search_allow_exact: value.search_allow_exact,
+            unindexed_filtering: value.unindexed_filtering,
+            max_search_batch_size: value.max_search_batch_size.map(|v| v as u64),
+            max_update_points: value.max_update_points.map(|v| v as u64),
+            max_payload_size: value.max_payload_size.map(|v| v as u64),
+            max_histogram_buckets: value.max_histogram_buckets.map(|v| v as u64),
+            write_rate_limit: value.write_rate_limit.map(|v| v as u64),
+        }
+    }
+}
+
+impl TryFrom<api::grpc::qdrant::PayloadValueSchema> for PayloadValueSchema {
+    type Error = Status;
+
+    fn try_from(value: api::grpc::qdrant::PayloadValueSchema) -> Result<Self, Self::Error> {
+        let api::grpc::qdrant::PayloadValueSchema {
+            r#type,
+            items,
+            properties,
+            required,
+            enum_values,
+        } = value;
+
+        let value_type = match api::grpc::qdrant::PayloadValueType::from_i32(r#type) {
+            Some(api::grpc::qdrant::PayloadValueType::String) => PayloadValueType::String,
+            Some(api::grpc::qdrant::PayloadValueType::Integer) => PayloadValueType::Integer,
+            Some(api::grpc::qdrant::PayloadValueType::Float) => PayloadValueType::Float,
+            Some(api::grpc::qdrant::PayloadValueType::Bool) => PayloadValueType::Bool,
+            Some(api::grpc::qdrant::PayloadValueType::Array) => PayloadValueType::Array,
+            Some(api::grpc::qdrant::PayloadValueType::Object) => PayloadValueType::Object,
+            Some(api::grpc::qdrant::PayloadValueType::Unknown) | None => {
+                return Err(Status::invalid_argument(format!(
+                    "Unknown payload value type: {}",
+                    r#type
+                )));
+            }
+        };
+
+        let enum_values = enum_values
+            .into_iter()
+            .map(|enum_value| {
+                use api::grpc::qdrant::payload_enum_value::Variant;
+                match enum_value.variant {
+                    Some(Variant::Keyword(keyword)) => Ok(ValueVariants::Keyword(keyword)),
+                    Some(Variant::Integer(integer)) => Ok(ValueVariants::Integer(integer)),
+                    Some(Variant::Boolean(boolean)) => Ok(ValueVariants::Bool(boolean)),
+                    None => Err(Status::invalid_argument("Payload enum value is empty")),
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            value_type,
+            items: items
+                .map(|items| PayloadValueSchema::try_from(*items).map(Box::new))
+                .transpose()?,
+            properties: (!properties.is_empty())
+                .then(|| {
+                    properties
+                        .into_iter()
+                        .map(|(field, schema)| Ok((field, schema.try_into()?)))
+                        .collect::<Result<BTreeMap<_, _>, Status>>()
+                })
+                .transpose()?,
+            required: (!required.is_empty()).then_some(required),
+            enum_values: (!enum_values.is_empty()).then_some(enum_values),
+        })
+    }
+}
+
+impl From<PayloadValueSchema> for api::grpc::qdrant::PayloadValueSchema {
+    fn from(value: PayloadValueSchema) -> Self {
+        let PayloadValueSchema {
+            value_type,
+            items,
+            properties,
+            required,
+            enum_values,
+        } = value;
+
+        let value_type = match value_type {
+            PayloadValueType::String => api::grpc::qdrant::PayloadValueType::String,
+            PayloadValueType::Integer => api::grpc::qdrant::PayloadValueType::Integer,
+            PayloadValueType::Float => api::grpc::qdrant::PayloadValueType::Float,
+            PayloadValueType::Bool => api::grpc::qdrant::PayloadValueType::Bool,
+            PayloadValueType::Array => api::grpc::qdrant::PayloadValueType::Array,
+            PayloadValueType::Object => api::grpc::qdrant::PayloadValueType::Object,
+        };
+
+        Self {
+            r#type: value_type as i32,
+            items: items.map(|items| Box::new((*items).into())),
+            properties: properties
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(field, schema)| (field, schema.into()))
+                .collect(),
+            required: required.unwrap_or_default(),
+            enum_values: enum_values
+                .unwrap_or_default()
+                .into_iter()
+                .map(|enum_value| {
+                    use api::grpc::qdrant::payload_enum_value::Variant;
+                    let variant = match enum_value {
+                        ValueVariants::Keyword(keyword) => Variant::Keyword(keyword),
+                        ValueVariants::Integer(integer) => Variant::Integer(integer),
+                        ValueVariants::Bool(boolean) => Variant::Boolean(boolean),
+                    };
+                    api::grpc::qdrant::PayloadEnumValue {
+                        variant: Some(variant),
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<api::grpc::qdrant::PayloadSchema> for PayloadSchema {
+    type Error = Status;
+
+    fn try_from(value: api::grpc::qdrant::PayloadSchema) -> Result<Self, Self::Error> {
+        let schema = Self {
+            properties: value
+                .properties
+                .into_iter()
+                .map(|(field, schema)| Ok((field, schema.try_into()?)))
+                .collect::<Result<_, Status>>()?,
+            required: value.required,
+            strict: value.strict.unwrap_or_default(),
+        };
+        schema.validate().map_err(|err| {
+            Status::invalid_argument(validation::label_errors("Invalid payload schema", &err))
+        })?;
+        Ok(schema)
+    }
+}
+
+impl From<PayloadSchema> for api::grpc::qdrant::PayloadSchema {
+    fn from(value: PayloadSchema) -> Self {
+        Self {
+            properties: value
+                .properties
+                .into_iter()
+                .map(|(field, schema)| (field, schema.into()))
+                .collect(),
+            required: value.required,
+            strict: Some(value.strict),
+        }
+    }
+}
+
 impl TryFrom<api::grpc::qdrant::CollectionParamsDiff> for CollectionParamsDiff {
     type Error = Status;
 
@@ -278,6 +484,10 @@ impl TryFrom<api::grpc::qdrant::CollectionParamsDiff> for CollectionParamsDiff {
                 .transpose()?,
             read_fan_out_factor: value.read_fan_out_factor,
             on_disk_payload: value.on_disk_payload,
+            auto_rebalance: value.auto_rebalance,
+            expiry: value.expiry_config.map(Into::into),
+            payload_schema: value.payload_schema.map(TryInto::try_into).transpose()?,
+            strict_mode: value.strict_mode_config.map(Into::into),
         })
     }
 }
@@ -378,6 +588,10 @@ impl From<CollectionInfo> for api::grpc::qdrant::CollectionInfo {
                     write_consistency_factor: Some(config.params.write_consistency_factor.get()),
                     read_fan_out_factor: config.params.read_fan_out_factor,
                     sharding_method: config.params.sharding_method.map(sharding_method_to_proto),
+                    auto_rebalance: Some(config.params.auto_rebalance),
+                    expiry_config: config.params.expiry.map(Into::into),
+                    payload_schema: config.params.payload_schema.map(Into::into),
+                    strict_mode_config: config.params.strict_mode.map(Into::into),
                     sparse_vectors_config: config.params.sparse_vectors.map(|sparse_vectors| {
                         api::grpc::qdrant::SparseVectorConfig {
                             map: sparse_vectors
@@ -439,6 +653,8 @@ impl From<Record> for api::grpc::qdrant::RetrievedPoint {
             payload: record.payload.map(payload_to_proto).unwrap_or_default(),
             vectors,
             shard_key: record.shard_key.map(convert_shard_key_to_grpc),
+            geo_distance: record.geo_distance,
+            lookup: vec![],
         }
     }
 }
@@ -560,16 +776,27 @@ impl TryFrom<api::grpc::qdrant::VectorParamsDiff> for VectorParamsDiff {
     }
 }
 
-impl From<api::grpc::qdrant::SparseVectorParams> for SparseVectorParams {
-    fn from(sparse_vector_params: api::grpc::qdrant::SparseVectorParams) -> Self {
-        Self {
+impl TryFrom<api::grpc::qdrant::SparseVectorParams> for SparseVectorParams {
+    type Error = Status;
+
+    fn try_from(
+        sparse_vector_params: api::grpc::qdrant::SparseVectorParams,
+    ) -> Result<Self, Status> {
+        Ok(Self {
             index: sparse_vector_params
                 .index
                 .map(|index_config| SparseIndexParams {
                     full_scan_threshold: index_config.full_scan_threshold.map(|v| v as usize),
                     on_disk: index_config.on_disk,
+                    compressed: index_config.compressed,
                 }),
-        }
+            prune_threshold: sparse_vector_params.prune_threshold,
+            max_dims: sparse_vector_params.max_dims.map(|v| v as usize),
+            datatype: sparse_vector_params
+                .datatype
+                .map(sparse_vector_datatype_from_proto)
+                .transpose()?,
+        })
     }
 }
 
@@ -580,8 +807,14 @@ impl From<SparseVectorParams> for api::grpc::qdrant::SparseVectorParams {
                 api::grpc::qdrant::SparseIndexConfig {
                     full_scan_threshold: index_config.full_scan_threshold.map(|v| v as u64),
                     on_disk: index_config.on_disk,
+                    compressed: index_config.compressed,
                 }
             }),
+            prune_threshold: sparse_vector_params.prune_threshold,
+            max_dims: sparse_vector_params.max_dims.map(|v| v as u64),
+            datatype: sparse_vector_params
+                .datatype
+                .map(sparse_vector_datatype_to_proto),
         }
     }
 }
@@ -639,13 +872,18 @@ impl TryFrom<api::grpc::qdrant::CollectionConfig> for CollectionConfig {
                             ),
                         },
                     },
-                    sparse_vectors: params.sparse_vectors_config.map(|sparse_vectors| {
-                        sparse_vectors
-                            .map
-                            .into_iter()
-                            .map(|(name, sparse_vector_params)| (name, sparse_vector_params.into()))
-                            .collect()
-                    }),
+                    sparse_vectors: params
+                        .sparse_vectors_config
+                        .map(|sparse_vectors| {
+                            sparse_vectors
+                                .map
+                                .into_iter()
+                                .map(|(name, sparse_vector_params)| {
+                                    Ok((name, sparse_vector_params.try_into()?))
+                                })
+                                .collect::<Result<_, Status>>()
+                        })
+                        .transpose()?,
                     shard_number: NonZeroU32::new(params.shard_number)
                         .ok_or_else(|| Status::invalid_argument("`shard_number` cannot be zero"))?,
                     on_disk_payload: params.on_disk_payload,
@@ -671,6 +909,10 @@ impl TryFrom<api::grpc::qdrant::CollectionConfig> for CollectionConfig {
                         .sharding_method
                         .map(sharding_method_from_proto)
                         .transpose()?,
+                    auto_rebalance: params.auto_rebalance.unwrap_or_default(),
+                    expiry: params.expiry_config.map(Into::into),
+                    payload_schema: params.payload_schema.map(TryInto::try_into).transpose()?,
+                    strict_mode: params.strict_mode_config.map(Into::into),
                 },
             },
             hnsw_config: match config.hnsw_config {
@@ -853,6 +1095,7 @@ impl From<UpdateResult> for api::grpc::qdrant::UpdateResult {
                 UpdateStatus::Acknowledged => api::grpc::qdrant::UpdateStatus::Acknowledged as i32,
                 UpdateStatus::Completed => api::grpc::qdrant::UpdateStatus::Completed as i32,
             },
+            consistency_token: value.consistency_token.map(|token| token.to_string()),
         }
     }
 }
@@ -872,6 +1115,11 @@ impl TryFrom<api::grpc::qdrant::UpdateResult> for UpdateResult {
                 }
                 _ => return Err(Status::invalid_argument("Malformed UpdateStatus type")),
             },
+            consistency_token: value
+                .consistency_token
+                .map(|token| token.parse())
+                .transpose()
+                .map_err(Status::invalid_argument)?,
         })
     }
 }
@@ -892,6 +1140,69 @@ impl From<CountResult> for api::grpc::qdrant::CountResult {
     }
 }
 
+impl From<api::grpc::qdrant::AggregateResult> for AggregateResult {
+    fn from(value: api::grpc::qdrant::AggregateResult) -> Self {
+        let api::grpc::qdrant::AggregateResult {
+            count,
+            min,
+            max,
+            sum,
+            integer_sum,
+            avg,
+            histogram,
+        } = value;
+        Self {
+            count: count as usize,
+            min,
+            max,
+            sum,
+            integer_sum,
+            avg,
+            histogram: Some(
+                histogram
+                    .into_iter()
+                    .map(|bucket| HistogramBucket {
+                        from: bucket.from,
+                        to: bucket.to,
+                        count: bucket.count as usize,
+                    })
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl From<AggregateResult> for api::grpc::qdrant::AggregateResult {
+    fn from(value: AggregateResult) -> Self {
+        let AggregateResult {
+            count,
+            min,
+            max,
+            sum,
+            integer_sum,
+            avg,
+            histogram,
+        } = value;
+        Self {
+            count: count as u64,
+            min,
+            max,
+            sum,
+            integer_sum,
+            avg,
+            histogram: histogram
+                .unwrap_or_default()
+                .into_iter()
+                .map(|bucket| api::grpc::qdrant::HistogramBucket {
+                    from: bucket.from,
+                    to: bucket.to,
+                    count: bucket.count as u64,
+                })
+                .collect(),
+        }
+    }
+}
+
 impl TryFrom<api::grpc::qdrant::SearchPoints> for CoreSearchRequest {
     type Error = Status;
     fn try_from(value: api::grpc::qdrant::SearchPoints) -> Result<Self, Self::Error> {
@@ -910,8 +1221,12 @@ impl TryFrom<api::grpc::qdrant::SearchPoints> for CoreSearchRequest {
             timeout: _,
             shard_key_selector: _,
             sparse_indices,
+            with_geo_distance,
+            with_lookup: _,
+            packed_vector,
         } = value;
 
+        let vector = api::grpc::conversions::dense_or_packed(vector, packed_vector);
         let vector_struct =
             api::grpc::conversions::into_named_vector_struct(vector_name, vector, sparse_indices)?;
 
@@ -924,6 +1239,9 @@ impl TryFrom<api::grpc::qdrant::SearchPoints> for CoreSearchRequest {
             with_payload: with_payload.map(TryInto::try_into).transpose()?,
             with_vector: with_vectors.map(Into::into),
             score_threshold: score_threshold.map(|s| s as ScoreType),
+            with_geo_distance: with_geo_distance
+                .map(|geo_distance| geo_distance.try_into())
+                .transpose()?,
         })
     }
 }
@@ -959,6 +1277,9 @@ impl<'a> From<CollectionSearchRequest<'a>> for api::grpc::qdrant::SearchPoints {
             timeout: None,
             shard_key_selector: None,
             sparse_indices,
+            with_geo_distance: None,
+            with_lookup: None,
+            packed_vector: vec![],
         }
     }
 }
@@ -1010,6 +1331,31 @@ impl From<QueryEnum> for api::grpc::qdrant::QueryEnum {
                     },
                 )),
             },
+            QueryEnum::Feedback(named) => api::grpc::qdrant::QueryEnum {
+                query: Some(api::grpc::qdrant::query_enum::Query::Feedback(
+                    api::grpc::qdrant::FeedbackQuery {
+                        target: Some(named.query.target.into()),
+                        feedback: named
+                            .query
+                            .feedback
+                            .into_iter()
+                            .map(|item| api::grpc::qdrant::FeedbackItem {
+                                example: Some(item.example.into()),
+                                weight: item.weight,
+                            })
+                            .collect(),
+                        context: named
+                            .query
+                            .context
+                            .into_iter()
+                            .map(|pair| api::grpc::qdrant::ContextPair {
+                                positive: Some(pair.positive.into()),
+                                negative: Some(pair.negative.into()),
+                            })
+                            .collect(),
+                    },
+                )),
+            },
         }
     }
 }
@@ -1042,6 +1388,7 @@ impl TryFrom<api::grpc::qdrant::WithLookup> for WithLookup {
 
         Ok(Self {
             collection_name: value.collection,
+            link: value.link.map(TryInto::try_into).transpose()?,
             with_payload: value
                 .with_payload
                 .map(|wp| wp.try_into())
@@ -1052,6 +1399,55 @@ impl TryFrom<api::grpc::qdrant::WithLookup> for WithLookup {
     }
 }
 
+impl TryFrom<api::grpc::qdrant::LookupLink> for LookupLink {
+    type Error = Status;
+
+    fn try_from(value: api::grpc::qdrant::LookupLink) -> Result<Self, Self::Error> {
+        let api::grpc::qdrant::LookupLink {
+            key,
+            target_key,
+            limit,
+        } = value;
+
+        if key.is_empty() || target_key.as_ref().is_some_and(|key| key.is_empty()) {
+            return Err(Status::invalid_argument(
+                "Lookup link keys must not be empty",
+            ));
+        }
+        if limit == Some(0) {
+            return Err(Status::invalid_argument(
+                "Lookup link limit must be greater than 0",
+            ));
+        }
+
+        Ok(Self {
+            key,
+            target_key,
+            limit: limit.map(|limit| limit as usize),
+        })
+    }
+}
+
+impl From<PointWithLookup<ScoredPoint>> for api::grpc::qdrant::ScoredPoint {
+    fn from(value: PointWithLookup<ScoredPoint>) -> Self {
+        let PointWithLookup { point, lookup } = value;
+        Self {
+            lookup: lookup.into_iter().flatten().map(Into::into).collect(),
+            ..point.into()
+        }
+    }
+}
+
+impl From<PointWithLookup<Record>> for api::grpc::qdrant::RetrievedPoint {
+    fn from(value: PointWithLookup<Record>) -> Self {
+        let PointWithLookup { point, lookup } = value;
+        Self {
+            lookup: lookup.into_iter().flatten().map(Into::into).collect(),
+            ..point.into()
+        }
+    }
+}
+
 impl TryFrom<api::grpc::qdrant::WithLookup> for WithLookupInterface {
     type Error = Status;
 
@@ -1089,6 +1485,17 @@ fn try_context_pair_from_grpc(
     }
 }
 
+fn try_feedback_item_from_grpc(
+    item: api::grpc::qdrant::FeedbackItem,
+) -> Result<FeedbackItem<Vector>, Status> {
+    match item.example {
+        Some(example) => Ok(FeedbackItem::new(example.into(), item.weight)),
+        None => Err(Status::invalid_argument(
+            "All feedback items must have an example",
+        )),
+    }
+}
+
 impl TryFrom<api::grpc::qdrant::CoreSearchPoints> for CoreSearchRequest {
     type Error = Status;
 
@@ -1145,6 +1552,31 @@ impl TryFrom<api::grpc::qdrant::CoreSearchPoints> for CoreSearchRequest {
                             using: value.vector_name,
                         })
                     }
+                    api::grpc::qdrant::query_enum::Query::Feedback(query) => {
+                        let target = match query.target {
+                            Some(target) => target,
+                            None => {
+                                return Err(Status::invalid_argument("Target is not specified"))
+                            }
+                        };
+
+                        let feedback = query
+                            .feedback
+                            .into_iter()
+                            .map(try_feedback_item_from_grpc)
+                            .try_collect()?;
+
+                        let pairs = query
+                            .context
+                            .into_iter()
+                            .map(try_context_pair_from_grpc)
+                            .try_collect()?;
+
+                        QueryEnum::Feedback(NamedQuery {
+                            query: FeedbackQuery::new(target.into(), feedback, pairs),
+                            using: value.vector_name,
+                        })
+                    }
                 })
             })
             .transpose()?
@@ -1164,6 +1596,8 @@ impl TryFrom<api::grpc::qdrant::CoreSearchPoints> for CoreSearchRequest {
                     .unwrap_or_default(),
             ),
             score_threshold: value.score_threshold,
+            // Distances are only computed for the requests of the client
+            with_geo_distance: None,
         })
     }
 }
@@ -1213,6 +1647,9 @@ impl TryFrom<api::grpc::qdrant::SearchPointGroups> for SearchGroupsRequestIntern
             timeout: None,
             shard_key_selector: None,
             sparse_indices: value.sparse_indices,
+            with_geo_distance: None,
+            with_lookup: None,
+            packed_vector: value.packed_vector,
         };
 
         let SearchRequestInternal {
@@ -1268,6 +1705,7 @@ impl From<api::grpc::qdrant::RecommendStrategy> for RecommendStrategy {
         match value {
             api::grpc::qdrant::RecommendStrategy::AverageVector => RecommendStrategy::AverageVector,
             api::grpc::qdrant::RecommendStrategy::BestScore => RecommendStrategy::BestScore,
+            api::grpc::qdrant::RecommendStrategy::Feedback => RecommendStrategy::Feedback,
         }
     }
 }
@@ -1351,12 +1789,47 @@ impl TryFrom<api::grpc::qdrant::VectorExample> for RecommendExample {
                     Ok(Self::PointId(id.try_into()?))
                 }
                 api::grpc::qdrant::vector_example::Example::Vector(vector) => {
-                    Ok(Self::Dense(vector.data))
+                    Ok(Self::Dense(vector.into_dense()))
                 }
             })
     }
 }
 
+impl TryFrom<api::grpc::qdrant::ContextExamplePair> for ContextExamplePair {
+    type Error = Status;
+
+    fn try_from(value: api::grpc::qdrant::ContextExamplePair) -> Result<Self, Self::Error> {
+        match (
+            value.positive.map(|p| p.try_into()),
+            value.negative.map(|n| n.try_into()),
+        ) {
+            (Some(Ok(positive)), Some(Ok(negative))) => {
+                Ok(ContextExamplePair { positive, negative })
+            }
+            (Some(Err(e)), _) | (_, Some(Err(e))) => Err(e),
+            (None, _) | (_, None) => Err(Status::invalid_argument(
+                "Both positive and negative are required in a context pair",
+            )),
+        }
+    }
+}
+
+impl TryFrom<api::grpc::qdrant::FeedbackExample> for FeedbackExample {
+    type Error = Status;
+
+    fn try_from(value: api::grpc::qdrant::FeedbackExample) -> Result<Self, Self::Error> {
+        let example = value
+            .example
+            .ok_or_else(|| Status::invalid_argument("Example is required in a feedback example"))?
+            .try_into()?;
+
+        Ok(FeedbackExample {
+            example,
+            weight: value.weight,
+        })
+    }
+}
+
 impl TryFrom<api::grpc::qdrant::RecommendPoints> for RecommendRequestInternal {
     type Error = Status;
 
@@ -1379,9 +1852,23 @@ impl TryFrom<api::grpc::qdrant::RecommendPoints> for RecommendRequestInternal {
         let negative_vectors = value.negative_vectors.into_iter().map(Into::into).collect();
         let negative = [negative_ids, negative_vectors].concat();
 
+        let feedback = value
+            .feedback
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, Self::Error>>()?;
+
+        let context = value
+            .context
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, Self::Error>>()?;
+
         Ok(RecommendRequestInternal {
             positive,
             negative,
+            feedback,
+            context,
             strategy: value.strategy.map(|s| s.try_into()).transpose()?,
             filter: value.filter.map(|f| f.try_into()).transpose()?,
             params: value.params.map(|p| p.into()),
@@ -1424,11 +1911,16 @@ impl TryFrom<api::grpc::qdrant::RecommendPointGroups> for RecommendGroupsRequest
             negative_vectors: value.negative_vectors,
             timeout: None, // Passed as query param
             shard_key_selector: None,
+            with_lookup: None,
+            feedback: value.feedback,
+            context: value.context,
         };
 
         let RecommendRequestInternal {
             positive,
             negative,
+            feedback,
+            context,
             strategy,
             using,
             lookup_from,
@@ -1444,6 +1936,8 @@ impl TryFrom<api::grpc::qdrant::RecommendPointGroups> for RecommendGroupsRequest
         Ok(RecommendGroupsRequestInternal {
             positive,
             negative,
+            feedback,
+            context,
             strategy,
             using,
             lookup_from,
@@ -1479,6 +1973,8 @@ impl From<VectorParams> for api::grpc::qdrant::VectorParams {
                 Distance::Euclid => api::grpc::qdrant::Distance::Euclid,
                 Distance::Dot => api::grpc::qdrant::Distance::Dot,
                 Distance::Manhattan => api::grpc::qdrant::Distance::Manhattan,
+                Distance::Hamming => api::grpc::qdrant::Distance::Hamming,
+                Distance::Jaccard => api::grpc::qdrant::Distance::Jaccard,
             }
             .into(),
             hnsw_config: value.hnsw_config.map(Into::into),
@@ -1550,6 +2046,25 @@ impl From<CollectionClusterInfo> for api::grpc::qdrant::CollectionClusterInfoRes
                 .into_iter()
                 .map(|shard| shard.into())
                 .collect(),
+            warnings: value.warnings,
+        }
+    }
+}
+
+impl From<api::grpc::qdrant::PeerLabels> for PeerLabels {
+    fn from(value: api::grpc::qdrant::PeerLabels) -> Self {
+        Self {
+            zone: value.zone,
+            rack: value.rack,
+        }
+    }
+}
+
+impl From<PeerLabels> for api::grpc::qdrant::PeerLabels {
+    fn from(value: PeerLabels) -> Self {
+        Self {
+            zone: value.zone,
+            rack: value.rack,
         }
     }
 }
@@ -0,0 +1,121 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use segment::types::{Condition, FieldCondition, Filter, Payload, Range};
+
+use crate::operations::point_ops::{PointInsertOperationsInternal, PointOperations};
+use crate::operations::CollectionUpdateOperations;
+
+/// Payload field holding the unix timestamp (in seconds) at which the point expires
+pub const EXPIRE_AT_KEY: &str = "expire_at";
+
+/// Current unix timestamp in seconds
+pub fn current_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+}
+
+/// Condition matching points, which are expired at the given timestamp
+fn expired_condition(now: u64) -> Condition {
+    Condition::Field(FieldCondition::new_range(
+        EXPIRE_AT_KEY,
+        Range {
+            lte: Some(now as f64),
+            ..Default::default()
+        },
+    ))
+}
+
+/// Filter selecting points, which are expired at the given timestamp
+pub fn expired_points_filter(now: u64) -> Filter {
+    Filter::new_must(expired_condition(now))
+}
+
+/// Extend the filter to exclude points, which are expired at the given timestamp
+pub fn exclude_expired(filter: Option<&Filter>, now: u64) -> Filter {
+    let not_expired = Filter::new_must_not(expired_condition(now));
+    match filter {
+        Some(filter) => filter.merge(&not_expired),
+        None => not_expired,
+    }
+}
+
+/// Assign `expire_at` to all upserted points, which don't have it set explicitly
+pub fn assign_expire_at(operation: &mut CollectionUpdateOperations, expire_at: u64) {
+    let CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(insert)) =
+        operation
+    else {
+        return;
+    };
+
+    let set_default = |payload: &mut Option<Payload>| {
+        payload
+            .get_or_insert_with(Payload::default)
+            .0
+            .entry(EXPIRE_AT_KEY)
+            .or_insert_with(|| expire_at.into());
+    };
+
+    match insert {
+        PointInsertOperationsInternal::PointsList(points) => {
+            points
+                .iter_mut()
+                .for_each(|point| set_default(&mut point.payload));
+        }
+        PointInsertOperationsInternal::PointsBatch(batch) => {
+            let points_count = batch.ids.len();
+            batch
+                .payloads
+                .get_or_insert_with(|| vec![None; points_count])
+                .iter_mut()
+                .for_each(set_default);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::operations::point_ops::PointStruct;
+
+    #[test]
+    fn test_assign_expire_at() {
+        let points = vec![
+            PointStruct {
+                id: 1.into(),
+                vector: vec![1.0, 2.0].into(),
+                payload: None,
+            },
+            PointStruct {
+                id: 2.into(),
+                vector: vec![1.0, 2.0].into(),
+                payload: Some(json!({ "expire_at": 10 }).into()),
+            },
+        ];
+        let mut operation = CollectionUpdateOperations::PointOperation(
+            PointOperations::UpsertPoints(PointInsertOperationsInternal::PointsList(points)),
+        );
+
+        assign_expire_at(&mut operation, 100);
+
+        let CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(
+            PointInsertOperationsInternal::PointsList(points),
+        )) = operation
+        else {
+            panic!("operation type must not change");
+        };
+        assert_eq!(points[0].payload, Some(json!({ "expire_at": 100 }).into()));
+        assert_eq!(points[1].payload, Some(json!({ "expire_at": 10 }).into()));
+    }
+
+    #[test]
+    fn test_exclude_expired() {
+        let filter = Filter::new_must(expired_condition(5));
+        let filter = exclude_expired(Some(&filter), 10);
+
+        assert_eq!(filter.must.unwrap().len(), 1);
+        assert_eq!(filter.must_not.unwrap(), vec![expired_condition(10)]);
+    }
+}
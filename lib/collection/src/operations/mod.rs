@@ -2,13 +2,17 @@ pub mod cluster_ops;
 pub mod config_diff;
 pub mod consistency_params;
 pub mod conversions;
+pub mod expiry;
 pub mod operation_effect;
 pub mod payload_ops;
+pub mod payload_schema;
 pub mod point_ops;
 pub mod shard_key_selector;
 pub mod shard_selector_internal;
 pub mod shared_storage_config;
 pub mod snapshot_ops;
+pub mod sparse_pruning;
+pub mod strict_mode;
 pub mod types;
 pub mod validation;
 pub mod vector_ops;
@@ -175,6 +179,22 @@ impl CollectionUpdateOperations {
             }
         }
     }
+
+    /// Drop vectors with names, for which `is_known` returns `false`, from the operation
+    ///
+    /// Allows applying operations, written before some vectors were removed from the collection.
+    pub fn retain_vectors(&mut self, is_known: impl Fn(&str) -> bool) {
+        match self {
+            CollectionUpdateOperations::PointOperation(operation) => {
+                operation.retain_vectors(is_known)
+            }
+            CollectionUpdateOperations::VectorOperation(operation) => {
+                operation.retain_vectors(is_known)
+            }
+            CollectionUpdateOperations::PayloadOperation(_)
+            | CollectionUpdateOperations::FieldIndexOperation(_) => {}
+        }
+    }
 }
 
 #[cfg(test)]
@@ -193,4 +213,35 @@ mod tests {
         let json = serde_json::to_string_pretty(&op).unwrap();
         println!("{json}")
     }
+
+    #[test]
+    fn test_retain_vectors() {
+        use segment::data_types::vectors::VectorStruct;
+
+        let mut op =
+            CollectionUpdateOperations::PointOperation(point_ops::PointOperations::UpsertPoints(
+                vec![point_ops::PointStruct {
+                    id: 1.into(),
+                    vector: VectorStruct::Multi(HashMap::from([
+                        ("kept".to_string(), vec![1.0, 2.0].into()),
+                        ("removed".to_string(), vec![3.0, 4.0].into()),
+                    ])),
+                    payload: None,
+                }]
+                .into(),
+            ));
+
+        op.retain_vectors(|vector_name| vector_name == "kept");
+
+        let CollectionUpdateOperations::PointOperation(point_ops::PointOperations::UpsertPoints(
+            point_ops::PointInsertOperationsInternal::PointsList(points),
+        )) = op
+        else {
+            panic!("operation type changed");
+        };
+        let VectorStruct::Multi(vectors) = &points[0].vector else {
+            panic!("vector type changed");
+        };
+        assert_eq!(vectors.keys().collect::<Vec<_>>(), vec!["kept"]);
+    }
 }
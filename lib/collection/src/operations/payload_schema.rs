@@ -0,0 +1,437 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+use schemars::JsonSchema;
+use segment::types::{
+    GeoPoint, GeoShape, Payload, PayloadContainer, PayloadFieldSchema, PayloadKeyType,
+    PayloadSchemaParams, PayloadSchemaType, ValueVariants,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use validator::{Validate, ValidationError};
+
+/// Type of payload value
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum PayloadValueType {
+    String,
+    Integer,
+    Float,
+    Bool,
+    Array,
+    Object,
+}
+
+impl PayloadValueType {
+    fn matches(self, value: &Value) -> bool {
+        match self {
+            PayloadValueType::String => value.is_string(),
+            PayloadValueType::Integer => value.is_i64(),
+            PayloadValueType::Float => value.is_number(),
+            PayloadValueType::Bool => value.is_boolean(),
+            PayloadValueType::Array => value.is_array(),
+            PayloadValueType::Object => value.is_object(),
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            PayloadValueType::String => "string",
+            PayloadValueType::Integer => "integer",
+            PayloadValueType::Float => "float",
+            PayloadValueType::Bool => "bool",
+            PayloadValueType::Array => "array",
+            PayloadValueType::Object => "object",
+        }
+    }
+}
+
+impl fmt::Display for PayloadValueType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Schema of a single payload value
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub struct PayloadValueSchema {
+    /// Type of the value
+    #[serde(rename = "type")]
+    pub value_type: PayloadValueType,
+    /// Schema of array elements. Only for `array` type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub items: Option<Box<PayloadValueSchema>>,
+    /// Schemas of object fields. Fields, which are not listed, are not validated.
+    /// Only for `object` type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub properties: Option<BTreeMap<String, PayloadValueSchema>>,
+    /// Fields, which the object must have. Only for `object` type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub required: Option<Vec<String>>,
+    /// If set - value must be one of the listed values.
+    /// Only for `string`, `integer` and `bool` types.
+    #[serde(default, rename = "enum", skip_serializing_if = "Option::is_none")]
+    pub enum_values: Option<Vec<ValueVariants>>,
+}
+
+/// Schema of points payload.
+/// Payload of upserted points and payload updates are validated against it, invalid writes are
+/// rejected. Values `null` are treated as missing.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub struct PayloadSchema {
+    /// Schemas of payload fields. Fields, which are not listed, are not validated.
+    #[serde(default)]
+    #[validate(custom = "validate_value_schemas")]
+    pub properties: BTreeMap<String, PayloadValueSchema>,
+    /// Fields, which payload of each point must have
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub required: Vec<String>,
+    /// If true - values of indexed fields, which can't be stored in the payload index, are rejected.
+    /// Default: false
+    #[serde(default)]
+    pub strict: bool,
+}
+
+impl PayloadSchema {
+    /// Check payload of a point against the schema.
+    ///
+    /// If `partial` is true, payload only updates some fields of the point,
+    /// so presence of required fields is not checked.
+    pub fn check_payload(&self, payload: &Payload, partial: bool) -> Result<(), String> {
+        let required = if partial { &[][..] } else { &self.required };
+        check_object(&self.properties, required, None, &payload.0)
+    }
+}
+
+fn check_object(
+    properties: &BTreeMap<String, PayloadValueSchema>,
+    required: &[String],
+    path: Option<&str>,
+    object: &Map<String, Value>,
+) -> Result<(), String> {
+    let field_path = |field: &str| match path {
+        Some(path) => format!("{path}.{field}"),
+        None => field.to_string(),
+    };
+
+    for field in required {
+        if object.get(field).map_or(true, Value::is_null) {
+            return Err(format!("field `{}` is required", field_path(field)));
+        }
+    }
+
+    for (field, schema) in properties {
+        if let Some(value) = object.get(field) {
+            check_value(schema, &field_path(field), value)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn check_value(schema: &PayloadValueSchema, path: &str, value: &Value) -> Result<(), String> {
+    if value.is_null() {
+        return Ok(());
+    }
+
+    if !schema.value_type.matches(value) {
+        return Err(format!(
+            "field `{path}` must be {}, got {}",
+            schema.value_type,
+            value_type_name(value),
+        ));
+    }
+
+    if let Some(enum_values) = &schema.enum_values {
+        if !enum_values
+            .iter()
+            .any(|enum_value| enum_value_matches(enum_value, value))
+        {
+            let allowed = serde_json::to_string(enum_values).unwrap_or_default();
+            return Err(format!(
+                "field `{path}` must be one of {allowed}, got {value}"
+            ));
+        }
+    }
+
+    match value {
+        Value::Array(items) => {
+            if let Some(items_schema) = &schema.items {
+                for (index, item) in items.iter().enumerate() {
+                    check_value(items_schema, &format!("{path}[{index}]"), item)?;
+                }
+            }
+        }
+        Value::Object(object) => {
+            let no_properties = BTreeMap::new();
+            let properties = schema.properties.as_ref().unwrap_or(&no_properties);
+            let required = schema.required.as_deref().unwrap_or_default();
+            check_object(properties, required, Some(path), object)?;
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+fn enum_value_matches(enum_value: &ValueVariants, value: &Value) -> bool {
+    match enum_value {
+        ValueVariants::Keyword(keyword) => value.as_str() == Some(keyword),
+        ValueVariants::Integer(integer) => value.as_i64() == Some(*integer),
+        ValueVariants::Bool(boolean) => value.as_bool() == Some(*boolean),
+    }
+}
+
+fn value_type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "bool",
+        Value::Number(number) if number.is_i64() => "integer",
+        Value::Number(_) => "float",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+/// Check, that values of indexed fields can be stored in payload indexes.
+///
+/// Payload indexes silently skip values they can't parse, which makes filters on such fields
+/// miss points.
+pub fn check_indexed_values(
+    payload: &Payload,
+    payload_indexes: &HashMap<PayloadKeyType, PayloadFieldSchema>,
+) -> Result<(), String> {
+    for (key, field_schema) in payload_indexes {
+        let index_type = match field_schema {
+            PayloadFieldSchema::FieldType(index_type) => *index_type,
+            PayloadFieldSchema::FieldParams(PayloadSchemaParams::Keyword(_)) => {
+                PayloadSchemaType::Keyword
+            }
+            PayloadFieldSchema::FieldParams(PayloadSchemaParams::Text(_)) => {
+                PayloadSchemaType::Text
+            }
+        };
+
+        for value in payload.0.get_value(key) {
+            let values = match value {
+                Value::Array(items) => items.iter().collect(),
+                value => vec![value],
+            };
+            for value in values.into_iter().filter(|value| !value.is_null()) {
+                if !is_indexable(index_type, value) {
+                    return Err(format!(
+                        "value {value} of field `{key}` can't be stored in {index_type:?} index",
+                    ));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn is_indexable(index_type: PayloadSchemaType, value: &Value) -> bool {
+    match index_type {
+        PayloadSchemaType::Keyword | PayloadSchemaType::Text => value.is_string(),
+        PayloadSchemaType::Integer => value.is_i64(),
+        PayloadSchemaType::Float => value.is_number(),
+        PayloadSchemaType::Bool => value.is_boolean(),
+        PayloadSchemaType::Geo => {
+            serde_json::from_value::<GeoPoint>(value.clone()).is_ok()
+                || serde_json::from_value::<GeoShape>(value.clone()).is_ok()
+        }
+    }
+}
+
+fn validate_value_schemas(
+    properties: &BTreeMap<String, PayloadValueSchema>,
+) -> Result<(), ValidationError> {
+    properties
+        .iter()
+        .try_for_each(|(field, schema)| validate_value_schema(field, schema))
+}
+
+fn validate_value_schema(path: &str, schema: &PayloadValueSchema) -> Result<(), ValidationError> {
+    let invalid = |message: String| {
+        let mut error = ValidationError::new("invalid_payload_schema");
+        error.message = Some(message.into());
+        Err(error)
+    };
+
+    let value_type = schema.value_type;
+    if schema.items.is_some() && value_type != PayloadValueType::Array {
+        return invalid(format!(
+            "`items` of field `{path}` are only allowed for array type"
+        ));
+    }
+    if (schema.properties.is_some() || schema.required.is_some())
+        && value_type != PayloadValueType::Object
+    {
+        return invalid(format!(
+            "`properties` and `required` of field `{path}` are only allowed for object type"
+        ));
+    }
+    if let Some(enum_values) = &schema.enum_values {
+        let matches_type = |enum_value: &ValueVariants| match enum_value {
+            ValueVariants::Keyword(_) => value_type == PayloadValueType::String,
+            ValueVariants::Integer(_) => {
+                matches!(
+                    value_type,
+                    PayloadValueType::Integer | PayloadValueType::Float
+                )
+            }
+            ValueVariants::Bool(_) => value_type == PayloadValueType::Bool,
+        };
+        if enum_values.is_empty() || !enum_values.iter().all(matches_type) {
+            return invalid(format!(
+                "`enum` of field `{path}` must be non-empty list of {value_type} values"
+            ));
+        }
+    }
+
+    if let Some(items) = &schema.items {
+        validate_value_schema(&format!("{path}[]"), items)?;
+    }
+    for (field, field_schema) in schema.properties.iter().flatten() {
+        validate_value_schema(&format!("{path}.{field}"), field_schema)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn schema() -> PayloadSchema {
+        serde_json::from_value(json!({
+            "properties": {
+                "price": {"type": "integer"},
+                "color": {"type": "string", "enum": ["red", "green"]},
+                "tags": {"type": "array", "items": {"type": "string"}},
+                "dimensions": {
+                    "type": "object",
+                    "properties": {"width": {"type": "float"}},
+                    "required": ["width"],
+                },
+            },
+            "required": ["price"],
+        }))
+        .unwrap()
+    }
+
+    fn payload(value: Value) -> Payload {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_check_payload() {
+        let schema = schema();
+        assert!(schema.validate().is_ok());
+
+        let valid = payload(json!({
+            "price": 10,
+            "color": "red",
+            "tags": ["a", "b"],
+            "dimensions": {"width": 1.5},
+            "unknown": "anything",
+        }));
+        assert!(schema.check_payload(&valid, false).is_ok());
+
+        let error = schema
+            .check_payload(&payload(json!({"price": "10"})), false)
+            .unwrap_err();
+        assert_eq!(error, "field `price` must be integer, got string");
+
+        let error = schema
+            .check_payload(&payload(json!({"color": "red"})), false)
+            .unwrap_err();
+        assert_eq!(error, "field `price` is required");
+
+        // Partial updates don't need required fields
+        assert!(schema
+            .check_payload(&payload(json!({"color": "red"})), true)
+            .is_ok());
+
+        let error = schema
+            .check_payload(&payload(json!({"price": 1, "color": "blue"})), false)
+            .unwrap_err();
+        assert_eq!(
+            error,
+            r#"field `color` must be one of ["red","green"], got "blue""#
+        );
+
+        let error = schema
+            .check_payload(&payload(json!({"price": 1, "tags": ["a", 1]})), false)
+            .unwrap_err();
+        assert_eq!(error, "field `tags[1]` must be string, got integer");
+
+        let error = schema
+            .check_payload(&payload(json!({"price": 1, "dimensions": {}})), false)
+            .unwrap_err();
+        assert_eq!(error, "field `dimensions.width` is required");
+
+        // Null is treated as missing value
+        assert!(schema
+            .check_payload(&payload(json!({"price": 1, "color": null})), false)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_check_indexed_values() {
+        let indexes = HashMap::from([
+            ("price".to_string(), PayloadSchemaType::Integer.into()),
+            ("location".to_string(), PayloadSchemaType::Geo.into()),
+            (
+                "items[].name".to_string(),
+                PayloadSchemaType::Keyword.into(),
+            ),
+        ]);
+
+        let valid = payload(json!({
+            "price": [1, 2],
+            "location": {"lon": 10.0, "lat": 20.0},
+            "items": [{"name": "a"}, {"name": null}],
+        }));
+        assert!(check_indexed_values(&valid, &indexes).is_ok());
+
+        let invalid = payload(json!({"price": "1"}));
+        assert!(check_indexed_values(&invalid, &indexes).is_err());
+
+        let invalid = payload(json!({"location": {"lon": 10.0, "lat": 200.0}}));
+        assert!(check_indexed_values(&invalid, &indexes).is_err());
+
+        let valid = payload(json!({
+            "location": {"type": "LineString", "coordinates": [[10.0, 20.0], [11.0, 21.0]]},
+        }));
+        assert!(check_indexed_values(&valid, &indexes).is_ok());
+
+        let invalid = payload(json!({
+            "location": {"type": "Polygon", "coordinates": [[[10.0, 20.0], [11.0, 21.0]]]},
+        }));
+        assert!(check_indexed_values(&invalid, &indexes).is_err());
+
+        let invalid = payload(json!({"items": [{"name": 1}]}));
+        assert!(check_indexed_values(&invalid, &indexes).is_err());
+    }
+
+    #[test]
+    fn test_validate_schema() {
+        let invalid: PayloadSchema = serde_json::from_value(json!({
+            "properties": {"price": {"type": "integer", "items": {"type": "string"}}},
+        }))
+        .unwrap();
+        assert!(invalid.validate().is_err());
+
+        let invalid: PayloadSchema = serde_json::from_value(json!({
+            "properties": {"color": {"type": "string", "enum": [1]}},
+        }))
+        .unwrap();
+        assert!(invalid.validate().is_err());
+    }
+}
@@ -56,6 +56,7 @@ impl TryFrom<Record> for PointStruct {
             payload,
             vector,
             shard_key: _,
+            geo_distance: _,
         } = record;
 
         if vector.is_none() {
@@ -327,6 +328,28 @@ impl PointOperations {
             PointOperations::SyncPoints(_) => true,
         }
     }
+
+    /// Drop vectors with names, for which `is_known` returns `false`, from inserted points
+    pub fn retain_vectors(&mut self, is_known: impl Fn(&str) -> bool) {
+        match self {
+            PointOperations::UpsertPoints(PointInsertOperationsInternal::PointsBatch(batch)) => {
+                if let BatchVectorStruct::Multi(vectors) = &mut batch.vectors {
+                    vectors.retain(|vector_name, _| is_known(vector_name));
+                }
+            }
+            PointOperations::UpsertPoints(PointInsertOperationsInternal::PointsList(points)) => {
+                for point in points {
+                    point.vector.retain(&is_known);
+                }
+            }
+            PointOperations::SyncPoints(operation) => {
+                for point in &mut operation.points {
+                    point.vector.retain(&is_known);
+                }
+            }
+            PointOperations::DeletePoints { .. } | PointOperations::DeletePointsByFilter(_) => {}
+        }
+    }
 }
 
 impl Validate for PointOperations {
@@ -0,0 +1,165 @@
+use std::collections::BTreeMap;
+
+use segment::data_types::vectors::{BatchVectorStruct, Vector, VectorStruct};
+
+use crate::operations::point_ops::{PointInsertOperationsInternal, PointOperations};
+use crate::operations::types::SparseVectorParams;
+use crate::operations::vector_ops::VectorOperations;
+use crate::operations::CollectionUpdateOperations;
+
+/// Apply pruning thresholds and top-K limits of the sparse vector params
+/// to all sparse vectors of the upsert or update vectors operation
+pub fn prune_sparse_vectors(
+    operation: &mut CollectionUpdateOperations,
+    sparse_vectors: &BTreeMap<String, SparseVectorParams>,
+) {
+    if !sparse_vectors.values().any(SparseVectorParams::is_pruning) {
+        return;
+    }
+
+    match operation {
+        CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(insert)) => {
+            match insert {
+                PointInsertOperationsInternal::PointsList(points) => points
+                    .iter_mut()
+                    .for_each(|point| prune_vector_struct(&mut point.vector, sparse_vectors)),
+                PointInsertOperationsInternal::PointsBatch(batch) => {
+                    if let BatchVectorStruct::Multi(vectors) = &mut batch.vectors {
+                        for (name, vectors) in vectors.iter_mut() {
+                            vectors
+                                .iter_mut()
+                                .for_each(|vector| prune_vector(name, vector, sparse_vectors));
+                        }
+                    }
+                }
+            }
+        }
+        CollectionUpdateOperations::VectorOperation(VectorOperations::UpdateVectors(update)) => {
+            update
+                .points
+                .iter_mut()
+                .for_each(|point| prune_vector_struct(&mut point.vector, sparse_vectors));
+        }
+        _ => {}
+    }
+}
+
+fn prune_vector_struct(
+    vector: &mut VectorStruct,
+    sparse_vectors: &BTreeMap<String, SparseVectorParams>,
+) {
+    if let VectorStruct::Multi(vectors) = vector {
+        for (name, vector) in vectors.iter_mut() {
+            prune_vector(name, vector, sparse_vectors);
+        }
+    }
+}
+
+fn prune_vector(
+    name: &str,
+    vector: &mut Vector,
+    sparse_vectors: &BTreeMap<String, SparseVectorParams>,
+) {
+    if let (Vector::Sparse(vector), Some(params)) = (vector, sparse_vectors.get(name)) {
+        params.prune(vector);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use sparse::common::sparse_vector::SparseVector;
+
+    use super::*;
+    use crate::operations::point_ops::{Batch, PointStruct};
+
+    fn params(prune_threshold: Option<f32>, max_dims: Option<usize>) -> SparseVectorParams {
+        SparseVectorParams {
+            index: None,
+            prune_threshold,
+            max_dims,
+            datatype: None,
+        }
+    }
+
+    fn sparse(indices: Vec<u32>, values: Vec<f32>) -> Vector {
+        Vector::Sparse(SparseVector::new(indices, values).unwrap())
+    }
+
+    #[test]
+    fn test_prune_points_list() {
+        let sparse_vectors = BTreeMap::from([
+            ("pruned".to_string(), params(Some(0.5), None)),
+            ("top".to_string(), params(None, Some(1))),
+        ]);
+        let vectors = HashMap::from([
+            (
+                "pruned".to_string(),
+                sparse(vec![1, 2, 3], vec![0.1, 1.0, -0.6]),
+            ),
+            (
+                "top".to_string(),
+                sparse(vec![1, 2, 3], vec![0.1, 1.0, -1.5]),
+            ),
+            ("dense".to_string(), Vector::Dense(vec![0.1, 1.0])),
+        ]);
+        let mut operation =
+            CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(
+                PointInsertOperationsInternal::PointsList(vec![PointStruct {
+                    id: 1.into(),
+                    vector: VectorStruct::Multi(vectors),
+                    payload: None,
+                }]),
+            ));
+
+        prune_sparse_vectors(&mut operation, &sparse_vectors);
+
+        let CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(
+            PointInsertOperationsInternal::PointsList(points),
+        )) = operation
+        else {
+            panic!("operation type must not change");
+        };
+        let VectorStruct::Multi(vectors) = &points[0].vector else {
+            panic!("vector type must not change");
+        };
+        assert_eq!(vectors["pruned"], sparse(vec![2, 3], vec![1.0, -0.6]));
+        assert_eq!(vectors["top"], sparse(vec![3], vec![-1.5]));
+        assert_eq!(vectors["dense"], Vector::Dense(vec![0.1, 1.0]));
+    }
+
+    #[test]
+    fn test_prune_points_batch() {
+        let sparse_vectors = BTreeMap::from([("text".to_string(), params(Some(0.5), Some(1)))]);
+        let mut operation = CollectionUpdateOperations::PointOperation(
+            PointOperations::UpsertPoints(PointInsertOperationsInternal::PointsBatch(Batch {
+                ids: vec![1.into(), 2.into()],
+                vectors: BatchVectorStruct::Multi(HashMap::from([(
+                    "text".to_string(),
+                    vec![
+                        sparse(vec![1, 2, 3], vec![0.6, 0.7, 0.1]),
+                        sparse(vec![1], vec![0.1]),
+                    ],
+                )])),
+                payloads: None,
+            })),
+        );
+
+        prune_sparse_vectors(&mut operation, &sparse_vectors);
+
+        let CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(
+            PointInsertOperationsInternal::PointsBatch(batch),
+        )) = operation
+        else {
+            panic!("operation type must not change");
+        };
+        let BatchVectorStruct::Multi(vectors) = batch.vectors else {
+            panic!("vector type must not change");
+        };
+        assert_eq!(
+            vectors["text"],
+            vec![sparse(vec![2], vec![0.7]), sparse(vec![], vec![])],
+        );
+    }
+}
@@ -0,0 +1,578 @@
+use segment::data_types::aggregation::HistogramParams;
+use segment::types::{Filter, Payload, PayloadSchemaType, SearchParams};
+
+use crate::config::StrictModeConfig;
+use crate::grouping::group_by::{GroupRequest, SourceRequest};
+use crate::operations::payload_ops::PayloadOps;
+use crate::operations::point_ops::{PointInsertOperationsInternal, PointOperations};
+use crate::operations::types::{
+    AggregateRequestInternal, CollectionError, CollectionResult, CoreSearchRequest,
+    CountRequestInternal, DiscoverRequestInternal, RecommendRequestInternal, ScrollRequestInternal,
+    TextSearchRequestInternal,
+};
+use crate::operations::vector_ops::VectorOperations;
+use crate::operations::CollectionUpdateOperations;
+
+/// Read request, which can be checked against the strict mode limits of the collection
+pub trait StrictModeVerification {
+    /// Max number of results requested
+    fn query_limit(&self) -> Option<usize>;
+
+    /// Number of results to skip
+    fn query_offset(&self) -> Option<usize>;
+
+    /// Filter of the request
+    fn query_filter(&self) -> Option<&Filter>;
+
+    /// Search params of the request
+    fn search_params(&self) -> Option<&SearchParams>;
+
+    /// Payload field, which the request reads through a payload index of the given type.
+    ///
+    /// Without such an index, the payload of every point is read instead.
+    fn indexed_field(&self) -> Option<(&str, PayloadSchemaType)> {
+        None
+    }
+
+    /// Max number of histogram buckets requested
+    fn histogram_buckets(&self) -> Option<usize> {
+        None
+    }
+
+    /// Check the request against the strict mode limits.
+    ///
+    /// Filter of the request has to be checked against the payload index separately,
+    /// see [`check_unindexed_filter`].
+    fn check_strict_mode(&self, config: &StrictModeConfig) -> CollectionResult<()> {
+        if let Some(limit) = self.query_limit() {
+            check_max("Query limit", limit, config.max_query_limit)?;
+        }
+
+        if let Some(offset) = self.query_offset() {
+            check_max("Query offset", offset, config.max_query_offset)?;
+        }
+
+        if let Some(buckets) = self.histogram_buckets() {
+            check_max(
+                "Max number of histogram buckets",
+                buckets,
+                config.max_histogram_buckets,
+            )?;
+        }
+
+        if let Some(params) = self.search_params() {
+            if let Some(hnsw_ef) = params.hnsw_ef {
+                check_max("HNSW ef", hnsw_ef, config.max_hnsw_ef)?;
+            }
+
+            if params.exact && config.search_allow_exact == Some(false) {
+                return Err(CollectionError::bad_request(
+                    "Exact search is forbidden by the strict mode of the collection".to_string(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl StrictModeVerification for CoreSearchRequest {
+    fn query_limit(&self) -> Option<usize> {
+        Some(self.limit)
+    }
+
+    fn query_offset(&self) -> Option<usize> {
+        Some(self.offset)
+    }
+
+    fn query_filter(&self) -> Option<&Filter> {
+        self.filter.as_ref()
+    }
+
+    fn search_params(&self) -> Option<&SearchParams> {
+        self.params.as_ref()
+    }
+}
+
+impl StrictModeVerification for RecommendRequestInternal {
+    fn query_limit(&self) -> Option<usize> {
+        Some(self.limit)
+    }
+
+    fn query_offset(&self) -> Option<usize> {
+        self.offset
+    }
+
+    fn query_filter(&self) -> Option<&Filter> {
+        self.filter.as_ref()
+    }
+
+    fn search_params(&self) -> Option<&SearchParams> {
+        self.params.as_ref()
+    }
+}
+
+impl StrictModeVerification for DiscoverRequestInternal {
+    fn query_limit(&self) -> Option<usize> {
+        Some(self.limit)
+    }
+
+    fn query_offset(&self) -> Option<usize> {
+        self.offset
+    }
+
+    fn query_filter(&self) -> Option<&Filter> {
+        self.filter.as_ref()
+    }
+
+    fn search_params(&self) -> Option<&SearchParams> {
+        self.params.as_ref()
+    }
+}
+
+impl StrictModeVerification for GroupRequest {
+    fn query_limit(&self) -> Option<usize> {
+        Some(self.limit)
+    }
+
+    fn query_offset(&self) -> Option<usize> {
+        None
+    }
+
+    fn query_filter(&self) -> Option<&Filter> {
+        match &self.source {
+            SourceRequest::Search(request) => request.filter.as_ref(),
+            SourceRequest::Recommend(request) => request.filter.as_ref(),
+        }
+    }
+
+    fn search_params(&self) -> Option<&SearchParams> {
+        match &self.source {
+            SourceRequest::Search(request) => request.params.as_ref(),
+            SourceRequest::Recommend(request) => request.params.as_ref(),
+        }
+    }
+}
+
+impl StrictModeVerification for ScrollRequestInternal {
+    fn query_limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    fn query_offset(&self) -> Option<usize> {
+        None
+    }
+
+    fn query_filter(&self) -> Option<&Filter> {
+        self.filter.as_ref()
+    }
+
+    fn search_params(&self) -> Option<&SearchParams> {
+        None
+    }
+
+    fn indexed_field(&self) -> Option<(&str, PayloadSchemaType)> {
+        self.order_by
+            .as_ref()
+            .map(|order_by| (order_by.geo_distance.key.as_str(), PayloadSchemaType::Geo))
+    }
+}
+
+impl StrictModeVerification for CountRequestInternal {
+    fn query_limit(&self) -> Option<usize> {
+        None
+    }
+
+    fn query_offset(&self) -> Option<usize> {
+        None
+    }
+
+    fn query_filter(&self) -> Option<&Filter> {
+        self.filter.as_ref()
+    }
+
+    fn search_params(&self) -> Option<&SearchParams> {
+        None
+    }
+}
+
+impl StrictModeVerification for AggregateRequestInternal {
+    fn query_limit(&self) -> Option<usize> {
+        None
+    }
+
+    fn query_offset(&self) -> Option<usize> {
+        None
+    }
+
+    fn query_filter(&self) -> Option<&Filter> {
+        self.filter.as_ref()
+    }
+
+    fn search_params(&self) -> Option<&SearchParams> {
+        None
+    }
+
+    fn histogram_buckets(&self) -> Option<usize> {
+        self.histogram.as_ref().map(HistogramParams::max_buckets)
+    }
+}
+
+impl StrictModeVerification for TextSearchRequestInternal {
+    fn query_limit(&self) -> Option<usize> {
+        Some(self.limit)
+    }
+
+    fn query_offset(&self) -> Option<usize> {
+        self.offset
+    }
+
+    fn query_filter(&self) -> Option<&Filter> {
+        self.filter.as_ref()
+    }
+
+    fn search_params(&self) -> Option<&SearchParams> {
+        None
+    }
+}
+
+/// Check the number of requests in a batch against the strict mode limits
+pub fn check_batch_size(batch_size: usize, config: &StrictModeConfig) -> CollectionResult<()> {
+    check_max(
+        "Search batch size",
+        batch_size,
+        config.max_search_batch_size,
+    )
+}
+
+/// Check the update operation against the strict mode limits.
+///
+/// Limits the number of points and the payload size of each point. Filter of the operation,
+/// see [`update_filter`], has to be checked against the payload index separately.
+pub fn check_update(
+    operation: &CollectionUpdateOperations,
+    config: &StrictModeConfig,
+) -> CollectionResult<()> {
+    check_max(
+        "Number of points",
+        update_points_count(operation),
+        config.max_update_points,
+    )?;
+
+    if let Some(max_payload_size) = config.max_payload_size {
+        check_payload_size(operation, max_payload_size)?;
+    }
+
+    Ok(())
+}
+
+/// Reject filter on the `unindexed_key` field, which the payload index can't serve
+pub fn check_unindexed_filter(
+    unindexed_key: Option<&str>,
+    config: &StrictModeConfig,
+) -> CollectionResult<()> {
+    match unindexed_key {
+        Some(key) if config.unindexed_filtering == Some(false) => {
+            Err(CollectionError::bad_request(format!(
+                "Filtering by field `{key}` requires a payload index suitable for the condition, \
+                 unindexed filtering is forbidden by the strict mode of the collection",
+            )))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Reject reading the `key` field without a payload index of the `schema_type`
+pub fn check_unindexed_field(
+    key: &str,
+    schema_type: PayloadSchemaType,
+    is_indexed: bool,
+    config: &StrictModeConfig,
+) -> CollectionResult<()> {
+    if is_indexed || config.unindexed_filtering != Some(false) {
+        return Ok(());
+    }
+    Err(CollectionError::bad_request(format!(
+        "Reading field `{key}` requires a payload index of type {schema_type:?}, \
+         unindexed reads are forbidden by the strict mode of the collection",
+    )))
+}
+
+fn check_max(name: &str, value: usize, max: Option<usize>) -> CollectionResult<()> {
+    match max {
+        Some(max) if value > max => Err(CollectionError::bad_request(format!(
+            "{name} is {value}, but the strict mode of the collection allows at most {max}",
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Number of points affected by the update operation, if they are listed explicitly
+fn update_points_count(operation: &CollectionUpdateOperations) -> usize {
+    match operation {
+        CollectionUpdateOperations::PointOperation(operation) => match operation {
+            PointOperations::UpsertPoints(PointInsertOperationsInternal::PointsBatch(batch)) => {
+                batch.ids.len()
+            }
+            PointOperations::UpsertPoints(PointInsertOperationsInternal::PointsList(points)) => {
+                points.len()
+            }
+            PointOperations::DeletePoints { ids } => ids.len(),
+            PointOperations::DeletePointsByFilter(_) => 0,
+            PointOperations::SyncPoints(sync) => sync.points.len(),
+        },
+        CollectionUpdateOperations::VectorOperation(operation) => match operation {
+            VectorOperations::UpdateVectors(update) => update.points.len(),
+            VectorOperations::DeleteVectors(ids, _) => ids.points.len(),
+            VectorOperations::DeleteVectorsByFilter(..) => 0,
+        },
+        CollectionUpdateOperations::PayloadOperation(operation) => match operation {
+            PayloadOps::SetPayload(set_payload) | PayloadOps::OverwritePayload(set_payload) => {
+                set_payload.points.as_ref().map_or(0, Vec::len)
+            }
+            PayloadOps::DeletePayload(delete_payload) => {
+                delete_payload.points.as_ref().map_or(0, Vec::len)
+            }
+            PayloadOps::ClearPayload { points } => points.len(),
+            PayloadOps::ClearPayloadByFilter(_) => 0,
+        },
+        CollectionUpdateOperations::FieldIndexOperation(_) => 0,
+    }
+}
+
+/// Filter selecting points affected by the update operation
+pub fn update_filter(operation: &CollectionUpdateOperations) -> Option<&Filter> {
+    match operation {
+        CollectionUpdateOperations::PointOperation(PointOperations::DeletePointsByFilter(
+            filter,
+        ))
+        | CollectionUpdateOperations::VectorOperation(VectorOperations::DeleteVectorsByFilter(
+            filter,
+            _,
+        ))
+        | CollectionUpdateOperations::PayloadOperation(PayloadOps::ClearPayloadByFilter(filter)) => {
+            Some(filter)
+        }
+        CollectionUpdateOperations::PayloadOperation(
+            PayloadOps::SetPayload(set_payload) | PayloadOps::OverwritePayload(set_payload),
+        ) => set_payload.filter.as_ref(),
+        CollectionUpdateOperations::PayloadOperation(PayloadOps::DeletePayload(delete_payload)) => {
+            delete_payload.filter.as_ref()
+        }
+        _ => None,
+    }
+}
+
+fn check_payload_size(
+    operation: &CollectionUpdateOperations,
+    max_payload_size: usize,
+) -> CollectionResult<()> {
+    let check = |payload: &Payload| {
+        let size = serde_json::to_vec(payload).map_or(0, |bytes| bytes.len());
+        if size > max_payload_size {
+            return Err(format!(
+                "is {size} bytes, but the strict mode of the collection allows at most \
+                 {max_payload_size} bytes",
+            ));
+        }
+        Ok(())
+    };
+
+    let result = match operation {
+        CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(insert)) => {
+            match insert {
+                PointInsertOperationsInternal::PointsBatch(batch) => batch
+                    .ids
+                    .iter()
+                    .zip(batch.payloads.iter().flatten())
+                    .filter_map(|(id, payload)| Some((id, payload.as_ref()?)))
+                    .try_for_each(|(id, payload)| {
+                        check(payload).map_err(|err| format!("Payload of point {id} {err}"))
+                    }),
+                PointInsertOperationsInternal::PointsList(points) => points
+                    .iter()
+                    .filter_map(|point| Some((point.id, point.payload.as_ref()?)))
+                    .try_for_each(|(id, payload)| {
+                        check(payload).map_err(|err| format!("Payload of point {id} {err}"))
+                    }),
+            }
+        }
+        CollectionUpdateOperations::PayloadOperation(
+            PayloadOps::SetPayload(set_payload) | PayloadOps::OverwritePayload(set_payload),
+        ) => check(&set_payload.payload).map_err(|err| format!("Payload {err}")),
+        _ => Ok(()),
+    };
+
+    result.map_err(CollectionError::bad_request)
+}
+
+#[cfg(test)]
+mod tests {
+    use segment::types::{Condition, IsNullCondition, PayloadField};
+    use serde_json::json;
+
+    use super::*;
+    use crate::operations::payload_ops::SetPayloadOp;
+
+    fn search_request(params: Option<SearchParams>) -> CoreSearchRequest {
+        CoreSearchRequest {
+            query: vec![1.0, 2.0].into(),
+            filter: None,
+            params,
+            limit: 10,
+            offset: 20,
+            with_payload: None,
+            with_vector: None,
+            score_threshold: None,
+            with_geo_distance: None,
+        }
+    }
+
+    #[test]
+    fn test_check_query_limits() {
+        let request = search_request(Some(SearchParams {
+            hnsw_ef: Some(128),
+            exact: true,
+            ..Default::default()
+        }));
+
+        assert!(request
+            .check_strict_mode(&StrictModeConfig::default())
+            .is_ok());
+
+        let config = StrictModeConfig {
+            max_query_limit: Some(10),
+            max_query_offset: Some(20),
+            max_hnsw_ef: Some(128),
+            ..Default::default()
+        };
+        assert!(request.check_strict_mode(&config).is_ok());
+
+        for config in [
+            StrictModeConfig {
+                max_query_limit: Some(5),
+                ..Default::default()
+            },
+            StrictModeConfig {
+                max_query_offset: Some(10),
+                ..Default::default()
+            },
+            StrictModeConfig {
+                max_hnsw_ef: Some(64),
+                ..Default::default()
+            },
+            StrictModeConfig {
+                search_allow_exact: Some(false),
+                ..Default::default()
+            },
+        ] {
+            let err = request.check_strict_mode(&config).unwrap_err();
+            assert!(matches!(err, CollectionError::BadRequest { .. }));
+        }
+
+        let config = StrictModeConfig {
+            max_search_batch_size: Some(2),
+            max_update_points: Some(5),
+            ..Default::default()
+        };
+        let err = check_batch_size(3, &config).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Bad request: Search batch size is 3, but the strict mode of the collection allows at most 2",
+        );
+    }
+
+    #[test]
+    fn test_check_histogram_buckets() {
+        let request = |max_buckets| AggregateRequestInternal {
+            filter: None,
+            key: "price".to_string(),
+            histogram: Some(HistogramParams {
+                interval: 10.0,
+                offset: 0.0,
+                max_buckets,
+            }),
+        };
+        let config = StrictModeConfig {
+            max_histogram_buckets: Some(100),
+            ..Default::default()
+        };
+
+        assert!(request(Some(100)).check_strict_mode(&config).is_ok());
+        assert!(request(Some(101)).check_strict_mode(&config).is_err());
+        // Default max number of buckets exceeds the limit
+        assert!(request(None).check_strict_mode(&config).is_err());
+        assert!(request(None)
+            .check_strict_mode(&StrictModeConfig::default())
+            .is_ok());
+
+        let request = AggregateRequestInternal {
+            histogram: None,
+            ..request(None)
+        };
+        assert!(request.check_strict_mode(&config).is_ok());
+    }
+
+    #[test]
+    fn test_check_unindexed_filter() {
+        let config = StrictModeConfig {
+            unindexed_filtering: Some(false),
+            ..Default::default()
+        };
+
+        assert!(check_unindexed_filter(None, &config).is_ok());
+        assert!(check_unindexed_filter(Some("size"), &StrictModeConfig::default()).is_ok());
+
+        let err = check_unindexed_filter(Some("size"), &config).unwrap_err();
+        assert!(err.to_string().contains("`size`"));
+
+        let geo = PayloadSchemaType::Geo;
+        assert!(check_unindexed_field("location", geo, true, &config).is_ok());
+        assert!(
+            check_unindexed_field("location", geo, false, &StrictModeConfig::default()).is_ok()
+        );
+        let err = check_unindexed_field("location", geo, false, &config).unwrap_err();
+        assert!(err.to_string().contains("`location`"));
+    }
+
+    #[test]
+    fn test_check_update() {
+        let config = StrictModeConfig {
+            max_search_batch_size: Some(10),
+            max_update_points: Some(2),
+            max_payload_size: Some(32),
+            ..Default::default()
+        };
+
+        let set_payload = |payload: serde_json::Value, points: Vec<u64>, filter: Option<Filter>| {
+            CollectionUpdateOperations::PayloadOperation(PayloadOps::SetPayload(SetPayloadOp {
+                payload: serde_json::from_value(payload).unwrap(),
+                points: Some(points.into_iter().map(Into::into).collect()),
+                filter,
+            }))
+        };
+
+        let operation = set_payload(json!({"color": "red"}), vec![1, 2], None);
+        assert!(check_update(&operation, &config).is_ok());
+        assert!(update_filter(&operation).is_none());
+
+        let operation = set_payload(json!({"color": "red"}), vec![1, 2, 3], None);
+        assert!(check_update(&operation, &config).is_err());
+
+        let operation = set_payload(
+            json!({"description": "a very long description of the point"}),
+            vec![1],
+            None,
+        );
+        let err = check_update(&operation, &config).unwrap_err();
+        assert!(err.to_string().contains("allows at most 32 bytes"));
+
+        let filter = Filter::new_must(Condition::IsNull(IsNullCondition {
+            is_null: PayloadField {
+                key: "size".to_string(),
+            },
+        }));
+        let operation = set_payload(json!({"color": "red"}), vec![], Some(filter.clone()));
+        assert_eq!(update_filter(&operation), Some(&filter));
+    }
+}
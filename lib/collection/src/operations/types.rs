@@ -14,21 +14,26 @@ use merge::Merge;
 use schemars::JsonSchema;
 use segment::common::anonymize::Anonymize;
 use segment::common::operation_error::OperationError;
+use segment::data_types::aggregation::{HistogramParams, NumericAggregation};
 use segment::data_types::groups::GroupId;
+use segment::data_types::text_index::TextStats;
 use segment::data_types::vectors::{
     DenseVector, Named, NamedQuery, NamedVectorStruct, QueryVector, Vector, VectorElementType,
     VectorRef, VectorStruct, DEFAULT_VECTOR_NAME,
 };
 use segment::types::{
-    Distance, Filter, Payload, PayloadIndexInfo, PayloadKeyType, PointIdType, QuantizationConfig,
-    ScoredPoint, SearchParams, SeqNumberType, ShardKey, WithPayloadInterface, WithVector,
+    Distance, Filter, GeoDistance, OrderBy, Payload, PayloadIndexInfo, PayloadKeyType, PointIdType,
+    QuantizationConfig, ScoredPoint, SearchParams, SeqNumberType, ShardKey, WithPayloadInterface,
+    WithVector,
 };
 use segment::vector_storage::query::context_query::ContextQuery;
 use segment::vector_storage::query::discovery_query::DiscoveryQuery;
+use segment::vector_storage::query::feedback_query::FeedbackQuery;
 use segment::vector_storage::query::reco_query::RecoQuery;
 use serde;
 use serde::{Deserialize, Serialize};
 use serde_json::Error as JsonError;
+use sparse::common::datatype::SparseVectorDatatype;
 use sparse::common::sparse_vector::SparseVector;
 use thiserror::Error;
 use tokio::sync::mpsc::error::SendError;
@@ -39,9 +44,12 @@ use validator::{Validate, ValidationError, ValidationErrors};
 
 use super::config_diff::{self};
 use crate::config::{CollectionConfig, CollectionParams};
-use crate::lookup::types::WithLookupInterface;
+use crate::lookup::types::{PointWithLookup, WithLookupInterface};
+use crate::lookup::WithLookup;
 use crate::operations::config_diff::{HnswConfigDiff, QuantizationConfigDiff};
+use crate::operations::consistency_params::ConsistencyToken;
 use crate::operations::shard_key_selector::ShardKeySelector;
+use crate::operations::CollectionUpdateOperations;
 use crate::save_on_disk;
 use crate::shards::replica_set::ReplicaState;
 use crate::shards::shard::{PeerId, ShardId};
@@ -90,6 +98,9 @@ pub struct Record {
     /// Shard Key
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shard_key: Option<ShardKey>,
+    /// Distance in meters from the `order_by` origin to the closest geo point of the point
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub geo_distance: Option<f64>,
 }
 
 /// Current statistics and configuration of the collection
@@ -192,6 +203,9 @@ pub struct CollectionClusterInfo {
     pub remote_shards: Vec<RemoteShardInfo>,
     /// Shard transfers
     pub shard_transfers: Vec<ShardTransferInfo>,
+    /// Problems with the placement of shard replicas, such as replicas sharing a failure domain
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Clone)]
@@ -251,6 +265,19 @@ pub struct UpdateResult {
     pub operation_id: Option<SeqNumberType>,
     /// Update status
     pub status: UpdateStatus,
+    /// Token to pass to read requests, to make them see the results of this update on any replica
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub consistency_token: Option<ConsistencyToken>,
+}
+
+/// Update operation applied to a shard, as recorded in the WAL of its replica
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct ShardChange {
+    /// Sequential number of the operation in the WAL of the replica
+    pub operation_id: SeqNumberType,
+    /// Applied update operation
+    pub operation: CollectionUpdateOperations,
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone)]
@@ -262,6 +289,10 @@ pub struct ScrollRequest {
     /// Specify in which shards to look for the points, if not specified - look in all shards
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shard_key: Option<ShardKeySelector>,
+    /// Look up records of another collection, which are linked to the result points through payload
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub with_lookup: Option<WithLookup>,
 }
 
 /// Scroll request - paginate over all points which matches given condition
@@ -281,6 +312,9 @@ pub struct ScrollRequestInternal {
     /// Whether to return the point vector with the result?
     #[serde(default, alias = "with_vectors")]
     pub with_vector: WithVector,
+    /// Order the points by the given criteria instead of ids.
+    /// Ordered scroll continues from the `offset` point, as returned in `next_page_offset`.
+    pub order_by: Option<OrderBy>,
 }
 
 impl Default for ScrollRequestInternal {
@@ -291,6 +325,7 @@ impl Default for ScrollRequestInternal {
             filter: None,
             with_payload: Some(WithPayloadInterface::Bool(true)),
             with_vector: WithVector::Bool(false),
+            order_by: None,
         }
     }
 }
@@ -305,6 +340,16 @@ pub struct ScrollResult {
     pub next_page_offset: Option<PointIdType>,
 }
 
+/// Result of the points read request, with the looked up records attached to the points
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ScrollLookupResult {
+    /// List of retrieved points
+    pub points: Vec<PointWithLookup<Record>>,
+    /// Offset which should be used to retrieve a next page result
+    pub next_page_offset: Option<PointIdType>,
+}
+
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone)]
 #[serde(rename_all = "snake_case")]
 pub struct SearchRequest {
@@ -314,6 +359,13 @@ pub struct SearchRequest {
     /// Specify in which shards to look for the points, if not specified - look in all shards
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shard_key: Option<ShardKeySelector>,
+    /// Look up records of another collection, which are linked to the result points through payload
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub with_lookup: Option<WithLookup>,
+    /// Return the distance in meters from the given origin to the closest geo point of each result
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub with_geo_distance: Option<GeoDistance>,
 }
 
 /// Search request.
@@ -364,6 +416,7 @@ pub enum QueryEnum {
     RecommendBestScore(NamedQuery<RecoQuery<Vector>>),
     Discover(NamedQuery<DiscoveryQuery<Vector>>),
     Context(NamedQuery<ContextQuery<Vector>>),
+    Feedback(NamedQuery<FeedbackQuery<Vector>>),
 }
 
 impl QueryEnum {
@@ -373,6 +426,7 @@ impl QueryEnum {
             QueryEnum::RecommendBestScore(reco_query) => reco_query.get_name(),
             QueryEnum::Discover(discovery_query) => discovery_query.get_name(),
             QueryEnum::Context(context_query) => context_query.get_name(),
+            QueryEnum::Feedback(feedback_query) => feedback_query.get_name(),
         }
     }
 }
@@ -389,6 +443,12 @@ impl From<NamedQuery<DiscoveryQuery<Vector>>> for QueryEnum {
     }
 }
 
+impl From<NamedQuery<FeedbackQuery<Vector>>> for QueryEnum {
+    fn from(query: NamedQuery<FeedbackQuery<Vector>>) -> Self {
+        QueryEnum::Feedback(query)
+    }
+}
+
 impl AsRef<QueryEnum> for QueryEnum {
     fn as_ref(&self) -> &QueryEnum {
         self
@@ -414,6 +474,8 @@ pub struct CoreSearchRequest {
     /// Whether to return the point vector with the result?
     pub with_vector: Option<WithVector>,
     pub score_threshold: Option<ScoreType>,
+    /// Attach the distance from the origin to the geo points of each result
+    pub with_geo_distance: Option<GeoDistance>,
 }
 
 #[derive(Debug, Clone)]
@@ -518,6 +580,16 @@ impl From<u64> for RecommendExample {
     }
 }
 
+/// Example with a weight, used by the `feedback` recommend strategy
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct FeedbackExample {
+    #[validate]
+    pub example: RecommendExample,
+    /// Positive weight pulls the results towards the example, negative pushes them away from it
+    pub weight: ScoreType,
+}
+
 /// How to use positive and negative examples to find the results, default is `average_vector`:
 ///
 /// * `average_vector` - Average positive and negative vectors and create a single query
@@ -527,12 +599,19 @@ impl From<u64> for RecommendExample {
 ///   examples, its score is then chosen from the `max(max_pos_score, max_neg_score)`.
 ///   If the `max_neg_score` is chosen then it is squared and negated, otherwise it is just
 ///   the `max_pos_score`.
+///
+/// * `feedback` - Uses relevance feedback objective. The first positive example is the target,
+///   each candidate is scored by its sigmoid-scaled similarity to the target and to the other
+///   examples, multiplied by their weights: `1` for positive, `-1` for negative and the given
+///   weight for `feedback` examples. Candidates closer to the negative side of any `context`
+///   pair are penalized. Requires at least one positive example.
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Default, PartialEq, Clone, Copy)]
 #[serde(rename_all = "snake_case")]
 pub enum RecommendStrategy {
     #[default]
     AverageVector,
     BestScore,
+    Feedback,
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Clone)]
@@ -573,6 +652,10 @@ pub struct RecommendRequest {
     /// Specify in which shards to look for the points, if not specified - look in all shards
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shard_key: Option<ShardKeySelector>,
+    /// Look up records of another collection, which are linked to the result points through payload
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub with_lookup: Option<WithLookup>,
 }
 
 /// Recommendation request.
@@ -595,6 +678,17 @@ pub struct RecommendRequestInternal {
     #[validate]
     pub negative: Vec<RecommendExample>,
 
+    /// Examples with explicit weights, only used with the `feedback` strategy
+    #[serde(default)]
+    #[validate]
+    pub feedback: Vec<FeedbackExample>,
+
+    /// Pairs of positive and negative examples, only used with the `feedback` strategy.
+    /// Points closer to the negative example of a pair than to its positive one are penalized
+    #[serde(default)]
+    #[validate]
+    pub context: Vec<ContextExamplePair>,
+
     /// How to use positive and negative examples to find the results
     pub strategy: Option<RecommendStrategy>,
 
@@ -639,6 +733,17 @@ pub struct RecommendRequestInternal {
     pub lookup_from: Option<LookupLocation>,
 }
 
+impl RecommendRequestInternal {
+    /// All examples of the request, including weighted and context ones
+    pub fn examples(&self) -> impl Iterator<Item = &RecommendExample> {
+        self.positive
+            .iter()
+            .chain(&self.negative)
+            .chain(self.feedback.iter().map(|item| &item.example))
+            .chain(self.context.iter().flat_map(|pair| pair.iter()))
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate)]
 #[serde(rename_all = "snake_case")]
 pub struct RecommendRequestBatch {
@@ -667,6 +772,17 @@ pub struct RecommendGroupsRequestInternal {
     #[serde(default)]
     pub negative: Vec<RecommendExample>,
 
+    /// Examples with explicit weights, only used with the `feedback` strategy
+    #[serde(default)]
+    #[validate]
+    pub feedback: Vec<FeedbackExample>,
+
+    /// Pairs of positive and negative examples, only used with the `feedback` strategy.
+    /// Points closer to the negative example of a pair than to its positive one are penalized
+    #[serde(default)]
+    #[validate]
+    pub context: Vec<ContextExamplePair>,
+
     /// How to use positive and negative examples to find the results
     #[serde(default)]
     pub strategy: Option<RecommendStrategy>,
@@ -836,6 +952,11 @@ pub struct CountRequestInternal {
     /// Approximate count might be unreliable during the indexing process. Default: true
     #[serde(default = "default_exact_count")]
     pub exact: bool,
+    /// If set, count distinct keyword and integer values of this payload field among matched points instead of points.
+    /// If `exact` is false, large numbers of distinct values are estimated with a HyperLogLog sketch,
+    /// otherwise counting more than 4096 distinct values fails.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub distinct: Option<PayloadKeyType>,
 }
 
 pub const fn default_exact_count() -> bool {
@@ -849,6 +970,164 @@ pub struct CountResult {
     pub count: usize,
 }
 
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate)]
+#[serde(rename_all = "snake_case")]
+pub struct AggregateRequest {
+    #[serde(flatten)]
+    #[validate]
+    pub aggregate_request: AggregateRequestInternal,
+    /// Specify in which shards to look for the points, if not specified - look in all shards
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shard_key: Option<ShardKeySelector>,
+}
+
+/// Aggregate Request
+/// Computes exact statistics of the numeric values of a payload field over the points which satisfy the given filter.
+/// Non-numeric values are ignored, every value of an array counts separately.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct AggregateRequestInternal {
+    /// Look only for points which satisfies this conditions
+    #[validate]
+    pub filter: Option<Filter>,
+    /// Payload key with the numeric values to aggregate
+    pub key: PayloadKeyType,
+    /// If specified, also count the values in fixed-width histogram buckets
+    #[validate]
+    pub histogram: Option<HistogramParams>,
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct HistogramBucket {
+    /// Lower bound of the bucket, inclusive
+    pub from: f64,
+    /// Upper bound of the bucket, exclusive
+    pub to: f64,
+    /// Number of values in the bucket
+    pub count: usize,
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct AggregateResult {
+    /// Number of aggregated values
+    pub count: usize,
+    /// Smallest value, absent if there are no values
+    pub min: Option<f64>,
+    /// Largest value, absent if there are no values
+    pub max: Option<f64>,
+    /// Sum of the values
+    pub sum: f64,
+    /// Exact sum of the values without a fractional part, absent if it doesn't fit into 64 bits
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integer_sum: Option<i64>,
+    /// Mean of the values, absent if there are no values
+    pub avg: Option<f64>,
+    /// Non-empty buckets ordered by their bounds, present if the histogram was requested
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub histogram: Option<Vec<HistogramBucket>>,
+}
+
+impl AggregateResult {
+    pub fn new(aggregation: NumericAggregation, histogram: Option<&HistogramParams>) -> Self {
+        let avg = aggregation.avg();
+        let histogram = histogram.map(|params| {
+            aggregation
+                .histogram
+                .into_iter()
+                .map(|(bucket, count)| {
+                    let (from, to) = params.bucket_bounds(bucket);
+                    HistogramBucket { from, to, count }
+                })
+                .collect()
+        });
+        Self {
+            count: aggregation.count,
+            min: aggregation.min,
+            max: aggregation.max,
+            sum: aggregation.sum(),
+            integer_sum: i64::try_from(aggregation.integer_sum).ok(),
+            avg,
+            histogram,
+        }
+    }
+
+    /// Restore the mergeable representation, inverse of [`AggregateResult::new`]
+    pub fn into_aggregation(self, histogram: Option<&HistogramParams>) -> NumericAggregation {
+        let histogram = match (histogram, self.histogram) {
+            (Some(params), Some(buckets)) => buckets
+                .into_iter()
+                // Middle of the bucket is robust to rounding of the bounds
+                .map(|bucket| {
+                    (
+                        params.bucket(bucket.from + params.interval / 2.0),
+                        bucket.count,
+                    )
+                })
+                .collect(),
+            _ => Default::default(),
+        };
+        // Keep the integer part exact, only the fractional part absorbs the rounding of the sum
+        let integer_sum = self.integer_sum.unwrap_or_default();
+        NumericAggregation {
+            count: self.count,
+            min: self.min,
+            max: self.max,
+            integer_sum: i128::from(integer_sum),
+            float_sum: self.sum - integer_sum as f64,
+            histogram,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate)]
+#[serde(rename_all = "snake_case")]
+pub struct TextSearchRequest {
+    #[serde(flatten)]
+    #[validate]
+    pub text_search_request: TextSearchRequestInternal,
+    /// Specify in which shards to look for the points, if not specified - look in all shards
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shard_key: Option<ShardKeySelector>,
+}
+
+/// Text Search Request
+/// Ranks points by the BM25 relevance of the text in a payload field with a full-text index to the query.
+/// Points containing any token of the query are matched. Document statistics are collected over all selected shards.
+/// Text search is a standalone request, it can't be combined with vector search into a hybrid or fusion query.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct TextSearchRequestInternal {
+    /// Payload key with the full-text index to search in
+    pub key: PayloadKeyType,
+    /// Text to search for, tokenized the same way as the indexed text
+    #[validate(length(min = 1))]
+    pub query: String,
+    /// Look only for points which satisfies this conditions
+    #[validate]
+    pub filter: Option<Filter>,
+    /// Max number of result to return
+    #[validate(range(min = 1))]
+    pub limit: usize,
+    /// Offset of the first result to return.
+    /// May be used to paginate results.
+    /// Note: large offset values may cause performance issues.
+    pub offset: Option<usize>,
+    /// Select which payload to return with the response. Default: None
+    pub with_payload: Option<WithPayloadInterface>,
+    /// Whether to return the point vector with the result?
+    #[serde(default, alias = "with_vectors")]
+    pub with_vector: Option<WithVector>,
+    /// Define a minimal score threshold for the result.
+    /// If defined, less relevant results will not be returned.
+    pub score_threshold: Option<ScoreType>,
+    /// Statistics of the field over all selected shards to score with, collected by the collection
+    /// before scoring. If absent, each shard scores with its own statistics.
+    #[serde(skip)]
+    pub stats: Option<TextStats>,
+}
+
 #[derive(Error, Debug, Clone)]
 #[error("{0}")]
 pub enum CollectionError {
@@ -1220,9 +1499,13 @@ impl Record {
 
 /// Params of single vector data storage
 #[derive(Debug, Hash, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq, Eq)]
+#[validate(schema(function = "validate_vector_params"))]
 #[serde(rename_all = "snake_case")]
 pub struct VectorParams {
     /// Size of a vectors used
+    /// For `Hamming` and `Jaccard` distances - number of bits, must be a multiple of 8.
+    /// Such vectors are provided as packed bytes, one byte value (0..=255) per element,
+    /// and are always stored in memory.
     #[validate(custom = "validate_nonzerou64_range_min_1_max_65536")]
     pub size: NonZeroU64,
     /// Type of distance function used for measuring distance between vectors
@@ -1245,6 +1528,36 @@ pub struct VectorParams {
     pub on_disk: Option<bool>,
 }
 
+/// Validate that binary vectors are byte-aligned and are not quantized.
+pub fn validate_vector_params(params: &VectorParams) -> Result<(), ValidationError> {
+    if !params.distance.is_binary() {
+        return Ok(());
+    }
+    if params.size.get() % u8::BITS as u64 != 0 {
+        let mut error = ValidationError::new("binary_vector_size");
+        error.message = Some(
+            format!(
+                "size of binary vectors must be a multiple of {} bits, got {}",
+                u8::BITS,
+                params.size,
+            )
+            .into(),
+        );
+        return Err(error);
+    }
+    if params.quantization_config.is_some() {
+        let mut error = ValidationError::new("binary_vector_quantization");
+        error.message = Some("quantization is not supported for binary vectors".into());
+        return Err(error);
+    }
+    if params.on_disk == Some(true) {
+        let mut error = ValidationError::new("binary_vector_on_disk");
+        error.message = Some("binary vectors are always stored in memory".into());
+        return Err(error);
+    }
+    Ok(())
+}
+
 /// Validate the value is in `[1, 65536]` or `None`.
 pub fn validate_nonzerou64_range_min_1_max_65536(
     value: &NonZeroU64,
@@ -1267,18 +1580,66 @@ impl Anonymize for VectorParams {
 }
 
 /// Params of single sparse vector data storage
-#[derive(Debug, Hash, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq, Eq)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq)]
 #[serde(rename_all = "snake_case")]
 pub struct SparseVectorParams {
     /// Custom params for index. If none - values from collection configuration are used.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub index: Option<SparseIndexParams>,
+    /// Remove dimensions with absolute weight below this threshold from upserted vectors.
+    /// Changes apply to vectors upserted afterwards, existing vectors are not pruned again.
+    /// If none - all dimensions are kept.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 0.0))]
+    pub prune_threshold: Option<f32>,
+    /// Keep only this number of dimensions with the largest absolute weights in upserted vectors.
+    /// Changes apply to vectors upserted afterwards, existing vectors are not pruned again.
+    /// If none - all dimensions are kept.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1))]
+    pub max_dims: Option<usize>,
+    /// Datatype of the stored weights. Lower precision reduces the storage size,
+    /// weights are rounded on upsert and `uint8` weights are quantized when the segment gets
+    /// an immutable index, so the same values are indexed and scored. Default: float32
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub datatype: Option<SparseVectorDatatype>,
+}
+
+impl std::hash::Hash for SparseVectorParams {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.index.hash(state);
+        self.prune_threshold.map(f32::to_bits).hash(state);
+        self.max_dims.hash(state);
+        self.datatype.hash(state);
+    }
+}
+
+impl Eq for SparseVectorParams {}
+
+impl SparseVectorParams {
+    /// Apply pruning and top-K selection of the params to the upserted vector
+    pub fn prune(&self, vector: &mut SparseVector) {
+        if let Some(threshold) = self.prune_threshold {
+            vector.prune(threshold);
+        }
+        if let Some(max_dims) = self.max_dims {
+            vector.keep_top_k(max_dims);
+        }
+    }
+
+    /// Whether upserted vectors are modified by these params
+    pub fn is_pruning(&self) -> bool {
+        self.prune_threshold.is_some() || self.max_dims.is_some()
+    }
 }
 
 impl Anonymize for SparseVectorParams {
     fn anonymize(&self) -> Self {
         Self {
             index: self.index.anonymize(),
+            prune_threshold: self.prune_threshold,
+            max_dims: self.max_dims,
+            datatype: self.datatype,
         }
     }
 }
@@ -1295,6 +1656,11 @@ pub struct SparseIndexParams {
     /// Store index on disk. If set to false, the index will be stored in RAM. Default: false
     #[serde(skip_serializing_if = "Option::is_none")]
     pub on_disk: Option<bool>,
+    /// Store posting lists of the on-disk index in compressed format.
+    /// Ids are delta encoded and weights are quantized, which reduces the index size at the cost of score precision.
+    /// Default: false
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compressed: Option<bool>,
 }
 
 impl Anonymize for SparseIndexParams {
@@ -1302,15 +1668,21 @@ impl Anonymize for SparseIndexParams {
         SparseIndexParams {
             full_scan_threshold: self.full_scan_threshold,
             on_disk: self.on_disk,
+            compressed: self.compressed,
         }
     }
 }
 
 impl SparseIndexParams {
-    pub fn new(full_scan_threshold: Option<usize>, on_disk: Option<bool>) -> Self {
+    pub fn new(
+        full_scan_threshold: Option<usize>,
+        on_disk: Option<bool>,
+        compressed: Option<bool>,
+    ) -> Self {
         SparseIndexParams {
             full_scan_threshold,
             on_disk,
+            compressed,
         }
     }
 
@@ -1321,6 +1693,9 @@ impl SparseIndexParams {
         if let Some(on_disk) = other.on_disk {
             self.on_disk = Some(on_disk);
         }
+        if let Some(compressed) = other.compressed {
+            self.compressed = Some(compressed);
+        }
     }
 }
 
@@ -1376,6 +1751,37 @@ impl VectorsConfig {
         }
     }
 
+    /// Add parameters of a new vector.
+    ///
+    /// If this is `Single`, it is converted into `Multi` with the existing vector named
+    /// [`DEFAULT_VECTOR_NAME`].
+    pub fn insert(&mut self, name: String, params: VectorParams) {
+        match self {
+            VectorsConfig::Single(single) => {
+                *self = VectorsConfig::Multi(BTreeMap::from([
+                    (DEFAULT_VECTOR_NAME.to_string(), single.clone()),
+                    (name, params),
+                ]));
+            }
+            VectorsConfig::Multi(multi) => {
+                multi.insert(name, params);
+            }
+        }
+    }
+
+    /// Remove parameters of the vector, returns `None` if there is no such vector.
+    pub fn remove(&mut self, name: &str) -> Option<VectorParams> {
+        match self {
+            VectorsConfig::Single(single) if name == DEFAULT_VECTOR_NAME => {
+                let params = single.clone();
+                *self = VectorsConfig::empty();
+                Some(params)
+            }
+            VectorsConfig::Single(_) => None,
+            VectorsConfig::Multi(multi) => multi.remove(name),
+        }
+    }
+
     /// Iterate over the named vector parameters.
     ///
     /// If this is `Single` it iterates over a single parameter named [`DEFAULT_VECTOR_NAME`].
@@ -1716,6 +2122,7 @@ impl From<SearchRequestInternal> for CoreSearchRequest {
             with_payload: request.with_payload,
             with_vector: request.with_vector,
             score_threshold: request.score_threshold,
+            with_geo_distance: None,
         }
     }
 }
@@ -1727,6 +2134,7 @@ impl From<QueryEnum> for QueryVector {
             QueryEnum::RecommendBestScore(named) => QueryVector::Recommend(named.query),
             QueryEnum::Discover(named) => QueryVector::Discovery(named.query),
             QueryEnum::Context(named) => QueryVector::Context(named.query),
+            QueryEnum::Feedback(named) => QueryVector::Feedback(named.query),
         }
     }
 }
@@ -1,7 +1,16 @@
+use std::collections::HashMap;
+
 use actix_web_validator::error::flatten_errors;
+use segment::types::{Payload, PayloadFieldSchema, PayloadKeyType};
 use serde_json::Value;
 use validator::{ValidationError, ValidationErrors};
 
+use crate::operations::payload_ops::PayloadOps;
+use crate::operations::payload_schema::{check_indexed_values, PayloadSchema};
+use crate::operations::point_ops::{PointInsertOperationsInternal, PointOperations};
+use crate::operations::types::{CollectionError, CollectionResult};
+use crate::operations::CollectionUpdateOperations;
+
 /// Warn about validation errors in the log.
 ///
 /// Validation errors are pretty printed field-by-field.
@@ -25,6 +34,75 @@ pub fn label_errors(label: impl AsRef<str>, errs: &ValidationErrors) -> String {
     )
 }
 
+/// Validate payload written by the operation against the payload schema of the collection.
+///
+/// In strict mode, values of indexed fields must also be accepted by their payload index.
+/// The error lists all points with invalid payload.
+pub fn validate_payload_schema(
+    operation: &CollectionUpdateOperations,
+    schema: &PayloadSchema,
+    payload_indexes: &HashMap<PayloadKeyType, PayloadFieldSchema>,
+) -> CollectionResult<()> {
+    let check = |payload: &Payload, partial: bool| {
+        schema.check_payload(payload, partial)?;
+        if schema.strict {
+            check_indexed_values(payload, payload_indexes)?;
+        }
+        Ok::<_, String>(())
+    };
+
+    let empty_payload = Payload::default();
+    let mut errors = Vec::new();
+
+    match operation {
+        CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(insert)) => {
+            match insert {
+                PointInsertOperationsInternal::PointsBatch(batch) => {
+                    for (index, id) in batch.ids.iter().enumerate() {
+                        let payload = batch
+                            .payloads
+                            .as_ref()
+                            .and_then(|payloads| payloads.get(index))
+                            .and_then(Option::as_ref)
+                            .unwrap_or(&empty_payload);
+                        if let Err(err) = check(payload, false) {
+                            errors.push(format!("point {id}: {err}"));
+                        }
+                    }
+                }
+                PointInsertOperationsInternal::PointsList(points) => {
+                    for point in points {
+                        let payload = point.payload.as_ref().unwrap_or(&empty_payload);
+                        if let Err(err) = check(payload, false) {
+                            errors.push(format!("point {}: {err}", point.id));
+                        }
+                    }
+                }
+            }
+        }
+        CollectionUpdateOperations::PayloadOperation(PayloadOps::SetPayload(set_payload)) => {
+            if let Err(err) = check(&set_payload.payload, true) {
+                errors.push(err);
+            }
+        }
+        CollectionUpdateOperations::PayloadOperation(PayloadOps::OverwritePayload(set_payload)) => {
+            if let Err(err) = check(&set_payload.payload, false) {
+                errors.push(err);
+            }
+        }
+        _ => {}
+    }
+
+    if errors.is_empty() {
+        return Ok(());
+    }
+
+    Err(CollectionError::bad_input(format!(
+        "Payload doesn't match payload schema of the collection: {}",
+        errors.join("; "),
+    )))
+}
+
 /// Describe the given validation errors.
 ///
 /// Returns a list of error messages for fields: `(field, message)`
@@ -88,6 +88,24 @@ impl VectorOperations {
             VectorOperations::DeleteVectorsByFilter(..) => false,
         }
     }
+
+    /// Drop vectors with names, for which `is_known` returns `false`, from the operation
+    ///
+    /// Points, which are left without vectors to update, are skipped.
+    pub fn retain_vectors(&mut self, is_known: impl Fn(&str) -> bool) {
+        match self {
+            VectorOperations::UpdateVectors(operation) => {
+                for point in &mut operation.points {
+                    point.vector.retain(&is_known);
+                }
+                operation.points.retain(|point| !point.vector.is_empty());
+            }
+            VectorOperations::DeleteVectors(_, vector_names)
+            | VectorOperations::DeleteVectorsByFilter(_, vector_names) => {
+                vector_names.retain(|vector_name| is_known(vector_name));
+            }
+        }
+    }
 }
 
 impl Validate for VectorOperations {
@@ -1,4 +1,5 @@
 use std::future::Future;
+use std::iter;
 use std::time::Duration;
 
 use itertools::Itertools;
@@ -9,6 +10,8 @@ use segment::data_types::vectors::{
 use segment::types::{
     Condition, ExtendedPointId, Filter, HasIdCondition, PointIdType, ScoredPoint,
 };
+use segment::vector_storage::query::context_query::ContextPair;
+use segment::vector_storage::query::feedback_query::{FeedbackItem, FeedbackQuery};
 use segment::vector_storage::query::reco_query::RecoQuery;
 use sparse::common::sparse_vector::SparseVector;
 use tokio::sync::RwLockReadGuard;
@@ -20,6 +23,7 @@ use crate::common::fetch_vectors::{
     ReferencedVectors,
 };
 use crate::common::retrieve_request_trait::RetrieveRequest;
+use crate::config::CollectionParams;
 use crate::operations::consistency_params::ReadConsistency;
 use crate::operations::shard_selector_internal::ShardSelectorInternal;
 use crate::operations::types::{
@@ -123,14 +127,48 @@ where
     Ok(results.into_iter().next().unwrap())
 }
 
+/// Average of bit vectors is not a bit vector, so binary vectors can't be recommended by average
+pub fn check_avg_vector_supported(
+    request: &RecommendRequestInternal,
+    collection_params: &CollectionParams,
+) -> CollectionResult<()> {
+    if request.strategy.unwrap_or_default() != RecommendStrategy::AverageVector {
+        return Ok(());
+    }
+    let vector_name = match &request.using {
+        None => DEFAULT_VECTOR_NAME,
+        Some(UsingVector::Name(name)) => name.as_str(),
+    };
+    if collection_params
+        .get_distance(vector_name)
+        .is_ok_and(|distance| distance.is_binary())
+    {
+        return Err(CollectionError::BadRequest {
+            description: format!(
+                "Average vector strategy is not supported for binary vector {vector_name}, use best score strategy instead"
+            ),
+        });
+    }
+    Ok(())
+}
+
 pub fn recommend_into_core_search(
     request: RecommendRequestInternal,
     all_vectors_records_map: &ReferencedVectors,
 ) -> CollectionResult<CoreSearchRequest> {
+    let strategy = request.strategy.unwrap_or_default();
+    if strategy != RecommendStrategy::Feedback
+        && (!request.feedback.is_empty() || !request.context.is_empty())
+    {
+        return Err(CollectionError::BadRequest {
+            description:
+                "Feedback examples and context pairs are only supported with the feedback strategy"
+                    .to_owned(),
+        });
+    }
+
     let reference_vectors_ids = request
-        .positive
-        .iter()
-        .chain(&request.negative)
+        .examples()
         .filter_map(|example| example.as_point_id())
         .collect_vec();
 
@@ -147,7 +185,7 @@ pub fn recommend_into_core_search(
         }
     }
 
-    match request.strategy.unwrap_or_default() {
+    match strategy {
         RecommendStrategy::AverageVector => {
             recommend_by_avg_vector(request, reference_vectors_ids, all_vectors_records_map)
         }
@@ -156,6 +194,9 @@ pub fn recommend_into_core_search(
             reference_vectors_ids,
             all_vectors_records_map,
         )),
+        RecommendStrategy::Feedback => {
+            recommend_by_feedback(request, reference_vectors_ids, all_vectors_records_map)
+        }
     }
 }
 
@@ -191,6 +232,8 @@ where
         return Ok(vec![]);
     }
 
+    let collection_params = collection.collection_config.read().await.params.clone();
+
     // Validate amount of examples
     request_batch.iter().try_for_each(|(request, _)| {
         match request.strategy.unwrap_or_default() {
@@ -201,6 +244,15 @@ where
                             .to_owned(),
                     });
                 }
+                check_avg_vector_supported(request, &collection_params)?;
+            }
+            RecommendStrategy::Feedback => {
+                if request.positive.is_empty() {
+                    return Err(CollectionError::BadRequest {
+                        description: "At least one positive vector ID required with this strategy"
+                            .to_owned(),
+                    });
+                }
             }
             RecommendStrategy::BestScore => {
                 if request.positive.is_empty() && request.negative.is_empty() {
@@ -331,6 +383,7 @@ fn recommend_by_avg_vector(
         limit,
         score_threshold,
         offset: offset.unwrap_or_default(),
+        with_geo_distance: None,
     })
 }
 
@@ -344,6 +397,8 @@ fn recommend_by_best_score(
     let RecommendRequestInternal {
         positive,
         negative,
+        feedback: _,
+        context: _,
         strategy: _,
         filter,
         params,
@@ -394,9 +449,122 @@ fn recommend_by_best_score(
         with_payload,
         with_vector,
         score_threshold,
+        with_geo_distance: None,
     }
 }
 
+fn recommend_by_feedback(
+    request: RecommendRequestInternal,
+    reference_vectors_ids: Vec<PointIdType>,
+    all_vectors_records_map: &ReferencedVectors,
+) -> CollectionResult<CoreSearchRequest> {
+    let lookup_vector_name = request.get_search_vector_name();
+
+    let RecommendRequestInternal {
+        positive,
+        negative,
+        feedback,
+        context,
+        strategy: _,
+        filter,
+        params,
+        limit,
+        offset,
+        with_payload,
+        with_vector,
+        score_threshold,
+        using,
+        lookup_from,
+    } = request;
+
+    let lookup_collection_name = lookup_from.as_ref().map(|x| &x.collection);
+
+    let mut positive = convert_to_vectors_owned(
+        positive,
+        all_vectors_records_map,
+        &lookup_vector_name,
+        lookup_collection_name,
+    )
+    .into_iter();
+
+    let negative = convert_to_vectors_owned(
+        negative,
+        all_vectors_records_map,
+        &lookup_vector_name,
+        lookup_collection_name,
+    );
+
+    // The first positive example is the target, which has the same weight as the other positives
+    let target = positive.next().ok_or_else(|| CollectionError::BadRequest {
+        description: "At least one positive vector ID required with this strategy".to_owned(),
+    })?;
+
+    // Weighted examples whose vector is missing are skipped, like positive and negative ones
+    let weighted = feedback.iter().filter_map(|item| {
+        convert_to_vectors(
+            iter::once(&item.example),
+            all_vectors_records_map,
+            &lookup_vector_name,
+            lookup_collection_name,
+        )
+        .next()
+        .map(|vector| FeedbackItem::new(vector.to_owned(), item.weight))
+    });
+
+    let feedback = positive
+        .map(|example| FeedbackItem::new(example, 1.0))
+        .chain(
+            negative
+                .into_iter()
+                .map(|example| FeedbackItem::new(example, -1.0)),
+        )
+        .chain(weighted)
+        .collect();
+
+    let context = context
+        .iter()
+        .filter_map(|pair| {
+            let mut vector_pair = convert_to_vectors(
+                pair.iter(),
+                all_vectors_records_map,
+                &lookup_vector_name,
+                lookup_collection_name,
+            )
+            .map(|vector| vector.to_owned());
+
+            Some(ContextPair {
+                positive: vector_pair.next()?,
+                negative: vector_pair.next()?,
+            })
+        })
+        .collect();
+
+    let query = QueryEnum::Feedback(NamedQuery {
+        query: FeedbackQuery::new(target, feedback, context),
+        using: using.map(|x| match x {
+            UsingVector::Name(name) => name,
+        }),
+    });
+
+    Ok(CoreSearchRequest {
+        query,
+        filter: Some(Filter {
+            should: None,
+            must: filter.map(|filter| vec![Condition::Filter(filter)]),
+            must_not: Some(vec![Condition::HasId(HasIdCondition {
+                has_id: reference_vectors_ids.into_iter().collect(),
+            })]),
+        }),
+        params,
+        limit,
+        offset: offset.unwrap_or_default(),
+        with_payload,
+        with_vector,
+        score_threshold,
+        with_geo_distance: None,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use segment::data_types::vectors::Vector;
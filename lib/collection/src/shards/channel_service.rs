@@ -13,12 +13,15 @@ use tonic::{Request, Status};
 use url::Url;
 
 use crate::operations::types::{CollectionError, CollectionResult};
+use crate::shards::peer_labels::PeerLabelsById;
 use crate::shards::shard::PeerId;
 
 #[derive(Clone)]
 pub struct ChannelService {
     // Shared with consensus_state
     pub id_to_address: Arc<parking_lot::RwLock<HashMap<PeerId, Uri>>>,
+    // Shared with consensus_state
+    pub id_to_labels: Arc<parking_lot::RwLock<PeerLabelsById>>,
     pub channel_pool: Arc<TransportChannelPool>,
     /// Port at which the public REST API is exposed for the current peer.
     pub current_rest_port: u16,
@@ -29,12 +32,14 @@ impl ChannelService {
     pub fn new(current_rest_port: u16) -> Self {
         Self {
             id_to_address: Default::default(),
+            id_to_labels: Default::default(),
             channel_pool: Default::default(),
             current_rest_port,
         }
     }
 
     pub async fn remove_peer(&self, peer_id: PeerId) {
+        self.id_to_labels.write().remove(&peer_id);
         let removed = self.id_to_address.write().remove(&peer_id);
         if let Some(uri) = removed {
             self.channel_pool.drop_pool(&uri).await;
@@ -60,10 +65,28 @@ impl ChannelService {
         term: u64,
         timeout: Duration,
     ) -> Result<(), CollectionError> {
-        let requests = self
-            .id_to_address
-            .read()
-            .keys()
+        let peers: Vec<_> = self.id_to_address.read().keys().copied().collect();
+        self.await_commit_on_peers(this_peer_id, &peers, commit, term, timeout)
+            .await
+    }
+
+    /// Wait until the given peers reach the given commit
+    ///
+    /// This peer is skipped, if listed. Errors the same way as [`Self::await_commit_on_all_peers`].
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is cancel safe.
+    pub async fn await_commit_on_peers(
+        &self,
+        this_peer_id: PeerId,
+        peers: &[PeerId],
+        commit: u64,
+        term: u64,
+        timeout: Duration,
+    ) -> Result<(), CollectionError> {
+        let requests = peers
+            .iter()
             .filter(|id| **id != this_peer_id)
             // The collective timeout at the bottom of this function handles actually timing out.
             // Since an explicit timeout must be given here as well, it is multiplied by two to
@@ -77,7 +100,7 @@ impl ChannelService {
             .await
             // Timeout error
             .map_err(|_elapsed| CollectionError::Timeout {
-                description: "Failed to wait for consensus commit on all peers, timed out.".into(),
+                description: "Failed to wait for consensus commit on peers, timed out.".into(),
             })?
             // Await consensus error
             .map_err(|err| {
@@ -182,6 +205,7 @@ impl Default for ChannelService {
     fn default() -> Self {
         Self {
             id_to_address: Default::default(),
+            id_to_labels: Default::default(),
             channel_pool: Default::default(),
             current_rest_port: 6333,
         }
@@ -1,5 +1,6 @@
 use api::grpc::conversions::{convert_shard_key_from_grpc_opt, payload_to_proto};
 use api::grpc::qdrant::points_selector::PointsSelectorOneOf;
+use api::grpc::qdrant::shard_change::{self, Operation};
 use api::grpc::qdrant::{
     ClearPayloadPoints, ClearPayloadPointsInternal, CreateFieldIndexCollection,
     CreateFieldIndexCollectionInternal, DeleteFieldIndexCollection,
@@ -13,13 +14,13 @@ use segment::types::{Filter, PayloadFieldSchema, PayloadSchemaParams, PointIdTyp
 use tonic::Status;
 
 use crate::operations::conversions::write_ordering_to_proto;
-use crate::operations::payload_ops::{DeletePayloadOp, SetPayloadOp};
+use crate::operations::payload_ops::{DeletePayloadOp, PayloadOps, SetPayloadOp};
 use crate::operations::point_ops::{
-    PointInsertOperationsInternal, PointSyncOperation, WriteOrdering,
+    PointInsertOperationsInternal, PointOperations, PointSyncOperation, WriteOrdering,
 };
-use crate::operations::types::CollectionResult;
-use crate::operations::vector_ops::UpdateVectorsOp;
-use crate::operations::CreateIndex;
+use crate::operations::types::{CollectionResult, ShardChange};
+use crate::operations::vector_ops::{UpdateVectorsOp, VectorOperations};
+use crate::operations::{CollectionUpdateOperations, CreateIndex, FieldIndexOperations};
 use crate::shards::shard::ShardId;
 
 pub fn internal_sync_points(
@@ -335,6 +336,10 @@ pub fn internal_create_index(
                 None,
             ),
             PayloadFieldSchema::FieldParams(field_params) => match field_params {
+                PayloadSchemaParams::Keyword(keyword_index_params) => (
+                    api::grpc::qdrant::FieldType::Keyword as i32,
+                    Some(keyword_index_params.into()),
+                ),
                 PayloadSchemaParams::Text(text_index_params) => (
                     api::grpc::qdrant::FieldType::Text as i32,
                     Some(text_index_params.into()),
@@ -375,6 +380,123 @@ pub fn internal_delete_index(
     }
 }
 
+/// Convert an operation read from WAL into a typed change for the changes stream
+///
+/// Requests of the operations are built the same way as for forwarding them to remote shards.
+pub fn shard_change_to_grpc(
+    collection_name: String,
+    change: ShardChange,
+) -> CollectionResult<api::grpc::qdrant::ShardChange> {
+    let ShardChange {
+        operation_id,
+        operation,
+    } = change;
+
+    let operation = match operation {
+        CollectionUpdateOperations::PointOperation(point_ops) => match point_ops {
+            PointOperations::UpsertPoints(points) => {
+                internal_upsert_points(None, collection_name, points, false, None)?
+                    .upsert_points
+                    .map(Operation::Upsert)
+            }
+            PointOperations::DeletePoints { ids } => {
+                internal_delete_points(None, collection_name, ids, false, None)
+                    .delete_points
+                    .map(Operation::DeletePoints)
+            }
+            PointOperations::DeletePointsByFilter(filter) => {
+                internal_delete_points_by_filter(None, collection_name, filter, false, None)
+                    .delete_points
+                    .map(Operation::DeletePoints)
+            }
+            PointOperations::SyncPoints(sync) => {
+                internal_sync_points(None, collection_name, sync, false, None)?
+                    .sync_points
+                    .map(|sync| {
+                        Operation::Sync(shard_change::SyncPoints {
+                            points: sync.points,
+                            from_id: sync.from_id,
+                            to_id: sync.to_id,
+                        })
+                    })
+            }
+        },
+        CollectionUpdateOperations::VectorOperation(vector_ops) => match vector_ops {
+            VectorOperations::UpdateVectors(update_vectors) => {
+                internal_update_vectors(None, collection_name, update_vectors, false, None)
+                    .update_vectors
+                    .map(Operation::UpdateVectors)
+            }
+            VectorOperations::DeleteVectors(ids, vector_names) => internal_delete_vectors(
+                None,
+                collection_name,
+                ids.points,
+                vector_names,
+                false,
+                None,
+            )
+            .delete_vectors
+            .map(Operation::DeleteVectors),
+            VectorOperations::DeleteVectorsByFilter(filter, vector_names) => {
+                internal_delete_vectors_by_filter(
+                    None,
+                    collection_name,
+                    filter,
+                    vector_names,
+                    false,
+                    None,
+                )
+                .delete_vectors
+                .map(Operation::DeleteVectors)
+            }
+        },
+        CollectionUpdateOperations::PayloadOperation(payload_ops) => match payload_ops {
+            PayloadOps::SetPayload(set_payload) => {
+                internal_set_payload(None, collection_name, set_payload, false, None)
+                    .set_payload_points
+                    .map(Operation::SetPayload)
+            }
+            PayloadOps::OverwritePayload(set_payload) => {
+                internal_set_payload(None, collection_name, set_payload, false, None)
+                    .set_payload_points
+                    .map(Operation::OverwritePayload)
+            }
+            PayloadOps::DeletePayload(delete_payload) => {
+                internal_delete_payload(None, collection_name, delete_payload, false, None)
+                    .delete_payload_points
+                    .map(Operation::DeletePayload)
+            }
+            PayloadOps::ClearPayload { points } => {
+                internal_clear_payload(None, collection_name, points, false, None)
+                    .clear_payload_points
+                    .map(Operation::ClearPayload)
+            }
+            PayloadOps::ClearPayloadByFilter(filter) => {
+                internal_clear_payload_by_filter(None, collection_name, filter, false, None)
+                    .clear_payload_points
+                    .map(Operation::ClearPayload)
+            }
+        },
+        CollectionUpdateOperations::FieldIndexOperation(field_index_op) => match field_index_op {
+            FieldIndexOperations::CreateIndex(create_index) => {
+                internal_create_index(None, collection_name, create_index, false, None)
+                    .create_field_index_collection
+                    .map(Operation::CreateFieldIndex)
+            }
+            FieldIndexOperations::DeleteIndex(field_name) => {
+                internal_delete_index(None, collection_name, field_name, false, None)
+                    .delete_field_index_collection
+                    .map(Operation::DeleteFieldIndex)
+            }
+        },
+    };
+
+    Ok(api::grpc::qdrant::ShardChange {
+        operation_id,
+        operation,
+    })
+}
+
 pub fn try_scored_point_from_grpc(
     point: api::grpc::qdrant::ScoredPoint,
     with_payload: bool,
@@ -403,5 +525,6 @@ pub fn try_scored_point_from_grpc(
         payload,
         vector,
         shard_key: convert_shard_key_from_grpc_opt(point.shard_key),
+        geo_distance: point.geo_distance,
     })
 }
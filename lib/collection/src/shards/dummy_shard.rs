@@ -3,14 +3,18 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use async_trait::async_trait;
+use segment::data_types::aggregation::NumericAggregation;
+use segment::data_types::distinct::DistinctCounter;
+use segment::data_types::text_index::TextStats;
 use segment::types::{
-    ExtendedPointId, Filter, ScoredPoint, WithPayload, WithPayloadInterface, WithVector,
+    ExtendedPointId, Filter, OrderBy, ScoredPoint, WithPayload, WithPayloadInterface, WithVector,
 };
 use tokio::runtime::Handle;
 
 use crate::operations::types::{
-    CollectionError, CollectionInfo, CollectionResult, CoreSearchRequestBatch,
-    CountRequestInternal, CountResult, PointRequestInternal, Record, UpdateResult,
+    AggregateRequestInternal, CollectionError, CollectionInfo, CollectionResult,
+    CoreSearchRequestBatch, CountRequestInternal, CountResult, PointRequestInternal, Record,
+    TextSearchRequestInternal, UpdateResult,
 };
 use crate::operations::CollectionUpdateOperations;
 use crate::shards::shard_trait::ShardOperation;
@@ -72,6 +76,7 @@ impl ShardOperation for DummyShard {
         _: &WithPayloadInterface,
         _: &WithVector,
         _: Option<&Filter>,
+        _: Option<&OrderBy>,
         _: &Handle,
     ) -> CollectionResult<Vec<Record>> {
         self.dummy()
@@ -94,6 +99,38 @@ impl ShardOperation for DummyShard {
         self.dummy()
     }
 
+    async fn aggregate(
+        &self,
+        _: Arc<AggregateRequestInternal>,
+        _: &Handle,
+    ) -> CollectionResult<NumericAggregation> {
+        self.dummy()
+    }
+
+    async fn count_distinct(
+        &self,
+        _: Arc<CountRequestInternal>,
+        _: &Handle,
+    ) -> CollectionResult<DistinctCounter> {
+        self.dummy()
+    }
+
+    async fn text_stats(
+        &self,
+        _: Arc<TextSearchRequestInternal>,
+        _: &Handle,
+    ) -> CollectionResult<TextStats> {
+        self.dummy()
+    }
+
+    async fn search_text(
+        &self,
+        _: Arc<TextSearchRequestInternal>,
+        _: &Handle,
+    ) -> CollectionResult<Vec<ScoredPoint>> {
+        self.dummy()
+    }
+
     async fn retrieve(
         &self,
         _: Arc<PointRequestInternal>,
@@ -17,7 +17,7 @@ use segment::segment::Segment;
 use segment::segment_constructor::{build_segment, load_segment};
 use segment::types::{
     CompressionRatio, Filter, PayloadIndexInfo, PayloadKeyType, PayloadStorageType, PointIdType,
-    QuantizationConfig, SegmentConfig, SegmentType,
+    QuantizationConfig, SegmentConfig, SegmentType, SeqNumberType,
 };
 use segment::utils::mem::Mem;
 use tokio::fs::{copy, create_dir_all, remove_dir_all};
@@ -31,7 +31,7 @@ use crate::collection_manager::collection_updater::CollectionUpdater;
 use crate::collection_manager::holders::segment_holder::{LockedSegment, SegmentHolder};
 use crate::collection_manager::optimizers::TrackerLog;
 use crate::common::file_utils::move_dir;
-use crate::config::CollectionConfig;
+use crate::config::{CollectionConfig, CollectionParams};
 use crate::operations::shared_storage_config::SharedStorageConfig;
 use crate::operations::types::{
     check_sparse_compatible_with_segment_config, CollectionError, CollectionInfoInternal,
@@ -48,6 +48,15 @@ use crate::wal::SerdeWal;
 
 pub type LockedWal = Arc<ParkingMutex<SerdeWal<CollectionUpdateOperations>>>;
 
+/// Max number of operations to read from WAL while holding its lock
+const READ_WAL_MAX_CHUNK: usize = 256;
+
+/// Number of the next operation to read, and operations read from WAL
+pub type WalOperations = (
+    SeqNumberType,
+    Vec<(SeqNumberType, CollectionUpdateOperations)>,
+);
+
 /// LocalShard
 ///
 /// LocalShard is an entity that can be moved between peers and contains some part of one collections data.
@@ -220,10 +229,13 @@ impl LocalShard {
                 ))
             })??;
 
-            let Some(segment) = segment else {
+            let Some(mut segment) = segment else {
                 continue;
             };
 
+            // Vectors might have been added or removed, while the segment was stored
+            Self::sync_segment_vectors(&mut segment, &collection_config_read.params)?;
+
             collection_config_read
                 .params
                 .vectors
@@ -271,7 +283,7 @@ impl LocalShard {
         )
         .await;
 
-        collection.load_from_wal(collection_id)?;
+        collection.load_from_wal(collection_id).await?;
 
         let available_memory_bytes = Mem::new().available_memory_bytes() as usize;
         let vectors_size_bytes = collection.estimate_vector_data_size().await;
@@ -440,7 +452,8 @@ impl LocalShard {
     }
 
     /// Loads latest collection operations from WAL
-    pub fn load_from_wal(&self, collection_id: CollectionId) -> CollectionResult<()> {
+    pub async fn load_from_wal(&self, collection_id: CollectionId) -> CollectionResult<()> {
+        let collection_params = self.collection_config.read().await.params.clone();
         let wal = self.wal.lock();
         let bar = ProgressBar::new(wal.len());
 
@@ -465,7 +478,13 @@ impl LocalShard {
         // (`SerdeWal::read_all` may even start reading WAL from some already truncated
         // index *occasionally*), but the storage can handle it.
 
-        for (op_num, update) in wal.read_all() {
+        // Operations before the first one in WAL have already been applied
+        let mut last_applied = wal.first_index().checked_sub(1);
+
+        for (op_num, mut update) in wal.read_all() {
+            last_applied = Some(op_num);
+            // Vectors might have been removed from the collection after the operation was written
+            update.retain_vectors(|vector_name| collection_params.contains_vector(vector_name));
             // Propagate `CollectionError::ServiceError`, but skip other error types.
             match &CollectionUpdater::update(segments, op_num, update) {
                 Err(err @ CollectionError::ServiceError { error, backtrace }) => {
@@ -498,6 +517,10 @@ impl LocalShard {
         self.segments.read().flush_all(true)?;
         bar.finish();
 
+        if let Some(last_applied) = last_applied {
+            self.update_tracker.set_applied_operation(last_applied);
+        }
+
         Ok(())
     }
 
@@ -513,6 +536,11 @@ impl LocalShard {
         update_handler.stop_flush_worker();
 
         update_handler.wait_workers_stops().await?;
+
+        for (_, segment) in self.segments.read().iter() {
+            Self::sync_locked_segment_vectors(segment, &config.params)?;
+        }
+
         let new_optimizers = build_optimizers(
             &self.path,
             &config.params,
@@ -528,6 +556,66 @@ impl LocalShard {
         Ok(())
     }
 
+    /// Make vectors of the locked segment match vectors of the collection.
+    ///
+    /// Proxy segment forwards all writes to its write segment, so the change is applied there.
+    /// Wrapped segment is changed as well, so reads of the proxy see the same vectors.
+    fn sync_locked_segment_vectors(
+        segment: &LockedSegment,
+        params: &CollectionParams,
+    ) -> CollectionResult<()> {
+        match segment {
+            LockedSegment::Original(segment) => {
+                Self::sync_segment_vectors(&mut segment.write(), params)
+            }
+            LockedSegment::Proxy(proxy) => {
+                let mut proxy = proxy.write();
+                Self::sync_locked_segment_vectors(&proxy.write_segment, params)?;
+                Self::sync_locked_segment_vectors(&proxy.wrapped_segment, params)?;
+                proxy.refresh_wrapped_config();
+                Ok(())
+            }
+        }
+    }
+
+    /// Make vectors of the segment match vectors of the collection.
+    ///
+    /// Vectors, which are missing in the segment, are added without values for existing points.
+    /// Vectors, which were removed from the collection or re-created with different parameters,
+    /// are removed from the segment.
+    fn sync_segment_vectors(
+        segment: &mut Segment,
+        params: &CollectionParams,
+    ) -> CollectionResult<()> {
+        let vector_data = params.into_base_vector_data()?;
+        let sparse_vector_data = params.into_sparse_vector_data()?;
+        let segment_config = segment.config().clone();
+
+        for (vector_name, segment_vector_config) in &segment_config.vector_data {
+            let is_compatible = vector_data.get(vector_name).is_some_and(|vector_config| {
+                vector_config.size == segment_vector_config.size
+                    && vector_config.distance == segment_vector_config.distance
+            });
+            if !is_compatible {
+                segment.remove_vector(vector_name)?;
+            }
+        }
+        for vector_name in segment_config.sparse_vector_data.keys() {
+            if !sparse_vector_data.contains_key(vector_name) {
+                segment.remove_vector(vector_name)?;
+            }
+        }
+
+        for (vector_name, vector_config) in vector_data {
+            segment.create_vector(&vector_name, vector_config)?;
+        }
+        for (vector_name, sparse_vector_config) in sparse_vector_data {
+            segment.create_sparse_vector(&vector_name, sparse_vector_config)?;
+        }
+
+        Ok(())
+    }
+
     /// Finishes ongoing update tasks
     pub async fn stop_gracefully(&self) {
         if let Err(err) = self.update_sender.load().send(UpdateSignal::Stop).await {
@@ -745,6 +833,11 @@ impl LocalShard {
             .map(|(_, value)| {
                 let vector_size = value.size.get() as usize;
 
+                if value.distance.is_binary() {
+                    // Binary vectors are stored bit-packed and are never quantized
+                    return vector_size / u8::BITS as usize;
+                }
+
                 let quantization_config = value
                     .quantization_config
                     .as_ref()
@@ -821,6 +914,74 @@ impl LocalShard {
     pub fn update_tracker(&self) -> &UpdateTracker {
         &self.update_tracker
     }
+
+    /// Find the first field of the filter, which conditions the payload index can't serve
+    ///
+    /// Payload indexes are created on all segments of the shard, so any segment is asked.
+    pub fn unindexed_filter_key(&self, filter: &Filter) -> Option<PayloadKeyType> {
+        self.segments
+            .read()
+            .iter()
+            .next()
+            .and_then(|(_, segment)| segment.get().read().unindexed_filter_key(filter))
+    }
+
+    /// Read up to `limit` applied operations from the WAL, starting from `from`
+    ///
+    /// Starts from the oldest retained operation if `from` is not specified. Returns the number of
+    /// the operation to continue reading from, along with the read operations.
+    ///
+    /// Reads at most [`READ_WAL_MAX_CHUNK`] operations at once on a blocking thread, so updates
+    /// of the shard are not blocked for long.
+    ///
+    /// Fails if operation `from` is already truncated from the WAL.
+    pub async fn read_wal(
+        &self,
+        from: Option<SeqNumberType>,
+        limit: usize,
+    ) -> CollectionResult<WalOperations> {
+        let applied_operation = self.update_tracker.applied_operation();
+        let limit = limit.min(READ_WAL_MAX_CHUNK);
+        let wal = self.wal.clone();
+
+        tokio::task::spawn_blocking(move || {
+            Self::read_wal_chunk(&wal, from, limit, applied_operation)
+        })
+        .await?
+    }
+
+    fn read_wal_chunk(
+        wal: &LockedWal,
+        from: Option<SeqNumberType>,
+        limit: usize,
+        applied_operation: Option<SeqNumberType>,
+    ) -> CollectionResult<WalOperations> {
+        let wal = wal.lock();
+
+        let first_index = wal.first_index();
+        let from = from.unwrap_or(first_index);
+
+        if from < first_index {
+            return Err(CollectionError::bad_request(format!(
+                "Operation {from} is not retained in WAL anymore, \
+                 the oldest retained operation is {first_index}",
+            )));
+        }
+
+        let Some(applied_operation) = applied_operation else {
+            return Ok((from, Vec::new()));
+        };
+
+        let operations: Vec<_> = wal
+            .read(from)
+            .take_while(|(op_num, _)| *op_num <= applied_operation)
+            .take(limit)
+            .collect();
+
+        let next = operations.last().map_or(from, |(op_num, _)| op_num + 1);
+
+        Ok((next, operations))
+    }
 }
 
 impl Drop for LocalShard {
@@ -1,11 +1,16 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 
 use async_trait::async_trait;
 use futures::future::try_join_all;
 use itertools::Itertools;
+use ordered_float::OrderedFloat;
+use segment::data_types::aggregation::NumericAggregation;
+use segment::data_types::distinct::DistinctCounter;
+use segment::data_types::text_index::TextStats;
 use segment::types::{
-    ExtendedPointId, Filter, ScoredPoint, WithPayload, WithPayloadInterface, WithVector,
+    ExtendedPointId, Filter, OrderBy, ScoredPoint, WithPayload, WithPayloadInterface, WithVector,
 };
 use tokio::runtime::Handle;
 use tokio::sync::oneshot;
@@ -13,9 +18,9 @@ use tokio::sync::oneshot;
 use crate::collection_manager::segments_searcher::SegmentsSearcher;
 use crate::common::stopping_guard::StoppingGuard;
 use crate::operations::types::{
-    CollectionError, CollectionInfo, CollectionResult, CoreSearchRequestBatch,
-    CountRequestInternal, CountResult, PointRequestInternal, QueryEnum, Record, UpdateResult,
-    UpdateStatus,
+    AggregateRequestInternal, CollectionError, CollectionInfo, CollectionResult,
+    CoreSearchRequestBatch, CountRequestInternal, CountResult, PointRequestInternal, QueryEnum,
+    Record, TextSearchRequestInternal, UpdateResult, UpdateStatus,
 };
 use crate::operations::CollectionUpdateOperations;
 use crate::optimizers_builder::DEFAULT_INDEXING_THRESHOLD_KB;
@@ -82,7 +87,8 @@ impl LocalShard {
                         // Don't post-process if we are dealing with custom scoring
                         QueryEnum::RecommendBestScore(_)
                         | QueryEnum::Discover(_)
-                        | QueryEnum::Context(_) => {}
+                        | QueryEnum::Context(_)
+                        | QueryEnum::Feedback(_) => {}
                     };
                     scored_point
                 });
@@ -100,6 +106,72 @@ impl LocalShard {
             .collect();
         Ok(top_results)
     }
+
+    /// Read up to `limit` points ordered by `order_by` value, which is attached to the records
+    ///
+    /// Reading starts from the `order_by` start value, ties at it are skipped up to `offset`.
+    async fn scroll_ordered(
+        &self,
+        offset: Option<ExtendedPointId>,
+        limit: usize,
+        with_payload_interface: &WithPayloadInterface,
+        with_vector: &WithVector,
+        filter: Option<&Filter>,
+        order_by: &OrderBy,
+        search_runtime_handle: &Handle,
+    ) -> CollectionResult<Vec<Record>> {
+        let segments = self.segments();
+        let read_handles: Vec<_> = {
+            let segments_guard = segments.read();
+            segments_guard
+                .iter()
+                .map(|(_, segment)| {
+                    let segment = segment.clone();
+                    let filter = filter.cloned();
+                    let order_by = order_by.clone();
+                    search_runtime_handle.spawn_blocking(move || {
+                        segment.get().read().read_ordered_filtered(
+                            offset,
+                            limit,
+                            filter.as_ref(),
+                            &order_by,
+                        )
+                    })
+                })
+                .collect()
+        };
+        let all_points = try_join_all(read_handles).await?;
+
+        let mut ordered_points = vec![];
+        for segment_points in all_points {
+            ordered_points.extend(segment_points?);
+        }
+        let ordered_points = ordered_points
+            .into_iter()
+            .sorted_by_key(|(value, point_id)| (OrderedFloat(*value), *point_id))
+            .unique_by(|(_, point_id)| *point_id)
+            .take(limit)
+            .collect_vec();
+
+        let point_ids = ordered_points.iter().map(|(_, id)| *id).collect_vec();
+        let with_payload = WithPayload::from(with_payload_interface);
+        let mut records: HashMap<_, _> =
+            SegmentsSearcher::retrieve(segments, &point_ids, &with_payload, with_vector)?
+                .into_iter()
+                .map(|record| (record.id, record))
+                .collect();
+
+        let points = ordered_points
+            .into_iter()
+            .filter_map(|(value, point_id)| {
+                let mut record = records.remove(&point_id)?;
+                record.geo_distance = Some(value);
+                Some(record)
+            })
+            .collect();
+
+        Ok(points)
+    }
 }
 #[async_trait]
 impl ShardOperation for LocalShard {
@@ -137,11 +209,13 @@ impl ShardOperation for LocalShard {
             Ok(UpdateResult {
                 operation_id: Some(operation_id),
                 status: UpdateStatus::Completed,
+                consistency_token: None,
             })
         } else {
             Ok(UpdateResult {
                 operation_id: Some(operation_id),
                 status: UpdateStatus::Acknowledged,
+                consistency_token: None,
             })
         }
     }
@@ -153,8 +227,23 @@ impl ShardOperation for LocalShard {
         with_payload_interface: &WithPayloadInterface,
         with_vector: &WithVector,
         filter: Option<&Filter>,
+        order_by: Option<&OrderBy>,
         search_runtime_handle: &Handle,
     ) -> CollectionResult<Vec<Record>> {
+        if let Some(order_by) = order_by {
+            return self
+                .scroll_ordered(
+                    offset,
+                    limit,
+                    with_payload_interface,
+                    with_vector,
+                    filter,
+                    order_by,
+                    search_runtime_handle,
+                )
+                .await;
+        }
+
         // ToDo: Make faster points selection with a set
         let segments = self.segments();
         let read_handles: Vec<_> = {
@@ -216,6 +305,172 @@ impl ShardOperation for LocalShard {
         Ok(CountResult { count: total_count })
     }
 
+    async fn aggregate(
+        &self,
+        request: Arc<AggregateRequestInternal>,
+        search_runtime_handle: &Handle,
+    ) -> CollectionResult<NumericAggregation> {
+        let read_handles: Vec<_> = {
+            let segments_guard = self.segments().read();
+            segments_guard
+                .iter()
+                .map(|(_, segment)| {
+                    let segment = segment.clone();
+                    let request = request.clone();
+                    search_runtime_handle.spawn_blocking(move || {
+                        segment.get().read().aggregate_filtered(
+                            request.filter.as_ref(),
+                            &request.key,
+                            request.histogram.as_ref(),
+                        )
+                    })
+                })
+                .collect()
+        };
+
+        let mut aggregation = NumericAggregation::default();
+        for segment_aggregation in try_join_all(read_handles).await? {
+            aggregation.merge(segment_aggregation?);
+        }
+        Ok(aggregation)
+    }
+
+    async fn count_distinct(
+        &self,
+        request: Arc<CountRequestInternal>,
+        search_runtime_handle: &Handle,
+    ) -> CollectionResult<DistinctCounter> {
+        let Some(key) = request.distinct.clone() else {
+            return Err(CollectionError::bad_request(
+                "Payload key for distinct count is not specified".to_string(),
+            ));
+        };
+
+        let read_handles: Vec<_> = {
+            let segments_guard = self.segments().read();
+            segments_guard
+                .iter()
+                .map(|(_, segment)| {
+                    let segment = segment.clone();
+                    let request = request.clone();
+                    let key = key.clone();
+                    search_runtime_handle.spawn_blocking(move || {
+                        segment.get().read().distinct_filtered(
+                            request.filter.as_ref(),
+                            &key,
+                            request.exact,
+                        )
+                    })
+                })
+                .collect()
+        };
+
+        let mut counter = DistinctCounter::default();
+        for segment_counter in try_join_all(read_handles).await? {
+            counter
+                .merge(segment_counter?, request.exact)
+                .map_err(CollectionError::bad_request)?;
+        }
+        Ok(counter)
+    }
+
+    async fn text_stats(
+        &self,
+        request: Arc<TextSearchRequestInternal>,
+        search_runtime_handle: &Handle,
+    ) -> CollectionResult<TextStats> {
+        let stats_handles: Vec<_> = {
+            let segments_guard = self.segments().read();
+            segments_guard
+                .iter()
+                .map(|(_, segment)| {
+                    let segment = segment.clone();
+                    let request = request.clone();
+                    search_runtime_handle.spawn_blocking(move || {
+                        segment
+                            .get()
+                            .read()
+                            .text_stats(&request.key, &request.query)
+                    })
+                })
+                .collect()
+        };
+
+        let mut stats = TextStats::default();
+        for segment_stats in try_join_all(stats_handles).await? {
+            stats.merge(segment_stats?);
+        }
+        Ok(stats)
+    }
+
+    async fn search_text(
+        &self,
+        request: Arc<TextSearchRequestInternal>,
+        search_runtime_handle: &Handle,
+    ) -> CollectionResult<Vec<ScoredPoint>> {
+        // Statistics of all segments are merged first, so that scores of different segments are comparable
+        let stats = match &request.stats {
+            Some(stats) => stats.clone(),
+            None => {
+                self.text_stats(request.clone(), search_runtime_handle)
+                    .await?
+            }
+        };
+        let stats = Arc::new(stats);
+
+        let segments: Vec<_> = self
+            .segments()
+            .read()
+            .iter()
+            .map(|(_, segment)| segment.clone())
+            .collect();
+
+        let top = request.limit + request.offset.unwrap_or_default();
+        let with_payload = Arc::new(WithPayload::from(
+            request
+                .with_payload
+                .as_ref()
+                .unwrap_or(&WithPayloadInterface::Bool(false)),
+        ));
+        let with_vector = Arc::new(request.with_vector.clone().unwrap_or_default());
+
+        let search_handles = segments.into_iter().map(|segment| {
+            let request = request.clone();
+            let stats = stats.clone();
+            let with_payload = with_payload.clone();
+            let with_vector = with_vector.clone();
+            search_runtime_handle.spawn_blocking(move || {
+                segment.get().read().search_text(
+                    &request.key,
+                    &request.query,
+                    &stats,
+                    request.filter.as_ref(),
+                    top,
+                    &with_payload,
+                    &with_vector,
+                )
+            })
+        });
+
+        let mut points = vec![];
+        for segment_points in try_join_all(search_handles).await? {
+            points.extend(segment_points?);
+        }
+        let points = points
+            .into_iter()
+            .filter(|point| {
+                request
+                    .score_threshold
+                    .map_or(true, |threshold| point.score >= threshold)
+            })
+            .sorted_by(|a, b| b.cmp(a))
+            // Point might be present in multiple segments during optimization
+            .unique_by(|point| point.id)
+            .take(top)
+            .collect();
+        Ok(points)
+    }
+
     async fn retrieve(
         &self,
         request: Arc<PointRequestInternal>,
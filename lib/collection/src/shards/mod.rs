@@ -1,10 +1,11 @@
 pub mod channel_service;
 pub mod collection_shard_distribution;
-mod conversions;
+pub mod conversions;
 pub mod dummy_shard;
 pub mod forward_proxy_shard;
 pub mod local_shard;
 pub mod local_shard_operations;
+pub mod peer_labels;
 pub mod proxy_shard;
 pub mod queue_proxy_shard;
 pub mod remote_shard;
@@ -0,0 +1,153 @@
+use std::collections::HashMap;
+
+use schemars::JsonSchema;
+use segment::common::anonymize::Anonymize;
+use serde::{Deserialize, Serialize};
+
+use crate::shards::shard::PeerId;
+
+pub type PeerLabelsById = HashMap<PeerId, PeerLabels>;
+
+/// Location of a peer, used to spread replicas of a shard across failure domains
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Default, Clone, PartialEq, Eq, Hash)]
+pub struct PeerLabels {
+    /// Availability zone the peer is located in
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub zone: Option<String>,
+    /// Rack the peer is located in, within its zone
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rack: Option<String>,
+}
+
+impl PeerLabels {
+    pub fn is_empty(&self) -> bool {
+        self.zone.is_none() && self.rack.is_none()
+    }
+
+    /// Whether both peers are known to be located in the same zone
+    pub fn same_zone(&self, other: &Self) -> bool {
+        matches!((&self.zone, &other.zone), (Some(zone), Some(other_zone)) if zone == other_zone)
+    }
+
+    /// Whether both peers are known to be located in the same rack
+    ///
+    /// Racks are only compared within the same zone, or if no zones are specified.
+    pub fn same_rack(&self, other: &Self) -> bool {
+        self.zone == other.zone
+            && matches!((&self.rack, &other.rack), (Some(rack), Some(other_rack)) if rack == other_rack)
+    }
+
+    /// Whether an outage of a single zone or rack can take down both peers
+    pub fn shares_failure_domain(&self, other: &Self) -> bool {
+        self.same_zone(other) || self.same_rack(other)
+    }
+}
+
+impl Anonymize for PeerLabels {
+    fn anonymize(&self) -> Self {
+        PeerLabels {
+            zone: self.zone.anonymize(),
+            rack: self.rack.anonymize(),
+        }
+    }
+}
+
+/// Count how many of `other_peers` are located in the same zone and in the same rack as `peer_id`
+///
+/// Peers without labels never share a failure domain.
+pub fn count_shared_failure_domains<'a>(
+    labels_by_id: &PeerLabelsById,
+    peer_id: PeerId,
+    other_peers: impl IntoIterator<Item = &'a PeerId>,
+) -> (usize, usize) {
+    let Some(labels) = labels_by_id.get(&peer_id) else {
+        return (0, 0);
+    };
+
+    other_peers
+        .into_iter()
+        .filter(|other_peer_id| **other_peer_id != peer_id)
+        .filter_map(|other_peer_id| labels_by_id.get(other_peer_id))
+        .fold((0, 0), |(zones, racks), other| {
+            (
+                zones + labels.same_zone(other) as usize,
+                racks + labels.same_rack(other) as usize,
+            )
+        })
+}
+
+/// Find groups of peers which hold replicas of the same shard within a single failure domain
+pub fn find_shared_failure_domains(
+    labels_by_id: &PeerLabelsById,
+    peers: &[PeerId],
+) -> Vec<Vec<PeerId>> {
+    let mut groups: Vec<Vec<PeerId>> = Vec::new();
+
+    for (i, peer_id) in peers.iter().enumerate() {
+        if groups.iter().any(|group| group.contains(peer_id)) {
+            continue;
+        }
+        let Some(labels) = labels_by_id.get(peer_id) else {
+            continue;
+        };
+
+        let mut group = vec![*peer_id];
+        group.extend(peers[i + 1..].iter().filter(|other_peer_id| {
+            labels_by_id
+                .get(other_peer_id)
+                .map_or(false, |other| labels.shares_failure_domain(other))
+        }));
+
+        if group.len() > 1 {
+            groups.push(group);
+        }
+    }
+
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(zone: Option<&str>, rack: Option<&str>) -> PeerLabels {
+        PeerLabels {
+            zone: zone.map(str::to_string),
+            rack: rack.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_shares_failure_domain() {
+        let a1 = labels(Some("a"), Some("1"));
+        let a2 = labels(Some("a"), Some("2"));
+        let b1 = labels(Some("b"), Some("1"));
+        let rack1 = labels(None, Some("1"));
+
+        assert!(a1.shares_failure_domain(&a2));
+        assert!(!a1.shares_failure_domain(&b1));
+        assert!(!a1.shares_failure_domain(&rack1));
+        assert!(rack1.shares_failure_domain(&rack1.clone()));
+        assert!(!PeerLabels::default().shares_failure_domain(&PeerLabels::default()));
+    }
+
+    #[test]
+    fn test_find_shared_failure_domains() {
+        let labels_by_id = PeerLabelsById::from([
+            (1, labels(Some("a"), None)),
+            (2, labels(Some("b"), None)),
+            (3, labels(Some("a"), None)),
+            (4, PeerLabels::default()),
+        ]);
+
+        assert_eq!(
+            find_shared_failure_domains(&labels_by_id, &[1, 2, 3, 4]),
+            vec![vec![1, 3]],
+        );
+        assert!(find_shared_failure_domains(&labels_by_id, &[1, 2, 4]).is_empty());
+        assert_eq!(
+            count_shared_failure_domains(&labels_by_id, 3, &[1, 2, 3]),
+            (1, 0)
+        );
+    }
+}
@@ -5,9 +5,12 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use async_trait::async_trait;
+use segment::data_types::aggregation::NumericAggregation;
+use segment::data_types::distinct::DistinctCounter;
+use segment::data_types::text_index::TextStats;
 use segment::types::{
-    ExtendedPointId, Filter, PointIdType, ScoredPoint, WithPayload, WithPayloadInterface,
-    WithVector,
+    ExtendedPointId, Filter, OrderBy, PayloadKeyType, PointIdType, ScoredPoint, SeqNumberType,
+    WithPayload, WithPayloadInterface, WithVector,
 };
 use tokio::runtime::Handle;
 use tokio::sync::{oneshot, RwLock};
@@ -18,11 +21,12 @@ use crate::operations::operation_effect::{
     EstimateOperationEffectArea, OperationEffectArea, PointsOperationEffect,
 };
 use crate::operations::types::{
-    CollectionError, CollectionInfo, CollectionResult, CoreSearchRequestBatch,
-    CountRequestInternal, CountResult, PointRequestInternal, Record, UpdateResult,
+    AggregateRequestInternal, CollectionError, CollectionInfo, CollectionResult,
+    CoreSearchRequestBatch, CountRequestInternal, CountResult, PointRequestInternal, Record,
+    TextSearchRequestInternal, UpdateResult,
 };
 use crate::operations::CollectionUpdateOperations;
-use crate::shards::local_shard::LocalShard;
+use crate::shards::local_shard::{LocalShard, WalOperations};
 use crate::shards::shard_trait::ShardOperation;
 use crate::shards::telemetry::LocalShardTelemetry;
 use crate::update_handler::UpdateSignal;
@@ -124,6 +128,18 @@ impl ProxyShard {
     pub fn update_tracker(&self) -> &UpdateTracker {
         self.wrapped_shard.update_tracker()
     }
+
+    pub fn unindexed_filter_key(&self, filter: &Filter) -> Option<PayloadKeyType> {
+        self.wrapped_shard.unindexed_filter_key(filter)
+    }
+
+    pub async fn read_wal(
+        &self,
+        from: Option<SeqNumberType>,
+        limit: usize,
+    ) -> CollectionResult<WalOperations> {
+        self.wrapped_shard.read_wal(from, limit).await
+    }
 }
 
 #[async_trait]
@@ -180,6 +196,7 @@ impl ShardOperation for ProxyShard {
         with_payload_interface: &WithPayloadInterface,
         with_vector: &WithVector,
         filter: Option<&Filter>,
+        order_by: Option<&OrderBy>,
         search_runtime_handle: &Handle,
     ) -> CollectionResult<Vec<Record>> {
         let local_shard = &self.wrapped_shard;
@@ -190,6 +207,7 @@ impl ShardOperation for ProxyShard {
                 with_payload_interface,
                 with_vector,
                 filter,
+                order_by,
                 search_runtime_handle,
             )
             .await
@@ -220,6 +238,50 @@ impl ShardOperation for ProxyShard {
         local_shard.count(request).await
     }
 
+    /// Forward read-only `aggregate` to `wrapped_shard`
+    async fn aggregate(
+        &self,
+        request: Arc<AggregateRequestInternal>,
+        search_runtime_handle: &Handle,
+    ) -> CollectionResult<NumericAggregation> {
+        let local_shard = &self.wrapped_shard;
+        local_shard.aggregate(request, search_runtime_handle).await
+    }
+
+    /// Forward read-only `count_distinct` to `wrapped_shard`
+    async fn count_distinct(
+        &self,
+        request: Arc<CountRequestInternal>,
+        search_runtime_handle: &Handle,
+    ) -> CollectionResult<DistinctCounter> {
+        let local_shard = &self.wrapped_shard;
+        local_shard
+            .count_distinct(request, search_runtime_handle)
+            .await
+    }
+
+    /// Forward read-only `text_stats` to `wrapped_shard`
+    async fn text_stats(
+        &self,
+        request: Arc<TextSearchRequestInternal>,
+        search_runtime_handle: &Handle,
+    ) -> CollectionResult<TextStats> {
+        let local_shard = &self.wrapped_shard;
+        local_shard.text_stats(request, search_runtime_handle).await
+    }
+
+    /// Forward read-only `search_text` to `wrapped_shard`
+    async fn search_text(
+        &self,
+        request: Arc<TextSearchRequestInternal>,
+        search_runtime_handle: &Handle,
+    ) -> CollectionResult<Vec<ScoredPoint>> {
+        let local_shard = &self.wrapped_shard;
+        local_shard
+            .search_text(request, search_runtime_handle)
+            .await
+    }
+
     /// Forward read-only `retrieve` to `wrapped_shard`
     async fn retrieve(
         &self,
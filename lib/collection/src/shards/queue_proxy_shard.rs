@@ -4,8 +4,12 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use async_trait::async_trait;
+use segment::data_types::aggregation::NumericAggregation;
+use segment::data_types::distinct::DistinctCounter;
+use segment::data_types::text_index::TextStats;
 use segment::types::{
-    ExtendedPointId, Filter, ScoredPoint, WithPayload, WithPayloadInterface, WithVector,
+    ExtendedPointId, Filter, OrderBy, PayloadKeyType, ScoredPoint, SeqNumberType, WithPayload,
+    WithPayloadInterface, WithVector,
 };
 use tokio::runtime::Handle;
 use tokio::sync::Mutex;
@@ -15,11 +19,12 @@ use super::transfer::driver::MAX_RETRY_COUNT;
 use super::update_tracker::UpdateTracker;
 use crate::operations::point_ops::WriteOrdering;
 use crate::operations::types::{
-    CollectionInfo, CollectionResult, CoreSearchRequestBatch, CountRequestInternal, CountResult,
-    PointRequestInternal, Record, UpdateResult,
+    AggregateRequestInternal, CollectionInfo, CollectionResult, CoreSearchRequestBatch,
+    CountRequestInternal, CountResult, PointRequestInternal, Record, TextSearchRequestInternal,
+    UpdateResult,
 };
 use crate::operations::CollectionUpdateOperations;
-use crate::shards::local_shard::LocalShard;
+use crate::shards::local_shard::{LocalShard, WalOperations};
 use crate::shards::shard_trait::ShardOperation;
 use crate::shards::telemetry::LocalShardTelemetry;
 
@@ -119,6 +124,27 @@ impl QueueProxyShard {
             .update_tracker()
     }
 
+    pub fn unindexed_filter_key(&self, filter: &Filter) -> Option<PayloadKeyType> {
+        self.inner
+            .as_ref()
+            .expect("Queue proxy has been finalized")
+            .wrapped_shard
+            .unindexed_filter_key(filter)
+    }
+
+    pub async fn read_wal(
+        &self,
+        from: Option<SeqNumberType>,
+        limit: usize,
+    ) -> CollectionResult<WalOperations> {
+        self.inner
+            .as_ref()
+            .expect("Queue proxy has been finalized")
+            .wrapped_shard
+            .read_wal(from, limit)
+            .await
+    }
+
     /// Check if the queue proxy shard is already finalized
     #[cfg(debug_assertions)]
     fn is_finalized(&self) -> bool {
@@ -172,6 +198,7 @@ impl ShardOperation for QueueProxyShard {
         with_payload_interface: &WithPayloadInterface,
         with_vector: &WithVector,
         filter: Option<&Filter>,
+        order_by: Option<&OrderBy>,
         search_runtime_handle: &Handle,
     ) -> CollectionResult<Vec<Record>> {
         self.inner
@@ -183,6 +210,7 @@ impl ShardOperation for QueueProxyShard {
                 with_payload_interface,
                 with_vector,
                 filter,
+                order_by,
                 search_runtime_handle,
             )
             .await
@@ -218,6 +246,58 @@ impl ShardOperation for QueueProxyShard {
             .await
     }
 
+    /// Forward read-only `aggregate` to `wrapped_shard`
+    async fn aggregate(
+        &self,
+        request: Arc<AggregateRequestInternal>,
+        search_runtime_handle: &Handle,
+    ) -> CollectionResult<NumericAggregation> {
+        self.inner
+            .as_ref()
+            .expect("Queue proxy has been finalized")
+            .aggregate(request, search_runtime_handle)
+            .await
+    }
+
+    /// Forward read-only `count_distinct` to `wrapped_shard`
+    async fn count_distinct(
+        &self,
+        request: Arc<CountRequestInternal>,
+        search_runtime_handle: &Handle,
+    ) -> CollectionResult<DistinctCounter> {
+        self.inner
+            .as_ref()
+            .expect("Queue proxy has been finalized")
+            .count_distinct(request, search_runtime_handle)
+            .await
+    }
+
+    /// Forward read-only `text_stats` to `wrapped_shard`
+    async fn text_stats(
+        &self,
+        request: Arc<TextSearchRequestInternal>,
+        search_runtime_handle: &Handle,
+    ) -> CollectionResult<TextStats> {
+        self.inner
+            .as_ref()
+            .expect("Queue proxy has been finalized")
+            .text_stats(request, search_runtime_handle)
+            .await
+    }
+
+    /// Forward read-only `search_text` to `wrapped_shard`
+    async fn search_text(
+        &self,
+        request: Arc<TextSearchRequestInternal>,
+        search_runtime_handle: &Handle,
+    ) -> CollectionResult<Vec<ScoredPoint>> {
+        self.inner
+            .as_ref()
+            .expect("Queue proxy has been finalized")
+            .search_text(request, search_runtime_handle)
+            .await
+    }
+
     /// Forward read-only `retrieve` to `wrapped_shard`
     async fn retrieve(
         &self,
@@ -401,6 +481,7 @@ impl ShardOperation for Inner {
         with_payload_interface: &WithPayloadInterface,
         with_vector: &WithVector,
         filter: Option<&Filter>,
+        order_by: Option<&OrderBy>,
         search_runtime_handle: &Handle,
     ) -> CollectionResult<Vec<Record>> {
         let local_shard = &self.wrapped_shard;
@@ -411,6 +492,7 @@ impl ShardOperation for Inner {
                 with_payload_interface,
                 with_vector,
                 filter,
+                order_by,
                 search_runtime_handle,
             )
             .await
@@ -441,6 +523,50 @@ impl ShardOperation for Inner {
         local_shard.count(request).await
     }
 
+    /// Forward read-only `aggregate` to `wrapped_shard`
+    async fn aggregate(
+        &self,
+        request: Arc<AggregateRequestInternal>,
+        search_runtime_handle: &Handle,
+    ) -> CollectionResult<NumericAggregation> {
+        let local_shard = &self.wrapped_shard;
+        local_shard.aggregate(request, search_runtime_handle).await
+    }
+
+    /// Forward read-only `count_distinct` to `wrapped_shard`
+    async fn count_distinct(
+        &self,
+        request: Arc<CountRequestInternal>,
+        search_runtime_handle: &Handle,
+    ) -> CollectionResult<DistinctCounter> {
+        let local_shard = &self.wrapped_shard;
+        local_shard
+            .count_distinct(request, search_runtime_handle)
+            .await
+    }
+
+    /// Forward read-only `text_stats` to `wrapped_shard`
+    async fn text_stats(
+        &self,
+        request: Arc<TextSearchRequestInternal>,
+        search_runtime_handle: &Handle,
+    ) -> CollectionResult<TextStats> {
+        let local_shard = &self.wrapped_shard;
+        local_shard.text_stats(request, search_runtime_handle).await
+    }
+
+    /// Forward read-only `search_text` to `wrapped_shard`
+    async fn search_text(
+        &self,
+        request: Arc<TextSearchRequestInternal>,
+        search_runtime_handle: &Handle,
+    ) -> CollectionResult<Vec<ScoredPoint>> {
+        let local_shard = &self.wrapped_shard;
+        local_shard
+            .search_text(request, search_runtime_handle)
+            .await
+    }
+
     /// Forward read-only `retrieve` to `wrapped_shard`
     async fn retrieve(
         &self,
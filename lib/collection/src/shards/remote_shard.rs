@@ -9,11 +9,12 @@ use api::grpc::qdrant::qdrant_client::QdrantClient;
 use api::grpc::qdrant::shard_snapshot_location::Location;
 use api::grpc::qdrant::shard_snapshots_client::ShardSnapshotsClient;
 use api::grpc::qdrant::{
-    CollectionOperationResponse, CoreSearchBatchPointsInternal, CountPoints, CountPointsInternal,
-    GetCollectionInfoRequest, GetCollectionInfoRequestInternal, GetPoints, GetPointsInternal,
-    HealthCheckRequest, InitiateShardTransferRequest, RecoverShardSnapshotRequest,
-    RecoverSnapshotResponse, ScrollPoints, ScrollPointsInternal, ShardSnapshotLocation,
-    WaitForShardStateRequest,
+    AggregatePoints, AggregatePointsInternal, CollectionOperationResponse,
+    CoreSearchBatchPointsInternal, CountPoints, CountPointsInternal, GetCollectionInfoRequest,
+    GetCollectionInfoRequestInternal, GetPoints, GetPointsInternal, HealthCheckRequest,
+    InitiateShardTransferRequest, RecoverShardSnapshotRequest, RecoverSnapshotResponse,
+    ScrollPoints, ScrollPointsInternal, SearchTextPoints, SearchTextPointsInternal,
+    ShardSnapshotLocation, WaitForOperationRequest, WaitForShardStateRequest,
 };
 use api::grpc::transport_channel_pool::{AddTimeout, MAX_GRPC_CHANNEL_TIMEOUT};
 use async_trait::async_trait;
@@ -21,8 +22,11 @@ use parking_lot::Mutex;
 use segment::common::operation_time_statistics::{
     OperationDurationsAggregator, ScopeDurationMeasurer,
 };
+use segment::data_types::aggregation::NumericAggregation;
+use segment::data_types::distinct::DistinctCounter;
+use segment::data_types::text_index::TextStats;
 use segment::types::{
-    ExtendedPointId, Filter, ScoredPoint, WithPayload, WithPayloadInterface, WithVector,
+    ExtendedPointId, Filter, OrderBy, ScoredPoint, WithPayload, WithPayloadInterface, WithVector,
 };
 use tokio::runtime::Handle;
 use tonic::codegen::InterceptedService;
@@ -39,9 +43,9 @@ use crate::operations::payload_ops::PayloadOps;
 use crate::operations::point_ops::{PointOperations, WriteOrdering};
 use crate::operations::snapshot_ops::SnapshotPriority;
 use crate::operations::types::{
-    CollectionError, CollectionInfo, CollectionResult, CoreSearchRequest, CoreSearchRequestBatch,
-    CountRequestInternal, CountResult, PointRequestInternal, Record, SearchRequestInternal,
-    UpdateResult,
+    AggregateRequestInternal, AggregateResult, CollectionError, CollectionInfo, CollectionResult,
+    CoreSearchRequest, CoreSearchRequestBatch, CountRequestInternal, CountResult,
+    PointRequestInternal, Record, SearchRequestInternal, TextSearchRequestInternal, UpdateResult,
 };
 use crate::operations::vector_ops::VectorOperations;
 use crate::operations::{CollectionUpdateOperations, FieldIndexOperations};
@@ -519,6 +523,30 @@ impl RemoteShard {
         Ok(res)
     }
 
+    /// Wait for a local shard on the remote to apply the WAL operation `operation_id`
+    pub async fn wait_for_operation(
+        &self,
+        collection_name: &str,
+        shard_id: ShardId,
+        operation_id: SeqNumberType,
+        timeout: Duration,
+    ) -> CollectionResult<CollectionOperationResponse> {
+        let res = self
+            .with_collections_client(|mut client| async move {
+                client
+                    .wait_for_operation(WaitForOperationRequest {
+                        collection_name: collection_name.into(),
+                        shard_id,
+                        operation_id,
+                        timeout: timeout.as_secs_f32().ceil() as u64,
+                    })
+                    .await
+            })
+            .await?
+            .into_inner();
+        Ok(res)
+    }
+
     pub async fn health_check(&self) -> CollectionResult<()> {
         let _ = self
             .with_qdrant_client(|mut client| async move {
@@ -556,6 +584,7 @@ impl ShardOperation for RemoteShard {
         with_payload_interface: &WithPayloadInterface,
         with_vector: &WithVector,
         filter: Option<&Filter>,
+        order_by: Option<&OrderBy>,
         search_runtime_handle: &Handle,
     ) -> CollectionResult<Vec<Record>> {
         let scroll_points = ScrollPoints {
@@ -567,6 +596,8 @@ impl ShardOperation for RemoteShard {
             with_vectors: Some(with_vector.clone().into()),
             read_consistency: None,
             shard_key_selector: None,
+            order_by: order_by.map(|order_by| order_by.clone().into()),
+            with_lookup: None,
         };
         let request = &ScrollPointsInternal {
             scroll_points: Some(scroll_points),
@@ -672,6 +703,7 @@ impl ShardOperation for RemoteShard {
             exact: Some(request.exact),
             read_consistency: None,
             shard_key_selector: None,
+            distinct: request.distinct.clone(),
         };
 
         let request = &CountPointsInternal {
@@ -694,6 +726,168 @@ impl ShardOperation for RemoteShard {
         )
     }
 
+    async fn aggregate(
+        &self,
+        request: Arc<AggregateRequestInternal>,
+        _search_runtime_handle: &Handle,
+    ) -> CollectionResult<NumericAggregation> {
+        let aggregate_points = AggregatePoints {
+            collection_name: self.collection_id.clone(),
+            filter: request.filter.clone().map(|f| f.into()),
+            key: request.key.clone(),
+            histogram: request.histogram.map(|h| h.into()),
+            read_consistency: None,
+            shard_key_selector: None,
+        };
+
+        let grpc_request = &AggregatePointsInternal {
+            aggregate_points: Some(aggregate_points),
+            shard_id: Some(self.id),
+        };
+        let aggregate_response = self
+            .with_points_client(|mut client| async move {
+                client
+                    .aggregate(tonic::Request::new(grpc_request.clone()))
+                    .await
+            })
+            .await?
+            .into_inner();
+        aggregate_response.result.map_or_else(
+            || {
+                Err(CollectionError::service_error(
+                    "Unexpected empty AggregateResult".to_string(),
+                ))
+            },
+            |aggregate_result| {
+                Ok(AggregateResult::from(aggregate_result)
+                    .into_aggregation(request.histogram.as_ref()))
+            },
+        )
+    }
+
+    async fn count_distinct(
+        &self,
+        request: Arc<CountRequestInternal>,
+        _search_runtime_handle: &Handle,
+    ) -> CollectionResult<DistinctCounter> {
+        let count_points = CountPoints {
+            collection_name: self.collection_id.clone(),
+            filter: request.filter.clone().map(|f| f.into()),
+            exact: Some(request.exact),
+            read_consistency: None,
+            shard_key_selector: None,
+            distinct: request.distinct.clone(),
+        };
+
+        let request = &CountPointsInternal {
+            count_points: Some(count_points),
+            shard_id: Some(self.id),
+        };
+        let count_distinct_response = self
+            .with_points_client(|mut client| async move {
+                client
+                    .count_distinct(tonic::Request::new(request.clone()))
+                    .await
+            })
+            .await?
+            .into_inner();
+        count_distinct_response.result.map_or_else(
+            || {
+                Err(CollectionError::service_error(
+                    "Unexpected empty DistinctValues".to_string(),
+                ))
+            },
+            |distinct_values| Ok(distinct_values.try_into()?),
+        )
+    }
+
+    async fn text_stats(
+        &self,
+        request: Arc<TextSearchRequestInternal>,
+        _search_runtime_handle: &Handle,
+    ) -> CollectionResult<TextStats> {
+        let search_text_points = SearchTextPoints {
+            collection_name: self.collection_id.clone(),
+            key: request.key.clone(),
+            query: request.query.clone(),
+            filter: request.filter.clone().map(|f| f.into()),
+            limit: request.limit as u64,
+            offset: request.offset.map(|offset| offset as u64),
+            with_payload: None,
+            with_vectors: None,
+            score_threshold: request.score_threshold,
+            read_consistency: None,
+            shard_key_selector: None,
+        };
+
+        let grpc_request = &SearchTextPointsInternal {
+            search_text_points: Some(search_text_points),
+            shard_id: Some(self.id),
+            stats: None,
+        };
+        let text_stats_response = self
+            .with_points_client(|mut client| async move {
+                client
+                    .text_stats(tonic::Request::new(grpc_request.clone()))
+                    .await
+            })
+            .await?
+            .into_inner();
+        text_stats_response.result.map_or_else(
+            || {
+                Err(CollectionError::service_error(
+                    "Unexpected empty TextStats".to_string(),
+                ))
+            },
+            |stats| Ok(stats.into()),
+        )
+    }
+
+    async fn search_text(
+        &self,
+        request: Arc<TextSearchRequestInternal>,
+        _search_runtime_handle: &Handle,
+    ) -> CollectionResult<Vec<ScoredPoint>> {
+        let search_text_points = SearchTextPoints {
+            collection_name: self.collection_id.clone(),
+            key: request.key.clone(),
+            query: request.query.clone(),
+            filter: request.filter.clone().map(|f| f.into()),
+            limit: request.limit as u64,
+            offset: request.offset.map(|offset| offset as u64),
+            with_payload: request.with_payload.clone().map(|wp| wp.into()),
+            with_vectors: request.with_vector.clone().map(|wv| wv.into()),
+            score_threshold: request.score_threshold,
+            read_consistency: None,
+            shard_key_selector: None,
+        };
+
+        let grpc_request = &SearchTextPointsInternal {
+            search_text_points: Some(search_text_points),
+            shard_id: Some(self.id),
+            stats: request.stats.clone().map(Into::into),
+        };
+        let search_response = self
+            .with_points_client(|mut client| async move {
+                client
+                    .search_text(tonic::Request::new(grpc_request.clone()))
+                    .await
+            })
+            .await?
+            .into_inner();
+
+        let is_payload_required = request
+            .with_payload
+            .as_ref()
+            .map_or(false, |with_payload| with_payload.is_required());
+        let result: Result<Vec<ScoredPoint>, Status> = search_response
+            .result
+            .into_iter()
+            .map(|point| try_scored_point_from_grpc(point, is_payload_required))
+            .collect();
+        result.map_err(|e| e.into())
+    }
+
     async fn retrieve(
         &self,
         request: Arc<PointRequestInternal>,
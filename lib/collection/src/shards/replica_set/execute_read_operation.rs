@@ -159,6 +159,7 @@ impl ShardReplicaSet {
         };
 
         let local_is_active = self.peer_is_active(&self.this_peer_id());
+        let local_is_lagging = local_is_active && self.peer_is_lagging(&self.this_peer_id());
 
         let local_operation = if local_is_active {
             let local_operation = async {
@@ -186,21 +187,41 @@ impl ShardReplicaSet {
 
         active_remotes.shuffle(&mut rand::thread_rng());
 
-        let remote_operations = active_remotes.into_iter().map(|remote| {
-            read_operation(remote)
-                .map(|result| (result, false))
-                .right_future()
-        });
+        // Replicas which did not apply yet an operation some read waited for are queried last,
+        // so they are only used if there are not enough up-to-date replicas
+        let (lagging_remotes, up_to_date_remotes): (Vec<_>, Vec<_>) = active_remotes
+            .into_iter()
+            .partition(|remote| self.peer_is_lagging(&remote.peer_id));
 
-        let mut operations = local_operation.into_iter().chain(remote_operations);
+        let (local_operation, lagging_local_operation) = if local_is_lagging {
+            (None, local_operation)
+        } else {
+            (local_operation, None)
+        };
+
+        let remote_operations =
+            up_to_date_remotes
+                .into_iter()
+                .chain(lagging_remotes)
+                .map(|remote| {
+                    read_operation(remote)
+                        .map(|result| (result, false))
+                        .right_future()
+                });
+
+        let mut operations = local_operation
+            .into_iter()
+            .chain(remote_operations)
+            .chain(lagging_local_operation);
 
         // Possible scenarios:
         //
         // - Local is available: default fan-out is 0 (no fan-out, unless explicitly requested)
         // - Local is not available: default fan-out is 1
+        // - Local is lagging: default fan-out is 1
         // - There is no local: default fan-out is 1
 
-        let default_fan_out = if is_local_ready && local_is_active {
+        let default_fan_out = if is_local_ready && local_is_active && !local_is_lagging {
             0
         } else {
             1
@@ -0,0 +1,41 @@
+use std::collections::HashMap;
+
+use segment::types::SeqNumberType;
+
+use crate::shards::shard::PeerId;
+
+/// Replicas which did not confirm yet an operation a consistency token waited for
+///
+/// Every peer maps to the highest operation ID it still has to apply. Reads query these peers
+/// after the up-to-date ones, so they would not miss updates that were already visible on another
+/// replica, unless the read consistency requires more replicas than are up-to-date.
+#[derive(Clone, Debug, Default)]
+pub struct Registry {
+    lagging_peers: HashMap<PeerId, SeqNumberType>,
+}
+
+impl Registry {
+    pub fn is_lagging(&self, peer_id: PeerId) -> bool {
+        self.lagging_peers.contains_key(&peer_id)
+    }
+
+    pub fn mark_lagging(&mut self, peer_id: PeerId, operation_id: SeqNumberType) {
+        let required = self.lagging_peers.entry(peer_id).or_default();
+        *required = (*required).max(operation_id);
+    }
+
+    /// Peer has applied `operation_id`, stop skipping it unless it has to apply a later one
+    pub fn catch_up(&mut self, peer_id: PeerId, operation_id: SeqNumberType) {
+        if self
+            .lagging_peers
+            .get(&peer_id)
+            .map_or(false, |&required| required <= operation_id)
+        {
+            self.lagging_peers.remove(&peer_id);
+        }
+    }
+
+    pub fn remove_peer(&mut self, peer_id: PeerId) {
+        let _ = self.lagging_peers.remove(&peer_id);
+    }
+}
@@ -1,22 +1,26 @@
 mod execute_read_operation;
+mod lagging_peers;
 mod locally_disabled_peers;
 mod read_ops;
 mod shard_transfer;
 mod snapshots;
 mod update;
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::ops::Deref as _;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Duration;
 
+use futures::future::{BoxFuture, FutureExt as _};
+use futures::stream::{FuturesUnordered, StreamExt as _};
 use schemars::JsonSchema;
+use segment::types::{Filter, PayloadKeyType, SeqNumberType};
 use serde::{Deserialize, Serialize};
 use tokio::runtime::Handle;
 use tokio::sync::{Mutex, RwLock};
 
-use super::local_shard::LocalShard;
+use super::local_shard::{LocalShard, WalOperations};
 use super::remote_shard::RemoteShard;
 use super::transfer::ShardTransfer;
 use super::CollectionId;
@@ -80,6 +84,10 @@ pub struct ShardReplicaSet {
     /// If the state of the peer is changed in the consensus, it is removed from the list.
     /// Update and read operations are not performed on the peers marked as dead.
     locally_disabled_peers: parking_lot::RwLock<locally_disabled_peers::Registry>,
+    /// List of peers that did not apply yet an operation some read waited for.
+    /// Entries are removed once the peer confirms the operation in the background.
+    /// Reads query the lagging peers only if there are not enough up-to-date ones.
+    lagging_peers: Arc<parking_lot::RwLock<lagging_peers::Registry>>,
     pub(crate) shard_path: PathBuf,
     pub(crate) shard_id: ShardId,
     notify_peer_failure_cb: ChangePeerState,
@@ -99,6 +107,9 @@ pub type ChangePeerState = Arc<dyn Fn(PeerId, ShardId) + Send + Sync>;
 
 const REPLICA_STATE_FILE: &str = "replica_state.json";
 
+/// How long a replica is skipped by reads while it did not confirm an awaited operation
+const LAGGING_PEER_TIMEOUT: Duration = Duration::from_secs(600);
+
 impl ShardReplicaSet {
     /// Create a new fresh replica set, no previous state is expected.
     #[allow(clippy::too_many_arguments)]
@@ -166,6 +177,7 @@ impl ShardReplicaSet {
             remotes: RwLock::new(remote_shards),
             replica_state: replica_state.into(),
             locally_disabled_peers: Default::default(),
+            lagging_peers: Default::default(),
             shard_path,
             abort_shard_transfer_cb: abort_shard_transfer,
             notify_peer_failure_cb: on_peer_failure,
@@ -273,6 +285,7 @@ impl ShardReplicaSet {
             replica_state: replica_state.into(),
             // TODO: move to collection config
             locally_disabled_peers: Default::default(),
+            lagging_peers: Default::default(),
             shard_path: shard_path.to_path_buf(),
             notify_peer_failure_cb: on_peer_failure,
             abort_shard_transfer_cb: abort_shard_transfer,
@@ -389,6 +402,203 @@ impl ShardReplicaSet {
         .await
     }
 
+    /// Watch for the local shard to apply the WAL operation `operation_id`
+    ///
+    /// The returned future resolves to `false` if the operation was not applied within `timeout`.
+    /// It does not borrow the replica set, so no locks are held while awaiting it.
+    pub async fn watch_local_operation(
+        &self,
+        operation_id: SeqNumberType,
+        timeout: Duration,
+    ) -> CollectionResult<BoxFuture<'static, bool>> {
+        match self.local.read().await.deref() {
+            Some(local) => Ok(local
+                .wait_for_applied_operation(operation_id, timeout)
+                .boxed()),
+            None => Err(self.local_shard_missing_error()),
+        }
+    }
+
+    /// Read up to `limit` applied operations from the WAL of the local shard, starting from `from`
+    pub async fn read_local_wal(
+        &self,
+        from: Option<SeqNumberType>,
+        limit: usize,
+    ) -> CollectionResult<WalOperations> {
+        match self.local.read().await.deref() {
+            Some(local) => local.read_wal(from, limit).await,
+            None => Err(self.local_shard_missing_error()),
+        }
+    }
+
+    /// Find the first field of the filter, which conditions the payload index of the local shard
+    /// can't serve
+    pub async fn unindexed_filter_key(
+        &self,
+        filter: &Filter,
+    ) -> CollectionResult<Option<PayloadKeyType>> {
+        match self.local.read().await.deref() {
+            Some(local) => local.unindexed_filter_key(filter),
+            None => Err(self.local_shard_missing_error()),
+        }
+    }
+
+    fn local_shard_missing_error(&self) -> CollectionError {
+        CollectionError::bad_request(format!(
+            "Shard {} has no replica on peer {}",
+            self.shard_id,
+            self.this_peer_id(),
+        ))
+    }
+
+    /// Wait for the local shard to apply the WAL operation `operation_id`
+    pub async fn wait_for_local_operation(
+        &self,
+        operation_id: SeqNumberType,
+        timeout: Duration,
+    ) -> CollectionResult<()> {
+        let is_applied = self.watch_local_operation(operation_id, timeout).await?;
+
+        if !is_applied.await {
+            return Err(CollectionError::timeout(
+                timeout.as_secs() as usize,
+                format!("wait for operation {operation_id}"),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Wait until any `Active` replica applies its WAL operation from `operations`
+    ///
+    /// Operation numbers are specific to every replica. Replicas that are not active anymore are
+    /// skipped, as they will be recovered before serving reads again. Replicas that did not apply
+    /// their operation yet are marked as lagging, so reads prefer the up-to-date ones until the
+    /// lagging replicas confirm the operation in the background. Lagging replicas still count
+    /// towards the read consistency, they are queried last if more replicas are required.
+    ///
+    /// Fails only if no replica applies its operation within `timeout`.
+    pub async fn wait_for_operations(
+        &self,
+        operations: &BTreeMap<PeerId, SeqNumberType>,
+        timeout: Duration,
+    ) -> CollectionResult<()> {
+        let this_peer_id = self.this_peer_id();
+        let remotes = self.remotes.read().await.clone();
+
+        let mut pending: FuturesUnordered<BoxFuture<'static, (PeerId, SeqNumberType, bool)>> =
+            FuturesUnordered::new();
+
+        for (&peer_id, &operation_id) in operations {
+            if !self.peer_is_active(&peer_id) {
+                continue;
+            }
+
+            let is_applied = if peer_id == this_peer_id {
+                match self
+                    .watch_local_operation(operation_id, LAGGING_PEER_TIMEOUT)
+                    .await
+                {
+                    Ok(is_applied) => is_applied,
+                    Err(_) => continue,
+                }
+            } else if let Some(remote) = remotes.iter().find(|remote| remote.peer_id == peer_id) {
+                let remote = remote.clone();
+                let collection_id = self.collection_id.clone();
+                let shard_id = self.shard_id;
+
+                async move {
+                    remote
+                        .wait_for_operation(
+                            &collection_id,
+                            shard_id,
+                            operation_id,
+                            LAGGING_PEER_TIMEOUT,
+                        )
+                        .await
+                        .is_ok()
+                }
+                .boxed()
+            } else {
+                continue;
+            };
+
+            pending.push(
+                is_applied
+                    .map(move |is_applied| (peer_id, operation_id, is_applied))
+                    .boxed(),
+            );
+        }
+
+        if pending.is_empty() {
+            return Ok(());
+        }
+
+        let mut resolved_peers = HashSet::new();
+
+        let wait_for_any = async {
+            while let Some((peer_id, operation_id, is_applied)) = pending.next().await {
+                resolved_peers.insert(peer_id);
+
+                if is_applied {
+                    self.lagging_peers.write().catch_up(peer_id, operation_id);
+                    return true;
+                }
+            }
+
+            false
+        };
+
+        let is_any_applied = tokio::time::timeout(timeout, wait_for_any)
+            .await
+            .unwrap_or(false);
+
+        if !is_any_applied {
+            return Err(CollectionError::timeout(
+                timeout.as_secs() as usize,
+                format!(
+                    "wait for operations {operations:?} on shard {}",
+                    self.shard_id,
+                ),
+            ));
+        }
+
+        if pending.is_empty() {
+            return Ok(());
+        }
+
+        // Skip the replicas which did not confirm their operation yet until they catch up
+        {
+            let mut lagging_peers = self.lagging_peers.write();
+
+            for (&peer_id, &operation_id) in operations {
+                if self.peer_is_active(&peer_id) && !resolved_peers.contains(&peer_id) {
+                    lagging_peers.mark_lagging(peer_id, operation_id);
+                }
+            }
+        }
+
+        let lagging_peers = self.lagging_peers.clone();
+        let collection_id = self.collection_id.clone();
+        let shard_id = self.shard_id;
+
+        self.search_runtime.spawn(async move {
+            while let Some((peer_id, operation_id, is_applied)) = pending.next().await {
+                if !is_applied {
+                    log::warn!(
+                        "Replica {peer_id} of shard {collection_id}:{shard_id} \
+                         did not apply operation {operation_id} in time"
+                    );
+                }
+
+                // Either caught up or timed out, in the latter case reads fall back to it
+                lagging_peers.write().catch_up(peer_id, operation_id);
+            }
+        });
+
+        Ok(())
+    }
+
     /// Wait for a replica set state condition to be true.
     ///
     /// Uses a blocking thread internally.
@@ -567,6 +777,8 @@ impl ShardReplicaSet {
     }
 
     pub async fn remove_peer(&self, peer_id: PeerId) -> CollectionResult<()> {
+        self.lagging_peers.write().remove_peer(peer_id);
+
         if self.this_peer_id() == peer_id {
             self.remove_local().await?;
         } else {
@@ -762,6 +974,11 @@ impl ShardReplicaSet {
         self.peer_state(peer_id) == Some(ReplicaState::Active) && !self.is_locally_disabled(peer_id)
     }
 
+    /// Whether the peer did not apply yet an operation some read waited for
+    fn peer_is_lagging(&self, peer_id: &PeerId) -> bool {
+        self.lagging_peers.read().is_lagging(*peer_id)
+    }
+
     fn is_locally_disabled(&self, peer_id: &PeerId) -> bool {
         self.locally_disabled_peers.read().is_disabled(*peer_id)
     }
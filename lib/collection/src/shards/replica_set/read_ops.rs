@@ -2,6 +2,9 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use futures::FutureExt as _;
+use segment::data_types::aggregation::NumericAggregation;
+use segment::data_types::distinct::DistinctCounter;
+use segment::data_types::text_index::TextStats;
 use segment::types::*;
 
 use super::ShardReplicaSet;
@@ -17,18 +20,21 @@ impl ShardReplicaSet {
         with_payload_interface: &WithPayloadInterface,
         with_vector: &WithVector,
         filter: Option<&Filter>,
+        order_by: Option<&OrderBy>,
         read_consistency: Option<ReadConsistency>,
         local_only: bool,
     ) -> CollectionResult<Vec<Record>> {
         let with_payload_interface = Arc::new(with_payload_interface.clone());
         let with_vector = Arc::new(with_vector.clone());
         let filter = filter.map(|filter| Arc::new(filter.clone()));
+        let order_by = order_by.map(|order_by| Arc::new(order_by.clone()));
 
         self.execute_and_resolve_read_operation(
             |shard| {
                 let with_payload_interface = with_payload_interface.clone();
                 let with_vector = with_vector.clone();
                 let filter = filter.clone();
+                let order_by = order_by.clone();
                 let search_runtime = self.search_runtime.clone();
 
                 async move {
@@ -39,6 +45,7 @@ impl ShardReplicaSet {
                             &with_payload_interface,
                             &with_vector,
                             filter.as_deref(),
+                            order_by.as_deref(),
                             &search_runtime,
                         )
                         .await
@@ -87,6 +94,78 @@ impl ShardReplicaSet {
         .await
     }
 
+    pub async fn aggregate(
+        &self,
+        request: Arc<AggregateRequestInternal>,
+        read_consistency: Option<ReadConsistency>,
+        local_only: bool,
+    ) -> CollectionResult<NumericAggregation> {
+        self.execute_and_resolve_read_operation(
+            |shard| {
+                let request = request.clone();
+                let search_runtime = self.search_runtime.clone();
+                async move { shard.aggregate(request, &search_runtime).await }.boxed()
+            },
+            read_consistency,
+            local_only,
+        )
+        .await
+    }
+
+    pub async fn count_distinct(
+        &self,
+        request: Arc<CountRequestInternal>,
+        read_consistency: Option<ReadConsistency>,
+        local_only: bool,
+    ) -> CollectionResult<DistinctCounter> {
+        self.execute_and_resolve_read_operation(
+            |shard| {
+                let request = request.clone();
+                let search_runtime = self.search_runtime.clone();
+                async move { shard.count_distinct(request, &search_runtime).await }.boxed()
+            },
+            read_consistency,
+            local_only,
+        )
+        .await
+    }
+
+    pub async fn text_stats(
+        &self,
+        request: Arc<TextSearchRequestInternal>,
+        read_consistency: Option<ReadConsistency>,
+        local_only: bool,
+    ) -> CollectionResult<TextStats> {
+        self.execute_and_resolve_read_operation(
+            |shard| {
+                let request = request.clone();
+                let search_runtime = self.search_runtime.clone();
+                async move { shard.text_stats(request, &search_runtime).await }.boxed()
+            },
+            read_consistency,
+            local_only,
+        )
+        .await
+    }
+
+    pub async fn search_text(
+        &self,
+        request: Arc<TextSearchRequestInternal>,
+        read_consistency: Option<ReadConsistency>,
+        local_only: bool,
+    ) -> CollectionResult<Vec<ScoredPoint>> {
+        self.execute_and_resolve_read_operation(
+            |shard| {
+                let request = request.clone();
+                let search_runtime = self.search_runtime.clone();
+                async move { shard.search_text(request, &search_runtime).await }.boxed()
+            },
+            read_consistency,
+            local_only,
+        )
+        .await
+    }
+
     pub async fn retrieve(
         &self,
         request: Arc<PointRequestInternal>,
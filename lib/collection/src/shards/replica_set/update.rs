@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::ops::Deref as _;
 use std::time::Duration;
 
@@ -6,7 +7,9 @@ use futures::{FutureExt as _, StreamExt as _};
 use itertools::Itertools as _;
 
 use super::{ReplicaSetState, ReplicaState, ShardReplicaSet};
-use crate::operations::point_ops::WriteOrdering;
+use crate::operations::consistency_params::ConsistencyToken;
+use crate::operations::expiry;
+use crate::operations::point_ops::{PointOperations, WriteOrdering};
 use crate::operations::types::{CollectionError, CollectionResult, UpdateResult};
 use crate::operations::CollectionUpdateOperations;
 use crate::shards::shard::PeerId;
@@ -78,6 +81,49 @@ impl ShardReplicaSet {
         }
     }
 
+    /// Delete points of the shard, which are expired at the unix timestamp `now`
+    ///
+    /// Only the leader replica issues the deletion, as a regular update replicated to all replicas
+    /// of the shard. Other replicas skip it, so expiry is applied once and in the same order
+    /// with other updates on every replica.
+    pub async fn delete_expired_points(&self, now: u64) -> CollectionResult<()> {
+        if self.leader_peer_for_update(WriteOrdering::Medium) != Some(self.this_peer_id()) {
+            return Ok(());
+        }
+
+        let filter = expiry::expired_points_filter(now);
+
+        let has_expired_points = match self.local.read().await.deref() {
+            Some(local) => !local
+                .get()
+                .scroll_by(
+                    None,
+                    1,
+                    &false.into(),
+                    &false.into(),
+                    Some(&filter),
+                    None,
+                    &self.search_runtime,
+                )
+                .await?
+                .is_empty(),
+            None => false,
+        };
+
+        if !has_expired_points {
+            return Ok(());
+        }
+
+        let operation = CollectionUpdateOperations::PointOperation(
+            PointOperations::DeletePointsByFilter(filter),
+        );
+
+        self.update_with_consistency(operation, false, WriteOrdering::Medium)
+            .await?;
+
+        Ok(())
+    }
+
     /// Designated a leader replica for the update based on the WriteOrdering
     fn leader_peer_for_update(&self, ordering: WriteOrdering) -> Option<PeerId> {
         match ordering {
@@ -259,12 +305,22 @@ impl ShardReplicaSet {
             )));
         }
 
+        // Collect operation numbers of all replicas, to be able to read this update from any of them
+        let operations = successes
+            .iter()
+            .filter_map(|(peer_id, res)| Some((*peer_id, res.operation_id?)))
+            .collect::<BTreeMap<_, _>>();
+
         // there are enough successes, return the first one
-        let (_, res) = successes
+        let (_, mut res) = successes
             .into_iter()
             .next()
             .expect("successes is not empty");
 
+        if !operations.is_empty() {
+            res.consistency_token = Some(ConsistencyToken::new(self.shard_id, operations));
+        }
+
         Ok(res)
     }
 
@@ -1,6 +1,9 @@
 use std::collections::{HashMap, HashSet};
 use std::hash;
 
+use segment::data_types::aggregation::NumericAggregation;
+use segment::data_types::distinct::DistinctCounter;
+use segment::data_types::text_index::TextStats;
 use segment::types::{Payload, ScoredPoint};
 use tinyvec::TinyVec;
 
@@ -41,6 +44,24 @@ impl Resolve for CountResult {
     }
 }
 
+impl Resolve for NumericAggregation {
+    fn resolve(aggregations: Vec<Self>, condition: ResolveCondition) -> Self {
+        resolve_by_key(aggregations, |aggregation| aggregation.count, condition)
+    }
+}
+
+impl Resolve for DistinctCounter {
+    fn resolve(counters: Vec<Self>, condition: ResolveCondition) -> Self {
+        resolve_by_key(counters, DistinctCounter::count, condition)
+    }
+}
+
+impl Resolve for TextStats {
+    fn resolve(stats: Vec<Self>, condition: ResolveCondition) -> Self {
+        resolve_by_key(stats, |stats| stats.documents_count, condition)
+    }
+}
+
 impl Resolve for Vec<Record> {
     fn resolve(records: Vec<Self>, condition: ResolveCondition) -> Self {
         let mut resolved = Resolver::resolve(records, |record| record.id, record_eq, condition);
@@ -49,6 +70,14 @@ impl Resolve for Vec<Record> {
     }
 }
 
+impl Resolve for Vec<ScoredPoint> {
+    fn resolve(points: Vec<Self>, condition: ResolveCondition) -> Self {
+        let mut resolved = Resolver::resolve(points, |point| point.id, scored_point_eq, condition);
+        resolved.sort_unstable();
+        resolved
+    }
+}
+
 impl Resolve for Vec<Vec<ScoredPoint>> {
     fn resolve(batches: Vec<Self>, condition: ResolveCondition) -> Self {
         // batches: <replica_id, <batch_id, ScoredPoint>>
@@ -69,6 +98,29 @@ impl Resolve for Vec<Vec<ScoredPoint>> {
     }
 }
 
+/// Select the response with the smallest key if all replicas have to agree,
+/// or with the median key if the majority has to agree
+fn resolve_by_key<T, K>(
+    mut responses: Vec<T>,
+    key: impl Fn(&T) -> K,
+    condition: ResolveCondition,
+) -> T
+where
+    T: Default,
+    K: Ord,
+{
+    responses.sort_unstable_by_key(&key);
+    let index = match condition {
+        ResolveCondition::All => 0,
+        ResolveCondition::Majority => responses.len() / 2,
+    };
+    if index < responses.len() {
+        responses.swap_remove(index)
+    } else {
+        T::default()
+    }
+}
+
 fn transpose<T>(vec: Vec<Vec<T>>) -> Vec<Vec<T>> {
     if vec.is_empty() {
         return Vec::new();
@@ -250,6 +302,58 @@ mod test {
 
     use super::*;
 
+    #[test]
+    fn test_resolve_numeric_aggregation() {
+        let aggregations = [3, 1, 2].map(|count| NumericAggregation {
+            count,
+            ..Default::default()
+        });
+
+        let resolved = NumericAggregation::resolve(aggregations.to_vec(), ResolveCondition::All);
+        assert_eq!(resolved.count, 1);
+
+        let resolved =
+            NumericAggregation::resolve(aggregations.to_vec(), ResolveCondition::Majority);
+        assert_eq!(resolved.count, 2);
+
+        let resolved = NumericAggregation::resolve(vec![], ResolveCondition::Majority);
+        assert_eq!(resolved, NumericAggregation::default());
+    }
+
+    #[test]
+    fn test_resolve_distinct_counter() {
+        let counters = [vec![1, 2, 3], vec![1], vec![1, 2]].map(|values| {
+            let mut counter = DistinctCounter::default();
+            for value in values {
+                counter
+                    .add(segment::types::ValueVariants::Integer(value), true)
+                    .unwrap();
+            }
+            counter
+        });
+
+        let resolved = DistinctCounter::resolve(counters.to_vec(), ResolveCondition::All);
+        assert_eq!(resolved.count(), 1);
+
+        let resolved = DistinctCounter::resolve(counters.to_vec(), ResolveCondition::Majority);
+        assert_eq!(resolved.count(), 2);
+    }
+
+    #[test]
+    fn test_resolve_text_stats() {
+        let stats = [3, 1, 2].map(|documents_count| TextStats {
+            documents_count,
+            tokens_count: documents_count * 10,
+            ..Default::default()
+        });
+
+        let resolved = TextStats::resolve(stats.to_vec(), ResolveCondition::All);
+        assert_eq!(resolved.tokens_count, 10);
+
+        let resolved = TextStats::resolve(stats.to_vec(), ResolveCondition::Majority);
+        assert_eq!(resolved.tokens_count, 20);
+    }
+
     #[rustfmt::skip]
     fn resolve_scored_points_batch_4_data() -> [Vec<ScoredPoint>; 3] {
         [
@@ -277,6 +381,7 @@ mod test {
             payload: None,
             vector: None,
             shard_key: None,
+            geo_distance: None,
         }
     }
 
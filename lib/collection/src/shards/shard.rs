@@ -1,12 +1,15 @@
 use core::marker::{Send, Sync};
 use std::future::{self, Future};
 use std::path::Path;
+use std::time::Duration;
+
+use segment::types::{Filter, PayloadKeyType, SeqNumberType};
 
 use super::update_tracker::UpdateTracker;
-use crate::operations::types::CollectionResult;
+use crate::operations::types::{CollectionError, CollectionResult};
 use crate::shards::dummy_shard::DummyShard;
 use crate::shards::forward_proxy_shard::ForwardProxyShard;
-use crate::shards::local_shard::LocalShard;
+use crate::shards::local_shard::{LocalShard, WalOperations};
 use crate::shards::proxy_shard::ProxyShard;
 use crate::shards::queue_proxy_shard::QueueProxyShard;
 use crate::shards::shard_trait::ShardOperation;
@@ -135,6 +138,59 @@ impl Shard {
         }
     }
 
+    /// Wait until the WAL operation `operation_id` is applied to this shard
+    ///
+    /// Returns `false` if the operation was not applied within `timeout`.
+    pub fn wait_for_applied_operation(
+        &self,
+        operation_id: SeqNumberType,
+        timeout: Duration,
+    ) -> impl Future<Output = bool> {
+        let applied_watcher = self
+            .update_tracker()
+            .map(|update_tracker| update_tracker.wait_for_applied_operation(operation_id, timeout));
+
+        async move {
+            match applied_watcher {
+                Some(applied_watcher) => applied_watcher.await,
+                None => false,
+            }
+        }
+    }
+
+    /// Read up to `limit` applied operations from the WAL of the local shard, starting from `from`
+    pub async fn read_wal(
+        &self,
+        from: Option<SeqNumberType>,
+        limit: usize,
+    ) -> CollectionResult<WalOperations> {
+        match self {
+            Shard::Local(local_shard) => local_shard.read_wal(from, limit).await,
+            Shard::Proxy(proxy_shard) => proxy_shard.read_wal(from, limit).await,
+            Shard::ForwardProxy(proxy_shard) => proxy_shard.read_wal(from, limit).await,
+            Shard::QueueProxy(proxy_shard) => proxy_shard.read_wal(from, limit).await,
+            Shard::Dummy(_) => Err(CollectionError::service_error(
+                "Shard is not loaded, WAL is not available",
+            )),
+        }
+    }
+
+    /// Find the first field of the filter, which conditions the payload index of the shard can't serve
+    pub fn unindexed_filter_key(
+        &self,
+        filter: &Filter,
+    ) -> CollectionResult<Option<PayloadKeyType>> {
+        match self {
+            Shard::Local(local_shard) => Ok(local_shard.unindexed_filter_key(filter)),
+            Shard::Proxy(proxy_shard) => Ok(proxy_shard.unindexed_filter_key(filter)),
+            Shard::ForwardProxy(proxy_shard) => Ok(proxy_shard.unindexed_filter_key(filter)),
+            Shard::QueueProxy(proxy_shard) => Ok(proxy_shard.unindexed_filter_key(filter)),
+            Shard::Dummy(_) => Err(CollectionError::service_error(
+                "Shard is not loaded, payload index is not available",
+            )),
+        }
+    }
+
     fn update_tracker(&self) -> Option<&UpdateTracker> {
         let update_tracker = match self {
             Self::Local(local_shard) => local_shard.update_tracker(),
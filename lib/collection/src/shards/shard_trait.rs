@@ -2,14 +2,18 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use async_trait::async_trait;
+use segment::data_types::aggregation::NumericAggregation;
+use segment::data_types::distinct::DistinctCounter;
+use segment::data_types::text_index::TextStats;
 use segment::types::{
-    ExtendedPointId, Filter, ScoredPoint, WithPayload, WithPayloadInterface, WithVector,
+    ExtendedPointId, Filter, OrderBy, ScoredPoint, WithPayload, WithPayloadInterface, WithVector,
 };
 use tokio::runtime::Handle;
 
 use crate::operations::types::{
-    CollectionInfo, CollectionResult, CoreSearchRequestBatch, CountRequestInternal, CountResult,
-    PointRequestInternal, Record, UpdateResult,
+    AggregateRequestInternal, CollectionInfo, CollectionResult, CoreSearchRequestBatch,
+    CountRequestInternal, CountResult, PointRequestInternal, Record, TextSearchRequestInternal,
+    UpdateResult,
 };
 use crate::operations::CollectionUpdateOperations;
 
@@ -29,6 +33,7 @@ pub trait ShardOperation {
         with_payload_interface: &WithPayloadInterface,
         with_vector: &WithVector,
         filter: Option<&Filter>,
+        order_by: Option<&OrderBy>,
         search_runtime_handle: &Handle,
     ) -> CollectionResult<Vec<Record>>;
 
@@ -43,6 +48,32 @@ pub trait ShardOperation {
 
     async fn count(&self, request: Arc<CountRequestInternal>) -> CollectionResult<CountResult>;
 
+    async fn aggregate(
+        &self,
+        request: Arc<AggregateRequestInternal>,
+        search_runtime_handle: &Handle,
+    ) -> CollectionResult<NumericAggregation>;
+
+    async fn count_distinct(
+        &self,
+        request: Arc<CountRequestInternal>,
+        search_runtime_handle: &Handle,
+    ) -> CollectionResult<DistinctCounter>;
+
+    /// Statistics of the text field of the request, required to score the query
+    async fn text_stats(
+        &self,
+        request: Arc<TextSearchRequestInternal>,
+        search_runtime_handle: &Handle,
+    ) -> CollectionResult<TextStats>;
+
+    /// Score with the statistics of the request, or with the statistics of this shard if absent
+    async fn search_text(
+        &self,
+        request: Arc<TextSearchRequestInternal>,
+        search_runtime_handle: &Handle,
+    ) -> CollectionResult<Vec<ScoredPoint>>;
+
     async fn retrieve(
         &self,
         request: Arc<PointRequestInternal>,
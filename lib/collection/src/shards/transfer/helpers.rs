@@ -3,6 +3,7 @@ use std::collections::{HashMap, HashSet};
 
 use super::{ShardTransfer, ShardTransferKey};
 use crate::operations::types::{CollectionError, CollectionResult};
+use crate::shards::peer_labels::{count_shared_failure_domains, PeerLabelsById};
 use crate::shards::replica_set::ReplicaState;
 use crate::shards::shard::{PeerId, ShardId};
 
@@ -166,10 +167,12 @@ pub fn suggest_transfer_source(
 ///
 /// Requirements:
 /// 1. Peer should not have an active replica of the shard
-/// 2. Peer should have minimal number of active transfers
+/// 2. Peer should preferably share no zone or rack with other replicas of the shard
+/// 3. Peer should have minimal number of active transfers
 pub fn suggest_peer_to_add_replica(
     shard_id: ShardId,
     shard_distribution: HashMap<ShardId, HashSet<PeerId>>,
+    labels_by_id: &PeerLabelsById,
 ) -> Option<PeerId> {
     let mut peer_loads: HashMap<PeerId, usize> = HashMap::new();
     for peers in shard_distribution.values() {
@@ -181,12 +184,17 @@ pub fn suggest_peer_to_add_replica(
         .get(&shard_id)
         .cloned()
         .unwrap_or_default();
-    for peer_with_shard in peers_with_shard {
-        peer_loads.remove(&peer_with_shard);
+    for peer_with_shard in &peers_with_shard {
+        peer_loads.remove(peer_with_shard);
     }
 
     let mut candidates = peer_loads.into_iter().collect::<Vec<(PeerId, usize)>>();
-    candidates.sort_unstable_by_key(|(_, count)| *count);
+    candidates.sort_unstable_by_key(|(peer_id, count)| {
+        (
+            count_shared_failure_domains(labels_by_id, *peer_id, &peers_with_shard),
+            *count,
+        )
+    });
     candidates.first().map(|(peer_id, _)| *peer_id)
 }
 
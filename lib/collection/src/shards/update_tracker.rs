@@ -1,22 +1,28 @@
 use std::future::{self, Future};
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 
+use segment::types::SeqNumberType;
 use tokio::sync::watch;
 
 #[derive(Clone, Debug)]
 pub struct UpdateTracker {
     update_operations: Arc<AtomicUsize>,
     update_notifier: Arc<watch::Sender<()>>,
+    /// Number of the last WAL operation applied to the segments, if any
+    applied_operation: Arc<watch::Sender<Option<SeqNumberType>>>,
 }
 
 impl Default for UpdateTracker {
     fn default() -> Self {
         let (update_notifier, _) = watch::channel(());
+        let (applied_operation, _) = watch::channel(None);
 
         Self {
             update_operations: Default::default(),
             update_notifier: Arc::new(update_notifier),
+            applied_operation: Arc::new(applied_operation),
         }
     }
 }
@@ -44,6 +50,41 @@ impl UpdateTracker {
 
         UpdateGuard::new(self.update_operations.clone())
     }
+
+    pub fn applied_operation(&self) -> Option<SeqNumberType> {
+        *self.applied_operation.borrow()
+    }
+
+    /// Mark all WAL operations up to `operation_id` as applied
+    pub fn set_applied_operation(&self, operation_id: SeqNumberType) {
+        self.applied_operation.send_if_modified(|applied| {
+            if applied.map_or(true, |applied| applied < operation_id) {
+                *applied = Some(operation_id);
+                true
+            } else {
+                false
+            }
+        });
+    }
+
+    /// Wait until the WAL operation `operation_id` is applied
+    ///
+    /// Returns `false` if the operation was not applied within `timeout`.
+    pub fn wait_for_applied_operation(
+        &self,
+        operation_id: SeqNumberType,
+        timeout: Duration,
+    ) -> impl Future<Output = bool> {
+        let mut applied_subscriber = self.applied_operation.subscribe();
+
+        async move {
+            let is_applied = applied_subscriber.wait_for(|applied| {
+                applied.map_or(false, |applied| applied >= operation_id)
+            });
+
+            matches!(tokio::time::timeout(timeout, is_applied).await, Ok(Ok(_)))
+        }
+    }
 }
 
 #[derive(Debug)]
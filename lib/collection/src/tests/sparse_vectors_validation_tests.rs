@@ -6,8 +6,8 @@ use validator::Validate;
 
 use crate::operations::point_ops::{Batch, PointStruct, PointsBatch, PointsList};
 use crate::operations::types::{
-    BaseGroupRequest, ContextExamplePair, DiscoverRequestInternal, RecommendExample,
-    RecommendRequestInternal, SearchGroupsRequestInternal, SearchRequestInternal,
+    BaseGroupRequest, ContextExamplePair, DiscoverRequestInternal, FeedbackExample,
+    RecommendExample, RecommendRequestInternal, SearchGroupsRequestInternal, SearchRequestInternal,
 };
 use crate::operations::vector_ops::PointVectors;
 
@@ -116,6 +116,8 @@ fn validate_error_sparse_vector_recommend_request_internal() {
     check_validation_error(RecommendRequestInternal {
         positive: vec![wrong_recommend_example()],
         negative: vec![wrong_recommend_example()],
+        feedback: vec![],
+        context: vec![],
         strategy: None,
         filter: None,
         params: None,
@@ -129,6 +131,14 @@ fn validate_error_sparse_vector_recommend_request_internal() {
     });
 }
 
+#[test]
+fn validate_error_sparse_vector_feedback_example() {
+    check_validation_error(FeedbackExample {
+        example: wrong_recommend_example(),
+        weight: 0.5,
+    });
+}
+
 #[test]
 fn validate_error_sparse_vector_context_example_pair() {
     check_validation_error(ContextExamplePair {
@@ -442,6 +442,11 @@ impl UpdateHandler {
                     let operation_result = flush_res
                         .and_then(|_| CollectionUpdater::update(&segments, op_num, operation));
 
+                    segments
+                        .read()
+                        .update_tracker()
+                        .set_applied_operation(op_num);
+
                     let res = match operation_result {
                         Ok(update_res) => optimize_sender
                             .send(OptimizerSignal::Operation(op_num))
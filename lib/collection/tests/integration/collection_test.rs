@@ -168,8 +168,10 @@ async fn test_collection_search_with_payload_and_vector_with_shards(shard_number
             geo_radius: None,
             values_count: None,
             geo_polygon: None,
+            geo_shape: None,
         }))),
         exact: true,
+        distinct: None,
     };
 
     let count_res = collection
@@ -410,6 +412,7 @@ async fn test_read_api_with_shards(shard_number: u32) {
                 filter: None,
                 with_payload: Some(WithPayloadInterface::Bool(true)),
                 with_vector: false.into(),
+                order_by: None,
             },
             None,
             &ShardSelectorInternal::All,
@@ -493,6 +496,7 @@ async fn test_collection_delete_points_by_filter_with_shards(shard_number: u32)
                 filter: None,
                 with_payload: Some(WithPayloadInterface::Bool(false)),
                 with_vector: false.into(),
+                order_by: None,
             },
             None,
             &ShardSelectorInternal::All,
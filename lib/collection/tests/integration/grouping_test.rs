@@ -137,6 +137,8 @@ mod group_by {
                 score_threshold: None,
                 positive: vec![1.into(), 2.into(), 3.into()],
                 negative: Vec::new(),
+                feedback: Vec::new(),
+                context: Vec::new(),
                 using: None,
                 lookup_from: None,
             }),
@@ -571,6 +573,7 @@ mod group_by_builder {
 
         request.with_lookup = Some(WithLookup {
             collection_name: "test".to_string(),
+            link: None,
             with_payload: Some(true.into()),
             with_vectors: Some(true.into()),
         });
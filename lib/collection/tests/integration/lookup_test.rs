@@ -1,6 +1,6 @@
 use collection::collection::Collection;
 use collection::lookup::types::PseudoId;
-use collection::lookup::{lookup_ids, WithLookup};
+use collection::lookup::{lookup_ids, lookup_links, LookupLink, WithLookup};
 use collection::operations::consistency_params::ReadConsistency;
 use collection::operations::point_ops::{Batch, WriteOrdering};
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
@@ -30,6 +30,7 @@ struct Resources {
 async fn setup() -> Resources {
     let request = WithLookup {
         collection_name: "test".to_string(),
+        link: None,
         with_payload: None,
         with_vectors: None,
     };
@@ -246,3 +247,136 @@ async fn err_when_collection_by_name_returns_none() {
         "Collection test not found".to_string()
     );
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn lookup_links_by_point_id() {
+    let Resources {
+        mut request,
+        collection,
+        read_consistency,
+        ..
+    } = setup().await;
+
+    let source = collection.read().await;
+    let target = collection.read().await;
+    let collection_by_name = |_: String| async { Some(target) };
+
+    request.link = Some(LookupLink {
+        key: "author_id".to_string(),
+        target_key: None,
+        limit: None,
+    });
+    request.with_payload = Some(true.into());
+
+    let payloads = [
+        Payload::from(json!({ "author_id": 5 })),
+        Payload::from(json!({ "author_id": [7, 8] })),
+        Payload::from(json!({ "author_id": 100_000 })),
+    ];
+
+    let points = payloads
+        .iter()
+        .enumerate()
+        .map(|(i, payload)| (PointIdType::from(i as u64), Some(payload)))
+        .collect_vec();
+
+    let result = lookup_links(
+        request,
+        &source,
+        points,
+        collection_by_name,
+        read_consistency,
+        &ShardSelectorInternal::All,
+    )
+    .await
+    .unwrap();
+
+    // Point with nonexistent link is not in the result
+    assert_eq!(result.len(), 2);
+
+    let linked_ids = |point_id: u64| {
+        result[&PointIdType::from(point_id)]
+            .iter()
+            .map(|record| record.id)
+            .collect_vec()
+    };
+
+    assert_eq!(linked_ids(0), vec![PointIdType::from(5)]);
+    assert_eq!(
+        linked_ids(1),
+        vec![PointIdType::from(7), PointIdType::from(8)]
+    );
+    assert_eq!(
+        result[&PointIdType::from(0)][0].payload,
+        Some(Payload::from(json!({ "foo": "bar 5" })))
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn lookup_links_by_target_key() {
+    let Resources {
+        mut request,
+        collection,
+        read_consistency,
+        ..
+    } = setup().await;
+
+    let source = collection.read().await;
+    let target = collection.read().await;
+    let collection_by_name = |_: String| async { Some(target) };
+
+    // Link every point to the points with the same `foo` value, which is the point itself
+    request.link = Some(LookupLink {
+        key: "foo".to_string(),
+        target_key: Some("foo".to_string()),
+        limit: Some(5),
+    });
+
+    // Link values are not provided, so they should be read from the source collection
+    let points = (0..10u64)
+        .map(|i| (PointIdType::from(i), None))
+        .collect_vec();
+
+    let result = lookup_links(
+        request,
+        &source,
+        points,
+        collection_by_name,
+        read_consistency,
+        &ShardSelectorInternal::All,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result.len(), 10);
+
+    for (point_id, records) in result {
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].id, point_id);
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn lookup_links_requires_link() {
+    let Resources {
+        request,
+        collection,
+        read_consistency,
+        ..
+    } = setup().await;
+
+    let source = collection.read().await;
+    let collection_by_name = |_: String| async { None };
+
+    let result = lookup_links(
+        request,
+        &source,
+        vec![],
+        collection_by_name,
+        read_consistency,
+        &ShardSelectorInternal::All,
+    )
+    .await;
+
+    assert!(result.is_err());
+}
@@ -51,7 +51,8 @@ fn sparse_vector_index_build_benchmark(c: &mut Criterion) {
     let wrapped_payload_index = Arc::new(AtomicRefCell::new(payload_index));
 
     let db = open_db(storage_dir.path(), &[DB_VECTOR_CF]).unwrap();
-    let vector_storage = open_simple_sparse_vector_storage(db, DB_VECTOR_CF).unwrap();
+    let vector_storage =
+        open_simple_sparse_vector_storage(db, DB_VECTOR_CF, Default::default()).unwrap();
     let mut borrowed_storage = vector_storage.borrow_mut();
 
     // add points to storage only once
@@ -0,0 +1,191 @@
+//! Matching of keywords against prefix, wildcard and regex patterns of the `match` condition
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use regex::{Regex, RegexBuilder};
+use regex_syntax::hir::literal::{ExtractKind, Extractor};
+
+use crate::types::{Match, MatchPrefix, MatchRegex, MatchWildcard};
+
+/// Limit of the compiled pattern size, protects against patterns exploding into huge automata
+const REGEX_SIZE_LIMIT: usize = 1 << 20;
+
+/// Number of compiled patterns kept per thread for checks against plain payload
+const PATTERN_CACHE_SIZE: usize = 64;
+
+thread_local! {
+    static PATTERN_CACHE: RefCell<HashMap<String, Option<Regex>>> = RefCell::new(HashMap::new());
+}
+
+#[derive(Debug, Clone)]
+pub enum KeywordMatcher {
+    /// Keyword starts with the prefix
+    Prefix(String),
+    /// Whole keyword matches the compiled pattern, all matching keywords start with `prefix`
+    Pattern { regex: Regex, prefix: String },
+    /// Pattern can't be compiled, nothing matches
+    Invalid,
+}
+
+impl KeywordMatcher {
+    /// Build matcher for prefix, wildcard and regex conditions, `None` for other kinds of match
+    pub fn new(r#match: &Match) -> Option<Self> {
+        let matcher = match r#match {
+            Match::Prefix(MatchPrefix { prefix }) => KeywordMatcher::Prefix(prefix.clone()),
+            Match::Wildcard(MatchWildcard { wildcard }) => {
+                Self::compile(&wildcard_to_regex(wildcard))
+            }
+            Match::Regex(MatchRegex { regex }) => Self::compile(regex),
+            Match::Value(_) | Match::Text(_) | Match::Any(_) | Match::Except(_) => return None,
+        };
+        Some(matcher)
+    }
+
+    fn compile(pattern: &str) -> Self {
+        match compile_cached(pattern) {
+            Some(regex) => KeywordMatcher::Pattern {
+                prefix: literal_prefix(regex.as_str()),
+                regex,
+            },
+            None => KeywordMatcher::Invalid,
+        }
+    }
+
+    /// Literal prefix shared by all matching keywords, empty if unknown
+    pub fn literal_prefix(&self) -> &str {
+        match self {
+            KeywordMatcher::Prefix(prefix) => prefix,
+            KeywordMatcher::Pattern { prefix, .. } => prefix,
+            KeywordMatcher::Invalid => "",
+        }
+    }
+
+    pub fn is_match(&self, keyword: &str) -> bool {
+        match self {
+            KeywordMatcher::Prefix(prefix) => keyword.starts_with(prefix.as_str()),
+            KeywordMatcher::Pattern { regex, .. } => regex.is_match(keyword),
+            KeywordMatcher::Invalid => false,
+        }
+    }
+}
+
+/// Translate wildcard into an equivalent regular expression, all other characters are literal
+fn wildcard_to_regex(wildcard: &str) -> String {
+    // Wildcards match line breaks as any other character
+    let mut pattern = String::from("(?s)");
+    let mut buffer = [0; 4];
+    for c in wildcard.chars() {
+        match c {
+            '*' => pattern.push_str(".*"),
+            '?' => pattern.push('.'),
+            c => pattern.push_str(&regex::escape(c.encode_utf8(&mut buffer))),
+        }
+    }
+    pattern
+}
+
+/// Longest literal prefix of all strings matching the compiled `pattern`, empty if there is none
+fn literal_prefix(pattern: &str) -> String {
+    let Ok(hir) = regex_syntax::parse(pattern) else {
+        return String::new();
+    };
+    let prefixes = Extractor::new().kind(ExtractKind::Prefix).extract(&hir);
+    let prefix = prefixes.longest_common_prefix().unwrap_or_default();
+    // Alternatives may share only a part of a multibyte character
+    let valid_len = match std::str::from_utf8(prefix) {
+        Ok(_) => prefix.len(),
+        Err(err) => err.valid_up_to(),
+    };
+    String::from_utf8_lossy(&prefix[..valid_len]).into_owned()
+}
+
+/// Compile pattern anchored to the whole keyword, reusing patterns recently compiled by the current thread
+fn compile_cached(pattern: &str) -> Option<Regex> {
+    PATTERN_CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        if let Some(regex) = cache.get(pattern) {
+            return regex.clone();
+        }
+        let regex = RegexBuilder::new(&format!("^(?:{pattern})$"))
+            .size_limit(REGEX_SIZE_LIMIT)
+            .build()
+            .ok();
+        if cache.len() >= PATTERN_CACHE_SIZE {
+            cache.clear();
+        }
+        cache.insert(pattern.to_string(), regex.clone());
+        regex
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matcher(r#match: Match) -> KeywordMatcher {
+        KeywordMatcher::new(&r#match).unwrap()
+    }
+
+    #[test]
+    fn test_prefix() {
+        let prefix = matcher(Match::Prefix(MatchPrefix {
+            prefix: "ber".to_string(),
+        }));
+        assert!(prefix.is_match("berlin"));
+        assert!(prefix.is_match("ber"));
+        assert!(!prefix.is_match("Berlin"));
+        assert_eq!(prefix.literal_prefix(), "ber");
+    }
+
+    #[test]
+    fn test_wildcard() {
+        let wildcard = matcher(Match::Wildcard(MatchWildcard {
+            wildcard: "b?r*.de".to_string(),
+        }));
+        assert!(wildcard.is_match("bar.de"));
+        assert!(wildcard.is_match("berlin.de"));
+        assert!(wildcard.is_match("bur\nx.de"));
+        assert!(!wildcard.is_match("br.de"));
+        assert!(!wildcard.is_match("barxde"));
+        assert!(!wildcard.is_match("bar.de.com"));
+        assert_eq!(wildcard.literal_prefix(), "b");
+    }
+
+    #[test]
+    fn test_regex() {
+        let regex = matcher(Match::Regex(MatchRegex {
+            regex: "[a-c]+|x".to_string(),
+        }));
+        assert!(regex.is_match("abc"));
+        assert!(regex.is_match("x"));
+        // Pattern must match the whole keyword
+        assert!(!regex.is_match("abcx"));
+        assert!(!regex.is_match("xx"));
+        assert_eq!(regex.literal_prefix(), "");
+
+        let cases = [
+            ("berlin|bern", "ber"),
+            ("user_[0-9]+", "user_"),
+            ("ab(c|d)e", "ab"),
+            ("(?i)abc", ""),
+            ("a*bc", ""),
+            ("(é|è)x", ""),
+            ("ée?", "é"),
+        ];
+        for (pattern, prefix) in cases {
+            let regex = matcher(Match::Regex(MatchRegex {
+                regex: pattern.to_string(),
+            }));
+            assert_eq!(regex.literal_prefix(), prefix, "{pattern}");
+        }
+
+        let invalid = matcher(Match::Regex(MatchRegex {
+            regex: "(unclosed".to_string(),
+        }));
+        assert!(matches!(invalid, KeywordMatcher::Invalid));
+        assert!(!invalid.is_match("(unclosed"));
+
+        assert!(KeywordMatcher::new(&Match::Text("abc".to_string().into())).is_none());
+    }
+}
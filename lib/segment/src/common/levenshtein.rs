@@ -0,0 +1,98 @@
+//! Levenshtein automaton for fuzzy matching of full-text tokens
+
+/// Accepts all terms within the given Levenshtein edit distance of the query token.
+///
+/// The automaton state is a row of the edit distance matrix: the distance between the consumed
+/// prefix of the term and each prefix of the query. Terms are rejected as soon as no state
+/// can reach the accepting one.
+#[derive(Debug, Clone)]
+pub struct LevenshteinAutomaton {
+    query: Vec<char>,
+    max_distance: usize,
+}
+
+impl LevenshteinAutomaton {
+    pub fn new(query: &str, max_distance: u8) -> Self {
+        Self {
+            query: query.chars().collect(),
+            max_distance: max_distance as usize,
+        }
+    }
+
+    /// State before consuming any character of the term
+    pub fn start(&self) -> Vec<usize> {
+        (0..=self.query.len()).collect()
+    }
+
+    /// State after consuming the next character of the term
+    pub fn step(&self, state: &[usize], c: char) -> Vec<usize> {
+        let mut next = Vec::with_capacity(state.len());
+        next.push(state[0] + 1);
+        for (i, &query_char) in self.query.iter().enumerate() {
+            let substitution = state[i] + usize::from(query_char != c);
+            let insertion = state[i + 1] + 1;
+            let deletion = next[i] + 1;
+            next.push(substitution.min(insertion).min(deletion));
+        }
+        next
+    }
+
+    /// Whether any continuation of the consumed characters can be accepted
+    pub fn can_match(&self, state: &[usize]) -> bool {
+        state.iter().any(|&distance| distance <= self.max_distance)
+    }
+
+    /// Whether the consumed characters are within the distance of the query
+    pub fn is_accepting(&self, state: &[usize]) -> bool {
+        state[self.query.len()] <= self.max_distance
+    }
+
+    pub fn is_match(&self, term: &str) -> bool {
+        // Terms which differ in length by more than the distance can't match
+        let term_len = term.chars().count();
+        if term_len.abs_diff(self.query.len()) > self.max_distance {
+            return false;
+        }
+        let mut state = self.start();
+        for c in term.chars() {
+            state = self.step(&state, c);
+            if !self.can_match(&state) {
+                return false;
+            }
+        }
+        self.is_accepting(&state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_levenshtein_automaton() {
+        let exact = LevenshteinAutomaton::new("robot", 0);
+        assert!(exact.is_match("robot"));
+        assert!(!exact.is_match("robots"));
+
+        let one = LevenshteinAutomaton::new("robot", 1);
+        assert!(one.is_match("robot"));
+        assert!(one.is_match("robots")); // insertion
+        assert!(one.is_match("robt")); // deletion
+        assert!(one.is_match("rabot")); // substitution
+        assert!(!one.is_match("orbot")); // transposition is two edits
+        assert!(!one.is_match("rob"));
+        assert!(!one.is_match(""));
+
+        let two = LevenshteinAutomaton::new("robot", 2);
+        assert!(two.is_match("orbot"));
+        assert!(two.is_match("rob"));
+        assert!(!two.is_match("robotics"));
+        assert!(!two.is_match("abbot x"));
+
+        // Distance is counted in characters, not bytes
+        let unicode = LevenshteinAutomaton::new("straße", 1);
+        assert!(!unicode.is_match("strasse"));
+        assert!(unicode.is_match("strase"));
+        assert!(unicode.is_match("straßen"));
+    }
+}
@@ -2,6 +2,8 @@ pub mod anonymize;
 pub mod arc_atomic_ref_cell_iterator;
 pub mod cpu;
 pub mod error_logging;
+pub mod keyword_matcher;
+pub mod levenshtein;
 pub mod mmap_type;
 pub mod operation_error;
 pub mod operation_time_statistics;
@@ -16,6 +18,7 @@ use std::sync::atomic::AtomicBool;
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::data_types::named_vectors::NamedVectors;
 use crate::data_types::vectors::{QueryVector, VectorRef};
+use crate::spaces::binary::is_valid_binary;
 use crate::types::{SegmentConfig, SparseVectorDataConfig, VectorDataConfig};
 
 pub type Flusher = Box<dyn FnOnce() -> OperationResult<()> + Send>;
@@ -70,6 +73,11 @@ fn check_query_vector(
                 check_vector_against_config(vector.to_vec_ref(), vector_config)
             })?
         }
+        QueryVector::Feedback(feedback_query) => {
+            feedback_query.flat_iter().try_for_each(|vector| {
+                check_vector_against_config(vector.to_vec_ref(), vector_config)
+            })?
+        }
     }
 
     Ok(())
@@ -96,6 +104,11 @@ fn check_query_sparse_vector(
                 check_sparse_vector_against_config(vector.to_vec_ref(), vector_config)
             })?
         }
+        QueryVector::Feedback(feedback_query) => {
+            feedback_query.flat_iter().try_for_each(|vector| {
+                check_sparse_vector_against_config(vector.to_vec_ref(), vector_config)
+            })?
+        }
     }
 
     Ok(())
@@ -176,13 +189,22 @@ fn check_vector_against_config(
     match vector {
         VectorRef::Dense(vector) => {
             // Check dimensionality
-            let dim = vector_config.size;
+            let dim = vector_config.input_dim();
             if vector.len() != dim {
                 return Err(OperationError::WrongVector {
                     expected_dim: dim,
                     received_dim: vector.len(),
                 });
             }
+            // Binary vectors are provided as one byte per element
+            if vector_config.distance.is_binary() && !is_valid_binary(vector) {
+                return Err(OperationError::ValidationError {
+                    description: format!(
+                        "Binary vector for {:?} distance must consist of integer byte values in range 0..=255",
+                        vector_config.distance,
+                    ),
+                });
+            }
             Ok(())
         }
         VectorRef::Sparse(_) => Err(OperationError::WrongSparse),
@@ -0,0 +1,307 @@
+use std::collections::BTreeMap;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use validator::{Validate, ValidationError};
+
+use crate::types::PayloadContainer;
+
+/// Max number of histogram buckets, if not specified in the request
+pub const DEFAULT_HISTOGRAM_MAX_BUCKETS: usize = 1000;
+
+/// Fixed-width histogram buckets: bucket `i` covers `[offset + i * interval; offset + (i + 1) * interval)`
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, Copy, PartialEq)]
+#[validate(schema(function = "validate_histogram_params"))]
+#[serde(rename_all = "snake_case")]
+pub struct HistogramParams {
+    /// Width of each bucket, must be positive
+    pub interval: f64,
+    /// Shift of the bucket boundaries. Default: 0
+    #[serde(default)]
+    pub offset: f64,
+    /// Max number of buckets between the smallest and the largest value, including empty ones.
+    /// Requests with a wider range of values are rejected. Default: 1000
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_buckets: Option<usize>,
+}
+
+impl HistogramParams {
+    /// Index of the bucket containing `value`
+    pub fn bucket(&self, value: f64) -> i64 {
+        ((value - self.offset) / self.interval).floor() as i64
+    }
+
+    /// Lower and upper bounds of the bucket with the given index
+    pub fn bucket_bounds(&self, bucket: i64) -> (f64, f64) {
+        let from = self.offset + bucket as f64 * self.interval;
+        (from, from + self.interval)
+    }
+
+    pub fn max_buckets(&self) -> usize {
+        self.max_buckets.unwrap_or(DEFAULT_HISTOGRAM_MAX_BUCKETS)
+    }
+
+    /// Reject values from `min` to `max`, if they span more than `max_buckets` buckets
+    pub fn check_range(&self, min: f64, max: f64) -> Result<(), String> {
+        let buckets = i128::from(self.bucket(max)) - i128::from(self.bucket(min)) + 1;
+        let max_buckets = self.max_buckets();
+        if buckets > max_buckets as i128 {
+            return Err(format!(
+                "Values from {min} to {max} span {buckets} histogram buckets of width {}, \
+                 but at most {max_buckets} are allowed, increase the interval or max_buckets",
+                self.interval,
+            ));
+        }
+        Ok(())
+    }
+}
+
+pub fn validate_histogram_params(params: &HistogramParams) -> Result<(), ValidationError> {
+    if !(params.interval.is_finite() && params.interval > 0.0 && params.offset.is_finite()) {
+        return Err(ValidationError::new(
+            "histogram interval must be positive and offset must be finite",
+        ));
+    }
+    if params.max_buckets == Some(0) {
+        return Err(ValidationError::new(
+            "histogram max_buckets must be positive",
+        ));
+    }
+    Ok(())
+}
+
+/// Exact summary of numeric values, can be merged across segments and shards
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct NumericAggregation {
+    /// Number of aggregated values
+    pub count: usize,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    /// Exact sum of the values without a fractional part
+    pub integer_sum: i128,
+    /// Sum of the values with a fractional part
+    pub float_sum: f64,
+    /// Number of values per bucket index, only non-empty buckets are stored
+    pub histogram: BTreeMap<i64, usize>,
+}
+
+impl NumericAggregation {
+    pub fn add(&mut self, value: f64, histogram: Option<&HistogramParams>) {
+        // Integral floats are exact in i64, if they fit, e.g. integers from a float index
+        if value.fract() == 0.0 && value.abs() < i64::MAX as f64 {
+            self.add_integer(value as i64, histogram);
+        } else {
+            self.add_value(value, histogram);
+            self.float_sum += value;
+        }
+    }
+
+    pub fn add_integer(&mut self, value: i64, histogram: Option<&HistogramParams>) {
+        self.add_value(value as f64, histogram);
+        self.integer_sum += i128::from(value);
+    }
+
+    /// Count the value in all statistics except the sum
+    fn add_value(&mut self, value: f64, histogram: Option<&HistogramParams>) {
+        self.count += 1;
+        self.min = Some(self.min.map_or(value, |min| min.min(value)));
+        self.max = Some(self.max.map_or(value, |max| max.max(value)));
+        if let Some(params) = histogram {
+            *self.histogram.entry(params.bucket(value)).or_insert(0) += 1;
+        }
+    }
+
+    /// Add all numeric values stored in the payload under `key`
+    pub fn add_payload(
+        &mut self,
+        payload: &impl PayloadContainer,
+        key: &str,
+        histogram: Option<&HistogramParams>,
+    ) {
+        let values = payload
+            .get_value(key)
+            .into_iter()
+            .flat_map(|value| match value {
+                Value::Array(values) => values.iter().collect(),
+                _ => vec![value],
+            })
+            .filter(|value| value.is_number());
+        for value in values {
+            match value.as_i64() {
+                Some(value) => self.add_integer(value, histogram),
+                None => self.add(value.as_f64().unwrap_or_default(), histogram),
+            }
+        }
+    }
+
+    pub fn merge(&mut self, other: NumericAggregation) {
+        self.count += other.count;
+        self.min = match (self.min, other.min) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        self.max = match (self.max, other.max) {
+            (Some(a), Some(b)) => Some(a.max(b)),
+            (a, b) => a.or(b),
+        };
+        self.integer_sum += other.integer_sum;
+        self.float_sum += other.float_sum;
+        for (bucket, count) in other.histogram {
+            *self.histogram.entry(bucket).or_insert(0) += count;
+        }
+    }
+
+    pub fn sum(&self) -> f64 {
+        self.integer_sum as f64 + self.float_sum
+    }
+
+    pub fn avg(&self) -> Option<f64> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.sum() / self.count as f64)
+        }
+    }
+
+    /// Reject the histogram, if the aggregated values span too many buckets
+    pub fn check_histogram(&self, histogram: Option<&HistogramParams>) -> Result<(), String> {
+        match (histogram, self.min, self.max) {
+            (Some(params), Some(min), Some(max)) => params.check_range(min, max),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::types::Payload;
+
+    #[test]
+    fn test_histogram_buckets() {
+        let params = HistogramParams {
+            interval: 10.0,
+            offset: 5.0,
+            max_buckets: None,
+        };
+        assert_eq!(params.bucket(5.0), 0);
+        assert_eq!(params.bucket(14.9), 0);
+        assert_eq!(params.bucket(15.0), 1);
+        assert_eq!(params.bucket(4.9), -1);
+        assert_eq!(params.bucket_bounds(-1), (-5.0, 5.0));
+
+        assert!(params.validate().is_ok());
+        let params = HistogramParams {
+            interval: 0.0,
+            offset: 0.0,
+            max_buckets: None,
+        };
+        assert!(params.validate().is_err());
+        let params = HistogramParams {
+            interval: 1.0,
+            offset: 0.0,
+            max_buckets: Some(0),
+        };
+        assert!(params.validate().is_err());
+    }
+
+    #[test]
+    fn test_histogram_max_buckets() {
+        let params = HistogramParams {
+            interval: 1.0,
+            offset: 0.0,
+            max_buckets: Some(3),
+        };
+        assert!(params.check_range(0.0, 2.5).is_ok());
+        assert!(params.check_range(0.0, 3.0).is_err());
+        assert!(params.check_range(f64::MIN, f64::MAX).is_err());
+
+        let mut aggregation = NumericAggregation::default();
+        assert!(aggregation.check_histogram(Some(&params)).is_ok());
+        aggregation.add(-1.0, Some(&params));
+        aggregation.add(1.5, Some(&params));
+        assert!(aggregation.check_histogram(Some(&params)).is_ok());
+        aggregation.add(2.0, Some(&params));
+        assert!(aggregation.check_histogram(Some(&params)).is_err());
+        assert!(aggregation.check_histogram(None).is_ok());
+
+        let params = HistogramParams {
+            max_buckets: None,
+            ..params
+        };
+        assert!(params.check_range(0.0, 999.0).is_ok());
+        assert!(params.check_range(0.0, 1000.0).is_err());
+    }
+
+    #[test]
+    fn test_integer_sum_is_exact() {
+        let large = 1 << 60;
+        let mut aggregation = NumericAggregation::default();
+        aggregation.add_integer(large, None);
+        aggregation.add_integer(1, None);
+        aggregation.add_integer(i64::MAX, None);
+        aggregation.add_integer(i64::MAX, None);
+        aggregation.add(2.0, None);
+        aggregation.add(0.5, None);
+
+        assert_eq!(
+            aggregation.integer_sum,
+            i128::from(large) + 3 + 2 * i128::from(i64::MAX)
+        );
+        assert_eq!(aggregation.float_sum, 0.5);
+        assert_eq!(aggregation.count, 6);
+    }
+
+    #[test]
+    fn test_merge_matches_single_pass() {
+        let params = HistogramParams {
+            interval: 1.0,
+            offset: 0.0,
+            max_buckets: None,
+        };
+        let values = [3.5, -1.0, 2.0, 7.25, 2.5, 0.0];
+
+        let mut expected = NumericAggregation::default();
+        values.iter().for_each(|v| expected.add(*v, Some(&params)));
+
+        let mut left = NumericAggregation::default();
+        let mut right = NumericAggregation::default();
+        values[..2].iter().for_each(|v| left.add(*v, Some(&params)));
+        values[2..]
+            .iter()
+            .for_each(|v| right.add(*v, Some(&params)));
+        left.merge(right);
+        left.merge(NumericAggregation::default());
+
+        assert_eq!(left, expected);
+        assert_eq!(left.count, 6);
+        assert_eq!(left.min, Some(-1.0));
+        assert_eq!(left.max, Some(7.25));
+        assert_eq!(left.avg(), Some(14.25 / 6.0));
+        assert_eq!(left.histogram.get(&2), Some(&2));
+        assert_eq!(NumericAggregation::default().avg(), None);
+    }
+
+    #[test]
+    fn test_add_payload() {
+        let payload: Payload = json!({
+            "price": [10, 20.5, "thirty"],
+            "nested": {"rating": 4},
+        })
+        .into();
+
+        let mut aggregation = NumericAggregation::default();
+        aggregation.add_payload(&payload, "price", None);
+        aggregation.add_payload(&payload, "nested.rating", None);
+        aggregation.add_payload(&payload, "missing", None);
+
+        assert_eq!(aggregation.count, 3);
+        assert_eq!(aggregation.integer_sum, 14);
+        assert_eq!(aggregation.float_sum, 20.5);
+        assert_eq!(aggregation.sum(), 34.5);
+        assert!(aggregation.histogram.is_empty());
+    }
+}
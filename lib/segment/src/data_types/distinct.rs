@@ -0,0 +1,299 @@
+use std::collections::HashSet;
+
+use serde_json::Value;
+
+use crate::types::{PayloadContainer, ValueVariants};
+
+/// Number of index bits of the HyperLogLog sketch, gives ~0.8% standard error
+pub const HYPERLOGLOG_PRECISION: u32 = 14;
+
+const HYPERLOGLOG_REGISTERS: usize = 1 << HYPERLOGLOG_PRECISION;
+
+/// Approximate distinct counting switches from the exact set of values to a sketch beyond this size,
+/// exact distinct counting fails beyond it
+pub const EXACT_DISTINCT_THRESHOLD: usize = 4096;
+
+/// HyperLogLog cardinality sketch over 64-bit hashes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HyperLogLog {
+    registers: Vec<u8>,
+}
+
+impl Default for HyperLogLog {
+    fn default() -> Self {
+        Self {
+            registers: vec![0; HYPERLOGLOG_REGISTERS],
+        }
+    }
+}
+
+impl HyperLogLog {
+    /// Restore the sketch from registers, returns `None` if the number of registers doesn't match
+    pub fn from_registers(registers: Vec<u8>) -> Option<Self> {
+        (registers.len() == HYPERLOGLOG_REGISTERS).then_some(Self { registers })
+    }
+
+    pub fn registers(&self) -> &[u8] {
+        &self.registers
+    }
+
+    pub fn add_hash(&mut self, hash: u64) {
+        let index = (hash >> (64 - HYPERLOGLOG_PRECISION)) as usize;
+        // Sentinel bit bounds the rank if all remaining bits are zero
+        let remaining = (hash << HYPERLOGLOG_PRECISION) | (1 << (HYPERLOGLOG_PRECISION - 1));
+        let rank = remaining.leading_zeros() as u8 + 1;
+        self.registers[index] = self.registers[index].max(rank);
+    }
+
+    pub fn merge(&mut self, other: &HyperLogLog) {
+        for (register, other) in self.registers.iter_mut().zip(&other.registers) {
+            *register = (*register).max(*other);
+        }
+    }
+
+    pub fn estimate(&self) -> usize {
+        let m = HYPERLOGLOG_REGISTERS as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let sum: f64 = self
+            .registers
+            .iter()
+            .map(|register| 2f64.powi(-(*register as i32)))
+            .sum();
+        let raw_estimate = alpha * m * m / sum;
+
+        let zeros = self
+            .registers
+            .iter()
+            .filter(|register| **register == 0)
+            .count();
+        if raw_estimate <= 2.5 * m && zeros > 0 {
+            // Linear counting is more precise for small cardinalities
+            (m * (m / zeros as f64).ln()).round() as usize
+        } else {
+            raw_estimate.round() as usize
+        }
+    }
+}
+
+/// Stable hash of the value, must be the same on all peers of the cluster
+pub fn hash_value(value: &ValueVariants) -> u64 {
+    match value {
+        ValueVariants::Keyword(keyword) => {
+            seahash::hash(&[b"k".as_slice(), keyword.as_bytes()].concat())
+        }
+        ValueVariants::Integer(integer) => {
+            seahash::hash(&[b"i".as_slice(), &integer.to_le_bytes()].concat())
+        }
+        ValueVariants::Bool(boolean) => seahash::hash(&[b'b', *boolean as u8]),
+    }
+}
+
+/// Distinct values of a payload field, can be merged across segments and shards
+#[derive(Debug, Clone, PartialEq)]
+pub enum DistinctCounter {
+    /// Exact set of distinct values
+    Values(HashSet<ValueVariants>),
+    /// Approximate sketch of distinct values
+    Sketch(HyperLogLog),
+}
+
+impl Default for DistinctCounter {
+    fn default() -> Self {
+        DistinctCounter::Values(HashSet::new())
+    }
+}
+
+impl DistinctCounter {
+    /// Add a value. If not `exact`, large sets of values are converted into a sketch,
+    /// otherwise they are rejected.
+    pub fn add(&mut self, value: ValueVariants, exact: bool) -> Result<(), String> {
+        match self {
+            DistinctCounter::Values(values) => {
+                values.insert(value);
+                self.compact(exact)
+            }
+            DistinctCounter::Sketch(sketch) => {
+                sketch.add_hash(hash_value(&value));
+                Ok(())
+            }
+        }
+    }
+
+    /// Add all keyword and integer values stored in the payload under `key`
+    pub fn add_payload(
+        &mut self,
+        payload: &impl PayloadContainer,
+        key: &str,
+        exact: bool,
+    ) -> Result<(), String> {
+        let values = payload
+            .get_value(key)
+            .into_iter()
+            .flat_map(|value| match value {
+                Value::Array(values) => values.iter().collect(),
+                _ => vec![value],
+            })
+            .filter_map(|value| match value {
+                Value::String(keyword) => Some(ValueVariants::Keyword(keyword.clone())),
+                Value::Number(number) => number.as_i64().map(ValueVariants::Integer),
+                _ => None,
+            });
+        for value in values {
+            self.add(value, exact)?;
+        }
+        Ok(())
+    }
+
+    pub fn merge(&mut self, other: DistinctCounter, exact: bool) -> Result<(), String> {
+        match other {
+            DistinctCounter::Values(other_values) => match self {
+                DistinctCounter::Values(values) => {
+                    values.extend(other_values);
+                    return self.compact(exact);
+                }
+                DistinctCounter::Sketch(sketch) => {
+                    other_values
+                        .iter()
+                        .for_each(|value| sketch.add_hash(hash_value(value)));
+                }
+            },
+            DistinctCounter::Sketch(mut other_sketch) => match self {
+                DistinctCounter::Values(values) => {
+                    values
+                        .iter()
+                        .for_each(|value| other_sketch.add_hash(hash_value(value)));
+                    *self = DistinctCounter::Sketch(other_sketch);
+                }
+                DistinctCounter::Sketch(sketch) => sketch.merge(&other_sketch),
+            },
+        }
+        Ok(())
+    }
+
+    /// Number of distinct values, approximate if the values were converted into a sketch
+    pub fn count(&self) -> usize {
+        match self {
+            DistinctCounter::Values(values) => values.len(),
+            DistinctCounter::Sketch(sketch) => sketch.estimate(),
+        }
+    }
+
+    fn compact(&mut self, exact: bool) -> Result<(), String> {
+        let DistinctCounter::Values(values) = self else {
+            return Ok(());
+        };
+        if values.len() <= EXACT_DISTINCT_THRESHOLD {
+            return Ok(());
+        }
+        if exact {
+            return Err(format!(
+                "Exact count of distinct values is limited to {EXACT_DISTINCT_THRESHOLD}, \
+                 set `exact` to false to estimate larger numbers of distinct values",
+            ));
+        }
+        let mut sketch = HyperLogLog::default();
+        values
+            .iter()
+            .for_each(|value| sketch.add_hash(hash_value(value)));
+        *self = DistinctCounter::Sketch(sketch);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::types::Payload;
+
+    #[test]
+    fn test_hyperloglog_estimate() {
+        for cardinality in [0, 10, 1_000, 100_000] {
+            let mut sketch = HyperLogLog::default();
+            for i in 0..cardinality {
+                // Duplicates don't change the estimate
+                sketch.add_hash(hash_value(&ValueVariants::Integer(i)));
+                sketch.add_hash(hash_value(&ValueVariants::Integer(i)));
+            }
+            let error = (sketch.estimate() as f64 - cardinality as f64).abs();
+            assert!(
+                error <= 0.03 * cardinality as f64,
+                "cardinality {cardinality}, estimate {}",
+                sketch.estimate(),
+            );
+        }
+    }
+
+    #[test]
+    fn test_merge_exact_and_sketch() {
+        let mut left = DistinctCounter::default();
+        let mut right = DistinctCounter::default();
+        for i in 0..3000 {
+            left.add(ValueVariants::Integer(i), false).unwrap();
+            right.add(ValueVariants::Integer(i + 2000), false).unwrap();
+        }
+        assert!(matches!(left, DistinctCounter::Values(_)));
+
+        let mut exact = left.clone();
+        exact.merge(right.clone(), true).unwrap_err();
+
+        left.merge(right, false).unwrap();
+        assert!(matches!(left, DistinctCounter::Sketch(_)));
+        assert!((left.count() as f64 - 5000.0).abs() < 150.0);
+
+        let mut small = DistinctCounter::default();
+        small
+            .add(ValueVariants::Keyword("a".to_string()), false)
+            .unwrap();
+        small.merge(left.clone(), false).unwrap();
+        assert!(matches!(small, DistinctCounter::Sketch(_)));
+    }
+
+    #[test]
+    fn test_exact_limit() {
+        let mut counter = DistinctCounter::default();
+        for i in 0..EXACT_DISTINCT_THRESHOLD as i64 {
+            counter.add(ValueVariants::Integer(i), true).unwrap();
+            // Duplicates don't count towards the limit
+            counter.add(ValueVariants::Integer(i), true).unwrap();
+        }
+        assert_eq!(counter.count(), EXACT_DISTINCT_THRESHOLD);
+
+        let err = counter
+            .clone()
+            .add(ValueVariants::Integer(-1), true)
+            .unwrap_err();
+        assert!(err.contains("`exact`"));
+
+        let mut other = DistinctCounter::default();
+        other
+            .add(ValueVariants::Keyword("a".to_string()), true)
+            .unwrap();
+        counter.clone().merge(other.clone(), true).unwrap_err();
+        counter.merge(other, false).unwrap();
+        assert!(matches!(counter, DistinctCounter::Sketch(_)));
+    }
+
+    #[test]
+    fn test_add_payload() {
+        let payload: Payload = json!({
+            "user": ["alice", "bob", "alice", 7, 7.5, true, "7"],
+        })
+        .into();
+
+        let mut counter = DistinctCounter::default();
+        counter.add_payload(&payload, "user", true).unwrap();
+        // "alice", "bob", 7 and "7" are distinct, floats and bools are skipped
+        assert_eq!(counter.count(), 4);
+    }
+
+    #[test]
+    fn test_registers_roundtrip() {
+        let mut sketch = HyperLogLog::default();
+        sketch.add_hash(hash_value(&ValueVariants::Keyword("a".to_string())));
+        let restored = HyperLogLog::from_registers(sketch.registers().to_vec()).unwrap();
+        assert_eq!(restored, sketch);
+        assert!(HyperLogLog::from_registers(vec![0; 10]).is_none());
+    }
+}
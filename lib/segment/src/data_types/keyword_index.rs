@@ -0,0 +1,175 @@
+use std::borrow::Cow;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use unicode_normalization::{is_nfkc, UnicodeNormalization};
+
+use crate::types::{
+    AnyVariants, Match, MatchAny, MatchExcept, MatchPrefix, MatchValue, MatchWildcard,
+    ValueVariants,
+};
+
+#[derive(Default, Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Hash, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum KeywordIndexType {
+    #[default]
+    Keyword,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Hash, Eq)]
+#[serde(rename_all = "snake_case")]
+pub struct KeywordIndexParams {
+    // Required for OpenAPI pattern matching
+    pub r#type: KeywordIndexType,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// If true, lowercase indexed keywords and keywords of match conditions. Default: false
+    pub lowercase: Option<bool>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// If true, apply Unicode NFKC normalization to indexed keywords and keywords of match conditions. Default: false
+    pub nfkc: Option<bool>,
+}
+
+impl KeywordIndexParams {
+    /// Whether keywords are modified before indexing
+    pub fn is_normalized(&self) -> bool {
+        self.lowercase.unwrap_or(false) || self.nfkc.unwrap_or(false)
+    }
+
+    /// Normalize keyword, NFKC is applied before lowercasing
+    pub fn normalize<'a>(&self, keyword: &'a str) -> Cow<'a, str> {
+        let mut keyword = Cow::Borrowed(keyword);
+        if self.nfkc.unwrap_or(false) && !is_nfkc(&keyword) {
+            keyword = Cow::Owned(keyword.nfkc().collect());
+        }
+        if self.lowercase.unwrap_or(false) && keyword.chars().any(|c| !c.is_lowercase()) {
+            let lowercase = keyword.to_lowercase();
+            if lowercase != keyword {
+                keyword = Cow::Owned(lowercase);
+            }
+        }
+        keyword
+    }
+
+    /// Normalize all strings of the payload value, including strings nested into arrays
+    pub fn normalize_value<'a>(&self, value: &'a Value) -> Cow<'a, Value> {
+        match value {
+            Value::String(keyword) => match self.normalize(keyword) {
+                Cow::Borrowed(_) => Cow::Borrowed(value),
+                Cow::Owned(keyword) => Cow::Owned(Value::String(keyword)),
+            },
+            Value::Array(values) => Cow::Owned(Value::Array(
+                values
+                    .iter()
+                    .map(|value| self.normalize_value(value).into_owned())
+                    .collect(),
+            )),
+            _ => Cow::Borrowed(value),
+        }
+    }
+
+    /// Normalize keywords of the match condition.
+    /// Regular expressions are kept as is, as they may rely on character classes.
+    pub fn normalize_match(&self, r#match: Match) -> Match {
+        if !self.is_normalized() {
+            return r#match;
+        }
+        let normalize = |keyword: String| self.normalize(&keyword).into_owned();
+        let normalize_all = |keywords: Vec<String>| -> Vec<String> {
+            keywords.into_iter().map(normalize).collect()
+        };
+        match r#match {
+            Match::Value(MatchValue {
+                value: ValueVariants::Keyword(keyword),
+            }) => Match::Value(MatchValue {
+                value: ValueVariants::Keyword(normalize(keyword)),
+            }),
+            Match::Any(MatchAny {
+                any: AnyVariants::Keywords(keywords),
+            }) => Match::Any(MatchAny {
+                any: AnyVariants::Keywords(normalize_all(keywords)),
+            }),
+            Match::Except(MatchExcept {
+                except: AnyVariants::Keywords(keywords),
+            }) => Match::Except(MatchExcept {
+                except: AnyVariants::Keywords(normalize_all(keywords)),
+            }),
+            Match::Prefix(MatchPrefix { prefix }) => Match::Prefix(MatchPrefix {
+                prefix: normalize(prefix),
+            }),
+            Match::Wildcard(MatchWildcard { wildcard }) => Match::Wildcard(MatchWildcard {
+                wildcard: normalize(wildcard),
+            }),
+            r#match @ (Match::Value(_)
+            | Match::Any(_)
+            | Match::Except(_)
+            | Match::Text(_)
+            | Match::Regex(_)) => r#match,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::types::MatchRegex;
+
+    fn params(lowercase: bool, nfkc: bool) -> KeywordIndexParams {
+        KeywordIndexParams {
+            r#type: KeywordIndexType::Keyword,
+            lowercase: Some(lowercase),
+            nfkc: Some(nfkc),
+        }
+    }
+
+    #[test]
+    fn test_normalize_keyword() {
+        assert_eq!(
+            params(false, false).normalize("ＢＥＲＬＩＮ"),
+            "ＢＥＲＬＩＮ"
+        );
+        assert_eq!(
+            params(true, false).normalize("ＢＥＲＬＩＮ"),
+            "ｂｅｒｌｉｎ"
+        );
+        assert_eq!(params(false, true).normalize("ＢＥＲＬＩＮ"), "BERLIN");
+        assert_eq!(params(true, true).normalize("ＢＥＲＬＩＮ"), "berlin");
+        // Ligatures and composed characters
+        assert_eq!(params(false, true).normalize("ﬁle"), "file");
+        assert_eq!(params(true, true).normalize("Cafe\u{301}"), "café");
+        assert!(matches!(
+            params(true, true).normalize("berlin"),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn test_normalize_value_and_match() {
+        let params = params(true, true);
+        assert_eq!(
+            params
+                .normalize_value(&json!(["Berlin", 1, "ＬＯＮＤＯＮ"]))
+                .into_owned(),
+            json!(["berlin", 1, "london"])
+        );
+
+        let normalized = params.normalize_match(Match::Any(MatchAny {
+            any: AnyVariants::Keywords(vec!["Berlin".to_string(), "ＬＯＮＤＯＮ".to_string()]),
+        }));
+        assert_eq!(
+            normalized,
+            Match::Any(MatchAny {
+                any: AnyVariants::Keywords(vec!["berlin".to_string(), "london".to_string()]),
+            })
+        );
+
+        let regex = Match::Regex(MatchRegex {
+            regex: "[A-Z]+".to_string(),
+        });
+        assert_eq!(params.normalize_match(regex.clone()), regex);
+    }
+}
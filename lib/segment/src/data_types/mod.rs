@@ -1,4 +1,7 @@
+pub mod aggregation;
+pub mod distinct;
 pub mod groups;
+pub mod keyword_index;
 pub mod named_vectors;
 pub mod text_index;
 pub mod tiny_map;
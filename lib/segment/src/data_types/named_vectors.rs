@@ -6,7 +6,7 @@ use sparse::common::sparse_vector::SparseVector;
 use super::tiny_map;
 use super::vectors::{DenseVector, Vector, VectorElementType, VectorRef};
 use crate::common::operation_error::OperationError;
-use crate::types::Distance;
+use crate::types::SegmentConfig;
 
 type CowKey<'a> = Cow<'a, str>;
 
@@ -200,20 +200,25 @@ impl<'a> NamedVectors<'a> {
         self.map.get(key).map(|v| v.as_vec_ref())
     }
 
-    pub fn preprocess<F>(&mut self, distance_map: F)
-    where
-        F: Fn(&str) -> Distance,
-    {
+    /// Preprocess vectors according to the segment config
+    ///
+    /// Expects all vectors to be present in the config.
+    pub fn preprocess(&mut self, config: &SegmentConfig) {
         for (name, vector) in self.map.iter_mut() {
-            let distance = distance_map(name);
             match vector {
                 CowVector::Dense(v) => {
+                    let distance = config.vector_data[name.as_ref()].distance;
                     let preprocessed_vector = distance.preprocess_vector(v.to_vec());
                     *vector = CowVector::Dense(Cow::Owned(preprocessed_vector))
                 }
                 CowVector::Sparse(v) => {
+                    let v = v.to_mut();
                     // sort by indices to enable faster dot product and overlap checks
-                    v.to_mut().sort_by_indices();
+                    v.sort_by_indices();
+                    // round weights to the stored datatype, so indexed and stored weights are equal
+                    config.sparse_vector_data[name.as_ref()]
+                        .datatype
+                        .round(&mut v.values);
                 }
             }
         }
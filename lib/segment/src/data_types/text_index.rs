@@ -1,6 +1,15 @@
+use std::collections::HashMap;
+
+use common::types::ScoreType;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+/// BM25 term frequency saturation parameter
+pub const BM25_K1: ScoreType = 1.2;
+
+/// BM25 document length normalization parameter
+pub const BM25_B: ScoreType = 0.75;
+
 // #[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Hash, Eq)]
 // #[serde(rename_all = "snake_case")]
 // pub enum TextFieldType {
@@ -42,3 +51,65 @@ pub struct TextIndexParams {
     /// If true, lowercase all tokens. Default: true
     pub lowercase: Option<bool>,
 }
+
+/// Statistics of a full-text indexed field required for BM25 scoring of a query
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TextStats {
+    /// Number of indexed documents
+    pub documents_count: usize,
+    /// Total number of tokens in all indexed documents
+    pub tokens_count: usize,
+    /// Number of documents containing each of the query tokens
+    pub document_frequencies: HashMap<String, usize>,
+}
+
+impl TextStats {
+    /// Combine statistics of disjoint sets of documents
+    pub fn merge(&mut self, other: TextStats) {
+        self.documents_count += other.documents_count;
+        self.tokens_count += other.tokens_count;
+        for (token, frequency) in other.document_frequencies {
+            *self.document_frequencies.entry(token).or_default() += frequency;
+        }
+    }
+
+    pub fn avg_tokens_count(&self) -> ScoreType {
+        if self.documents_count == 0 {
+            return 0.0;
+        }
+        self.tokens_count as ScoreType / self.documents_count as ScoreType
+    }
+
+    /// Inverse document frequency of the token, always positive
+    pub fn idf(&self, token: &str) -> ScoreType {
+        let documents_count = self.documents_count as ScoreType;
+        let document_frequency = self
+            .document_frequencies
+            .get(token)
+            .copied()
+            .unwrap_or_default() as ScoreType;
+        (1.0 + (documents_count - document_frequency + 0.5) / (document_frequency + 0.5)).ln()
+    }
+}
+
+/// BM25 score of a single query token for a document
+///
+/// * `idf` - inverse document frequency of the token
+/// * `frequency` - number of occurrences of the token in the document
+/// * `tokens_count` - length of the document in tokens
+/// * `avg_tokens_count` - average length of the documents in tokens
+pub fn bm25_token_score(
+    idf: ScoreType,
+    frequency: u32,
+    tokens_count: usize,
+    avg_tokens_count: ScoreType,
+) -> ScoreType {
+    let frequency = frequency as ScoreType;
+    let length_ratio = if avg_tokens_count > 0.0 {
+        tokens_count as ScoreType / avg_tokens_count
+    } else {
+        1.0
+    };
+    let normalization = BM25_K1 * (1.0 - BM25_B + BM25_B * length_ratio);
+    idf * frequency * (BM25_K1 + 1.0) / (frequency + normalization)
+}
@@ -10,6 +10,7 @@ use crate::common::operation_error::OperationError;
 use crate::common::utils::transpose_map_into_named_vector;
 use crate::vector_storage::query::context_query::ContextQuery;
 use crate::vector_storage::query::discovery_query::DiscoveryQuery;
+use crate::vector_storage::query::feedback_query::FeedbackQuery;
 use crate::vector_storage::query::reco_query::RecoQuery;
 
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize, JsonSchema)]
@@ -213,6 +214,16 @@ pub enum VectorStruct {
 }
 
 impl VectorStruct {
+    /// Keep only named vectors, for which `keep` returns `true`.
+    ///
+    /// Single vector has no name and is always kept.
+    pub fn retain(&mut self, keep: impl Fn(&str) -> bool) {
+        match self {
+            VectorStruct::Single(_) => {}
+            VectorStruct::Multi(vectors) => vectors.retain(|vector_name, _| keep(vector_name)),
+        }
+    }
+
     /// Check if this vector struct is empty.
     pub fn is_empty(&self) -> bool {
         match self {
@@ -449,6 +460,7 @@ pub enum QueryVector {
     Recommend(RecoQuery<Vector>),
     Discovery(DiscoveryQuery<Vector>),
     Context(ContextQuery<Vector>),
+    Feedback(FeedbackQuery<Vector>),
 }
 
 impl From<DenseVector> for QueryVector {
@@ -3,12 +3,15 @@ use std::path::{Path, PathBuf};
 use std::sync::atomic::AtomicBool;
 
 use crate::common::operation_error::{OperationResult, SegmentFailedState};
+use crate::data_types::aggregation::{HistogramParams, NumericAggregation};
+use crate::data_types::distinct::DistinctCounter;
 use crate::data_types::named_vectors::NamedVectors;
+use crate::data_types::text_index::TextStats;
 use crate::data_types::vectors::{QueryVector, Vector};
 use crate::index::field_index::CardinalityEstimation;
 use crate::telemetry::SegmentTelemetry;
 use crate::types::{
-    Filter, Payload, PayloadFieldSchema, PayloadKeyType, PayloadKeyTypeRef, PointIdType,
+    Filter, OrderBy, Payload, PayloadFieldSchema, PayloadKeyType, PayloadKeyTypeRef, PointIdType,
     ScoredPoint, SearchParams, SegmentConfig, SegmentInfo, SegmentType, SeqNumberType, WithPayload,
     WithVector,
 };
@@ -121,6 +124,57 @@ pub trait SegmentEntry {
         filter: Option<&'a Filter>,
     ) -> Vec<PointIdType>;
 
+    /// Read up to `limit` points which satisfies filtering condition, ordered by `order_by`.
+    ///
+    /// Starts from the `order_by` start value, ties at it are skipped up to the `offset` id.
+    /// Returns the ordering value together with the id of each point.
+    fn read_ordered_filtered<'a>(
+        &'a self,
+        offset: Option<PointIdType>,
+        limit: usize,
+        filter: Option<&'a Filter>,
+        order_by: &'a OrderBy,
+    ) -> OperationResult<Vec<(f64, PointIdType)>>;
+
+    /// Aggregate numeric values of the payload field `key` of all points which satisfy filtering condition.
+    fn aggregate_filtered<'a>(
+        &'a self,
+        filter: Option<&'a Filter>,
+        key: PayloadKeyTypeRef,
+        histogram: Option<&'a HistogramParams>,
+    ) -> OperationResult<NumericAggregation>;
+
+    /// Collect distinct keyword and integer values of the payload field `key` of all points which satisfy filtering condition.
+    ///
+    /// If not `exact`, large sets of values are collected into an approximate sketch,
+    /// otherwise they are rejected.
+    fn distinct_filtered<'a>(
+        &'a self,
+        filter: Option<&'a Filter>,
+        key: PayloadKeyTypeRef,
+        exact: bool,
+    ) -> OperationResult<DistinctCounter>;
+
+    /// Statistics of the full-text index of the payload field `key` required to score the `query` with BM25.
+    ///
+    /// Returns empty statistics if the field has no full-text index.
+    fn text_stats(&self, key: PayloadKeyTypeRef, query: &str) -> OperationResult<TextStats>;
+
+    /// Search for the `top` points matching the `query` by the full-text index of the payload field `key`.
+    ///
+    /// Points are scored with BM25 using `stats`, which are expected to be merged across all searched segments.
+    #[allow(clippy::too_many_arguments)]
+    fn search_text(
+        &self,
+        key: PayloadKeyTypeRef,
+        query: &str,
+        stats: &TextStats,
+        filter: Option<&Filter>,
+        top: usize,
+        with_payload: &WithPayload,
+        with_vector: &WithVector,
+    ) -> OperationResult<Vec<ScoredPoint>>;
+
     /// Read points in [from; to) range
     fn read_range(&self, from: Option<PointIdType>, to: Option<PointIdType>) -> Vec<PointIdType>;
 
@@ -184,6 +238,9 @@ pub trait SegmentEntry {
     /// Get indexed fields
     fn get_indexed_fields(&self) -> HashMap<PayloadKeyType, PayloadFieldSchema>;
 
+    /// Find the first field of the filter, which conditions can't be served by the payload index
+    fn unindexed_filter_key(&self, filter: &Filter) -> Option<PayloadKeyType>;
+
     /// Checks if segment errored during last operations
     fn check_error(&self) -> Option<SegmentFailedState>;
 
@@ -1,7 +1,7 @@
 use common::types::PointOffsetType;
 use serde_json::Value;
-use smol_str::SmolStr;
 
+use super::map_index::keyword_index::KeywordIndex;
 use super::map_index::MapIndex;
 use crate::common::operation_error::OperationResult;
 use crate::common::utils::MultiValue;
@@ -12,9 +12,10 @@ use crate::index::field_index::geo_index::GeoMapIndex;
 use crate::index::field_index::numeric_index::NumericIndex;
 use crate::index::field_index::{CardinalityEstimation, PayloadBlockCondition};
 use crate::telemetry::PayloadIndexTelemetry;
-use crate::types::{
-    FieldCondition, FloatPayloadType, IntPayloadType, Match, MatchText, PayloadKeyType,
-};
+use crate::types::{FieldCondition, FloatPayloadType, IntPayloadType, Match, PayloadKeyType};
+
+/// Check of a condition against a single payload value, prepared once for the condition
+pub type ValueCheckerFn<'a> = Box<dyn Fn(&Value) -> bool + 'a>;
 
 pub trait PayloadFieldIndex {
     /// Return number of points with at least one value indexed in here
@@ -117,7 +118,7 @@ pub trait ValueIndexer<T> {
 pub enum FieldIndex {
     IntIndex(NumericIndex<IntPayloadType>),
     IntMapIndex(MapIndex<IntPayloadType>),
-    KeywordIndex(MapIndex<SmolStr>),
+    KeywordIndex(KeywordIndex),
     FloatIndex(NumericIndex<FloatPayloadType>),
     GeoIndex(GeoMapIndex),
     FullTextIndex(FullTextIndex),
@@ -125,35 +126,30 @@ pub enum FieldIndex {
 }
 
 impl FieldIndex {
-    /// Try to check condition for a payload given a field index.
+    /// Try to prepare a check of the condition for payload values given a field index.
     /// Required because some index parameters may influence the condition checking logic.
-    /// For example, full text index may have different tokenizers.
+    /// For example, full text index may have different tokenizers,
+    /// and keyword index may normalize keywords.
+    /// The condition is parsed once, the checker is applied to the values of many points.
     ///
     /// Returns `None` if there is no special logic for the given index
-    /// returns `Some(true)` if condition is satisfied
-    /// returns `Some(false)` if condition is not satisfied
-    pub fn check_condition(
-        &self,
-        condition: &FieldCondition,
-        payload_value: &Value,
-    ) -> Option<bool> {
+    pub fn value_checker(&self, condition: &FieldCondition) -> Option<ValueCheckerFn<'_>> {
         match self {
             FieldIndex::IntIndex(_) => None,
             FieldIndex::IntMapIndex(_) => None,
-            FieldIndex::KeywordIndex(_) => None,
+            FieldIndex::KeywordIndex(keyword_index) => keyword_index.value_checker(condition),
             FieldIndex::FloatIndex(_) => None,
             FieldIndex::GeoIndex(_) => None,
             FieldIndex::BinaryIndex(_) => None,
             FieldIndex::FullTextIndex(full_text_index) => match &condition.r#match {
-                Some(Match::Text(MatchText { text })) => {
-                    let query = full_text_index.parse_query(text);
-                    for value in full_text_index.get_values(payload_value) {
-                        let document = full_text_index.parse_document(&value);
-                        if query.check_match(&document) {
-                            return Some(true);
-                        }
-                    }
-                    Some(false)
+                Some(Match::Text(text_match)) => {
+                    let query = full_text_index.parse_text_match(text_match);
+                    Some(Box::new(move |payload_value: &Value| {
+                        full_text_index
+                            .get_values(payload_value)
+                            .iter()
+                            .any(|value| query.check_match(&full_text_index.parse_document(value)))
+                    }))
                 }
                 _ => None,
             },
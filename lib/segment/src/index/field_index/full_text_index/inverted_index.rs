@@ -1,10 +1,13 @@
-use std::collections::{BTreeSet, HashMap};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
 
-use common::types::PointOffsetType;
+use common::types::{PointOffsetType, ScoreType};
 use serde::{Deserialize, Serialize};
 
 use super::posting_list::PostingList;
 use super::postings_iterator::intersect_postings_iterator;
+use crate::common::levenshtein::LevenshteinAutomaton;
+use crate::data_types::text_index::bm25_token_score;
 use crate::index::field_index::{CardinalityEstimation, PayloadBlockCondition, PrimaryCondition};
 use crate::types::{FieldCondition, Match, MatchText, PayloadKeyType};
 
@@ -13,12 +16,25 @@ pub type TokenId = u32;
 #[derive(Default, Serialize, Deserialize, Debug, Clone)]
 pub struct Document {
     tokens: Vec<TokenId>,
+    /// Number of occurrences of each token, aligned with `tokens`
+    frequencies: Vec<u32>,
 }
 
 impl Document {
-    pub fn new(mut tokens: Vec<TokenId>) -> Self {
-        tokens.sort_unstable();
-        Self { tokens }
+    /// Create document from tokens, repeated tokens are counted as their frequencies
+    pub fn new(tokens: Vec<TokenId>) -> Self {
+        let mut frequencies: BTreeMap<TokenId, u32> = BTreeMap::new();
+        for token in tokens {
+            *frequencies.entry(token).or_default() += 1;
+        }
+        Self::from_frequencies(frequencies)
+    }
+
+    fn from_frequencies(frequencies: BTreeMap<TokenId, u32>) -> Self {
+        Self {
+            tokens: frequencies.keys().copied().collect(),
+            frequencies: frequencies.into_values().collect(),
+        }
     }
 
     pub fn len(&self) -> usize {
@@ -36,32 +52,64 @@ impl Document {
     pub fn check(&self, token: TokenId) -> bool {
         self.tokens.binary_search(&token).is_ok()
     }
+
+    /// Number of occurrences of the token in the document
+    pub fn frequency(&self, token: TokenId) -> u32 {
+        self.tokens
+            .binary_search(&token)
+            .map_or(0, |position| self.frequencies[position])
+    }
+
+    /// Total number of tokens in the document, including repeated ones
+    pub fn tokens_count(&self) -> usize {
+        self.frequencies
+            .iter()
+            .map(|&frequency| frequency as usize)
+            .sum()
+    }
 }
 
 #[derive(Debug)]
 pub struct ParsedQuery {
-    pub tokens: Vec<Option<TokenId>>,
+    /// Vocabulary tokens matching each query token.
+    /// Exact query tokens have at most one, fuzzy ones all tokens within the edit distance.
+    pub tokens: Vec<Vec<TokenId>>,
 }
 
 impl ParsedQuery {
     pub fn check_match(&self, document: &Document) -> bool {
-        if self.tokens.contains(&None) {
-            return false;
+        // Check that every query token has a matching token in document,
+        // query tokens without matches in the vocabulary match nothing
+        self.tokens.iter().all(|matching_tokens| {
+            matching_tokens
+                .iter()
+                .any(|&token_id| document.check(token_id))
+        })
+    }
+}
+
+/// Smallest string greater than all strings starting with the prefix, `None` if there is no such string
+fn prefix_successor(prefix: &[char]) -> Option<String> {
+    let mut successor = prefix.to_vec();
+    while let Some(last) = successor.pop() {
+        // Skip surrogates, which are not valid chars
+        if let Some(next) = (last as u32 + 1..=char::MAX as u32).find_map(char::from_u32) {
+            successor.push(next);
+            return Some(successor.into_iter().collect());
         }
-        // Check that all tokens are in document
-        self.tokens
-            .iter()
-            // unwrap crash safety: all tokens exist in the vocabulary if it passes the above check
-            .all(|query_token| document.check(query_token.unwrap()))
     }
+    None
 }
 
 #[derive(Default)]
 pub struct InvertedIndex {
     postings: Vec<Option<PostingList>>,
-    pub vocab: HashMap<String, TokenId>,
+    /// Sorted, so that terms sharing a prefix can be visited together
+    pub vocab: BTreeMap<String, TokenId>,
     pub point_to_docs: Vec<Option<Document>>,
     pub points_count: usize,
+    /// Total number of tokens in all indexed documents, required for BM25 document length normalization
+    pub tokens_count: usize,
 }
 
 impl InvertedIndex {
@@ -69,9 +117,10 @@ impl InvertedIndex {
         Default::default()
     }
 
-    pub fn document_from_tokens(&mut self, tokens: &BTreeSet<String>) -> Document {
-        let mut document_tokens = vec![];
-        for token in tokens {
+    /// Build document from tokens with their frequencies, adding unseen tokens to the vocabulary
+    pub fn document_from_tokens(&mut self, tokens: &BTreeMap<String, u32>) -> Document {
+        let mut frequencies = BTreeMap::new();
+        for (token, &frequency) in tokens {
             // check if in vocab
             let vocab_idx = match self.vocab.get(token) {
                 Some(&idx) => idx,
@@ -81,14 +130,15 @@ impl InvertedIndex {
                     next_token_id
                 }
             };
-            document_tokens.push(vocab_idx);
+            frequencies.insert(vocab_idx, frequency.max(1));
         }
 
-        Document::new(document_tokens)
+        Document::from_frequencies(frequencies)
     }
 
     pub fn index_document(&mut self, idx: PointOffsetType, document: Document) {
         self.points_count += 1;
+        self.tokens_count += document.tokens_count();
         if self.point_to_docs.len() <= idx as usize {
             self.point_to_docs
                 .resize_with(idx as usize + 1, Default::default);
@@ -123,6 +173,7 @@ impl InvertedIndex {
         };
 
         self.points_count -= 1;
+        self.tokens_count -= removed_doc.tokens_count();
 
         for removed_token in removed_doc.tokens() {
             // unwrap safety: posting list exists and contains the document id
@@ -134,16 +185,84 @@ impl InvertedIndex {
         Some(removed_doc)
     }
 
+    /// Vocabulary tokens within the Levenshtein edit distance of the query token.
+    ///
+    /// The sorted vocabulary is walked with the automaton: states of the prefix shared with
+    /// the previous term are reused, and all terms with a prefix which can't match are skipped.
+    pub fn fuzzy_tokens(&self, token: &str, max_distance: u8) -> Vec<TokenId> {
+        let automaton = LevenshteinAutomaton::new(token, max_distance);
+        let mut token_ids = vec![];
+
+        // Automaton states after each character of the current prefix, starting with the empty one
+        let mut prefix: Vec<char> = vec![];
+        let mut states = vec![automaton.start()];
+        // Smallest term which is not visited yet
+        let mut next_term = String::new();
+
+        while let Some((term, &token_id)) = self.vocab.range::<str, _>(next_term.as_str()..).next()
+        {
+            let chars: Vec<char> = term.chars().collect();
+            let shared = prefix
+                .iter()
+                .zip(&chars)
+                .take_while(|(prefix_char, term_char)| prefix_char == term_char)
+                .count();
+            states.truncate(shared + 1);
+            prefix = chars;
+
+            let mut rejected_len = None;
+            for (i, &c) in prefix.iter().enumerate().skip(shared) {
+                let state = automaton.step(&states[i], c);
+                if !automaton.can_match(&state) {
+                    rejected_len = Some(i + 1);
+                    break;
+                }
+                states.push(state);
+            }
+
+            match rejected_len {
+                Some(len) => {
+                    // No term starting with the rejected prefix can match
+                    match prefix_successor(&prefix[..len]) {
+                        Some(successor) => next_term = successor,
+                        None => break,
+                    }
+                    prefix.truncate(len - 1);
+                }
+                None => {
+                    if automaton.is_accepting(&states[prefix.len()]) {
+                        token_ids.push(token_id);
+                    }
+                    next_term = format!("{term}\0");
+                }
+            }
+        }
+
+        token_ids.sort_unstable();
+        token_ids
+    }
+
+    /// Postings of the documents containing any of the tokens, `None` if there are no such documents.
+    /// Postings of several tokens are merged.
+    fn union_postings(&self, tokens: &[TokenId]) -> Option<Cow<'_, PostingList>> {
+        // if a ParsedQuery token was given an index, then it must exist in the vocabulary
+        // dictionary. Posting list entry can be None but it exists.
+        let postings: Vec<_> = tokens
+            .iter()
+            .filter_map(|&idx| self.postings.get(idx as usize).unwrap().as_ref())
+            .collect();
+        match postings.len() {
+            0 => None,
+            1 => Some(Cow::Borrowed(postings[0])),
+            _ => Some(Cow::Owned(PostingList::union(postings))),
+        }
+    }
+
     pub fn filter(&self, query: &ParsedQuery) -> Box<dyn Iterator<Item = PointOffsetType> + '_> {
         let postings_opt: Option<Vec<_>> = query
             .tokens
             .iter()
-            .map(|&vocab_idx| match vocab_idx {
-                None => None,
-                // if a ParsedQuery token was given an index, then it must exist in the vocabulary
-                // dictionary. Posting list entry can be None but it exists.
-                Some(idx) => self.postings.get(idx as usize).unwrap().as_ref(),
-            })
+            .map(|tokens| self.union_postings(tokens))
             .collect();
         if postings_opt.is_none() {
             // There are unseen tokens -> no matches
@@ -154,7 +273,60 @@ impl InvertedIndex {
             // Empty request -> no matches
             return Box::new(vec![].into_iter());
         }
-        intersect_postings_iterator(postings)
+        let borrowed_postings: Option<Vec<&PostingList>> = postings
+            .iter()
+            .map(|posting| match posting {
+                Cow::Borrowed(posting) => Some(*posting),
+                Cow::Owned(_) => None,
+            })
+            .collect();
+        if let Some(borrowed_postings) = borrowed_postings {
+            return intersect_postings_iterator(borrowed_postings);
+        }
+        // Merged postings of fuzzy tokens don't outlive this call, so points are collected
+        let points: Vec<_> =
+            intersect_postings_iterator(postings.iter().map(|posting| &**posting).collect())
+                .collect();
+        Box::new(points.into_iter())
+    }
+
+    /// Number of documents containing the token
+    pub fn document_frequency(&self, token: TokenId) -> usize {
+        self.postings
+            .get(token as usize)
+            .and_then(|posting| posting.as_ref())
+            .map_or(0, |posting| posting.len())
+    }
+
+    /// Accumulate BM25 scores of the documents which contain any of the weighted tokens.
+    ///
+    /// * `token_weights` - query tokens with their inverse document frequencies
+    /// * `avg_tokens_count` - average document length in tokens
+    /// * `check` - only documents which pass the check are scored
+    pub fn score_bm25(
+        &self,
+        token_weights: &[(TokenId, ScoreType)],
+        avg_tokens_count: ScoreType,
+        check: impl Fn(PointOffsetType) -> bool,
+    ) -> HashMap<PointOffsetType, ScoreType> {
+        let mut scores: HashMap<PointOffsetType, ScoreType> = HashMap::new();
+        for &(token, idf) in token_weights {
+            let Some(Some(posting)) = self.postings.get(token as usize) else {
+                continue;
+            };
+            for idx in posting.iter().filter(|&idx| check(idx)) {
+                let Some(Some(document)) = self.point_to_docs.get(idx as usize) else {
+                    continue;
+                };
+                *scores.entry(idx).or_default() += bm25_token_score(
+                    idf,
+                    document.frequency(token),
+                    document.tokens_count(),
+                    avg_tokens_count,
+                );
+            }
+        }
+        scores
     }
 
     pub fn estimate_cardinality(
@@ -165,11 +337,7 @@ impl InvertedIndex {
         let postings_opt: Option<Vec<_>> = query
             .tokens
             .iter()
-            .map(|&vocab_idx| match vocab_idx {
-                None => None,
-                // unwrap safety: same as in filter()
-                Some(idx) => self.postings.get(idx as usize).unwrap().as_ref(),
-            })
+            .map(|tokens| self.union_postings(tokens))
             .collect();
         if postings_opt.is_none() {
             // There are unseen tokens -> no matches
@@ -242,11 +410,13 @@ impl InvertedIndex {
                         key: key.clone(),
                         r#match: Some(Match::Text(MatchText {
                             text: token.clone(),
+                            fuzzy: None,
                         })),
                         range: None,
                         geo_bounding_box: None,
                         geo_radius: None,
                         geo_polygon: None,
+                        geo_shape: None,
                         values_count: None,
                     },
                     cardinality: posting.len(),
@@ -2,7 +2,7 @@ mod inverted_index;
 mod posting_list;
 mod postings_iterator;
 pub mod text_index;
-mod tokenizers;
+pub mod tokenizers;
 
 #[cfg(test)]
 mod tests;
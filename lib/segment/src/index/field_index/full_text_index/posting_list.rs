@@ -10,6 +10,17 @@ impl PostingList {
         Self { list: vec![idx] }
     }
 
+    /// Posting list of the documents contained in any of the given posting lists
+    pub fn union<'a>(postings: impl IntoIterator<Item = &'a PostingList>) -> Self {
+        let mut list: Vec<_> = postings
+            .into_iter()
+            .flat_map(|posting| posting.iter())
+            .collect();
+        list.sort_unstable();
+        list.dedup();
+        Self { list }
+    }
+
     pub fn insert(&mut self, idx: PointOffsetType) {
         if let Err(insertion_idx) = self.list.binary_search(&idx) {
             // Yes, this is O(n) but:
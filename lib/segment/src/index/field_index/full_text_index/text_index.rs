@@ -1,7 +1,7 @@
-use std::collections::{BTreeSet, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::sync::Arc;
 
-use common::types::PointOffsetType;
+use common::types::{PointOffsetType, ScoreType};
 use parking_lot::RwLock;
 use rocksdb::DB;
 use serde::{Deserialize, Serialize};
@@ -10,7 +10,7 @@ use serde_json::Value;
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::common::rocksdb_wrapper::DatabaseColumnWrapper;
 use crate::common::Flusher;
-use crate::data_types::text_index::TextIndexParams;
+use crate::data_types::text_index::{TextIndexParams, TextStats};
 use crate::index::field_index::full_text_index::inverted_index::{
     Document, InvertedIndex, ParsedQuery,
 };
@@ -19,7 +19,7 @@ use crate::index::field_index::{
     CardinalityEstimation, PayloadBlockCondition, PayloadFieldIndex, ValueIndexer,
 };
 use crate::telemetry::PayloadIndexTelemetry;
-use crate::types::{FieldCondition, Match, PayloadKeyType};
+use crate::types::{FieldCondition, Match, MatchText, PayloadKeyType};
 
 pub struct FullTextIndex {
     inverted_index: InvertedIndex,
@@ -36,12 +36,23 @@ impl FullTextIndex {
         bincode::deserialize(data).unwrap()
     }
 
-    fn serialize_document_tokens(&self, tokens: BTreeSet<String>) -> OperationResult<Vec<u8>> {
+    fn serialize_document_tokens(&self, tokens: BTreeMap<String, u32>) -> OperationResult<Vec<u8>> {
         #[derive(Serialize)]
         struct StoredDocument {
             tokens: BTreeSet<String>,
+            /// Frequencies of the tokens in the order of `tokens`, omitted if all tokens are unique
+            #[serde(skip_serializing_if = "Vec::is_empty")]
+            frequencies: Vec<u32>,
         }
-        let doc = StoredDocument { tokens };
+        let frequencies = if tokens.values().all(|&frequency| frequency == 1) {
+            vec![]
+        } else {
+            tokens.values().copied().collect()
+        };
+        let doc = StoredDocument {
+            tokens: tokens.into_keys().collect(),
+            frequencies,
+        };
         serde_cbor::to_vec(&doc).map_err(|e| {
             OperationError::service_error(format!("Failed to serialize document: {e}"))
         })
@@ -51,12 +62,19 @@ impl FullTextIndex {
         #[derive(Deserialize)]
         struct StoredDocument {
             tokens: BTreeSet<String>,
+            // Documents stored before frequencies were tracked have unique tokens only
+            #[serde(default)]
+            frequencies: Vec<u32>,
         }
         serde_cbor::from_slice::<StoredDocument>(data)
             .map_err(|e| {
                 OperationError::service_error(format!("Failed to deserialize document: {e}"))
             })
-            .map(|doc| index.document_from_tokens(&doc.tokens))
+            .map(|doc| {
+                let frequencies = doc.frequencies.into_iter().chain(std::iter::repeat(1));
+                let tokens = doc.tokens.into_iter().zip(frequencies).collect();
+                index.document_from_tokens(&tokens)
+            })
     }
 
     fn storage_cf_name(field: &str) -> String {
@@ -94,15 +112,34 @@ impl FullTextIndex {
     }
 
     pub fn parse_query(&self, text: &str) -> ParsedQuery {
+        self.parse_fuzzy_query(text, 0)
+    }
+
+    /// Parse query, in which every token matches vocabulary tokens within `max_distance` edits
+    pub fn parse_fuzzy_query(&self, text: &str, max_distance: u8) -> ParsedQuery {
         let mut tokens = HashSet::new();
         Tokenizer::tokenize_query(text, &self.config, |token| {
-            tokens.insert(self.inverted_index.vocab.get(token).copied());
+            let matching_tokens = if max_distance == 0 {
+                self.inverted_index
+                    .vocab
+                    .get(token)
+                    .copied()
+                    .into_iter()
+                    .collect()
+            } else {
+                self.inverted_index.fuzzy_tokens(token, max_distance)
+            };
+            tokens.insert(matching_tokens);
         });
         ParsedQuery {
             tokens: tokens.into_iter().collect(),
         }
     }
 
+    pub fn parse_text_match(&self, text_match: &MatchText) -> ParsedQuery {
+        self.parse_fuzzy_query(&text_match.text, text_match.max_distance())
+    }
+
     pub fn parse_document(&self, text: &str) -> Document {
         let mut document_tokens = vec![];
         Tokenizer::tokenize_doc(text, &self.config, |token| {
@@ -113,6 +150,45 @@ impl FullTextIndex {
         Document::new(document_tokens)
     }
 
+    /// Statistics of the indexed documents and of the distinct tokens of the query
+    pub fn query_stats(&self, query: &str) -> TextStats {
+        let mut document_frequencies = HashMap::new();
+        Tokenizer::tokenize_query(query, &self.config, |token| {
+            let document_frequency = self.inverted_index.vocab.get(token).map_or(0, |&token_id| {
+                self.inverted_index.document_frequency(token_id)
+            });
+            document_frequencies.insert(token.to_owned(), document_frequency);
+        });
+        TextStats {
+            documents_count: self.inverted_index.points_count,
+            tokens_count: self.inverted_index.tokens_count,
+            document_frequencies,
+        }
+    }
+
+    /// BM25 scores of the documents containing any token of the query.
+    /// Scores are computed with the given `stats`, which may cover more documents than this index.
+    pub fn score_query(
+        &self,
+        query: &str,
+        stats: &TextStats,
+        check: impl Fn(PointOffsetType) -> bool,
+    ) -> HashMap<PointOffsetType, ScoreType> {
+        let mut tokens = HashSet::new();
+        Tokenizer::tokenize_query(query, &self.config, |token| {
+            tokens.insert(token.to_owned());
+        });
+        let token_weights: Vec<_> = tokens
+            .iter()
+            .filter_map(|token| {
+                let token_id = *self.inverted_index.vocab.get(token)?;
+                Some((token_id, stats.idf(token)))
+            })
+            .collect();
+        self.inverted_index
+            .score_bm25(&token_weights, stats.avg_tokens_count(), check)
+    }
+
     #[cfg(test)]
     pub fn query(&self, query: &str) -> Box<dyn Iterator<Item = PointOffsetType> + '_> {
         let parsed_query = self.parse_query(query);
@@ -135,11 +211,11 @@ impl ValueIndexer<String> for FullTextIndex {
             return Ok(());
         }
 
-        let mut tokens: BTreeSet<String> = BTreeSet::new();
+        let mut tokens: BTreeMap<String, u32> = BTreeMap::new();
 
         for value in values {
             Tokenizer::tokenize_doc(&value, &self.config, |token| {
-                tokens.insert(token.to_owned());
+                *tokens.entry(token.to_owned()).or_default() += 1;
             });
         }
 
@@ -206,7 +282,7 @@ impl PayloadFieldIndex for FullTextIndex {
         condition: &FieldCondition,
     ) -> OperationResult<Box<dyn Iterator<Item = PointOffsetType> + '_>> {
         if let Some(Match::Text(text_match)) = &condition.r#match {
-            let parsed_query = self.parse_query(&text_match.text);
+            let parsed_query = self.parse_text_match(text_match);
             return Ok(self.inverted_index.filter(&parsed_query));
         }
         Err(OperationError::service_error("failed to filter"))
@@ -217,7 +293,7 @@ impl PayloadFieldIndex for FullTextIndex {
         condition: &FieldCondition,
     ) -> OperationResult<CardinalityEstimation> {
         if let Some(Match::Text(text_match)) = &condition.r#match {
-            let parsed_query = self.parse_query(&text_match.text);
+            let parsed_query = self.parse_text_match(text_match);
             return Ok(self
                 .inverted_index
                 .estimate_cardinality(&parsed_query, condition));
@@ -241,22 +317,28 @@ mod tests {
     use tempfile::Builder;
 
     use super::*;
+    use crate::common::levenshtein::LevenshteinAutomaton;
     use crate::common::rocksdb_wrapper::open_db_with_existing_cf;
     use crate::common::utils::MultiValue;
     use crate::data_types::text_index::{TextIndexType, TokenizerType};
-    use crate::types::MatchText;
 
     fn filter_request(text: &str) -> FieldCondition {
+        fuzzy_filter_request(text, None)
+    }
+
+    fn fuzzy_filter_request(text: &str, fuzzy: Option<u8>) -> FieldCondition {
         FieldCondition {
             key: "text".to_owned(),
             r#match: Some(Match::Text(MatchText {
                 text: text.to_owned(),
+                fuzzy,
             })),
             range: None,
             geo_bounding_box: None,
             geo_radius: None,
             values_count: None,
             geo_polygon: None,
+            geo_shape: None,
         }
     }
 
@@ -350,4 +432,149 @@ mod tests {
             assert_eq!(search_res, vec![0, 1, 3, 4]);
         }
     }
+
+    #[test]
+    fn test_fuzzy_text_match() {
+        let payloads = [
+            serde_json::json!("The robot rebellion"),
+            serde_json::json!("Robots build robots"),
+            serde_json::json!("A rabbit in the garden"),
+            serde_json::json!("Reboot the computer"),
+        ];
+
+        let temp_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+        let config = TextIndexParams {
+            r#type: TextIndexType::Text,
+            tokenizer: TokenizerType::Word,
+            min_token_len: None,
+            max_token_len: None,
+            lowercase: Some(true),
+        };
+        let db = open_db_with_existing_cf(&temp_dir.path().join("test_db")).unwrap();
+        let mut index = FullTextIndex::new(db, config, "text");
+        index.recreate().unwrap();
+        for (idx, payload) in payloads.iter().enumerate() {
+            index
+                .add_point(idx as PointOffsetType, &MultiValue::one(payload))
+                .unwrap();
+        }
+
+        let filter = |text: &str, fuzzy: Option<u8>| {
+            let condition = fuzzy_filter_request(text, fuzzy);
+            let points: Vec<_> = index.filter(&condition).unwrap().collect();
+            // Checks of single documents agree with the filter
+            let parsed_query = index.parse_fuzzy_query(text, fuzzy.unwrap_or(0));
+            for idx in 0..payloads.len() as PointOffsetType {
+                let doc = index.get_doc(idx).unwrap();
+                assert_eq!(parsed_query.check_match(doc), points.contains(&idx));
+            }
+            let cardinality = index.estimate_cardinality(&condition).unwrap();
+            assert!(cardinality.max >= points.len());
+            points
+        };
+
+        assert!(filter("robt", None).is_empty());
+        assert_eq!(filter("robt", Some(1)), vec![0]);
+        // Transposition takes two edits
+        assert!(filter("robto", Some(1)).is_empty());
+        assert_eq!(filter("robto", Some(2)), vec![0, 1]);
+        // Postings of matching tokens are merged for each query token
+        assert_eq!(filter("rbot", Some(2)), vec![0, 1, 3]);
+        // and intersected across query tokens
+        assert_eq!(filter("robot rebelion", Some(1)), vec![0]);
+        assert_eq!(
+            filter("robot computr", Some(1)),
+            Vec::<PointOffsetType>::new()
+        );
+        assert_eq!(filter("reboot computr", Some(1)), vec![3]);
+        assert!(filter("xyz", Some(2)).is_empty());
+
+        // Walk of the sorted vocabulary finds the same tokens as checking every token
+        for query in ["robt", "rbot", "gardn", "th", "xyz"] {
+            for max_distance in 1..=MatchText::MAX_FUZZY {
+                let automaton = LevenshteinAutomaton::new(query, max_distance);
+                let mut expected: Vec<_> = index
+                    .inverted_index
+                    .vocab
+                    .iter()
+                    .filter(|(term, _)| automaton.is_match(term))
+                    .map(|(_, &token_id)| token_id)
+                    .collect();
+                expected.sort_unstable();
+                assert_eq!(
+                    index.inverted_index.fuzzy_tokens(query, max_distance),
+                    expected
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_bm25_scoring() {
+        let payloads = [
+            serde_json::json!("robots and robots and more robots"),
+            serde_json::json!("the robot rebellion of the robots"),
+            serde_json::json!(
+                "a long story about humans, space, stars and a single robots factory"
+            ),
+            serde_json::json!("humans only"),
+        ];
+
+        let temp_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+        let config = TextIndexParams {
+            r#type: TextIndexType::Text,
+            tokenizer: TokenizerType::Word,
+            min_token_len: None,
+            max_token_len: None,
+            lowercase: Some(true),
+        };
+
+        let ranking = |index: &FullTextIndex, query: &str| {
+            let stats = index.query_stats(query);
+            let scores = index.score_query(query, &stats, |_| true);
+            let mut ranked: Vec<_> = scores.into_iter().collect();
+            ranked.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+            ranked.into_iter().map(|(idx, _)| idx).collect::<Vec<_>>()
+        };
+
+        {
+            let db = open_db_with_existing_cf(&temp_dir.path().join("test_db")).unwrap();
+            let mut index = FullTextIndex::new(db, config.clone(), "text");
+            index.recreate().unwrap();
+            for (idx, payload) in payloads.iter().enumerate() {
+                index
+                    .add_point(idx as PointOffsetType, &MultiValue::one(payload))
+                    .unwrap();
+            }
+
+            let stats = index.query_stats("Robots humans");
+            assert_eq!(stats.documents_count, 4);
+            assert_eq!(stats.tokens_count, 6 + 6 + 12 + 2);
+            assert_eq!(stats.document_frequencies["robots"], 3);
+            assert_eq!(stats.document_frequencies["humans"], 2);
+
+            // Frequent and short documents rank higher
+            assert_eq!(ranking(&index, "robots"), vec![0, 1, 2]);
+            // Rare tokens weight more
+            assert_eq!(ranking(&index, "robot"), vec![1]);
+            assert_eq!(ranking(&index, "rebellion robots")[0], 1);
+            assert!(ranking(&index, "unknown").is_empty());
+
+            // Filtered documents are not scored
+            let scores = index.score_query("robots", &stats, |idx| idx != 0);
+            assert!(!scores.contains_key(&0));
+            assert_eq!(scores.len(), 2);
+
+            index.flusher()().unwrap();
+        }
+
+        {
+            // Token frequencies survive reloading
+            let db = open_db_with_existing_cf(&temp_dir.path().join("test_db")).unwrap();
+            let mut index = FullTextIndex::new(db, config, "text");
+            assert!(index.load().unwrap());
+            assert_eq!(index.query_stats("robots").tokens_count, 6 + 6 + 12 + 2);
+            assert_eq!(ranking(&index, "robots"), vec![0, 1, 2]);
+        }
+    }
 }
@@ -1,7 +1,7 @@
 use std::ops::Range;
 
 use geo::algorithm::haversine_distance::HaversineDistance;
-use geo::{Coord, Intersects, LineString, Point, Polygon};
+use geo::{BoundingRect, Coord, Geometry, Intersects, LineString, Point, Polygon};
 use geohash::{decode, decode_bbox, encode, Direction, GeohashError};
 use itertools::Itertools;
 use smol_str::SmolStr;
@@ -12,7 +12,7 @@ use crate::types::{GeoBoundingBox, GeoPoint, GeoPolygon, GeoRadius};
 pub type GeoHash = SmolStr;
 
 /// Max size of geo-hash used for indexing. size=12 is about 6cm2
-const GEOHASH_MAX_LENGTH: usize = 12;
+pub const GEOHASH_MAX_LENGTH: usize = 12;
 
 const LON_RANGE: Range<f64> = -180.0..180.0;
 const LAT_RANGE: Range<f64> = -90.0..90.0;
@@ -208,6 +208,17 @@ fn check_polygon_intersection(geohash: &str, polygon: &Polygon) -> bool {
     rect.intersects(polygon)
 }
 
+/// Check if geohash tile intersects the geometry
+fn check_geometry_intersection(geohash: &str, geometry: &Geometry) -> bool {
+    let precision = geohash.len();
+    if precision == 0 {
+        return true;
+    }
+    let rect = decode_bbox(geohash).unwrap();
+
+    geometry.intersects(&rect)
+}
+
 fn create_hashes(
     mapping_fn: impl Fn(usize) -> Option<Vec<GeoHash>>,
 ) -> OperationResult<Vec<GeoHash>> {
@@ -327,6 +338,90 @@ pub fn polygon_hashes(polygon: &GeoPolygon, max_regions: usize) -> OperationResu
     create_hashes(mapping_fn)
 }
 
+/// Return as-high-as-possible with maximum of `max_regions`
+/// number of geo-hash guaranteed to contain the whole geometry.
+pub fn geometry_hashes(geometry: &Geometry, max_regions: usize) -> OperationResult<Vec<GeoHash>> {
+    if max_regions == 0 {
+        return Err(OperationError::service_error(
+            "max_regions cannot be equal to zero",
+        ));
+    }
+    let rect = geometry
+        .bounding_rect()
+        .ok_or_else(|| OperationError::service_error("geometry has no bounding rectangle"))?;
+    let geo_bounding_box = GeoBoundingBox {
+        top_left: GeoPoint {
+            lon: rect.min().x,
+            lat: rect.max().y,
+        },
+        bottom_right: GeoPoint {
+            lon: rect.max().x,
+            lat: rect.min().y,
+        },
+    };
+    let full_geohash_bounding_box: GeohashBoundingBox = geo_bounding_box.into();
+
+    let mapping_fn = |precision| {
+        full_geohash_bounding_box
+            .geohash_regions(precision, max_regions)
+            .map(|hashes| {
+                hashes
+                    .into_iter()
+                    .filter(|hash| check_geometry_intersection(hash, geometry))
+                    .collect_vec()
+            })
+    };
+    create_hashes(mapping_fn)
+}
+
+/// Geo-hash cell of the given `precision` containing the `origin`, together with its 8 neighbours.
+///
+/// Also returns the radius in meters around the `origin`, which is guaranteed to be covered
+/// by the returned cells.
+pub fn geo_hash_ring(origin: &GeoPoint, precision: usize) -> OperationResult<(Vec<GeoHash>, f64)> {
+    let malformed =
+        |e: GeohashError| OperationError::service_error(format!("Malformed geo point: {e}"));
+    let center: GeoHash = encode((origin.lon, origin.lat).into(), precision)
+        .map_err(malformed)?
+        .into();
+
+    let directions = [
+        Direction::N,
+        Direction::NE,
+        Direction::E,
+        Direction::SE,
+        Direction::S,
+        Direction::SW,
+        Direction::W,
+        Direction::NW,
+    ];
+    let mut ring = vec![center.clone()];
+    for direction in directions {
+        ring.push(sphere_neighbor(&center, direction).map_err(malformed)?);
+    }
+    let ring = ring.into_iter().unique().collect_vec();
+
+    // The origin lies inside of the central cell, so the ring covers at least one cell size in every direction.
+    // Cells are the narrowest at the latitude closest to the pole.
+    let (_, lon_err, lat_err) = decode(&center).map_err(malformed)?;
+    let cell_height = 2.0 * lat_err.abs();
+    let cell_width = 2.0 * lon_err.abs();
+    let polar_lat = origin.lat.abs() + cell_height;
+    let radius = if polar_lat >= LAT_RANGE.end {
+        0.0
+    } else {
+        let height_meters = Point::new(origin.lon, origin.lat).haversine_distance(&Point::new(
+            origin.lon,
+            sphere_lat(origin.lat + cell_height),
+        ));
+        let width_meters =
+            Point::new(0.0, polar_lat).haversine_distance(&Point::new(cell_width, polar_lat));
+        height_meters.min(width_meters)
+    };
+
+    Ok((ring, radius))
+}
+
 /// A globally-average value is usually considered to be 6,371 kilometres (3,959 mi) with a 0.3% variability (±10 km).
 /// <https://en.wikipedia.org/wiki/Earth_radius>.
 const EARTH_RADIUS_METERS: f64 = 6371.0 * 1000.;
@@ -606,6 +701,44 @@ mod tests {
         assert_eq!(nyc_hashes_result.unwrap(), ["dr5ru"]);
     }
 
+    #[test]
+    fn geometry_hashes_nyc() {
+        let near_nyc_polygon = build_polygon(vec![
+            (-74.00101399, 40.76517460),
+            (-73.98201792, 40.76517460),
+            (-73.98201792, 40.75078539),
+            (-74.00101399, 40.75078539),
+            (-74.00101399, 40.76517460),
+        ]);
+        let geometry = Geometry::Polygon(near_nyc_polygon.convert().polygon);
+
+        // same coverage as for the polygon filter
+        for max_regions in [7, 10, 200] {
+            let mut geometry_hashes = geometry_hashes(&geometry, max_regions).unwrap();
+            let mut polygon_hashes = polygon_hashes(&near_nyc_polygon, max_regions).unwrap();
+            geometry_hashes.sort_unstable();
+            polygon_hashes.sort_unstable();
+            assert_eq!(geometry_hashes, polygon_hashes);
+        }
+
+        // a single point is covered by a single max precision hash
+        let point = Geometry::Point(Point::new(NYC.lon, NYC.lat));
+        let point_hashes = geometry_hashes(&point, GEOHASH_MAX_LENGTH).unwrap();
+        assert_eq!(
+            point_hashes,
+            [encode_max_precision(NYC.lon, NYC.lat).unwrap()]
+        );
+
+        // a line is covered only by the tiles it crosses
+        let line = Geometry::LineString(LineString::from(vec![
+            (-74.00101399, 40.76517460),
+            (-73.98201792, 40.75078539),
+        ]));
+        let line_hashes = geometry_hashes(&line, 200).unwrap();
+        assert!(!line_hashes.is_empty());
+        assert!(line_hashes.len() < 168);
+    }
+
     #[test]
     fn random_circles() {
         let mut rnd = StdRng::seed_from_u64(42);
@@ -888,6 +1021,28 @@ mod tests {
         assert_eq!(dist, 20015114.442035925);
     }
 
+    #[test]
+    fn geo_hash_ring_covers_radius() {
+        let (ring, radius) = geo_hash_ring(&NYC, 6).unwrap();
+        assert_eq!(ring.len(), 9);
+        assert!(ring.iter().all(|hash| hash.len() == 6));
+        assert!(ring.contains(&"dr5ru7".into()));
+        // cell of precision 6 is about 0.9km x 0.6km at the NYC latitude
+        assert!(radius > 400.0 && radius < 1300.0, "radius = {radius}");
+
+        let center = Point::new(NYC.lon, NYC.lat);
+        let mut rnd = StdRng::seed_from_u64(42);
+        for _ in 0..1000 {
+            let lon = NYC.lon + rnd.gen_range(-0.02..0.02);
+            let lat = NYC.lat + rnd.gen_range(-0.02..0.02);
+            if center.haversine_distance(&Point::new(lon, lat)) > radius {
+                continue;
+            }
+            let hash = encode_max_precision(lon, lat).unwrap();
+            assert!(ring.iter().any(|cell| hash.starts_with(cell.as_str())));
+        }
+    }
+
     #[test]
     fn turn_geo_hash_to_box() {
         let geo_box = geo_hash_to_box(&"dr5ruj4477kd".into());
@@ -4,7 +4,10 @@ use std::str::FromStr;
 use std::sync::Arc;
 
 use common::types::PointOffsetType;
+use geo::prelude::HaversineDistance;
+use geo::{Geometry, Point};
 use itertools::Itertools;
+use ordered_float::OrderedFloat;
 use parking_lot::RwLock;
 use rocksdb::DB;
 use serde_json::Value;
@@ -13,8 +16,9 @@ use crate::common::operation_error::{OperationError, OperationResult};
 use crate::common::rocksdb_wrapper::DatabaseColumnWrapper;
 use crate::common::Flusher;
 use crate::index::field_index::geo_hash::{
-    circle_hashes, common_hash_prefix, encode_max_precision, geo_hash_to_box, polygon_hashes,
-    polygon_hashes_estimation, rectangle_hashes, GeoHash,
+    circle_hashes, common_hash_prefix, encode_max_precision, geo_hash_ring, geo_hash_to_box,
+    geometry_hashes, polygon_hashes, polygon_hashes_estimation, rectangle_hashes, GeoHash,
+    GEOHASH_MAX_LENGTH,
 };
 use crate::index::field_index::stat_tools::estimate_multi_value_selection_cardinality;
 use crate::index::field_index::{
@@ -22,13 +26,28 @@ use crate::index::field_index::{
 };
 use crate::telemetry::PayloadIndexTelemetry;
 use crate::types::{
-    FieldCondition, GeoBoundingBox, GeoPoint, GeoRadius, PayloadKeyType, PolygonWrapper,
+    FieldCondition, GeoBoundingBox, GeoPoint, GeoRadius, GeoShape, GeoShapeRelation,
+    PayloadKeyType, PolygonWrapper,
 };
 
 /// Max number of sub-regions computed for an input geo query
 // TODO discuss value, should it be dynamically computed?
 const GEO_QUERY_MAX_REGION: usize = 12;
 
+/// Max number of sub-regions covering a single indexed geo shape
+const GEO_SHAPE_MAX_REGION: usize = 12;
+
+/// DB key prefix of stored geo shapes.
+/// Can't collide with geo-hashes, as `a` is not a part of the geo-hash alphabet.
+const SHAPES_DB_KEY_PREFIX: &str = "shape";
+
+/// Indexed payload value of the geo index
+#[derive(Debug, Clone, PartialEq)]
+pub enum GeoValue {
+    Point(GeoPoint),
+    Shape(GeoShape),
+}
+
 pub struct MutableGeoMapIndex {
     /*
     {
@@ -52,6 +71,10 @@ pub struct MutableGeoMapIndex {
      */
     points_map: BTreeMap<GeoHash, HashSet<PointOffsetType>>,
     point_to_values: Vec<Vec<GeoPoint>>,
+    /// Geo-hashes covering the stored shapes, possibly of different precision
+    shapes_map: BTreeMap<GeoHash, HashSet<PointOffsetType>>,
+    point_to_shapes: Vec<Vec<Geometry>>,
+    /// Number of points with at least one geo point or geo shape
     points_count: usize,
     points_values_count: usize,
     max_values_per_point: usize,
@@ -71,6 +94,8 @@ impl MutableGeoMapIndex {
             values_per_hash: Default::default(),
             points_map: Default::default(),
             point_to_values: vec![],
+            shapes_map: Default::default(),
+            point_to_shapes: vec![],
             points_count: 0,
             points_values_count: 0,
             max_values_per_point: 0,
@@ -86,6 +111,18 @@ impl MutableGeoMapIndex {
         self.point_to_values.get(idx as usize).map(Vec::as_slice)
     }
 
+    fn get_shapes(&self, idx: PointOffsetType) -> Option<&[Geometry]> {
+        self.point_to_shapes.get(idx as usize).map(Vec::as_slice)
+    }
+
+    fn has_values(&self, idx: PointOffsetType) -> bool {
+        self.get_values(idx)
+            .map_or(false, |values| !values.is_empty())
+            || self
+                .get_shapes(idx)
+                .map_or(false, |shapes| !shapes.is_empty())
+    }
+
     fn get_points_per_hash(&self) -> impl Iterator<Item = (&GeoHash, usize)> {
         self.points_per_hash
             .iter()
@@ -106,6 +143,7 @@ impl MutableGeoMapIndex {
         };
 
         let mut points_to_hashes: BTreeMap<PointOffsetType, Vec<GeoHash>> = Default::default();
+        let mut points_to_shapes: Vec<(PointOffsetType, Vec<GeoShape>)> = vec![];
 
         for (key, value) in self.db_wrapper.lock_db().iter()? {
             let key_str = std::str::from_utf8(&key).map_err(|_| {
@@ -113,16 +151,21 @@ impl MutableGeoMapIndex {
             })?;
 
             let (geo_hash, idx) = GeoMapIndex::decode_db_key(key_str)?;
+
+            if geo_hash == SHAPES_DB_KEY_PREFIX {
+                let shapes: Vec<GeoShape> = serde_json::from_slice(&value).map_err(|_| {
+                    OperationError::service_error("Index load error: invalid geo shape encoding")
+                })?;
+                points_to_shapes.push((idx, shapes));
+                continue;
+            }
+
             let geo_point = GeoMapIndex::decode_db_value(value)?;
 
             if self.point_to_values.len() <= idx as usize {
                 self.point_to_values.resize_with(idx as usize + 1, Vec::new);
             }
 
-            if self.point_to_values[idx as usize].is_empty() {
-                self.points_count += 1;
-            }
-
             points_to_hashes
                 .entry(idx)
                 .or_default()
@@ -144,21 +187,87 @@ impl MutableGeoMapIndex {
                 self.increment_hash_value_counts(&geo_hash);
             }
         }
+
+        for (idx, shapes) in points_to_shapes {
+            self.insert_shapes(idx, &shapes)?;
+        }
+
+        let max_idx = max(self.point_to_values.len(), self.point_to_shapes.len());
+        self.points_count = (0..max_idx as PointOffsetType)
+            .filter(|idx| self.has_values(*idx))
+            .count();
         Ok(true)
     }
 
+    /// Add shapes of the point into in-memory structures
+    fn insert_shapes(&mut self, idx: PointOffsetType, shapes: &[GeoShape]) -> OperationResult<()> {
+        if self.point_to_shapes.len() <= idx as usize {
+            self.point_to_shapes.resize_with(idx as usize + 1, Vec::new);
+        }
+
+        let mut geometries = Vec::with_capacity(shapes.len());
+        for shape in shapes {
+            let geometry = shape.to_geometry();
+            for geo_hash in geometry_hashes(&geometry, GEO_SHAPE_MAX_REGION)? {
+                self.shapes_map.entry(geo_hash).or_default().insert(idx);
+            }
+            geometries.push(geometry);
+        }
+
+        self.points_values_count += geometries.len();
+        self.point_to_shapes[idx as usize] = geometries;
+        Ok(())
+    }
+
+    /// Remove shapes of the point from in-memory structures and storage
+    fn remove_shapes(&mut self, idx: PointOffsetType) -> OperationResult<()> {
+        let Some(removed_shapes) = self.point_to_shapes.get_mut(idx as usize) else {
+            return Ok(());
+        };
+        let removed_shapes = std::mem::take(removed_shapes);
+
+        if removed_shapes.is_empty() {
+            return Ok(());
+        }
+
+        self.points_values_count -= removed_shapes.len();
+        self.db_wrapper
+            .remove(GeoMapIndex::encode_db_key(SHAPES_DB_KEY_PREFIX, idx))?;
+
+        for removed_shape in &removed_shapes {
+            for geo_hash in geometry_hashes(removed_shape, GEO_SHAPE_MAX_REGION)? {
+                let is_last = if let Some(hash_ids) = self.shapes_map.get_mut(&geo_hash) {
+                    hash_ids.remove(&idx);
+                    hash_ids.is_empty()
+                } else {
+                    false
+                };
+
+                if is_last {
+                    self.shapes_map.remove(&geo_hash);
+                }
+            }
+        }
+        Ok(())
+    }
+
     fn remove_point(&mut self, idx: PointOffsetType) -> OperationResult<()> {
-        if self.point_to_values.len() <= idx as usize {
+        if !self.has_values(idx) {
             return Ok(()); // Already removed or never actually existed
         }
 
-        let removed_geo_points = std::mem::take(&mut self.point_to_values[idx as usize]);
+        self.points_count -= 1;
+        self.remove_shapes(idx)?;
+
+        let Some(removed_geo_points) = self.point_to_values.get_mut(idx as usize) else {
+            return Ok(());
+        };
+        let removed_geo_points = std::mem::take(removed_geo_points);
 
         if removed_geo_points.is_empty() {
             return Ok(());
         }
 
-        self.points_count -= 1;
         self.points_values_count -= removed_geo_points.len();
         let mut removed_geo_hashes = Vec::with_capacity(removed_geo_points.len());
 
@@ -192,6 +301,35 @@ impl MutableGeoMapIndex {
         Ok(())
     }
 
+    fn add_many_geo_values(
+        &mut self,
+        idx: PointOffsetType,
+        values: Vec<GeoValue>,
+    ) -> OperationResult<()> {
+        if values.is_empty() {
+            return Ok(());
+        }
+
+        let (points, shapes): (Vec<_>, Vec<_>) =
+            values.into_iter().partition_map(|value| match value {
+                GeoValue::Point(point) => itertools::Either::Left(point),
+                GeoValue::Shape(shape) => itertools::Either::Right(shape),
+            });
+
+        if !shapes.is_empty() {
+            self.insert_shapes(idx, &shapes)?;
+            let key = GeoMapIndex::encode_db_key(SHAPES_DB_KEY_PREFIX, idx);
+            let value = serde_json::to_vec(&shapes).map_err(|e| {
+                OperationError::service_error(format!("Failed to serialize geo shapes: {e}"))
+            })?;
+            self.db_wrapper.put(key, value)?;
+        }
+
+        self.add_many_geo_points(idx, &points)?;
+        self.points_count += 1;
+        Ok(())
+    }
+
     fn add_many_geo_points(
         &mut self,
         idx: PointOffsetType,
@@ -234,7 +372,6 @@ impl MutableGeoMapIndex {
         self.increment_hash_point_counts(&geo_hashes);
 
         self.points_values_count += values.len();
-        self.points_count += 1;
         self.max_values_per_point = self.max_values_per_point.max(values.len());
         Ok(())
     }
@@ -251,6 +388,25 @@ impl MutableGeoMapIndex {
         )
     }
 
+    /// Get points with shapes, which cover regions overlapping the given geo-hash
+    ///
+    /// Shapes are covered by geo-hashes of different precision,
+    /// so both enclosing and enclosed regions are considered.
+    fn get_shapes_of_region(&self, geo: GeoHash) -> impl Iterator<Item = PointOffsetType> + '_ {
+        let enclosing = (0..geo.len())
+            .filter_map(|i| self.shapes_map.get(&geo[0..i]))
+            .collect_vec();
+        let enclosed = self
+            .shapes_map
+            .range(geo.clone()..)
+            .take_while(move |(p, _h)| p.starts_with(geo.as_str()))
+            .map(|(_p, points)| points);
+        enclosing
+            .into_iter()
+            .chain(enclosed)
+            .flat_map(|points| points.iter().copied())
+    }
+
     fn increment_hash_value_counts(&mut self, geo_hash: &GeoHash) {
         for i in 0..=geo_hash.len() {
             let sub_geo_hash = &geo_hash[0..i];
@@ -453,6 +609,18 @@ impl GeoMapIndex {
         }
     }
 
+    pub fn get_shapes(&self, idx: PointOffsetType) -> Option<&[Geometry]> {
+        match self {
+            GeoMapIndex::Mutable(index) => index.get_shapes(idx),
+        }
+    }
+
+    fn get_shapes_of_region(&self, geo: GeoHash) -> Box<dyn Iterator<Item = PointOffsetType> + '_> {
+        match self {
+            GeoMapIndex::Mutable(index) => Box::new(index.get_shapes_of_region(geo)),
+        }
+    }
+
     pub fn check_radius(&self, idx: PointOffsetType, radius: &GeoRadius) -> bool {
         self.get_values(idx)
             .map(|values| values.iter().any(|x| radius.check_point(x)))
@@ -471,6 +639,92 @@ impl GeoMapIndex {
             .unwrap_or(false)
     }
 
+    /// Check if any geo point or geo shape of the point is in `relation` with the `query` geometry
+    pub fn check_geo_shape(
+        &self,
+        idx: PointOffsetType,
+        relation: GeoShapeRelation,
+        query: &Geometry,
+    ) -> bool {
+        let points_match = self.get_values(idx).map_or(false, |values| {
+            values.iter().any(|point| {
+                relation.check(&Geometry::Point(Point::new(point.lon, point.lat)), query)
+            })
+        });
+        points_match
+            || self.get_shapes(idx).map_or(false, |shapes| {
+                shapes.iter().any(|shape| relation.check(shape, query))
+            })
+    }
+
+    /// Distance in meters from the `origin` to the closest geo point of the point
+    pub fn distance_to(&self, idx: PointOffsetType, origin: &GeoPoint) -> Option<f64> {
+        let origin = Point::new(origin.lon, origin.lat);
+        self.get_values(idx)?
+            .iter()
+            .map(|value| origin.haversine_distance(&Point::new(value.lon, value.lat)))
+            .min_by_key(|distance| OrderedFloat(*distance))
+    }
+
+    /// Up to `limit` points, accepted by `check_fn`, ordered by the distance to the `origin`.
+    ///
+    /// `check_fn` receives the distance to the `origin` together with the point.
+    /// Points at equal distance are ordered by `tie_key`, so that callers can keep the order
+    /// consistent with their own cursor, e.g. by external id.
+    /// Expands the searched area over rings of geo-hash cells, starting from the highest precision.
+    /// The search stops once there are enough points within the radius, which is guaranteed
+    /// to be fully covered by the ring.
+    /// Only geo points are considered, geo shapes are ignored.
+    pub fn points_by_distance<K: Ord>(
+        &self,
+        origin: &GeoPoint,
+        limit: usize,
+        check_fn: impl Fn(f64, PointOffsetType) -> bool,
+        tie_key: impl Fn(PointOffsetType) -> K,
+    ) -> OperationResult<Vec<(f64, PointOffsetType)>> {
+        if limit == 0 {
+            return Ok(vec![]);
+        }
+
+        let collect_ordered = |cells: Vec<GeoHash>| {
+            self.get_iterator(cells)
+                .filter_map(|idx| {
+                    let distance = self.distance_to(idx, origin)?;
+                    check_fn(distance, idx).then(|| (distance, tie_key(idx), idx))
+                })
+                .sorted_by(|(distance_a, key_a, _), (distance_b, key_b, _)| {
+                    (OrderedFloat(*distance_a), key_a).cmp(&(OrderedFloat(*distance_b), key_b))
+                })
+                .map(|(distance, _, idx)| (distance, idx))
+                .collect_vec()
+        };
+
+        for precision in (1..=GEOHASH_MAX_LENGTH).rev() {
+            let (ring, radius) = geo_hash_ring(origin, precision)?;
+
+            // Not enough points in the ring, no need to check them
+            let ring_points: usize = ring.iter().map(|cell| self.get_points_of_hash(cell)).sum();
+            if ring_points < limit {
+                continue;
+            }
+
+            let mut candidates = collect_ordered(ring);
+            let covered = candidates
+                .iter()
+                .take_while(|(distance, _)| *distance <= radius)
+                .count();
+            if covered >= limit {
+                candidates.truncate(limit);
+                return Ok(candidates);
+            }
+        }
+
+        // Empty geo-hash covers the whole globe
+        let mut candidates = collect_ordered(vec![GeoHash::default()]);
+        candidates.truncate(limit);
+        Ok(candidates)
+    }
+
     pub fn match_cardinality(&self, values: &[GeoHash]) -> CardinalityEstimation {
         let max_values_per_point = self.max_values_per_point();
         if max_values_per_point == 0 {
@@ -539,6 +793,19 @@ impl GeoMapIndex {
         )
     }
 
+    /// Get iterator over points with shapes, overlapping any of the given geo-hash regions
+    fn get_shapes_iterator(
+        &self,
+        values: Vec<GeoHash>,
+    ) -> Box<dyn Iterator<Item = PointOffsetType> + '_> {
+        Box::new(
+            values
+                .into_iter()
+                .flat_map(|top_geo_hash| self.get_shapes_of_region(top_geo_hash))
+                .unique(),
+        )
+    }
+
     /// Get iterator over smallest geo-hash regions larger than `threshold` points
     fn get_large_hashes(
         &self,
@@ -570,30 +837,34 @@ impl GeoMapIndex {
 
     pub fn values_count(&self, point_id: PointOffsetType) -> usize {
         self.get_values(point_id).map(|x| x.len()).unwrap_or(0)
+            + self.get_shapes(point_id).map(|x| x.len()).unwrap_or(0)
     }
 
     pub fn values_is_empty(&self, point_id: PointOffsetType) -> bool {
-        self.get_values(point_id)
-            .map(|x| x.is_empty())
-            .unwrap_or(true)
+        self.values_count(point_id) == 0
     }
 }
 
-impl ValueIndexer<GeoPoint> for GeoMapIndex {
-    fn add_many(&mut self, id: PointOffsetType, values: Vec<GeoPoint>) -> OperationResult<()> {
+impl ValueIndexer<GeoValue> for GeoMapIndex {
+    fn add_many(&mut self, id: PointOffsetType, values: Vec<GeoValue>) -> OperationResult<()> {
         match self {
-            GeoMapIndex::Mutable(index) => index.add_many_geo_points(id, &values),
+            GeoMapIndex::Mutable(index) => index.add_many_geo_values(id, values),
         }
     }
 
-    fn get_value(&self, value: &Value) -> Option<GeoPoint> {
+    fn get_value(&self, value: &Value) -> Option<GeoValue> {
         match value {
             Value::Object(obj) => {
                 let lon_op = obj.get("lon").and_then(|x| x.as_f64());
                 let lat_op = obj.get("lat").and_then(|x| x.as_f64());
 
                 if let (Some(lon), Some(lat)) = (lon_op, lat_op) {
-                    return GeoPoint::new(lon, lat).ok();
+                    return GeoPoint::new(lon, lat).ok().map(GeoValue::Point);
+                }
+                if obj.contains_key("coordinates") {
+                    return serde_json::from_value(value.clone())
+                        .ok()
+                        .map(GeoValue::Shape);
                 }
                 None
             }
@@ -670,6 +941,19 @@ impl PayloadFieldIndex for GeoMapIndex {
             )));
         }
 
+        if let Some(geo_shape) = &condition.geo_shape {
+            let query = geo_shape.shape.to_geometry();
+            let geo_hashes = geometry_hashes(&query, GEO_QUERY_MAX_REGION)?;
+            let relation = geo_shape.relation;
+            let candidates = self
+                .get_iterator(geo_hashes.clone())
+                .chain(self.get_shapes_iterator(geo_hashes))
+                .unique();
+            return Ok(Box::new(candidates.filter(move |point| {
+                self.check_geo_shape(*point, relation, &query)
+            })));
+        }
+
         Err(OperationError::service_error("failed to filter"))
     }
 
@@ -721,6 +1005,19 @@ impl PayloadFieldIndex for GeoMapIndex {
             return Ok(exterior_estimation);
         }
 
+        if let Some(geo_shape) = &condition.geo_shape {
+            let geo_hashes = geometry_hashes(&geo_shape.shape.to_geometry(), GEO_QUERY_MAX_REGION)?;
+            let mut estimation = self.match_cardinality(&geo_hashes);
+            // Shapes are not accounted in per-hash counters, so count shape candidates directly
+            let shape_candidates = self.get_shapes_iterator(geo_hashes).count();
+            estimation.max = min(self.points_count(), estimation.max + shape_candidates);
+            estimation.exp = min(estimation.max, estimation.exp + shape_candidates);
+            estimation
+                .primary_clauses
+                .push(PrimaryCondition::Condition(condition.clone()));
+            return Ok(estimation);
+        }
+
         Err(OperationError::service_error(
             "failed to estimate cardinality",
         ))
@@ -750,7 +1047,7 @@ mod tests {
 
     use itertools::Itertools;
     use rand::prelude::StdRng;
-    use rand::SeedableRng;
+    use rand::{Rng, SeedableRng};
     use serde_json::json;
     use tempfile::Builder;
 
@@ -759,7 +1056,7 @@ mod tests {
     use crate::common::utils::MultiValue;
     use crate::fixtures::payload_fixtures::random_geo_payload;
     use crate::types::test_utils::build_polygon;
-    use crate::types::{GeoLineString, GeoPolygon, GeoRadius};
+    use crate::types::{GeoLineString, GeoPolygon, GeoRadius, GeoShapeCondition, GeoShapeQuery};
 
     const NYC: GeoPoint = GeoPoint {
         lat: 40.75798,
@@ -1314,6 +1611,160 @@ mod tests {
         assert_eq!(new_index.points_values_count(), 2);
     }
 
+    #[test]
+    fn geo_shape_filtering() {
+        fn filter_sorted(index: &GeoMapIndex, condition: &FieldCondition) -> Vec<PointOffsetType> {
+            let mut points = index.filter(condition).unwrap().collect_vec();
+            points.sort_unstable();
+
+            let cardinality = index.estimate_cardinality(condition).unwrap();
+            assert!(cardinality.min <= points.len());
+            assert!(cardinality.max >= points.len());
+            points
+        }
+
+        let shape_condition = |relation, shape| {
+            FieldCondition::new_geo_shape(FIELD_NAME, GeoShapeCondition { relation, shape })
+        };
+
+        let berlin_zone = json!({
+            "type": "Polygon",
+            "coordinates": [[[13.0, 52.3], [13.8, 52.3], [13.8, 52.7], [13.0, 52.7], [13.0, 52.3]]]
+        });
+        let berlin_potsdam_road = json!({
+            "type": "LineString",
+            "coordinates": [[BERLIN.lon, BERLIN.lat], [POTSDAM.lon, POTSDAM.lat]]
+        });
+        let berlin_point = json!({"lon": BERLIN.lon, "lat": BERLIN.lat});
+        let tokyo_zones = json!([{
+            "type": "MultiPolygon",
+            "coordinates": [
+                [[[139.5, 35.5], [139.9, 35.5], [139.9, 35.9], [139.5, 35.9], [139.5, 35.5]]],
+                [[[139.0, 35.0], [139.1, 35.0], [139.1, 35.1], [139.0, 35.0]]]
+            ]
+        }, {"lon": TOKYO.lon, "lat": TOKYO.lat}]);
+
+        let intersects_berlin =
+            shape_condition(GeoShapeRelation::Intersects, GeoShapeQuery::Point(BERLIN));
+        let within_berlin_area = shape_condition(
+            GeoShapeRelation::Within,
+            GeoShapeQuery::BoundingBox(GeoBoundingBox {
+                top_left: GeoPoint {
+                    lon: 12.9,
+                    lat: 52.8,
+                },
+                bottom_right: GeoPoint {
+                    lon: 13.9,
+                    lat: 52.2,
+                },
+            }),
+        );
+        let contains_berlin_center = shape_condition(
+            GeoShapeRelation::Contains,
+            GeoShapeQuery::Polygon(build_polygon(vec![
+                (13.3, 52.45),
+                (13.5, 52.45),
+                (13.5, 52.55),
+                (13.3, 52.55),
+                (13.3, 52.45),
+            ])),
+        );
+        let intersects_tokyo =
+            shape_condition(GeoShapeRelation::Intersects, GeoShapeQuery::Point(TOKYO));
+
+        let temp_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+        {
+            let db = open_db_with_existing_cf(&temp_dir.path().join("test_db")).unwrap();
+            let mut index = GeoMapIndex::new(db, FIELD_NAME);
+            index.recreate().unwrap();
+
+            index.add_point(1, &MultiValue::one(&berlin_zone)).unwrap();
+            index
+                .add_point(2, &MultiValue::one(&berlin_potsdam_road))
+                .unwrap();
+            index.add_point(3, &MultiValue::one(&berlin_point)).unwrap();
+            index.add_point(4, &MultiValue::one(&tokyo_zones)).unwrap();
+
+            assert_eq!(index.points_count(), 4);
+            assert_eq!(index.points_values_count(), 5);
+            assert_eq!(index.values_count(4), 2);
+
+            assert_eq!(filter_sorted(&index, &intersects_berlin), vec![1, 2, 3]);
+            assert_eq!(filter_sorted(&index, &within_berlin_area), vec![1, 2, 3]);
+            assert_eq!(filter_sorted(&index, &contains_berlin_center), vec![1]);
+            assert_eq!(filter_sorted(&index, &intersects_tokyo), vec![4]);
+
+            // point-only conditions do not match shapes
+            let berlin_radius = condition_for_geo_radius(
+                FIELD_NAME.to_string(),
+                GeoRadius {
+                    center: BERLIN,
+                    radius: 1000.0,
+                },
+            );
+            assert_eq!(filter_sorted(&index, &berlin_radius), vec![3]);
+
+            index.flusher()().unwrap();
+        }
+
+        let db = open_db_with_existing_cf(&temp_dir.path().join("test_db")).unwrap();
+        let mut index = GeoMapIndex::new(db, FIELD_NAME);
+        index.load().unwrap();
+
+        assert_eq!(index.points_count(), 4);
+        assert_eq!(index.points_values_count(), 5);
+        assert_eq!(filter_sorted(&index, &intersects_berlin), vec![1, 2, 3]);
+        assert_eq!(filter_sorted(&index, &contains_berlin_center), vec![1]);
+
+        index.remove_point(1).unwrap();
+        index.remove_point(4).unwrap();
+        assert_eq!(index.points_count(), 2);
+        assert_eq!(index.points_values_count(), 2);
+        assert!(index.values_is_empty(4));
+        assert_eq!(filter_sorted(&index, &intersects_berlin), vec![2, 3]);
+        assert!(filter_sorted(&index, &contains_berlin_center).is_empty());
+        assert!(filter_sorted(&index, &intersects_tokyo).is_empty());
+    }
+
+    #[test]
+    fn points_by_distance_matches_full_scan() {
+        let num_points = 1000;
+        let mut index = build_random_index(num_points, 2);
+
+        // Dense cluster around Berlin, so the ring expansion can stop early
+        let mut rnd = StdRng::seed_from_u64(43);
+        for idx in num_points..num_points + 100 {
+            let value = json!({
+                "lon": BERLIN.lon + rnd.gen_range(-0.05..0.05),
+                "lat": BERLIN.lat + rnd.gen_range(-0.05..0.05),
+            });
+            index
+                .add_point(idx as PointOffsetType, &MultiValue::one(&value))
+                .unwrap();
+        }
+
+        for origin in [NYC, BERLIN, POTSDAM, TOKYO] {
+            // Skip odd points and points within 10 km
+            let start_from = 10_000.0;
+            let check =
+                |distance: f64, idx: PointOffsetType| idx % 2 == 0 && distance >= start_from;
+
+            for limit in [1, 10, 50, 200] {
+                let expected = (0..(num_points + 100) as PointOffsetType)
+                    .filter_map(|idx| index.distance_to(idx, &origin).map(|d| (d, idx)))
+                    .filter(|(distance, idx)| check(*distance, *idx))
+                    .sorted_by_key(|(distance, idx)| (OrderedFloat(*distance), *idx))
+                    .take(limit)
+                    .collect_vec();
+
+                let ordered = index
+                    .points_by_distance(&origin, limit, check, |idx| idx)
+                    .unwrap();
+                assert_eq!(ordered, expected, "origin: {origin:?}, limit: {limit}");
+            }
+        }
+    }
+
     #[test]
     fn test_empty_index_cardinality() {
         let polygon = GeoPolygon {
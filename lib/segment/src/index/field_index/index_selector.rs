@@ -6,6 +6,7 @@ use rocksdb::DB;
 use super::binary_index::BinaryIndex;
 use crate::index::field_index::full_text_index::text_index::FullTextIndex;
 use crate::index::field_index::geo_index::GeoMapIndex;
+use crate::index::field_index::map_index::keyword_index::KeywordIndex;
 use crate::index::field_index::map_index::MapIndex;
 use crate::index::field_index::numeric_index::NumericIndex;
 use crate::index::field_index::FieldIndex;
@@ -23,8 +24,9 @@ pub fn index_selector(
     match payload_schema {
         PayloadFieldSchema::FieldType(payload_type) => match payload_type {
             PayloadSchemaType::Keyword => {
-                vec![FieldIndex::KeywordIndex(MapIndex::new(
+                vec![FieldIndex::KeywordIndex(KeywordIndex::new(
                     db,
+                    Default::default(),
                     field,
                     is_appendable,
                 ))]
@@ -51,6 +53,14 @@ pub fn index_selector(
             PayloadSchemaType::Bool => vec![FieldIndex::BinaryIndex(BinaryIndex::new(db, field))],
         },
         PayloadFieldSchema::FieldParams(payload_params) => match payload_params {
+            PayloadSchemaParams::Keyword(keyword_index_params) => {
+                vec![FieldIndex::KeywordIndex(KeywordIndex::new(
+                    db,
+                    keyword_index_params.clone(),
+                    field,
+                    is_appendable,
+                ))]
+            }
             PayloadSchemaParams::Text(text_index_params) => vec![FieldIndex::FullTextIndex(
                 FullTextIndex::new(db, text_index_params.clone(), field),
             )],
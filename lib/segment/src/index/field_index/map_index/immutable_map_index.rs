@@ -9,6 +9,7 @@ use std::sync::Arc;
 use common::types::PointOffsetType;
 use parking_lot::RwLock;
 use rocksdb::DB;
+use smol_str::SmolStr;
 
 use super::mutable_map_index::MutableMapIndex;
 use super::MapIndex;
@@ -17,6 +18,8 @@ use crate::common::rocksdb_wrapper::DatabaseColumnWrapper;
 
 pub struct ImmutableMapIndex<N: Hash + Eq + Clone + Display + FromStr> {
     value_to_points: HashMap<N, Range<u32>>,
+    /// Indexed values in ascending order, used for range scans over keys
+    sorted_values: Vec<N>,
     value_to_points_container: Vec<PointOffsetType>,
     point_to_values: Vec<Range<u32>>,
     point_to_values_container: Vec<N>,
@@ -26,12 +29,13 @@ pub struct ImmutableMapIndex<N: Hash + Eq + Clone + Display + FromStr> {
     db_wrapper: DatabaseColumnWrapper,
 }
 
-impl<N: Hash + Eq + Clone + Display + FromStr + Default> ImmutableMapIndex<N> {
+impl<N: Hash + Eq + Ord + Clone + Display + FromStr + Default> ImmutableMapIndex<N> {
     pub fn new(db: Arc<RwLock<DB>>, field_name: &str) -> Self {
         let store_cf_name = MapIndex::<N>::storage_cf_name(field_name);
         let db_wrapper = DatabaseColumnWrapper::new(db, &store_cf_name);
         Self {
             value_to_points: Default::default(),
+            sorted_values: Default::default(),
             value_to_points_container: Default::default(),
             point_to_values: Default::default(),
             point_to_values_container: Default::default(),
@@ -107,6 +111,9 @@ impl<N: Hash + Eq + Clone + Display + FromStr + Default> ImmutableMapIndex<N> {
 
         if self.shrink_value_range(value) {
             self.value_to_points.remove(value);
+            if let Ok(pos) = self.sorted_values.binary_search(value) {
+                self.sorted_values.remove(pos);
+            }
         }
     }
 
@@ -168,6 +175,7 @@ impl<N: Hash + Eq + Clone + Display + FromStr + Default> ImmutableMapIndex<N> {
         self.indexed_points = indexed_points;
         self.values_count = values_count;
         self.value_to_points.clear();
+        self.sorted_values.clear();
         self.value_to_points_container.clear();
         self.point_to_values.clear();
         self.point_to_values_container.clear();
@@ -180,6 +188,8 @@ impl<N: Hash + Eq + Clone + Display + FromStr + Default> ImmutableMapIndex<N> {
             self.value_to_points.insert(value, range.clone());
             self.value_to_points_container.extend(points);
         }
+        self.sorted_values = self.value_to_points.keys().cloned().collect();
+        self.sorted_values.sort_unstable();
 
         // flatten points-to-values map
         for values in point_to_values {
@@ -238,3 +248,15 @@ impl<N: Hash + Eq + Clone + Display + FromStr + Default> ImmutableMapIndex<N> {
         Box::new(self.value_to_points.keys())
     }
 }
+
+impl ImmutableMapIndex<SmolStr> {
+    /// Iterate over indexed keywords starting with `prefix` in ascending order
+    pub fn get_values_with_prefix(&self, prefix: String) -> impl Iterator<Item = &SmolStr> + '_ {
+        let start = self
+            .sorted_values
+            .partition_point(|value| value.as_str() < prefix.as_str());
+        self.sorted_values[start..]
+            .iter()
+            .take_while(move |value| value.starts_with(prefix.as_str()))
+    }
+}
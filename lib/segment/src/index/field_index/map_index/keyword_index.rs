@@ -0,0 +1,252 @@
+use std::sync::Arc;
+
+use common::types::PointOffsetType;
+use parking_lot::RwLock;
+use rocksdb::DB;
+use serde_json::Value;
+use smol_str::SmolStr;
+
+use super::MapIndex;
+use crate::common::operation_error::OperationResult;
+use crate::common::Flusher;
+use crate::data_types::keyword_index::KeywordIndexParams;
+use crate::index::field_index::{
+    CardinalityEstimation, PayloadBlockCondition, PayloadFieldIndex, ValueCheckerFn, ValueIndexer,
+};
+use crate::payload_storage::condition_checker::check_normalized_match;
+use crate::telemetry::PayloadIndexTelemetry;
+use crate::types::{FieldCondition, Match, PayloadKeyType};
+
+/// Map index over keywords, which are normalized according to the index params
+/// both on indexing and on querying
+pub struct KeywordIndex {
+    index: MapIndex<SmolStr>,
+    params: KeywordIndexParams,
+}
+
+impl KeywordIndex {
+    pub fn new(
+        db: Arc<RwLock<DB>>,
+        params: KeywordIndexParams,
+        field_name: &str,
+        is_appendable: bool,
+    ) -> Self {
+        Self {
+            index: MapIndex::new(db, field_name, is_appendable),
+            params,
+        }
+    }
+
+    pub fn get_values(&self, idx: PointOffsetType) -> Option<&[SmolStr]> {
+        self.index.get_values(idx)
+    }
+
+    pub fn recreate(&self) -> OperationResult<()> {
+        self.index.recreate()
+    }
+
+    pub fn get_telemetry_data(&self) -> PayloadIndexTelemetry {
+        self.index.get_telemetry_data()
+    }
+
+    pub fn values_count(&self, point_id: PointOffsetType) -> usize {
+        self.index.values_count(point_id)
+    }
+
+    pub fn values_is_empty(&self, point_id: PointOffsetType) -> bool {
+        self.index.values_is_empty(point_id)
+    }
+
+    /// Normalize keywords of the match condition the same way as indexed keywords
+    pub fn normalize_match(&self, r#match: Match) -> Match {
+        self.params.normalize_match(r#match)
+    }
+
+    /// Prepare a check of the condition against payload values with normalized keywords.
+    /// Returns `None` if the index doesn't normalize keywords.
+    pub fn value_checker(&self, condition: &FieldCondition) -> Option<ValueCheckerFn<'_>> {
+        if !self.params.is_normalized() || condition.values_count.is_some() {
+            return None;
+        }
+        let r#match = condition.r#match.clone()?;
+        Some(Box::new(move |payload_value: &Value| {
+            check_normalized_match(&r#match, payload_value, &self.params)
+        }))
+    }
+
+    fn normalize_condition(&self, condition: &FieldCondition) -> FieldCondition {
+        FieldCondition {
+            r#match: condition
+                .r#match
+                .clone()
+                .map(|r#match| self.normalize_match(r#match)),
+            ..condition.clone()
+        }
+    }
+}
+
+impl PayloadFieldIndex for KeywordIndex {
+    fn count_indexed_points(&self) -> usize {
+        self.index.count_indexed_points()
+    }
+
+    fn load(&mut self) -> OperationResult<bool> {
+        self.index.load()
+    }
+
+    fn clear(self) -> OperationResult<()> {
+        self.index.clear()
+    }
+
+    fn flusher(&self) -> Flusher {
+        PayloadFieldIndex::flusher(&self.index)
+    }
+
+    fn filter<'a>(
+        &'a self,
+        condition: &'a FieldCondition,
+    ) -> OperationResult<Box<dyn Iterator<Item = PointOffsetType> + 'a>> {
+        if !self.params.is_normalized() {
+            return self.index.filter(condition);
+        }
+        // Normalized condition doesn't outlive this call, so points are collected
+        let condition = self.normalize_condition(condition);
+        let points: Vec<_> = self.index.filter(&condition)?.collect();
+        Ok(Box::new(points.into_iter()))
+    }
+
+    fn estimate_cardinality(
+        &self,
+        condition: &FieldCondition,
+    ) -> OperationResult<CardinalityEstimation> {
+        if !self.params.is_normalized() {
+            return self.index.estimate_cardinality(condition);
+        }
+        self.index
+            .estimate_cardinality(&self.normalize_condition(condition))
+    }
+
+    fn payload_blocks(
+        &self,
+        threshold: usize,
+        key: PayloadKeyType,
+    ) -> Box<dyn Iterator<Item = PayloadBlockCondition> + '_> {
+        self.index.payload_blocks(threshold, key)
+    }
+}
+
+impl ValueIndexer<String> for KeywordIndex {
+    fn add_many(&mut self, id: PointOffsetType, values: Vec<String>) -> OperationResult<()> {
+        self.index.add_many(id, values)
+    }
+
+    fn get_value(&self, value: &Value) -> Option<String> {
+        self.index
+            .get_value(value)
+            .map(|keyword| self.params.normalize(&keyword).into_owned())
+    }
+
+    fn remove_point(&mut self, id: PointOffsetType) -> OperationResult<()> {
+        self.index.remove_point(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde_json::json;
+    use tempfile::Builder;
+
+    use super::*;
+    use crate::common::rocksdb_wrapper::open_db_with_existing_cf;
+    use crate::common::utils::MultiValue;
+    use crate::data_types::keyword_index::KeywordIndexType;
+    use crate::index::field_index::FieldIndex;
+    use crate::payload_storage::query_checker::check_field_condition;
+    use crate::types::{
+        AnyVariants, MatchAny, MatchPrefix, MatchValue, MatchWildcard, ValueVariants,
+    };
+
+    const FIELD_NAME: &str = "test";
+
+    #[test]
+    fn test_normalized_keyword_index() {
+        let params = KeywordIndexParams {
+            r#type: KeywordIndexType::Keyword,
+            lowercase: Some(true),
+            nfkc: Some(true),
+        };
+        let payloads = [
+            json!("Berlin"),
+            json!(["ＢＥＲＬＩＮ", "Paris"]),
+            json!("berlin"),
+            json!("Bern"),
+            json!(1),
+        ];
+
+        let temp_dir = Builder::new().prefix("store_dir").tempdir().unwrap();
+        let db = open_db_with_existing_cf(temp_dir.path()).unwrap();
+        let mut index = KeywordIndex::new(db, params, FIELD_NAME, true);
+        index.recreate().unwrap();
+        for (idx, payload) in payloads.iter().enumerate() {
+            index
+                .add_point(idx as PointOffsetType, &MultiValue::Single(Some(payload)))
+                .unwrap();
+        }
+        assert_eq!(index.get_values(1).unwrap(), ["berlin", "paris"]);
+
+        let field_indexes = HashMap::from([(
+            PayloadKeyType::from(FIELD_NAME),
+            vec![FieldIndex::KeywordIndex(index)],
+        )]);
+        let index = &field_indexes[FIELD_NAME][0];
+
+        let cases = [
+            (
+                Match::Value(MatchValue {
+                    value: ValueVariants::Keyword("BERLIN".to_string()),
+                }),
+                vec![0, 1, 2],
+            ),
+            (
+                Match::Any(MatchAny {
+                    any: AnyVariants::Keywords(vec!["ｐａｒｉｓ".to_string()]),
+                }),
+                vec![1],
+            ),
+            (
+                Match::Prefix(MatchPrefix {
+                    prefix: "BER".to_string(),
+                }),
+                vec![0, 1, 2, 3],
+            ),
+            (
+                Match::Wildcard(MatchWildcard {
+                    wildcard: "B?RN".to_string(),
+                }),
+                vec![3],
+            ),
+        ];
+
+        for (r#match, expected) in cases {
+            let condition = FieldCondition::new_match(FIELD_NAME, r#match);
+
+            let mut points: Vec<_> = index.filter(&condition).unwrap().collect();
+            points.sort_unstable();
+            assert_eq!(points, expected, "{condition:?}");
+
+            // Checks against payload give the same result as the index
+            let checked: Vec<_> = payloads
+                .iter()
+                .enumerate()
+                .filter(|(_, payload)| {
+                    let payload = json!({ FIELD_NAME: payload });
+                    check_field_condition(&condition, payload.as_object().unwrap(), &field_indexes)
+                })
+                .map(|(idx, _)| idx as PointOffsetType)
+                .collect();
+            assert_eq!(checked, expected, "{condition:?}");
+        }
+    }
+}
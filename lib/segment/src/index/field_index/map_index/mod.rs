@@ -1,4 +1,5 @@
 pub mod immutable_map_index;
+pub mod keyword_index;
 pub mod mutable_map_index;
 
 use std::fmt::Display;
@@ -15,6 +16,7 @@ use rocksdb::DB;
 use serde_json::Value;
 use smol_str::SmolStr;
 
+use crate::common::keyword_matcher::KeywordMatcher;
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::common::rocksdb_wrapper::DatabaseColumnWrapper;
 use crate::common::Flusher;
@@ -34,7 +36,7 @@ pub enum MapIndex<N: Hash + Eq + Clone + Display + FromStr> {
     Immutable(ImmutableMapIndex<N>),
 }
 
-impl<N: Hash + Eq + Clone + Display + FromStr + Default> MapIndex<N> {
+impl<N: Hash + Eq + Ord + Clone + Display + FromStr + Default> MapIndex<N> {
     pub fn new(db: Arc<RwLock<DB>>, field_name: &str, is_appendable: bool) -> Self {
         if is_appendable {
             MapIndex::Mutable(MutableMapIndex::new(db, field_name))
@@ -307,6 +309,28 @@ impl<N: Hash + Eq + Clone + Display + FromStr + Default> MapIndex<N> {
     }
 }
 
+impl MapIndex<SmolStr> {
+    /// Indexed keywords accepted by the matcher.
+    /// Immutable index only scans sorted keywords sharing the literal prefix of the pattern.
+    fn get_matching_values(
+        &self,
+        matcher: KeywordMatcher,
+    ) -> Box<dyn Iterator<Item = &SmolStr> + '_> {
+        match self {
+            MapIndex::Mutable(index) => Box::new(
+                index
+                    .get_values_iterator()
+                    .filter(move |keyword| matcher.is_match(keyword)),
+            ),
+            MapIndex::Immutable(index) => Box::new(
+                index
+                    .get_values_with_prefix(matcher.literal_prefix().to_string())
+                    .filter(move |keyword| matcher.is_match(keyword)),
+            ),
+        }
+    }
+}
+
 impl PayloadFieldIndex for MapIndex<SmolStr> {
     fn count_indexed_points(&self) -> usize {
         self.get_indexed_points()
@@ -352,6 +376,15 @@ impl PayloadFieldIndex for MapIndex<SmolStr> {
             Some(Match::Except(MatchExcept {
                 except: AnyVariants::Keywords(keywords),
             })) => Ok(self.except_iterator(keywords)),
+            Some(r#match @ (Match::Prefix(_) | Match::Wildcard(_) | Match::Regex(_))) => {
+                let matcher = KeywordMatcher::new(r#match)
+                    .ok_or_else(|| OperationError::service_error("failed to filter"))?;
+                Ok(Box::new(
+                    self.get_matching_values(matcher)
+                        .flat_map(|keyword| self.get_iterator(keyword.as_str()))
+                        .unique(),
+                ))
+            }
             _ => Err(OperationError::service_error("failed to filter")),
         }
     }
@@ -398,6 +431,21 @@ impl PayloadFieldIndex for MapIndex<SmolStr> {
             Some(Match::Except(MatchExcept {
                 except: AnyVariants::Keywords(keywords),
             })) => Ok(self.except_cardinality::<str, &str>(keywords.iter().map(|k| k.as_str()))),
+            Some(r#match @ (Match::Prefix(_) | Match::Wildcard(_) | Match::Regex(_))) => {
+                let matcher = KeywordMatcher::new(r#match).ok_or_else(|| {
+                    OperationError::service_error("failed to estimate cardinality")
+                })?;
+                let estimations = self
+                    .get_matching_values(matcher)
+                    .map(|keyword| self.match_cardinality(keyword.as_str()))
+                    .collect::<Vec<_>>();
+                let estimation = if estimations.is_empty() {
+                    CardinalityEstimation::exact(0)
+                } else {
+                    combine_should_estimations(&estimations, self.get_indexed_points())
+                };
+                Ok(estimation.with_primary_clause(PrimaryCondition::Condition(condition.clone())))
+            }
             _ => Err(OperationError::service_error(
                 "failed to estimate cardinality",
             )),
@@ -603,10 +651,11 @@ mod tests {
 
     use super::*;
     use crate::common::rocksdb_wrapper::open_db_with_existing_cf;
+    use crate::types::{MatchPrefix, MatchRegex, MatchWildcard};
 
     const FIELD_NAME: &str = "test";
 
-    fn save_map_index<N: Hash + Eq + Clone + Display + FromStr + Debug + Default>(
+    fn save_map_index<N: Hash + Eq + Ord + Clone + Display + FromStr + Debug + Default>(
         data: &[Vec<N>],
         path: &Path,
     ) {
@@ -624,7 +673,7 @@ mod tests {
         index.flusher()().unwrap();
     }
 
-    fn load_map_index<N: Hash + Eq + Clone + Display + FromStr + Debug + Default>(
+    fn load_map_index<N: Hash + Eq + Ord + Clone + Display + FromStr + Debug + Default>(
         data: &[Vec<N>],
         path: &Path,
     ) -> MapIndex<N> {
@@ -702,6 +751,66 @@ mod tests {
             .equals_min_exp_max(&CardinalityEstimation::exact(0)));
     }
 
+    #[test]
+    fn test_keyword_pattern_match() {
+        let data = vec![
+            vec![SmolStr::from("berlin"), SmolStr::from("bern")],
+            vec![SmolStr::from("bremen")],
+            vec![SmolStr::from("barcelona"), SmolStr::from("berlin")],
+            vec![SmolStr::from("Berlin")],
+        ];
+
+        let temp_dir = Builder::new().prefix("store_dir").tempdir().unwrap();
+        save_map_index(&data, temp_dir.path());
+        let mutable_index = load_map_index(&data, temp_dir.path());
+        let mut immutable_index = MapIndex::<SmolStr>::new(
+            open_db_with_existing_cf(temp_dir.path()).unwrap(),
+            FIELD_NAME,
+            false,
+        );
+        immutable_index.load_from_db().unwrap();
+
+        let cases = [
+            (
+                Match::Prefix(MatchPrefix {
+                    prefix: "ber".to_string(),
+                }),
+                vec![0, 2],
+            ),
+            (
+                Match::Wildcard(MatchWildcard {
+                    wildcard: "b?r*n".to_string(),
+                }),
+                vec![0, 2],
+            ),
+            (
+                Match::Regex(MatchRegex {
+                    regex: "(?i)b[a-z]+n".to_string(),
+                }),
+                vec![0, 1, 2, 3],
+            ),
+            (
+                Match::Prefix(MatchPrefix {
+                    prefix: "x".to_string(),
+                }),
+                vec![],
+            ),
+        ];
+
+        for (r#match, expected) in cases {
+            let condition = FieldCondition::new_match(FIELD_NAME, r#match);
+            for index in [&mutable_index, &immutable_index] {
+                let mut points: Vec<_> = index.filter(&condition).unwrap().collect();
+                points.sort_unstable();
+                assert_eq!(points, expected, "{condition:?}");
+
+                let estimation = index.estimate_cardinality(&condition).unwrap();
+                assert!(estimation.min <= expected.len());
+                assert!(estimation.max >= expected.len());
+            }
+        }
+    }
+
     #[test]
     fn test_empty_index() {
         let data: Vec<Vec<String>> = vec![];
@@ -389,6 +389,7 @@ fn test_cond<T: Encodable + Numericable + PartialOrd + Clone>(
         geo_radius: None,
         values_count: None,
         geo_polygon: None,
+        geo_shape: None,
     };
 
     let offsets = index.filter(&condition).unwrap().collect_vec();
@@ -22,6 +22,15 @@ use crate::index::visited_pool::{VisitedListHandle, VisitedPool};
 pub type LockedLinkContainer = RwLock<LinkContainer>;
 pub type LockedLayersContainer = Vec<LockedLinkContainer>;
 
+/// Links of a carried over point on some level, which lost some of the neighbours
+#[derive(Debug, Clone)]
+pub struct LinksToHeal {
+    pub point_id: PointOffsetType,
+    pub level: usize,
+    /// Neighbours of the lost neighbours, used as replacement candidates
+    pub candidates: Vec<PointOffsetType>,
+}
+
 /// Same as `GraphLayers`,  but allows to build in parallel
 /// Convertible to `GraphLayers`
 pub struct GraphLayersBuilder {
@@ -191,6 +200,198 @@ impl GraphLayersBuilder {
             .merge_from_other(other.entry_points.into_inner());
     }
 
+    /// Carry over links of an already built graph into the builder.
+    ///
+    /// `old_to_new` maps point ids of `graph` into point ids of the builder,
+    /// `None` marks points, which are removed or should be inserted again.
+    ///
+    /// Carried over points keep their levels and are marked as ready, so the rest of the points
+    /// can be inserted with [`Self::link_new_point`] as usual.
+    /// Only the first `m` (`m0` on level 0) links of every point are carried over, additional links
+    /// of the payload graphs, merged with [`Self::merge_from_other`], are built again.
+    /// Links to removed points are dropped, the affected links are returned and should be
+    /// restored with [`Self::heal_links`] before inserting new points.
+    /// Points, which lost all of their neighbours on some level, are not marked as ready
+    /// and should be inserted again.
+    pub fn fill_from_graph<TGraphLinks: GraphLinks>(
+        &mut self,
+        graph: &GraphLayers<TGraphLinks>,
+        old_to_new: &[Option<PointOffsetType>],
+    ) -> Vec<LinksToHeal> {
+        let map_id = |old_id: PointOffsetType| old_to_new.get(old_id as usize).copied().flatten();
+
+        let mut links_to_heal = vec![];
+        for old_id in 0..min(graph.num_points(), old_to_new.len()) as PointOffsetType {
+            let Some(new_id) = map_id(old_id) else {
+                continue;
+            };
+            let level = graph.point_level(old_id);
+            self.set_levels(new_id, level);
+
+            let mut point_links_to_heal = vec![];
+            let mut is_isolated = false;
+            for curr_level in 0..=level {
+                let old_links = graph.links.links(old_id, curr_level);
+                // Links of the main graph go first, payload links are appended after them
+                let old_links = &old_links[..min(old_links.len(), self.get_m(curr_level))];
+                let links: LinkContainer = old_links.iter().filter_map(|&x| map_id(x)).collect();
+
+                if links.len() < old_links.len() {
+                    if links.is_empty() {
+                        is_isolated = true;
+                        break;
+                    }
+                    let candidates = old_links
+                        .iter()
+                        .filter(|&&link| map_id(link).is_none())
+                        .flat_map(|&removed| graph.links.links(removed, curr_level))
+                        .filter_map(|&x| map_id(x))
+                        .filter(|&x| x != new_id)
+                        .collect();
+                    point_links_to_heal.push(LinksToHeal {
+                        point_id: new_id,
+                        level: curr_level,
+                        candidates,
+                    });
+                }
+                *self.links_layers[new_id as usize][curr_level].get_mut() = links;
+            }
+
+            if is_isolated {
+                // Point will be linked from scratch
+                for links in &mut self.links_layers[new_id as usize] {
+                    links.get_mut().clear();
+                }
+                continue;
+            }
+
+            links_to_heal.extend(point_links_to_heal);
+            self.entry_points
+                .get_mut()
+                .new_point(new_id, level, |_| true);
+            self.ready_list.get_mut().set(new_id as usize, true);
+        }
+
+        links_to_heal
+    }
+
+    /// Restore links of a carried over point, which lost some of the neighbours.
+    ///
+    /// Remaining neighbours and replacement candidates are selected the same way as on insertion.
+    /// New neighbours are linked back to the healed point, so the graph stays navigable in both
+    /// directions.
+    /// `points_scorer` should score other points against the healed point.
+    pub fn heal_links(&self, links_to_heal: &LinksToHeal, points_scorer: FilteredScorer) {
+        let LinksToHeal {
+            point_id,
+            level,
+            candidates,
+        } = links_to_heal;
+        let level_m = self.get_m(*level);
+
+        let mut visited_list = self.get_visited_list_from_pool();
+        visited_list.check_and_update_visited(*point_id);
+
+        let mut links = self.links_layers[*point_id as usize][*level].write();
+        let old_links = links.clone();
+        let mut scored_candidates: Vec<_> = {
+            let ready_list = self.ready_list.read();
+            links
+                .iter()
+                .chain(candidates.iter())
+                .copied()
+                .filter(|&x| ready_list[x as usize] && !visited_list.check_and_update_visited(x))
+                .map(|x| ScoredPointOffset {
+                    idx: x,
+                    score: points_scorer.score_point(x),
+                })
+                .collect()
+        };
+        // Closest first
+        scored_candidates.sort_unstable_by(|a, b| b.cmp(a));
+
+        let healed_links = if self.use_heuristic {
+            Self::select_candidate_with_heuristic_from_sorted(
+                scored_candidates.into_iter(),
+                level_m,
+                |a, b| points_scorer.score_internal(a, b),
+            )
+        } else {
+            scored_candidates
+                .into_iter()
+                .take(level_m)
+                .map(|x| x.idx)
+                .collect()
+        };
+        *links = healed_links.clone();
+        // Release the lock before touching links of other points
+        drop(links);
+
+        let scorer = |a, b| points_scorer.score_internal(a, b);
+        for other_point in healed_links
+            .into_iter()
+            .filter(|other_point| !old_links.contains(other_point))
+        {
+            self.link_back(*point_id, other_point, *level, level_m, scorer);
+        }
+    }
+
+    /// Add `point_id` into links of `other_point`, keeping only the closest links if there are
+    /// already `level_m` of them
+    fn link_back<F>(
+        &self,
+        point_id: PointOffsetType,
+        other_point: PointOffsetType,
+        level: usize,
+        level_m: usize,
+        mut scorer: F,
+    ) where
+        F: FnMut(PointOffsetType, PointOffsetType) -> ScoreType,
+    {
+        let mut other_point_links = self.links_layers[other_point as usize][level].write();
+        if other_point_links.contains(&point_id) {
+            return;
+        }
+        if !self.use_heuristic {
+            Self::connect_new_point(
+                &mut other_point_links,
+                point_id,
+                other_point,
+                level_m,
+                scorer,
+            );
+        } else if other_point_links.len() < level_m {
+            // If linked point is lack of neighbours
+            other_point_links.push(point_id);
+        } else {
+            let mut candidates = BinaryHeap::with_capacity(level_m + 1);
+            candidates.push(ScoredPointOffset {
+                idx: point_id,
+                score: scorer(point_id, other_point),
+            });
+            for other_point_link in other_point_links.iter().take(level_m).copied() {
+                candidates.push(ScoredPointOffset {
+                    idx: other_point_link,
+                    score: scorer(other_point_link, other_point),
+                });
+            }
+            let selected_candidates = Self::select_candidate_with_heuristic_from_sorted(
+                candidates.into_sorted_vec().into_iter().rev(),
+                level_m,
+                scorer,
+            );
+            other_point_links.clear(); // this do not free memory, which is good
+            for selected in selected_candidates.iter().copied() {
+                other_point_links.push(selected);
+            }
+        }
+    }
+
+    /// Check if the point is already linked into the graph
+    pub fn is_ready(&self, point_id: PointOffsetType) -> bool {
+        self.ready_list.read()[point_id as usize]
+    }
+
     fn num_points(&self) -> usize {
         self.links_layers.len()
     }
@@ -387,36 +588,7 @@ impl GraphLayersBuilder {
                         };
 
                         for &other_point in &selected_nearest {
-                            let mut other_point_links =
-                                self.links_layers[other_point as usize][curr_level].write();
-                            if other_point_links.len() < level_m {
-                                // If linked point is lack of neighbours
-                                other_point_links.push(point_id);
-                            } else {
-                                let mut candidates = BinaryHeap::with_capacity(level_m + 1);
-                                candidates.push(ScoredPointOffset {
-                                    idx: point_id,
-                                    score: scorer(point_id, other_point),
-                                });
-                                for other_point_link in
-                                    other_point_links.iter().take(level_m).copied()
-                                {
-                                    candidates.push(ScoredPointOffset {
-                                        idx: other_point_link,
-                                        score: scorer(other_point_link, other_point),
-                                    });
-                                }
-                                let selected_candidates =
-                                    Self::select_candidate_with_heuristic_from_sorted(
-                                        candidates.into_sorted_vec().into_iter().rev(),
-                                        level_m,
-                                        scorer,
-                                    );
-                                other_point_links.clear(); // this do not free memory, which is good
-                                for selected in selected_candidates.iter().copied() {
-                                    other_point_links.push(selected);
-                                }
-                            }
+                            self.link_back(point_id, other_point, curr_level, level_m, scorer);
                         }
                     } else {
                         for nearest_point in &search_context.nearest {
@@ -886,4 +1058,114 @@ mod tests {
             .for_each(|x| result.push(*x));
         assert_eq!(&result, &vec![1, 2, 3, 4, 5, 6]);
     }
+
+    #[test]
+    fn test_heal_removed_points() {
+        let num_vectors = 1000;
+        let dim = 8;
+        let top = 10;
+        let ef = 64;
+
+        let mut rng = StdRng::seed_from_u64(42);
+
+        type M = CosineMetric;
+
+        let (vector_holder, graph_layers_builder) =
+            create_graph_layer::<M, _>(num_vectors, dim, true, &mut rng);
+        let old_graph = graph_layers_builder
+            .into_graph_layers::<GraphLinksRam>(None)
+            .unwrap();
+
+        // Remove every 5th point, keep ids of the rest
+        let is_removed = |idx: PointOffsetType| idx % 5 == 0;
+        let old_to_new = (0..num_vectors as PointOffsetType)
+            .map(|idx| (!is_removed(idx)).then_some(idx))
+            .collect_vec();
+
+        let mut graph_layers_builder = GraphLayersBuilder::new(num_vectors, M, M * 2, 16, 10, true);
+        let links_to_heal = graph_layers_builder.fill_from_graph(&old_graph, &old_to_new);
+        assert!(!links_to_heal.is_empty());
+
+        let fake_filter_context = FakeFilterContext {};
+        let mut linked_back = 0;
+        for links in &links_to_heal {
+            let point_links = |builder: &GraphLayersBuilder| {
+                builder.links_layers[links.point_id as usize][links.level]
+                    .read()
+                    .clone()
+            };
+            let links_before = point_links(&graph_layers_builder);
+
+            let vector = vector_holder.vectors.get(links.point_id).to_vec();
+            let raw_scorer = vector_holder.get_raw_scorer(vector).unwrap();
+            let scorer = FilteredScorer::new(raw_scorer.as_ref(), Some(&fake_filter_context));
+            graph_layers_builder.heal_links(links, scorer);
+
+            // New neighbours of the healed point link back to it
+            linked_back += point_links(&graph_layers_builder)
+                .into_iter()
+                .filter(|other| !links_before.contains(other))
+                .filter(|&other| {
+                    graph_layers_builder.links_layers[other as usize][links.level]
+                        .read()
+                        .contains(&links.point_id)
+                })
+                .count();
+        }
+        assert!(linked_back > 0);
+
+        // Isolated points are inserted again
+        for idx in 0..num_vectors as PointOffsetType {
+            if is_removed(idx) || graph_layers_builder.is_ready(idx) {
+                continue;
+            }
+            let vector = vector_holder.vectors.get(idx).to_vec();
+            let raw_scorer = vector_holder.get_raw_scorer(vector).unwrap();
+            let scorer = FilteredScorer::new(raw_scorer.as_ref(), Some(&fake_filter_context));
+            graph_layers_builder.link_new_point(idx, scorer);
+        }
+
+        for (idx, layers) in graph_layers_builder.links_layers.iter().enumerate() {
+            let idx = idx as PointOffsetType;
+            assert_eq!(graph_layers_builder.is_ready(idx), !is_removed(idx));
+            for (level, links) in layers.iter().enumerate() {
+                let links = links.read();
+                assert!(links.iter().all(|&link| !is_removed(link)));
+                assert!(links.len() <= graph_layers_builder.get_m(level));
+            }
+        }
+
+        let graph = graph_layers_builder
+            .into_graph_layers::<GraphLinksRam>(None)
+            .unwrap();
+
+        let attempts = 20;
+        let mut hits = 0;
+        for _ in 0..attempts {
+            let query = random_vector(&mut rng, dim);
+            let processed_query = M::preprocess(query.clone());
+
+            let mut reference_top = FixedLengthPriorityQueue::new(top);
+            for idx in (0..num_vectors as PointOffsetType).filter(|&idx| !is_removed(idx)) {
+                reference_top.push(ScoredPointOffset {
+                    idx,
+                    score: M::similarity(&vector_holder.vectors.get(idx), &processed_query),
+                });
+            }
+            let reference_ids = reference_top.into_vec().iter().map(|x| x.idx).collect_vec();
+
+            let raw_scorer = vector_holder.get_raw_scorer(query).unwrap();
+            let scorer = FilteredScorer::new(raw_scorer.as_ref(), Some(&fake_filter_context));
+            let graph_search = graph.search(top, ef, scorer, None);
+
+            assert!(graph_search.iter().all(|x| !is_removed(x.idx)));
+            hits += graph_search
+                .iter()
+                .filter(|x| reference_ids.contains(&x.idx))
+                .count();
+        }
+
+        let recall = hits as f64 / (attempts * top) as f64;
+        assert!(recall > 0.9, "recall after healing: {recall}");
+    }
 }
@@ -5,6 +5,7 @@ use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
 
 use atomic_refcell::AtomicRefCell;
+use bitvec::prelude::BitVec;
 use common::types::{PointOffsetType, ScoredPointOffset};
 use log::debug;
 use memory::mmap_ops;
@@ -249,6 +250,270 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
         Ok(())
     }
 
+    /// Build index, carrying over links of the `old_index` graph.
+    ///
+    /// `old_to_new` maps internal ids of `old_index` into internal ids of this index.
+    /// Links to points, which are not carried over, are healed and only the remaining points
+    /// are inserted into the graph.
+    /// If the old graph was built with different parameters, the index is built from scratch.
+    pub fn build_index_from<TOldGraphLinks: GraphLinks>(
+        &mut self,
+        old_index: &HNSWIndex<TOldGraphLinks>,
+        old_to_new: &[Option<PointOffsetType>],
+        stopped: &AtomicBool,
+    ) -> OperationResult<()> {
+        let old_graph = old_index
+            .graph
+            .as_ref()
+            .filter(|_| self.is_graph_compatible(&old_index.config))
+            .map(|graph| (graph, old_to_new));
+
+        let (graph, indexed_vectors) = self.build_graph(stopped, old_graph)?;
+        self.set_graph(graph, indexed_vectors)
+    }
+
+    /// Check if links of a graph, built with `other` config, can be carried over into this index
+    fn is_graph_compatible(&self, other: &HnswGraphConfig) -> bool {
+        self.config.m > 0
+            && self.config.m == other.m
+            && self.config.m0 == other.m0
+            && self.config.ef_construct == other.ef_construct
+            && self.config.payload_m == other.payload_m
+            && self.config.payload_m0 == other.payload_m0
+    }
+
+    fn set_graph(
+        &mut self,
+        graph: GraphLayers<TGraphLinks>,
+        indexed_vectors: usize,
+    ) -> OperationResult<()> {
+        self.config.indexed_vector_count.replace(indexed_vectors);
+        self.graph = Some(graph);
+        self.save()
+    }
+
+    /// Build main index graph and additional payload links.
+    ///
+    /// Returns the graph together with the number of indexed vectors.
+    fn build_graph<TOldGraphLinks: GraphLinks>(
+        &self,
+        stopped: &AtomicBool,
+        old_graph: Option<(&GraphLayers<TOldGraphLinks>, &[Option<PointOffsetType>])>,
+    ) -> OperationResult<(GraphLayers<TGraphLinks>, usize)> {
+        let id_tracker = self.id_tracker.borrow();
+        let vector_storage = self.vector_storage.borrow();
+        let quantized_vectors = self.quantized_vectors.borrow();
+        let mut rng = thread_rng();
+
+        let total_vector_count = vector_storage.total_vector_count();
+        let deleted_bitslice = vector_storage.deleted_vector_bitslice();
+
+        debug!("building HNSW for {} vectors", total_vector_count);
+        let indexing_threshold = self.config.full_scan_threshold;
+        let mut graph_layers_builder = GraphLayersBuilder::new(
+            total_vector_count,
+            self.config.m,
+            self.config.m0,
+            self.config.ef_construct,
+            (total_vector_count
+                .checked_div(indexing_threshold)
+                .unwrap_or(0)
+                * 10)
+                .max(1),
+            HNSW_USE_HEURISTIC,
+        );
+
+        let pool = rayon::ThreadPoolBuilder::new()
+            .thread_name(|idx| format!("hnsw-build-{idx}"))
+            .num_threads(max_rayon_threads(self.config.max_indexing_threads))
+            .build()?;
+
+        let links_to_heal = match old_graph {
+            Some((old_graph, old_to_new)) if self.config.m > 0 => {
+                let mut is_indexed = BitVec::repeat(false, total_vector_count);
+                for vector_id in id_tracker.iter_ids_excluding(deleted_bitslice) {
+                    is_indexed.set(vector_id as usize, true);
+                }
+                // Do not carry over points, which are deleted since the mapping was made
+                let old_to_new: Vec<_> = old_to_new
+                    .iter()
+                    .map(|new_id| {
+                        new_id
+                            .filter(|&id| is_indexed.get(id as usize).map(|x| *x).unwrap_or(false))
+                    })
+                    .collect();
+                graph_layers_builder.fill_from_graph(old_graph, &old_to_new)
+            }
+            _ => vec![],
+        };
+
+        let mut points_count = 0;
+        for vector_id in id_tracker.iter_ids_excluding(deleted_bitslice) {
+            check_process_stopped(stopped)?;
+            points_count += 1;
+            if graph_layers_builder.is_ready(vector_id) {
+                // Carried over from the old graph with its level
+                continue;
+            }
+            let level = graph_layers_builder.get_random_layer(&mut rng);
+            graph_layers_builder.set_levels(vector_id, level);
+        }
+
+        let mut indexed_vectors = 0;
+
+        if self.config.m > 0 {
+            let ids: Vec<_> = id_tracker
+                .iter_ids_excluding(deleted_bitslice)
+                .filter(|&vector_id| !graph_layers_builder.is_ready(vector_id))
+                .collect();
+
+            indexed_vectors = points_count;
+
+            debug!(
+                "carried over {} points, healing {} links, inserting {} points",
+                points_count - ids.len(),
+                links_to_heal.len(),
+                ids.len(),
+            );
+
+            let point_raw_scorer = |vector_id| {
+                let vector = vector_storage.get_vector(vector_id);
+                let vector = vector.as_vec_ref().into();
+                if let Some(quantized_storage) = quantized_vectors.as_ref() {
+                    quantized_storage.raw_scorer(
+                        vector,
+                        id_tracker.deleted_point_bitslice(),
+                        vector_storage.deleted_vector_bitslice(),
+                        stopped,
+                    )
+                } else {
+                    new_raw_scorer(vector, &vector_storage, id_tracker.deleted_point_bitslice())
+                }
+            };
+
+            // Replace links to removed points before any new point is inserted,
+            // so that new points are linked through the healthy graph
+            if !links_to_heal.is_empty() {
+                pool.install(|| {
+                    links_to_heal.par_iter().try_for_each(|links| {
+                        check_process_stopped(stopped)?;
+                        let raw_scorer = point_raw_scorer(links.point_id)?;
+                        let points_scorer = FilteredScorer::new(raw_scorer.as_ref(), None);
+                        graph_layers_builder.heal_links(links, points_scorer);
+                        Ok::<_, OperationError>(())
+                    })
+                })?;
+            }
+
+            let insert_point = |vector_id| {
+                check_process_stopped(stopped)?;
+                let raw_scorer = point_raw_scorer(vector_id)?;
+                let points_scorer = FilteredScorer::new(raw_scorer.as_ref(), None);
+
+                graph_layers_builder.link_new_point(vector_id, points_scorer);
+                Ok::<_, OperationError>(())
+            };
+
+            let first_points = ids.len().min(SINGLE_THREADED_HNSW_BUILD_THRESHOLD);
+
+            for vector_id in ids[..first_points].iter().copied() {
+                insert_point(vector_id)?;
+            }
+
+            if ids.len() > first_points {
+                pool.install(|| {
+                    ids.into_par_iter()
+                        .skip(first_points)
+                        .try_for_each(insert_point)
+                })?;
+            }
+
+            debug!("finish main graph");
+        } else {
+            debug!("skip building main HNSW graph");
+        }
+
+        let visited_pool = VisitedPool::new();
+        let mut block_filter_list = visited_pool.get(total_vector_count);
+        let visits_iteration = block_filter_list.get_current_iteration_id();
+
+        let payload_index = self.payload_index.borrow();
+        let payload_m = self.config.payload_m.unwrap_or(self.config.m);
+
+        if payload_m > 0 {
+            // Calculate true average number of links per vertex in the HNSW graph
+            // to better estimate percolation threshold
+            let average_links_per_0_level =
+                graph_layers_builder.get_average_connectivity_on_level(0);
+            let average_links_per_0_level_int = (average_links_per_0_level as usize).max(1);
+
+            for (field, _) in payload_index.indexed_fields() {
+                debug!("building additional index for field {}", &field);
+
+                // It is expected, that graph will become disconnected less than
+                // $1/m$ points left.
+                // So blocks larger than $1/m$ are not needed.
+                // We add multiplier for the extra safety.
+                let percolation_multiplier = 4;
+                let max_block_size = if self.config.m > 0 {
+                    total_vector_count / average_links_per_0_level_int * percolation_multiplier
+                } else {
+                    usize::MAX
+                };
+                let min_block_size = indexing_threshold;
+
+                for payload_block in payload_index.payload_blocks(&field, min_block_size) {
+                    check_process_stopped(stopped)?;
+                    if payload_block.cardinality > max_block_size {
+                        continue;
+                    }
+                    // ToDo: reuse graph layer for same payload
+                    let mut additional_graph = GraphLayersBuilder::new_with_params(
+                        total_vector_count,
+                        payload_m,
+                        self.config.payload_m0.unwrap_or(self.config.m0),
+                        self.config.ef_construct,
+                        1,
+                        HNSW_USE_HEURISTIC,
+                        false,
+                    );
+                    self.build_filtered_graph(
+                        &pool,
+                        stopped,
+                        &mut additional_graph,
+                        payload_block.condition,
+                        &mut block_filter_list,
+                    )?;
+                    graph_layers_builder.merge_from_other(additional_graph);
+                }
+            }
+
+            let indexed_payload_vectors = block_filter_list.count_visits_since(visits_iteration);
+
+            debug_assert!(indexed_vectors >= indexed_payload_vectors || self.config.m == 0);
+            indexed_vectors = indexed_vectors.max(indexed_payload_vectors);
+            debug_assert!(indexed_payload_vectors <= total_vector_count);
+        } else {
+            debug!("skip building additional HNSW links");
+        }
+
+        let graph_links_path = GraphLayers::<TGraphLinks>::get_links_path(&self.path);
+        let graph: GraphLayers<TGraphLinks> =
+            graph_layers_builder.into_graph_layers(Some(&graph_links_path))?;
+
+        #[cfg(debug_assertions)]
+        {
+            for (idx, deleted) in deleted_bitslice.iter().enumerate() {
+                if *deleted {
+                    debug_assert!(graph.links.links(idx as PointOffsetType, 0).is_empty());
+                }
+            }
+        }
+
+        debug!("finish additional payload field indexing");
+        Ok((graph, indexed_vectors))
+    }
+
     fn search_with_graph(
         &self,
         vector: &QueryVector,
@@ -634,168 +899,16 @@ impl<TGraphLinks: GraphLinks> VectorIndex for HNSWIndex<TGraphLinks> {
     }
 
     fn build_index(&mut self, stopped: &AtomicBool) -> OperationResult<()> {
-        // Build main index graph
-        let id_tracker = self.id_tracker.borrow();
-        let vector_storage = self.vector_storage.borrow();
-        let quantized_vectors = self.quantized_vectors.borrow();
-        let mut rng = thread_rng();
-
-        let total_vector_count = vector_storage.total_vector_count();
-        let deleted_bitslice = vector_storage.deleted_vector_bitslice();
-
-        debug!("building HNSW for {} vectors", total_vector_count);
-        let indexing_threshold = self.config.full_scan_threshold;
-        let mut graph_layers_builder = GraphLayersBuilder::new(
-            total_vector_count,
-            self.config.m,
-            self.config.m0,
-            self.config.ef_construct,
-            (total_vector_count
-                .checked_div(indexing_threshold)
-                .unwrap_or(0)
-                * 10)
-                .max(1),
-            HNSW_USE_HEURISTIC,
-        );
-
-        let pool = rayon::ThreadPoolBuilder::new()
-            .thread_name(|idx| format!("hnsw-build-{idx}"))
-            .num_threads(max_rayon_threads(self.config.max_indexing_threads))
-            .build()?;
-
-        for vector_id in id_tracker.iter_ids_excluding(deleted_bitslice) {
-            check_process_stopped(stopped)?;
-            let level = graph_layers_builder.get_random_layer(&mut rng);
-            graph_layers_builder.set_levels(vector_id, level);
-        }
-
-        let mut indexed_vectors = 0;
-
-        if self.config.m > 0 {
-            let mut ids_iterator = id_tracker.iter_ids_excluding(deleted_bitslice);
-
-            let first_few_ids: Vec<_> = ids_iterator
-                .by_ref()
-                .take(SINGLE_THREADED_HNSW_BUILD_THRESHOLD)
-                .collect();
-            let ids: Vec<_> = ids_iterator.collect();
-
-            indexed_vectors = ids.len() + first_few_ids.len();
-
-            let insert_point = |vector_id| {
-                check_process_stopped(stopped)?;
-                let vector = vector_storage.get_vector(vector_id);
-                let vector = vector.as_vec_ref().into();
-                let raw_scorer = if let Some(quantized_storage) = quantized_vectors.as_ref() {
-                    quantized_storage.raw_scorer(
-                        vector,
-                        id_tracker.deleted_point_bitslice(),
-                        vector_storage.deleted_vector_bitslice(),
-                        stopped,
-                    )
-                } else {
-                    new_raw_scorer(vector, &vector_storage, id_tracker.deleted_point_bitslice())
-                }?;
-                let points_scorer = FilteredScorer::new(raw_scorer.as_ref(), None);
-
-                graph_layers_builder.link_new_point(vector_id, points_scorer);
-                Ok::<_, OperationError>(())
-            };
-
-            for vector_id in first_few_ids {
-                insert_point(vector_id)?;
-            }
-
-            if !ids.is_empty() {
-                pool.install(|| ids.into_par_iter().try_for_each(insert_point))?;
-            }
-
-            debug!("finish main graph");
-        } else {
-            debug!("skip building main HNSW graph");
-        }
-
-        let visited_pool = VisitedPool::new();
-        let mut block_filter_list = visited_pool.get(total_vector_count);
-        let visits_iteration = block_filter_list.get_current_iteration_id();
-
-        let payload_index = self.payload_index.borrow();
-        let payload_m = self.config.payload_m.unwrap_or(self.config.m);
-
-        if payload_m > 0 {
-            // Calculate true average number of links per vertex in the HNSW graph
-            // to better estimate percolation threshold
-            let average_links_per_0_level =
-                graph_layers_builder.get_average_connectivity_on_level(0);
-            let average_links_per_0_level_int = (average_links_per_0_level as usize).max(1);
-
-            for (field, _) in payload_index.indexed_fields() {
-                debug!("building additional index for field {}", &field);
-
-                // It is expected, that graph will become disconnected less than
-                // $1/m$ points left.
-                // So blocks larger than $1/m$ are not needed.
-                // We add multiplier for the extra safety.
-                let percolation_multiplier = 4;
-                let max_block_size = if self.config.m > 0 {
-                    total_vector_count / average_links_per_0_level_int * percolation_multiplier
-                } else {
-                    usize::MAX
-                };
-                let min_block_size = indexing_threshold;
-
-                for payload_block in payload_index.payload_blocks(&field, min_block_size) {
-                    check_process_stopped(stopped)?;
-                    if payload_block.cardinality > max_block_size {
-                        continue;
-                    }
-                    // ToDo: reuse graph layer for same payload
-                    let mut additional_graph = GraphLayersBuilder::new_with_params(
-                        total_vector_count,
-                        payload_m,
-                        self.config.payload_m0.unwrap_or(self.config.m0),
-                        self.config.ef_construct,
-                        1,
-                        HNSW_USE_HEURISTIC,
-                        false,
-                    );
-                    self.build_filtered_graph(
-                        &pool,
-                        stopped,
-                        &mut additional_graph,
-                        payload_block.condition,
-                        &mut block_filter_list,
-                    )?;
-                    graph_layers_builder.merge_from_other(additional_graph);
-                }
-            }
-
-            let indexed_payload_vectors = block_filter_list.count_visits_since(visits_iteration);
-
-            debug_assert!(indexed_vectors >= indexed_payload_vectors || self.config.m == 0);
-            indexed_vectors = indexed_vectors.max(indexed_payload_vectors);
-            debug_assert!(indexed_payload_vectors <= total_vector_count);
-        } else {
-            debug!("skip building additional HNSW links");
-        }
-
-        self.config.indexed_vector_count.replace(indexed_vectors);
-
-        let graph_links_path = GraphLayers::<TGraphLinks>::get_links_path(&self.path);
-        self.graph = Some(graph_layers_builder.into_graph_layers(Some(&graph_links_path))?);
-
-        #[cfg(debug_assertions)]
-        {
-            let graph = self.graph.as_ref().unwrap();
-            for (idx, deleted) in deleted_bitslice.iter().enumerate() {
-                if *deleted {
-                    debug_assert!(graph.links.links(idx as PointOffsetType, 0).is_empty());
-                }
-            }
-        }
-
-        debug!("finish additional payload field indexing");
-        self.save()
+        // Carry over links of the current graph, if the index is rebuilt after deletions
+        let same_ids: Option<Vec<_>> = self.graph.as_ref().map(|graph| {
+            (0..graph.num_points() as PointOffsetType)
+                .map(Some)
+                .collect()
+        });
+        let old_graph = self.graph.as_ref().zip(same_ids.as_deref());
+
+        let (graph, indexed_vectors) = self.build_graph(stopped, old_graph)?;
+        self.set_graph(graph, indexed_vectors)
     }
 
     fn get_telemetry_data(&self) -> VectorIndexSearchesTelemetry {
@@ -7,8 +7,11 @@ use std::cmp::{max, min};
 
 use itertools::Itertools;
 
+use crate::common::utils::JsonPathPayload;
 use crate::index::field_index::{CardinalityEstimation, PrimaryCondition};
-use crate::types::{Condition, Filter};
+use crate::types::{
+    Condition, FieldCondition, Filter, IsEmptyCondition, IsNullCondition, PayloadKeyType,
+};
 
 /// Re-estimate cardinality based on number of available vectors
 /// Assuming that deleted vectors are not correlated with the filter
@@ -231,6 +234,49 @@ where
     combine_must_estimations(&must_estimations, total)
 }
 
+/// Find the first field of the filter, which conditions can't be estimated with payload indexes
+///
+/// `is_indexed` tells whether payload indexes can estimate the field condition, conditions of
+/// nested filters are passed with the full path of the field. `is_empty` and `is_null` conditions
+/// only require any index on the field, which is checked by `has_index`.
+pub fn unindexed_filter_key<F, H>(
+    filter: &Filter,
+    nested_path: Option<&JsonPathPayload>,
+    is_indexed: &F,
+    has_index: &H,
+) -> Option<PayloadKeyType>
+where
+    F: Fn(&FieldCondition) -> bool,
+    H: Fn(&str) -> bool,
+{
+    [&filter.must, &filter.should, &filter.must_not]
+        .into_iter()
+        .flatten()
+        .flatten()
+        .find_map(|condition| match condition {
+            Condition::Filter(filter) => {
+                unindexed_filter_key(filter, nested_path, is_indexed, has_index)
+            }
+            Condition::Nested(nested) => {
+                let full_path = JsonPathPayload::extend_or_new(nested_path, &nested.array_key());
+                unindexed_filter_key(nested.filter(), Some(&full_path), is_indexed, has_index)
+            }
+            Condition::IsEmpty(IsEmptyCondition { is_empty: field })
+            | Condition::IsNull(IsNullCondition { is_null: field }) => {
+                let full_path = JsonPathPayload::extend_or_new(nested_path, &field.key).path;
+                (!has_index(&full_path)).then_some(full_path)
+            }
+            Condition::HasId(_) => None,
+            Condition::Field(field_condition) => {
+                let full_path_condition = FieldCondition {
+                    key: JsonPathPayload::extend_or_new(nested_path, &field_condition.key).path,
+                    ..field_condition.clone()
+                };
+                (!is_indexed(&full_path_condition)).then_some(full_path_condition.key)
+            }
+        })
+}
+
 pub fn invert_estimation(
     estimation: &CardinalityEstimation,
     total: usize,
@@ -275,6 +321,7 @@ mod tests {
             geo_radius: None,
             values_count: None,
             geo_polygon: None,
+            geo_shape: None,
         })
     }
 
@@ -3,18 +3,19 @@ use std::collections::HashSet;
 use common::types::PointOffsetType;
 use serde_json::Value;
 
+use crate::common::keyword_matcher::KeywordMatcher;
 use crate::common::utils::IndexesMap;
 use crate::id_tracker::IdTrackerSS;
 use crate::index::field_index::FieldIndex;
 use crate::index::query_optimization::optimized_filter::ConditionCheckerFn;
 use crate::index::query_optimization::payload_provider::PayloadProvider;
 use crate::payload_storage::query_checker::{
-    check_field_condition, check_is_empty_condition, check_is_null_condition, check_payload,
-    select_nested_indexes,
+    check_is_empty_condition, check_is_null_condition, check_prepared_field_condition,
+    index_value_checker, PreparedFilter,
 };
 use crate::types::{
     AnyVariants, Condition, FieldCondition, FloatPayloadType, GeoBoundingBox, GeoPolygon,
-    GeoRadius, Match, MatchAny, MatchExcept, MatchText, MatchValue, OwnedPayloadRef,
+    GeoRadius, GeoShapeCondition, Match, MatchAny, MatchExcept, MatchValue, OwnedPayloadRef,
     PayloadContainer, Range, ValueVariants,
 };
 
@@ -33,9 +34,16 @@ pub fn condition_converter<'a>(
                     .find_map(|index| field_condition_index(index, field_condition))
             })
             .unwrap_or_else(|| {
+                let index_checker = field_indexes
+                    .get(&field_condition.key)
+                    .and_then(|indexes| index_value_checker(field_condition, indexes));
                 Box::new(move |point_id| {
                     payload_provider.with_payload(point_id, |payload| {
-                        check_field_condition(field_condition, &payload, field_indexes)
+                        check_prepared_field_condition(
+                            field_condition,
+                            &payload,
+                            index_checker.as_ref(),
+                        )
                     })
                 })
             }),
@@ -91,9 +99,10 @@ pub fn condition_converter<'a>(
             // In this case we want to use `nested.field`, but we only have `field` in query.
             // Therefore we need to trim `nested` part from key. So that query executor
             // can address proper index for nested field.
+            // Conditions of the nested filter are prepared with these indexes once for all points.
             let nested_path = nested.array_key();
 
-            let nested_indexes = select_nested_indexes(&nested_path, field_indexes);
+            let nested_filter = PreparedFilter::new_nested(nested, field_indexes);
 
             Box::new(move |point_id| {
                 payload_provider.with_payload(point_id, |payload| {
@@ -102,14 +111,12 @@ pub fn condition_converter<'a>(
                     for value in field_values {
                         if let Value::Object(object) = value {
                             let get_payload = || OwnedPayloadRef::from(object);
-                            if check_payload(
+                            if nested_filter.check_payload(
                                 Box::new(get_payload),
                                 // None because has_id in nested is not supported. So retrieving
                                 // IDs through the tracker would always return None.
                                 None,
-                                &nested.nested.filter,
                                 point_id,
-                                &nested_indexes,
                             ) {
                                 // If at least one nested object matches, return true
                                 return true;
@@ -168,9 +175,31 @@ pub fn field_condition_index<'a>(
         return Some(checker);
     }
 
+    if let Some(checker) = field_condition
+        .geo_shape
+        .clone()
+        .and_then(|cond| get_geo_shape_checkers(index, cond))
+    {
+        return Some(checker);
+    }
+
     None
 }
 
+pub fn get_geo_shape_checkers(
+    index: &FieldIndex,
+    geo_shape: GeoShapeCondition,
+) -> Option<ConditionCheckerFn> {
+    let relation = geo_shape.relation;
+    let query = geo_shape.shape.to_geometry();
+    match index {
+        FieldIndex::GeoIndex(geo_index) => Some(Box::new(move |point_id: PointOffsetType| {
+            geo_index.check_geo_shape(point_id, relation, &query)
+        })),
+        _ => None,
+    }
+}
+
 pub fn get_geo_polygon_checkers(
     index: &FieldIndex,
     geo_polygon: GeoPolygon,
@@ -241,6 +270,11 @@ pub fn get_range_checkers(index: &FieldIndex, range: Range) -> Option<ConditionC
 }
 
 pub fn get_match_checkers(index: &FieldIndex, cond_match: Match) -> Option<ConditionCheckerFn> {
+    // Keywords of the condition are compared with keywords normalized on indexing
+    let cond_match = match index {
+        FieldIndex::KeywordIndex(keyword_index) => keyword_index.normalize_match(cond_match),
+        _ => cond_match,
+    };
     match cond_match {
         Match::Value(MatchValue {
             value: value_variant,
@@ -270,9 +304,9 @@ pub fn get_match_checkers(index: &FieldIndex, cond_match: Match) -> Option<Condi
             }
             _ => None,
         },
-        Match::Text(MatchText { text }) => match index {
+        Match::Text(text_match) => match index {
             FieldIndex::FullTextIndex(full_text_index) => {
-                let parsed_query = full_text_index.parse_query(&text);
+                let parsed_query = full_text_index.parse_text_match(&text_match);
                 Some(Box::new(move |point_id: PointOffsetType| {
                     full_text_index
                         .get_doc(point_id)
@@ -322,6 +356,17 @@ pub fn get_match_checkers(index: &FieldIndex, cond_match: Match) -> Option<Condi
                 index.values_count(point_id) > 0
             })),
         },
+        Match::Prefix(_) | Match::Wildcard(_) | Match::Regex(_) => match index {
+            FieldIndex::KeywordIndex(index) => {
+                let matcher = KeywordMatcher::new(&cond_match)?;
+                Some(Box::new(move |point_id: PointOffsetType| {
+                    index
+                        .get_values(point_id)
+                        .map_or(false, |values| values.iter().any(|k| matcher.is_match(k)))
+                }))
+            }
+            _ => None,
+        },
     }
 }
 
@@ -19,6 +19,8 @@ pub enum SparseIndexType {
     ImmutableRam,
     /// Mmap sparse index
     Mmap,
+    /// Mmap sparse index with compressed posting lists
+    CompressedMmap,
 }
 
 /// Configuration for sparse inverted index.
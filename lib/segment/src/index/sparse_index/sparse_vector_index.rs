@@ -152,7 +152,7 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
         for dim_id in query_vector.indices.iter() {
             if let Some(dim_id) = self.indices_tracker.remap_index(*dim_id) {
                 if let Some(posting_list) = self.inverted_index.get(&dim_id) {
-                    for element in posting_list.elements.iter() {
+                    for element in posting_list {
                         unique_record_ids.insert(element.record_id);
                     }
                 }
@@ -330,7 +330,10 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
                 is_stopped,
                 prefiltered_points,
             ),
-            QueryVector::Recommend(_) | QueryVector::Discovery(_) | QueryVector::Context(_) => {
+            QueryVector::Recommend(_)
+            | QueryVector::Discovery(_)
+            | QueryVector::Context(_)
+            | QueryVector::Feedback(_) => {
                 let _timer = if filter.is_some() {
                     ScopeDurationMeasurer::new(&self.searches_telemetry.filtered_plain)
                 } else {
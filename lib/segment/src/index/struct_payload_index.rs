@@ -17,12 +17,13 @@ use crate::common::rocksdb_wrapper::open_db_with_existing_cf;
 use crate::common::utils::{IndexesMap, JsonPathPayload, MultiValue};
 use crate::common::Flusher;
 use crate::id_tracker::IdTrackerSS;
+use crate::index::field_index::full_text_index::text_index::FullTextIndex;
 use crate::index::field_index::index_selector::index_selector;
 use crate::index::field_index::{
     CardinalityEstimation, FieldIndex, PayloadBlockCondition, PrimaryCondition,
 };
 use crate::index::payload_config::PayloadConfig;
-use crate::index::query_estimator::estimate_filter;
+use crate::index::query_estimator::{estimate_filter, unindexed_filter_key};
 use crate::index::query_optimization::payload_provider::PayloadProvider;
 use crate::index::struct_filter_context::StructFilterContext;
 use crate::index::visited_pool::VisitedPool;
@@ -74,6 +75,18 @@ impl StructPayloadIndex {
         })
     }
 
+    /// Find the first field of the filter, which conditions this payload index can't estimate
+    ///
+    /// Such conditions are checked against the payload of each point.
+    pub fn unindexed_filter_key(&self, filter: &Filter) -> Option<PayloadKeyType> {
+        unindexed_filter_key(
+            filter,
+            None,
+            &|condition| self.estimate_field_condition(condition, None).is_some(),
+            &|key| self.field_indexes.contains_key(key),
+        )
+    }
+
     fn query_field<'a>(
         &'a self,
         field_condition: &'a FieldCondition,
@@ -208,6 +221,17 @@ impl StructPayloadIndex {
         self.id_tracker.borrow().available_point_count()
     }
 
+    /// Full-text index of the field, if any
+    pub fn full_text_index(&self, key: PayloadKeyTypeRef) -> Option<&FullTextIndex> {
+        self.field_indexes
+            .get(key)?
+            .iter()
+            .find_map(|index| match index {
+                FieldIndex::FullTextIndex(text_index) => Some(text_index),
+                _ => None,
+            })
+    }
+
     fn struct_filtered_context<'a>(&'a self, filter: &'a Filter) -> StructFilterContext<'a> {
         let estimator = |condition: &Condition| self.condition_cardinality(condition, None);
         let id_tracker = self.id_tracker.borrow();
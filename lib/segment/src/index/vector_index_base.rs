@@ -2,6 +2,7 @@ use std::path::PathBuf;
 use std::sync::atomic::AtomicBool;
 
 use common::types::{PointOffsetType, ScoredPointOffset};
+use sparse::index::inverted_index::inverted_index_compressed_mmap::InvertedIndexCompressedMmap;
 use sparse::index::inverted_index::inverted_index_mmap::InvertedIndexMmap;
 use sparse::index::inverted_index::inverted_index_ram::InvertedIndexRam;
 
@@ -46,6 +47,7 @@ pub enum VectorIndexEnum {
     HnswMmap(HNSWIndex<GraphLinksMmap>),
     SparseRam(SparseVectorIndex<InvertedIndexRam>),
     SparseMmap(SparseVectorIndex<InvertedIndexMmap>),
+    SparseCompressedMmap(SparseVectorIndex<InvertedIndexCompressedMmap>),
 }
 
 impl VectorIndexEnum {
@@ -56,6 +58,34 @@ impl VectorIndexEnum {
             Self::HnswMmap(_) => true,
             Self::SparseRam(_) => true,
             Self::SparseMmap(_) => true,
+            Self::SparseCompressedMmap(_) => true,
+        }
+    }
+
+    /// Build index, reusing the structure of `old_index` where possible.
+    ///
+    /// `old_to_new` maps internal ids of `old_index` into internal ids of this index.
+    /// Falls back to [`VectorIndex::build_index`] if the structure can't be reused.
+    pub fn build_index_from(
+        &mut self,
+        old_index: &VectorIndexEnum,
+        old_to_new: &[Option<PointOffsetType>],
+        stopped: &AtomicBool,
+    ) -> OperationResult<()> {
+        match (self, old_index) {
+            (Self::HnswRam(index), Self::HnswRam(old_index)) => {
+                index.build_index_from(old_index, old_to_new, stopped)
+            }
+            (Self::HnswRam(index), Self::HnswMmap(old_index)) => {
+                index.build_index_from(old_index, old_to_new, stopped)
+            }
+            (Self::HnswMmap(index), Self::HnswRam(old_index)) => {
+                index.build_index_from(old_index, old_to_new, stopped)
+            }
+            (Self::HnswMmap(index), Self::HnswMmap(old_index)) => {
+                index.build_index_from(old_index, old_to_new, stopped)
+            }
+            (index, _) => index.build_index(stopped),
         }
     }
 }
@@ -83,6 +113,9 @@ impl VectorIndex for VectorIndexEnum {
             VectorIndexEnum::SparseMmap(index) => {
                 index.search(vectors, filter, top, params, is_stopped)
             }
+            VectorIndexEnum::SparseCompressedMmap(index) => {
+                index.search(vectors, filter, top, params, is_stopped)
+            }
         }
     }
 
@@ -93,6 +126,7 @@ impl VectorIndex for VectorIndexEnum {
             VectorIndexEnum::HnswMmap(index) => index.build_index(stopped),
             VectorIndexEnum::SparseRam(index) => index.build_index(stopped),
             VectorIndexEnum::SparseMmap(index) => index.build_index(stopped),
+            VectorIndexEnum::SparseCompressedMmap(index) => index.build_index(stopped),
         }
     }
 
@@ -103,6 +137,7 @@ impl VectorIndex for VectorIndexEnum {
             VectorIndexEnum::HnswMmap(index) => index.get_telemetry_data(),
             VectorIndexEnum::SparseRam(index) => index.get_telemetry_data(),
             VectorIndexEnum::SparseMmap(index) => index.get_telemetry_data(),
+            VectorIndexEnum::SparseCompressedMmap(index) => index.get_telemetry_data(),
         }
     }
 
@@ -113,6 +148,7 @@ impl VectorIndex for VectorIndexEnum {
             VectorIndexEnum::HnswMmap(index) => index.files(),
             VectorIndexEnum::SparseRam(index) => index.files(),
             VectorIndexEnum::SparseMmap(index) => index.files(),
+            VectorIndexEnum::SparseCompressedMmap(index) => index.files(),
         }
     }
 
@@ -123,6 +159,7 @@ impl VectorIndex for VectorIndexEnum {
             Self::HnswMmap(index) => index.indexed_vector_count(),
             Self::SparseRam(index) => index.indexed_vector_count(),
             Self::SparseMmap(index) => index.indexed_vector_count(),
+            Self::SparseCompressedMmap(index) => index.indexed_vector_count(),
         }
     }
 
@@ -133,6 +170,7 @@ impl VectorIndex for VectorIndexEnum {
             Self::HnswMmap(index) => index.update_vector(id, vector),
             Self::SparseRam(index) => index.update_vector(id, vector),
             Self::SparseMmap(index) => index.update_vector(id, vector),
+            Self::SparseCompressedMmap(index) => index.update_vector(id, vector),
         }
     }
 }
@@ -2,9 +2,15 @@
 
 use serde_json::Value;
 
+use crate::common::keyword_matcher::KeywordMatcher;
+use crate::common::levenshtein::LevenshteinAutomaton;
+use crate::data_types::keyword_index::KeywordIndexParams;
+use crate::data_types::text_index::TextIndexParams;
+use crate::index::field_index::full_text_index::tokenizers::Tokenizer;
 use crate::types::{
-    AnyVariants, FieldCondition, GeoBoundingBox, GeoPoint, GeoPolygon, GeoRadius, Match, MatchAny,
-    MatchExcept, MatchText, MatchValue, Range, ValueVariants, ValuesCount,
+    AnyVariants, FieldCondition, GeoBoundingBox, GeoPoint, GeoPolygon, GeoRadius, GeoShape,
+    GeoShapeCondition, Match, MatchAny, MatchExcept, MatchText, MatchValue, Range, ValueVariants,
+    ValuesCount,
 };
 
 pub trait ValueChecker {
@@ -52,6 +58,11 @@ impl ValueChecker for FieldCondition {
                 .geo_polygon
                 .as_ref()
                 .map_or(false, |condition| condition.check_match(payload));
+        res = res
+            || self
+                .geo_shape
+                .as_ref()
+                .map_or(false, |condition| condition.check_match(payload));
         res = res
             || self
                 .values_count
@@ -80,8 +91,11 @@ impl ValueChecker for Match {
                 }
                 _ => false,
             },
-            Match::Text(MatchText { text }) => match payload {
-                Value::String(stored) => stored.contains(text),
+            Match::Text(MatchText { text, fuzzy }) => match payload {
+                Value::String(stored) => match fuzzy {
+                    Some(max_distance) => check_fuzzy_text(text, stored, *max_distance),
+                    None => stored.contains(text),
+                },
                 _ => false,
             },
             Match::Any(MatchAny { any }) => match (payload, any) {
@@ -105,10 +119,46 @@ impl ValueChecker for Match {
                 (Value::Number(_), _) => true,
                 (Value::String(_), _) => true,
             },
+            Match::Prefix(_) | Match::Wildcard(_) | Match::Regex(_) => match payload {
+                Value::String(stored) => {
+                    KeywordMatcher::new(self).map_or(false, |matcher| matcher.is_match(stored))
+                }
+                _ => false,
+            },
         }
     }
 }
 
+/// Fuzzy full-text match without an index: every token of the query must be within
+/// the edit distance of some token of the stored text.
+/// Both are tokenized the same way as by a full-text index with default parameters.
+fn check_fuzzy_text(text: &str, stored: &str, max_distance: u8) -> bool {
+    let config = TextIndexParams::default();
+    let mut automata = vec![];
+    Tokenizer::tokenize_query(text, &config, |token| {
+        automata.push(LevenshteinAutomaton::new(token, max_distance));
+    });
+    let mut stored_tokens = vec![];
+    Tokenizer::tokenize_doc(stored, &config, |token| {
+        stored_tokens.push(token.to_owned());
+    });
+    automata
+        .iter()
+        .all(|automaton| stored_tokens.iter().any(|token| automaton.is_match(token)))
+}
+
+/// Check match condition against the payload value, normalizing keywords of both the same way
+/// as the keyword index does, so that indexed and payload checks give the same results
+pub fn check_normalized_match(
+    r#match: &Match,
+    payload: &Value,
+    params: &KeywordIndexParams,
+) -> bool {
+    params
+        .normalize_match(r#match.clone())
+        .check(&params.normalize_value(payload))
+}
+
 impl ValueChecker for Range {
     fn check_match(&self, payload: &Value) -> bool {
         match payload {
@@ -172,6 +222,24 @@ impl ValueChecker for GeoPolygon {
     }
 }
 
+impl ValueChecker for GeoShapeCondition {
+    fn check_match(&self, payload: &Value) -> bool {
+        match payload {
+            Value::Object(obj) => {
+                let lon_op = obj.get("lon").and_then(|x| x.as_f64());
+                let lat_op = obj.get("lat").and_then(|x| x.as_f64());
+
+                if let (Some(lon), Some(lat)) = (lon_op, lat_op) {
+                    return self.check_point(&GeoPoint { lon, lat });
+                }
+                serde_json::from_value::<GeoShape>(payload.clone())
+                    .map_or(false, |shape| self.check_shape(&shape))
+            }
+            _ => false,
+        }
+    }
+}
+
 impl ValueChecker for ValuesCount {
     fn check_match(&self, payload: &Value) -> bool {
         self.check_count(payload)
@@ -187,7 +255,7 @@ mod tests {
     use serde_json::json;
 
     use super::*;
-    use crate::types::GeoPoint;
+    use crate::types::{GeoPoint, GeoShapeQuery, GeoShapeRelation};
 
     #[test]
     fn test_geo_matching() {
@@ -221,6 +289,47 @@ mod tests {
         assert!(!miss_geo_query.check(&berlin_and_moscow));
     }
 
+    #[test]
+    fn test_geo_shape_matching() {
+        let zone = json!({
+            "type": "Polygon",
+            "coordinates": [[[13.0, 52.0], [14.0, 52.0], [14.0, 53.0], [13.0, 53.0], [13.0, 52.0]]]
+        });
+        let road = json!({
+            "type": "LineString",
+            "coordinates": [[12.0, 52.5], [15.0, 52.5]]
+        });
+
+        let query = |relation, shape| GeoShapeCondition { relation, shape };
+        let berlin = GeoShapeQuery::Point(GeoPoint {
+            lat: 52.52197645,
+            lon: 13.413637435864272,
+        });
+        let area = GeoShapeQuery::BoundingBox(GeoBoundingBox {
+            top_left: GeoPoint {
+                lon: 12.5,
+                lat: 53.5,
+            },
+            bottom_right: GeoPoint {
+                lon: 14.5,
+                lat: 51.5,
+            },
+        });
+
+        assert!(query(GeoShapeRelation::Intersects, berlin.clone()).check(&zone));
+        assert!(query(GeoShapeRelation::Contains, berlin.clone()).check(&zone));
+        assert!(!query(GeoShapeRelation::Within, berlin.clone()).check(&zone));
+
+        assert!(query(GeoShapeRelation::Within, area.clone()).check(&zone));
+        assert!(!query(GeoShapeRelation::Within, area.clone()).check(&road));
+        assert!(query(GeoShapeRelation::Intersects, area.clone()).check(&road));
+
+        // geo points are matched as well
+        let point = json!({"lon": 13.5, "lat": 52.5});
+        assert!(query(GeoShapeRelation::Within, area).check(&point));
+        assert!(!query(GeoShapeRelation::Intersects, berlin).check(&point));
+    }
+
     #[test]
     fn test_value_count() {
         let countries = json!([
@@ -256,4 +365,25 @@ mod tests {
         };
         assert!(gte_two_countries_query.check(&countries));
     }
+
+    #[test]
+    fn test_fuzzy_text_matching() {
+        let text = |text: &str, fuzzy: Option<u8>| {
+            Match::Text(MatchText {
+                text: text.to_string(),
+                fuzzy,
+            })
+        };
+        let payload = json!(["the robot rebellion", "humans only"]);
+
+        assert!(!text("robt", None).check(&payload));
+        assert!(text("robt", Some(1)).check(&payload));
+        assert!(text("robt rebelion", Some(1)).check(&payload));
+        // All query words must match the same value
+        assert!(!text("robot humans", Some(1)).check(&payload));
+        assert!(!text("robto", Some(1)).check(&payload));
+        assert!(text("robto", Some(2)).check(&payload));
+        // Tokens are lowercased and split on punctuation like by the default full-text index
+        assert!(text("Robt, Rebelion!", Some(1)).check(&payload));
+    }
 }
@@ -8,83 +8,191 @@ use common::types::PointOffsetType;
 
 use crate::common::utils::IndexesMap;
 use crate::id_tracker::IdTrackerSS;
-use crate::index::field_index::FieldIndex;
+use crate::index::field_index::{FieldIndex, ValueCheckerFn};
 use crate::payload_storage::condition_checker::ValueChecker;
 use crate::payload_storage::payload_storage_enum::PayloadStorageEnum;
 use crate::payload_storage::ConditionChecker;
 use crate::types::{
-    Condition, FieldCondition, Filter, IsEmptyCondition, IsNullCondition, OwnedPayloadRef, Payload,
-    PayloadContainer, PayloadKeyType,
+    Condition, FieldCondition, Filter, IsEmptyCondition, IsNullCondition, NestedCondition,
+    OwnedPayloadRef, Payload, PayloadContainer, PayloadKeyType,
 };
 
-fn check_condition<F>(checker: &F, condition: &Condition) -> bool
-where
-    F: Fn(&Condition) -> bool,
-{
-    match condition {
-        Condition::Filter(filter) => check_filter(checker, filter),
-        _ => checker(condition),
+/// Condition of a filter, prepared once for checks against the payloads of many points
+enum PreparedCondition<'a> {
+    /// Field condition with the check of the field index which influences its result, if any
+    Field(&'a FieldCondition, Option<ValueCheckerFn<'a>>),
+    /// Nested condition with the path of the nested objects
+    Nested(String, PreparedFilter<'a>),
+    Filter(PreparedFilter<'a>),
+    /// Conditions which are not influenced by field indexes
+    Other(&'a Condition),
+}
+
+impl<'a> PreparedCondition<'a> {
+    fn new(
+        condition: &'a Condition,
+        field_indexes: &HashMap<&'a str, &'a Vec<FieldIndex>>,
+    ) -> Self {
+        match condition {
+            Condition::Field(field_condition) => {
+                let index_checker = field_indexes
+                    .get(field_condition.key.as_str())
+                    .and_then(|&indexes| index_value_checker(field_condition, indexes));
+                PreparedCondition::Field(field_condition, index_checker)
+            }
+            Condition::Nested(nested) => PreparedCondition::Nested(
+                nested.array_key(),
+                PreparedFilter::nested_with_indexes(nested, field_indexes),
+            ),
+            Condition::Filter(filter) => {
+                PreparedCondition::Filter(PreparedFilter::with_indexes(filter, field_indexes))
+            }
+            Condition::IsEmpty(_) | Condition::IsNull(_) | Condition::HasId(_) => {
+                PreparedCondition::Other(condition)
+            }
+        }
     }
 }
 
-pub fn check_filter<F>(checker: &F, filter: &Filter) -> bool
-where
-    F: Fn(&Condition) -> bool,
-{
-    check_should(checker, &filter.should)
-        && check_must(checker, &filter.must)
-        && check_must_not(checker, &filter.must_not)
+/// Filter with field conditions prepared with the field indexes which influence their results,
+/// so that for example full-text queries are parsed once instead of for every checked point
+pub struct PreparedFilter<'a> {
+    should: Option<Vec<PreparedCondition<'a>>>,
+    must: Option<Vec<PreparedCondition<'a>>>,
+    must_not: Option<Vec<PreparedCondition<'a>>>,
 }
 
-fn check_should<F>(checker: &F, should: &Option<Vec<Condition>>) -> bool
-where
-    F: Fn(&Condition) -> bool,
-{
-    let check = |x| check_condition(checker, x);
-    match should {
-        None => true,
-        Some(conditions) => conditions.iter().any(check),
+impl<'a> PreparedFilter<'a> {
+    pub fn new<R>(filter: &'a Filter, field_indexes: &'a HashMap<PayloadKeyType, R>) -> Self
+    where
+        R: AsRef<Vec<FieldIndex>>,
+    {
+        Self::with_indexes(filter, &index_refs(field_indexes))
     }
-}
 
-fn check_must<F>(checker: &F, must: &Option<Vec<Condition>>) -> bool
-where
-    F: Fn(&Condition) -> bool,
-{
-    let check = |x| check_condition(checker, x);
-    match must {
-        None => true,
-        Some(conditions) => conditions.iter().all(check),
+    /// Prepare the filter of the nested condition with the indexes of the nested fields
+    pub fn new_nested<R>(
+        nested: &'a NestedCondition,
+        field_indexes: &'a HashMap<PayloadKeyType, R>,
+    ) -> Self
+    where
+        R: AsRef<Vec<FieldIndex>>,
+    {
+        Self::nested_with_indexes(nested, &index_refs(field_indexes))
     }
-}
 
-fn check_must_not<F>(checker: &F, must: &Option<Vec<Condition>>) -> bool
-where
-    F: Fn(&Condition) -> bool,
-{
-    let check = |x| !check_condition(checker, x);
-    match must {
-        None => true,
-        Some(conditions) => conditions.iter().all(check),
+    fn nested_with_indexes(
+        nested: &'a NestedCondition,
+        field_indexes: &HashMap<&'a str, &'a Vec<FieldIndex>>,
+    ) -> Self {
+        // Nested conditions address fields without the nested path,
+        // so it is trimmed from the keys of the nested field indexes
+        let nested_indexes = select_nested_indexes(&nested.array_key(), field_indexes);
+        Self::with_indexes(nested.filter(), &nested_indexes)
+    }
+
+    fn with_indexes(
+        filter: &'a Filter,
+        field_indexes: &HashMap<&'a str, &'a Vec<FieldIndex>>,
+    ) -> Self {
+        let prepare = |conditions: &'a Option<Vec<Condition>>| {
+            conditions.as_ref().map(|conditions| {
+                conditions
+                    .iter()
+                    .map(|condition| PreparedCondition::new(condition, field_indexes))
+                    .collect()
+            })
+        };
+        Self {
+            should: prepare(&filter.should),
+            must: prepare(&filter.must),
+            must_not: prepare(&filter.must_not),
+        }
+    }
+
+    fn check<F>(&self, checker: &F) -> bool
+    where
+        F: Fn(&PreparedCondition<'a>) -> bool,
+    {
+        let check = |condition: &PreparedCondition<'a>| match condition {
+            PreparedCondition::Filter(filter) => filter.check(checker),
+            _ => checker(condition),
+        };
+        self.should
+            .as_ref()
+            .map_or(true, |conditions| conditions.iter().any(check))
+            && self
+                .must
+                .as_ref()
+                .map_or(true, |conditions| conditions.iter().all(check))
+            && self
+                .must_not
+                .as_ref()
+                .map_or(true, |conditions| !conditions.iter().any(check))
+    }
+
+    pub fn check_payload<'p>(
+        &self,
+        get_payload: Box<dyn Fn() -> OwnedPayloadRef<'p> + 'p>,
+        id_tracker: Option<&IdTrackerSS>,
+        point_id: PointOffsetType,
+    ) -> bool {
+        let checker = |condition: &PreparedCondition<'a>| match condition {
+            PreparedCondition::Field(field_condition, index_checker) => {
+                check_prepared_field_condition(
+                    field_condition,
+                    get_payload().deref(),
+                    index_checker.as_ref(),
+                )
+            }
+            PreparedCondition::Nested(nested_path, nested_filter) => get_payload()
+                .get_value(nested_path)
+                .values()
+                .iter()
+                .filter_map(|value| value.as_object())
+                .any(|object| {
+                    nested_filter.check_payload(
+                        Box::new(|| OwnedPayloadRef::from(object)),
+                        // None because has_id in nested is not supported
+                        None,
+                        point_id,
+                    )
+                }),
+            PreparedCondition::Other(Condition::IsEmpty(is_empty)) => {
+                check_is_empty_condition(is_empty, get_payload().deref())
+            }
+            PreparedCondition::Other(Condition::IsNull(is_null)) => {
+                check_is_null_condition(is_null, get_payload().deref())
+            }
+            PreparedCondition::Other(Condition::HasId(has_id)) => id_tracker
+                .and_then(|id_tracker| id_tracker.external_id(point_id))
+                .map_or(false, |id| has_id.has_id.contains(&id)),
+            PreparedCondition::Other(_) | PreparedCondition::Filter(_) => unreachable!(),
+        };
+
+        self.check(&checker)
     }
 }
 
-pub fn select_nested_indexes<'a, R>(
-    nested_path: &str,
-    field_indexes: &'a HashMap<PayloadKeyType, R>,
-) -> HashMap<PayloadKeyType, &'a Vec<FieldIndex>>
+fn index_refs<R>(field_indexes: &HashMap<PayloadKeyType, R>) -> HashMap<&str, &Vec<FieldIndex>>
 where
     R: AsRef<Vec<FieldIndex>>,
 {
+    field_indexes
+        .iter()
+        .map(|(key, indexes)| (key.as_str(), indexes.as_ref()))
+        .collect()
+}
+
+fn select_nested_indexes<'a>(
+    nested_path: &str,
+    field_indexes: &HashMap<&'a str, &'a Vec<FieldIndex>>,
+) -> HashMap<&'a str, &'a Vec<FieldIndex>> {
     let nested_prefix = format!("{}.", nested_path);
-    let nested_indexes: HashMap<_, _> = field_indexes
+    field_indexes
         .iter()
-        .filter_map(|(key, indexes)| {
-            key.strip_prefix(&nested_prefix)
-                .map(|key| (key.into(), indexes.as_ref()))
-        })
-        .collect();
-    nested_indexes
+        .filter_map(|(&key, &indexes)| key.strip_prefix(&nested_prefix).map(|key| (key, indexes)))
+        .collect()
 }
 
 pub fn check_payload<'a, R>(
@@ -97,37 +205,7 @@ pub fn check_payload<'a, R>(
 where
     R: AsRef<Vec<FieldIndex>>,
 {
-    let checker = |condition: &Condition| match condition {
-        Condition::Field(field_condition) => {
-            check_field_condition(field_condition, get_payload().deref(), field_indexes)
-        }
-        Condition::IsEmpty(is_empty) => check_is_empty_condition(is_empty, get_payload().deref()),
-        Condition::IsNull(is_null) => check_is_null_condition(is_null, get_payload().deref()),
-        Condition::HasId(has_id) => id_tracker
-            .and_then(|id_tracker| id_tracker.external_id(point_id))
-            .map_or(false, |id| has_id.has_id.contains(&id)),
-        Condition::Nested(nested) => {
-            let nested_path = nested.array_key();
-            let nested_indexes = select_nested_indexes(&nested_path, field_indexes);
-            get_payload()
-                .get_value(&nested_path)
-                .values()
-                .iter()
-                .filter_map(|value| value.as_object())
-                .any(|object| {
-                    check_payload(
-                        Box::new(|| OwnedPayloadRef::from(object)),
-                        None,
-                        &nested.nested.filter,
-                        point_id,
-                        &nested_indexes,
-                    )
-                })
-        }
-        Condition::Filter(_) => unreachable!(),
-    };
-
-    check_filter(&checker, query)
+    PreparedFilter::new(query, field_indexes).check_payload(get_payload, id_tracker, point_id)
 }
 
 pub fn check_is_empty_condition(
@@ -141,6 +219,30 @@ pub fn check_is_null_condition(is_null: &IsNullCondition, payload: &impl Payload
     payload.get_value(&is_null.is_null.key).check_is_null()
 }
 
+/// Check of the field condition by the first of the field indexes which influences its result
+pub fn index_value_checker<'a>(
+    field_condition: &FieldCondition,
+    field_indexes: &'a [FieldIndex],
+) -> Option<ValueCheckerFn<'a>> {
+    field_indexes
+        .iter()
+        .find_map(|index| index.value_checker(field_condition))
+}
+
+pub fn check_prepared_field_condition(
+    field_condition: &FieldCondition,
+    payload: &impl PayloadContainer,
+    index_checker: Option<&ValueCheckerFn>,
+) -> bool {
+    let field_values = payload.get_value(&field_condition.key);
+    match index_checker {
+        // This covers a case, when a field index affects the result of the condition.
+        Some(index_checker) => field_values.into_iter().any(index_checker),
+        // Fallback to regular condition check if no index affects the condition
+        None => field_values.into_iter().any(|p| field_condition.check(p)),
+    }
+}
+
 pub fn check_field_condition<R>(
     field_condition: &FieldCondition,
     payload: &impl PayloadContainer,
@@ -149,38 +251,10 @@ pub fn check_field_condition<R>(
 where
     R: AsRef<Vec<FieldIndex>>,
 {
-    let field_values = payload.get_value(&field_condition.key);
-    let field_indexes = field_indexes.get(&field_condition.key);
-
-    // This covers a case, when a field index affects the result of the condition.
-    if let Some(field_indexes) = field_indexes {
-        for p in field_values {
-            let mut index_checked = false;
-            for index in field_indexes.as_ref() {
-                if let Some(index_check_res) = index.check_condition(field_condition, p) {
-                    if index_check_res {
-                        // If at least one object matches the condition, we can return true
-                        return true;
-                    }
-                    index_checked = true;
-                    // If index check of the condition returned something, we don't need to check
-                    // other indexes
-                    break;
-                }
-            }
-            if !index_checked {
-                // If none of the indexes returned anything, we need to check the condition
-                // against the payload
-                if field_condition.check(p) {
-                    return true;
-                }
-            }
-        }
-        false
-    } else {
-        // Fallback to regular condition check if there are no indexes for the field
-        field_values.into_iter().any(|p| field_condition.check(p))
-    }
+    let index_checker = field_indexes
+        .get(&field_condition.key)
+        .and_then(|indexes| index_value_checker(field_condition, indexes.as_ref()));
+    check_prepared_field_condition(field_condition, payload, index_checker.as_ref())
 }
 
 /// Only used for testing
@@ -10,6 +10,7 @@ use atomic_refcell::AtomicRefCell;
 use common::types::{PointOffsetType, ScoredPointOffset};
 use io::file_operations::{atomic_save_json, read_json};
 use memory::mmap_ops;
+use ordered_float::OrderedFloat;
 use parking_lot::{Mutex, RwLock};
 use rocksdb::DB;
 use sparse::common::sparse_vector::SparseVector;
@@ -24,19 +25,24 @@ use crate::common::version::{StorageVersion, VERSION_FILE};
 use crate::common::{
     check_named_vectors, check_query_vectors, check_stopped, check_vector, check_vector_name,
 };
+use crate::data_types::aggregation::{HistogramParams, NumericAggregation};
+use crate::data_types::distinct::DistinctCounter;
 use crate::data_types::named_vectors::NamedVectors;
+use crate::data_types::text_index::TextStats;
 use crate::data_types::vectors::{QueryVector, Vector};
 use crate::entry::entry_point::SegmentEntry;
 use crate::id_tracker::IdTrackerSS;
-use crate::index::field_index::CardinalityEstimation;
+use crate::index::field_index::{CardinalityEstimation, FieldIndex};
 use crate::index::struct_payload_index::StructPayloadIndex;
 use crate::index::{PayloadIndex, VectorIndex, VectorIndexEnum};
-use crate::spaces::tools::peek_top_smallest_iterable;
+use crate::segment_constructor::{open_sparse_vector_data, open_vector_data, remove_vector_data};
+use crate::spaces::tools::{peek_top_largest_iterable, peek_top_smallest_iterable};
 use crate::telemetry::SegmentTelemetry;
 use crate::types::{
-    Filter, Payload, PayloadFieldSchema, PayloadIndexInfo, PayloadKeyType, PayloadKeyTypeRef,
-    PayloadSchemaType, PointIdType, ScoredPoint, SearchParams, SegmentConfig, SegmentInfo,
-    SegmentState, SegmentType, SeqNumberType, VectorDataInfo, WithPayload, WithVector,
+    Filter, OrderBy, Payload, PayloadFieldSchema, PayloadIndexInfo, PayloadKeyType,
+    PayloadKeyTypeRef, PayloadSchemaType, PointIdType, ScoredPoint, SearchParams, SegmentConfig,
+    SegmentInfo, SegmentState, SegmentType, SeqNumberType, SparseVectorDataConfig, ValueVariants,
+    VectorDataConfig, VectorDataInfo, WithPayload, WithVector,
 };
 use crate::utils;
 use crate::utils::fs::find_symlink;
@@ -112,6 +118,18 @@ impl VectorData {
     }
 }
 
+/// Value stored for points, which don't have the vector. It is marked as deleted right away.
+pub(crate) fn placeholder_vector(vector_storage: &VectorStorageEnum) -> Vector {
+    let dim = vector_storage.vector_dim();
+    match vector_storage {
+        VectorStorageEnum::DenseSimple(_)
+        | VectorStorageEnum::Memmap(_)
+        | VectorStorageEnum::AppendableMemmap(_)
+        | VectorStorageEnum::BinarySimple(_) => vec![1.0; dim].into(),
+        VectorStorageEnum::SparseSimple(_) => SparseVector::default().into(),
+    }
+}
+
 impl Segment {
     /// Replace vectors in-place
     ///
@@ -206,13 +224,7 @@ impl Segment {
             let mut vector_index = vector_data.vector_index.borrow_mut();
             match vector_opt {
                 None => {
-                    let dim = vector_storage.vector_dim();
-                    let vector: Vector = match *vector_storage {
-                        VectorStorageEnum::DenseSimple(_)
-                        | VectorStorageEnum::Memmap(_)
-                        | VectorStorageEnum::AppendableMemmap(_) => vec![1.0; dim].into(),
-                        VectorStorageEnum::SparseSimple(_) => SparseVector::default().into(),
-                    };
+                    let vector = placeholder_vector(&vector_storage);
                     vector_storage.insert_vector(new_index, vector.to_vec_ref())?;
                     vector_storage.delete_vector(new_index)?;
                     vector_index.update_vector(new_index, vector.to_vec_ref())?;
@@ -455,6 +467,119 @@ impl Segment {
         Self::save_state(&self.get_state(), &self.current_path)
     }
 
+    /// Save changed segment config along with the persisted version.
+    ///
+    /// Operations, which are not persisted yet, are recovered from WAL.
+    fn save_config(&self) -> OperationResult<()> {
+        let state = SegmentState {
+            version: *self.persisted_version.lock(),
+            config: self.segment_config.clone(),
+        };
+        Self::save_state(&state, &self.current_path)
+    }
+
+    /// Add a new dense vector to the segment.
+    ///
+    /// Existing points have no value for the vector.
+    /// Does nothing, if the segment already has the vector.
+    pub fn create_vector(
+        &mut self,
+        vector_name: &str,
+        config: VectorDataConfig,
+    ) -> OperationResult<()> {
+        if self.vector_data.contains_key(vector_name) {
+            return Ok(());
+        }
+        let mut segment_config = self.segment_config.clone();
+        segment_config
+            .vector_data
+            .insert(vector_name.to_owned(), config);
+        self.add_vector_data(vector_name, segment_config, open_vector_data)
+    }
+
+    /// Add a new sparse vector to the segment.
+    ///
+    /// Existing points have no value for the vector.
+    /// Does nothing, if the segment already has the vector.
+    pub fn create_sparse_vector(
+        &mut self,
+        vector_name: &str,
+        config: SparseVectorDataConfig,
+    ) -> OperationResult<()> {
+        if self.vector_data.contains_key(vector_name) {
+            return Ok(());
+        }
+        let mut segment_config = self.segment_config.clone();
+        segment_config
+            .sparse_vector_data
+            .insert(vector_name.to_owned(), config);
+        self.add_vector_data(vector_name, segment_config, open_sparse_vector_data)
+    }
+
+    fn add_vector_data(
+        &mut self,
+        vector_name: &str,
+        segment_config: SegmentConfig,
+        open: impl FnOnce(
+            &Path,
+            &Arc<RwLock<DB>>,
+            &Arc<AtomicRefCell<IdTrackerSS>>,
+            &Arc<AtomicRefCell<StructPayloadIndex>>,
+            &SegmentConfig,
+            &str,
+        ) -> OperationResult<VectorData>,
+    ) -> OperationResult<()> {
+        // Background flush saves the config it has started with, wait for it to finish
+        drop(self.lock_flushing()?);
+
+        // Leftovers of a removed vector with the same name
+        remove_vector_data(&self.current_path, &self.database, vector_name)?;
+
+        let vector_data = open(
+            &self.current_path,
+            &self.database,
+            &self.id_tracker,
+            &self.payload_index,
+            &segment_config,
+            vector_name,
+        )?;
+
+        // Existing points have no value for the new vector
+        {
+            let mut vector_storage = vector_data.vector_storage.borrow_mut();
+            let vector = placeholder_vector(&vector_storage);
+            let point_count = self.id_tracker.borrow().total_point_count();
+            for internal_id in 0..point_count as PointOffsetType {
+                vector_storage.insert_vector(internal_id, vector.to_vec_ref())?;
+                vector_storage.delete_vector(internal_id)?;
+            }
+            vector_storage.flusher()()?;
+        }
+
+        self.vector_data.insert(vector_name.to_owned(), vector_data);
+        self.segment_config = segment_config;
+        self.save_config()
+    }
+
+    /// Remove a dense or sparse vector from the segment along with its data.
+    ///
+    /// Does nothing, if the segment doesn't have the vector.
+    pub fn remove_vector(&mut self, vector_name: &str) -> OperationResult<()> {
+        // Background flush saves the config it has started with, wait for it to finish
+        drop(self.lock_flushing()?);
+
+        let Some(vector_data) = self.vector_data.remove(vector_name) else {
+            return Ok(());
+        };
+        self.segment_config.vector_data.remove(vector_name);
+        self.segment_config.sparse_vector_data.remove(vector_name);
+        self.save_config()?;
+
+        // Release storages before removing their files
+        drop(vector_data);
+        remove_vector_data(&self.current_path, &self.database, vector_name)
+    }
+
     fn infer_from_payload_data(
         &self,
         key: PayloadKeyTypeRef,
@@ -613,6 +738,7 @@ impl Segment {
                     payload,
                     vector,
                     shard_key: None,
+                    geo_distance: None,
                 })
             })
             .collect()
@@ -666,6 +792,218 @@ impl Segment {
             .collect()
     }
 
+    /// Read up to `limit` filtered points ordered by the distance to the `order_by` origin.
+    ///
+    /// Only points at or after the cursor of `order_by` start distance and `offset` id are read.
+    /// Uses the geo index of the field if present, otherwise checks the payload of every point.
+    pub fn filtered_read_by_geo_distance(
+        &self,
+        offset: Option<PointIdType>,
+        limit: usize,
+        filter: Option<&Filter>,
+        order_by: &OrderBy,
+    ) -> OperationResult<Vec<(f64, PointIdType)>> {
+        let geo_distance = &order_by.geo_distance;
+        let payload_index = self.payload_index.borrow();
+        let id_tracker = self.id_tracker.borrow();
+        let filter_context = filter.map(|condition| payload_index.filter_context(condition));
+        let check = |distance: f64, internal_id: PointOffsetType| {
+            let is_after_cursor = id_tracker
+                .external_id(internal_id)
+                .is_some_and(|point_id| order_by.is_after_cursor(distance, point_id, offset));
+            is_after_cursor
+                && filter_context
+                    .as_ref()
+                    .map_or(true, |context| context.check(internal_id))
+        };
+
+        let geo_index = payload_index
+            .field_indexes
+            .get(&geo_distance.key)
+            .and_then(|indexes| {
+                indexes.iter().find_map(|index| match index {
+                    FieldIndex::GeoIndex(geo_index) => Some(geo_index),
+                    _ => None,
+                })
+            });
+
+        // Ties are broken by external id, the same way as the results of segments are merged
+        // and as the `offset` cursor pages, so that no point at the cursor distance is skipped
+        match geo_index {
+            Some(geo_index) => {
+                let ordered = geo_index.points_by_distance(
+                    &geo_distance.origin,
+                    limit,
+                    |distance, internal_id| {
+                        !id_tracker.is_deleted_point(internal_id) && check(distance, internal_id)
+                    },
+                    |internal_id| id_tracker.external_id(internal_id),
+                )?;
+                Ok(ordered
+                    .into_iter()
+                    .filter_map(|(distance, internal_id)| {
+                        id_tracker
+                            .external_id(internal_id)
+                            .map(|external_id| (distance, external_id))
+                    })
+                    .collect())
+            }
+            None => {
+                let mut distances = vec![];
+                for internal_id in id_tracker.iter_ids() {
+                    let payload = payload_index.payload(internal_id)?;
+                    if let Some(distance) = geo_distance.payload_distance(&payload) {
+                        if check(distance, internal_id) {
+                            if let Some(external_id) = id_tracker.external_id(internal_id) {
+                                distances.push((distance, external_id));
+                            }
+                        }
+                    }
+                }
+                distances.sort_unstable_by_key(|(distance, external_id)| {
+                    (OrderedFloat(*distance), *external_id)
+                });
+                distances.truncate(limit);
+                Ok(distances)
+            }
+        }
+    }
+
+    /// Aggregate numeric values of the payload field `key` of all filtered points.
+    ///
+    /// Uses the numeric index of the field if present, otherwise reads the payload of every point.
+    /// Fails if the values span more histogram buckets than allowed.
+    pub fn filtered_aggregate(
+        &self,
+        filter: Option<&Filter>,
+        key: PayloadKeyTypeRef,
+        histogram: Option<&HistogramParams>,
+    ) -> OperationResult<NumericAggregation> {
+        let payload_index = self.payload_index.borrow();
+        let points = match filter {
+            Some(condition) => payload_index.query_points(condition),
+            None => self.id_tracker.borrow().iter_ids().collect(),
+        };
+
+        let numeric_index = payload_index.field_indexes.get(key).and_then(|indexes| {
+            indexes
+                .iter()
+                .find(|index| matches!(index, FieldIndex::IntIndex(_) | FieldIndex::FloatIndex(_)))
+        });
+
+        let mut aggregation = NumericAggregation::default();
+        for internal_id in points {
+            match numeric_index {
+                Some(FieldIndex::IntIndex(index)) => index
+                    .get_values(internal_id)
+                    .into_iter()
+                    .flatten()
+                    .for_each(|value| aggregation.add_integer(*value, histogram)),
+                Some(FieldIndex::FloatIndex(index)) => index
+                    .get_values(internal_id)
+                    .into_iter()
+                    .flatten()
+                    .for_each(|value| aggregation.add(*value, histogram)),
+                _ => {
+                    let payload = payload_index.payload(internal_id)?;
+                    aggregation.add_payload(&payload, key, histogram);
+                }
+            }
+        }
+        aggregation
+            .check_histogram(histogram)
+            .map_err(|description| OperationError::ValidationError { description })?;
+        Ok(aggregation)
+    }
+
+    /// Collect distinct keyword and integer values of the payload field `key` of all filtered points.
+    ///
+    /// Uses the keyword or integer map index of the field if present, otherwise reads the payload of every point.
+    /// Fails if `exact` and the number of distinct values exceeds
+    /// [`EXACT_DISTINCT_THRESHOLD`](crate::data_types::distinct::EXACT_DISTINCT_THRESHOLD).
+    pub fn filtered_distinct(
+        &self,
+        filter: Option<&Filter>,
+        key: PayloadKeyTypeRef,
+        exact: bool,
+    ) -> OperationResult<DistinctCounter> {
+        let payload_index = self.payload_index.borrow();
+        let points = match filter {
+            Some(condition) => payload_index.query_points(condition),
+            None => self.id_tracker.borrow().iter_ids().collect(),
+        };
+
+        let map_index = payload_index.field_indexes.get(key).and_then(|indexes| {
+            indexes.iter().find(|index| {
+                matches!(
+                    index,
+                    FieldIndex::KeywordIndex(_) | FieldIndex::IntMapIndex(_)
+                )
+            })
+        });
+
+        let mut counter = DistinctCounter::default();
+        for internal_id in points {
+            let added = match map_index {
+                Some(FieldIndex::KeywordIndex(index)) => index
+                    .get_values(internal_id)
+                    .into_iter()
+                    .flatten()
+                    .try_for_each(|value| {
+                        counter.add(ValueVariants::Keyword(value.to_string()), exact)
+                    }),
+                Some(FieldIndex::IntMapIndex(index)) => index
+                    .get_values(internal_id)
+                    .into_iter()
+                    .flatten()
+                    .try_for_each(|value| counter.add(ValueVariants::Integer(*value), exact)),
+                _ => {
+                    let payload = payload_index.payload(internal_id)?;
+                    counter.add_payload(&payload, key, exact)
+                }
+            };
+            added.map_err(|description| OperationError::ValidationError { description })?;
+        }
+        Ok(counter)
+    }
+
+    /// Score filtered points by the full-text index of the payload field `key` with BM25 and read the `top` of them.
+    ///
+    /// Segments without a full-text index on the field have no matches.
+    #[allow(clippy::too_many_arguments)]
+    pub fn filtered_search_text(
+        &self,
+        key: PayloadKeyTypeRef,
+        query: &str,
+        stats: &TextStats,
+        filter: Option<&Filter>,
+        top: usize,
+        with_payload: &WithPayload,
+        with_vector: &WithVector,
+    ) -> OperationResult<Vec<ScoredPoint>> {
+        let internal_result = {
+            let payload_index = self.payload_index.borrow();
+            let id_tracker = self.id_tracker.borrow();
+            let Some(text_index) = payload_index.full_text_index(key) else {
+                return Ok(vec![]);
+            };
+            let filter_context = filter.map(|condition| payload_index.filter_context(condition));
+            let scores = text_index.score_query(query, stats, |internal_id| {
+                !id_tracker.is_deleted_point(internal_id)
+                    && filter_context
+                        .as_ref()
+                        .map_or(true, |context| context.check(internal_id))
+            });
+            peek_top_largest_iterable(
+                scores
+                    .into_iter()
+                    .map(|(idx, score)| ScoredPointOffset { idx, score }),
+                top,
+            )
+        };
+        self.process_search_result(&internal_result, with_payload, with_vector)
+    }
+
     /// Check consistency of the segment's data and repair it if possible.
     pub fn check_consistency_and_repair(&mut self) -> OperationResult<()> {
         let mut internal_ids_to_delete = HashSet::new();
@@ -815,7 +1153,7 @@ impl SegmentEntry for Segment {
     ) -> OperationResult<bool> {
         debug_assert!(self.is_appendable());
         check_named_vectors(&vectors, &self.segment_config)?;
-        vectors.preprocess(|name| self.segment_config.distance(name).unwrap());
+        vectors.preprocess(&self.segment_config);
         let stored_internal_point = self.id_tracker.borrow().internal_id(point_id);
         self.handle_version_and_failure(op_num, stored_internal_point, |segment| {
             if let Some(existing_internal_id) = stored_internal_point {
@@ -868,7 +1206,7 @@ impl SegmentEntry for Segment {
         mut vectors: NamedVectors,
     ) -> OperationResult<bool> {
         check_named_vectors(&vectors, &self.segment_config)?;
-        vectors.preprocess(|name| self.segment_config.distance(name).unwrap());
+        vectors.preprocess(&self.segment_config);
         let internal_id = self.id_tracker.borrow().internal_id(point_id);
         match internal_id {
             None => Err(OperationError::PointIdError {
@@ -1078,6 +1416,55 @@ impl SegmentEntry for Segment {
         }
     }
 
+    fn read_ordered_filtered<'a>(
+        &'a self,
+        offset: Option<PointIdType>,
+        limit: usize,
+        filter: Option<&'a Filter>,
+        order_by: &'a OrderBy,
+    ) -> OperationResult<Vec<(f64, PointIdType)>> {
+        self.filtered_read_by_geo_distance(offset, limit, filter, order_by)
+    }
+
+    fn aggregate_filtered<'a>(
+        &'a self,
+        filter: Option<&'a Filter>,
+        key: PayloadKeyTypeRef,
+        histogram: Option<&'a HistogramParams>,
+    ) -> OperationResult<NumericAggregation> {
+        self.filtered_aggregate(filter, key, histogram)
+    }
+
+    fn distinct_filtered<'a>(
+        &'a self,
+        filter: Option<&'a Filter>,
+        key: PayloadKeyTypeRef,
+        exact: bool,
+    ) -> OperationResult<DistinctCounter> {
+        self.filtered_distinct(filter, key, exact)
+    }
+
+    fn text_stats(&self, key: PayloadKeyTypeRef, query: &str) -> OperationResult<TextStats> {
+        let payload_index = self.payload_index.borrow();
+        Ok(payload_index
+            .full_text_index(key)
+            .map(|text_index| text_index.query_stats(query))
+            .unwrap_or_default())
+    }
+
+    fn search_text(
+        &self,
+        key: PayloadKeyTypeRef,
+        query: &str,
+        stats: &TextStats,
+        filter: Option<&Filter>,
+        top: usize,
+        with_payload: &WithPayload,
+        with_vector: &WithVector,
+    ) -> OperationResult<Vec<ScoredPoint>> {
+        self.filtered_search_text(key, query, stats, filter, top, with_payload, with_vector)
+    }
+
     fn read_range(&self, from: Option<PointIdType>, to: Option<PointIdType>) -> Vec<PointIdType> {
         let id_tracker = self.id_tracker.borrow();
         let iterator = id_tracker.iter_from(from).map(|x| x.0);
@@ -1373,6 +1760,10 @@ impl SegmentEntry for Segment {
         self.payload_index.borrow().indexed_fields()
     }
 
+    fn unindexed_filter_key(&self, filter: &Filter) -> Option<PayloadKeyType> {
+        self.payload_index.borrow().unindexed_filter_key(filter)
+    }
+
     fn check_error(&self) -> Option<SegmentFailedState> {
         self.error_status.clone()
     }
@@ -2317,4 +2708,83 @@ mod tests {
                 .unwrap();
         }
     }
+
+    #[test]
+    fn test_create_and_remove_vector() {
+        let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+        let vector_config = VectorDataConfig {
+            size: 2,
+            distance: Distance::Dot,
+            storage_type: VectorStorageType::Memory,
+            index: Indexes::Plain {},
+            quantization_config: None,
+        };
+        let config = SegmentConfig {
+            vector_data: HashMap::from([("a".into(), vector_config.clone())]),
+            sparse_vector_data: Default::default(),
+            payload_storage_type: Default::default(),
+        };
+        let mut segment = build_segment(dir.path(), &config, true).unwrap();
+
+        segment
+            .upsert_point(
+                100,
+                1.into(),
+                NamedVectors::from([("a".into(), vec![0.1, 0.2])]),
+            )
+            .unwrap();
+        segment
+            .upsert_point(
+                101,
+                2.into(),
+                NamedVectors::from([("a".into(), vec![0.3, 0.4])]),
+            )
+            .unwrap();
+
+        // Existing points have no value for the new vector
+        segment.create_vector("b", vector_config.clone()).unwrap();
+        assert!(segment.config().vector_data.contains_key("b"));
+        assert_eq!(segment.info().num_vectors, 2);
+        let internal_1 = segment.lookup_internal_id(1.into()).unwrap();
+        assert!(segment.vector("b", 1.into()).unwrap().is_none());
+
+        SegmentEntry::update_vectors(
+            &mut segment,
+            102,
+            2.into(),
+            NamedVectors::from([("b".into(), vec![0.5, 0.6])]),
+        )
+        .unwrap();
+        assert_eq!(segment.info().num_vectors, 3);
+
+        // New points may have the new vector
+        segment
+            .upsert_point(
+                103,
+                3.into(),
+                NamedVectors::from([("a".into(), vec![0.7, 0.8]), ("b".into(), vec![0.9, 1.0])]),
+            )
+            .unwrap();
+        assert_eq!(segment.info().num_vectors, 5);
+
+        segment.remove_vector("a").unwrap();
+        assert!(!segment.config().vector_data.contains_key("a"));
+        assert_eq!(segment.info().num_vectors, 2);
+
+        // Changes survive reload
+        let segment_path = segment.current_path.clone();
+        segment.flush(true).unwrap();
+        drop(segment);
+        let segment = load_segment(&segment_path).unwrap().unwrap();
+        assert_eq!(
+            segment.config().vector_data.keys().collect::<Vec<_>>(),
+            vec!["b"]
+        );
+        assert!(segment.vector("b", 1.into()).unwrap().is_none());
+        assert_eq!(
+            segment.vector("b", 2.into()).unwrap(),
+            Some(vec![0.5, 0.6].into())
+        );
+        assert_eq!(segment.lookup_internal_id(1.into()).unwrap(), internal_1);
+    }
 }
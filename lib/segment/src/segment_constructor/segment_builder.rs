@@ -2,18 +2,22 @@ use std::cmp;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+
+use atomic_refcell::AtomicRefCell;
+use common::types::PointOffsetType;
 
 use super::get_vector_storage_path;
 use crate::common::error_logging::LogError;
 use crate::common::operation_error::{check_process_stopped, OperationError, OperationResult};
 use crate::entry::entry_point::SegmentEntry;
 use crate::index::hnsw_index::max_rayon_threads;
-use crate::index::{PayloadIndex, VectorIndex};
-use crate::segment::Segment;
+use crate::index::{PayloadIndex, VectorIndex, VectorIndexEnum};
+use crate::segment::{placeholder_vector, Segment};
 use crate::segment_constructor::{build_segment, load_segment};
 use crate::types::{Indexes, PayloadFieldSchema, PayloadKeyType, SegmentConfig};
 use crate::vector_storage::quantized::quantized_vectors::QuantizedVectors;
-use crate::vector_storage::VectorStorage;
+use crate::vector_storage::{VectorStorage, VectorStorageEnum};
 
 /// Structure for constructing segment out of several other segments
 pub struct SegmentBuilder {
@@ -21,6 +25,15 @@ pub struct SegmentBuilder {
     pub destination_path: PathBuf,
     pub temp_path: PathBuf,
     pub indexed_fields: HashMap<PayloadKeyType, PayloadFieldSchema>,
+    source_indexes: Vec<SourceIndexes>,
+}
+
+/// Vector indexes of a source segment, which can be reused on index building
+struct SourceIndexes {
+    vector_indexes: HashMap<String, Arc<AtomicRefCell<VectorIndexEnum>>>,
+    /// Mapping of internal ids of the source segment into internal ids of the new segment
+    old_to_new: Vec<Option<PointOffsetType>>,
+    points_count: usize,
 }
 
 impl SegmentBuilder {
@@ -39,6 +52,7 @@ impl SegmentBuilder {
             destination_path,
             temp_path,
             indexed_fields: Default::default(),
+            source_indexes: vec![],
         })
     }
 
@@ -87,25 +101,35 @@ impl SegmentBuilder {
             .collect();
         let mut payload_index = self_segment.payload_index.borrow_mut();
 
-        if vector_storages.len() != other_vector_storages.len() {
-            return Err(OperationError::service_error(
-                format!("Self and other segments have different vector names count. Self count: {}, other count: {}", vector_storages.len(), other_vector_storages.len()),
-            ));
-        }
-
+        let mut old_to_new = vec![None; other_id_tracker.total_point_count()];
         let mut new_internal_range = None;
         for (vector_name, vector_storage) in &mut vector_storages {
             check_process_stopped(stopped)?;
-            let other_vector_storage = other_vector_storages.get(vector_name).ok_or_else(|| {
-                OperationError::service_error(format!(
-                    "Cannot update from other segment because if missing vector name {vector_name}"
-                ))
-            })?;
-            let internal_range = vector_storage.update_from(
-                other_vector_storage,
-                &mut other_id_tracker.iter_ids(),
-                stopped,
-            )?;
+            let internal_range = match other_vector_storages.get(vector_name) {
+                Some(other_vector_storage) => vector_storage.update_from(
+                    other_vector_storage,
+                    &mut other_id_tracker.iter_ids(),
+                    stopped,
+                )?,
+                None => {
+                    // Vector was added to the collection after the other segment was built,
+                    // points of the other segment don't have it
+                    if matches!(**vector_storage, VectorStorageEnum::Memmap(_)) {
+                        return Err(OperationError::service_error(format!(
+                            "Cannot update from other segment because of missing vector name {vector_name}"
+                        )));
+                    }
+                    let start = vector_storage.total_vector_count() as PointOffsetType;
+                    let end = start + other_id_tracker.iter_ids().count() as PointOffsetType;
+                    let placeholder = placeholder_vector(vector_storage);
+                    for internal_id in start..end {
+                        check_process_stopped(stopped)?;
+                        vector_storage.insert_vector(internal_id, placeholder.to_vec_ref())?;
+                        vector_storage.delete_vector(internal_id)?;
+                    }
+                    start..end
+                }
+            };
             match new_internal_range.clone() {
                 Some(new_internal_range) => {
                     if new_internal_range != internal_range {
@@ -135,6 +159,10 @@ impl SegmentBuilder {
                     };
                 let other_version = other_id_tracker.internal_version(old_internal_id).unwrap();
 
+                if let Some(new_id) = old_to_new.get_mut(old_internal_id as usize) {
+                    *new_id = Some(new_internal_id);
+                }
+
                 match id_tracker.internal_id(external_id) {
                     None => {
                         // New point, just insert
@@ -178,6 +206,18 @@ impl SegmentBuilder {
             self.indexed_fields.insert(field, payload_schema);
         }
 
+        self.source_indexes.push(SourceIndexes {
+            vector_indexes: other
+                .vector_data
+                .iter()
+                .map(|(vector_name, vector_data)| {
+                    (vector_name.to_owned(), vector_data.vector_index.clone())
+                })
+                .collect(),
+            points_count: other_id_tracker.available_point_count(),
+            old_to_new,
+        });
+
         Ok(true)
     }
 
@@ -194,8 +234,29 @@ impl SegmentBuilder {
 
             Self::update_quantization(&mut segment, stopped)?;
 
-            for vector_data in segment.vector_data.values_mut() {
-                vector_data.vector_index.borrow_mut().build_index(stopped)?;
+            for (vector_name, vector_data) in &segment.vector_data {
+                let mut vector_index = vector_data.vector_index.borrow_mut();
+                // Graph of the largest indexed source segment is carried over into the new index,
+                // so only points of other segments have to be inserted
+                let old_index = self
+                    .source_indexes
+                    .iter()
+                    .filter_map(|source| {
+                        let old_index = source.vector_indexes.get(vector_name)?;
+                        let is_hnsw = matches!(
+                            &*old_index.borrow(),
+                            VectorIndexEnum::HnswRam(_) | VectorIndexEnum::HnswMmap(_)
+                        );
+                        is_hnsw.then_some((old_index, source))
+                    })
+                    .max_by_key(|(_, source)| source.points_count)
+                    .map(|(old_index, source)| (old_index, &source.old_to_new));
+                match old_index {
+                    Some((old_index, old_to_new)) => {
+                        vector_index.build_index_from(&old_index.borrow(), old_to_new, stopped)?
+                    }
+                    None => vector_index.build_index(stopped)?,
+                }
             }
 
             segment.flush(true)?;
@@ -226,7 +287,19 @@ impl SegmentBuilder {
                     _ => 1,
                 }
             } else {
-                // quantization is applied only for dense vectors
+                // sparse `uint8` weights are quantized once the sparse index is immutable
+                let is_appendable = config
+                    .sparse_vector_data
+                    .get(vector_name)
+                    .map_or(true, |config| config.is_appendable());
+                if let VectorStorageEnum::SparseSimple(vector_storage) =
+                    &mut *vector_data.vector_storage.borrow_mut()
+                {
+                    if !is_appendable {
+                        check_process_stopped(stopped)?;
+                        vector_storage.quantize(stopped)?;
+                    }
+                }
                 continue;
             };
 
@@ -6,17 +6,18 @@ use std::sync::Arc;
 
 use atomic_refcell::AtomicRefCell;
 use log::info;
-use parking_lot::Mutex;
+use parking_lot::{Mutex, RwLock};
+use rocksdb::DB;
 use semver::Version;
 use serde::Deserialize;
 use uuid::Uuid;
 
 use crate::common::operation_error::{OperationError, OperationResult};
-use crate::common::rocksdb_wrapper::{open_db, DB_VECTOR_CF};
+use crate::common::rocksdb_wrapper::{open_db, DatabaseColumnWrapper, DB_VECTOR_CF};
 use crate::common::version::StorageVersion;
 use crate::data_types::vectors::DEFAULT_VECTOR_NAME;
 use crate::id_tracker::simple_id_tracker::SimpleIdTracker;
-use crate::id_tracker::IdTracker;
+use crate::id_tracker::{IdTracker, IdTrackerSS};
 use crate::index::hnsw_index::graph_links::{GraphLinksMmap, GraphLinksRam};
 use crate::index::hnsw_index::hnsw::HNSWIndex;
 use crate::index::plain_payload_index::PlainIndex;
@@ -34,6 +35,7 @@ use crate::types::{
 use crate::vector_storage::appendable_mmap_vector_storage::open_appendable_memmap_vector_storage;
 use crate::vector_storage::memmap_vector_storage::open_memmap_vector_storage;
 use crate::vector_storage::quantized::quantized_vectors::QuantizedVectors;
+use crate::vector_storage::simple_binary_vector_storage::open_simple_binary_vector_storage;
 use crate::vector_storage::simple_dense_vector_storage::open_simple_vector_storage;
 use crate::vector_storage::simple_sparse_vector_storage::open_simple_sparse_vector_storage;
 use crate::vector_storage::VectorStorage;
@@ -65,6 +67,206 @@ pub fn get_vector_index_path(segment_path: &Path, vector_name: &str) -> PathBuf
     segment_path.join(get_vector_name_with_prefix(VECTOR_INDEX_PATH, vector_name))
 }
 
+/// Open storage and index of the dense vector, configured in the segment `config`
+pub(crate) fn open_vector_data(
+    segment_path: &Path,
+    database: &Arc<RwLock<DB>>,
+    id_tracker: &Arc<AtomicRefCell<IdTrackerSS>>,
+    payload_index: &Arc<AtomicRefCell<StructPayloadIndex>>,
+    config: &SegmentConfig,
+    vector_name: &str,
+) -> OperationResult<VectorData> {
+    let vector_config = &config.vector_data[vector_name];
+    let vector_storage_path = get_vector_storage_path(segment_path, vector_name);
+    let vector_index_path = get_vector_index_path(segment_path, vector_name);
+
+    // Select suitable vector storage type based on configuration
+    let vector_storage = match vector_config.storage_type {
+        // Binary vectors are compact and are always kept in memory
+        _ if vector_config.distance.is_binary() => {
+            let db_column_name = get_vector_name_with_prefix(DB_VECTOR_CF, vector_name);
+            // Column family is missing, if vector is added to an existing segment
+            DatabaseColumnWrapper::new(database.clone(), &db_column_name)
+                .create_column_family_if_not_exists()?;
+            open_simple_binary_vector_storage(
+                database.clone(),
+                &db_column_name,
+                vector_config.input_dim(),
+                vector_config.distance,
+            )?
+        }
+        // In memory
+        VectorStorageType::Memory => {
+            let db_column_name = get_vector_name_with_prefix(DB_VECTOR_CF, vector_name);
+            // Column family is missing, if vector is added to an existing segment
+            DatabaseColumnWrapper::new(database.clone(), &db_column_name)
+                .create_column_family_if_not_exists()?;
+            open_simple_vector_storage(
+                database.clone(),
+                &db_column_name,
+                vector_config.size,
+                vector_config.distance,
+            )?
+        }
+        // Mmap on disk, not appendable
+        VectorStorageType::Mmap => open_memmap_vector_storage(
+            &vector_storage_path,
+            vector_config.size,
+            vector_config.distance,
+        )?,
+        // Chunked mmap on disk, appendable
+        VectorStorageType::ChunkedMmap => open_appendable_memmap_vector_storage(
+            &vector_storage_path,
+            vector_config.size,
+            vector_config.distance,
+        )?,
+    };
+
+    // Warn when number of points between ID tracker and storage differs
+    let point_count = id_tracker.borrow().total_point_count();
+    let vector_count = vector_storage.borrow().total_vector_count();
+    if vector_count != point_count {
+        log::debug!(
+            "Mismatch of point and vector counts ({point_count} != {vector_count}, storage: {})",
+            vector_storage_path.display(),
+        );
+    }
+
+    let quantized_vectors = sp(if config.quantization_config(vector_name).is_some() {
+        let quantized_data_path = vector_storage_path;
+        if QuantizedVectors::config_exists(&quantized_data_path) {
+            let quantized_vectors =
+                QuantizedVectors::load(&vector_storage.borrow(), &quantized_data_path)?;
+            Some(quantized_vectors)
+        } else {
+            None
+        }
+    } else {
+        None
+    });
+
+    let vector_index: Arc<AtomicRefCell<VectorIndexEnum>> = match &vector_config.index {
+        Indexes::Plain {} => sp(VectorIndexEnum::Plain(PlainIndex::new(
+            id_tracker.clone(),
+            vector_storage.clone(),
+            payload_index.clone(),
+        ))),
+        Indexes::Hnsw(vector_hnsw_config) => sp(if vector_hnsw_config.on_disk == Some(true) {
+            VectorIndexEnum::HnswMmap(HNSWIndex::<GraphLinksMmap>::open(
+                &vector_index_path,
+                id_tracker.clone(),
+                vector_storage.clone(),
+                quantized_vectors.clone(),
+                payload_index.clone(),
+                vector_hnsw_config.clone(),
+            )?)
+        } else {
+            VectorIndexEnum::HnswRam(HNSWIndex::<GraphLinksRam>::open(
+                &vector_index_path,
+                id_tracker.clone(),
+                vector_storage.clone(),
+                quantized_vectors.clone(),
+                payload_index.clone(),
+                vector_hnsw_config.clone(),
+            )?)
+        }),
+    };
+
+    Ok(VectorData {
+        vector_storage,
+        vector_index,
+        quantized_vectors,
+    })
+}
+
+/// Open storage and index of the sparse vector, configured in the segment `config`
+pub(crate) fn open_sparse_vector_data(
+    segment_path: &Path,
+    database: &Arc<RwLock<DB>>,
+    id_tracker: &Arc<AtomicRefCell<IdTrackerSS>>,
+    payload_index: &Arc<AtomicRefCell<StructPayloadIndex>>,
+    config: &SegmentConfig,
+    vector_name: &str,
+) -> OperationResult<VectorData> {
+    let sparse_vector_config = &config.sparse_vector_data[vector_name];
+    let vector_storage_path = get_vector_storage_path(segment_path, vector_name);
+    let vector_index_path = get_vector_index_path(segment_path, vector_name);
+
+    let db_column_name = get_vector_name_with_prefix(DB_VECTOR_CF, vector_name);
+    // Column family is missing, if vector is added to an existing segment
+    DatabaseColumnWrapper::new(database.clone(), &db_column_name)
+        .create_column_family_if_not_exists()?;
+    let vector_storage = open_simple_sparse_vector_storage(
+        database.clone(),
+        &db_column_name,
+        sparse_vector_config.datatype,
+    )?;
+
+    // Warn when number of points between ID tracker and storage differs
+    let point_count = id_tracker.borrow().total_point_count();
+    let vector_count = vector_storage.borrow().total_vector_count();
+    if vector_count != point_count {
+        log::debug!(
+            "Mismatch of point and vector counts ({point_count} != {vector_count}, storage: {})",
+            vector_storage_path.display(),
+        );
+    }
+
+    let vector_index = match sparse_vector_config.index.index_type {
+        SparseIndexType::Mmap => sp(VectorIndexEnum::SparseMmap(SparseVectorIndex::open(
+            sparse_vector_config.index,
+            id_tracker.clone(),
+            vector_storage.clone(),
+            payload_index.clone(),
+            &vector_index_path,
+        )?)),
+        SparseIndexType::CompressedMmap => sp(VectorIndexEnum::SparseCompressedMmap(
+            SparseVectorIndex::open(
+                sparse_vector_config.index,
+                id_tracker.clone(),
+                vector_storage.clone(),
+                payload_index.clone(),
+                &vector_index_path,
+            )?,
+        )),
+        SparseIndexType::MutableRam | SparseIndexType::ImmutableRam => {
+            sp(VectorIndexEnum::SparseRam(SparseVectorIndex::open(
+                sparse_vector_config.index,
+                id_tracker.clone(),
+                vector_storage.clone(),
+                payload_index.clone(),
+                &vector_index_path,
+            )?))
+        }
+    };
+
+    Ok(VectorData {
+        vector_storage,
+        vector_index,
+        quantized_vectors: sp(None),
+    })
+}
+
+/// Remove all stored data of the dense or sparse vector
+pub(crate) fn remove_vector_data(
+    segment_path: &Path,
+    database: &Arc<RwLock<DB>>,
+    vector_name: &str,
+) -> OperationResult<()> {
+    let db_column_name = get_vector_name_with_prefix(DB_VECTOR_CF, vector_name);
+    DatabaseColumnWrapper::new(database.clone(), &db_column_name).remove_column_family()?;
+
+    for path in [
+        get_vector_storage_path(segment_path, vector_name),
+        get_vector_index_path(segment_path, vector_name),
+    ] {
+        if path.exists() {
+            std::fs::remove_dir_all(path)?;
+        }
+    }
+    Ok(())
+}
+
 fn create_segment(
     version: Option<SeqNumberType>,
     segment_path: &Path,
@@ -89,7 +291,7 @@ fn create_segment(
         PayloadStorageType::OnDisk => sp(OnDiskPayloadStorage::open(database.clone())?.into()),
     };
 
-    let id_tracker = sp(SimpleIdTracker::open(database.clone())?);
+    let id_tracker: Arc<AtomicRefCell<IdTrackerSS>> = sp(SimpleIdTracker::open(database.clone())?);
 
     let appendable_flag = config
         .vector_data
@@ -112,140 +314,28 @@ fn create_segment(
     )?);
 
     let mut vector_data = HashMap::new();
-    for (vector_name, vector_config) in &config.vector_data {
-        let vector_storage_path = get_vector_storage_path(segment_path, vector_name);
-        let vector_index_path = get_vector_index_path(segment_path, vector_name);
-
-        // Select suitable vector storage type based on configuration
-        let vector_storage = match vector_config.storage_type {
-            // In memory
-            VectorStorageType::Memory => {
-                let db_column_name = get_vector_name_with_prefix(DB_VECTOR_CF, vector_name);
-                open_simple_vector_storage(
-                    database.clone(),
-                    &db_column_name,
-                    vector_config.size,
-                    vector_config.distance,
-                )?
-            }
-            // Mmap on disk, not appendable
-            VectorStorageType::Mmap => open_memmap_vector_storage(
-                &vector_storage_path,
-                vector_config.size,
-                vector_config.distance,
-            )?,
-            // Chunked mmap on disk, appendable
-            VectorStorageType::ChunkedMmap => open_appendable_memmap_vector_storage(
-                &vector_storage_path,
-                vector_config.size,
-                vector_config.distance,
-            )?,
-        };
-
-        // Warn when number of points between ID tracker and storage differs
-        let point_count = id_tracker.borrow().total_point_count();
-        let vector_count = vector_storage.borrow().total_vector_count();
-        if vector_count != point_count {
-            log::debug!(
-                "Mismatch of point and vector counts ({point_count} != {vector_count}, storage: {})",
-                vector_storage_path.display(),
-            );
-        }
-
-        let quantized_vectors = sp(if config.quantization_config(vector_name).is_some() {
-            let quantized_data_path = vector_storage_path;
-            if QuantizedVectors::config_exists(&quantized_data_path) {
-                let quantized_vectors =
-                    QuantizedVectors::load(&vector_storage.borrow(), &quantized_data_path)?;
-                Some(quantized_vectors)
-            } else {
-                None
-            }
-        } else {
-            None
-        });
-
-        let vector_index: Arc<AtomicRefCell<VectorIndexEnum>> = match &vector_config.index {
-            Indexes::Plain {} => sp(VectorIndexEnum::Plain(PlainIndex::new(
-                id_tracker.clone(),
-                vector_storage.clone(),
-                payload_index.clone(),
-            ))),
-            Indexes::Hnsw(vector_hnsw_config) => sp(if vector_hnsw_config.on_disk == Some(true) {
-                VectorIndexEnum::HnswMmap(HNSWIndex::<GraphLinksMmap>::open(
-                    &vector_index_path,
-                    id_tracker.clone(),
-                    vector_storage.clone(),
-                    quantized_vectors.clone(),
-                    payload_index.clone(),
-                    vector_hnsw_config.clone(),
-                )?)
-            } else {
-                VectorIndexEnum::HnswRam(HNSWIndex::<GraphLinksRam>::open(
-                    &vector_index_path,
-                    id_tracker.clone(),
-                    vector_storage.clone(),
-                    quantized_vectors.clone(),
-                    payload_index.clone(),
-                    vector_hnsw_config.clone(),
-                )?)
-            }),
-        };
-
-        vector_data.insert(
-            vector_name.to_owned(),
-            VectorData {
-                vector_storage,
-                vector_index,
-                quantized_vectors,
-            },
-        );
+    for vector_name in config.vector_data.keys() {
+        let data = open_vector_data(
+            segment_path,
+            &database,
+            &id_tracker,
+            &payload_index,
+            config,
+            vector_name,
+        )?;
+        vector_data.insert(vector_name.to_owned(), data);
     }
 
-    for (vector_name, sparse_vector_config) in &config.sparse_vector_data {
-        let vector_storage_path = get_vector_storage_path(segment_path, vector_name);
-        let vector_index_path = get_vector_index_path(segment_path, vector_name);
-
-        let db_column_name = get_vector_name_with_prefix(DB_VECTOR_CF, vector_name);
-        let vector_storage = open_simple_sparse_vector_storage(database.clone(), &db_column_name)?;
-
-        // Warn when number of points between ID tracker and storage differs
-        let point_count = id_tracker.borrow().total_point_count();
-        let vector_count = vector_storage.borrow().total_vector_count();
-        if vector_count != point_count {
-            log::debug!(
-                "Mismatch of point and vector counts ({point_count} != {vector_count}, storage: {})",
-                vector_storage_path.display(),
-            );
-        }
-
-        let vector_index = match sparse_vector_config.index.index_type {
-            SparseIndexType::Mmap => sp(VectorIndexEnum::SparseMmap(SparseVectorIndex::open(
-                sparse_vector_config.index,
-                id_tracker.clone(),
-                vector_storage.clone(),
-                payload_index.clone(),
-                &vector_index_path,
-            )?)),
-            SparseIndexType::MutableRam | SparseIndexType::ImmutableRam => {
-                sp(VectorIndexEnum::SparseRam(SparseVectorIndex::open(
-                    sparse_vector_config.index,
-                    id_tracker.clone(),
-                    vector_storage.clone(),
-                    payload_index.clone(),
-                    &vector_index_path,
-                )?))
-            }
-        };
-
-        vector_data.insert(
-            vector_name.to_owned(),
-            VectorData {
-                vector_storage,
-                vector_index,
-                quantized_vectors: sp(None),
-            },
-        );
+    for vector_name in config.sparse_vector_data.keys() {
+        let data = open_sparse_vector_data(
+            segment_path,
+            &database,
+            &id_tracker,
+            &payload_index,
+            config,
+            vector_name,
+        )?;
+        vector_data.insert(vector_name.to_owned(), data);
     }
 
     let segment_type = if config.is_any_vector_indexed() {
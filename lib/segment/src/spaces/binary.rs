@@ -0,0 +1,216 @@
+use common::types::ScoreType;
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use super::binary_neon::*;
+#[cfg(target_arch = "x86_64")]
+use super::binary_popcnt::*;
+use crate::data_types::vectors::{DenseVector, VectorElementType};
+use crate::types::Distance;
+
+/// Type of a single stored element of binary vectors, each one holds 8 bits
+pub type BinaryElementType = u8;
+
+#[cfg(any(
+    target_arch = "x86_64",
+    all(target_arch = "aarch64", target_feature = "neon")
+))]
+const MIN_DIM_SIZE_POPCNT: usize = 16;
+
+/// Defines how to compare bit-packed binary vectors
+pub trait BinaryMetric {
+    fn distance() -> Distance;
+
+    /// Greater the value - closer the vectors
+    fn similarity(v1: &[BinaryElementType], v2: &[BinaryElementType]) -> ScoreType;
+
+    /// correct metric score for displaying
+    fn postprocess(score: ScoreType) -> ScoreType;
+}
+
+/// Hamming distance between bit vectors.
+///
+/// Score is the negated number of differing bits, so that greater is closer.
+#[derive(Clone)]
+pub struct HammingMetric;
+
+/// Jaccard (Tanimoto) similarity between bit vectors.
+///
+/// Score is the number of bits set in both vectors divided by the number of bits set in any of them.
+#[derive(Clone)]
+pub struct JaccardMetric;
+
+impl BinaryMetric for HammingMetric {
+    fn distance() -> Distance {
+        Distance::Hamming
+    }
+
+    fn similarity(v1: &[BinaryElementType], v2: &[BinaryElementType]) -> ScoreType {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if is_x86_feature_detected!("popcnt") && v1.len() >= MIN_DIM_SIZE_POPCNT {
+                return -(unsafe { hamming_distance_popcnt(v1, v2) } as ScoreType);
+            }
+        }
+
+        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        {
+            if std::arch::is_aarch64_feature_detected!("neon") && v1.len() >= MIN_DIM_SIZE_POPCNT {
+                return -(unsafe { hamming_distance_neon(v1, v2) } as ScoreType);
+            }
+        }
+
+        -(hamming_distance(v1, v2) as ScoreType)
+    }
+
+    fn postprocess(score: ScoreType) -> ScoreType {
+        score.abs()
+    }
+}
+
+impl BinaryMetric for JaccardMetric {
+    fn distance() -> Distance {
+        Distance::Jaccard
+    }
+
+    fn similarity(v1: &[BinaryElementType], v2: &[BinaryElementType]) -> ScoreType {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if is_x86_feature_detected!("popcnt") && v1.len() >= MIN_DIM_SIZE_POPCNT {
+                let (intersection, union) = unsafe { jaccard_counts_popcnt(v1, v2) };
+                return jaccard_similarity(intersection, union);
+            }
+        }
+
+        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        {
+            if std::arch::is_aarch64_feature_detected!("neon") && v1.len() >= MIN_DIM_SIZE_POPCNT {
+                let (intersection, union) = unsafe { jaccard_counts_neon(v1, v2) };
+                return jaccard_similarity(intersection, union);
+            }
+        }
+
+        let (intersection, union) = jaccard_counts(v1, v2);
+        jaccard_similarity(intersection, union)
+    }
+
+    fn postprocess(score: ScoreType) -> ScoreType {
+        score
+    }
+}
+
+#[inline]
+fn jaccard_similarity(intersection: u32, union: u32) -> ScoreType {
+    if union == 0 {
+        // Two empty sets are considered equal
+        return 1.0;
+    }
+    intersection as ScoreType / union as ScoreType
+}
+
+pub fn hamming_distance(v1: &[BinaryElementType], v2: &[BinaryElementType]) -> u32 {
+    v1.iter().zip(v2).map(|(a, b)| (a ^ b).count_ones()).sum()
+}
+
+/// Returns number of bits set in both vectors and number of bits set in any of them
+pub fn jaccard_counts(v1: &[BinaryElementType], v2: &[BinaryElementType]) -> (u32, u32) {
+    v1.iter()
+        .zip(v2)
+        .fold((0, 0), |(intersection, union), (a, b)| {
+            (
+                intersection + (a & b).count_ones(),
+                union + (a | b).count_ones(),
+            )
+        })
+}
+
+/// Check that every element of the dense vector holds a single byte value
+pub fn is_valid_binary(vector: &[VectorElementType]) -> bool {
+    vector.iter().all(|byte| {
+        byte.fract() == 0.0 && (0.0..=BinaryElementType::MAX as VectorElementType).contains(byte)
+    })
+}
+
+/// Convert a dense vector, which holds one byte value per element, into a binary vector
+///
+/// Elements are expected to be validated with [`is_valid_binary`] beforehand.
+pub fn dense_to_binary(vector: &[VectorElementType]) -> Vec<BinaryElementType> {
+    vector
+        .iter()
+        .map(|byte| *byte as BinaryElementType)
+        .collect()
+}
+
+/// Convert a binary vector into a dense vector, which holds one byte value per element
+pub fn binary_to_dense(vector: &[BinaryElementType]) -> DenseVector {
+    vector
+        .iter()
+        .copied()
+        .map(VectorElementType::from)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::Rng;
+
+    use super::*;
+
+    fn random_bytes(rng: &mut impl Rng, len: usize) -> Vec<BinaryElementType> {
+        (0..len).map(|_| rng.gen()).collect()
+    }
+
+    #[test]
+    fn test_dense_binary_conversion() {
+        let mut rng = rand::thread_rng();
+        for len in [1, 3, 16, 129] {
+            let bytes = random_bytes(&mut rng, len);
+            let dense = binary_to_dense(&bytes);
+            assert!(is_valid_binary(&dense));
+            assert_eq!(dense_to_binary(&dense), bytes);
+        }
+    }
+
+    #[test]
+    fn test_invalid_binary() {
+        assert!(!is_valid_binary(&[0.5]));
+        assert!(!is_valid_binary(&[-1.0]));
+        assert!(!is_valid_binary(&[256.0]));
+        assert!(!is_valid_binary(&[VectorElementType::NAN]));
+    }
+
+    #[test]
+    fn test_binary_metrics() {
+        let v1 = [0b1111_0000, 0, 0, 1];
+        let v2 = [0b1010_0000, 0, 0, 3];
+
+        assert_eq!(HammingMetric::similarity(&v1, &v2), -3.0);
+        assert_eq!(HammingMetric::postprocess(-3.0), 3.0);
+        assert_eq!(HammingMetric::similarity(&v1, &v1), 0.0);
+
+        assert_eq!(JaccardMetric::similarity(&v1, &v2), 3.0 / 6.0);
+        assert_eq!(JaccardMetric::similarity(&v1, &v1), 1.0);
+
+        let empty = [0; 4];
+        assert_eq!(JaccardMetric::similarity(&empty, &empty), 1.0);
+        assert_eq!(JaccardMetric::similarity(&empty, &v1), 0.0);
+    }
+
+    #[test]
+    fn test_binary_metrics_simd_consistency() {
+        let mut rng = rand::thread_rng();
+        for len in [16, 17, 100, 257] {
+            let v1 = random_bytes(&mut rng, len);
+            let v2 = random_bytes(&mut rng, len);
+
+            let (intersection, union) = jaccard_counts(&v1, &v2);
+            assert_eq!(
+                HammingMetric::similarity(&v1, &v2),
+                -(hamming_distance(&v1, &v2) as ScoreType)
+            );
+            assert_eq!(
+                JaccardMetric::similarity(&v1, &v2),
+                jaccard_similarity(intersection, union)
+            );
+        }
+    }
+}
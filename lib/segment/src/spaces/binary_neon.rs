@@ -0,0 +1,59 @@
+use std::arch::aarch64::*;
+
+use crate::spaces::binary::BinaryElementType;
+
+/// Number of stored elements in a single 128-bit register
+const ELEMENTS_PER_REGISTER: usize = 16;
+
+#[inline]
+#[target_feature(enable = "neon")]
+unsafe fn load_bytes(ptr: *const BinaryElementType, i: usize) -> uint8x16_t {
+    vld1q_u8(ptr.add(i * ELEMENTS_PER_REGISTER))
+}
+
+#[target_feature(enable = "neon")]
+pub(crate) unsafe fn hamming_distance_neon(
+    v1: &[BinaryElementType],
+    v2: &[BinaryElementType],
+) -> u32 {
+    let n = v1.len();
+    let m = n - (n % ELEMENTS_PER_REGISTER);
+    let ptr1 = v1.as_ptr();
+    let ptr2 = v2.as_ptr();
+
+    let mut result: u32 = 0;
+    for i in 0..m / ELEMENTS_PER_REGISTER {
+        let diff = veorq_u8(load_bytes(ptr1, i), load_bytes(ptr2, i));
+        // At most 8 bits per lane, so the sum of 16 lanes fits into u8
+        result += vaddvq_u8(vcntq_u8(diff)) as u32;
+    }
+    for i in m..n {
+        result += (v1[i] ^ v2[i]).count_ones();
+    }
+    result
+}
+
+#[target_feature(enable = "neon")]
+pub(crate) unsafe fn jaccard_counts_neon(
+    v1: &[BinaryElementType],
+    v2: &[BinaryElementType],
+) -> (u32, u32) {
+    let n = v1.len();
+    let m = n - (n % ELEMENTS_PER_REGISTER);
+    let ptr1 = v1.as_ptr();
+    let ptr2 = v2.as_ptr();
+
+    let mut intersection: u32 = 0;
+    let mut union: u32 = 0;
+    for i in 0..m / ELEMENTS_PER_REGISTER {
+        let a = load_bytes(ptr1, i);
+        let b = load_bytes(ptr2, i);
+        intersection += vaddvq_u8(vcntq_u8(vandq_u8(a, b))) as u32;
+        union += vaddvq_u8(vcntq_u8(vorrq_u8(a, b))) as u32;
+    }
+    for i in m..n {
+        intersection += (v1[i] & v2[i]).count_ones();
+        union += (v1[i] | v2[i]).count_ones();
+    }
+    (intersection, union)
+}
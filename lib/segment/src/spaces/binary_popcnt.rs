@@ -0,0 +1,83 @@
+use std::arch::x86_64::*;
+
+use crate::spaces::binary::BinaryElementType;
+
+/// Number of stored elements in a single 64-bit popcount chunk
+const ELEMENTS_PER_CHUNK: usize = 8;
+
+#[inline]
+#[target_feature(enable = "popcnt")]
+unsafe fn read_chunk(ptr: *const BinaryElementType, i: usize) -> i64 {
+    (ptr.add(i * ELEMENTS_PER_CHUNK) as *const i64).read_unaligned()
+}
+
+#[target_feature(enable = "popcnt")]
+pub(crate) unsafe fn hamming_distance_popcnt(
+    v1: &[BinaryElementType],
+    v2: &[BinaryElementType],
+) -> u32 {
+    let n = v1.len();
+    let m = n - (n % ELEMENTS_PER_CHUNK);
+    let ptr1 = v1.as_ptr();
+    let ptr2 = v2.as_ptr();
+
+    let mut result: u32 = 0;
+    for i in 0..m / ELEMENTS_PER_CHUNK {
+        result += _popcnt64(read_chunk(ptr1, i) ^ read_chunk(ptr2, i)) as u32;
+    }
+    for i in m..n {
+        result += (v1[i] ^ v2[i]).count_ones();
+    }
+    result
+}
+
+#[target_feature(enable = "popcnt")]
+pub(crate) unsafe fn jaccard_counts_popcnt(
+    v1: &[BinaryElementType],
+    v2: &[BinaryElementType],
+) -> (u32, u32) {
+    let n = v1.len();
+    let m = n - (n % ELEMENTS_PER_CHUNK);
+    let ptr1 = v1.as_ptr();
+    let ptr2 = v2.as_ptr();
+
+    let mut intersection: u32 = 0;
+    let mut union: u32 = 0;
+    for i in 0..m / ELEMENTS_PER_CHUNK {
+        let a = read_chunk(ptr1, i);
+        let b = read_chunk(ptr2, i);
+        intersection += _popcnt64(a & b) as u32;
+        union += _popcnt64(a | b) as u32;
+    }
+    for i in m..n {
+        intersection += (v1[i] & v2[i]).count_ones();
+        union += (v1[i] | v2[i]).count_ones();
+    }
+    (intersection, union)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spaces::binary::{hamming_distance, jaccard_counts};
+
+    #[test]
+    fn test_popcnt() {
+        if !is_x86_feature_detected!("popcnt") {
+            println!("popcnt test skipped");
+            return;
+        }
+
+        let v1: Vec<BinaryElementType> = (0..37).map(|i| (i * 7 % 256) as u8).collect();
+        let v2: Vec<BinaryElementType> = (0..37).map(|i| (i * 13 % 256) as u8).collect();
+
+        assert_eq!(
+            unsafe { hamming_distance_popcnt(&v1, &v2) },
+            hamming_distance(&v1, &v2)
+        );
+        assert_eq!(
+            unsafe { jaccard_counts_popcnt(&v1, &v2) },
+            jaccard_counts(&v1, &v2)
+        );
+    }
+}
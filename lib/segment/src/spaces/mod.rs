@@ -1,3 +1,4 @@
+pub mod binary;
 pub mod metric;
 pub mod simple;
 pub mod tools;
@@ -10,3 +11,9 @@ pub mod simple_avx;
 
 #[cfg(target_arch = "aarch64")]
 pub mod simple_neon;
+
+#[cfg(target_arch = "x86_64")]
+pub mod binary_popcnt;
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+pub mod binary_neon;
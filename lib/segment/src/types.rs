@@ -9,25 +9,31 @@ use std::str::FromStr;
 
 use common::types::ScoreType;
 use geo::prelude::HaversineDistance;
-use geo::{Contains, Coord, LineString, Point, Polygon};
+use geo::{Contains, Coord, Geometry, LineString, MultiPolygon, Point, Polygon, Rect, Relate};
 use itertools::Itertools;
 use ordered_float::OrderedFloat;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
 use smol_str::SmolStr;
+use sparse::common::datatype::SparseVectorDatatype;
 use uuid::Uuid;
 use validator::{Validate, ValidationError, ValidationErrors};
 
+use crate::common::keyword_matcher::KeywordMatcher;
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::common::utils;
 use crate::common::utils::{
     check_exclude_pattern, check_include_pattern, filter_json_values, get_value_from_json_map,
     get_value_from_json_map_opt, MultiValue,
 };
+use crate::data_types::keyword_index::KeywordIndexParams;
 use crate::data_types::text_index::TextIndexParams;
 use crate::data_types::vectors::{DenseVector, VectorElementType, VectorStruct};
 use crate::index::sparse_index::sparse_index_config::{SparseIndexConfig, SparseIndexType};
+use crate::spaces::binary::{
+    dense_to_binary, BinaryElementType, BinaryMetric, HammingMetric, JaccardMetric,
+};
 use crate::spaces::metric::Metric;
 use crate::spaces::simple::{CosineMetric, DotProductMetric, EuclidMetric, ManhattanMetric};
 use crate::vector_storage::simple_sparse_vector_storage::SPARSE_VECTOR_DISTANCE;
@@ -126,15 +132,29 @@ pub enum Distance {
     Dot,
     // <https://simple.wikipedia.org/wiki/Manhattan_distance>
     Manhattan,
+    // <https://en.wikipedia.org/wiki/Hamming_distance>
+    Hamming,
+    // <https://en.wikipedia.org/wiki/Jaccard_index>
+    Jaccard,
 }
 
 impl Distance {
+    /// Whether this distance compares bit-packed binary vectors, stored as bytes
+    pub fn is_binary(&self) -> bool {
+        match self {
+            Distance::Cosine | Distance::Euclid | Distance::Dot | Distance::Manhattan => false,
+            Distance::Hamming | Distance::Jaccard => true,
+        }
+    }
+
     pub fn preprocess_vector(&self, vector: DenseVector) -> DenseVector {
         match self {
             Distance::Cosine => CosineMetric::preprocess(vector),
             Distance::Euclid => EuclidMetric::preprocess(vector),
             Distance::Dot => DotProductMetric::preprocess(vector),
             Distance::Manhattan => ManhattanMetric::preprocess(vector),
+            // Binary vectors are converted into bytes by the binary vector storage
+            Distance::Hamming | Distance::Jaccard => vector,
         }
     }
 
@@ -144,13 +164,15 @@ impl Distance {
             Distance::Euclid => EuclidMetric::postprocess(score),
             Distance::Dot => DotProductMetric::postprocess(score),
             Distance::Manhattan => ManhattanMetric::postprocess(score),
+            Distance::Hamming => HammingMetric::postprocess(score),
+            Distance::Jaccard => JaccardMetric::postprocess(score),
         }
     }
 
     pub fn distance_order(&self) -> Order {
         match self {
-            Distance::Cosine | Distance::Dot => Order::LargeBetter,
-            Distance::Euclid | Distance::Manhattan => Order::SmallBetter,
+            Distance::Cosine | Distance::Dot | Distance::Jaccard => Order::LargeBetter,
+            Distance::Euclid | Distance::Manhattan | Distance::Hamming => Order::SmallBetter,
         }
     }
 
@@ -171,6 +193,12 @@ impl Distance {
             Distance::Euclid => EuclidMetric::similarity(v1, v2),
             Distance::Dot => DotProductMetric::similarity(v1, v2),
             Distance::Manhattan => ManhattanMetric::similarity(v1, v2),
+            Distance::Hamming => {
+                HammingMetric::similarity(&dense_to_binary(v1), &dense_to_binary(v2))
+            }
+            Distance::Jaccard => {
+                JaccardMetric::similarity(&dense_to_binary(v1), &dense_to_binary(v2))
+            }
         }
     }
 }
@@ -196,6 +224,9 @@ pub struct ScoredPoint {
     /// Shard Key
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shard_key: Option<ShardKey>,
+    /// Distance in meters from the requested origin to the closest geo point of the point
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub geo_distance: Option<f64>,
 }
 
 impl Eq for ScoredPoint {}
@@ -250,6 +281,11 @@ impl PayloadIndexInfo {
                 points: points_count,
             },
             PayloadFieldSchema::FieldParams(schema_params) => match schema_params {
+                PayloadSchemaParams::Keyword(_) => PayloadIndexInfo {
+                    data_type: PayloadSchemaType::Keyword,
+                    params: Some(schema_params),
+                    points: points_count,
+                },
                 PayloadSchemaParams::Text(_) => PayloadIndexInfo {
                     data_type: PayloadSchemaType::Text,
                     params: Some(schema_params),
@@ -635,6 +671,8 @@ impl SegmentConfig {
     pub fn quantization_config(&self, vector_name: &str) -> Option<&QuantizationConfig> {
         self.vector_data
             .get(vector_name)
+            // Binary vectors are already compact, quantization is not applicable to them
+            .filter(|v| !v.distance.is_binary())
             .and_then(|v| v.quantization_config.as_ref())
     }
 
@@ -746,6 +784,26 @@ impl VectorDataConfig {
         };
         is_index_appendable && is_storage_appendable
     }
+
+    /// Number of elements in vectors
+    ///
+    /// For binary distances `size` is the number of bits, and every element holds 8 of them.
+    pub fn input_dim(&self) -> usize {
+        if self.distance.is_binary() {
+            self.size / BinaryElementType::BITS as usize
+        } else {
+            self.size
+        }
+    }
+
+    /// Size of a single stored vector in bytes
+    pub fn vector_size_bytes(&self) -> usize {
+        if self.distance.is_binary() {
+            self.input_dim() * size_of::<BinaryElementType>()
+        } else {
+            self.size * size_of::<VectorElementType>()
+        }
+    }
 }
 
 /// Config of single sparse vector data storage
@@ -754,6 +812,9 @@ impl VectorDataConfig {
 pub struct SparseVectorDataConfig {
     /// Sparse inverted index config
     pub index: SparseIndexConfig,
+    /// Datatype of the stored weights
+    #[serde(default)]
+    pub datatype: SparseVectorDatatype,
 }
 
 impl SparseVectorDataConfig {
@@ -770,7 +831,10 @@ impl SparseVectorDataConfig {
     }
 
     pub fn is_index_on_disk(&self) -> bool {
-        self.index.index_type == SparseIndexType::Mmap
+        matches!(
+            self.index.index_type,
+            SparseIndexType::Mmap | SparseIndexType::CompressedMmap
+        )
     }
 }
 
@@ -851,6 +915,125 @@ impl TryFrom<GeoPointShadow> for GeoPoint {
     }
 }
 
+/// Position of a GeoJSON geometry: `[lon, lat]`
+pub type GeoJsonPosition = [f64; 2];
+
+/// Geo shape payload schema
+///
+/// Described as a GeoJSON geometry object, e.g.
+/// `{"type": "Polygon", "coordinates": [[[lon, lat], ...]]}`
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq)]
+#[serde(tag = "type", content = "coordinates", try_from = "GeoShapeShadow")]
+pub enum GeoShape {
+    /// Line of at least 2 positions
+    LineString(Vec<GeoJsonPosition>),
+    /// Exterior ring followed by optional interior rings.
+    /// Each ring must consist of a minimum of 4 positions, and the first and last
+    /// positions must be the same.
+    Polygon(Vec<Vec<GeoJsonPosition>>),
+    /// Set of polygons
+    MultiPolygon(Vec<Vec<Vec<GeoJsonPosition>>>),
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "type", content = "coordinates")]
+enum GeoShapeShadow {
+    LineString(Vec<GeoJsonPosition>),
+    Polygon(Vec<Vec<GeoJsonPosition>>),
+    MultiPolygon(Vec<Vec<Vec<GeoJsonPosition>>>),
+}
+
+impl GeoShape {
+    fn validate_positions(positions: &[GeoJsonPosition]) -> OperationResult<()> {
+        for [lon, lat] in positions {
+            GeoPoint::validate(*lon, *lat).map_err(|err| OperationError::ValidationError {
+                description: err.to_string(),
+            })?;
+        }
+        Ok(())
+    }
+
+    fn validate_polygon(rings: &[Vec<GeoJsonPosition>]) -> OperationResult<()> {
+        if rings.is_empty() {
+            return Err(OperationError::ValidationError {
+                description: "polygon invalid, at least the exterior ring is required".to_string(),
+            });
+        }
+
+        for ring in rings {
+            Self::validate_positions(ring)?;
+            GeoPolygon::validate_line_string(&GeoLineString {
+                points: ring
+                    .iter()
+                    .map(|&[lon, lat]| GeoPoint { lon, lat })
+                    .collect(),
+            })?;
+        }
+        Ok(())
+    }
+
+    pub fn validate(&self) -> OperationResult<()> {
+        match self {
+            GeoShape::LineString(positions) => {
+                if positions.len() < 2 {
+                    return Err(OperationError::ValidationError {
+                        description: format!(
+                            "linestring invalid, the size must be at least 2, got {}",
+                            positions.len()
+                        ),
+                    });
+                }
+                Self::validate_positions(positions)
+            }
+            GeoShape::Polygon(rings) => Self::validate_polygon(rings),
+            GeoShape::MultiPolygon(polygons) => polygons
+                .iter()
+                .try_for_each(|rings| Self::validate_polygon(rings)),
+        }
+    }
+
+    /// Convert shape into Geo crate geometry for checking spatial relations
+    pub fn to_geometry(&self) -> Geometry {
+        fn line(positions: &[GeoJsonPosition]) -> LineString {
+            positions
+                .iter()
+                .map(|&[lon, lat]| Coord { x: lon, y: lat })
+                .collect()
+        }
+
+        fn polygon(rings: &[Vec<GeoJsonPosition>]) -> Polygon {
+            let exterior = rings
+                .first()
+                .map(|ring| line(ring))
+                .unwrap_or_else(|| LineString::new(vec![]));
+            let interiors = rings.iter().skip(1).map(|ring| line(ring)).collect();
+            Polygon::new(exterior, interiors)
+        }
+
+        match self {
+            GeoShape::LineString(positions) => Geometry::LineString(line(positions)),
+            GeoShape::Polygon(rings) => Geometry::Polygon(polygon(rings)),
+            GeoShape::MultiPolygon(polygons) => Geometry::MultiPolygon(MultiPolygon::new(
+                polygons.iter().map(|rings| polygon(rings)).collect(),
+            )),
+        }
+    }
+}
+
+impl TryFrom<GeoShapeShadow> for GeoShape {
+    type Error = OperationError;
+
+    fn try_from(value: GeoShapeShadow) -> OperationResult<Self> {
+        let shape = match value {
+            GeoShapeShadow::LineString(positions) => GeoShape::LineString(positions),
+            GeoShapeShadow::Polygon(rings) => GeoShape::Polygon(rings),
+            GeoShapeShadow::MultiPolygon(polygons) => GeoShape::MultiPolygon(polygons),
+        };
+        shape.validate()?;
+        Ok(shape)
+    }
+}
+
 pub trait PayloadContainer {
     /// Return value from payload by path if it is present in the payload.
     /// If value is not present in the payload, returns `None`.
@@ -1065,6 +1248,7 @@ pub enum PayloadSchemaType {
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Hash, Eq)]
 #[serde(untagged, rename_all = "snake_case")]
 pub enum PayloadSchemaParams {
+    Keyword(KeywordIndexParams),
     Text(TextIndexParams),
 }
 
@@ -1086,6 +1270,9 @@ impl TryFrom<PayloadIndexInfo> for PayloadFieldSchema {
 
     fn try_from(index_info: PayloadIndexInfo) -> Result<Self, Self::Error> {
         match (index_info.data_type, index_info.params) {
+            (PayloadSchemaType::Keyword, Some(PayloadSchemaParams::Keyword(params))) => Ok(
+                PayloadFieldSchema::FieldParams(PayloadSchemaParams::Keyword(params)),
+            ),
             (PayloadSchemaType::Text, Some(PayloadSchemaParams::Text(params))) => Ok(
                 PayloadFieldSchema::FieldParams(PayloadSchemaParams::Text(params)),
             ),
@@ -1119,6 +1306,11 @@ pub fn value_type(value: &Value) -> Option<PayloadSchemaType> {
             if let (Some(_), Some(_)) = (lon_op, lat_op) {
                 return Some(PayloadSchemaType::Geo);
             }
+            if obj.contains_key("coordinates")
+                && serde_json::from_value::<GeoShape>(value.clone()).is_ok()
+            {
+                return Some(PayloadSchemaType::Geo);
+            }
             None
         }
     }
@@ -1143,7 +1335,7 @@ where
     }
 }
 
-#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq, Hash)]
 #[serde(untagged)]
 pub enum ValueVariants {
     Keyword(String),
@@ -1170,11 +1362,25 @@ pub struct MatchValue {
 #[serde(rename_all = "snake_case")]
 pub struct MatchText {
     pub text: String,
+    /// If set, query tokens also match indexed tokens within this Levenshtein edit distance. Allowed values: 1, 2
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(range(min = 1, max = 2))]
+    pub fuzzy: Option<u8>,
+}
+
+impl MatchText {
+    /// Largest allowed edit distance of the fuzzy match
+    pub const MAX_FUZZY: u8 = 2;
+
+    /// Edit distance tolerated for each query token, 0 for exact match
+    pub fn max_distance(&self) -> u8 {
+        self.fuzzy.unwrap_or(0)
+    }
 }
 
 impl From<String> for MatchText {
     fn from(text: String) -> Self {
-        MatchText { text }
+        MatchText { text, fuzzy: None }
     }
 }
 
@@ -1192,6 +1398,27 @@ pub struct MatchExcept {
     pub except: AnyVariants,
 }
 
+/// Match keywords starting with the given prefix
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub struct MatchPrefix {
+    pub prefix: String,
+}
+
+/// Match keywords against the wildcard pattern, where `*` matches any sequence of characters and `?` matches any single character
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub struct MatchWildcard {
+    pub wildcard: String,
+}
+
+/// Match keywords against the regular expression, the whole keyword must match
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub struct MatchRegex {
+    pub regex: String,
+}
+
 /// Match filter request
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq)]
 #[serde(untagged, rename_all = "snake_case")]
@@ -1200,6 +1427,9 @@ pub enum MatchInterface {
     Text(MatchText),
     Any(MatchAny),
     Except(MatchExcept),
+    Prefix(MatchPrefix),
+    Wildcard(MatchWildcard),
+    Regex(MatchRegex),
 }
 
 /// Match filter request
@@ -1210,6 +1440,9 @@ pub enum Match {
     Text(MatchText),
     Any(MatchAny),
     Except(MatchExcept),
+    Prefix(MatchPrefix),
+    Wildcard(MatchWildcard),
+    Regex(MatchRegex),
 }
 
 impl Match {
@@ -1219,7 +1452,10 @@ impl Match {
 
     #[cfg(test)]
     fn new_text(text: &str) -> Self {
-        Self::Text(MatchText { text: text.into() })
+        Self::Text(MatchText {
+            text: text.into(),
+            fuzzy: None,
+        })
     }
 
     pub fn new_any(any: AnyVariants) -> Self {
@@ -1241,11 +1477,21 @@ impl From<MatchInterface> for Match {
     fn from(value: MatchInterface) -> Self {
         match value {
             MatchInterface::Value(value) => Self::Value(MatchValue { value: value.value }),
-            MatchInterface::Text(text) => Self::Text(MatchText { text: text.text }),
+            MatchInterface::Text(text) => Self::Text(MatchText {
+                text: text.text,
+                fuzzy: text.fuzzy,
+            }),
             MatchInterface::Any(any) => Self::Any(MatchAny { any: any.any }),
             MatchInterface::Except(except) => Self::Except(MatchExcept {
                 except: except.except,
             }),
+            MatchInterface::Prefix(prefix) => Self::Prefix(MatchPrefix {
+                prefix: prefix.prefix,
+            }),
+            MatchInterface::Wildcard(wildcard) => Self::Wildcard(MatchWildcard {
+                wildcard: wildcard.wildcard,
+            }),
+            MatchInterface::Regex(regex) => Self::Regex(MatchRegex { regex: regex.regex }),
         }
     }
 }
@@ -1526,6 +1772,145 @@ impl TryFrom<GeoPolygonShadow> for GeoPolygon {
     }
 }
 
+/// Spatial relation between the stored geo value and the query shape
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum GeoShapeRelation {
+    /// Stored value and the query shape have at least one common point
+    Intersects,
+    /// Stored value lies entirely inside the query shape
+    Within,
+    /// Stored value entirely covers the query shape
+    Contains,
+}
+
+impl GeoShapeRelation {
+    pub fn check(&self, value: &Geometry, query: &Geometry) -> bool {
+        let matrix = value.relate(query);
+        match self {
+            GeoShapeRelation::Intersects => matrix.is_intersects(),
+            GeoShapeRelation::Within => matrix.is_within(),
+            GeoShapeRelation::Contains => matrix.is_contains(),
+        }
+    }
+}
+
+/// Query shape of the geo shape filter
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum GeoShapeQuery {
+    Point(GeoPoint),
+    BoundingBox(GeoBoundingBox),
+    Polygon(GeoPolygon),
+}
+
+impl GeoShapeQuery {
+    pub fn to_geometry(&self) -> Geometry {
+        match self {
+            GeoShapeQuery::Point(point) => Geometry::Point(Point::new(point.lon, point.lat)),
+            GeoShapeQuery::BoundingBox(bounding_box) => Geometry::Rect(Rect::new(
+                Coord {
+                    x: bounding_box.top_left.lon,
+                    y: bounding_box.top_left.lat,
+                },
+                Coord {
+                    x: bounding_box.bottom_right.lon,
+                    y: bounding_box.bottom_right.lat,
+                },
+            )),
+            GeoShapeQuery::Polygon(polygon) => Geometry::Polygon(polygon.convert().polygon),
+        }
+    }
+}
+
+/// Geo filter request
+///
+/// Matches geo points and geo shapes, which are in the given `relation` with the query `shape`
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct GeoShapeCondition {
+    /// Spatial relation between the stored value and the query shape
+    pub relation: GeoShapeRelation,
+    /// Shape to compare stored values with
+    pub shape: GeoShapeQuery,
+}
+
+impl GeoShapeCondition {
+    pub fn check_point(&self, point: &GeoPoint) -> bool {
+        let value = Geometry::Point(Point::new(point.lon, point.lat));
+        self.relation.check(&value, &self.shape.to_geometry())
+    }
+
+    pub fn check_shape(&self, shape: &GeoShape) -> bool {
+        self.relation
+            .check(&shape.to_geometry(), &self.shape.to_geometry())
+    }
+}
+
+/// Geo distance from the `origin` to the geo points stored under `key`
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct GeoDistance {
+    /// Payload key with geo points
+    pub key: PayloadKeyType,
+    /// Point to measure distance from
+    pub origin: GeoPoint,
+}
+
+impl GeoDistance {
+    /// Haversine distance in meters between the origin and the given point
+    pub fn distance_to(&self, point: &GeoPoint) -> f64 {
+        let origin = Point::new(self.origin.lon, self.origin.lat);
+        origin.haversine_distance(&Point::new(point.lon, point.lat))
+    }
+
+    /// Distance to the closest geo point stored in the payload under `key`
+    pub fn payload_distance(&self, payload: &impl PayloadContainer) -> Option<f64> {
+        payload
+            .get_value(&self.key)
+            .into_iter()
+            .flat_map(|value| match value {
+                Value::Array(values) => values.iter().collect(),
+                _ => vec![value],
+            })
+            .filter_map(|value| match value {
+                Value::Object(obj) => {
+                    let lon = obj.get("lon").and_then(|x| x.as_f64())?;
+                    let lat = obj.get("lat").and_then(|x| x.as_f64())?;
+                    Some(self.distance_to(&GeoPoint { lon, lat }))
+                }
+                _ => None,
+            })
+            .min_by_key(|distance| OrderedFloat(*distance))
+    }
+}
+
+/// Order of the points returned by scroll
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct OrderBy {
+    /// Order points by ascending distance to the origin, points without geo values are skipped
+    pub geo_distance: GeoDistance,
+    /// Skip points closer than this distance in meters.
+    /// Scroll with `offset` continues from the distance of the offset point
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_from: Option<f64>,
+}
+
+impl OrderBy {
+    /// Whether the point at the given distance is at or after the `start_from` and `offset` cursor
+    pub fn is_after_cursor(
+        &self,
+        distance: f64,
+        point_id: PointIdType,
+        offset: Option<PointIdType>,
+    ) -> bool {
+        let start_from = self.start_from.unwrap_or(0.0);
+        distance > start_from
+            || distance == start_from && offset.map_or(true, |offset| point_id >= offset)
+    }
+}
+
 /// All possible payload filtering conditions
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq)]
 #[validate(schema(function = "validate_field_condition"))]
@@ -1543,6 +1928,8 @@ pub struct FieldCondition {
     pub geo_radius: Option<GeoRadius>,
     /// Check if geo point is within a given polygon
     pub geo_polygon: Option<GeoPolygon>,
+    /// Check spatial relation of geo points and geo shapes with a given shape
+    pub geo_shape: Option<GeoShapeCondition>,
     /// Check number of values of the field
     pub values_count: Option<ValuesCount>,
 }
@@ -1556,6 +1943,7 @@ impl FieldCondition {
             geo_bounding_box: None,
             geo_radius: None,
             geo_polygon: None,
+            geo_shape: None,
             values_count: None,
         }
     }
@@ -1568,6 +1956,7 @@ impl FieldCondition {
             geo_bounding_box: None,
             geo_radius: None,
             geo_polygon: None,
+            geo_shape: None,
             values_count: None,
         }
     }
@@ -1583,6 +1972,7 @@ impl FieldCondition {
             geo_bounding_box: Some(geo_bounding_box),
             geo_radius: None,
             geo_polygon: None,
+            geo_shape: None,
             values_count: None,
         }
     }
@@ -1595,6 +1985,7 @@ impl FieldCondition {
             geo_bounding_box: None,
             geo_radius: Some(geo_radius),
             geo_polygon: None,
+            geo_shape: None,
             values_count: None,
         }
     }
@@ -1607,6 +1998,20 @@ impl FieldCondition {
             geo_bounding_box: None,
             geo_radius: None,
             geo_polygon: Some(geo_polygon),
+            geo_shape: None,
+            values_count: None,
+        }
+    }
+
+    pub fn new_geo_shape(key: impl Into<PayloadKeyType>, geo_shape: GeoShapeCondition) -> Self {
+        Self {
+            key: key.into(),
+            r#match: None,
+            range: None,
+            geo_bounding_box: None,
+            geo_radius: None,
+            geo_polygon: None,
+            geo_shape: Some(geo_shape),
             values_count: None,
         }
     }
@@ -1619,6 +2024,7 @@ impl FieldCondition {
             geo_bounding_box: None,
             geo_radius: None,
             geo_polygon: None,
+            geo_shape: None,
             values_count: Some(values_count),
         }
     }
@@ -1629,18 +2035,37 @@ impl FieldCondition {
             && self.geo_bounding_box.is_none()
             && self.geo_radius.is_none()
             && self.geo_polygon.is_none()
+            && self.geo_shape.is_none()
             && self.values_count.is_none()
     }
 }
 
 pub fn validate_field_condition(field_condition: &FieldCondition) -> Result<(), ValidationError> {
     if field_condition.all_fields_none() {
-        Err(ValidationError::new(
+        return Err(ValidationError::new(
             "At least one field condition must be specified",
-        ))
-    } else {
-        Ok(())
+        ));
+    }
+    if let Some(KeywordMatcher::Invalid) = field_condition
+        .r#match
+        .as_ref()
+        .and_then(KeywordMatcher::new)
+    {
+        return Err(ValidationError::new(
+            "Invalid keyword pattern: regular expression can't be compiled",
+        ));
     }
+    if let Some(Match::Text(MatchText {
+        fuzzy: Some(fuzzy), ..
+    })) = &field_condition.r#match
+    {
+        if !(1..=MatchText::MAX_FUZZY).contains(fuzzy) {
+            return Err(ValidationError::new(
+                "Fuzzy text match edit distance must be 1 or 2",
+            ));
+        }
+    }
+    Ok(())
 }
 
 /// Payload field
@@ -2264,6 +2689,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_fuzzy_text_match() {
+        let query = r#"
+        {
+            "key": "hello",
+            "match": { "text": "wrold", "fuzzy": 1 }
+        }
+        "#;
+        let condition: FieldCondition = serde_json::from_str(query).unwrap();
+        assert!(condition.validate().is_ok());
+        assert_eq!(
+            condition.r#match.unwrap(),
+            Match::Text(MatchText {
+                text: "wrold".to_owned(),
+                fuzzy: Some(1),
+            })
+        );
+
+        let query = r#"
+        {
+            "key": "hello",
+            "match": { "text": "wrold", "fuzzy": 3 }
+        }
+        "#;
+        let condition: FieldCondition = serde_json::from_str(query).unwrap();
+        assert!(condition.validate().is_err());
+    }
+
     #[test]
     fn test_parse_match_any() {
         let query = r#"
@@ -6,6 +6,7 @@ use common::types::{PointOffsetType, ScoreType, ScoredPointOffset};
 
 use super::query::context_query::ContextQuery;
 use super::query::discovery_query::DiscoveryQuery;
+use super::query::feedback_query::FeedbackQuery;
 use super::query::reco_query::RecoQuery;
 use super::query::TransformInto;
 use super::query_scorer::custom_query_scorer::CustomQueryScorer;
@@ -246,6 +247,10 @@ impl<'a> AsyncRawScorerBuilder<'a> {
             Distance::Euclid => self._build_with_metric::<EuclidMetric>(),
             Distance::Dot => self._build_with_metric::<DotProductMetric>(),
             Distance::Manhattan => self._build_with_metric::<ManhattanMetric>(),
+            Distance::Hamming | Distance::Jaccard => Err(OperationError::service_error(format!(
+                "{:?} distance requires binary vector storage",
+                self.distance,
+            ))),
         }
     }
 
@@ -323,6 +328,18 @@ impl<'a> AsyncRawScorerBuilder<'a> {
                     is_stopped.unwrap_or(&DEFAULT_STOPPED),
                 )))
             }
+            QueryVector::Feedback(feedback_query) => {
+                let feedback_query: FeedbackQuery<DenseVector> = feedback_query.transform_into()?;
+                let query_scorer = CustomQueryScorer::<TMetric, _, _>::new(feedback_query, storage);
+                Ok(Box::new(AsyncRawScorerImpl::new(
+                    points_count,
+                    query_scorer,
+                    storage.get_mmap_vectors(),
+                    point_deleted,
+                    vec_deleted,
+                    is_stopped.unwrap_or(&DEFAULT_STOPPED),
+                )))
+            }
         }
     }
 }
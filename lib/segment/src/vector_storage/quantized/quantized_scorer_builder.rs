@@ -11,6 +11,7 @@ use crate::data_types::vectors::{DenseVector, QueryVector};
 use crate::types::Distance;
 use crate::vector_storage::query::context_query::ContextQuery;
 use crate::vector_storage::query::discovery_query::DiscoveryQuery;
+use crate::vector_storage::query::feedback_query::FeedbackQuery;
 use crate::vector_storage::query::reco_query::RecoQuery;
 use crate::vector_storage::query::TransformInto;
 use crate::vector_storage::{raw_scorer_from_query_scorer, RawScorer};
@@ -93,6 +94,12 @@ impl<'a> QuantizedScorerBuilder<'a> {
                     QuantizedCustomQueryScorer::new(context_query, quantized_storage, *distance);
                 raw_scorer_from_query_scorer(query_scorer, point_deleted, vec_deleted, is_stopped)
             }
+            QueryVector::Feedback(feedback_query) => {
+                let feedback_query: FeedbackQuery<DenseVector> = feedback_query.transform_into()?;
+                let query_scorer =
+                    QuantizedCustomQueryScorer::new(feedback_query, quantized_storage, *distance);
+                raw_scorer_from_query_scorer(query_scorer, point_deleted, vec_deleted, is_stopped)
+            }
         }
     }
 }
@@ -117,6 +117,9 @@ impl QuantizedVectors {
                 Self::create_impl(v.as_ref(), quantization_config, path, max_threads, stopped)
             }
             VectorStorageEnum::SparseSimple(_) => Err(OperationError::WrongSparse),
+            VectorStorageEnum::BinarySimple(_) => Err(OperationError::service_error(
+                "Quantization is not supported for binary vectors",
+            )),
         }
     }
 
@@ -133,7 +136,7 @@ impl QuantizedVectors {
         let distance = vector_storage.distance();
         let dim = vector_storage.vector_dim();
 
-        let vector_parameters = Self::construct_vector_parameters(distance, dim, count);
+        let vector_parameters = Self::construct_vector_parameters(distance, dim, count)?;
 
         let quantized_storage = match quantization_config {
             QuantizationConfig::Scalar(ScalarQuantization {
@@ -389,18 +392,24 @@ impl QuantizedVectors {
         distance: Distance,
         dim: usize,
         count: usize,
-    ) -> quantization::VectorParameters {
-        quantization::VectorParameters {
+    ) -> OperationResult<quantization::VectorParameters> {
+        let distance_type = match distance {
+            Distance::Cosine => quantization::DistanceType::Dot,
+            Distance::Euclid => quantization::DistanceType::L2,
+            Distance::Dot => quantization::DistanceType::Dot,
+            Distance::Manhattan => quantization::DistanceType::L1,
+            Distance::Hamming | Distance::Jaccard => {
+                return Err(OperationError::service_error(format!(
+                    "Quantization is not supported for {distance:?} distance"
+                )));
+            }
+        };
+        Ok(quantization::VectorParameters {
             dim,
             count,
-            distance_type: match distance {
-                Distance::Cosine => quantization::DistanceType::Dot,
-                Distance::Euclid => quantization::DistanceType::L2,
-                Distance::Dot => quantization::DistanceType::Dot,
-                Distance::Manhattan => quantization::DistanceType::L1,
-            },
+            distance_type,
             invert: distance == Distance::Euclid || distance == Distance::Manhattan,
-        }
+        })
     }
 
     fn get_bucket_size(compression: CompressionRatio) -> usize {
@@ -0,0 +1,206 @@
+use std::iter;
+
+use common::math::scaled_fast_sigmoid;
+use common::types::ScoreType;
+use itertools::Itertools;
+
+use super::context_query::ContextPair;
+use super::{Query, TransformInto};
+use crate::common::operation_error::OperationResult;
+use crate::data_types::vectors::{QueryVector, Vector};
+
+/// Example with a confidence weight.
+///
+/// Positive weight marks the example as relevant, negative weight - as irrelevant.
+#[derive(Debug, Clone)]
+pub struct FeedbackItem<T> {
+    pub example: T,
+    pub weight: ScoreType,
+}
+
+impl<T> FeedbackItem<T> {
+    pub fn new(example: T, weight: ScoreType) -> Self {
+        Self { example, weight }
+    }
+
+    pub fn transform<F, U>(self, mut f: F) -> OperationResult<FeedbackItem<U>>
+    where
+        F: FnMut(T) -> OperationResult<U>,
+    {
+        Ok(FeedbackItem {
+            example: f(self.example)?,
+            weight: self.weight,
+        })
+    }
+}
+
+#[cfg(test)]
+impl<T> From<(T, ScoreType)> for FeedbackItem<T> {
+    fn from(item: (T, ScoreType)) -> Self {
+        Self::new(item.0, item.1)
+    }
+}
+
+/// Relevance feedback query.
+///
+/// Score is the sigmoid-scaled similarity to the target, plus the sigmoid-scaled similarity to
+/// every example multiplied by its weight. Context pairs additionally penalize points which are
+/// closer to the negative side of any pair, see [`ContextPair::loss_by`].
+#[derive(Debug, Clone)]
+pub struct FeedbackQuery<T> {
+    pub target: T,
+    pub feedback: Vec<FeedbackItem<T>>,
+    pub context: Vec<ContextPair<T>>,
+}
+
+impl<T> FeedbackQuery<T> {
+    pub fn new(target: T, feedback: Vec<FeedbackItem<T>>, context: Vec<ContextPair<T>>) -> Self {
+        Self {
+            target,
+            feedback,
+            context,
+        }
+    }
+
+    pub fn flat_iter(&self) -> impl Iterator<Item = &T> {
+        let feedback_iter = self.feedback.iter().map(|item| &item.example);
+        let context_iter = self.context.iter().flat_map(|pair| pair.iter());
+
+        iter::once(&self.target)
+            .chain(feedback_iter)
+            .chain(context_iter)
+    }
+}
+
+impl<T, U> TransformInto<FeedbackQuery<U>, T, U> for FeedbackQuery<T> {
+    fn transform<F>(self, mut f: F) -> OperationResult<FeedbackQuery<U>>
+    where
+        F: FnMut(T) -> OperationResult<U>,
+    {
+        Ok(FeedbackQuery::new(
+            f(self.target)?,
+            self.feedback
+                .into_iter()
+                .map(|item| item.transform(&mut f))
+                .try_collect()?,
+            self.context
+                .into_iter()
+                .map(|pair| pair.transform(&mut f))
+                .try_collect()?,
+        ))
+    }
+}
+
+impl<T> Query<T> for FeedbackQuery<T> {
+    fn score_by(&self, similarity: impl Fn(&T) -> ScoreType) -> ScoreType {
+        // Similarities are squashed into the same range for every metric,
+        // so that weights are comparable with each other and with the target
+        let target_score = scaled_fast_sigmoid(similarity(&self.target));
+
+        let feedback_score: ScoreType = self
+            .feedback
+            .iter()
+            .map(|item| item.weight * scaled_fast_sigmoid(similarity(&item.example)))
+            .sum();
+
+        let context_loss: ScoreType = self
+            .context
+            .iter()
+            .map(|pair| pair.loss_by(&similarity))
+            .sum();
+
+        target_score + feedback_score + context_loss
+    }
+}
+
+impl From<FeedbackQuery<Vector>> for QueryVector {
+    fn from(query: FeedbackQuery<Vector>) -> Self {
+        QueryVector::Feedback(query)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::cmp::Ordering;
+
+    use common::math::scaled_fast_sigmoid;
+    use common::types::ScoreType;
+    use proptest::prelude::*;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn dummy_similarity(x: &isize) -> ScoreType {
+        *x as ScoreType
+    }
+
+    #[test]
+    fn only_target() {
+        let query = FeedbackQuery::new(3, vec![], vec![]);
+
+        assert_eq!(query.score_by(dummy_similarity), scaled_fast_sigmoid(3.0));
+    }
+
+    /// Compares the score of a candidate against the score of the same query with no feedback
+    #[rstest]
+    #[case::relevant(vec![(5, 1.0)], Ordering::Greater)]
+    #[case::irrelevant(vec![(5, -1.0)], Ordering::Less)]
+    #[case::zero_weight(vec![(5, 0.0)], Ordering::Equal)]
+    #[case::more_confident_relevant(vec![(5, 2.0), (5, -1.0)], Ordering::Greater)]
+    #[case::more_confident_irrelevant(vec![(5, 0.5), (5, -1.0)], Ordering::Less)]
+    fn feedback_shifts_score(
+        #[case] feedback: Vec<(isize, ScoreType)>,
+        #[case] expected: Ordering,
+    ) {
+        let target = 1;
+
+        let feedback = feedback.into_iter().map(FeedbackItem::from).collect();
+        let query = FeedbackQuery::new(target, feedback, vec![]);
+        let baseline = FeedbackQuery::new(target, vec![], vec![]);
+
+        let score = query.score_by(dummy_similarity);
+        let baseline_score = baseline.score_by(dummy_similarity);
+
+        assert_eq!(score.total_cmp(&baseline_score), expected);
+    }
+
+    #[rstest]
+    #[case::on_positive(vec![(1, 0)], 0.0)]
+    #[case::on_negative(vec![(0, 1)], -1.0)]
+    #[case::on_both(vec![(1, 0), (0, 2)], -2.0)]
+    fn context_penalizes(#[case] pairs: Vec<(isize, isize)>, #[case] expected_loss: ScoreType) {
+        let target = 1;
+
+        let pairs = pairs.into_iter().map(ContextPair::from).collect();
+        let query = FeedbackQuery::new(target, vec![], pairs);
+
+        let score = query.score_by(dummy_similarity);
+        let expected = scaled_fast_sigmoid(1.0) + expected_loss;
+
+        assert!(
+            (score - expected).abs() < 0.0001,
+            "score: {score}, expected: {expected}"
+        );
+    }
+
+    proptest! {
+        /// Relevant feedback always prefers candidates that are closer to the example
+        #[test]
+        fn relevant_feedback_preserves_order(
+            a in -100isize..=100,
+            b in -100isize..=100,
+            weight in 0.01f32..=10.0,
+        ) {
+            // Candidates are represented by their similarity to the example, target is neutral
+            let score = |candidate: isize| {
+                let query = FeedbackQuery::new(0, vec![FeedbackItem::new(1, weight)], vec![]);
+                query.score_by(|x: &isize| if *x == 1 { candidate as ScoreType } else { 0.0 })
+            };
+
+            let ordering_before = a.cmp(&b);
+            let ordering_after = score(a).total_cmp(&score(b));
+
+            prop_assert!(ordering_after == ordering_before || ordering_after == Ordering::Equal);
+        }
+    }
+}
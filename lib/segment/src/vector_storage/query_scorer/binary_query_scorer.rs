@@ -0,0 +1,98 @@
+use std::marker::PhantomData;
+
+use common::types::{PointOffsetType, ScoreType};
+
+use crate::spaces::binary::{BinaryElementType, BinaryMetric};
+use crate::vector_storage::query::Query;
+use crate::vector_storage::query_scorer::QueryScorer;
+use crate::vector_storage::BinaryVectorStorage;
+
+pub struct BinaryMetricQueryScorer<'a, TMetric: BinaryMetric, TVectorStorage: BinaryVectorStorage> {
+    vector_storage: &'a TVectorStorage,
+    query: Vec<BinaryElementType>,
+    metric: PhantomData<TMetric>,
+}
+
+impl<'a, TMetric: BinaryMetric, TVectorStorage: BinaryVectorStorage>
+    BinaryMetricQueryScorer<'a, TMetric, TVectorStorage>
+{
+    pub fn new(query: Vec<BinaryElementType>, vector_storage: &'a TVectorStorage) -> Self {
+        Self {
+            query,
+            vector_storage,
+            metric: PhantomData,
+        }
+    }
+}
+
+impl<'a, TMetric: BinaryMetric, TVectorStorage: BinaryVectorStorage>
+    QueryScorer<[BinaryElementType]> for BinaryMetricQueryScorer<'a, TMetric, TVectorStorage>
+{
+    #[inline]
+    fn score_stored(&self, idx: PointOffsetType) -> ScoreType {
+        TMetric::similarity(&self.query, self.vector_storage.get_binary(idx))
+    }
+
+    #[inline]
+    fn score(&self, v2: &[BinaryElementType]) -> ScoreType {
+        TMetric::similarity(&self.query, v2)
+    }
+
+    fn score_internal(&self, point_a: PointOffsetType, point_b: PointOffsetType) -> ScoreType {
+        let v1 = self.vector_storage.get_binary(point_a);
+        let v2 = self.vector_storage.get_binary(point_b);
+        TMetric::similarity(v1, v2)
+    }
+}
+
+pub struct BinaryCustomQueryScorer<
+    'a,
+    TMetric: BinaryMetric,
+    TVectorStorage: BinaryVectorStorage,
+    TQuery: Query<Vec<BinaryElementType>>,
+> {
+    vector_storage: &'a TVectorStorage,
+    query: TQuery,
+    metric: PhantomData<TMetric>,
+}
+
+impl<
+        'a,
+        TMetric: BinaryMetric,
+        TVectorStorage: BinaryVectorStorage,
+        TQuery: Query<Vec<BinaryElementType>>,
+    > BinaryCustomQueryScorer<'a, TMetric, TVectorStorage, TQuery>
+{
+    pub fn new(query: TQuery, vector_storage: &'a TVectorStorage) -> Self {
+        Self {
+            query,
+            vector_storage,
+            metric: PhantomData,
+        }
+    }
+}
+
+impl<
+        'a,
+        TMetric: BinaryMetric,
+        TVectorStorage: BinaryVectorStorage,
+        TQuery: Query<Vec<BinaryElementType>>,
+    > QueryScorer<[BinaryElementType]>
+    for BinaryCustomQueryScorer<'a, TMetric, TVectorStorage, TQuery>
+{
+    #[inline]
+    fn score_stored(&self, idx: PointOffsetType) -> ScoreType {
+        let stored = self.vector_storage.get_binary(idx);
+        self.score(stored)
+    }
+
+    #[inline]
+    fn score(&self, against: &[BinaryElementType]) -> ScoreType {
+        self.query
+            .score_by(|example| TMetric::similarity(example, against))
+    }
+
+    fn score_internal(&self, _point_a: PointOffsetType, _point_b: PointOffsetType) -> ScoreType {
+        unimplemented!("Custom scorer can compare against multiple vectors, not just one")
+    }
+}
@@ -1,5 +1,6 @@
 use common::types::{PointOffsetType, ScoreType};
 
+pub mod binary_query_scorer;
 pub mod custom_query_scorer;
 pub mod metric_query_scorer;
 pub mod sparse_custom_query_scorer;
@@ -6,13 +6,18 @@ use sparse::common::sparse_vector::SparseVector;
 
 use super::query::context_query::ContextQuery;
 use super::query::discovery_query::DiscoveryQuery;
+use super::query::feedback_query::FeedbackQuery;
 use super::query::reco_query::RecoQuery;
 use super::query::TransformInto;
+use super::query_scorer::binary_query_scorer::{BinaryCustomQueryScorer, BinaryMetricQueryScorer};
 use super::query_scorer::custom_query_scorer::CustomQueryScorer;
 use super::query_scorer::sparse_custom_query_scorer::SparseCustomQueryScorer;
-use super::{DenseVectorStorage, SparseVectorStorage, VectorStorageEnum};
+use super::{BinaryVectorStorage, DenseVectorStorage, SparseVectorStorage, VectorStorageEnum};
 use crate::common::operation_error::{OperationError, OperationResult};
-use crate::data_types::vectors::{DenseVector, QueryVector};
+use crate::data_types::vectors::{DenseVector, QueryVector, Vector};
+use crate::spaces::binary::{
+    dense_to_binary, BinaryElementType, BinaryMetric, HammingMetric, JaccardMetric,
+};
 use crate::spaces::metric::Metric;
 use crate::spaces::simple::{CosineMetric, DotProductMetric, EuclidMetric, ManhattanMetric};
 use crate::spaces::tools::peek_top_largest_iterable;
@@ -135,6 +140,9 @@ pub fn new_stoppable_raw_scorer<'a>(
         VectorStorageEnum::SparseSimple(vs) => {
             raw_sparse_scorer_impl(query, vs, point_deleted, is_stopped)
         }
+        VectorStorageEnum::BinarySimple(vs) => {
+            raw_binary_scorer_impl(query, vs, point_deleted, is_stopped)
+        }
     }
 }
 
@@ -178,6 +186,15 @@ pub fn raw_sparse_scorer_impl<'a, TVectorStorage: SparseVectorStorage>(
                 is_stopped,
             )
         }
+        QueryVector::Feedback(feedback_query) => {
+            let feedback_query: FeedbackQuery<SparseVector> = feedback_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                SparseCustomQueryScorer::<_, _>::new(feedback_query, vector_storage),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
     }
 }
 
@@ -220,6 +237,105 @@ pub fn raw_scorer_impl<'a, TVectorStorage: DenseVectorStorage>(
             point_deleted,
             is_stopped,
         ),
+        Distance::Hamming | Distance::Jaccard => Err(OperationError::service_error(format!(
+            "{:?} distance requires binary vector storage",
+            vector_storage.distance(),
+        ))),
+    }
+}
+
+pub fn raw_binary_scorer_impl<'a, TVectorStorage: BinaryVectorStorage>(
+    query: QueryVector,
+    vector_storage: &'a TVectorStorage,
+    point_deleted: &'a BitSlice,
+    is_stopped: &'a AtomicBool,
+) -> OperationResult<Box<dyn RawScorer + 'a>> {
+    match vector_storage.distance() {
+        Distance::Hamming => new_binary_scorer_with_metric::<HammingMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Jaccard => new_binary_scorer_with_metric::<JaccardMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Cosine | Distance::Euclid | Distance::Dot | Distance::Manhattan => {
+            Err(OperationError::service_error(format!(
+                "{:?} distance is not supported by binary vector storage",
+                vector_storage.distance(),
+            )))
+        }
+    }
+}
+
+/// Convert a query vector, which holds one byte value per element, into a binary vector
+fn query_to_binary(vector: Vector) -> OperationResult<Vec<BinaryElementType>> {
+    let vector: DenseVector = vector.try_into()?;
+    Ok(dense_to_binary(&vector))
+}
+
+fn new_binary_scorer_with_metric<
+    'a,
+    TMetric: BinaryMetric + 'a,
+    TVectorStorage: BinaryVectorStorage,
+>(
+    query: QueryVector,
+    vector_storage: &'a TVectorStorage,
+    point_deleted: &'a BitSlice,
+    is_stopped: &'a AtomicBool,
+) -> OperationResult<Box<dyn RawScorer + 'a>> {
+    let vec_deleted = vector_storage.deleted_vector_bitslice();
+    match query {
+        QueryVector::Nearest(vector) => raw_scorer_from_query_scorer(
+            BinaryMetricQueryScorer::<TMetric, _>::new(query_to_binary(vector)?, vector_storage),
+            point_deleted,
+            vec_deleted,
+            is_stopped,
+        ),
+        QueryVector::Recommend(reco_query) => {
+            let reco_query: RecoQuery<Vec<BinaryElementType>> =
+                reco_query.transform(query_to_binary)?;
+            raw_scorer_from_query_scorer(
+                BinaryCustomQueryScorer::<TMetric, _, _>::new(reco_query, vector_storage),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+        QueryVector::Discovery(discovery_query) => {
+            let discovery_query: DiscoveryQuery<Vec<BinaryElementType>> =
+                discovery_query.transform(query_to_binary)?;
+            raw_scorer_from_query_scorer(
+                BinaryCustomQueryScorer::<TMetric, _, _>::new(discovery_query, vector_storage),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+        QueryVector::Context(context_query) => {
+            let context_query: ContextQuery<Vec<BinaryElementType>> =
+                context_query.transform(query_to_binary)?;
+            raw_scorer_from_query_scorer(
+                BinaryCustomQueryScorer::<TMetric, _, _>::new(context_query, vector_storage),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+        QueryVector::Feedback(feedback_query) => {
+            let feedback_query: FeedbackQuery<Vec<BinaryElementType>> =
+                feedback_query.transform(query_to_binary)?;
+            raw_scorer_from_query_scorer(
+                BinaryCustomQueryScorer::<TMetric, _, _>::new(feedback_query, vector_storage),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
     }
 }
 
@@ -264,6 +380,15 @@ fn new_scorer_with_metric<'a, TMetric: Metric + 'a, TVectorStorage: DenseVectorS
                 is_stopped,
             )
         }
+        QueryVector::Feedback(feedback_query) => {
+            let feedback_query: FeedbackQuery<DenseVector> = feedback_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                CustomQueryScorer::<TMetric, _, _>::new(feedback_query, vector_storage),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
     }
 }
 
@@ -0,0 +1,228 @@
+use std::ops::Range;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+
+use atomic_refcell::AtomicRefCell;
+use bitvec::prelude::{BitSlice, BitVec};
+use common::types::PointOffsetType;
+use log::debug;
+use parking_lot::RwLock;
+use rocksdb::DB;
+use serde::{Deserialize, Serialize};
+
+use super::chunked_vectors::ChunkedVectors;
+use super::vector_storage_base::VectorStorage;
+use super::{BinaryVectorStorage, VectorStorageEnum};
+use crate::common::operation_error::{check_process_stopped, OperationError, OperationResult};
+use crate::common::rocksdb_wrapper::DatabaseColumnWrapper;
+use crate::common::Flusher;
+use crate::data_types::named_vectors::CowVector;
+use crate::data_types::vectors::VectorRef;
+use crate::spaces::binary::{binary_to_dense, dense_to_binary, BinaryElementType};
+use crate::types::Distance;
+use crate::vector_storage::bitvec::bitvec_set_deleted;
+
+/// In-memory storage of bit-packed binary vectors with on-update persistence using `store`
+///
+/// Every stored element holds 8 bits of the vector.
+/// Vectors are exposed as dense vectors with one byte value per element.
+pub struct SimpleBinaryVectorStorage {
+    dim: usize,
+    distance: Distance,
+    vectors: ChunkedVectors<BinaryElementType>,
+    db_wrapper: DatabaseColumnWrapper,
+    update_buffer: StoredRecord,
+    /// BitVec for deleted flags. Grows dynamically upto last set flag.
+    deleted: BitVec,
+    /// Current number of deleted vectors.
+    deleted_count: usize,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+struct StoredRecord {
+    pub deleted: bool,
+    pub vector: Vec<BinaryElementType>,
+}
+
+pub fn open_simple_binary_vector_storage(
+    database: Arc<RwLock<DB>>,
+    database_column_name: &str,
+    dim: usize,
+    distance: Distance,
+) -> OperationResult<Arc<AtomicRefCell<VectorStorageEnum>>> {
+    debug_assert!(distance.is_binary());
+
+    let mut vectors = ChunkedVectors::new(dim);
+    let (mut deleted, mut deleted_count) = (BitVec::new(), 0);
+
+    let db_wrapper = DatabaseColumnWrapper::new(database, database_column_name);
+
+    for (key, value) in db_wrapper.lock_db().iter()? {
+        let point_id: PointOffsetType = bincode::deserialize(&key)
+            .map_err(|_| OperationError::service_error("cannot deserialize point id from db"))?;
+        let stored_record: StoredRecord = bincode::deserialize(&value)
+            .map_err(|_| OperationError::service_error("cannot deserialize record from db"))?;
+
+        // Propagate deleted flag
+        if stored_record.deleted {
+            bitvec_set_deleted(&mut deleted, point_id, true);
+            deleted_count += 1;
+        }
+        vectors.insert(point_id, &stored_record.vector)?;
+    }
+
+    debug!("Segment vectors: {}", vectors.len());
+    debug!(
+        "Estimated segment size {} MB",
+        vectors.len() * dim / 1024 / 1024
+    );
+
+    Ok(Arc::new(AtomicRefCell::new(
+        VectorStorageEnum::BinarySimple(SimpleBinaryVectorStorage {
+            dim,
+            distance,
+            vectors,
+            db_wrapper,
+            update_buffer: StoredRecord {
+                deleted: false,
+                vector: vec![0; dim],
+            },
+            deleted,
+            deleted_count,
+        }),
+    )))
+}
+
+impl SimpleBinaryVectorStorage {
+    /// Set deleted flag for given key. Returns previous deleted state.
+    #[inline]
+    fn set_deleted(&mut self, key: PointOffsetType, deleted: bool) -> bool {
+        if key as usize >= self.vectors.len() {
+            return false;
+        }
+        let was_deleted = bitvec_set_deleted(&mut self.deleted, key, deleted);
+        if was_deleted != deleted {
+            if !was_deleted {
+                self.deleted_count += 1;
+            } else {
+                self.deleted_count -= 1;
+            }
+        }
+        was_deleted
+    }
+
+    fn update_stored(
+        &mut self,
+        key: PointOffsetType,
+        deleted: bool,
+        vector: Option<&[BinaryElementType]>,
+    ) -> OperationResult<()> {
+        // Write vector state to buffer record
+        let record = &mut self.update_buffer;
+        record.deleted = deleted;
+        if let Some(vector) = vector {
+            record.vector.copy_from_slice(vector);
+        }
+
+        // Store updated record
+        self.db_wrapper.put(
+            bincode::serialize(&key).unwrap(),
+            bincode::serialize(&record).unwrap(),
+        )?;
+
+        Ok(())
+    }
+
+    /// Convert a dense vector with one byte value per element into the stored form
+    fn to_binary(vector: VectorRef) -> OperationResult<Vec<BinaryElementType>> {
+        let vector: &[_] = vector.try_into()?;
+        Ok(dense_to_binary(vector))
+    }
+}
+
+impl BinaryVectorStorage for SimpleBinaryVectorStorage {
+    fn get_binary(&self, key: PointOffsetType) -> &[BinaryElementType] {
+        self.vectors.get(key)
+    }
+}
+
+impl VectorStorage for SimpleBinaryVectorStorage {
+    fn vector_dim(&self) -> usize {
+        self.dim
+    }
+
+    fn distance(&self) -> Distance {
+        self.distance
+    }
+
+    fn is_on_disk(&self) -> bool {
+        false
+    }
+
+    fn total_vector_count(&self) -> usize {
+        self.vectors.len()
+    }
+
+    fn get_vector(&self, key: PointOffsetType) -> CowVector {
+        binary_to_dense(self.get_binary(key)).into()
+    }
+
+    fn insert_vector(&mut self, key: PointOffsetType, vector: VectorRef) -> OperationResult<()> {
+        let vector = Self::to_binary(vector)?;
+        self.vectors.insert(key, &vector)?;
+        self.set_deleted(key, false);
+        self.update_stored(key, false, Some(&vector))?;
+        Ok(())
+    }
+
+    fn update_from(
+        &mut self,
+        other: &VectorStorageEnum,
+        other_ids: &mut dyn Iterator<Item = PointOffsetType>,
+        stopped: &AtomicBool,
+    ) -> OperationResult<Range<PointOffsetType>> {
+        let start_index = self.vectors.len() as PointOffsetType;
+        for point_id in other_ids {
+            check_process_stopped(stopped)?;
+            let other_vector = match other {
+                // Avoid the round trip through the dense representation
+                VectorStorageEnum::BinarySimple(other) => other.get_binary(point_id).to_vec(),
+                _ => Self::to_binary(other.get_vector(point_id).as_vec_ref())?,
+            };
+            let other_deleted = other.is_deleted_vector(point_id);
+            let new_id = self.vectors.push(&other_vector)?;
+            self.set_deleted(new_id, other_deleted);
+            self.update_stored(new_id, other_deleted, Some(&other_vector))?;
+        }
+        let end_index = self.vectors.len() as PointOffsetType;
+        Ok(start_index..end_index)
+    }
+
+    fn flusher(&self) -> Flusher {
+        self.db_wrapper.flusher()
+    }
+
+    fn files(&self) -> Vec<std::path::PathBuf> {
+        vec![]
+    }
+
+    fn delete_vector(&mut self, key: PointOffsetType) -> OperationResult<bool> {
+        let is_deleted = !self.set_deleted(key, true);
+        if is_deleted {
+            self.update_stored(key, true, None)?;
+        }
+        Ok(is_deleted)
+    }
+
+    fn is_deleted_vector(&self, key: PointOffsetType) -> bool {
+        self.deleted.get(key as usize).map(|b| *b).unwrap_or(false)
+    }
+
+    fn deleted_vector_count(&self) -> usize {
+        self.deleted_count
+    }
+
+    fn deleted_vector_bitslice(&self) -> &BitSlice {
+        self.deleted.as_bitslice()
+    }
+}
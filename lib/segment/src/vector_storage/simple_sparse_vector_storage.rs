@@ -8,7 +8,11 @@ use common::types::PointOffsetType;
 use parking_lot::RwLock;
 use rocksdb::DB;
 use serde::{Deserialize, Serialize};
+use sparse::common::datatype::{
+    EncodedWeights, QuantizationParams, QuantizationParamsBuilder, SparseVectorDatatype,
+};
 use sparse::common::sparse_vector::SparseVector;
+use sparse::common::types::DimId;
 
 use super::SparseVectorStorage;
 use crate::common::operation_error::{check_process_stopped, OperationError, OperationResult};
@@ -22,9 +26,16 @@ use crate::vector_storage::{VectorStorage, VectorStorageEnum};
 
 pub const SPARSE_VECTOR_DISTANCE: Distance = Distance::Dot;
 
+/// Key of the `Uint8` quantization params, can't collide with serialized point offsets
+const QUANTIZATION_PARAMS_KEY: &[u8] = b"quantization_params";
+
 /// In-memory vector storage with on-update persistence using `store`
 pub struct SimpleSparseVectorStorage {
     db_wrapper: DatabaseColumnWrapper,
+    /// Datatype of the stored weights
+    datatype: SparseVectorDatatype,
+    /// Per-dimension params of `Uint8` weights, present once the storage is quantized
+    quantization: Option<QuantizationParams>,
     update_buffer: StoredRecord,
     /// BitVec for deleted flags. Grows dynamically upto last set flag.
     deleted: BitVec,
@@ -41,22 +52,90 @@ struct StoredRecord {
     pub vector: SparseVector,
 }
 
+/// Stored record with weights encoded in a non-default datatype
+#[derive(Debug, Deserialize, Serialize, Clone)]
+struct EncodedStoredRecord {
+    pub deleted: bool,
+    pub indices: Vec<DimId>,
+    pub weights: EncodedWeights,
+}
+
+impl StoredRecord {
+    fn serialize(
+        &self,
+        datatype: SparseVectorDatatype,
+        quantization: Option<&QuantizationParams>,
+    ) -> Vec<u8> {
+        let SparseVector { indices, values } = &self.vector;
+        let weights = match datatype {
+            // keep the original format for full precision weights
+            SparseVectorDatatype::Float32 => return bincode::serialize(self).unwrap(),
+            SparseVectorDatatype::Float16 => datatype.encode(values),
+            // weights out of the quantized range are kept in half precision
+            SparseVectorDatatype::Uint8 => quantization
+                .and_then(|quantization| quantization.encode(indices, values))
+                .unwrap_or_else(|| datatype.encode(values)),
+        };
+        bincode::serialize(&EncodedStoredRecord {
+            deleted: self.deleted,
+            indices: indices.clone(),
+            weights,
+        })
+        .unwrap()
+    }
+
+    fn deserialize(
+        data: &[u8],
+        datatype: SparseVectorDatatype,
+        quantization: Option<&QuantizationParams>,
+    ) -> OperationResult<Self> {
+        let record = match datatype {
+            SparseVectorDatatype::Float32 => bincode::deserialize(data).ok(),
+            SparseVectorDatatype::Float16 | SparseVectorDatatype::Uint8 => {
+                bincode::deserialize::<EncodedStoredRecord>(data)
+                    .ok()
+                    .and_then(|record| {
+                        let values = record.weights.decode(&record.indices, quantization)?;
+                        Some(StoredRecord {
+                            deleted: record.deleted,
+                            vector: SparseVector {
+                                indices: record.indices,
+                                values,
+                            },
+                        })
+                    })
+            }
+        };
+        record.ok_or_else(|| OperationError::service_error("cannot deserialize record from db"))
+    }
+}
+
 #[allow(unused)]
 pub fn open_simple_sparse_vector_storage(
     database: Arc<RwLock<DB>>,
     database_column_name: &str,
+    datatype: SparseVectorDatatype,
 ) -> OperationResult<Arc<AtomicRefCell<VectorStorageEnum>>> {
     let (mut deleted, mut deleted_count) = (BitVec::new(), 0);
     let db_wrapper = DatabaseColumnWrapper::new(database, database_column_name);
 
+    let quantization = db_wrapper
+        .get_pinned(QUANTIZATION_PARAMS_KEY, |data| {
+            bincode::deserialize::<QuantizationParams>(data)
+        })?
+        .transpose()
+        .map_err(|_| OperationError::service_error("cannot deserialize quantization params"))?;
+
     let mut total_vector_count = 0;
     let mut total_sparse_size = 0;
     db_wrapper.lock_db().iter()?;
     for (key, value) in db_wrapper.lock_db().iter()? {
+        if key.as_ref() == QUANTIZATION_PARAMS_KEY {
+            continue;
+        }
         let point_id: PointOffsetType = bincode::deserialize(&key)
             .map_err(|_| OperationError::service_error("cannot deserialize point id from db"))?;
-        let stored_record: StoredRecord = bincode::deserialize(&value)
-            .map_err(|_| OperationError::service_error("cannot deserialize record from db"))?;
+        let stored_record = StoredRecord::deserialize(&value, datatype, quantization.as_ref())?;
 
         // Propagate deleted flag
         if stored_record.deleted {
@@ -70,6 +149,8 @@ pub fn open_simple_sparse_vector_storage(
     Ok(Arc::new(AtomicRefCell::new(
         VectorStorageEnum::SparseSimple(SimpleSparseVectorStorage {
             db_wrapper,
+            datatype,
+            quantization,
             update_buffer: StoredRecord {
                 deleted: false,
                 vector: SparseVector::default(),
@@ -121,12 +202,53 @@ impl SimpleSparseVectorStorage {
         // Store updated record
         self.db_wrapper.put(
             bincode::serialize(&key).unwrap(),
-            bincode::serialize(&record).unwrap(),
+            record.serialize(self.datatype, None),
         )?;
 
         Ok(())
     }
 
+    /// Quantize stored `Uint8` weights with per-dimension params.
+    ///
+    /// Params are computed from all stored vectors, so the storage is expected to be immutable.
+    /// Weights of vectors inserted afterwards are kept in half precision.
+    pub fn quantize(&mut self, stopped: &AtomicBool) -> OperationResult<()> {
+        if self.datatype != SparseVectorDatatype::Uint8 || self.quantization.is_some() {
+            return Ok(());
+        }
+
+        let mut builder = QuantizationParamsBuilder::default();
+        for key in 0..self.total_vector_count as PointOffsetType {
+            check_process_stopped(stopped)?;
+            if let Ok(vector) = self.get_sparse(key) {
+                builder.update(&vector.indices, &vector.values);
+            }
+        }
+        let quantization = builder.build();
+        self.db_wrapper.put(
+            QUANTIZATION_PARAMS_KEY,
+            bincode::serialize(&quantization).unwrap(),
+        )?;
+        self.quantization = Some(quantization);
+
+        // Stored records are rewritten with quantized weights
+        for key in 0..self.total_vector_count as PointOffsetType {
+            check_process_stopped(stopped)?;
+            let bin_key = bincode::serialize(&key).unwrap();
+            let Some(record) = self.db_wrapper.get_pinned(&bin_key, |data| {
+                StoredRecord::deserialize(data, self.datatype, None)
+            })?
+            else {
+                continue;
+            };
+            self.db_wrapper.put(
+                bin_key,
+                record?.serialize(self.datatype, self.quantization.as_ref()),
+            )?;
+        }
+        Ok(())
+    }
+
     /// Estimate average vector size based on total number of non-zero elements in all vectors.
     ///
     /// This is needed because the optimizer relies on the vector dimension * size_of_f32 * point_count to
@@ -148,9 +270,7 @@ impl SparseVectorStorage for SimpleSparseVectorStorage {
         let bin_key = bincode::serialize(&key)
             .map_err(|_| OperationError::service_error("Cannot serialize sparse vector key"))?;
         let data = self.db_wrapper.get(bin_key)?;
-        let record: StoredRecord = bincode::deserialize(&data).map_err(|_| {
-            OperationError::service_error("Cannot deserialize sparse vector from db")
-        })?;
+        let record = StoredRecord::deserialize(&data, self.datatype, self.quantization.as_ref())?;
         Ok(record.vector)
     }
 }
@@ -25,6 +25,7 @@ use crate::vector_storage::memmap_vector_storage::open_memmap_vector_storage_wit
 use crate::vector_storage::quantized::quantized_vectors::QuantizedVectors;
 use crate::vector_storage::query::context_query::{ContextPair, ContextQuery};
 use crate::vector_storage::query::discovery_query::DiscoveryQuery;
+use crate::vector_storage::query::feedback_query::{FeedbackItem, FeedbackQuery};
 use crate::vector_storage::query::reco_query::RecoQuery;
 use crate::vector_storage::simple_dense_vector_storage::open_simple_vector_storage;
 use crate::vector_storage::tests::utils::score;
@@ -54,6 +55,7 @@ fn random_query<R: Rng + ?Sized>(
         QueryVariant::Recommend => random_reco_query(rnd, sampler),
         QueryVariant::Discovery => random_discovery_query(rnd, sampler),
         QueryVariant::Context => random_context_query(rnd, sampler),
+        QueryVariant::Feedback => random_feedback_query(rnd, sampler),
     }
 }
 
@@ -111,6 +113,33 @@ fn random_context_query<R: Rng + ?Sized>(
     ContextQuery::new(pairs).into()
 }
 
+fn random_feedback_query<R: Rng + ?Sized>(
+    rnd: &mut R,
+    sampler: &mut impl Iterator<Item = f32>,
+) -> QueryVector {
+    let num_examples: usize = rnd.gen_range(0..MAX_EXAMPLES);
+    let num_pairs: usize = rnd.gen_range(0..MAX_EXAMPLES);
+
+    let target = sampler.take(DIMS).collect_vec().into();
+
+    let feedback = (0..num_examples)
+        .map(|_| {
+            let example = sampler.take(DIMS).collect_vec().into();
+            FeedbackItem::new(example, rnd.gen_range(-1.0..=1.0))
+        })
+        .collect_vec();
+
+    let pairs = (0..num_pairs)
+        .map(|_| {
+            let positive = sampler.take(DIMS).collect_vec().into();
+            let negative = sampler.take(DIMS).collect_vec().into();
+            ContextPair { positive, negative }
+        })
+        .collect_vec();
+
+    FeedbackQuery::new(target, feedback, pairs).into()
+}
+
 fn ram_storage(dir: &Path) -> AtomicRefCell<VectorStorageEnum> {
     let storage = open_simple_vector_storage(
         rocksdb_wrapper::open_db(dir, &[rocksdb_wrapper::DB_VECTOR_CF]).unwrap(),
@@ -181,6 +210,7 @@ enum QueryVariant {
     Recommend,
     Discovery,
     Context,
+    Feedback,
 }
 
 fn scoring_equivalency(
@@ -320,7 +350,8 @@ fn compare_scoring_equivalency(
     #[values(
         QueryVariant::Recommend,
         QueryVariant::Discovery,
-        QueryVariant::Context
+        QueryVariant::Context,
+        QueryVariant::Feedback
     )]
     query_variant: QueryVariant,
     #[values(ram_storage)] other_storage: impl FnOnce(
@@ -340,7 +371,8 @@ fn async_compare_scoring_equivalency(
     #[values(
         QueryVariant::Recommend,
         QueryVariant::Discovery,
-        QueryVariant::Context
+        QueryVariant::Context,
+        QueryVariant::Feedback
     )]
     query_variant: QueryVariant,
 
@@ -1,7 +1,9 @@
+use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
 
 use atomic_refcell::AtomicRefCell;
 use common::types::PointOffsetType;
+use sparse::common::datatype::SparseVectorDatatype;
 use sparse::common::sparse_vector::SparseVector;
 use tempfile::Builder;
 
@@ -135,7 +137,8 @@ fn do_test_update_from_delete_points(storage: Arc<AtomicRefCell<VectorStorageEnu
     {
         let dir2 = Builder::new().prefix("db_dir").tempdir().unwrap();
         let db = open_db(dir2.path(), &[DB_VECTOR_CF]).unwrap();
-        let storage2 = open_simple_sparse_vector_storage(db, DB_VECTOR_CF).unwrap();
+        let storage2 =
+            open_simple_sparse_vector_storage(db, DB_VECTOR_CF, Default::default()).unwrap();
         {
             let mut borrowed_storage2 = storage2.borrow_mut();
             points.iter().enumerate().for_each(|(i, vec)| {
@@ -210,12 +213,13 @@ fn test_delete_points_in_simple_sparse_vector_storage() {
 
     {
         let db = open_db(dir.path(), &[DB_VECTOR_CF]).unwrap();
-        let storage = open_simple_sparse_vector_storage(db, DB_VECTOR_CF).unwrap();
+        let storage =
+            open_simple_sparse_vector_storage(db, DB_VECTOR_CF, Default::default()).unwrap();
         do_test_delete_points(storage.clone());
         storage.borrow().flusher()().unwrap();
     }
     let db = open_db(dir.path(), &[DB_VECTOR_CF]).unwrap();
-    let _storage = open_simple_sparse_vector_storage(db, DB_VECTOR_CF).unwrap();
+    let _storage = open_simple_sparse_vector_storage(db, DB_VECTOR_CF, Default::default()).unwrap();
 }
 
 #[test]
@@ -223,11 +227,108 @@ fn test_update_from_delete_points_simple_sparse_vector_storage() {
     let dir = Builder::new().prefix("storage_dir").tempdir().unwrap();
     {
         let db = open_db(dir.path(), &[DB_VECTOR_CF]).unwrap();
-        let storage = open_simple_sparse_vector_storage(db, DB_VECTOR_CF).unwrap();
+        let storage =
+            open_simple_sparse_vector_storage(db, DB_VECTOR_CF, Default::default()).unwrap();
         do_test_update_from_delete_points(storage.clone());
         storage.borrow().flusher()().unwrap();
     }
 
     let db = open_db(dir.path(), &[DB_VECTOR_CF]).unwrap();
-    let _storage = open_simple_sparse_vector_storage(db, DB_VECTOR_CF).unwrap();
+    let _storage = open_simple_sparse_vector_storage(db, DB_VECTOR_CF, Default::default()).unwrap();
+}
+
+#[test]
+fn test_quantized_simple_sparse_vector_storage() {
+    let vector: SparseVector = vec![(0, 0.1), (3, 2.5), (7, -1.0), (9, 100.0)]
+        .try_into()
+        .unwrap();
+
+    for datatype in [SparseVectorDatatype::Float16, SparseVectorDatatype::Uint8] {
+        let mut rounded = vector.clone();
+        datatype.round(&mut rounded.values);
+
+        let dir = Builder::new().prefix("storage_dir").tempdir().unwrap();
+        {
+            let db = open_db(dir.path(), &[DB_VECTOR_CF]).unwrap();
+            let storage = open_simple_sparse_vector_storage(db, DB_VECTOR_CF, datatype).unwrap();
+            let mut borrowed_storage = storage.borrow_mut();
+            borrowed_storage
+                .insert_vector(0, (&rounded).into())
+                .unwrap();
+            borrowed_storage
+                .insert_vector(1, (&rounded).into())
+                .unwrap();
+            borrowed_storage.delete_vector(1).unwrap();
+            borrowed_storage.flusher()().unwrap();
+        }
+
+        let db = open_db(dir.path(), &[DB_VECTOR_CF]).unwrap();
+        let storage = open_simple_sparse_vector_storage(db, DB_VECTOR_CF, datatype).unwrap();
+        let borrowed_storage = storage.borrow();
+        assert_eq!(borrowed_storage.total_vector_count(), 2);
+        assert_eq!(borrowed_storage.deleted_vector_count(), 1);
+
+        // rounded weights are stored without loss
+        let stored = borrowed_storage.get_vector(0);
+        let stored: &SparseVector = stored.as_vec_ref().try_into().unwrap();
+        assert_eq!(stored, &rounded);
+    }
+}
+
+#[test]
+fn test_quantize_simple_sparse_vector_storage() {
+    let datatype = SparseVectorDatatype::Uint8;
+    // dimension 1 has much smaller weights than dimension 0
+    let vectors: Vec<SparseVector> = (0..10)
+        .map(|i| {
+            let mut vector: SparseVector = vec![(0, i as f32 * 10.0), (1, i as f32 * 0.001)]
+                .try_into()
+                .unwrap();
+            datatype.round(&mut vector.values);
+            vector
+        })
+        .collect();
+
+    let dir = Builder::new().prefix("storage_dir").tempdir().unwrap();
+    let quantized: Vec<SparseVector> = {
+        let db = open_db(dir.path(), &[DB_VECTOR_CF]).unwrap();
+        let storage = open_simple_sparse_vector_storage(db, DB_VECTOR_CF, datatype).unwrap();
+        let mut borrowed_storage = storage.borrow_mut();
+        for (key, vector) in vectors.iter().enumerate() {
+            borrowed_storage
+                .insert_vector(key as PointOffsetType, vector.into())
+                .unwrap();
+        }
+        let VectorStorageEnum::SparseSimple(sparse_storage) = &mut *borrowed_storage else {
+            panic!("unexpected storage type");
+        };
+        sparse_storage.quantize(&AtomicBool::new(false)).unwrap();
+        borrowed_storage.flusher()().unwrap();
+
+        (0..vectors.len() as PointOffsetType)
+            .map(|key| {
+                let stored = borrowed_storage.get_vector(key);
+                let stored: &SparseVector = stored.as_vec_ref().try_into().unwrap();
+                stored.clone()
+            })
+            .collect()
+    };
+
+    // each dimension is quantized with its own step
+    for (vector, quantized) in vectors.iter().zip(&quantized) {
+        assert_eq!(vector.indices, quantized.indices);
+        assert!((vector.values[0] - quantized.values[0]).abs() <= 90.0 / 254.0);
+        assert!((vector.values[1] - quantized.values[1]).abs() <= 0.009 / 254.0);
+    }
+
+    // quantized weights are loaded with the persisted params
+    let db = open_db(dir.path(), &[DB_VECTOR_CF]).unwrap();
+    let storage = open_simple_sparse_vector_storage(db, DB_VECTOR_CF, datatype).unwrap();
+    let borrowed_storage = storage.borrow();
+    assert_eq!(borrowed_storage.total_vector_count(), vectors.len());
+    for (key, quantized) in quantized.iter().enumerate() {
+        let stored = borrowed_storage.get_vector(key as PointOffsetType);
+        let stored: &SparseVector = stored.as_vec_ref().try_into().unwrap();
+        assert_eq!(stored, quantized);
+    }
 }
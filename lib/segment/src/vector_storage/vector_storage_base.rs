@@ -12,8 +12,10 @@ use crate::common::operation_error::OperationResult;
 use crate::common::Flusher;
 use crate::data_types::named_vectors::CowVector;
 use crate::data_types::vectors::{VectorElementType, VectorRef};
+use crate::spaces::binary::BinaryElementType;
 use crate::types::Distance;
 use crate::vector_storage::appendable_mmap_vector_storage::AppendableMmapVectorStorage;
+use crate::vector_storage::simple_binary_vector_storage::SimpleBinaryVectorStorage;
 use crate::vector_storage::simple_sparse_vector_storage::SimpleSparseVectorStorage;
 
 /// Trait for vector storage
@@ -104,11 +106,16 @@ pub trait SparseVectorStorage: VectorStorage {
     fn get_sparse(&self, key: PointOffsetType) -> OperationResult<SparseVector>;
 }
 
+pub trait BinaryVectorStorage: VectorStorage {
+    fn get_binary(&self, key: PointOffsetType) -> &[BinaryElementType];
+}
+
 pub enum VectorStorageEnum {
     DenseSimple(SimpleDenseVectorStorage),
     Memmap(Box<MemmapVectorStorage>),
     AppendableMemmap(Box<AppendableMmapVectorStorage>),
     SparseSimple(SimpleSparseVectorStorage),
+    BinarySimple(SimpleBinaryVectorStorage),
 }
 
 impl VectorStorage for VectorStorageEnum {
@@ -118,6 +125,7 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::Memmap(v) => v.vector_dim(),
             VectorStorageEnum::AppendableMemmap(v) => v.vector_dim(),
             VectorStorageEnum::SparseSimple(v) => v.vector_dim(),
+            VectorStorageEnum::BinarySimple(v) => v.vector_dim(),
         }
     }
 
@@ -127,6 +135,7 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::Memmap(v) => v.distance(),
             VectorStorageEnum::AppendableMemmap(v) => v.distance(),
             VectorStorageEnum::SparseSimple(v) => v.distance(),
+            VectorStorageEnum::BinarySimple(v) => v.distance(),
         }
     }
 
@@ -136,6 +145,7 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::Memmap(v) => v.is_on_disk(),
             VectorStorageEnum::AppendableMemmap(v) => v.is_on_disk(),
             VectorStorageEnum::SparseSimple(v) => v.is_on_disk(),
+            VectorStorageEnum::BinarySimple(v) => v.is_on_disk(),
         }
     }
 
@@ -145,6 +155,7 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::Memmap(v) => v.total_vector_count(),
             VectorStorageEnum::AppendableMemmap(v) => v.total_vector_count(),
             VectorStorageEnum::SparseSimple(v) => v.total_vector_count(),
+            VectorStorageEnum::BinarySimple(v) => v.total_vector_count(),
         }
     }
 
@@ -154,6 +165,7 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::Memmap(v) => v.get_vector(key),
             VectorStorageEnum::AppendableMemmap(v) => v.get_vector(key),
             VectorStorageEnum::SparseSimple(v) => v.get_vector(key),
+            VectorStorageEnum::BinarySimple(v) => v.get_vector(key),
         }
     }
 
@@ -163,6 +175,7 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::Memmap(v) => v.get_vector_opt(key),
             VectorStorageEnum::AppendableMemmap(v) => v.get_vector_opt(key),
             VectorStorageEnum::SparseSimple(v) => v.get_vector_opt(key),
+            VectorStorageEnum::BinarySimple(v) => v.get_vector_opt(key),
         }
     }
 
@@ -172,6 +185,7 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::Memmap(v) => v.insert_vector(key, vector),
             VectorStorageEnum::AppendableMemmap(v) => v.insert_vector(key, vector),
             VectorStorageEnum::SparseSimple(v) => v.insert_vector(key, vector),
+            VectorStorageEnum::BinarySimple(v) => v.insert_vector(key, vector),
         }
     }
 
@@ -186,6 +200,7 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::Memmap(v) => v.update_from(other, other_ids, stopped),
             VectorStorageEnum::AppendableMemmap(v) => v.update_from(other, other_ids, stopped),
             VectorStorageEnum::SparseSimple(v) => v.update_from(other, other_ids, stopped),
+            VectorStorageEnum::BinarySimple(v) => v.update_from(other, other_ids, stopped),
         }
     }
 
@@ -195,6 +210,7 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::Memmap(v) => v.flusher(),
             VectorStorageEnum::AppendableMemmap(v) => v.flusher(),
             VectorStorageEnum::SparseSimple(v) => v.flusher(),
+            VectorStorageEnum::BinarySimple(v) => v.flusher(),
         }
     }
 
@@ -204,6 +220,7 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::Memmap(v) => v.files(),
             VectorStorageEnum::AppendableMemmap(v) => v.files(),
             VectorStorageEnum::SparseSimple(v) => v.files(),
+            VectorStorageEnum::BinarySimple(v) => v.files(),
         }
     }
 
@@ -213,6 +230,7 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::Memmap(v) => v.delete_vector(key),
             VectorStorageEnum::AppendableMemmap(v) => v.delete_vector(key),
             VectorStorageEnum::SparseSimple(v) => v.delete_vector(key),
+            VectorStorageEnum::BinarySimple(v) => v.delete_vector(key),
         }
     }
 
@@ -222,6 +240,7 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::Memmap(v) => v.is_deleted_vector(key),
             VectorStorageEnum::AppendableMemmap(v) => v.is_deleted_vector(key),
             VectorStorageEnum::SparseSimple(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::BinarySimple(v) => v.is_deleted_vector(key),
         }
     }
 
@@ -231,6 +250,7 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::Memmap(v) => v.deleted_vector_count(),
             VectorStorageEnum::AppendableMemmap(v) => v.deleted_vector_count(),
             VectorStorageEnum::SparseSimple(v) => v.deleted_vector_count(),
+            VectorStorageEnum::BinarySimple(v) => v.deleted_vector_count(),
         }
     }
 
@@ -240,6 +260,7 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::Memmap(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::AppendableMemmap(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::SparseSimple(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::BinarySimple(v) => v.deleted_vector_bitslice(),
         }
     }
 }
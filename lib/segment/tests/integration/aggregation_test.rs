@@ -0,0 +1,83 @@
+use rand::prelude::StdRng;
+use rand::SeedableRng;
+use segment::data_types::aggregation::HistogramParams;
+use segment::entry::entry_point::SegmentEntry;
+use segment::fixtures::payload_fixtures::{random_filter, FLT_KEY, INT_KEY};
+use segment::fixtures::segment_fixtures::random_segment;
+use segment::types::PayloadSchemaType;
+use tempfile::Builder;
+
+const NUM_POINTS: usize = 2000;
+const ATTEMPTS: usize = 20;
+
+#[test]
+fn test_aggregation_index_consistency() {
+    let seed = 42;
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let dir = Builder::new().prefix("storage_dir").tempdir().unwrap();
+    let dir_indexed = Builder::new().prefix("storage_dir").tempdir().unwrap();
+
+    let segment = random_segment(dir.path(), NUM_POINTS);
+    let mut indexed_segment = random_segment(dir_indexed.path(), NUM_POINTS);
+
+    // Same payloads in both segments, only the indexed one has numeric field indexes
+    for point_id in 0..NUM_POINTS as u64 {
+        let payload = segment.payload(point_id.into()).unwrap();
+        indexed_segment
+            .set_full_payload(101, point_id.into(), &payload)
+            .unwrap();
+    }
+    indexed_segment
+        .create_field_index(102, INT_KEY, Some(&PayloadSchemaType::Integer.into()))
+        .unwrap();
+    indexed_segment
+        .create_field_index(102, FLT_KEY, Some(&PayloadSchemaType::Float.into()))
+        .unwrap();
+
+    let histogram = HistogramParams {
+        interval: 2.5,
+        offset: 0.0,
+        max_buckets: None,
+    };
+
+    for _ in 0..ATTEMPTS {
+        let filter = random_filter(&mut rng, 3);
+
+        for key in [INT_KEY, FLT_KEY] {
+            let plain = segment
+                .aggregate_filtered(Some(&filter), key, Some(&histogram))
+                .unwrap();
+            let indexed = indexed_segment
+                .aggregate_filtered(Some(&filter), key, Some(&histogram))
+                .unwrap();
+
+            assert_eq!(plain.count, indexed.count, "filter: {filter:#?}");
+            assert_eq!(plain.min, indexed.min);
+            assert_eq!(plain.max, indexed.max);
+            assert_eq!(plain.integer_sum, indexed.integer_sum);
+            assert!((plain.sum() - indexed.sum()).abs() < 1e-6);
+            assert_eq!(plain.histogram, indexed.histogram);
+            assert_eq!(
+                plain.histogram.values().sum::<usize>(),
+                plain.count,
+                "every value falls into a bucket"
+            );
+        }
+    }
+
+    let all = segment.aggregate_filtered(None, FLT_KEY, None).unwrap();
+    assert_eq!(all.count, NUM_POINTS);
+    assert!(all.histogram.is_empty());
+    assert!(all.min.unwrap() >= 0.0 && all.max.unwrap() < 10.0);
+
+    // Values from 0 to 10 span 11 buckets of width 1
+    let narrow = HistogramParams {
+        interval: 1.0,
+        offset: 0.0,
+        max_buckets: Some(5),
+    };
+    assert!(segment
+        .aggregate_filtered(None, FLT_KEY, Some(&narrow))
+        .is_err());
+}
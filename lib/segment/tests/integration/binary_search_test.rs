@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+use std::sync::atomic::AtomicBool;
+
+use common::types::ScoreType;
+use rand::{thread_rng, Rng};
+use rstest::rstest;
+use segment::data_types::vectors::{
+    only_default_vector, DenseVector, QueryVector, Vector, DEFAULT_VECTOR_NAME,
+};
+use segment::entry::entry_point::SegmentEntry;
+use segment::segment_constructor::build_segment;
+use segment::types::{
+    Distance, Indexes, SegmentConfig, SeqNumberType, VectorDataConfig, VectorStorageType,
+};
+use tempfile::Builder;
+
+fn random_bytes<R: Rng + ?Sized>(rnd_gen: &mut R, size: usize) -> DenseVector {
+    (0..size).map(|_| rnd_gen.gen::<u8>() as f32).collect()
+}
+
+/// Reference score computed over unpacked bytes, as returned by the segment
+fn expected_score(distance: Distance, v1: &[f32], v2: &[f32]) -> ScoreType {
+    let (mut differ, mut intersection, mut union) = (0, 0, 0);
+    for (a, b) in v1.iter().zip(v2) {
+        let (a, b) = (*a as u8, *b as u8);
+        differ += (a ^ b).count_ones();
+        intersection += (a & b).count_ones();
+        union += (a | b).count_ones();
+    }
+    match distance {
+        Distance::Hamming => -(differ as ScoreType),
+        Distance::Jaccard if union == 0 => 1.0,
+        Distance::Jaccard => intersection as ScoreType / union as ScoreType,
+        _ => unreachable!(),
+    }
+}
+
+#[rstest]
+#[case::hamming(Distance::Hamming, VectorStorageType::Memory)]
+#[case::jaccard(Distance::Jaccard, VectorStorageType::Memory)]
+#[case::hamming_mmap(Distance::Hamming, VectorStorageType::ChunkedMmap)]
+fn binary_search_test(#[case] distance: Distance, #[case] storage_type: VectorStorageType) {
+    let bits = 72;
+    let bytes = bits / 8;
+    let num_vectors: u64 = 500;
+    let top = 10;
+
+    let mut rnd = thread_rng();
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+
+    let config = SegmentConfig {
+        vector_data: HashMap::from([(
+            DEFAULT_VECTOR_NAME.to_owned(),
+            VectorDataConfig {
+                size: bits,
+                distance,
+                storage_type,
+                index: Indexes::Plain {},
+                quantization_config: None,
+            },
+        )]),
+        sparse_vector_data: Default::default(),
+        payload_storage_type: Default::default(),
+    };
+
+    let mut segment = build_segment(dir.path(), &config, true).unwrap();
+
+    let vectors: Vec<_> = (0..num_vectors)
+        .map(|_| random_bytes(&mut rnd, bytes))
+        .collect();
+    for (n, vector) in vectors.iter().enumerate() {
+        segment
+            .upsert_point(
+                n as SeqNumberType,
+                (n as u64).into(),
+                only_default_vector(vector),
+            )
+            .unwrap();
+    }
+
+    // Vectors are returned in the form they were inserted
+    let stored = segment.vector(DEFAULT_VECTOR_NAME, 42.into()).unwrap();
+    assert_eq!(stored, Some(Vector::from(vectors[42].clone())));
+
+    // Only byte values of the configured length are accepted
+    let invalid = [vec![0.0; bytes + 1], vec![256.0; bytes], vec![0.5; bytes]];
+    for vector in invalid {
+        assert!(segment
+            .upsert_point(num_vectors, 0.into(), only_default_vector(&vector))
+            .is_err());
+    }
+
+    for _ in 0..10 {
+        let query = random_bytes(&mut rnd, bytes);
+        let result = segment
+            .search(
+                DEFAULT_VECTOR_NAME,
+                &QueryVector::from(query.clone()),
+                &false.into(),
+                &false.into(),
+                None,
+                top,
+                None,
+                &AtomicBool::new(false),
+            )
+            .unwrap();
+
+        let mut expected: Vec<_> = vectors
+            .iter()
+            .map(|vector| expected_score(distance, &query, vector))
+            .collect();
+        expected.sort_by(|a, b| b.total_cmp(a));
+
+        assert_eq!(result.len(), top);
+        for (point, expected) in result.iter().zip(&expected) {
+            assert_eq!(point.score, *expected);
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use segment::data_types::vectors::only_default_vector;
+use segment::entry::entry_point::SegmentEntry;
+use segment::segment::Segment;
+use segment::types::{GeoDistance, GeoPoint, OrderBy, PayloadSchemaType, PointIdType};
+use serde_json::json;
+use tempfile::Builder;
+
+use crate::fixtures::segment::empty_segment;
+
+const GEO_KEY: &str = "location";
+
+/// Points 9, 5 and 1 share the same location, point 3 is farther away.
+/// Points are inserted in that order, so internal ids don't follow external ids.
+fn geo_segment(path: &std::path::Path, with_index: bool) -> Segment {
+    let mut segment = empty_segment(path);
+    let points = [(9u64, 13.40), (5, 13.40), (1, 13.40), (3, 13.50)];
+    for (op_num, (point_id, lon)) in points.into_iter().enumerate() {
+        let op_num = op_num as u64 * 2;
+        segment
+            .upsert_point(
+                op_num,
+                PointIdType::from(point_id),
+                only_default_vector(&[1.0, 0.0, 0.0, 0.0]),
+            )
+            .unwrap();
+        let payload = json!({ GEO_KEY: { "lon": lon, "lat": 52.52 } }).into();
+        segment
+            .set_payload(op_num + 1, PointIdType::from(point_id), &payload)
+            .unwrap();
+    }
+    if with_index {
+        segment
+            .create_field_index(100, GEO_KEY, Some(&PayloadSchemaType::Geo.into()))
+            .unwrap();
+    }
+    segment
+}
+
+/// Pages through the segment the same way a collection scroll does: the extra point of each
+/// page becomes the cursor of the next one.
+fn paginate(segment: &Segment, page_size: usize) -> Vec<PointIdType> {
+    let geo_distance = GeoDistance {
+        key: GEO_KEY.to_string(),
+        origin: GeoPoint {
+            lon: 13.40,
+            lat: 52.52,
+        },
+    };
+    let mut order_by = OrderBy {
+        geo_distance,
+        start_from: None,
+    };
+    let mut offset = None;
+    let mut scrolled = vec![];
+    loop {
+        let mut page = segment
+            .read_ordered_filtered(offset, page_size + 1, None, &order_by)
+            .unwrap();
+        let next = if page.len() > page_size {
+            page.pop()
+        } else {
+            None
+        };
+        scrolled.extend(page.into_iter().map(|(_, point_id)| point_id));
+        match next {
+            Some((distance, point_id)) => {
+                order_by.start_from = Some(distance);
+                offset = Some(point_id);
+            }
+            None => return scrolled,
+        }
+    }
+}
+
+#[test]
+fn test_geo_distance_pages_over_equal_distances() {
+    let expected: Vec<PointIdType> = [1u64, 5, 9, 3].into_iter().map(PointIdType::from).collect();
+    for with_index in [false, true] {
+        let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+        let segment = geo_segment(dir.path(), with_index);
+        for page_size in 1..=3 {
+            assert_eq!(
+                paginate(&segment, page_size),
+                expected,
+                "with_index: {with_index}, page_size: {page_size}",
+            );
+        }
+    }
+}
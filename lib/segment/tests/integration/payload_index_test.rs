@@ -27,7 +27,8 @@ use segment::types::PayloadSchemaType::{Integer, Keyword};
 use segment::types::{
     AnyVariants, Condition, Distance, FieldCondition, Filter, GeoBoundingBox, GeoLineString,
     GeoPoint, GeoPolygon, GeoRadius, Indexes, IsEmptyCondition, Match, Payload, PayloadField,
-    PayloadSchemaType, Range, SegmentConfig, VectorDataConfig, VectorStorageType, WithPayload,
+    PayloadSchemaType, Range, SegmentConfig, ValueVariants, VectorDataConfig, VectorStorageType,
+    WithPayload,
 };
 use serde_json::json;
 use tempfile::Builder;
@@ -850,3 +851,49 @@ fn test_any_matcher_cardinality_estimation() {
     assert!(exact <= estimation.max);
     assert!(exact >= estimation.min);
 }
+
+#[test]
+fn test_unindexed_filter_key() {
+    let dir1 = Builder::new().prefix("segment1_dir").tempdir().unwrap();
+    let dir2 = Builder::new().prefix("segment2_dir").tempdir().unwrap();
+    let (struct_segment, _) = build_test_segments(dir1.path(), dir2.path());
+
+    let match_str = Condition::Field(FieldCondition::new_match(
+        STR_KEY,
+        Match::new_value(ValueVariants::Keyword("red".to_string())),
+    ));
+    let range_str = Condition::Field(FieldCondition::new_range(
+        STR_KEY,
+        Range {
+            gte: Some(10.0),
+            ..Default::default()
+        },
+    ));
+    let range_int = Condition::Field(FieldCondition::new_range(
+        INT_KEY,
+        Range {
+            gte: Some(10.0),
+            ..Default::default()
+        },
+    ));
+    let is_empty_unknown = Condition::IsEmpty(IsEmptyCondition {
+        is_empty: PayloadField {
+            key: "unknown".to_string(),
+        },
+    });
+
+    let unindexed_key = |filter: Filter| struct_segment.unindexed_filter_key(&filter);
+
+    assert_eq!(unindexed_key(Filter::new_must(match_str)), None);
+    assert_eq!(unindexed_key(Filter::new_must_not(range_int)), None);
+    assert_eq!(
+        unindexed_key(Filter::new_must(range_str)),
+        Some(STR_KEY.to_string()),
+    );
+    assert_eq!(
+        unindexed_key(Filter::new_must(Condition::Filter(Filter::new_should(
+            is_empty_unknown
+        )))),
+        Some("unknown".to_string()),
+    );
+}
@@ -1,15 +1,27 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+use std::path::Path;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use itertools::Itertools;
+use rand::prelude::StdRng;
+use rand::SeedableRng;
 use segment::common::operation_error::OperationError;
-use segment::data_types::vectors::{only_default_vector, DEFAULT_VECTOR_NAME};
+use segment::data_types::vectors::{only_default_vector, QueryVector, DEFAULT_VECTOR_NAME};
 use segment::entry::entry_point::SegmentEntry;
+use segment::fixtures::payload_fixtures::random_vector;
+use segment::id_tracker::IdTracker;
+use segment::index::hnsw_index::graph_layers::GraphLayers;
+use segment::index::hnsw_index::graph_links::{GraphLinks, GraphLinksRam};
 use segment::segment::Segment;
 use segment::segment_constructor::segment_builder::SegmentBuilder;
-use segment::types::{Indexes, SegmentConfig, VectorDataConfig, VectorStorageType};
+use segment::segment_constructor::{build_segment, get_vector_index_path};
+use segment::types::{
+    Distance, ExtendedPointId, HnswConfig, Indexes, PointIdType, SearchParams, SegmentConfig,
+    SeqNumberType, VectorDataConfig, VectorStorageType, WithPayload,
+};
 use tempfile::Builder;
 
 use crate::fixtures::segment::{build_segment_1, build_segment_2, empty_segment};
@@ -186,3 +198,204 @@ fn test_building_cancellation() {
         was_cancelled_later,
     );
 }
+
+fn random_vectors_configs(dim: usize) -> (SegmentConfig, SegmentConfig) {
+    let plain_config = SegmentConfig {
+        vector_data: HashMap::from([(
+            DEFAULT_VECTOR_NAME.to_owned(),
+            VectorDataConfig {
+                size: dim,
+                distance: Distance::Cosine,
+                storage_type: VectorStorageType::Memory,
+                index: Indexes::Plain {},
+                quantization_config: None,
+            },
+        )]),
+        sparse_vector_data: Default::default(),
+        payload_storage_type: Default::default(),
+    };
+
+    let mut hnsw_config = plain_config.clone();
+    hnsw_config
+        .vector_data
+        .get_mut(DEFAULT_VECTOR_NAME)
+        .unwrap()
+        .index = Indexes::Hnsw(HnswConfig {
+        m: 16,
+        ef_construct: 64,
+        full_scan_threshold: 16, // KB
+        max_indexing_threads: 2,
+        on_disk: Some(false),
+        payload_m: None,
+    });
+
+    (plain_config, hnsw_config)
+}
+
+fn build_random_segment(
+    path: &Path,
+    config: &SegmentConfig,
+    ids: Range<u64>,
+    version: SeqNumberType,
+    rng: &mut StdRng,
+) -> Segment {
+    let dim = config.vector_data[DEFAULT_VECTOR_NAME].size;
+    let mut segment = build_segment(path, config, true).unwrap();
+    for idx in ids {
+        let vector = random_vector(rng, dim);
+        segment
+            .upsert_point(version, idx.into(), only_default_vector(&vector))
+            .unwrap();
+    }
+    segment
+}
+
+/// Compare HNSW search results with exact search, returns recall
+fn hnsw_recall(segment: &Segment, rng: &mut StdRng, top: usize) -> f64 {
+    let stopped = AtomicBool::new(false);
+    let dim = segment.segment_config.vector_data[DEFAULT_VECTOR_NAME].size;
+
+    let exact_params = SearchParams {
+        exact: true,
+        ..Default::default()
+    };
+
+    let attempts = 20;
+    let mut hits = 0;
+    for _ in 0..attempts {
+        let query: QueryVector = random_vector(rng, dim).into();
+        let search = |params: Option<&SearchParams>| {
+            segment
+                .search(
+                    DEFAULT_VECTOR_NAME,
+                    &query,
+                    &WithPayload::default(),
+                    &false.into(),
+                    None,
+                    top,
+                    params,
+                    &stopped,
+                )
+                .unwrap()
+                .into_iter()
+                .map(|point| point.id)
+                .collect_vec()
+        };
+
+        let exact_ids = search(Some(&exact_params));
+        let hnsw_ids = search(None);
+
+        hits += hnsw_ids.iter().filter(|id| exact_ids.contains(id)).count();
+    }
+
+    hits as f64 / (attempts * top) as f64
+}
+
+#[test]
+fn test_rebuilding_hnsw_segment_after_deletes() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let temp_dir = Builder::new().prefix("segment_temp_dir").tempdir().unwrap();
+
+    let stopped = AtomicBool::new(false);
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let num_points = 2_000;
+    let (plain_config, hnsw_config) = random_vectors_configs(8);
+
+    let plain_segment = build_random_segment(dir.path(), &plain_config, 0..num_points, 1, &mut rng);
+
+    let mut builder = SegmentBuilder::new(dir.path(), temp_dir.path(), &hnsw_config).unwrap();
+    builder.update_from(&plain_segment, &stopped).unwrap();
+    let mut indexed_segment = builder.build(&stopped).unwrap();
+
+    // Remove every 5th point, so that the graph has to be healed
+    for idx in (0..num_points).step_by(5) {
+        indexed_segment.delete_point(2, idx.into()).unwrap();
+    }
+
+    let mut builder = SegmentBuilder::new(dir.path(), temp_dir.path(), &hnsw_config).unwrap();
+    builder.update_from(&indexed_segment, &stopped).unwrap();
+    let rebuilt_segment = builder.build(&stopped).unwrap();
+
+    assert_eq!(
+        rebuilt_segment.available_point_count(),
+        indexed_segment.available_point_count(),
+    );
+    assert!(rebuilt_segment
+        .iter_points()
+        .all(|id| indexed_segment.has_point(id)));
+
+    let recall = hnsw_recall(&rebuilt_segment, &mut rng, 10);
+    assert!(recall > 0.9, "recall after rebuild: {recall}");
+}
+
+#[test]
+fn test_merging_into_hnsw_segment() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let temp_dir = Builder::new().prefix("segment_temp_dir").tempdir().unwrap();
+
+    let stopped = AtomicBool::new(false);
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let (plain_config, hnsw_config) = random_vectors_configs(8);
+
+    let plain_segment = build_random_segment(dir.path(), &plain_config, 0..2_000, 1, &mut rng);
+
+    let mut builder = SegmentBuilder::new(dir.path(), temp_dir.path(), &hnsw_config).unwrap();
+    builder.update_from(&plain_segment, &stopped).unwrap();
+    let indexed_segment = builder.build(&stopped).unwrap();
+
+    // Small segment with new points, overlapping with the indexed one by newer versions
+    let small_segment = build_random_segment(dir.path(), &plain_config, 1_900..2_300, 2, &mut rng);
+
+    let mut builder = SegmentBuilder::new(dir.path(), temp_dir.path(), &hnsw_config).unwrap();
+    builder.update_from(&small_segment, &stopped).unwrap();
+    builder.update_from(&indexed_segment, &stopped).unwrap();
+    let merged_segment = builder.build(&stopped).unwrap();
+
+    assert_eq!(merged_segment.available_point_count(), 2_300);
+    for idx in 1_900..2_300 {
+        assert_eq!(merged_segment.point_version(idx.into()), Some(2));
+    }
+
+    let recall = hnsw_recall(&merged_segment, &mut rng, 10);
+    assert!(recall > 0.9, "recall after merge: {recall}");
+
+    // Graph of the indexed segment is carried over: points, which are not updated, keep most of
+    // the links between each other
+    let is_kept = |id: &PointIdType| matches!(id, ExtendedPointId::NumId(id) if *id < 1_900);
+    let old_links = level_0_links(&indexed_segment);
+    let merged_links = level_0_links(&merged_segment);
+    let (mut total, mut kept) = (0, 0);
+    for (point_id, links) in old_links.iter().filter(|(id, _)| is_kept(id)) {
+        for link in links.iter().filter(|link| is_kept(link)) {
+            total += 1;
+            if merged_links[point_id].contains(link) {
+                kept += 1;
+            }
+        }
+    }
+    let kept_ratio = kept as f64 / total as f64;
+    assert!(kept_ratio > 0.8, "kept links ratio: {kept_ratio}");
+}
+
+/// Read links of the HNSW graph on level 0 by external ids
+fn level_0_links(segment: &Segment) -> HashMap<PointIdType, HashSet<PointIdType>> {
+    let index_path = get_vector_index_path(&segment.current_path, DEFAULT_VECTOR_NAME);
+    let links =
+        GraphLinksRam::load_from_file(&GraphLayers::<GraphLinksRam>::get_links_path(&index_path))
+            .unwrap();
+
+    let id_tracker = segment.id_tracker.borrow();
+    id_tracker
+        .iter_ids()
+        .map(|internal_id| {
+            let external_links = links
+                .links(internal_id, 0)
+                .iter()
+                .filter_map(|&link| id_tracker.external_id(link))
+                .collect();
+            (id_tracker.external_id(internal_id).unwrap(), external_links)
+        })
+        .collect()
+}
@@ -121,6 +121,7 @@ fn sparse_index_discover_test() {
                     full_scan_threshold: Some(DEFAULT_SPARSE_FULL_SCAN_THRESHOLD),
                     index_type: SparseIndexType::MutableRam,
                 },
+                datatype: Default::default(),
             },
         )]),
         payload_storage_type: Default::default(),
@@ -146,15 +146,17 @@ fn check_index_storage_consistency<T: InvertedIndex>(sparse_vector_index: &Spars
             .iter()
             .zip(remapped_vector.values.iter())
         {
-            let posting_list = sparse_vector_index.inverted_index.get(dim_id).unwrap();
+            let posting_list: Vec<_> = sparse_vector_index
+                .inverted_index
+                .get(dim_id)
+                .unwrap()
+                .collect();
             // assert posting list sorted by record id
             assert!(posting_list
-                .elements
                 .windows(2)
                 .all(|w| w[0].record_id < w[1].record_id));
             // assert posted list contains record id
             assert!(posting_list
-                .elements
                 .iter()
                 .any(|e| e.record_id == id && e.weight == *dim_value));
         }
@@ -540,6 +542,7 @@ fn sparse_vector_index_persistence_test() {
                     full_scan_threshold: Some(DEFAULT_SPARSE_FULL_SCAN_THRESHOLD),
                     index_type: SparseIndexType::MutableRam,
                 },
+                datatype: Default::default(),
             },
         )]),
         payload_storage_type: Default::default(),
@@ -789,6 +792,7 @@ fn sparse_vector_test_large_index() {
                     full_scan_threshold: Some(DEFAULT_SPARSE_FULL_SCAN_THRESHOLD),
                     index_type: SparseIndexType::MutableRam,
                 },
+                datatype: Default::default(),
             },
         )]),
         payload_storage_type: Default::default(),
@@ -0,0 +1,150 @@
+use segment::data_types::text_index::TextStats;
+use segment::data_types::vectors::only_default_vector;
+use segment::entry::entry_point::SegmentEntry;
+use segment::segment::Segment;
+use segment::types::{
+    Condition, FieldCondition, Filter, Match, PayloadSchemaType, PointIdType, ValueVariants,
+    WithPayload, WithVector,
+};
+use serde_json::json;
+use tempfile::Builder;
+
+use crate::fixtures::segment::empty_segment;
+
+const TEXT_KEY: &str = "text";
+
+const TEXTS: [&str; 6] = [
+    "robots and robots and more robots",
+    "the robot rebellion of the robots",
+    "a long story about humans, space, stars and a single robots factory",
+    "humans only",
+    "robots build robots",
+    "nothing relevant here",
+];
+
+fn text_segment(path: &std::path::Path, point_ids: &[u64]) -> Segment {
+    let mut segment = empty_segment(path);
+    for &point_id in point_ids {
+        // Operations on new points must not be older than the segment
+        let op_num = point_id * 2;
+        segment
+            .upsert_point(
+                op_num,
+                point_id.into(),
+                only_default_vector(&[1.0, 0.0, 0.0, 0.0]),
+            )
+            .unwrap();
+        let payload = json!({
+            TEXT_KEY: TEXTS[point_id as usize],
+            "even": point_id % 2 == 0,
+        })
+        .into();
+        segment
+            .set_payload(op_num + 1, point_id.into(), &payload)
+            .unwrap();
+    }
+    segment
+        .create_field_index(100, TEXT_KEY, Some(&PayloadSchemaType::Text.into()))
+        .unwrap();
+    segment
+}
+
+fn search(segments: &[&Segment], query: &str, filter: Option<&Filter>) -> Vec<(PointIdType, f32)> {
+    let mut stats = TextStats::default();
+    for segment in segments {
+        stats.merge(segment.text_stats(TEXT_KEY, query).unwrap());
+    }
+    let mut points: Vec<_> = segments
+        .iter()
+        .flat_map(|segment| {
+            segment
+                .search_text(
+                    TEXT_KEY,
+                    query,
+                    &stats,
+                    filter,
+                    10,
+                    &WithPayload::from(false),
+                    &WithVector::Bool(false),
+                )
+                .unwrap()
+        })
+        .collect();
+    points.sort_by(|a, b| b.cmp(a));
+    points
+        .into_iter()
+        .map(|point| (point.id, point.score))
+        .collect()
+}
+
+fn ids(point_ids: &[u64]) -> Vec<PointIdType> {
+    point_ids.iter().map(|&id| id.into()).collect()
+}
+
+#[test]
+fn test_text_search_merged_stats() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let dir1 = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let dir2 = Builder::new().prefix("segment_dir").tempdir().unwrap();
+
+    let whole = text_segment(dir.path(), &[0, 1, 2, 3, 4, 5]);
+    let part1 = text_segment(dir1.path(), &[0, 2, 4]);
+    let part2 = text_segment(dir2.path(), &[1, 3, 5]);
+
+    // Scores with statistics merged across segments are the same as of a single segment
+    for query in ["robots", "robot rebellion", "humans and robots", "unknown"] {
+        let expected = search(&[&whole], query, None);
+        let merged = search(&[&part1, &part2], query, None);
+        assert_eq!(expected.len(), merged.len(), "query: {query}");
+        for ((expected_id, expected_score), (id, score)) in expected.into_iter().zip(merged) {
+            assert_eq!(expected_id, id, "query: {query}");
+            assert!((expected_score - score).abs() < 1e-5, "query: {query}");
+        }
+    }
+
+    let ranked: Vec<_> = search(&[&whole], "robots", None)
+        .into_iter()
+        .map(|(id, _)| id)
+        .collect();
+    assert_eq!(ranked, ids(&[4, 0, 1, 2]));
+
+    // Filter restricts the scored points
+    let filter = Filter::new_must(Condition::Field(FieldCondition::new_match(
+        "even",
+        Match::new_value(ValueVariants::Bool(false)),
+    )));
+    let filtered: Vec<_> = search(&[&whole], "robots", Some(&filter))
+        .into_iter()
+        .map(|(id, _)| id)
+        .collect();
+    assert_eq!(filtered, ids(&[1]));
+}
+
+#[test]
+fn test_text_search_skips_deleted_and_unindexed() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let mut segment = text_segment(dir.path(), &[0, 1, 2, 3, 4, 5]);
+
+    segment.delete_point(101, 4.into()).unwrap();
+    let ranked: Vec<_> = search(&[&segment], "robots", None)
+        .into_iter()
+        .map(|(id, _)| id)
+        .collect();
+    assert_eq!(ranked, ids(&[0, 1, 2]));
+
+    // Fields without a full-text index have no matches
+    let stats = segment.text_stats("even", "robots").unwrap();
+    assert_eq!(stats, TextStats::default());
+    let points = segment
+        .search_text(
+            "even",
+            "robots",
+            &stats,
+            None,
+            10,
+            &WithPayload::from(false),
+            &WithVector::Bool(false),
+        )
+        .unwrap();
+    assert!(points.is_empty());
+}
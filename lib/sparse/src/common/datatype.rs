@@ -0,0 +1,288 @@
+use std::collections::HashMap;
+
+use half::f16;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::common::types::{DimId, DimWeight};
+
+/// Datatype of stored sparse vector weights
+#[derive(Debug, Default, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum SparseVectorDatatype {
+    /// Full precision, 4 bytes per weight
+    #[default]
+    Float32,
+    /// Half precision, 2 bytes per weight
+    Float16,
+    /// Linear quantization with per-dimension params, 1 byte per weight.
+    /// Weights are kept in half precision until the segment gets an immutable index
+    Uint8,
+}
+
+/// Sparse vector weights encoded with a [`SparseVectorDatatype`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum EncodedWeights {
+    Float32(Vec<DimWeight>),
+    Float16(Vec<u16>),
+    /// Weights quantized with the [`QuantizationParams`] of the storage
+    Uint8(Vec<u8>),
+}
+
+impl SparseVectorDatatype {
+    /// Encode weights without quantization params.
+    ///
+    /// `Uint8` weights are encoded in half precision, see [`QuantizationParams::encode`].
+    pub fn encode(self, weights: &[DimWeight]) -> EncodedWeights {
+        match self {
+            SparseVectorDatatype::Float32 => EncodedWeights::Float32(weights.to_vec()),
+            SparseVectorDatatype::Float16 | SparseVectorDatatype::Uint8 => EncodedWeights::Float16(
+                weights
+                    .iter()
+                    .map(|weight| f16::from_f32(*weight).to_bits())
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Round weights to the closest values representable by [`SparseVectorDatatype::encode`].
+    ///
+    /// Rounded weights are encoded without any further loss.
+    pub fn round(self, weights: &mut [DimWeight]) {
+        if self == SparseVectorDatatype::Float32 {
+            return;
+        }
+        let decoded = self
+            .encode(weights)
+            .decode(&[], None)
+            .expect("weights are not quantized");
+        weights.copy_from_slice(&decoded);
+    }
+}
+
+impl EncodedWeights {
+    /// Decode weights of the given dimensions.
+    ///
+    /// Returns `None` for quantized weights without quantization params.
+    pub fn decode(
+        &self,
+        indices: &[DimId],
+        params: Option<&QuantizationParams>,
+    ) -> Option<Vec<DimWeight>> {
+        match self {
+            EncodedWeights::Float32(weights) => Some(weights.clone()),
+            EncodedWeights::Float16(weights) => Some(
+                weights
+                    .iter()
+                    .map(|bits| f16::from_bits(*bits).to_f32())
+                    .collect(),
+            ),
+            EncodedWeights::Uint8(values) => params?.decode(indices, values),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        match self {
+            EncodedWeights::Float32(weights) => weights.len(),
+            EncodedWeights::Float16(weights) => weights.len(),
+            EncodedWeights::Uint8(values) => values.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Linear quantization of the weights of a single dimension into `u8`
+///
+/// Step is a power of two and offset is a multiple of the step,
+/// so decoded weights are exactly representable and encoding them again is lossless.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DimQuantization {
+    pub offset: DimWeight,
+    pub step: DimWeight,
+}
+
+impl DimQuantization {
+    /// Quantization covering all weights between `min` and `max`
+    fn new(min: DimWeight, max: DimWeight) -> Self {
+        let range = max - min;
+        if range == 0.0 {
+            return Self {
+                offset: min,
+                step: 0.0,
+            };
+        }
+        // One level is reserved, as the offset is up to one step below the min weight
+        let step = 2f32.powi((range / (u8::MAX - 1) as DimWeight).log2().ceil() as i32);
+        let offset = (min / step).floor() * step;
+        Self { offset, step }
+    }
+
+    /// Quantized weight, `None` if the weight is out of the quantized range
+    fn encode(&self, weight: DimWeight) -> Option<u8> {
+        if self.step == 0.0 {
+            return (weight == self.offset).then_some(0);
+        }
+        let value = ((weight - self.offset) / self.step).round();
+        (0.0..=u8::MAX as DimWeight)
+            .contains(&value)
+            .then_some(value as u8)
+    }
+
+    fn decode(&self, value: u8) -> DimWeight {
+        self.offset + value as DimWeight * self.step
+    }
+}
+
+/// Per-dimension quantization params of the `Uint8` datatype
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuantizationParams {
+    dims: HashMap<DimId, DimQuantization>,
+}
+
+impl QuantizationParams {
+    /// Quantize weights of the given dimensions.
+    ///
+    /// Returns `None` if any dimension is unknown or any weight is out of its quantized range.
+    pub fn encode(&self, indices: &[DimId], weights: &[DimWeight]) -> Option<EncodedWeights> {
+        indices
+            .iter()
+            .zip(weights)
+            .map(|(dim_id, weight)| self.dims.get(dim_id)?.encode(*weight))
+            .collect::<Option<_>>()
+            .map(EncodedWeights::Uint8)
+    }
+
+    fn decode(&self, indices: &[DimId], values: &[u8]) -> Option<Vec<DimWeight>> {
+        indices
+            .iter()
+            .zip(values)
+            .map(|(dim_id, value)| Some(self.dims.get(dim_id)?.decode(*value)))
+            .collect()
+    }
+}
+
+/// Collects weight ranges of all dimensions to build [`QuantizationParams`]
+#[derive(Debug, Default)]
+pub struct QuantizationParamsBuilder {
+    ranges: HashMap<DimId, (DimWeight, DimWeight)>,
+}
+
+impl QuantizationParamsBuilder {
+    pub fn update(&mut self, indices: &[DimId], weights: &[DimWeight]) {
+        for (dim_id, weight) in indices.iter().zip(weights) {
+            let (min, max) = self
+                .ranges
+                .entry(*dim_id)
+                .or_insert((DimWeight::INFINITY, DimWeight::NEG_INFINITY));
+            *min = min.min(*weight);
+            *max = max.max(*weight);
+        }
+    }
+
+    pub fn build(self) -> QuantizationParams {
+        QuantizationParams {
+            dims: self
+                .ranges
+                .into_iter()
+                .map(|(dim_id, (min, max))| (dim_id, DimQuantization::new(min, max)))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    use super::*;
+
+    fn quantize(indices: &[DimId], weights: &[DimWeight]) -> (QuantizationParams, Vec<DimWeight>) {
+        let mut builder = QuantizationParamsBuilder::default();
+        builder.update(indices, weights);
+        let params = builder.build();
+        let decoded = params
+            .encode(indices, weights)
+            .unwrap()
+            .decode(indices, Some(&params))
+            .unwrap();
+        (params, decoded)
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let weights: Vec<DimWeight> = (0..100).map(|_| rng.gen_range(-5.0..20.0)).collect();
+
+        let decoded = SparseVectorDatatype::Float32
+            .encode(&weights)
+            .decode(&[], None)
+            .unwrap();
+        assert_eq!(decoded, weights);
+
+        let decoded = SparseVectorDatatype::Float16
+            .encode(&weights)
+            .decode(&[], None)
+            .unwrap();
+        for (weight, decoded) in weights.iter().zip(&decoded) {
+            assert!((weight - decoded).abs() <= weight.abs() / 1000.0 + f32::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_encode_decode_u8_per_dimension() {
+        let mut rng = StdRng::seed_from_u64(42);
+        // dimensions with very different weight ranges
+        let mut indices = vec![];
+        let mut weights = vec![];
+        for _ in 0..100 {
+            indices.push(0);
+            weights.push(rng.gen_range(-5.0..20.0));
+            indices.push(1);
+            weights.push(rng.gen_range(0.0..0.01));
+        }
+
+        let (params, decoded) = quantize(&indices, &weights);
+        for ((dim_id, weight), decoded) in indices.iter().zip(&weights).zip(&decoded) {
+            let step = params.dims[dim_id].step;
+            assert!(step <= 2.0 * (if *dim_id == 0 { 25.0 } else { 0.01 }) / 254.0);
+            assert!((weight - decoded).abs() <= step / 2.0);
+        }
+
+        // quantized weights are lossless to encode
+        let (_, decoded_again) = quantize(&indices, &decoded);
+        assert_eq!(decoded_again, decoded);
+
+        // unknown dimensions and weights out of range are not quantized
+        assert!(params.encode(&[2], &[1.0]).is_none());
+        assert!(params.encode(&[1], &[1.0]).is_none());
+        assert!(EncodedWeights::Uint8(vec![0]).decode(&[0], None).is_none());
+    }
+
+    #[test]
+    fn test_round_is_lossless_to_encode() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for datatype in [SparseVectorDatatype::Float16, SparseVectorDatatype::Uint8] {
+            for _ in 0..100 {
+                let mut weights: Vec<DimWeight> =
+                    (0..50).map(|_| rng.gen_range(0.0..100.0)).collect();
+                datatype.round(&mut weights);
+                let decoded = datatype.encode(&weights).decode(&[], None).unwrap();
+                assert_eq!(decoded, weights);
+            }
+        }
+    }
+
+    #[test]
+    fn test_encode_u8_constant() {
+        let (params, decoded) = quantize(&[3, 3], &[1.5, 1.5]);
+        assert_eq!(decoded, vec![1.5, 1.5]);
+        assert!(params.encode(&[3], &[2.0]).is_none());
+
+        let (_, decoded) = quantize(&[], &[]);
+        assert!(decoded.is_empty());
+    }
+}
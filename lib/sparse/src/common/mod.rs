@@ -1,3 +1,4 @@
+pub mod datatype;
 pub mod sparse_vector;
 pub mod sparse_vector_fixture;
 pub mod types;
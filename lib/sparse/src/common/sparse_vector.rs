@@ -1,4 +1,5 @@
 use itertools::Itertools;
+use ordered_float::OrderedFloat;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use validator::{Validate, ValidationError, ValidationErrors};
@@ -55,6 +56,38 @@ impl SparseVector {
         self.indices.is_empty() && self.values.is_empty()
     }
 
+    /// Remove dimensions with absolute weight below the threshold.
+    pub fn prune(&mut self, threshold: DimWeight) {
+        if self.values.iter().all(|value| value.abs() >= threshold) {
+            return;
+        }
+        let (indices, values) = self
+            .indices
+            .iter()
+            .zip(&self.values)
+            .filter(|(_, value)| value.abs() >= threshold)
+            .map(|(&i, &v)| (i, v))
+            .unzip();
+        self.indices = indices;
+        self.values = values;
+    }
+
+    /// Keep only `k` dimensions with the largest absolute weights.
+    ///
+    /// Order of the remaining dimensions is preserved.
+    pub fn keep_top_k(&mut self, k: usize) {
+        if self.indices.len() <= k {
+            return;
+        }
+        let mut positions: Vec<usize> = (0..self.indices.len()).collect();
+        positions.sort_by_key(|&pos| std::cmp::Reverse(OrderedFloat(self.values[pos].abs())));
+        positions.truncate(k);
+        positions.sort_unstable();
+
+        self.indices = positions.iter().map(|&pos| self.indices[pos]).collect();
+        self.values = positions.iter().map(|&pos| self.values[pos]).collect();
+    }
+
     /// Score this vector against another vector using dot product.
     /// Warning: Expects both vectors to be sorted by indices.
     ///
@@ -240,6 +273,28 @@ mod tests {
         assert!(not_sorted.is_sorted());
     }
 
+    #[test]
+    fn prune_test() {
+        let mut vector = SparseVector::new(vec![1, 5, 3, 2], vec![0.1, -2.0, 0.05, 0.5]).unwrap();
+        vector.prune(0.1);
+        assert_eq!(vector.indices, vec![1, 5, 2]);
+        assert_eq!(vector.values, vec![0.1, -2.0, 0.5]);
+    }
+
+    #[test]
+    fn keep_top_k_test() {
+        let mut vector = SparseVector::new(vec![1, 5, 3, 2], vec![0.1, -2.0, 0.05, 0.5]).unwrap();
+        vector.keep_top_k(4);
+        assert_eq!(vector.indices.len(), 4);
+
+        vector.keep_top_k(2);
+        assert_eq!(vector.indices, vec![5, 2]);
+        assert_eq!(vector.values, vec![-2.0, 0.5]);
+
+        vector.keep_top_k(0);
+        assert!(vector.is_empty());
+    }
+
     #[test]
     fn combine_aggregate_test() {
         let a = SparseVector::new(vec![1, 2, 3], vec![0.1, 0.2, 0.3]).unwrap();
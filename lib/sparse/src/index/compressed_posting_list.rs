@@ -0,0 +1,312 @@
+//! Compressed representation of posting lists.
+//!
+//! Posting list is split into blocks of [`BLOCK_SIZE`] elements.
+//! Record ids of a block are stored as bit-packed deltas between consecutive ids,
+//! weights are quantized into `u8` using the min and max weight of the whole posting list.
+//! Each block keeps the max weight of its elements, so whole blocks can be skipped during search.
+
+use std::mem::size_of;
+
+use common::types::PointOffsetType;
+
+use crate::common::types::DimWeight;
+use crate::index::posting_list::PostingElement;
+
+/// Number of posting elements in a compressed block
+pub const BLOCK_SIZE: usize = 128;
+
+/// Header of a block of compressed posting list elements
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct CompressedBlock {
+    /// Record ID of the first element in the block
+    pub first_id: PointOffsetType,
+    /// Record ID of the last element in the block
+    pub last_id: PointOffsetType,
+    /// Offset of the packed id deltas of the block in the ids data of the posting list
+    pub ids_offset: u32,
+    /// Number of bits used for each packed id delta
+    pub bits: u32,
+    /// Max weight of the elements in the block
+    pub max_weight: DimWeight,
+    /// Max weight of the elements in the next blocks of the posting list
+    pub max_next_weight: DimWeight,
+}
+
+/// Linear quantization of posting list weights into `u8`
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct WeightQuantization {
+    pub min: DimWeight,
+    pub step: DimWeight,
+}
+
+impl WeightQuantization {
+    pub fn new(elements: &[PostingElement]) -> Self {
+        let (min, max) = elements
+            .iter()
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), element| {
+                (min.min(element.weight), max.max(element.weight))
+            });
+        if elements.is_empty() {
+            return Self::default();
+        }
+        Self {
+            min,
+            step: (max - min) / u8::MAX as DimWeight,
+        }
+    }
+
+    pub fn quantize(&self, weight: DimWeight) -> u8 {
+        if self.step == 0.0 {
+            return 0;
+        }
+        ((weight - self.min) / self.step)
+            .round()
+            .clamp(0.0, u8::MAX as DimWeight) as u8
+    }
+
+    pub fn dequantize(&self, value: u8) -> DimWeight {
+        self.min + value as DimWeight * self.step
+    }
+}
+
+/// Owned compressed posting list
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CompressedPostingList {
+    pub quantization: WeightQuantization,
+    pub blocks: Vec<CompressedBlock>,
+    /// Quantized weights of all elements
+    pub weights: Vec<u8>,
+    /// Bit-packed id deltas of all blocks
+    pub ids: Vec<u8>,
+}
+
+impl CompressedPostingList {
+    /// Compress posting list elements ordered by id
+    pub fn from_elements(elements: &[PostingElement]) -> Self {
+        let quantization = WeightQuantization::new(elements);
+        let mut blocks = Vec::with_capacity(elements.len().div_ceil(BLOCK_SIZE));
+        let mut weights = Vec::with_capacity(elements.len());
+        let mut ids = Vec::new();
+
+        for chunk in elements.chunks(BLOCK_SIZE) {
+            // ids are strictly increasing, so the deltas are stored minus one
+            let deltas = chunk
+                .windows(2)
+                .map(|pair| pair[1].record_id - pair[0].record_id - 1);
+            let bits = deltas
+                .clone()
+                .max()
+                .map_or(0, |max_delta| u32::BITS - max_delta.leading_zeros());
+            let ids_offset = ids.len() as u32;
+            pack_bits(deltas, bits, &mut ids);
+
+            // block max is computed over the dequantized weights to remain an upper bound of decoded elements
+            let mut max_weight = f32::NEG_INFINITY;
+            for element in chunk {
+                let quantized = quantization.quantize(element.weight);
+                max_weight = max_weight.max(quantization.dequantize(quantized));
+                weights.push(quantized);
+            }
+
+            blocks.push(CompressedBlock {
+                first_id: chunk[0].record_id,
+                last_id: chunk[chunk.len() - 1].record_id,
+                ids_offset,
+                bits,
+                max_weight,
+                max_next_weight: f32::NEG_INFINITY,
+            });
+        }
+
+        let mut max_next_weight = f32::NEG_INFINITY;
+        for block in blocks.iter_mut().rev() {
+            block.max_next_weight = max_next_weight;
+            max_next_weight = max_next_weight.max(block.max_weight);
+        }
+
+        Self {
+            quantization,
+            blocks,
+            weights,
+            ids,
+        }
+    }
+
+    pub fn view(&self) -> CompressedPostingListView<'_> {
+        CompressedPostingListView {
+            quantization: self.quantization,
+            blocks: &self.blocks,
+            weights: &self.weights,
+            ids: &self.ids,
+        }
+    }
+}
+
+/// Borrowed compressed posting list, e.g. from a memory mapped file
+#[derive(Debug, Clone, Copy)]
+pub struct CompressedPostingListView<'a> {
+    pub quantization: WeightQuantization,
+    pub blocks: &'a [CompressedBlock],
+    pub weights: &'a [u8],
+    pub ids: &'a [u8],
+}
+
+impl CompressedPostingListView<'_> {
+    /// Number of elements in the posting list
+    pub fn len(&self) -> usize {
+        self.weights.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.weights.is_empty()
+    }
+
+    /// Decode elements of the block into the buffer
+    ///
+    /// `max_next_weight` of the decoded elements is exact within the block
+    /// and takes the max weight of the next blocks into account.
+    pub fn decode_block(&self, block_index: usize, buffer: &mut Vec<PostingElement>) {
+        let block = &self.blocks[block_index];
+        let start = block_index * BLOCK_SIZE;
+        let end = (start + BLOCK_SIZE).min(self.len());
+        let ids = &self.ids[block.ids_offset as usize..];
+
+        buffer.clear();
+        let mut record_id = block.first_id;
+        for (i, weight) in self.weights[start..end].iter().enumerate() {
+            if i > 0 {
+                record_id += read_bits(ids, (i - 1) * block.bits as usize, block.bits) + 1;
+            }
+            buffer.push(PostingElement::new(
+                record_id,
+                self.quantization.dequantize(*weight),
+            ));
+        }
+
+        let mut max_next_weight = block.max_next_weight;
+        for element in buffer.iter_mut().rev() {
+            element.max_next_weight = max_next_weight;
+            max_next_weight = max_next_weight.max(element.weight);
+        }
+    }
+
+    /// Decode all elements of the posting list
+    pub fn to_elements(&self) -> Vec<PostingElement> {
+        let mut elements = Vec::with_capacity(self.len());
+        let mut buffer = Vec::with_capacity(BLOCK_SIZE);
+        for block_index in 0..self.blocks.len() {
+            self.decode_block(block_index, &mut buffer);
+            elements.extend_from_slice(&buffer);
+        }
+        elements
+    }
+}
+
+/// Append values to the little-endian bit stream using `bits` bits for each value
+fn pack_bits(values: impl Iterator<Item = u32>, bits: u32, out: &mut Vec<u8>) {
+    let mut buffer: u64 = 0;
+    let mut buffered_bits = 0;
+    for value in values {
+        buffer |= (value as u64) << buffered_bits;
+        buffered_bits += bits;
+        while buffered_bits >= 8 {
+            out.push(buffer as u8);
+            buffer >>= 8;
+            buffered_bits -= 8;
+        }
+    }
+    if buffered_bits > 0 {
+        out.push(buffer as u8);
+    }
+}
+
+/// Read `bits` bits starting at the bit `position` of the little-endian bit stream
+fn read_bits(data: &[u8], position: usize, bits: u32) -> u32 {
+    if bits == 0 {
+        return 0;
+    }
+    let start = position / 8;
+    let end = (start + size_of::<u64>()).min(data.len());
+    let mut bytes = [0u8; size_of::<u64>()];
+    bytes[..end - start].copy_from_slice(&data[start..end]);
+    let value = u64::from_le_bytes(bytes) >> (position % 8);
+    (value & ((1u64 << bits) - 1)) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    use super::*;
+    use crate::index::posting_list::PostingList;
+
+    #[test]
+    fn test_bit_packing() {
+        let values = [0, 1, 5, 1023, 7, 0, 512];
+        let mut data = Vec::new();
+        pack_bits(values.iter().copied(), 10, &mut data);
+        assert_eq!(data.len(), (values.len() * 10).div_ceil(8));
+        for (i, value) in values.iter().enumerate() {
+            assert_eq!(read_bits(&data, i * 10, 10), *value);
+        }
+
+        let mut data = Vec::new();
+        pack_bits([u32::MAX, 1].into_iter(), 32, &mut data);
+        assert_eq!(read_bits(&data, 0, 32), u32::MAX);
+        assert_eq!(read_bits(&data, 32, 32), 1);
+    }
+
+    #[test]
+    fn test_compressed_posting_list() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut record_id = 0;
+        let records = (0..1000)
+            .map(|_| {
+                record_id += rng.gen_range(1..100);
+                (record_id, rng.gen_range(-1.0..10.0))
+            })
+            .collect();
+        let posting_list = PostingList::from(records);
+
+        let compressed = CompressedPostingList::from_elements(&posting_list.elements);
+        assert_eq!(compressed.blocks.len(), 1000_usize.div_ceil(BLOCK_SIZE));
+        // uncompressed posting list takes 12 bytes per element
+        assert!(compressed.ids.len() + compressed.weights.len() < 1000 * 2);
+
+        let decoded = compressed.view().to_elements();
+        assert_eq!(decoded.len(), posting_list.elements.len());
+        let max_error = compressed.quantization.step / 2.0 + f32::EPSILON * 10.0;
+        for (original, decoded) in posting_list.elements.iter().zip(&decoded) {
+            assert_eq!(original.record_id, decoded.record_id);
+            assert!((original.weight - decoded.weight).abs() <= max_error);
+        }
+
+        // max next weight of decoded elements is exact
+        let mut max_next_weight = f32::NEG_INFINITY;
+        for element in decoded.iter().rev() {
+            assert_eq!(element.max_next_weight, max_next_weight);
+            max_next_weight = max_next_weight.max(element.weight);
+        }
+
+        // block max weights are upper bounds of the block elements
+        for (block, elements) in compressed.blocks.iter().zip(decoded.chunks(BLOCK_SIZE)) {
+            assert_eq!(block.first_id, elements[0].record_id);
+            assert_eq!(block.last_id, elements[elements.len() - 1].record_id);
+            assert!(elements.iter().all(|e| e.weight <= block.max_weight));
+        }
+    }
+
+    #[test]
+    fn test_compressed_posting_list_single_weight() {
+        let posting_list = PostingList::from(vec![(1, 2.0), (2, 2.0), (u32::MAX, 2.0)]);
+        let compressed = CompressedPostingList::from_elements(&posting_list.elements);
+        assert_eq!(compressed.view().to_elements(), posting_list.elements);
+
+        let empty = CompressedPostingList::from_elements(&[]);
+        assert!(empty.view().is_empty());
+        assert!(empty.view().to_elements().is_empty());
+    }
+}
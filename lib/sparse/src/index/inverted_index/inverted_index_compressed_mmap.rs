@@ -0,0 +1,313 @@
+use std::mem::size_of;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use common::types::PointOffsetType;
+use io::file_operations::{atomic_save_json, read_json};
+use memmap2::{Mmap, MmapMut};
+use memory::madvise;
+use memory::mmap_ops::{
+    create_and_ensure_length, open_read_mmap, open_write_mmap, transmute_from_u8,
+    transmute_from_u8_to_slice, transmute_to_u8, transmute_to_u8_slice,
+};
+
+use super::inverted_index_mmap::InvertedIndexFileHeader;
+use crate::common::sparse_vector::SparseVector;
+use crate::common::types::DimId;
+use crate::index::compressed_posting_list::{
+    CompressedBlock, CompressedPostingList, CompressedPostingListView, WeightQuantization,
+};
+use crate::index::inverted_index::inverted_index_ram::InvertedIndexRam;
+use crate::index::inverted_index::InvertedIndex;
+use crate::index::posting_list::PostingListIterator;
+
+const POSTING_HEADER_SIZE: usize = size_of::<CompressedPostingListFileHeader>();
+const INDEX_FILE_NAME: &str = "inverted_index_compressed.data";
+const INDEX_CONFIG_FILE_NAME: &str = "inverted_index_compressed_config.json";
+/// Alignment of the posting lists data, required to read the block headers in place
+const POSTING_ALIGNMENT: usize = std::mem::align_of::<CompressedBlock>();
+
+/// Inverted flatten index from dimension id to compressed posting list
+///
+/// Each posting list is stored as block headers, followed by the quantized weights
+/// and the bit-packed id deltas of its elements.
+pub struct InvertedIndexCompressedMmap {
+    path: PathBuf,
+    mmap: Arc<Mmap>,
+    pub file_header: InvertedIndexFileHeader,
+}
+
+#[derive(Debug, Default, Clone)]
+#[repr(C)]
+struct CompressedPostingListFileHeader {
+    pub start_offset: u64,
+    /// Size of the bit-packed id deltas in bytes
+    pub ids_len: u64,
+    /// Number of elements
+    pub len: u32,
+    /// Number of blocks
+    pub blocks_count: u32,
+    pub quantization: WeightQuantization,
+}
+
+impl InvertedIndex for InvertedIndexCompressedMmap {
+    fn open(path: &Path) -> std::io::Result<Self> {
+        Self::load(path)
+    }
+
+    fn save(&self, path: &Path) -> std::io::Result<()> {
+        debug_assert_eq!(path, self.path);
+        Ok(())
+    }
+
+    fn get(&self, id: &DimId) -> Option<PostingListIterator> {
+        self.get(id).map(PostingListIterator::new_compressed)
+    }
+
+    fn files(path: &Path) -> Vec<PathBuf> {
+        vec![
+            Self::index_file_path(path),
+            Self::index_config_file_path(path),
+        ]
+    }
+
+    fn upsert(&mut self, _id: PointOffsetType, _vector: SparseVector) {
+        panic!("Cannot upsert into a read-only compressed Mmap inverted index")
+    }
+
+    fn from_ram_index<P: AsRef<Path>>(
+        ram_index: InvertedIndexRam,
+        path: P,
+    ) -> std::io::Result<Self> {
+        Self::convert_and_save(&ram_index, path)
+    }
+
+    fn vector_count(&self) -> usize {
+        self.file_header.vector_count
+    }
+
+    fn max_index(&self) -> Option<DimId> {
+        match self.file_header.posting_count {
+            0 => None,
+            len => Some(len as DimId - 1),
+        }
+    }
+}
+
+impl InvertedIndexCompressedMmap {
+    pub fn index_file_path(path: &Path) -> PathBuf {
+        path.join(INDEX_FILE_NAME)
+    }
+
+    pub fn index_config_file_path(path: &Path) -> PathBuf {
+        path.join(INDEX_CONFIG_FILE_NAME)
+    }
+
+    pub fn get(&self, id: &DimId) -> Option<CompressedPostingListView> {
+        // check that the id is not out of bounds (posting_count includes the empty zeroth entry)
+        if *id >= self.file_header.posting_count as DimId {
+            return None;
+        }
+        let header_start = *id as usize * POSTING_HEADER_SIZE;
+        let header = transmute_from_u8::<CompressedPostingListFileHeader>(
+            &self.mmap[header_start..header_start + POSTING_HEADER_SIZE],
+        )
+        .clone();
+
+        let blocks_start = header.start_offset as usize;
+        let weights_start =
+            blocks_start + header.blocks_count as usize * size_of::<CompressedBlock>();
+        let ids_start = weights_start + header.len as usize;
+        let ids_end = ids_start + header.ids_len as usize;
+        Some(CompressedPostingListView {
+            quantization: header.quantization,
+            blocks: transmute_from_u8_to_slice(&self.mmap[blocks_start..weights_start]),
+            weights: &self.mmap[weights_start..ids_start],
+            ids: &self.mmap[ids_start..ids_end],
+        })
+    }
+
+    pub fn convert_and_save<P: AsRef<Path>>(
+        inverted_index_ram: &InvertedIndexRam,
+        path: P,
+    ) -> std::io::Result<Self> {
+        let compressed_postings: Vec<_> = inverted_index_ram
+            .postings
+            .iter()
+            .map(|posting| CompressedPostingList::from_elements(&posting.elements))
+            .collect();
+
+        let total_posting_headers_size = compressed_postings.len() * POSTING_HEADER_SIZE;
+        let total_posting_data_size: usize = compressed_postings
+            .iter()
+            .map(Self::posting_data_size)
+            .sum();
+
+        let file_length = total_posting_headers_size + total_posting_data_size;
+        let file_path = Self::index_file_path(path.as_ref());
+        create_and_ensure_length(file_path.as_ref(), file_length)?;
+
+        let mut mmap = open_write_mmap(file_path.as_ref())?;
+        madvise::madvise(&mmap, madvise::Advice::Normal)?;
+
+        // file index data
+        Self::save_postings(&mut mmap, &compressed_postings, total_posting_headers_size);
+        if file_length > 0 {
+            mmap.flush()?;
+        }
+
+        // save header properties
+        let posting_count = inverted_index_ram.postings.len();
+        let vector_count = inverted_index_ram.vector_count();
+
+        // finalize data with index file.
+        let file_header = InvertedIndexFileHeader {
+            posting_count,
+            vector_count,
+        };
+        let config_file_path = Self::index_config_file_path(path.as_ref());
+        atomic_save_json(&config_file_path, &file_header)?;
+
+        Ok(Self {
+            path: path.as_ref().to_owned(),
+            mmap: Arc::new(mmap.make_read_only()?),
+            file_header,
+        })
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> std::io::Result<Self> {
+        // read index config file
+        let config_file_path = Self::index_config_file_path(path.as_ref());
+        // if the file header does not exist, the index is malformed
+        let file_header: InvertedIndexFileHeader = read_json(&config_file_path)?;
+        // read index data into mmap
+        let file_path = Self::index_file_path(path.as_ref());
+        let mmap = open_read_mmap(file_path.as_ref())?;
+        madvise::madvise(&mmap, madvise::Advice::Normal)?;
+        Ok(Self {
+            path: path.as_ref().to_owned(),
+            mmap: Arc::new(mmap),
+            file_header,
+        })
+    }
+
+    /// Size of the posting list data, padded to keep the next block headers aligned
+    fn posting_data_size(posting: &CompressedPostingList) -> usize {
+        let size = posting.blocks.len() * size_of::<CompressedBlock>()
+            + posting.weights.len()
+            + posting.ids.len();
+        size.next_multiple_of(POSTING_ALIGNMENT)
+    }
+
+    fn save_postings(
+        mmap: &mut MmapMut,
+        compressed_postings: &[CompressedPostingList],
+        total_posting_headers_size: usize,
+    ) {
+        let mut offset = total_posting_headers_size;
+        for (id, posting) in compressed_postings.iter().enumerate() {
+            let posting_header = CompressedPostingListFileHeader {
+                start_offset: offset as u64,
+                ids_len: posting.ids.len() as u64,
+                len: posting.weights.len() as u32,
+                blocks_count: posting.blocks.len() as u32,
+                quantization: posting.quantization,
+            };
+
+            // save posting header
+            let posting_header_bytes = transmute_to_u8(&posting_header);
+            let start_posting_offset = id * POSTING_HEADER_SIZE;
+            let end_posting_offset = (id + 1) * POSTING_HEADER_SIZE;
+            mmap[start_posting_offset..end_posting_offset].copy_from_slice(posting_header_bytes);
+
+            // save posting data
+            let mut data_offset = offset;
+            for bytes in [
+                transmute_to_u8_slice(&posting.blocks),
+                posting.weights.as_slice(),
+                posting.ids.as_slice(),
+            ] {
+                mmap[data_offset..data_offset + bytes.len()].copy_from_slice(bytes);
+                data_offset += bytes.len();
+            }
+            offset += Self::posting_data_size(posting);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::Builder;
+
+    use super::*;
+    use crate::index::inverted_index::inverted_index_mmap::InvertedIndexMmap;
+    use crate::index::inverted_index::inverted_index_ram::InvertedIndexBuilder;
+    use crate::index::posting_list::PostingList;
+
+    fn compare_indexes(
+        inverted_index_ram: &InvertedIndexRam,
+        inverted_index_mmap: &InvertedIndexCompressedMmap,
+    ) {
+        for id in 0..inverted_index_ram.postings.len() as DimId {
+            let posting_list_ram = inverted_index_ram.get(&id).unwrap().elements.as_slice();
+            let posting_list_mmap = inverted_index_mmap.get(&id).unwrap();
+            let max_error = posting_list_mmap.quantization.step / 2.0 + 1e-4;
+            let decoded = posting_list_mmap.to_elements();
+            assert_eq!(posting_list_ram.len(), decoded.len());
+            for (ram, mmap) in posting_list_ram.iter().zip(&decoded) {
+                assert_eq!(ram.record_id, mmap.record_id);
+                assert!((ram.weight - mmap.weight).abs() <= max_error);
+            }
+        }
+    }
+
+    #[test]
+    fn test_inverted_index_compressed_mmap() {
+        let inverted_index_ram = InvertedIndexBuilder::new()
+            .add(
+                1,
+                PostingList::from((1..1000).map(|id| (id * 7, (id % 13) as f32)).collect()),
+            )
+            .add(
+                2,
+                PostingList::from(vec![(1, 10.0), (2, 20.0), (3, 30.0), (4, 1.0)]),
+            )
+            .add(3, PostingList::from(vec![(1, -10.0), (200, 20.5)]))
+            .add(5, PostingList::from(vec![(1, 10.0)])) // skip 4
+            .build();
+
+        let tmp_dir_path = Builder::new().prefix("test_index_dir").tempdir().unwrap();
+
+        {
+            let inverted_index_mmap =
+                InvertedIndexCompressedMmap::convert_and_save(&inverted_index_ram, &tmp_dir_path)
+                    .unwrap();
+
+            compare_indexes(&inverted_index_ram, &inverted_index_mmap);
+        }
+        let inverted_index_mmap = InvertedIndexCompressedMmap::load(&tmp_dir_path).unwrap();
+        // posting_count: 0th entry is always empty + 1st + 2nd + 3rd + 4th empty + 5th
+        assert_eq!(inverted_index_mmap.file_header.posting_count, 6);
+        assert_eq!(inverted_index_mmap.file_header.vector_count, 1004);
+
+        compare_indexes(&inverted_index_ram, &inverted_index_mmap);
+
+        assert!(inverted_index_mmap.get(&0).unwrap().is_empty());
+        assert_eq!(inverted_index_mmap.get(&1).unwrap().len(), 999);
+        assert_eq!(inverted_index_mmap.get(&2).unwrap().len(), 4);
+        assert!(inverted_index_mmap.get(&4).unwrap().is_empty());
+        assert_eq!(inverted_index_mmap.get(&5).unwrap().len(), 1);
+        assert!(inverted_index_mmap.get(&6).is_none());
+
+        // compressed index is smaller than the uncompressed one
+        let uncompressed_path = Builder::new().prefix("test_index_dir").tempdir().unwrap();
+        InvertedIndexMmap::convert_and_save(&inverted_index_ram, &uncompressed_path).unwrap();
+        let file_size = |path: PathBuf| std::fs::metadata(path).unwrap().len();
+        assert!(
+            file_size(InvertedIndexCompressedMmap::index_file_path(
+                tmp_dir_path.path()
+            )) * 3
+                < file_size(InvertedIndexMmap::index_file_path(uncompressed_path.path()))
+        );
+    }
+}
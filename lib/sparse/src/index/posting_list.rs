@@ -1,9 +1,11 @@
+use std::borrow::Cow;
 use std::cmp::max;
 
 use common::types::PointOffsetType;
 use ordered_float::OrderedFloat;
 
 use crate::common::types::DimWeight;
+use crate::index::compressed_posting_list::{CompressedPostingListView, BLOCK_SIZE};
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub struct PostingElement {
@@ -171,41 +173,110 @@ impl PostingBuilder {
 }
 
 /// Iterator over posting list elements offering skipping abilities to avoid full iteration.
+///
+/// Compressed posting lists are decoded block by block.
 pub struct PostingListIterator<'a> {
-    pub elements: &'a [PostingElement],
+    /// Elements of the current block, or all elements of an uncompressed posting list
+    elements: Cow<'a, [PostingElement]>,
+    /// Compressed posting list, if any
+    compressed: Option<CompressedPostingListView<'a>>,
+    /// Index of the decoded block of the compressed posting list
+    block_index: usize,
+    /// Position in the whole posting list
     pub current_index: usize,
 }
 
-impl<'a> Iterator for PostingListIterator<'a> {
-    type Item = &'a PostingElement;
+impl Iterator for PostingListIterator<'_> {
+    type Item = PostingElement;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current_index < self.elements.len() {
-            let element = &self.elements[self.current_index];
-            self.current_index += 1;
-            Some(element)
-        } else {
-            None
-        }
+        let element = *self.peek()?;
+        self.current_index += 1;
+        self.decode_current_block();
+        Some(element)
     }
 }
 
 impl<'a> PostingListIterator<'a> {
     pub fn new(elements: &'a [PostingElement]) -> PostingListIterator<'a> {
         PostingListIterator {
-            elements,
+            elements: Cow::Borrowed(elements),
+            compressed: None,
+            block_index: 0,
+            current_index: 0,
+        }
+    }
+
+    pub fn new_compressed(posting_list: CompressedPostingListView<'a>) -> PostingListIterator<'a> {
+        let mut elements = Vec::with_capacity(BLOCK_SIZE);
+        if !posting_list.is_empty() {
+            posting_list.decode_block(0, &mut elements);
+        }
+        PostingListIterator {
+            elements: Cow::Owned(elements),
+            compressed: Some(posting_list),
+            block_index: 0,
             current_index: 0,
         }
     }
 
+    /// Number of elements in the whole posting list
+    pub fn len(&self) -> usize {
+        match &self.compressed {
+            Some(posting_list) => posting_list.len(),
+            None => self.elements.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Position of the first element of `elements` in the whole posting list
+    fn block_start(&self) -> usize {
+        self.block_index * BLOCK_SIZE
+    }
+
+    /// Make sure the block of the current position is decoded
+    fn decode_current_block(&mut self) {
+        let Some(posting_list) = self.compressed else {
+            return;
+        };
+        let block_index = self.current_index / BLOCK_SIZE;
+        if block_index != self.block_index && self.current_index < posting_list.len() {
+            posting_list.decode_block(block_index, self.elements.to_mut());
+            self.block_index = block_index;
+        }
+    }
+
     /// Returns the next element without advancing the iterator.
     pub fn peek(&self) -> Option<&PostingElement> {
-        self.elements.get(self.current_index)
+        self.elements.get(self.current_index - self.block_start())
     }
 
     /// Returns the number of elements from the current position to the end of the list.
     pub fn len_to_end(&self) -> usize {
-        self.elements.len() - self.current_index
+        self.len() - self.current_index
+    }
+
+    /// Upper bound of the weights of the remaining elements with ID < id.
+    ///
+    /// Compressed posting lists only consider the max weights of the blocks overlapping with the range,
+    /// uncompressed posting lists consider all remaining elements.
+    /// Returns None if the iterator is at the end.
+    pub fn max_weight_before(&self, id: PointOffsetType) -> Option<DimWeight> {
+        let element = self.peek()?;
+        match &self.compressed {
+            None => Some(element.weight.max(element.max_next_weight)),
+            Some(posting_list) => Some(
+                posting_list.blocks[self.block_index..]
+                    .iter()
+                    .take_while(|block| block.first_id < id)
+                    .fold(f32::NEG_INFINITY, |max_weight, block| {
+                        max_weight.max(block.max_weight)
+                    }),
+            ),
+        }
     }
 
     /// Tries to find the element with ID == id and returns it.
@@ -213,24 +284,41 @@ impl<'a> PostingListIterator<'a> {
     /// and None is returned.
     /// If the iterator is already at the end, None is returned.
     /// If the iterator skipped to the end, None is returned and current index is set to the length of the list.
-    /// Uses binary search.
+    /// Uses binary search, compressed posting lists only decode the block which may contain the id.
     pub fn skip_to(&mut self, id: PointOffsetType) -> Option<&PostingElement> {
         // Check if we are already at the end
-        if self.current_index >= self.elements.len() {
+        if self.current_index >= self.len() {
             return None;
         }
 
+        if let Some(posting_list) = self.compressed {
+            // Find the first block with elements >= id without decoding the skipped blocks
+            let block_index = self.block_index
+                + posting_list.blocks[self.block_index..]
+                    .partition_point(|block| block.last_id < id);
+            if block_index >= posting_list.blocks.len() {
+                return self.skip_to_end();
+            }
+            if block_index != self.block_index {
+                posting_list.decode_block(block_index, self.elements.to_mut());
+                self.block_index = block_index;
+                self.current_index = self.block_start();
+            }
+        }
+
         // Use binary search to find the next element with ID > id
-        let next_element =
-            self.elements[self.current_index..].binary_search_by(|e| e.record_id.cmp(&id));
+        let block_start = self.block_start();
+        let next_element = self.elements[self.current_index - block_start..]
+            .binary_search_by(|e| e.record_id.cmp(&id));
 
         match next_element {
             Ok(found_offset) => {
                 self.current_index += found_offset;
-                Some(&self.elements[self.current_index])
+                Some(&self.elements[self.current_index - block_start])
             }
             Err(insert_index) => {
                 self.current_index += insert_index;
+                self.decode_current_block();
                 None
             }
         }
@@ -238,7 +326,7 @@ impl<'a> PostingListIterator<'a> {
 
     /// Skips to the end of the posting list and returns None.
     pub fn skip_to_end(&mut self) -> Option<&PostingElement> {
-        self.current_index = self.elements.len();
+        self.current_index = self.len();
         None
     }
 }
@@ -246,6 +334,7 @@ impl<'a> PostingListIterator<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::index::compressed_posting_list::CompressedPostingList;
 
     #[test]
     fn test_posting_operations() {
@@ -284,6 +373,52 @@ mod tests {
         assert!(iter.peek().is_none());
     }
 
+    #[test]
+    fn test_compressed_posting_operations() {
+        // first block has larger weights than the rest of the posting list
+        let posting_list = PostingList::from(
+            (0..1000)
+                .map(|i| (i * 3, if (i as usize) < BLOCK_SIZE { 10.0 } else { 1.0 }))
+                .collect(),
+        );
+        let compressed = CompressedPostingList::from_elements(&posting_list.elements);
+
+        let mut iter = PostingListIterator::new_compressed(compressed.view());
+        assert_eq!(iter.len(), 1000);
+
+        assert_eq!(iter.next().unwrap().record_id, 0);
+        assert_eq!(iter.peek().unwrap().record_id, 3);
+
+        // only the first block overlaps with the range
+        assert!(iter.max_weight_before(3 * BLOCK_SIZE as u32).unwrap() > 9.9);
+
+        // skip within the block
+        assert_eq!(iter.skip_to(30).unwrap().record_id, 30);
+        assert_eq!(iter.current_index, 10);
+
+        // skip to the next blocks
+        assert!(iter.skip_to(1000).is_none());
+        assert_eq!(iter.peek().unwrap().record_id, 1002);
+        assert_eq!(iter.current_index, 334);
+        assert_eq!(iter.len_to_end(), 666);
+        assert!(iter.max_weight_before(PointOffsetType::MAX).unwrap() < 1.1);
+
+        // iterate over the block boundary
+        assert_eq!(iter.skip_to(3 * 383).unwrap().record_id, 3 * 383);
+        assert_eq!(iter.next().unwrap().record_id, 3 * 383);
+        assert_eq!(iter.next().unwrap().record_id, 3 * 384);
+        assert_eq!(iter.peek().unwrap().record_id, 3 * 385);
+
+        // skipped elements are not returned
+        assert_eq!(iter.count(), 1000 - 385);
+
+        let mut iter = PostingListIterator::new_compressed(compressed.view());
+        assert!(iter.skip_to(3 * 1000).is_none());
+        assert!(iter.peek().is_none());
+        assert_eq!(iter.len_to_end(), 0);
+        assert!(iter.max_weight_before(PointOffsetType::MAX).is_none());
+    }
+
     #[test]
     fn test_upsert_insert_last() {
         let mut builder = PostingBuilder::new();
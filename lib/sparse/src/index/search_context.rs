@@ -259,10 +259,13 @@ impl<'a> SearchContext<'a> {
                         Ordering::Greater => {
                             // next_min_id is > element.record_id there is a chance to prune up to `next_min_id`
                             let posting_query_offset = longest_posting_iterator.query_weight_offset;
-                            // check against the max possible score using the `max_next_weight`
-                            // we can under prune as we should actually check the best score up to `next_min_id` - 1 only
-                            // instead of the max possible score but it is not possible to know the best score up to `next_min_id` - 1
-                            let max_weight_from_list = element.weight.max(element.max_next_weight);
+                            // check against the max possible score up to `next_min_id`
+                            // compressed posting lists provide the max weight of the blocks in range (block-max pruning),
+                            // otherwise we can under prune as the `max_next_weight` covers the whole rest of the posting list
+                            let max_weight_from_list = longest_posting_iterator
+                                .posting_list_iterator
+                                .max_weight_before(next_min_id)
+                                .unwrap_or(element.weight);
                             let max_score_contribution =
                                 max_weight_from_list * self.query.values[posting_query_offset];
                             if max_score_contribution <= min_score {
@@ -309,6 +312,7 @@ mod tests {
 
     use super::*;
     use crate::common::sparse_vector_fixture::random_sparse_vector;
+    use crate::index::inverted_index::inverted_index_compressed_mmap::InvertedIndexCompressedMmap;
     use crate::index::inverted_index::inverted_index_mmap::InvertedIndexMmap;
     use crate::index::inverted_index::inverted_index_ram::{
         InvertedIndexBuilder, InvertedIndexRam,
@@ -1082,4 +1086,52 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn compressed_block_max_pruning_test() {
+        let num_vectors = 5000;
+        let top = 10;
+        let mut rnd = StdRng::seed_from_u64(42);
+        let is_stopped = AtomicBool::new(false);
+
+        let mut builder = InvertedIndexBuilder::new();
+        for dim_id in 1..=3 {
+            let mut records = Vec::new();
+            for record_id in 1..=num_vectors {
+                if rnd.gen_bool(0.5) {
+                    records.push((record_id, rnd.gen_range(0.0..10.0)));
+                }
+            }
+            builder.add(dim_id, PostingList::from(records));
+        }
+        let inverted_index_ram = builder.build();
+
+        let tmp_dir_path = tempfile::Builder::new()
+            .prefix("test_index_dir")
+            .tempdir()
+            .unwrap();
+        let inverted_index_mmap =
+            InvertedIndexCompressedMmap::convert_and_save(&inverted_index_ram, &tmp_dir_path)
+                .unwrap();
+
+        let query = SparseVector {
+            indices: vec![1, 2, 3],
+            values: vec![1.0, 0.5, 2.0],
+        };
+
+        let mut search_context =
+            SearchContext::new(query.clone(), top, &inverted_index_mmap, &is_stopped);
+        let pruned = search_context.search(&match_all);
+
+        // plain search scores all the vectors without pruning
+        let mut search_context = SearchContext::new(query, top, &inverted_index_mmap, &is_stopped);
+        let all_ids: Vec<_> = (1..=num_vectors).collect();
+        let expected = search_context.plain_search(&all_ids);
+
+        assert_eq!(pruned.len(), top);
+        for (pruned, expected) in pruned.iter().zip(&expected) {
+            assert_eq!(pruned.idx, expected.idx);
+            assert!((pruned.score - expected.score).abs() < 1e-4);
+        }
+    }
 }
@@ -114,6 +114,28 @@ impl AliasPersistence {
         }
     }
 
+    /// Points all aliases of a collection to its new name.
+    pub fn rename_collection(
+        &mut self,
+        old_collection_name: &str,
+        new_collection_name: &str,
+    ) -> Result<(), StorageError> {
+        let mut changed = false;
+
+        for target_collection in self.alias_mapping.0.values_mut() {
+            if target_collection == old_collection_name {
+                *target_collection = new_collection_name.to_string();
+                changed = true;
+            }
+        }
+
+        if changed {
+            self.alias_mapping.save(&self.data_path)?;
+        }
+
+        Ok(())
+    }
+
     pub fn collection_aliases(&self, collection_name: &str) -> Vec<String> {
         let mut result = vec![];
         for (alias, target_collection) in self.alias_mapping.0.iter() {
@@ -1,10 +1,11 @@
 use std::collections::BTreeMap;
 
-use collection::config::{CollectionConfig, ShardingMethod};
+use collection::config::{CollectionConfig, ExpiryConfig, ShardingMethod, StrictModeConfig};
 use collection::operations::config_diff::{
     CollectionParamsDiff, HnswConfigDiff, OptimizersConfigDiff, QuantizationConfigDiff,
     WalConfigDiff,
 };
+use collection::operations::payload_schema::PayloadSchema;
 use collection::operations::types::{
     SparseVectorParams, SparseVectorsConfig, VectorsConfig, VectorsConfigDiff,
 };
@@ -164,6 +165,19 @@ pub struct CreateCollection {
     /// Sparse vector data config.
     #[validate]
     pub sparse_vectors: Option<BTreeMap<String, SparseVectorParams>>,
+    /// Points expiry config. If none - points never expire.
+    #[serde(default)]
+    #[validate]
+    pub expiry: Option<ExpiryConfig>,
+    /// Schema, which payload of upserted points and payload updates are validated against.
+    /// If none - payload is not validated.
+    #[serde(default)]
+    #[validate]
+    pub payload_schema: Option<PayloadSchema>,
+    /// Strict mode config. If none - requests to the collection are not limited.
+    #[serde(default)]
+    #[validate]
+    pub strict_mode: Option<StrictModeConfig>,
 }
 
 /// Operation for creating new collection and (optionally) specify index params
@@ -210,6 +224,7 @@ pub struct UpdateCollection {
     #[serde(alias = "optimizer_config")]
     pub optimizers_config: Option<OptimizersConfigDiff>, // TODO: Allow updates for other configuration params as well
     /// Collection base params. If none - it is left unchanged.
+    #[validate]
     pub params: Option<CollectionParamsDiff>,
     /// HNSW parameters to update for the collection index. If none - it is left unchanged.
     #[validate]
@@ -221,6 +236,16 @@ pub struct UpdateCollection {
     /// Map of sparse vector data parameters to update for each sparse vector.
     #[validate]
     pub sparse_vectors: Option<SparseVectorsConfig>,
+    /// New vectors to add to the collection. Existing points have no values for them,
+    /// which can be set with vector update operations.
+    #[validate]
+    pub create_vectors: Option<VectorsConfig>,
+    /// New sparse vectors to add to the collection. Existing points have no values for them,
+    /// which can be set with vector update operations.
+    #[validate]
+    pub create_sparse_vectors: Option<SparseVectorsConfig>,
+    /// Names of vectors or sparse vectors to remove from the collection along with their data.
+    pub delete_vectors: Option<Vec<String>>,
 }
 
 /// Operation for updating parameters of the existing collection
@@ -243,6 +268,9 @@ impl UpdateCollectionOperation {
                 optimizers_config: None,
                 quantization_config: None,
                 sparse_vectors: None,
+                create_vectors: None,
+                create_sparse_vectors: None,
+                delete_vectors: None,
             },
             shard_replica_changes: None,
         }
@@ -291,6 +319,66 @@ pub struct ChangeAliasesOperation {
 #[serde(rename_all = "snake_case")]
 pub struct DeleteCollectionOperation(pub String);
 
+/// Operation for renaming an existing collection.
+/// Aliases of the collection are moved to the new name.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, PartialEq, Eq, Hash, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct RenameCollection {
+    /// New name of the collection
+    #[validate(
+        length(min = 1, max = 255),
+        custom = "common::validation::validate_collection_name"
+    )]
+    pub new_collection_name: String,
+    /// If true - existing collection with the new name is replaced in a single step.
+    /// Aliases, pointing to the replaced collection, will point to the renamed one.
+    /// Default is false
+    #[serde(default)]
+    pub replace_existing: bool,
+}
+
+/// Operation for renaming an existing collection
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct RenameCollectionOperation {
+    pub collection_name: String,
+    pub rename_collection: RenameCollection,
+}
+
+/// Operation for creating a copy of an existing collection.
+/// Each shard is copied from a snapshot of one of its active replicas, so the copy keeps the same
+/// shard layout. Other replicas of the copy are filled with regular shard transfers.
+/// Index parameters of the copy may differ, in which case indexes are rebuilt in the background.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, PartialEq, Eq, Hash, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct CloneCollection {
+    /// Name of the new collection
+    #[validate(
+        length(min = 1, max = 255),
+        custom = "common::validation::validate_collection_name"
+    )]
+    pub new_collection_name: String,
+    /// Map of vector data parameters to change in the copy for each named vector.
+    /// If none - parameters of the source collection are used.
+    #[validate]
+    pub vectors: Option<VectorsConfigDiff>,
+    /// HNSW parameters to change in the copy. If none - parameters of the source collection are used.
+    #[validate]
+    pub hnsw_config: Option<HnswConfigDiff>,
+    /// Quantization parameters of the copy. If none - parameters of the source collection are used.
+    #[serde(default, alias = "quantization")]
+    #[validate]
+    pub quantization_config: Option<QuantizationConfigDiff>,
+}
+
+/// Operation for creating a copy of an existing collection
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct CloneCollectionOperation {
+    pub collection_name: String,
+    pub clone_collection: CloneCollection,
+}
+
 #[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Clone)]
 pub enum ShardTransferOperations {
     Start(ShardTransfer),
@@ -356,6 +444,8 @@ pub enum CollectionMetaOperations {
     CreateCollection(CreateCollectionOperation),
     UpdateCollection(UpdateCollectionOperation),
     DeleteCollection(DeleteCollectionOperation),
+    RenameCollection(RenameCollectionOperation),
+    CloneCollection(CloneCollectionOperation),
     ChangeAliases(ChangeAliasesOperation),
     TransferShard(CollectionId, ShardTransferOperations),
     SetShardReplicaState(SetShardReplicaState),
@@ -383,6 +473,9 @@ impl From<CollectionConfig> for CreateCollection {
             init_from: None,
             quantization_config: value.quantization_config,
             sparse_vectors: value.params.sparse_vectors,
+            expiry: value.params.expiry,
+            payload_schema: value.params.payload_schema,
+            strict_mode: value.params.strict_mode,
         }
     }
 }
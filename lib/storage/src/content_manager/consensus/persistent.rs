@@ -6,6 +6,7 @@ use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
 use atomicwrites::{AllowOverwrite, AtomicFile};
+use collection::shards::peer_labels::{PeerLabels, PeerLabelsById};
 use collection::shards::shard::PeerId;
 use http::Uri;
 use parking_lot::RwLock;
@@ -39,6 +40,9 @@ pub struct Persistent {
     /// Last known cluster topology
     #[serde(with = "serialize_peer_addresses")]
     pub peer_address_by_id: Arc<RwLock<PeerAddressById>>,
+    /// Labels of the known peers, peers without labels may be missing
+    #[serde(default, with = "serialize_peer_labels")]
+    pub peer_labels_by_id: Arc<RwLock<PeerLabelsById>>,
     pub this_peer_id: PeerId,
     #[serde(skip)]
     pub path: PathBuf,
@@ -60,8 +64,10 @@ impl Persistent {
         &mut self,
         meta: &SnapshotMetadata,
         address_by_id: PeerAddressById,
+        labels_by_id: PeerLabelsById,
     ) -> Result<(), StorageError> {
         *self.peer_address_by_id.write() = address_by_id;
+        *self.peer_labels_by_id.write() = labels_by_id;
         self.state.conf_state = meta.get_conf_state().clone();
         self.state.hard_state.term = cmp::max(self.state.hard_state.term, meta.term);
         self.state.hard_state.commit = meta.index;
@@ -137,7 +143,12 @@ impl Persistent {
         self.save()
     }
 
-    pub fn insert_peer(&mut self, peer_id: PeerId, address: Uri) -> Result<(), StorageError> {
+    pub fn insert_peer(
+        &mut self,
+        peer_id: PeerId,
+        address: Uri,
+        labels: PeerLabels,
+    ) -> Result<(), StorageError> {
         if let Some(prev_peer_address) = self
             .peer_address_by_id
             .write()
@@ -147,6 +158,16 @@ impl Persistent {
         } else {
             log::debug!("Added peer with id {peer_id} and address {address}")
         }
+
+        let mut peer_labels_by_id = self.peer_labels_by_id.write();
+        if labels.is_empty() {
+            peer_labels_by_id.remove(&peer_id);
+        } else {
+            log::debug!("Set labels of peer {peer_id} to {labels:?}");
+            peer_labels_by_id.insert(peer_id, labels);
+        }
+        drop(peer_labels_by_id);
+
         self.save()
     }
 
@@ -158,6 +179,10 @@ impl Persistent {
         self.peer_address_by_id.read().clone()
     }
 
+    pub fn peer_labels_by_id(&self) -> PeerLabelsById {
+        self.peer_labels_by_id.read().clone()
+    }
+
     pub fn this_peer_id(&self) -> PeerId {
         self.this_peer_id
     }
@@ -188,6 +213,7 @@ impl Persistent {
             },
             apply_progress_queue: Default::default(),
             peer_address_by_id: Default::default(),
+            peer_labels_by_id: Default::default(),
             this_peer_id,
             path,
             latest_snapshot_meta: Default::default(),
@@ -275,6 +301,32 @@ mod serialize_peer_addresses {
     }
 }
 
+mod serialize_peer_labels {
+    use std::sync::Arc;
+
+    use collection::shards::peer_labels::PeerLabelsById;
+    use parking_lot::RwLock;
+    use serde::{self, Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S>(
+        labels: &Arc<RwLock<PeerLabelsById>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        labels.read().serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Arc<RwLock<PeerLabelsById>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let labels = PeerLabelsById::deserialize(deserializer)?;
+        Ok(Arc::new(RwLock::new(labels)))
+    }
+}
+
 /// Definition of struct to help with serde serialization.
 /// Should be used only in `[serde(with=...)]`
 #[derive(Serialize, Deserialize)]
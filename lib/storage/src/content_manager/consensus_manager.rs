@@ -10,13 +10,14 @@ use anyhow::{anyhow, Context};
 use chrono::Utc;
 use collection::collection_state;
 use collection::common::is_ready::IsReady;
+use collection::shards::peer_labels::{PeerLabels, PeerLabelsById};
 use collection::shards::shard::PeerId;
 use collection::shards::CollectionId;
 use common::defaults;
 use futures::future::join_all;
 use parking_lot::{Mutex, RwLock};
 use raft::eraftpb::{ConfChangeType, ConfChangeV2, Entry as RaftEntry};
-use raft::{GetEntriesContext, RaftState, RawNode, SoftState, Storage};
+use raft::{GetEntriesContext, RaftState, RawNode, SoftState, StateRole, Storage};
 use serde::{Deserialize, Serialize};
 use tokio::sync::broadcast;
 use tokio::sync::broadcast::Receiver;
@@ -24,7 +25,7 @@ use tokio::time::error::Elapsed;
 use tonic::transport::Uri;
 
 use super::alias_mapping::AliasMapping;
-use super::consensus_ops::{ConsensusOperations, SnapshotStatus};
+use super::consensus_ops::{AddPeerContext, ConsensusOperations, SnapshotStatus};
 use super::errors::StorageError;
 use super::CollectionContainer;
 use crate::content_manager::consensus::consensus_wal::ConsensusOpWal;
@@ -47,6 +48,8 @@ pub struct SnapshotData {
     pub collections_data: CollectionsSnapshot,
     #[serde(with = "crate::serialize_peer_addresses")]
     pub address_by_id: PeerAddressById,
+    #[serde(default)]
+    pub labels_by_id: PeerLabelsById,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
@@ -190,6 +193,7 @@ impl<C: CollectionContainer> ConsensusManager<C> {
     pub fn cluster_status(&self) -> ClusterStatus {
         let persistent = self.persistent.read();
         let hard_state = &persistent.state.hard_state;
+        let labels_by_id = persistent.peer_labels_by_id();
         let peers = persistent
             .peer_address_by_id()
             .into_iter()
@@ -198,6 +202,7 @@ impl<C: CollectionContainer> ConsensusManager<C> {
                     peer_id,
                     PeerInfo {
                         uri: uri.to_string(),
+                        labels: labels_by_id.get(&peer_id).cloned().unwrap_or_default(),
                     },
                 )
             })
@@ -381,19 +386,21 @@ impl<C: CollectionContainer> ConsensusManager<C> {
                 }
                 ConfChangeType::AddLearnerNode => {
                     log::debug!("Adding learner node {}", single_change.node_id);
-                    if let Ok(peer_uri) = String::from_utf8_lossy(entry.get_context())
-                        .deref()
-                        .try_into()
-                    {
-                        let peer_uri: Uri = peer_uri;
+                    let peer = AddPeerContext::from_bytes(entry.get_context())
+                        .ok()
+                        .and_then(|context| {
+                            Some((Uri::try_from(context.uri).ok()?, context.labels))
+                        });
+                    if let Some((peer_uri, labels)) = peer {
                         // Add peer to state
-                        self.add_peer(single_change.node_id, peer_uri.clone())?;
+                        self.add_peer(single_change.node_id, peer_uri.clone(), labels.clone())?;
 
                         // Notify the submitter, that operation was performed
                         {
                             let operation = ConsensusOperations::AddPeer {
                                 peer_id: single_change.node_id,
                                 uri: peer_uri.to_string(),
+                                labels,
                             };
                             let on_apply = self.on_consensus_op_apply.lock().remove(&operation);
                             if let Some(on_apply) = on_apply {
@@ -471,9 +478,11 @@ impl<C: CollectionContainer> ConsensusManager<C> {
         let data: SnapshotData = snapshot.get_data().try_into()?;
         self.toc.apply_collections_snapshot(data.collections_data)?;
         self.wal.lock().clear()?;
-        self.persistent
-            .write()
-            .update_from_snapshot(meta, data.address_by_id)?;
+        self.persistent.write().update_from_snapshot(
+            meta,
+            data.address_by_id,
+            data.labels_by_id,
+        )?;
 
         Ok(Ok(()))
     }
@@ -509,8 +518,13 @@ impl<C: CollectionContainer> ConsensusManager<C> {
             .apply_state_update(|state| state.hard_state.commit = index)
     }
 
-    pub fn add_peer(&self, peer_id: PeerId, uri: Uri) -> Result<(), StorageError> {
-        self.persistent.write().insert_peer(peer_id, uri)
+    pub fn add_peer(
+        &self,
+        peer_id: PeerId,
+        uri: Uri,
+        labels: PeerLabels,
+    ) -> Result<(), StorageError> {
+        self.persistent.write().insert_peer(peer_id, uri, labels)
     }
 
     pub fn remove_peer(&self, peer_id: PeerId) -> Result<(), StorageError> {
@@ -689,6 +703,10 @@ impl<C: CollectionContainer> ConsensusManager<C> {
         self.persistent.read().peer_address_by_id()
     }
 
+    pub fn peer_labels_by_id(&self) -> PeerLabelsById {
+        self.persistent.read().peer_labels_by_id()
+    }
+
     pub fn peer_count(&self) -> usize {
         self.persistent.read().peer_address_by_id.read().len()
     }
@@ -702,7 +720,12 @@ impl<C: CollectionContainer> ConsensusManager<C> {
     }
 
     pub fn sync_local_state(&self) -> Result<(), StorageError> {
-        self.toc.sync_local_state()
+        let is_leader = self
+            .soft_state
+            .read()
+            .as_ref()
+            .is_some_and(|state| state.raft_state == StateRole::Leader);
+        self.toc.sync_local_state(is_leader)
     }
 }
 
@@ -770,6 +793,7 @@ impl<C: CollectionContainer> Storage for ConsensusManager<C> {
             let snapshot = SnapshotData {
                 collections_data,
                 address_by_id: persistent.peer_address_by_id(),
+                labels_by_id: persistent.peer_labels_by_id(),
             };
             Ok(raft::eraftpb::Snapshot {
                 data: serde_cbor::to_vec(&snapshot).map_err(raft_error_other)?,
@@ -981,7 +1005,10 @@ mod tests {
             Ok(())
         }
 
-        fn sync_local_state(&self) -> Result<(), crate::content_manager::errors::StorageError> {
+        fn sync_local_state(
+            &self,
+            _is_leader: bool,
+        ) -> Result<(), crate::content_manager::errors::StorageError> {
             Ok(())
         }
     }
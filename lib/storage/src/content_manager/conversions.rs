@@ -3,9 +3,10 @@ use collection::operations::types::SparseVectorsConfig;
 use tonic::Status;
 
 use crate::content_manager::collection_meta_ops::{
-    AliasOperations, ChangeAliasesOperation, CollectionMetaOperations, CreateAlias,
-    CreateAliasOperation, CreateCollection, CreateCollectionOperation, DeleteAlias,
-    DeleteAliasOperation, DeleteCollectionOperation, InitFrom, RenameAlias, RenameAliasOperation,
+    AliasOperations, ChangeAliasesOperation, CloneCollection, CloneCollectionOperation,
+    CollectionMetaOperations, CreateAlias, CreateAliasOperation, CreateCollection,
+    CreateCollectionOperation, DeleteAlias, DeleteAliasOperation, DeleteCollectionOperation,
+    InitFrom, RenameAlias, RenameAliasOperation, RenameCollection, RenameCollectionOperation,
     UpdateCollection, UpdateCollectionOperation,
 };
 use crate::content_manager::errors::StorageError;
@@ -36,7 +37,14 @@ impl TryFrom<api::grpc::qdrant::CreateCollection> for CollectionMetaOperations {
                 },
                 sparse_vectors: value
                     .sparse_vectors_config
-                    .map(|config| config.map.into_iter().map(|(k, v)| (k, v.into())).collect()),
+                    .map(|config| {
+                        config
+                            .map
+                            .into_iter()
+                            .map(|(k, v)| Ok((k, v.try_into()?)))
+                            .collect::<Result<_, Status>>()
+                    })
+                    .transpose()?,
                 hnsw_config: value.hnsw_config.map(|v| v.into()),
                 wal_config: value.wal_config.map(|v| v.into()),
                 optimizers_config: value.optimizers_config.map(|v| v.into()),
@@ -55,6 +63,9 @@ impl TryFrom<api::grpc::qdrant::CreateCollection> for CollectionMetaOperations {
                     .sharding_method
                     .map(sharding_method_from_proto)
                     .transpose()?,
+                expiry: value.expiry_config.map(Into::into),
+                payload_schema: value.payload_schema.map(TryInto::try_into).transpose()?,
+                strict_mode: value.strict_mode_config.map(Into::into),
             },
         )))
     }
@@ -79,16 +90,36 @@ impl TryFrom<api::grpc::qdrant::UpdateCollection> for CollectionMetaOperations {
                     .quantization_config
                     .map(TryInto::try_into)
                     .transpose()?,
-                sparse_vectors: value.sparse_vectors_config.map(|config| {
-                    SparseVectorsConfig(
-                        config.map.into_iter().map(|(k, v)| (k, v.into())).collect(),
-                    )
-                }),
+                sparse_vectors: value
+                    .sparse_vectors_config
+                    .map(sparse_vectors_config_from_proto)
+                    .transpose()?,
+                create_vectors: value
+                    .create_vectors_config
+                    .and_then(|config| config.config)
+                    .map(TryInto::try_into)
+                    .transpose()?,
+                create_sparse_vectors: value
+                    .create_sparse_vectors_config
+                    .map(sparse_vectors_config_from_proto)
+                    .transpose()?,
+                delete_vectors: (!value.delete_vectors.is_empty()).then_some(value.delete_vectors),
             },
         )))
     }
 }
 
+fn sparse_vectors_config_from_proto(
+    config: api::grpc::qdrant::SparseVectorConfig,
+) -> Result<SparseVectorsConfig, Status> {
+    config
+        .map
+        .into_iter()
+        .map(|(k, v)| Ok((k, v.try_into()?)))
+        .collect::<Result<_, Status>>()
+        .map(SparseVectorsConfig)
+}
+
 impl TryFrom<api::grpc::qdrant::DeleteCollection> for CollectionMetaOperations {
     type Error = Status;
 
@@ -99,6 +130,43 @@ impl TryFrom<api::grpc::qdrant::DeleteCollection> for CollectionMetaOperations {
     }
 }
 
+impl TryFrom<api::grpc::qdrant::RenameCollection> for CollectionMetaOperations {
+    type Error = Status;
+
+    fn try_from(value: api::grpc::qdrant::RenameCollection) -> Result<Self, Self::Error> {
+        Ok(Self::RenameCollection(RenameCollectionOperation {
+            collection_name: value.collection_name,
+            rename_collection: RenameCollection {
+                new_collection_name: value.new_collection_name,
+                replace_existing: value.replace_existing.unwrap_or_default(),
+            },
+        }))
+    }
+}
+
+impl TryFrom<api::grpc::qdrant::CloneCollection> for CollectionMetaOperations {
+    type Error = Status;
+
+    fn try_from(value: api::grpc::qdrant::CloneCollection) -> Result<Self, Self::Error> {
+        Ok(Self::CloneCollection(CloneCollectionOperation {
+            collection_name: value.collection_name,
+            clone_collection: CloneCollection {
+                new_collection_name: value.new_collection_name,
+                vectors: value
+                    .vectors_config
+                    .and_then(|config| config.config)
+                    .map(TryInto::try_into)
+                    .transpose()?,
+                hnsw_config: value.hnsw_config.map(Into::into),
+                quantization_config: value
+                    .quantization_config
+                    .map(TryInto::try_into)
+                    .transpose()?,
+            },
+        }))
+    }
+}
+
 impl From<api::grpc::qdrant::CreateAlias> for AliasOperations {
     fn from(value: api::grpc::qdrant::CreateAlias) -> Self {
         Self::CreateAlias(CreateAliasOperation {
@@ -0,0 +1,313 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use collection::config::CollectionParams;
+use collection::shards::CollectionId;
+use io::file_operations::{atomic_save_json, read_json};
+use schemars::JsonSchema;
+use segment::types::{Filter, PointIdType, WithPayloadInterface};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::content_manager::errors::StorageError;
+
+pub const COPY_JOBS_FILE: &str = "copy_jobs.json";
+
+pub const DEFAULT_COPY_BATCH_SIZE: usize = 1000;
+
+/// Copy points of another collection into this collection.
+/// Points are copied in batches on the server side, existing points with the same ids are overwritten.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, PartialEq, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct CopyPointsRequest {
+    /// Name of the collection to copy points from
+    #[validate(length(min = 1, max = 255))]
+    pub source_collection: String,
+    /// Copy only points which satisfy this conditions. If not provided - all points are copied.
+    #[validate]
+    pub filter: Option<Filter>,
+    /// Select which payload to copy. Default: All
+    pub with_payload: Option<WithPayloadInterface>,
+    /// Map of vector names in this collection to vector names in the source collection.
+    /// Vectors, which are not mentioned in the map, are not copied.
+    /// If not provided - vectors with the same name in both collections are copied.
+    pub vectors: Option<HashMap<String, String>>,
+    /// Number of points copied in a single batch. Default: 1000
+    #[validate(range(min = 1))]
+    pub batch_size: Option<usize>,
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum CopyJobStatus {
+    Running,
+    Finished,
+    Failed,
+}
+
+/// State of the job, which copies points into the collection
+#[derive(Debug, Deserialize, Serialize, JsonSchema, PartialEq, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct CopyJob {
+    pub request: CopyPointsRequest,
+    pub status: CopyJobStatus,
+    /// Number of points copied so far
+    pub copied_points: usize,
+    /// Id of the next point to copy. The job continues from this point once resumed.
+    pub next_offset: Option<PointIdType>,
+    /// Reason of the failure, if job failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl CopyJob {
+    pub fn new(request: CopyPointsRequest) -> Self {
+        Self {
+            request,
+            status: CopyJobStatus::Running,
+            copied_points: 0,
+            next_offset: None,
+            error: None,
+        }
+    }
+}
+
+/// Persists state of copy jobs, so interrupted jobs can be resumed.
+/// Jobs are identified by the name of the target collection, only one job per collection is allowed.
+#[derive(Debug)]
+pub struct CopyJobs {
+    data_path: PathBuf,
+    jobs: HashMap<CollectionId, CopyJob>,
+}
+
+impl CopyJobs {
+    pub fn open(dir_path: &Path) -> Result<Self, StorageError> {
+        let data_path = dir_path.join(COPY_JOBS_FILE);
+        let jobs = if data_path.exists() {
+            read_json(&data_path)?
+        } else {
+            HashMap::new()
+        };
+        Ok(Self { data_path, jobs })
+    }
+
+    fn save(&self) -> Result<(), StorageError> {
+        Ok(atomic_save_json(&self.data_path, &self.jobs)?)
+    }
+
+    pub fn get(&self, collection_name: &str) -> Option<&CopyJob> {
+        self.jobs.get(collection_name)
+    }
+
+    pub fn insert(&mut self, collection_name: String, job: CopyJob) -> Result<(), StorageError> {
+        self.jobs.insert(collection_name, job);
+        self.save()
+    }
+
+    pub fn remove(&mut self, collection_name: &str) -> Result<(), StorageError> {
+        if self.jobs.remove(collection_name).is_some() {
+            self.save()?;
+        }
+        Ok(())
+    }
+
+    /// Record copied batch.
+    ///
+    /// Returns `false` if the job is no longer running, and copying should stop.
+    pub fn update_progress(
+        &mut self,
+        collection_name: &str,
+        copied_points: usize,
+        next_offset: Option<PointIdType>,
+    ) -> Result<bool, StorageError> {
+        let Some(job) = self.jobs.get_mut(collection_name) else {
+            return Ok(false);
+        };
+        if job.status != CopyJobStatus::Running {
+            return Ok(false);
+        }
+        job.copied_points += copied_points;
+        job.next_offset = next_offset;
+        self.save()?;
+        Ok(true)
+    }
+
+    pub fn finish(
+        &mut self,
+        collection_name: &str,
+        error: Option<String>,
+    ) -> Result<(), StorageError> {
+        let Some(job) = self.jobs.get_mut(collection_name) else {
+            return Ok(());
+        };
+        job.status = match error {
+            None => CopyJobStatus::Finished,
+            Some(_) => CopyJobStatus::Failed,
+        };
+        job.error = error;
+        self.save()
+    }
+
+    /// Names of collections, which have unfinished copy jobs
+    pub fn running(&self) -> Vec<CollectionId> {
+        self.jobs
+            .iter()
+            .filter(|(_, job)| job.status == CopyJobStatus::Running)
+            .map(|(collection_name, _)| collection_name.clone())
+            .collect()
+    }
+}
+
+/// Resolve which vectors to copy, as a map of target vector names to source vector names.
+///
+/// Copied vectors must have the same type in both collections, dense vectors must have the same
+/// dimensionality. Distance of the target collection is applied to the copied vectors.
+pub fn resolve_copied_vectors(
+    source: &CollectionParams,
+    target: &CollectionParams,
+    vectors: Option<&HashMap<String, String>>,
+) -> Result<HashMap<String, String>, StorageError> {
+    let Some(vectors) = vectors else {
+        let dense = target
+            .vectors
+            .params_iter()
+            .map(|(name, _)| name)
+            .filter(|name| source.vectors.get_params(name).is_some());
+        let sparse = target
+            .sparse_vectors
+            .iter()
+            .flat_map(|sparse| sparse.keys())
+            .map(String::as_str)
+            .filter(|name| is_sparse_vector(source, name));
+        return Ok(dense
+            .chain(sparse)
+            .map(|name| (name.to_string(), name.to_string()))
+            .collect());
+    };
+
+    for (target_name, source_name) in vectors {
+        match (
+            target.vectors.get_params(target_name),
+            source.vectors.get_params(source_name),
+        ) {
+            (Some(target_params), Some(source_params)) => {
+                if target_params.size != source_params.size {
+                    return Err(StorageError::bad_input(format!(
+                        "Can't copy vector {source_name} of size {} into vector {target_name} of size {}",
+                        source_params.size, target_params.size,
+                    )));
+                }
+            }
+            (None, None)
+                if is_sparse_vector(target, target_name)
+                    && is_sparse_vector(source, source_name) => {}
+            (None, _) if !is_sparse_vector(target, target_name) => {
+                return Err(StorageError::bad_input(format!(
+                    "Vector {target_name} doesn't exist in the target collection"
+                )));
+            }
+            (_, None) if !is_sparse_vector(source, source_name) => {
+                return Err(StorageError::bad_input(format!(
+                    "Vector {source_name} doesn't exist in the source collection"
+                )));
+            }
+            _ => {
+                return Err(StorageError::bad_input(format!(
+                    "Can't copy vector {source_name} into vector {target_name} of a different type"
+                )));
+            }
+        }
+    }
+
+    Ok(vectors.clone())
+}
+
+fn is_sparse_vector(params: &CollectionParams, name: &str) -> bool {
+    params
+        .sparse_vectors
+        .as_ref()
+        .is_some_and(|sparse| sparse.contains_key(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU64;
+
+    use collection::operations::types::{SparseVectorParams, VectorParams, VectorsConfig};
+    use segment::types::Distance;
+
+    use super::*;
+
+    fn params(dense: &[(&str, u64)], sparse: &[&str]) -> CollectionParams {
+        CollectionParams {
+            vectors: VectorsConfig::Multi(
+                dense
+                    .iter()
+                    .map(|(name, size)| {
+                        let params = VectorParams {
+                            size: NonZeroU64::new(*size).unwrap(),
+                            distance: Distance::Cosine,
+                            hnsw_config: None,
+                            quantization_config: None,
+                            on_disk: None,
+                        };
+                        (name.to_string(), params)
+                    })
+                    .collect(),
+            ),
+            sparse_vectors: Some(
+                sparse
+                    .iter()
+                    .map(|name| {
+                        let params = SparseVectorParams {
+                            index: None,
+                            prune_threshold: None,
+                            max_dims: None,
+                            datatype: None,
+                        };
+                        (name.to_string(), params)
+                    })
+                    .collect(),
+            ),
+            ..CollectionParams::empty()
+        }
+    }
+
+    #[test]
+    fn test_resolve_copied_vectors() {
+        let source = params(&[("image", 4), ("text", 8)], &["keywords"]);
+        let target = params(&[("image", 4), ("title", 8)], &["keywords", "tags"]);
+
+        // Same names are copied by default, others are dropped
+        let vectors = resolve_copied_vectors(&source, &target, None).unwrap();
+        assert_eq!(
+            vectors,
+            HashMap::from([
+                ("image".to_string(), "image".to_string()),
+                ("keywords".to_string(), "keywords".to_string()),
+            ])
+        );
+
+        // Renamed vectors
+        let mapping = HashMap::from([
+            ("title".to_string(), "text".to_string()),
+            ("tags".to_string(), "keywords".to_string()),
+        ]);
+        assert_eq!(
+            resolve_copied_vectors(&source, &target, Some(&mapping)).unwrap(),
+            mapping
+        );
+
+        // Size mismatch
+        let mapping = HashMap::from([("image".to_string(), "text".to_string())]);
+        assert!(resolve_copied_vectors(&source, &target, Some(&mapping)).is_err());
+
+        // Type mismatch
+        let mapping = HashMap::from([("tags".to_string(), "text".to_string())]);
+        assert!(resolve_copied_vectors(&source, &target, Some(&mapping)).is_err());
+
+        // Unknown vector
+        let mapping = HashMap::from([("image".to_string(), "unknown".to_string())]);
+        assert!(resolve_copied_vectors(&source, &target, Some(&mapping)).is_err());
+    }
+}
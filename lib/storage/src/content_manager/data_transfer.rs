@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+use std::path::Path;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -11,10 +13,14 @@ use collection::operations::{CollectionUpdateOperations, CreateIndex, FieldIndex
 use collection::shards::replica_set::ReplicaState;
 use collection::shards::shard::{PeerId, ShardId};
 use collection::shards::CollectionId;
-use segment::types::{WithPayloadInterface, WithVector};
+use segment::data_types::named_vectors::NamedVectors;
+use segment::data_types::vectors::VectorStruct;
+use segment::types::{PointIdType, WithPayloadInterface, WithVector};
 use tokio::sync::RwLock;
 
 use crate::content_manager::collections_ops::Collections;
+use crate::content_manager::copy_jobs::{CopyPointsRequest, DEFAULT_COPY_BATCH_SIZE};
+use crate::content_manager::errors::StorageError;
 
 const MIGRATION_BATCH_SIZE: usize = 1000;
 const COLLECTION_INITIATION_TIMEOUT: Duration = Duration::from_secs(60);
@@ -25,7 +31,7 @@ const COLLECTION_INITIATION_TIMEOUT: Duration = Duration::from_secs(60);
 ///
 /// For each shard, it should present on local peer, it should be active, it should have maximal peer id.
 /// Selection of max peer id guarantees that only one shard will be migrated from one peer.
-async fn get_local_source_shards(
+pub async fn get_local_source_shards(
     source: &Collection,
     this_peer_id: PeerId,
 ) -> CollectionResult<Vec<ShardId>> {
@@ -84,6 +90,7 @@ async fn replicate_shard_data(
             filter: None,
             with_payload: Some(WithPayloadInterface::Bool(true)),
             with_vector: WithVector::Bool(true),
+            order_by: None,
         };
 
         let collections_read = collections.read().await;
@@ -181,6 +188,180 @@ pub async fn populate_collection(
     Ok(())
 }
 
+/// Copy data of the local shard of the `source` collection into the same shard of the `target`
+/// collection, using a temporary shard snapshot.
+///
+/// Local replica of the source shard must be active.
+///
+/// Collections are not locked while the snapshot is created and restored.
+pub async fn clone_local_shard(
+    collections: Arc<RwLock<Collections>>,
+    source_collection: &CollectionId,
+    target_collection: &CollectionId,
+    shard_id: ShardId,
+    this_peer_id: PeerId,
+    is_distributed: bool,
+    temp_dir: &Path,
+) -> CollectionResult<()> {
+    let ((source_shards, source_snapshots_path), (target_shards, _)) = {
+        let collections_read = collections.read().await;
+
+        let source = handle_get_collection(collections_read.get(source_collection))?;
+        let local_state = source
+            .state()
+            .await
+            .shards
+            .get(&shard_id)
+            .and_then(|shard_info| shard_info.replicas.get(&this_peer_id).copied());
+        if local_state != Some(ReplicaState::Active) {
+            return Err(CollectionError::service_error(format!(
+                "Local replica of shard {shard_id} of collection {source_collection} is not active, can't copy its data"
+            )));
+        }
+
+        let target = handle_get_collection(collections_read.get(target_collection))?;
+
+        (
+            source.shard_snapshots_handle(),
+            target.shard_snapshots_handle(),
+        )
+    };
+
+    let snapshot_path = {
+        let source_shards = source_shards.read().await;
+        let snapshot = source_shards
+            .create_shard_snapshot(
+                &source_snapshots_path,
+                source_collection,
+                shard_id,
+                temp_dir,
+            )
+            .await?;
+        source_shards
+            .get_shard_snapshot_path(&source_snapshots_path, shard_id, &snapshot.name)
+            .await?
+    };
+
+    let result = target_shards
+        .read()
+        .await
+        .restore_shard_snapshot(
+            &snapshot_path,
+            target_collection,
+            shard_id,
+            this_peer_id,
+            is_distributed,
+            temp_dir,
+            cancel::CancellationToken::new(),
+        )
+        .await;
+
+    if let Err(err) = tokio::fs::remove_file(&snapshot_path).await {
+        log::warn!(
+            "Failed to remove temporary snapshot {}: {err}",
+            snapshot_path.display()
+        );
+    }
+
+    result
+}
+
+/// Copy points, selected by the `request`, from the `source` collection into the `target` collection,
+/// starting from the `offset` point.
+///
+/// `vectors` maps vector names of the target collection to vector names of the source collection.
+/// `on_batch` is called after each copied batch with the number of copied points and the offset of
+/// the next batch. Copying stops, if it returns `false`.
+pub async fn copy_points(
+    collections: Arc<RwLock<Collections>>,
+    source_collection: &CollectionId,
+    target_collection: &CollectionId,
+    request: &CopyPointsRequest,
+    vectors: &HashMap<String, String>,
+    mut offset: Option<PointIdType>,
+    mut on_batch: impl FnMut(usize, Option<PointIdType>) -> Result<bool, StorageError>,
+) -> Result<(), StorageError> {
+    let with_vector = if vectors.is_empty() {
+        WithVector::Bool(false)
+    } else {
+        WithVector::Selector(vectors.values().cloned().collect())
+    };
+
+    loop {
+        let scroll_request = ScrollRequestInternal {
+            offset,
+            limit: Some(request.batch_size.unwrap_or(DEFAULT_COPY_BATCH_SIZE)),
+            filter: request.filter.clone(),
+            with_payload: Some(
+                request
+                    .with_payload
+                    .clone()
+                    .unwrap_or(WithPayloadInterface::Bool(true)),
+            ),
+            with_vector: with_vector.clone(),
+            order_by: None,
+        };
+
+        let collections_read = collections.read().await;
+
+        let source = handle_get_collection(collections_read.get(source_collection))?;
+        let scroll_result = source
+            .scroll_by(scroll_request, None, &ShardSelectorInternal::All)
+            .await?;
+
+        let next_offset = scroll_result.next_page_offset;
+        let copied = scroll_result.points.len();
+
+        if copied > 0 {
+            let points = scroll_result
+                .points
+                .into_iter()
+                .map(|point| PointStruct {
+                    id: point.id,
+                    vector: rename_vectors(point.vector, vectors),
+                    payload: point.payload,
+                })
+                .collect();
+
+            let upsert_request = CollectionUpdateOperations::PointOperation(
+                PointOperations::UpsertPoints(PointInsertOperationsInternal::PointsList(points)),
+            );
+
+            let target = handle_get_collection(collections_read.get(target_collection))?;
+            target
+                .update_from_client_simple(upsert_request, true, WriteOrdering::default())
+                .await?;
+        }
+
+        drop(collections_read);
+
+        if !on_batch(copied, next_offset)? || next_offset.is_none() {
+            break;
+        }
+        offset = next_offset;
+    }
+
+    Ok(())
+}
+
+/// Select vectors of the source point, which are copied, under their names in the target collection
+fn rename_vectors(vector: Option<VectorStruct>, vectors: &HashMap<String, String>) -> VectorStruct {
+    let source_vectors = vector
+        .map(|vector| vector.into_all_vectors().into_owned_map())
+        .unwrap_or_default();
+
+    let target_vectors = vectors
+        .iter()
+        .filter_map(|(target_name, source_name)| {
+            // Same source vector may be copied into multiple target vectors
+            let vector = source_vectors.get(source_name)?;
+            Some((target_name.clone(), vector.clone()))
+        })
+        .collect();
+
+    NamedVectors::from_map(target_vectors).into()
+}
+
 pub async fn transfer_indexes(
     collections: Arc<RwLock<Collections>>,
     source_collection: &CollectionId,
@@ -10,13 +10,16 @@ mod collections_ops;
 pub mod consensus;
 pub mod consensus_manager;
 pub mod conversions;
+pub mod copy_jobs;
 mod data_transfer;
 pub mod errors;
 pub mod shard_distribution;
+pub mod shard_rebalancer;
 pub mod snapshots;
 pub mod toc;
 
 pub mod consensus_ops {
+    use collection::shards::peer_labels::PeerLabels;
     use collection::shards::replica_set::ReplicaState;
     use collection::shards::replica_set::ReplicaState::Initializing;
     use collection::shards::shard::PeerId;
@@ -37,6 +40,8 @@ pub mod consensus_ops {
         AddPeer {
             peer_id: PeerId,
             uri: String,
+            #[serde(default)]
+            labels: PeerLabels,
         },
         RemovePeer(PeerId),
         RequestSnapshot,
@@ -109,6 +114,9 @@ pub mod consensus_ops {
                     hnsw_config: None,
                     quantization_config: None,
                     sparse_vectors: None,
+                    create_vectors: None,
+                    create_sparse_vectors: None,
+                    delete_vectors: None,
                 },
             );
             operation
@@ -153,6 +161,36 @@ pub mod consensus_ops {
         }
     }
 
+    /// Context of the Raft configuration change which adds a peer
+    #[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone)]
+    pub struct AddPeerContext {
+        pub uri: String,
+        #[serde(default)]
+        pub labels: PeerLabels,
+    }
+
+    impl AddPeerContext {
+        /// Peers without labels are encoded as a plain URI, same as in older versions
+        pub fn to_bytes(&self) -> Result<Vec<u8>, serde_cbor::Error> {
+            if self.labels.is_empty() {
+                Ok(self.uri.clone().into_bytes())
+            } else {
+                serde_cbor::to_vec(self)
+            }
+        }
+
+        pub fn from_bytes(bytes: &[u8]) -> Result<Self, serde_cbor::Error> {
+            // CBOR encoded context is never valid UTF-8, as it starts with a map header
+            match std::str::from_utf8(bytes) {
+                Ok(uri) => Ok(Self {
+                    uri: uri.to_string(),
+                    labels: PeerLabels::default(),
+                }),
+                Err(_) => serde_cbor::from_slice(bytes),
+            }
+        }
+    }
+
     #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Deserialize, Serialize)]
     pub enum SnapshotStatus {
         Finish,
@@ -192,5 +230,9 @@ pub trait CollectionContainer {
 
     fn remove_peer(&self, peer_id: PeerId) -> Result<(), StorageError>;
 
-    fn sync_local_state(&self) -> Result<(), StorageError>;
+    /// Sync local state of all collections with the consensus state
+    ///
+    /// `is_leader` tells whether this peer is the consensus leader, which plans cluster-wide
+    /// changes such as shard rebalancing.
+    fn sync_local_state(&self, is_leader: bool) -> Result<(), StorageError>;
 }
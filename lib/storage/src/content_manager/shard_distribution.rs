@@ -1,9 +1,8 @@
-use std::cmp::{self, Reverse};
-use std::collections::BinaryHeap;
-use std::iter::repeat_with;
+use std::cmp;
 use std::num::NonZeroU32;
 
 use collection::shards::collection_shard_distribution::CollectionShardDistribution;
+use collection::shards::peer_labels::{count_shared_failure_domains, PeerLabelsById};
 use collection::shards::shard::{PeerId, ShardId};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -73,15 +72,17 @@ impl ShardDistributionProposal {
 
     /// Builds a proposal for the distribution of shards.
     /// It will propose to allocate shards so that all peers have the same number of shards of this collection  at the end.
+    ///
+    /// Replicas of the same shard are placed in different zones and racks first, if peers have labels.
     pub fn new(
         shard_number: NonZeroU32,
         replication_factor: NonZeroU32,
         known_peers: &[PeerId],
+        labels_by_id: &PeerLabelsById,
     ) -> Self {
-        // Min-heap: peer with lowest number of shards is on top
-        let mut min_heap: BinaryHeap<_> = known_peers
+        let mut peers: Vec<_> = known_peers
             .iter()
-            .map(|peer| Reverse(PeerShardCount::new(*peer)))
+            .map(|peer| PeerShardCount::new(*peer))
             .collect();
 
         // There should not be more than 1 replica per peer
@@ -90,10 +91,23 @@ impl ShardDistributionProposal {
         // Get fair distribution of shards on peers
         let distribution = (0..shard_number.get())
             .map(|shard_id| {
-                let replicas =
-                    repeat_with(|| min_heap.peek_mut().unwrap().0.get_and_inc_shard_count())
-                        .take(replica_number)
-                        .collect();
+                let mut replicas = Vec::with_capacity(replica_number);
+                for _ in 0..replica_number {
+                    // Peer sharing the least failure domains with other replicas, then the one
+                    // with lowest number of shards
+                    let peer = peers
+                        .iter_mut()
+                        .filter(|peer| !replicas.contains(&peer.peer_id))
+                        .min_by(|a, b| {
+                            let shared_a =
+                                count_shared_failure_domains(labels_by_id, a.peer_id, &replicas);
+                            let shared_b =
+                                count_shared_failure_domains(labels_by_id, b.peer_id, &replicas);
+                            shared_a.cmp(&shared_b).then_with(|| (*a).cmp(b))
+                        })
+                        .unwrap();
+                    replicas.push(peer.get_and_inc_shard_count());
+                }
                 (shard_id, replicas)
             })
             .collect();
@@ -140,6 +154,8 @@ impl From<ShardDistributionProposal> for CollectionShardDistribution {
 mod tests {
     use std::collections::HashSet;
 
+    use collection::shards::peer_labels::PeerLabels;
+
     use super::*;
 
     #[test]
@@ -149,6 +165,7 @@ mod tests {
             NonZeroU32::new(6).unwrap(),
             NonZeroU32::new(1).unwrap(),
             &known_peers,
+            &PeerLabelsById::new(),
         );
 
         // Check it distribution is as even as possible
@@ -188,6 +205,7 @@ mod tests {
                             NonZeroU32::new(shard_number).unwrap(),
                             NonZeroU32::new(replication_factor).unwrap(),
                             &known_peers,
+                            &PeerLabelsById::new(),
                         )
                     })
                     // Take just the inhabited peer IDs
@@ -209,4 +227,35 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_distribution_spreads_replicas_across_zones() {
+        let known_peers = vec![1, 2, 3, 4, 5, 6];
+        let zone = |zone: &str| PeerLabels {
+            zone: Some(zone.to_string()),
+            rack: None,
+        };
+        let labels_by_id = PeerLabelsById::from([
+            (1, zone("a")),
+            (2, zone("a")),
+            (3, zone("a")),
+            (4, zone("b")),
+            (5, zone("b")),
+            (6, zone("c")),
+        ]);
+
+        for _ in 0..100 {
+            let proposal = ShardDistributionProposal::new(
+                NonZeroU32::new(6).unwrap(),
+                NonZeroU32::new(3).unwrap(),
+                &known_peers,
+                &labels_by_id,
+            );
+
+            for (shard_id, peers) in &proposal.distribution {
+                let zones: HashSet<_> = peers.iter().map(|peer| &labels_by_id[peer].zone).collect();
+                assert_eq!(zones.len(), 3, "replicas of shard {shard_id} share a zone");
+            }
+        }
+    }
 }
@@ -0,0 +1,373 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use collection::collection_state::ShardInfo;
+use collection::shards::peer_labels::{count_shared_failure_domains, PeerLabelsById};
+use collection::shards::replica_set::ReplicaState;
+use collection::shards::shard::{PeerId, ShardId};
+use collection::shards::transfer::ShardTransfer;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Number of shard replicas and their total size on a single peer
+#[derive(Debug, Deserialize, Serialize, JsonSchema, PartialEq, Eq, Clone)]
+pub struct PeerShardLoad {
+    pub peer_id: PeerId,
+    /// Number of shard replicas located on this peer
+    pub shard_count: usize,
+    /// Estimated size of all shard replicas located on this peer, in bytes
+    pub shards_size_bytes: u64,
+}
+
+/// Move of a single shard replica from one peer to another
+#[derive(Debug, Deserialize, Serialize, JsonSchema, PartialEq, Eq, Hash, Clone)]
+pub struct PlannedShardMove {
+    pub shard_id: ShardId,
+    pub from_peer_id: PeerId,
+    pub to_peer_id: PeerId,
+    /// Estimated size of the moved shard, in bytes
+    pub size_bytes: u64,
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema, PartialEq, Eq, Clone)]
+pub struct RebalancePlan {
+    /// Current load of every known peer, including the pending transfers
+    pub peers: Vec<PeerShardLoad>,
+    /// Moves to perform to balance the cluster, in order
+    pub moves: Vec<PlannedShardMove>,
+}
+
+impl RebalancePlan {
+    /// Builds a plan which evens out the number of shard replicas across the known peers with
+    /// the least amount of moves.
+    ///
+    /// Replicas are always moved from the most loaded peer to the least loaded one, until the
+    /// numbers of replicas on any two peers differ by at most one. Among the movable shards the
+    /// one whose other replicas share the least zones and racks with the target peer is picked,
+    /// then the one that best evens out the size of both peers.
+    ///
+    /// A shard is movable only if all of its replicas are active, and each shard is moved at most
+    /// once per plan. Sizes of shards missing in `shard_sizes` are estimated as the average size
+    /// of the known ones.
+    ///
+    /// `pending` transfers, either in progress or proposed but not yet accepted by consensus, are
+    /// treated as already finished, and their shards are never moved again.
+    pub fn new(
+        known_peers: &[PeerId],
+        shards: &HashMap<ShardId, ShardInfo>,
+        shard_sizes: &HashMap<ShardId, u64>,
+        labels_by_id: &PeerLabelsById,
+        pending: &[ShardTransfer],
+    ) -> Self {
+        let average_size = if shard_sizes.is_empty() {
+            0
+        } else {
+            shard_sizes.values().sum::<u64>() / shard_sizes.len() as u64
+        };
+        let size_of =
+            |shard_id: &ShardId| shard_sizes.get(shard_id).copied().unwrap_or(average_size);
+
+        // Peers which currently hold a replica, by shard. Sorted for a deterministic plan.
+        let mut placement: BTreeMap<ShardId, HashSet<PeerId>> = shards
+            .iter()
+            .map(|(shard_id, info)| (*shard_id, info.replicas.keys().copied().collect()))
+            .collect();
+
+        let mut moved = HashSet::new();
+        for transfer in pending {
+            let Some(replicas) = placement.get_mut(&transfer.shard_id) else {
+                continue;
+            };
+            replicas.insert(transfer.to);
+            if !transfer.sync {
+                replicas.remove(&transfer.from);
+            }
+            moved.insert(transfer.shard_id);
+        }
+
+        let mut loads: BTreeMap<PeerId, PeerShardLoad> = known_peers
+            .iter()
+            .map(|peer_id| {
+                let load = PeerShardLoad {
+                    peer_id: *peer_id,
+                    shard_count: 0,
+                    shards_size_bytes: 0,
+                };
+                (*peer_id, load)
+            })
+            .collect();
+
+        for (shard_id, peers) in &placement {
+            for peer_id in peers {
+                // Replicas on unknown peers can't be balanced, so they are not accounted for
+                if let Some(load) = loads.get_mut(peer_id) {
+                    load.shard_count += 1;
+                    load.shards_size_bytes += size_of(shard_id);
+                }
+            }
+        }
+
+        let peers: Vec<_> = loads.values().cloned().collect();
+
+        let movable: HashSet<ShardId> = shards
+            .iter()
+            .filter(|(_, info)| {
+                info.replicas
+                    .values()
+                    .all(|state| *state == ReplicaState::Active)
+            })
+            .map(|(shard_id, _)| *shard_id)
+            .collect();
+
+        let mut moves = Vec::new();
+
+        while let Some(shard_move) =
+            Self::next_move(&loads, &placement, &movable, &moved, labels_by_id, size_of)
+        {
+            let size_bytes = shard_move.size_bytes;
+
+            let from = loads.get_mut(&shard_move.from_peer_id).unwrap();
+            from.shard_count -= 1;
+            from.shards_size_bytes -= size_bytes;
+
+            let to = loads.get_mut(&shard_move.to_peer_id).unwrap();
+            to.shard_count += 1;
+            to.shards_size_bytes += size_bytes;
+
+            let replicas = placement.get_mut(&shard_move.shard_id).unwrap();
+            replicas.remove(&shard_move.from_peer_id);
+            replicas.insert(shard_move.to_peer_id);
+
+            moved.insert(shard_move.shard_id);
+            moves.push(shard_move);
+        }
+
+        Self { peers, moves }
+    }
+
+    /// Find the next move from a loaded peer to an underloaded one, if there is any
+    fn next_move(
+        loads: &BTreeMap<PeerId, PeerShardLoad>,
+        placement: &BTreeMap<ShardId, HashSet<PeerId>>,
+        movable: &HashSet<ShardId>,
+        moved: &HashSet<ShardId>,
+        labels_by_id: &PeerLabelsById,
+        size_of: impl Fn(&ShardId) -> u64,
+    ) -> Option<PlannedShardMove> {
+        // Least loaded peers first
+        let mut by_load: Vec<_> = loads.values().collect();
+        by_load.sort_by_key(|load| (load.shard_count, load.shards_size_bytes, load.peer_id));
+
+        for from in by_load.iter().rev() {
+            for to in by_load.iter() {
+                if from.shard_count < to.shard_count + 2 {
+                    // Moving anything here would not improve the balance
+                    break;
+                }
+
+                let size_difference = from.shards_size_bytes as i128 - to.shards_size_bytes as i128;
+
+                let best_shard = placement
+                    .iter()
+                    .filter(|(shard_id, peers)| {
+                        movable.contains(shard_id)
+                            && !moved.contains(shard_id)
+                            && peers.contains(&from.peer_id)
+                            && !peers.contains(&to.peer_id)
+                    })
+                    .min_by_key(|(shard_id, peers)| {
+                        let other_replicas = peers.iter().filter(|peer| **peer != from.peer_id);
+                        (
+                            count_shared_failure_domains(labels_by_id, to.peer_id, other_replicas),
+                            // Size difference between both peers after the move
+                            (size_difference - 2 * size_of(shard_id) as i128).unsigned_abs(),
+                        )
+                    })
+                    .map(|(shard_id, _)| *shard_id);
+
+                if let Some(shard_id) = best_shard {
+                    return Some(PlannedShardMove {
+                        shard_id,
+                        from_peer_id: from.peer_id,
+                        to_peer_id: to.peer_id,
+                        size_bytes: size_of(&shard_id),
+                    });
+                }
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use collection::shards::peer_labels::PeerLabels;
+
+    use super::*;
+
+    fn shards(placement: &[(ShardId, &[PeerId])]) -> HashMap<ShardId, ShardInfo> {
+        placement
+            .iter()
+            .map(|(shard_id, peers)| {
+                let replicas = peers
+                    .iter()
+                    .map(|peer_id| (*peer_id, ReplicaState::Active))
+                    .collect();
+                (*shard_id, ShardInfo { replicas })
+            })
+            .collect()
+    }
+
+    fn shard_counts(plan: &RebalancePlan) -> HashMap<PeerId, usize> {
+        let mut counts: HashMap<_, _> = plan
+            .peers
+            .iter()
+            .map(|load| (load.peer_id, load.shard_count))
+            .collect();
+        for shard_move in &plan.moves {
+            *counts.get_mut(&shard_move.from_peer_id).unwrap() -= 1;
+            *counts.get_mut(&shard_move.to_peer_id).unwrap() += 1;
+        }
+        counts
+    }
+
+    #[test]
+    fn test_balanced_cluster_is_left_alone() {
+        let shards = shards(&[(0, &[1]), (1, &[2]), (2, &[3]), (3, &[1])]);
+        let plan = RebalancePlan::new(
+            &[1, 2, 3],
+            &shards,
+            &HashMap::new(),
+            &PeerLabelsById::new(),
+            &[],
+        );
+        assert!(plan.moves.is_empty());
+    }
+
+    #[test]
+    fn test_new_peer_receives_shards() {
+        let shards = shards(&[
+            (0, &[1, 2]),
+            (1, &[1, 2]),
+            (2, &[1, 2]),
+            (3, &[1, 2]),
+            (4, &[1, 2]),
+            (5, &[1, 2]),
+        ]);
+        let plan = RebalancePlan::new(
+            &[1, 2, 3],
+            &shards,
+            &HashMap::new(),
+            &PeerLabelsById::new(),
+            &[],
+        );
+
+        // 12 replicas on 3 peers, 4 of them have to be moved
+        assert_eq!(plan.moves.len(), 4);
+        assert!(shard_counts(&plan).values().all(|count| *count == 4));
+        assert!(plan
+            .moves
+            .iter()
+            .all(|shard_move| shard_move.to_peer_id == 3));
+
+        // Never move two replicas of the same shard to the same peer
+        let moved_shards: HashSet<_> = plan.moves.iter().map(|m| m.shard_id).collect();
+        assert_eq!(moved_shards.len(), plan.moves.len());
+    }
+
+    #[test]
+    fn test_pending_transfers_are_accounted() {
+        let shards = shards(&[
+            (0, &[1, 2]),
+            (1, &[1, 2]),
+            (2, &[1, 2]),
+            (3, &[1, 2]),
+            (4, &[1, 2]),
+            (5, &[1, 2]),
+        ]);
+        let pending = [ShardTransfer {
+            shard_id: 0,
+            from: 1,
+            to: 3,
+            sync: false,
+            method: None,
+        }];
+        let plan = RebalancePlan::new(
+            &[1, 2, 3],
+            &shards,
+            &HashMap::new(),
+            &PeerLabelsById::new(),
+            &pending,
+        );
+
+        // One of 4 required moves is already pending
+        assert_eq!(plan.moves.len(), 3);
+        assert!(shard_counts(&plan).values().all(|count| *count == 4));
+        assert!(plan.moves.iter().all(|shard_move| shard_move.shard_id != 0));
+    }
+
+    #[test]
+    fn test_sizes_are_balanced() {
+        let shards = shards(&[(0, &[1]), (1, &[1]), (2, &[1]), (3, &[1])]);
+        let sizes = HashMap::from([(0, 100), (1, 10), (2, 10), (3, 100)]);
+        let plan = RebalancePlan::new(&[1, 2], &shards, &sizes, &PeerLabelsById::new(), &[]);
+
+        assert_eq!(plan.moves.len(), 2);
+        let moved_size: u64 = plan.moves.iter().map(|m| m.size_bytes).sum();
+        assert_eq!(moved_size, 110);
+    }
+
+    #[test]
+    fn test_inactive_shards_are_not_moved() {
+        let mut shards = shards(&[(0, &[1]), (1, &[1]), (2, &[1])]);
+        shards
+            .get_mut(&0)
+            .unwrap()
+            .replicas
+            .insert(1, ReplicaState::Partial);
+        shards
+            .get_mut(&1)
+            .unwrap()
+            .replicas
+            .insert(1, ReplicaState::Dead);
+
+        let plan = RebalancePlan::new(
+            &[1, 2],
+            &shards,
+            &HashMap::new(),
+            &PeerLabelsById::new(),
+            &[],
+        );
+        assert_eq!(plan.moves.len(), 1);
+        assert_eq!(plan.moves[0].shard_id, 2);
+    }
+
+    #[test]
+    fn test_moves_avoid_shared_zones() {
+        // Peer 3 is located in the same zone as peer 1
+        let zone = |zone: &str| PeerLabels {
+            zone: Some(zone.to_string()),
+            rack: None,
+        };
+        let labels_by_id = PeerLabelsById::from([
+            (1, zone("a")),
+            (2, zone("b")),
+            (3, zone("a")),
+            (4, zone("c")),
+        ]);
+
+        // Peer 2 holds all replicas, the other replica of every shard is on peer 1 or peer 4
+        let shards = shards(&[(0, &[2, 1]), (1, &[2, 4]), (2, &[2, 1]), (3, &[2, 4])]);
+        let plan = RebalancePlan::new(&[1, 2, 3, 4], &shards, &HashMap::new(), &labels_by_id, &[]);
+
+        let to_peer_3: Vec<_> = plan
+            .moves
+            .iter()
+            .filter(|shard_move| shard_move.to_peer_id == 3)
+            .collect();
+        assert!(!to_peer_3.is_empty());
+        assert!(to_peer_3
+            .iter()
+            .all(|shard_move| shards[&shard_move.shard_id].replicas.contains_key(&4)));
+    }
+}
@@ -66,7 +66,7 @@ impl CollectionContainer for TableOfContent {
         })
     }
 
-    fn sync_local_state(&self) -> Result<(), StorageError> {
+    fn sync_local_state(&self, is_leader: bool) -> Result<(), StorageError> {
         self.general_runtime.block_on(async {
             let collections = self.collections.read().await;
             let transfer_failure_callback =
@@ -103,6 +103,10 @@ impl CollectionContainer for TableOfContent {
                         convert_from_listener_to_active_callback,
                     )
                     .await?;
+
+                if is_leader {
+                    self.spawn_auto_rebalance_check(collection).await;
+                }
             }
             Ok(())
         })
@@ -1,8 +1,9 @@
 use std::collections::HashSet;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
+use collection::collection::Collection;
 use collection::collection_state;
-use collection::config::ShardingMethod;
+use collection::config::{CollectionConfig, ShardingMethod};
 use collection::shards::collection_shard_distribution::CollectionShardDistribution;
 use collection::shards::replica_set::ReplicaState;
 use collection::shards::{transfer, CollectionId};
@@ -60,6 +61,22 @@ impl TableOfContent {
                 log::info!("Deleting collection {}", operation.0);
                 self.delete_collection(&operation.0).await
             }
+            CollectionMetaOperations::RenameCollection(operation) => {
+                log::info!(
+                    "Renaming collection {} to {}",
+                    operation.collection_name,
+                    operation.rename_collection.new_collection_name
+                );
+                self.rename_collection(operation).await
+            }
+            CollectionMetaOperations::CloneCollection(operation) => {
+                log::info!(
+                    "Cloning collection {} into {}",
+                    operation.collection_name,
+                    operation.clone_collection.new_collection_name
+                );
+                self.clone_collection(operation).await
+            }
             CollectionMetaOperations::ChangeAliases(operation) => {
                 log::debug!("Changing aliases");
                 self.update_aliases(operation).await
@@ -111,16 +128,32 @@ impl TableOfContent {
             optimizers_config,
             quantization_config,
             sparse_vectors,
+            create_vectors,
+            create_sparse_vectors,
+            delete_vectors,
         } = operation.update_collection;
         let collection = self.get_collection(&operation.collection_name).await?;
         let mut recreate_optimizers = false;
 
+        // Vectors are removed and created first, so other parameters may refer to new vectors
+        if let Some(vector_names) = delete_vectors {
+            collection.delete_vectors(&vector_names).await?;
+            recreate_optimizers = true;
+        }
+        if create_vectors.is_some() || create_sparse_vectors.is_some() {
+            collection
+                .create_vectors(create_vectors.as_ref(), create_sparse_vectors.as_ref())
+                .await?;
+            recreate_optimizers = true;
+        }
+
         if let Some(diff) = optimizers_config {
             collection.update_optimizer_params_from_diff(diff).await?;
             recreate_optimizers = true;
         }
         if let Some(diff) = params {
             collection.update_params_from_diff(diff).await?;
+            collection.create_expiry_index().await?;
             recreate_optimizers = true;
         }
         if let Some(diff) = hnsw_config {
@@ -162,35 +195,261 @@ impl TableOfContent {
                 .await
                 .remove_collection(collection_name)?;
 
-            let path = self.get_collection_path(collection_name);
             drop(removed);
+            self.remove_collection_data(collection_name).await?;
+            self.remove_copy_job(collection_name)?;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Removes files of the collection, which is already dropped from memory
+    async fn remove_collection_data(&self, collection_name: &str) -> Result<(), StorageError> {
+        let deleted_path = self
+            .move_collection_data_to_deleted(collection_name)
+            .await?;
+
+        // At this point collection is removed from memory and moved to ".deleted" folder.
+        // Next time we load service the collection will not appear in the list of collections.
+        // We can take our time to delete the collection from disk.
+        Self::delete_collection_data(deleted_path);
+        Ok(())
+    }
+
+    /// Moves files of the collection to ".deleted" folder, returns their new location
+    async fn move_collection_data_to_deleted(
+        &self,
+        collection_name: &str,
+    ) -> Result<PathBuf, StorageError> {
+        let path = self.get_collection_path(collection_name);
+
+        // Move collection to ".deleted" folder to prevent accidental reuse
+        let uuid = Uuid::new_v4().to_string();
+        let removed_collections_path =
+            Path::new(&self.storage_config.storage_path).join(".deleted");
+        tokio::fs::create_dir_all(&removed_collections_path).await?;
+        let deleted_path = removed_collections_path
+            .join(collection_name)
+            .with_extension(uuid);
+        tokio::fs::rename(path, &deleted_path).await?;
+        Ok(deleted_path)
+    }
+
+    fn delete_collection_data(deleted_path: PathBuf) {
+        tokio::spawn(async move {
+            if let Err(error) = tokio::fs::remove_dir_all(&deleted_path).await {
+                log::error!(
+                    "Can't delete collection {} from disk. Error: {}",
+                    deleted_path.display(),
+                    error
+                );
+            }
+        });
+    }
+
+    /// Renames collection, optionally replacing an existing collection with the new name.
+    ///
+    /// Collections are locked only to take the renamed and the replaced collection out of the map
+    /// and to put the reloaded one back in, together with its aliases. Files are moved and the
+    /// collection is loaded without blocking requests to other collections. Meanwhile requests to
+    /// both collections fail with not found, they never observe a partially renamed collection.
+    ///
+    /// If moving the files fails, both collections are loaded again with their original names.
+    /// Data of the replaced collection is deleted only once the renamed collection is loaded.
+    async fn rename_collection(
+        &self,
+        operation: RenameCollectionOperation,
+    ) -> Result<bool, StorageError> {
+        let RenameCollectionOperation {
+            collection_name,
+            rename_collection:
+                RenameCollection {
+                    new_collection_name,
+                    replace_existing,
+                },
+        } = operation;
+
+        if collection_name == new_collection_name {
+            return Err(StorageError::bad_input(format!(
+                "Can't rename collection {collection_name} to the same name"
+            )));
+        }
+
+        // Renaming moves collection files, prevent creating a collection in parallel
+        let _collection_create_guard = self.collection_create_lock.lock().await;
+
+        let (renamed, replaced) = {
+            let mut collections = self.collections.write().await;
+            let aliases = self.alias_persistence.read().await;
+
+            collections
+                .validate_collection_exists(&collection_name)
+                .await?;
+            if !replace_existing {
+                collections
+                    .validate_collection_not_exists(&new_collection_name)
+                    .await?;
+            }
+            if aliases.check_alias_exists(&new_collection_name) {
+                return Err(StorageError::bad_input(format!(
+                    "Can't rename collection {collection_name} to {new_collection_name}. Alias with the same name already exists",
+                )));
+            }
+
+            for name in [&collection_name, &new_collection_name] {
+                if let Some(collection) = collections.get(name) {
+                    if !collection.state().await.transfers.is_empty() {
+                        return Err(StorageError::bad_input(format!(
+                            "Can't rename collection {collection_name} to {new_collection_name}. Collection {name} has ongoing shard transfers",
+                        )));
+                    }
+                }
+            }
+
+            // Copying points into the collection doesn't survive the rename
+            self.remove_copy_job(&collection_name)?;
+            self.remove_copy_job(&new_collection_name)?;
+
+            // Both snapshot directories are required to load either collection back
+            self.create_snapshots_path(&collection_name).await?;
+            self.create_snapshots_path(&new_collection_name).await?;
+
+            (
+                collections.remove(&collection_name),
+                collections.remove(&new_collection_name),
+            )
+        };
+
+        // Drop collections to stop all their workers before moving the files
+        let is_replacing = replaced.is_some();
+        drop(renamed);
+        drop(replaced);
+
+        let replaced_path = match self
+            .move_renamed_collection_files(&collection_name, &new_collection_name, is_replacing)
+            .await
+        {
+            Ok(replaced_path) => replaced_path,
+            Err(err) => {
+                log::error!(
+                    "Can't rename collection {collection_name} to {new_collection_name}, loading it back: {err}"
+                );
+                let mut restored = vec![];
+                let replaced_name = is_replacing.then_some(&new_collection_name);
+                for name in std::iter::once(&collection_name).chain(replaced_name) {
+                    // Files which could not be put back are not loaded
+                    if CollectionConfig::check(&self.get_collection_path(name)) {
+                        restored.push((name.clone(), self.load_collection(name).await));
+                    }
+                }
+                let mut collections = self.collections.write().await;
+                for (name, collection) in restored {
+                    collections.insert(name, collection);
+                }
+                return Err(err);
+            }
+        };
+
+        let collection = self.load_collection(&new_collection_name).await;
+
+        {
+            let mut collections = self.collections.write().await;
+            let mut aliases = self.alias_persistence.write().await;
+            collections.insert(new_collection_name.clone(), collection);
+
+            if let Some(replaced_path) = replaced_path {
+                Self::delete_collection_data(replaced_path);
+            }
+
+            // Aliases follow the renamed collection, aliases of the replaced one now point to it too
+            aliases.rename_collection(&collection_name, &new_collection_name)?;
+        }
+
+        // Snapshots of the replaced collection are kept next to the snapshots of the renamed one
+        self.move_collection_snapshots(&collection_name, &new_collection_name)
+            .await?;
+
+        Ok(true)
+    }
+
+    /// Moves files of the renamed collection to its new name. Files of the replaced collection
+    /// are moved to ".deleted" folder and their location is returned, so they could be removed
+    /// once the renamed collection is loaded.
+    ///
+    /// On error, moved files are put back to their original location.
+    async fn move_renamed_collection_files(
+        &self,
+        collection_name: &str,
+        new_collection_name: &str,
+        is_replacing: bool,
+    ) -> Result<Option<PathBuf>, StorageError> {
+        let collection_path = self.get_collection_path(collection_name);
+        let new_collection_path = self.get_collection_path(new_collection_name);
+
+        let replaced_path = if is_replacing {
+            Some(
+                self.move_collection_data_to_deleted(new_collection_name)
+                    .await?,
+            )
+        } else {
+            None
+        };
+
+        let moved = async {
+            self.create_collection_path(new_collection_name).await?;
+            // Target directory is empty, so it is replaced by the rename
+            tokio::fs::rename(&collection_path, &new_collection_path).await?;
+            Ok::<_, StorageError>(())
+        }
+        .await;
 
-            // Move collection to ".deleted" folder to prevent accidental reuse
-            let uuid = Uuid::new_v4().to_string();
-            let removed_collections_path =
-                Path::new(&self.storage_config.storage_path).join(".deleted");
-            tokio::fs::create_dir_all(&removed_collections_path).await?;
-            let deleted_path = removed_collections_path
-                .join(collection_name)
-                .with_extension(uuid);
-            tokio::fs::rename(path, &deleted_path).await?;
-
-            // At this point collection is removed from memory and moved to ".deleted" folder.
-            // Next time we load service the collection will not appear in the list of collections.
-            // We can take our time to delete the collection from disk.
-            tokio::spawn(async move {
-                if let Err(error) = tokio::fs::remove_dir_all(&deleted_path).await {
+        if let Err(err) = moved {
+            if let Some(replaced_path) = &replaced_path {
+                // Target directory is empty or missing, so it is replaced by the rename
+                if let Err(err) = tokio::fs::rename(replaced_path, &new_collection_path).await {
                     log::error!(
-                        "Can't delete collection {} from disk. Error: {}",
-                        deleted_path.display(),
-                        error
+                        "Can't restore collection {new_collection_name} from {}: {err}",
+                        replaced_path.display(),
                     );
                 }
-            });
-            Ok(true)
-        } else {
-            Ok(false)
+            }
+            return Err(err);
         }
+
+        Ok(replaced_path)
+    }
+
+    /// Loads collection from its directory, its snapshots directory must exist
+    async fn load_collection(&self, collection_name: &str) -> Collection {
+        let collection_name = collection_name.to_string();
+        Collection::load(
+            collection_name.clone(),
+            self.this_peer_id,
+            &self.get_collection_path(&collection_name),
+            &self.snapshots_path_for_collection(&collection_name),
+            self.storage_config
+                .to_shared_storage_config(self.is_distributed())
+                .into(),
+            self.channel_service.clone(),
+            Self::change_peer_state_callback(
+                self.consensus_proposal_sender.clone(),
+                collection_name.clone(),
+                ReplicaState::Dead,
+                None,
+            ),
+            Self::request_shard_transfer_callback(
+                self.consensus_proposal_sender.clone(),
+                collection_name.clone(),
+            ),
+            Self::abort_shard_transfer_callback(
+                self.consensus_proposal_sender.clone(),
+                collection_name.clone(),
+            ),
+            Some(self.search_runtime.handle().clone()),
+            Some(self.update_runtime.handle().clone()),
+        )
+        .await
     }
 
     /// performs several alias changes in an atomic fashion
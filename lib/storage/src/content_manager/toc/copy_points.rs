@@ -0,0 +1,170 @@
+use std::sync::Arc;
+
+use collection::config::CollectionParams;
+use collection::shards::CollectionId;
+use tokio::sync::RwLock;
+
+use super::TableOfContent;
+use crate::content_manager::collections_ops::Collections;
+use crate::content_manager::copy_jobs::{
+    resolve_copied_vectors, CopyJob, CopyJobStatus, CopyJobs, CopyPointsRequest,
+};
+use crate::content_manager::data_transfer;
+use crate::content_manager::errors::StorageError;
+
+impl TableOfContent {
+    /// Start a job, which copies points of another collection into the given collection.
+    ///
+    /// The job runs in background on this peer, its progress is persisted after each batch.
+    /// If the previous job with the same parameters has failed, it is resumed from the point
+    /// where it stopped.
+    pub async fn start_copy_points(
+        &self,
+        collection_name: &str,
+        mut request: CopyPointsRequest,
+    ) -> Result<CopyJob, StorageError> {
+        self.check_write_lock()?;
+
+        let (target_name, target_params) = self.collection_name_and_params(collection_name).await?;
+        let (source_name, source_params) = self
+            .collection_name_and_params(&request.source_collection)
+            .await?;
+
+        if source_name == target_name {
+            return Err(StorageError::bad_input(format!(
+                "Can't copy points of collection {target_name} into itself"
+            )));
+        }
+
+        resolve_copied_vectors(&source_params, &target_params, request.vectors.as_ref())?;
+
+        // Source may be referenced by alias, which can be switched while job is running
+        request.source_collection = source_name;
+
+        let job = {
+            let mut copy_jobs = self.copy_jobs.lock();
+            let job = match copy_jobs.get(&target_name) {
+                Some(job) if job.status == CopyJobStatus::Running => {
+                    return Err(StorageError::bad_input(format!(
+                        "Points are already being copied into collection {target_name}"
+                    )));
+                }
+                Some(job) if job.status == CopyJobStatus::Failed && job.request == request => {
+                    CopyJob {
+                        status: CopyJobStatus::Running,
+                        error: None,
+                        ..job.clone()
+                    }
+                }
+                _ => CopyJob::new(request),
+            };
+            copy_jobs.insert(target_name.clone(), job.clone())?;
+            job
+        };
+
+        self.run_copy_job(target_name);
+
+        Ok(job)
+    }
+
+    /// Get state of the latest job, copying points into the given collection
+    pub async fn get_copy_points_job(
+        &self,
+        collection_name: &str,
+    ) -> Result<CopyJob, StorageError> {
+        let collection_name = self.get_collection(collection_name).await?.name();
+        self.copy_jobs
+            .lock()
+            .get(&collection_name)
+            .cloned()
+            .ok_or_else(|| StorageError::NotFound {
+                description: format!("No points were copied into collection {collection_name}"),
+            })
+    }
+
+    pub(super) fn resume_copy_jobs(&self) {
+        let running = self.copy_jobs.lock().running();
+        for collection_name in running {
+            log::info!("Resuming copying points into collection {collection_name}");
+            self.run_copy_job(collection_name);
+        }
+    }
+
+    pub(super) fn remove_copy_job(&self, collection_name: &str) -> Result<(), StorageError> {
+        self.copy_jobs.lock().remove(collection_name)
+    }
+
+    async fn collection_name_and_params(
+        &self,
+        collection_name: &str,
+    ) -> Result<(CollectionId, CollectionParams), StorageError> {
+        let collection = self.get_collection(collection_name).await?;
+        Ok((collection.name(), collection.state().await.config.params))
+    }
+
+    fn run_copy_job(&self, collection_name: CollectionId) {
+        let collections = self.collections.clone();
+        let copy_jobs = self.copy_jobs.clone();
+
+        self.general_runtime.spawn(async move {
+            let result = copy_points_task(collections, &copy_jobs, &collection_name).await;
+
+            let error = match result {
+                Ok(()) => {
+                    log::info!("Finished copying points into collection {collection_name}");
+                    None
+                }
+                Err(err) => {
+                    log::error!("Failed to copy points into collection {collection_name}: {err}");
+                    Some(err.to_string())
+                }
+            };
+
+            if let Err(err) = copy_jobs.lock().finish(&collection_name, error) {
+                log::error!("Can't save state of copy job of collection {collection_name}: {err}");
+            }
+        });
+    }
+}
+
+async fn copy_points_task(
+    collections: Arc<RwLock<Collections>>,
+    copy_jobs: &parking_lot::Mutex<CopyJobs>,
+    target_collection: &CollectionId,
+) -> Result<(), StorageError> {
+    let Some(job) = copy_jobs.lock().get(target_collection).cloned() else {
+        return Ok(());
+    };
+    let source_collection = &job.request.source_collection;
+
+    // Collection configs may have changed since the job was started
+    let vectors = {
+        let collections_read = collections.read().await;
+        let mut params = Vec::with_capacity(2);
+        for collection_name in [source_collection, target_collection] {
+            let collection =
+                collections_read
+                    .get(collection_name)
+                    .ok_or_else(|| StorageError::NotFound {
+                        description: format!("Collection `{collection_name}` doesn't exist!"),
+                    })?;
+            params.push(collection.state().await.config.params);
+        }
+        resolve_copied_vectors(&params[0], &params[1], job.request.vectors.as_ref())?
+    };
+
+    data_transfer::copy_points(
+        collections,
+        source_collection,
+        target_collection,
+        &job.request,
+        &vectors,
+        job.next_offset,
+        |copied, next_offset| {
+            copy_jobs
+                .lock()
+                .update_progress(target_collection, copied, next_offset)
+        },
+    )
+    .await
+}
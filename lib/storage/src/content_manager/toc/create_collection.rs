@@ -2,21 +2,24 @@ use std::collections::BTreeMap;
 use std::num::NonZeroU32;
 
 use collection::collection::Collection;
+use collection::collection_state;
 use collection::config::{
     self, default_shard_number, CollectionConfig, CollectionParams, ShardingMethod,
 };
 use collection::operations::config_diff::DiffConfig as _;
 use collection::operations::types::{
-    check_sparse_compatible, CollectionResult, SparseVectorParams, VectorsConfig,
+    check_sparse_compatible, CollectionError, CollectionResult, SparseVectorParams, VectorsConfig,
 };
 use collection::shards::collection_shard_distribution::CollectionShardDistribution;
 use collection::shards::replica_set::ReplicaState;
 use collection::shards::shard::{PeerId, ShardId};
 use collection::shards::CollectionId;
+use tokio::sync::RwLock;
 
 use super::TableOfContent;
 use crate::content_manager::collection_meta_ops::*;
-use crate::content_manager::collections_ops::Checker as _;
+use crate::content_manager::collections_ops::{Checker as _, Collections};
+use crate::content_manager::consensus::operation_sender::OperationSender;
 use crate::content_manager::consensus_ops::ConsensusOperations;
 use crate::content_manager::data_transfer;
 use crate::content_manager::errors::StorageError;
@@ -46,6 +49,9 @@ impl TableOfContent {
             init_from,
             quantization_config,
             sparse_vectors,
+            expiry,
+            payload_schema,
+            strict_mode,
         } = operation;
 
         self.collections
@@ -125,6 +131,10 @@ impl TableOfContent {
                 },
             )?,
             read_fan_out_factor: None,
+            auto_rebalance: false,
+            expiry,
+            payload_schema,
+            strict_mode,
         };
         let wal_config = match wal_config_diff {
             None => self.storage_config.wal.clone(),
@@ -186,6 +196,8 @@ impl TableOfContent {
         )
         .await?;
 
+        collection.create_expiry_index().await?;
+
         let local_shards = collection.get_local_shards().await;
 
         {
@@ -212,6 +224,260 @@ impl TableOfContent {
         Ok(true)
     }
 
+    /// Creates a copy of the collection with the same shard layout.
+    ///
+    /// Config of the copy may be changed, e.g. to reindex vectors with other parameters.
+    /// Data of every shard is copied in the background from a local shard snapshot of one of its
+    /// source replicas, other replicas of the copy are rebuilt from it by replica transfers.
+    /// Updates of the source collection, which happen while data is being copied, may not be
+    /// present in the copy.
+    pub(super) async fn clone_collection(
+        &self,
+        operation: CloneCollectionOperation,
+    ) -> Result<bool, StorageError> {
+        let CloneCollectionOperation {
+            collection_name,
+            clone_collection:
+                CloneCollection {
+                    new_collection_name,
+                    vectors,
+                    hnsw_config,
+                    quantization_config,
+                },
+        } = operation;
+
+        let collection_create_guard = self.collection_create_lock.lock().await;
+
+        // Source collection may be referenced by alias
+        let (source_name, source_state) = {
+            let source = self.get_collection(&collection_name).await?;
+            (source.name(), source.state().await)
+        };
+        let collection_state::State {
+            config: mut collection_config,
+            shards,
+            payload_index_schema,
+            ..
+        } = source_state;
+
+        if collection_config.params.sharding_method.unwrap_or_default() == ShardingMethod::Custom {
+            return Err(StorageError::bad_input(format!(
+                "Can't clone collection {collection_name} with custom sharding method"
+            )));
+        }
+
+        self.collections
+            .read()
+            .await
+            .validate_collection_not_exists(&new_collection_name)
+            .await?;
+
+        if self
+            .alias_persistence
+            .read()
+            .await
+            .check_alias_exists(&new_collection_name)
+        {
+            return Err(StorageError::bad_input(format!(
+                "Can't create collection with name {new_collection_name}. Alias with the same name already exists",
+            )));
+        }
+
+        if let Some(diff) = &vectors {
+            diff.check_vector_names(&collection_config.params)?;
+            collection_config.params.update_vectors_from_diff(diff)?;
+        }
+        if let Some(diff) = hnsw_config {
+            collection_config.hnsw_config = diff.update(&collection_config.hnsw_config)?;
+        }
+        if let Some(diff) = quantization_config {
+            collection_config.quantization_config = diff.into_config();
+        }
+
+        let collection_path = self.create_collection_path(&new_collection_name).await?;
+        let snapshots_path = self.create_snapshots_path(&new_collection_name).await?;
+
+        let storage_config = self
+            .storage_config
+            .to_shared_storage_config(self.is_distributed())
+            .into();
+
+        let collection = Collection::new(
+            new_collection_name.clone(),
+            self.this_peer_id,
+            &collection_path,
+            &snapshots_path,
+            &collection_config,
+            storage_config,
+            CollectionShardDistribution::from_shards_info(shards),
+            self.channel_service.clone(),
+            Self::change_peer_state_callback(
+                self.consensus_proposal_sender.clone(),
+                new_collection_name.clone(),
+                ReplicaState::Dead,
+                None,
+            ),
+            Self::request_shard_transfer_callback(
+                self.consensus_proposal_sender.clone(),
+                new_collection_name.clone(),
+            ),
+            Self::abort_shard_transfer_callback(
+                self.consensus_proposal_sender.clone(),
+                new_collection_name.clone(),
+            ),
+            Some(self.search_runtime.handle().clone()),
+            Some(self.update_runtime.handle().clone()),
+        )
+        .await?;
+
+        for (field_name, field_schema) in payload_index_schema.schema {
+            collection
+                .create_payload_index(field_name, field_schema)
+                .await?;
+        }
+
+        let local_shards = collection.get_local_shards().await;
+
+        {
+            let mut write_collections = self.collections.write().await;
+            write_collections
+                .validate_collection_not_exists(&new_collection_name)
+                .await?;
+            write_collections.insert(new_collection_name.clone(), collection);
+        }
+
+        drop(collection_create_guard);
+
+        self.run_data_cloning(source_name, new_collection_name, local_shards)?;
+
+        Ok(true)
+    }
+
+    /// Copy data of the local shards from the source collection in the background
+    ///
+    /// Data of every shard is copied once, from the source replica with the highest peer ID.
+    /// Other replicas of the copy are marked as dead right away, so they are rebuilt from the
+    /// copied replica by regular replica transfers.
+    fn run_data_cloning(
+        &self,
+        from_collection: CollectionId,
+        to_collection: CollectionId,
+        shards: Vec<ShardId>,
+    ) -> Result<(), StorageError> {
+        let collections = self.collections.clone();
+        let consensus_proposal_sender = self.consensus_proposal_sender.clone();
+        let this_peer_id = self.this_peer_id;
+        let is_distributed = self.is_distributed();
+        let temp_dir = self.optional_temp_or_snapshot_temp_path()?;
+
+        self.general_runtime.spawn(async move {
+            let source_shards = {
+                let collections_read = collections.read().await;
+                match collections_read.get(&from_collection) {
+                    Some(source) => {
+                        data_transfer::get_local_source_shards(source, this_peer_id).await
+                    }
+                    None => Err(CollectionError::NotFound {
+                        what: format!("Collection {from_collection}"),
+                    }),
+                }
+            };
+
+            let source_shards = source_shards.unwrap_or_else(|err| {
+                log::error!(
+                    "Can't select shards of collection {from_collection} to copy into {to_collection}: {err}"
+                );
+                Vec::new()
+            });
+
+            for shard_id in shards {
+                if !source_shards.contains(&shard_id) {
+                    log::debug!(
+                        "Shard {shard_id} of collection {to_collection} is copied on another peer, recovering local replica by transfer"
+                    );
+                    Self::propose_dead_replica(
+                        consensus_proposal_sender.as_ref(),
+                        &to_collection,
+                        shard_id,
+                        this_peer_id,
+                    );
+                    continue;
+                }
+
+                let result = data_transfer::clone_local_shard(
+                    collections.clone(),
+                    &from_collection,
+                    &to_collection,
+                    shard_id,
+                    this_peer_id,
+                    is_distributed,
+                    &temp_dir,
+                )
+                .await;
+
+                match result {
+                    Ok(()) => {
+                        log::debug!(
+                            "Shard {shard_id} of collection {to_collection} copied from {from_collection}"
+                        );
+                        let result = Self::report_replica_initialized(
+                            consensus_proposal_sender.as_ref(),
+                            &collections,
+                            to_collection.clone(),
+                            this_peer_id,
+                            shard_id,
+                        )
+                        .await;
+                        if let Err(err) = result {
+                            log::error!(
+                                "Can't activate shard {shard_id} of collection {to_collection}: {err}"
+                            );
+                        }
+                    }
+                    Err(err) => {
+                        log::error!(
+                            "Can't copy shard {shard_id} of collection {from_collection} into {to_collection}: {err}"
+                        );
+                        // Dead replica is recovered from other replicas of the copy
+                        Self::propose_dead_replica(
+                            consensus_proposal_sender.as_ref(),
+                            &to_collection,
+                            shard_id,
+                            this_peer_id,
+                        );
+                    }
+                }
+            }
+        });
+
+        Ok(())
+    }
+
+    fn propose_dead_replica(
+        proposal_sender: Option<&OperationSender>,
+        collection_name: &CollectionId,
+        shard_id: ShardId,
+        peer_id: PeerId,
+    ) {
+        let Some(proposal_sender) = proposal_sender else {
+            return;
+        };
+
+        let operation = ConsensusOperations::set_replica_state(
+            collection_name.clone(),
+            shard_id,
+            peer_id,
+            ReplicaState::Dead,
+            None,
+        );
+
+        if let Err(send_error) = proposal_sender.send(operation) {
+            log::error!(
+                "Can't send proposal to deactivate replica of shard {shard_id} of collection {collection_name}: {send_error}"
+            );
+        }
+    }
+
     async fn check_collections_compatibility(
         &self,
         vectors: &VectorsConfig,
@@ -237,7 +503,24 @@ impl TableOfContent {
         peer_id: PeerId,
         shard_id: ShardId,
     ) -> CollectionResult<()> {
-        if let Some(proposal_sender) = &self.consensus_proposal_sender {
+        Self::report_replica_initialized(
+            self.consensus_proposal_sender.as_ref(),
+            &self.collections,
+            collection_name,
+            peer_id,
+            shard_id,
+        )
+        .await
+    }
+
+    async fn report_replica_initialized(
+        consensus_proposal_sender: Option<&OperationSender>,
+        collections: &RwLock<Collections>,
+        collection_name: String,
+        peer_id: PeerId,
+        shard_id: ShardId,
+    ) -> CollectionResult<()> {
+        if let Some(proposal_sender) = consensus_proposal_sender {
             let operation =
                 ConsensusOperations::initialize_replica(collection_name.clone(), shard_id, peer_id);
             if let Err(send_error) = proposal_sender.send(operation) {
@@ -251,7 +534,7 @@ impl TableOfContent {
             }
         } else {
             // Just activate the shard
-            let collections = self.collections.read().await;
+            let collections = collections.read().await;
             if let Some(collection) = collections.get(&collection_name) {
                 collection
                     .set_shard_replica_state(
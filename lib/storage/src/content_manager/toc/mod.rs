@@ -1,5 +1,6 @@
 mod collection_container;
 mod collection_meta_ops;
+mod copy_points;
 mod create_collection;
 mod locks;
 mod point_ops;
@@ -14,7 +15,7 @@ use std::num::NonZeroU32;
 use std::path::{Path, PathBuf};
 use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use api::grpc::qdrant::qdrant_internal_client::QdrantInternalClient;
 use api::grpc::qdrant::WaitOnConsensusCommitRequest;
@@ -23,10 +24,13 @@ use collection::collection::{Collection, RequestShardTransfer};
 use collection::config::{default_replication_factor, CollectionConfig};
 use collection::operations::types::*;
 use collection::shards::channel_service::ChannelService;
+use collection::shards::peer_labels::PeerLabelsById;
 use collection::shards::replica_set;
 use collection::shards::replica_set::{AbortShardTransfer, ReplicaState};
 use collection::shards::shard::{PeerId, ShardId};
+use collection::shards::transfer::ShardTransfer;
 use collection::telemetry::CollectionTelemetry;
+use common::defaults;
 use futures::future::try_join_all;
 use futures::Future;
 use segment::common::cpu::get_num_cpus;
@@ -41,8 +45,10 @@ use crate::content_manager::alias_mapping::AliasPersistence;
 use crate::content_manager::collection_meta_ops::CreateCollectionOperation;
 use crate::content_manager::collections_ops::{Checker, Collections};
 use crate::content_manager::consensus::operation_sender::OperationSender;
+use crate::content_manager::copy_jobs::CopyJobs;
 use crate::content_manager::errors::StorageError;
 use crate::content_manager::shard_distribution::ShardDistributionProposal;
+use crate::content_manager::shard_rebalancer::RebalancePlan;
 use crate::types::{PeerAddressById, StorageConfig};
 use crate::ConsensusOperations;
 
@@ -50,6 +56,16 @@ pub const ALIASES_PATH: &str = "aliases";
 pub const COLLECTIONS_DIR: &str = "collections";
 pub const FULL_SNAPSHOT_FILE_NAME: &str = "full-snapshot";
 
+/// Minimal interval between automatic rebalancing checks of a collection
+const AUTO_REBALANCE_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Timeout of requests for the number of points in remote shards, to estimate their sizes
+const SHARD_SIZES_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Shard moves proposed by automatic rebalancing of every collection, which are not confirmed by
+/// consensus yet, together with the time they were proposed at.
+type RebalanceProposals = HashMap<String, Vec<(ShardTransfer, Instant)>>;
+
 /// The main object of the service. It holds all objects, required for proper functioning.
 /// In most cases only one `TableOfContent` is enough for service. It is created only once during
 /// the launch of the service.
@@ -77,6 +93,12 @@ pub struct TableOfContent {
     collection_create_lock: Mutex<()>,
     /// Dispatcher for shard transfer to access consensus.
     shard_transfer_dispatcher: parking_lot::Mutex<Option<ShardTransferDispatcher>>,
+    /// Jobs copying points between collections, running on this peer.
+    copy_jobs: Arc<parking_lot::Mutex<CopyJobs>>,
+    /// Shard moves proposed by automatic rebalancing, shared with the background checks.
+    rebalance_proposals: Arc<parking_lot::Mutex<RebalanceProposals>>,
+    /// Time of the last automatic rebalancing check of every collection.
+    rebalance_checked_at: parking_lot::Mutex<HashMap<String, Instant>>,
 }
 
 impl TableOfContent {
@@ -160,6 +182,8 @@ impl TableOfContent {
         let alias_path = Path::new(&storage_config.storage_path).join(ALIASES_PATH);
         let alias_persistence =
             AliasPersistence::open(alias_path).expect("Can't open database by the provided config");
+        let copy_jobs = CopyJobs::open(Path::new(&storage_config.storage_path))
+            .expect("Can't open copy jobs state");
 
         let rate_limiter = match storage_config.performance.update_rate_limit {
             Some(limit) => Some(Semaphore::new(limit)),
@@ -179,7 +203,7 @@ impl TableOfContent {
             }
         };
 
-        TableOfContent {
+        let toc = TableOfContent {
             collections: Arc::new(RwLock::new(collections)),
             storage_config: Arc::new(storage_config.clone()),
             search_runtime,
@@ -194,7 +218,15 @@ impl TableOfContent {
             update_rate_limiter: rate_limiter,
             collection_create_lock: Default::default(),
             shard_transfer_dispatcher: Default::default(),
-        }
+            copy_jobs: Arc::new(parking_lot::Mutex::new(copy_jobs)),
+            rebalance_proposals: Default::default(),
+            rebalance_checked_at: Default::default(),
+        };
+
+        // Resume copy jobs, interrupted by restart
+        toc.resume_copy_jobs();
+
+        toc
     }
 
     /// Return `true` if service is working in distributed mode.
@@ -312,23 +344,20 @@ impl TableOfContent {
             .shard_number
             .and_then(NonZeroU32::new)
             .unwrap_or(suggested_shard_number);
-        let mut known_peers_set: HashSet<_> = self
-            .channel_service
-            .id_to_address
-            .read()
-            .keys()
-            .copied()
-            .collect();
-        known_peers_set.insert(self.this_peer_id());
-        let known_peers: Vec<_> = known_peers_set.into_iter().collect();
+        let known_peers = self.known_peers();
         let replication_factor = op
             .create_collection
             .replication_factor
             .and_then(NonZeroU32::new)
             .unwrap_or_else(default_replication_factor);
 
-        let shard_distribution =
-            ShardDistributionProposal::new(shard_number, replication_factor, &known_peers);
+        let labels_by_id = self.channel_service.id_to_labels.read().clone();
+        let shard_distribution = ShardDistributionProposal::new(
+            shard_number,
+            replication_factor,
+            &known_peers,
+            &labels_by_id,
+        );
 
         log::debug!(
             "Suggesting distribution for {} shards for collection '{}' among {} peers {:?}",
@@ -340,6 +369,74 @@ impl TableOfContent {
         shard_distribution
     }
 
+    /// Suggest moves of shard replicas which balance the collection across all known peers,
+    /// without performing them.
+    pub async fn suggest_shard_rebalance(
+        &self,
+        collection_name: &str,
+    ) -> Result<RebalancePlan, StorageError> {
+        let collection = self.get_collection(collection_name).await?;
+        let state = collection.state().await;
+        let shard_sizes = collection.shard_sizes(SHARD_SIZES_TIMEOUT).await;
+        let labels_by_id = self.channel_service.id_to_labels.read().clone();
+        Ok(RebalancePlan::new(
+            &self.known_peers(),
+            &state.shards,
+            &shard_sizes,
+            &labels_by_id,
+            &state.transfers.into_iter().collect::<Vec<_>>(),
+        ))
+    }
+
+    /// Check in the background whether the collection has to be rebalanced, if it has automatic
+    /// rebalancing enabled, at most once per [`AUTO_REBALANCE_CHECK_INTERVAL`].
+    ///
+    /// Must only be called on the consensus leader, so that a single peer plans moves for the
+    /// whole cluster. Measuring shards requests remote peers, so the check runs on the general
+    /// runtime and doesn't block the caller.
+    async fn spawn_auto_rebalance_check(&self, collection: &Collection) {
+        if !collection.state().await.config.params.auto_rebalance {
+            return;
+        }
+
+        let collection_name = collection.name();
+        {
+            let mut checked_at = self.rebalance_checked_at.lock();
+            let now = Instant::now();
+            let is_checked_recently = checked_at
+                .get(&collection_name)
+                .is_some_and(|checked_at| now - *checked_at < AUTO_REBALANCE_CHECK_INTERVAL);
+            if is_checked_recently {
+                return;
+            }
+            checked_at.insert(collection_name.clone(), now);
+        }
+
+        let collections = self.collections.clone();
+        let proposals = self.rebalance_proposals.clone();
+        let known_peers = self.known_peers();
+        let labels_by_id = self.channel_service.id_to_labels.read().clone();
+
+        self.general_runtime.spawn(async move {
+            let collections = collections.read().await;
+            if let Some(collection) = collections.get(&collection_name) {
+                auto_rebalance_shards(collection, &known_peers, &labels_by_id, &proposals).await;
+            }
+        });
+    }
+
+    fn known_peers(&self) -> Vec<PeerId> {
+        let mut known_peers_set: HashSet<_> = self
+            .channel_service
+            .id_to_address
+            .read()
+            .keys()
+            .copied()
+            .collect();
+        known_peers_set.insert(self.this_peer_id());
+        known_peers_set.into_iter().collect()
+    }
+
     /// Initiate receiving shard.
     ///
     /// Fails if the collection does not exist
@@ -680,3 +777,87 @@ impl TableOfContent {
         &self.channel_service
     }
 }
+
+/// Request the moves of a rebalancing plan, if the collection has automatic rebalancing enabled.
+///
+/// Shards with transfers in progress, or with moves proposed earlier and not confirmed by
+/// consensus yet, are not moved again.
+async fn auto_rebalance_shards(
+    collection: &Collection,
+    known_peers: &[PeerId],
+    labels_by_id: &PeerLabelsById,
+    rebalance_proposals: &parking_lot::Mutex<RebalanceProposals>,
+) {
+    let state = collection.state().await;
+    if !state.config.params.auto_rebalance {
+        return;
+    }
+
+    // Forget proposals, which are now known to consensus or were dropped by it
+    let proposed: Vec<_> = {
+        let mut proposals = rebalance_proposals.lock();
+        let collection_proposals = proposals.entry(collection.name()).or_default();
+        collection_proposals.retain(|(transfer, proposed_at)| {
+            let is_known = state.transfers.contains(transfer)
+                || state
+                    .shards
+                    .get(&transfer.shard_id)
+                    .map_or(true, |shard| shard.replicas.contains_key(&transfer.to));
+            !is_known && proposed_at.elapsed() < defaults::CONSENSUS_META_OP_WAIT
+        });
+        collection_proposals
+            .iter()
+            .map(|(transfer, _)| transfer.clone())
+            .collect()
+    };
+
+    let pending: Vec<_> = state.transfers.iter().chain(&proposed).cloned().collect();
+
+    // Shard sizes don't affect whether the collection is balanced, only which shards to move.
+    // Check the balance first to avoid measuring shards on every call.
+    let is_balanced = RebalancePlan::new(
+        known_peers,
+        &state.shards,
+        &HashMap::new(),
+        labels_by_id,
+        &pending,
+    )
+    .moves
+    .is_empty();
+    if is_balanced {
+        return;
+    }
+
+    let shard_sizes = collection.shard_sizes(SHARD_SIZES_TIMEOUT).await;
+    let plan = RebalancePlan::new(
+        known_peers,
+        &state.shards,
+        &shard_sizes,
+        labels_by_id,
+        &pending,
+    );
+    let moves = plan.moves.into_iter().map(|shard_move| {
+        (
+            shard_move.shard_id,
+            shard_move.from_peer_id,
+            shard_move.to_peer_id,
+        )
+    });
+
+    let requested = collection.request_shard_moves(moves, &proposed).await;
+    if requested.is_empty() {
+        return;
+    }
+
+    log::info!(
+        "Requested {} shard moves to rebalance collection {}",
+        requested.len(),
+        collection.name(),
+    );
+    let now = Instant::now();
+    rebalance_proposals
+        .lock()
+        .entry(collection.name())
+        .or_default()
+        .extend(requested.into_iter().map(|transfer| (transfer, now)));
+}
@@ -1,16 +1,20 @@
+use std::collections::HashMap;
 use std::time::Duration;
 
 use collection::collection::Collection;
 use collection::grouping::group_by::GroupRequest;
 use collection::grouping::GroupBy;
-use collection::operations::consistency_params::ReadConsistency;
+use collection::lookup::{lookup_links, WithLookup};
+use collection::operations::consistency_params::{ConsistencyToken, ReadConsistency};
 use collection::operations::point_ops::WriteOrdering;
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
 use collection::operations::types::*;
 use collection::operations::CollectionUpdateOperations;
 use collection::{discovery, recommendations};
 use futures::future::try_join_all;
-use segment::types::{ScoredPoint, ShardKey};
+use segment::data_types::distinct::DistinctCounter;
+use segment::data_types::text_index::TextStats;
+use segment::types::{Payload, PointIdType, ScoredPoint, ShardKey};
 
 use super::TableOfContent;
 use crate::content_manager::errors::StorageError;
@@ -35,6 +39,7 @@ impl TableOfContent {
         timeout: Option<Duration>,
     ) -> Result<Vec<ScoredPoint>, StorageError> {
         let collection = self.get_collection(collection_name).await?;
+        collection.check_strict_mode(&request).await?;
         recommendations::recommend_by(
             request,
             &collection,
@@ -65,6 +70,9 @@ impl TableOfContent {
         timeout: Option<Duration>,
     ) -> Result<Vec<Vec<ScoredPoint>>, StorageError> {
         let collection = self.get_collection(collection_name).await?;
+        collection
+            .check_strict_mode_batch(requests.iter().map(|(request, _)| request))
+            .await?;
         recommendations::recommend_batch_by(
             requests,
             &collection,
@@ -99,6 +107,12 @@ impl TableOfContent {
         timeout: Option<Duration>,
     ) -> Result<Vec<Vec<ScoredPoint>>, StorageError> {
         let collection = self.get_collection(collection_name).await?;
+        // Requests of other peers are already checked by the peer, which received them
+        if !shard_selection.is_shard_id() {
+            collection
+                .check_strict_mode_batch(request.searches.iter())
+                .await?;
+        }
         collection
             .core_search_batch(request, read_consistency, shard_selection, timeout)
             .await
@@ -125,12 +139,151 @@ impl TableOfContent {
         shard_selection: ShardSelectorInternal,
     ) -> Result<CountResult, StorageError> {
         let collection = self.get_collection(collection_name).await?;
+        if !shard_selection.is_shard_id() {
+            collection.check_strict_mode(&request).await?;
+        }
         collection
             .count(request, read_consistency, &shard_selection)
             .await
             .map_err(|err| err.into())
     }
 
+    /// Collect distinct values of a payload field of the points in the collection.
+    ///
+    /// # Arguments
+    ///
+    /// * `collection_name` - in what collection do we count
+    /// * `request` - [`CountRequestInternal`] with the `distinct` field set
+    /// * `shard_selection` - which local shard to use
+    ///
+    /// # Result
+    ///
+    /// Mergeable set or sketch of distinct values over all matching points.
+    ///
+    pub async fn count_distinct(
+        &self,
+        collection_name: &str,
+        request: CountRequestInternal,
+        read_consistency: Option<ReadConsistency>,
+        shard_selection: ShardSelectorInternal,
+    ) -> Result<DistinctCounter, StorageError> {
+        let collection = self.get_collection(collection_name).await?;
+        if !shard_selection.is_shard_id() {
+            collection.check_strict_mode(&request).await?;
+        }
+        collection
+            .count_distinct(request, read_consistency, &shard_selection)
+            .await
+            .map_err(|err| err.into())
+    }
+
+    /// Aggregate numeric payload values of the points in the collection.
+    ///
+    /// # Arguments
+    ///
+    /// * `collection_name` - in what collection do we aggregate
+    /// * `request` - [`AggregateRequestInternal`]
+    /// * `shard_selection` - which local shard to use
+    ///
+    /// # Result
+    ///
+    /// Statistics and histogram of the values over all matching points.
+    ///
+    pub async fn aggregate(
+        &self,
+        collection_name: &str,
+        request: AggregateRequestInternal,
+        read_consistency: Option<ReadConsistency>,
+        shard_selection: ShardSelectorInternal,
+    ) -> Result<AggregateResult, StorageError> {
+        let collection = self.get_collection(collection_name).await?;
+        if !shard_selection.is_shard_id() {
+            collection.check_strict_mode(&request).await?;
+        }
+        collection
+            .aggregate(request, read_consistency, &shard_selection)
+            .await
+            .map_err(|err| err.into())
+    }
+
+    /// Statistics of a full-text indexed payload field required to score a text search.
+    ///
+    /// # Arguments
+    ///
+    /// * `collection_name` - in what collection do we search
+    /// * `request` - [`TextSearchRequestInternal`]
+    /// * `shard_selection` - which local shard to use
+    ///
+    /// # Result
+    ///
+    /// Document statistics of the field, merged over the selected shards.
+    ///
+    pub async fn text_stats(
+        &self,
+        collection_name: &str,
+        request: TextSearchRequestInternal,
+        read_consistency: Option<ReadConsistency>,
+        shard_selection: ShardSelectorInternal,
+    ) -> Result<TextStats, StorageError> {
+        let collection = self.get_collection(collection_name).await?;
+        if !shard_selection.is_shard_id() {
+            collection.check_strict_mode(&request).await?;
+        }
+        collection
+            .text_stats(request.into(), read_consistency, &shard_selection)
+            .await
+            .map_err(|err| err.into())
+    }
+
+    /// Rank points of the collection by BM25 relevance of a full-text indexed payload field.
+    ///
+    /// # Arguments
+    ///
+    /// * `collection_name` - in what collection do we search
+    /// * `request` - [`TextSearchRequestInternal`]
+    /// * `shard_selection` - which local shard to use
+    ///
+    /// # Result
+    ///
+    /// Points with the most relevant text, ordered by score.
+    ///
+    pub async fn search_text(
+        &self,
+        collection_name: &str,
+        request: TextSearchRequestInternal,
+        read_consistency: Option<ReadConsistency>,
+        shard_selection: ShardSelectorInternal,
+    ) -> Result<Vec<ScoredPoint>, StorageError> {
+        let collection = self.get_collection(collection_name).await?;
+        if !shard_selection.is_shard_id() {
+            collection.check_strict_mode(&request).await?;
+        }
+        collection
+            .search_text(request, read_consistency, &shard_selection)
+            .await
+            .map_err(|err| err.into())
+    }
+
+    /// Wait until the updates of `consistency_token` are visible to reads from the collection
+    ///
+    /// # Arguments
+    ///
+    /// * `collection_name` - collection the updates were made in
+    /// * `consistency_token` - [`ConsistencyToken`] returned by the updates
+    /// * `timeout` - how long to wait, defaults to the search timeout
+    pub async fn wait_for_consistency_token(
+        &self,
+        collection_name: &str,
+        consistency_token: &ConsistencyToken,
+        timeout: Option<Duration>,
+    ) -> Result<(), StorageError> {
+        let collection = self.get_collection(collection_name).await?;
+        collection
+            .wait_for_consistency_token(consistency_token, timeout)
+            .await
+            .map_err(|err| err.into())
+    }
+
     /// Return specific points by IDs
     ///
     /// # Arguments
@@ -165,6 +318,7 @@ impl TableOfContent {
         timeout: Option<Duration>,
     ) -> Result<GroupsResult, StorageError> {
         let collection = self.get_collection(collection_name).await?;
+        collection.check_strict_mode(&request).await?;
 
         let collection_by_name = |name| self.get_collection_opt(name);
 
@@ -189,6 +343,7 @@ impl TableOfContent {
         timeout: Option<Duration>,
     ) -> Result<Vec<ScoredPoint>, StorageError> {
         let collection = self.get_collection(collection_name).await?;
+        collection.check_strict_mode(&request).await?;
         discovery::discover(
             request,
             &collection,
@@ -209,6 +364,9 @@ impl TableOfContent {
         timeout: Option<Duration>,
     ) -> Result<Vec<Vec<ScoredPoint>>, StorageError> {
         let collection = self.get_collection(collection_name).await?;
+        collection
+            .check_strict_mode_batch(requests.iter().map(|(request, _)| request))
+            .await?;
 
         discovery::discover_batch(
             requests,
@@ -240,12 +398,48 @@ impl TableOfContent {
         shard_selection: ShardSelectorInternal,
     ) -> Result<ScrollResult, StorageError> {
         let collection = self.get_collection(collection_name).await?;
+        if !shard_selection.is_shard_id() {
+            collection.check_strict_mode(&request).await?;
+        }
         collection
             .scroll_by(request, read_consistency, &shard_selection)
             .await
             .map_err(|err| err.into())
     }
 
+    /// Look up records of another collection, which are linked to the given points
+    ///
+    /// # Arguments
+    ///
+    /// * `collection_name` - collection the points belong to
+    /// * `request` - [`WithLookup`] with the lookup collection and the link definition
+    /// * `points` - ids of the points with their payloads, if already known
+    /// * `shard_selection` - which shards to use for reading the link values
+    ///
+    /// # Result
+    ///
+    /// Looked up records of each point which has at least one linked record
+    pub async fn lookup(
+        &self,
+        collection_name: &str,
+        request: WithLookup,
+        points: Vec<(PointIdType, Option<&Payload>)>,
+        read_consistency: Option<ReadConsistency>,
+        shard_selection: ShardSelectorInternal,
+    ) -> Result<HashMap<PointIdType, Vec<Record>>, StorageError> {
+        let collection = self.get_collection(collection_name).await?;
+        lookup_links(
+            request,
+            &collection,
+            points,
+            |name| self.get_collection_opt(name),
+            read_consistency,
+            &shard_selection,
+        )
+        .await
+        .map_err(|err| err.into())
+    }
+
     async fn _update_shard_keys(
         collection: &Collection,
         shard_keys: Vec<ShardKey>,
@@ -317,6 +511,9 @@ impl TableOfContent {
         if operation.is_write_operation() {
             self.check_write_lock()?;
         }
+        if !shard_selector.is_shard_id() {
+            collection.check_strict_mode_update(&operation).await?;
+        }
         let res = match shard_selector {
             ShardSelectorInternal::Empty => {
                 collection
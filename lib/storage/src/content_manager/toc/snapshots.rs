@@ -1,5 +1,6 @@
 use std::path::{Path, PathBuf};
 
+use collection::common::file_utils::{move_dir, move_file};
 use collection::operations::snapshot_ops::SnapshotDescription;
 use collection::shards::replica_set::ReplicaState;
 use collection::shards::shard::{PeerId, ShardId};
@@ -42,6 +43,43 @@ impl TableOfContent {
         Ok(snapshots_path)
     }
 
+    /// Moves snapshots of one collection into the snapshots directory of another one.
+    /// Existing snapshots of the target collection are kept.
+    pub(super) async fn move_collection_snapshots(
+        &self,
+        from_collection: &str,
+        to_collection: &str,
+    ) -> Result<(), StorageError> {
+        let from_path = self.snapshots_path_for_collection(from_collection);
+        let to_path = self.snapshots_path_for_collection(to_collection);
+
+        if !from_path.exists() {
+            return Ok(());
+        }
+        if !to_path.exists() {
+            move_dir(&from_path, &to_path).await?;
+            return Ok(());
+        }
+
+        // Merge directories, shard snapshots are stored in nested directories
+        let mut pending = vec![(from_path.clone(), to_path)];
+        while let Some((from_dir, to_dir)) = pending.pop() {
+            tokio::fs::create_dir_all(&to_dir).await?;
+            let mut entries = tokio::fs::read_dir(&from_dir).await?;
+            while let Some(entry) = entries.next_entry().await? {
+                let target = to_dir.join(entry.file_name());
+                if entry.file_type().await?.is_dir() {
+                    pending.push((entry.path(), target));
+                } else {
+                    move_file(entry.path(), target).await?;
+                }
+            }
+        }
+        tokio::fs::remove_dir_all(&from_path).await?;
+
+        Ok(())
+    }
+
     pub async fn create_snapshot(
         &self,
         collection_name: &str,
@@ -1,9 +1,12 @@
+use std::collections::HashSet;
 use std::num::NonZeroU32;
 use std::ops::Deref;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use collection::config::ShardingMethod;
+use collection::shards::replica_set::ReplicaState;
+use collection::shards::shard::PeerId;
 use common::defaults::CONSENSUS_META_OP_WAIT;
 
 use crate::content_manager::collection_meta_ops::AliasOperations;
@@ -101,10 +104,55 @@ impl Dispatcher {
                     self.toc.check_write_lock()?;
                     CollectionMetaOperations::CreateShardKey(op)
                 }
+                CollectionMetaOperations::CloneCollection(op) => {
+                    self.toc.check_write_lock()?;
+
+                    // Copy has the same shard layout as the source collection. Data of each shard
+                    // is cloned into the replica on the active source peer with maximal ID,
+                    // expect it to become active once data is copied. Other replicas are rebuilt
+                    // by regular shard transfers afterwards.
+                    let source = self.toc.get_collection(&op.collection_name).await?;
+                    for (shard_id, shard_info) in source.state().await.shards {
+                        let cloned_peer = shard_info
+                            .replicas
+                            .into_iter()
+                            .filter(|(_, replica_state)| *replica_state == ReplicaState::Active)
+                            .map(|(peer_id, _)| peer_id)
+                            .max();
+                        if let Some(peer_id) = cloned_peer {
+                            expect_operations.push(ConsensusOperations::initialize_replica(
+                                op.clone_collection.new_collection_name.clone(),
+                                shard_id,
+                                peer_id,
+                            ));
+                        }
+                    }
+
+                    CollectionMetaOperations::CloneCollection(op)
+                }
 
                 op => op,
             };
 
+            // Renamed collection only has to be available on peers serving its data, don't wait
+            // for peers which are down or don't hold any active replica
+            let sync_peers = match &op {
+                CollectionMetaOperations::RenameCollection(op) => {
+                    let collection = self.toc.get_collection(&op.collection_name).await?;
+                    let peers: HashSet<PeerId> = collection
+                        .state()
+                        .await
+                        .shards
+                        .into_values()
+                        .flat_map(|shard_info| shard_info.replicas)
+                        .filter(|(_, replica_state)| *replica_state == ReplicaState::Active)
+                        .map(|(peer_id, _)| peer_id)
+                        .collect();
+                    Some(peers.into_iter().collect::<Vec<_>>())
+                }
+                _ => None,
+            };
+
             let operation_awaiter =
                 // If explicit timeout is set - then we need to wait for all expected operations.
                 // E.g. in case of `CreateCollection` we will explicitly wait for all replicas to be activated.
@@ -119,6 +167,9 @@ impl Dispatcher {
                 // Sync nodes after collection or shard key creation
                 CollectionMetaOperations::CreateCollection(_)
                 | CollectionMetaOperations::CreateShardKey(_) => true,
+                // Sync nodes after collection is available under a new name
+                CollectionMetaOperations::RenameCollection(_)
+                | CollectionMetaOperations::CloneCollection(_) => true,
                 // Sync nodes when creating or renaming collection aliases
                 CollectionMetaOperations::ChangeAliases(changes) => {
                     changes.actions.iter().any(|change| match change {
@@ -159,14 +210,20 @@ impl Dispatcher {
             if do_sync_nodes {
                 let remaining_timeout =
                     wait_timeout.map(|timeout| timeout.saturating_sub(start.elapsed()));
-                if let Err(err) = self.await_consensus_sync(remaining_timeout).await {
+                let sync_result = match &sync_peers {
+                    Some(peers) => self.await_consensus_sync_on(peers, remaining_timeout).await,
+                    None => self.await_consensus_sync(remaining_timeout).await,
+                };
+                if let Err(err) = sync_result {
                     log::warn!("Failed to synchronize all nodes after collection operation in time, some nodes may not be ready: {err}");
                 }
             }
 
             Ok(res)
         } else {
-            if let CollectionMetaOperations::CreateCollection(_) = &operation {
+            if let CollectionMetaOperations::CreateCollection(_)
+            | CollectionMetaOperations::CloneCollection(_) = &operation
+            {
                 self.toc.check_write_lock()?;
             }
             self.toc.perform_collection_meta_op(operation).await
@@ -208,6 +265,33 @@ impl Dispatcher {
             Ok(())
         }
     }
+
+    /// Same as [`Self::await_consensus_sync`], but only waits for the given peers
+    pub async fn await_consensus_sync_on(
+        &self,
+        peers: &[PeerId],
+        timeout: Option<Duration>,
+    ) -> Result<(), StorageError> {
+        let timeout = timeout.unwrap_or(CONSENSUS_META_OP_WAIT);
+
+        if let Some(state) = self.consensus_state.as_ref() {
+            let state = state.hard_state();
+            let term = state.term;
+            let commit = state.commit;
+            let channel_service = self.toc.get_channel_service();
+            let this_peer_id = self.toc.this_peer_id;
+
+            channel_service
+                .await_commit_on_peers(this_peer_id, peers, commit, term, timeout)
+                .await?;
+
+            log::debug!(
+                "Consensus is synchronized on peers {peers:?} with term: {term}, commit: {commit}",
+            );
+        }
+
+        Ok(())
+    }
 }
 
 impl Deref for Dispatcher {
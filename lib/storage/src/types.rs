@@ -9,6 +9,7 @@ use collection::operations::shared_storage_config::{
 };
 use collection::operations::types::NodeType;
 use collection::optimizers_builder::OptimizersConfig;
+use collection::shards::peer_labels::PeerLabels;
 use collection::shards::shard::PeerId;
 use memory::madvise;
 use schemars::JsonSchema;
@@ -118,6 +119,9 @@ const fn default_mmap_advice() -> madvise::Advice {
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Clone)]
 pub struct PeerInfo {
     pub uri: String,
+    /// Location of the peer
+    #[serde(default, skip_serializing_if = "PeerLabels::is_empty")]
+    pub labels: PeerLabels,
     // ToDo: How long ago was the last communication? In milliseconds
     // pub last_responded_millis: usize
 }
@@ -212,6 +216,7 @@ impl Anonymize for PeerInfo {
     fn anonymize(&self) -> Self {
         PeerInfo {
             uri: self.uri.anonymize(),
+            labels: self.labels.anonymize(),
         }
     }
 }
@@ -108,6 +108,9 @@ fn test_alias_operation() {
                         init_from: None,
                         quantization_config: None,
                         sharding_method: None,
+                        expiry: None,
+                        payload_schema: None,
+                        strict_mode: None,
                     },
                 )),
                 None,
@@ -0,0 +1,130 @@
+import pytest
+
+from .helpers.collection_setup import basic_collection_setup, drop_collection
+from .helpers.helpers import request_with_validation
+
+collection_name = 'test_collection_aggregate'
+
+
+@pytest.fixture(autouse=True)
+def setup(on_disk_vectors):
+    basic_collection_setup(collection_name=collection_name, on_disk_vectors=on_disk_vectors)
+    response = request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "points": [
+                {"id": 1001, "vector": [0.1, 0.2, 0.3, 0.4], "payload": {"category": "a", "price": 10}},
+                {"id": 1002, "vector": [0.2, 0.3, 0.4, 0.5], "payload": {"category": "a", "price": 25.5}},
+                {"id": 1003, "vector": [0.3, 0.4, 0.5, 0.6], "payload": {"category": "b", "price": [5, 15]}},
+                {"id": 1004, "vector": [0.4, 0.5, 0.6, 0.7], "payload": {"category": "b", "price": "free"}},
+                {"id": 1005, "vector": [0.5, 0.6, 0.7, 0.8], "payload": {"category": "a"}},
+            ]
+        }
+    )
+    assert response.ok
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def aggregate(body):
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/aggregate',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body=body,
+    )
+    assert response.ok, response.text
+    return response.json()['result']
+
+
+def check_aggregations():
+    # Every numeric value of an array counts separately, non-numeric values are ignored
+    result = aggregate({"key": "price"})
+    assert result['count'] == 4
+    assert result['min'] == 5
+    assert result['max'] == 25.5
+    assert result['sum'] == 55.5
+    assert result['integer_sum'] == 30
+    assert result['avg'] == 55.5 / 4
+    assert 'histogram' not in result
+
+    result = aggregate({
+        "key": "price",
+        "filter": {"must": [{"key": "category", "match": {"value": "a"}}]},
+        "histogram": {"interval": 10},
+    })
+    assert result['count'] == 2
+    assert result['sum'] == 35.5
+    assert result['histogram'] == [
+        {"from": 10.0, "to": 20.0, "count": 1},
+        {"from": 20.0, "to": 30.0, "count": 1},
+    ]
+
+    result = aggregate({"key": "price", "histogram": {"interval": 10, "offset": 5}})
+    assert result['histogram'] == [
+        {"from": 5.0, "to": 15.0, "count": 2},
+        {"from": 15.0, "to": 25.0, "count": 1},
+        {"from": 25.0, "to": 35.0, "count": 1},
+    ]
+
+
+def test_aggregate():
+    check_aggregations()
+
+
+def test_aggregate_indexed():
+    response = request_with_validation(
+        api='/collections/{collection_name}/index',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "field_name": "price",
+            "field_schema": "float"
+        }
+    )
+    assert response.ok
+
+    check_aggregations()
+
+
+def test_aggregate_empty():
+    result = aggregate({
+        "key": "price",
+        "filter": {"must": [{"key": "category", "match": {"value": "c"}}]},
+        "histogram": {"interval": 1},
+    })
+    assert result['count'] == 0
+    assert result['sum'] == 0
+    assert result['min'] is None
+    assert result['max'] is None
+    assert result['avg'] is None
+    assert result['histogram'] == []
+
+
+def test_aggregate_invalid_interval():
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/aggregate',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"key": "price", "histogram": {"interval": 0}},
+    )
+    assert response.status_code == 422
+
+
+def test_aggregate_too_many_buckets():
+    # Values from 5 to 25.5 span 21 buckets of width 1
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/aggregate',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"key": "price", "histogram": {"interval": 1, "max_buckets": 20}},
+    )
+    assert response.status_code == 400
+    assert "max_buckets" in response.json()["status"]["error"]
+
+    result = aggregate({"key": "price", "histogram": {"interval": 1, "max_buckets": 21}})
+    assert sum(bucket['count'] for bucket in result['histogram']) == 4
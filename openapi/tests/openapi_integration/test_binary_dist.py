@@ -0,0 +1,152 @@
+import pytest
+
+from .helpers.collection_setup import drop_collection
+from .helpers.helpers import request_with_validation
+
+collection_name = 'test_collection_binary'
+
+
+def binary_collection_setup(
+    collection_name='test_collection',
+    on_disk_vectors=False,
+):
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="DELETE",
+        path_params={'collection_name': collection_name},
+    )
+    assert response.ok
+
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        body={
+            "vectors": {
+                "hamming": {
+                    "size": 16,
+                    "distance": "Hamming",
+                    "on_disk": on_disk_vectors,
+                },
+                "jaccard": {
+                    "size": 16,
+                    "distance": "Jaccard",
+                    "on_disk": on_disk_vectors,
+                },
+            }
+        }
+    )
+    assert response.ok
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "points": [
+                {
+                    "id": 1,
+                    "vector": {"hamming": [255, 0], "jaccard": [255, 0]},
+                },
+                {
+                    "id": 2,
+                    "vector": {"hamming": [15, 0], "jaccard": [15, 0]},
+                },
+                {
+                    "id": 3,
+                    "vector": {"hamming": [0, 1], "jaccard": [0, 1]},
+                },
+            ]
+        }
+    )
+    assert response.ok
+
+
+@pytest.fixture(autouse=True, scope="module")
+def setup(on_disk_vectors):
+    binary_collection_setup(collection_name=collection_name, on_disk_vectors=on_disk_vectors)
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def test_binary_vector_validation():
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PUT",
+        path_params={'collection_name': 'test_collection_binary_invalid'},
+        body={
+            "vectors": {
+                "size": 12,
+                "distance": "Hamming",
+            }
+        }
+    )
+    assert response.status_code == 422
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "points": [
+                {
+                    "id": 4,
+                    "vector": {"hamming": [256, 0]},
+                },
+            ]
+        }
+    )
+    assert not response.ok
+
+
+def test_search_hamming():
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/search',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "vector": {"name": "hamming", "vector": [255, 0]},
+            "limit": 3,
+            "with_vector": True,
+        }
+    )
+    assert response.ok
+    result = response.json()['result']
+
+    assert [point['id'] for point in result] == [1, 2, 3]
+    assert [point['score'] for point in result] == [0, 4, 9]
+
+    # Vectors are returned in the same packed bytes form as they were inserted
+    assert result[1]['vector']['hamming'] == [15, 0]
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/search',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "vector": {"name": "hamming", "vector": [255, 0]},
+            "limit": 3,
+            "score_threshold": 5,
+        }
+    )
+    assert response.ok
+    assert [point['id'] for point in response.json()['result']] == [1, 2]
+
+
+def test_search_jaccard():
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/search',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "vector": {"name": "jaccard", "vector": [255, 0]},
+            "limit": 3,
+        }
+    )
+    assert response.ok
+    result = response.json()['result']
+
+    assert [point['id'] for point in result] == [1, 2, 3]
+    assert [point['score'] for point in result] == [1.0, 0.5, 0.0]
@@ -0,0 +1,169 @@
+import time
+
+import pytest
+
+from .helpers.collection_setup import basic_collection_setup, drop_collection
+from .helpers.helpers import request_with_validation
+
+collection_name = 'test_collection_rename'
+clone_name = 'test_collection_rename_clone'
+alias_name = 'test_collection_rename_alias'
+
+
+@pytest.fixture(autouse=True)
+def setup():
+    basic_collection_setup(collection_name=collection_name)
+    drop_collection(collection_name=clone_name)
+    yield
+    drop_collection(collection_name=collection_name)
+    drop_collection(collection_name=clone_name)
+
+
+def count_points(name):
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/count',
+        method="POST",
+        path_params={'collection_name': name},
+        body={"exact": True},
+    )
+    if not response.ok:
+        return None
+    return response.json()['result']['count']
+
+
+def wait_points_count(name, expected, timeout=10):
+    start = time.time()
+    while count_points(name) != expected:
+        assert time.time() - start < timeout, f"collection {name} did not get {expected} points in time"
+        time.sleep(0.2)
+
+
+def test_rename_collection():
+    points_count = count_points(collection_name)
+
+    response = request_with_validation(
+        api='/collections/aliases',
+        method="POST",
+        body={"actions": [{"create_alias": {"alias_name": alias_name, "collection_name": collection_name}}]},
+    )
+    assert response.ok
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/rename',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"new_collection_name": clone_name},
+    )
+    assert response.ok
+
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="GET",
+        path_params={'collection_name': collection_name},
+    )
+    assert response.status_code == 404
+
+    assert count_points(clone_name) == points_count
+    # Alias follows the renamed collection
+    assert count_points(alias_name) == points_count
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/aliases',
+        method="GET",
+        path_params={'collection_name': clone_name},
+    )
+    assert response.ok
+    assert [alias['alias_name'] for alias in response.json()['result']['aliases']] == [alias_name]
+
+
+def test_rename_collection_to_existing():
+    basic_collection_setup(collection_name=clone_name)
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/rename',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"new_collection_name": clone_name},
+    )
+    assert response.status_code == 400
+
+    # Replace the existing collection
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/delete',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={"points": [1]},
+    )
+    assert response.ok
+    points_count = count_points(collection_name)
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/rename',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"new_collection_name": clone_name, "replace_existing": True},
+    )
+    assert response.ok
+    assert count_points(clone_name) == points_count
+
+
+def test_clone_collection():
+    points_count = count_points(collection_name)
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/clone',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "new_collection_name": clone_name,
+            "hnsw_config": {"m": 32},
+            "quantization_config": {"scalar": {"type": "int8"}},
+        },
+    )
+    assert response.ok
+
+    wait_points_count(clone_name, points_count)
+
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="GET",
+        path_params={'collection_name': clone_name},
+    )
+    assert response.ok
+    config = response.json()['result']['config']
+    assert config['hnsw_config']['m'] == 32
+    assert config['quantization_config']['scalar']['type'] == 'int8'
+    assert config['params']['vectors']['size'] == 4
+
+    # Source collection is left untouched
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="GET",
+        path_params={'collection_name': collection_name},
+    )
+    assert response.ok
+    assert response.json()['result']['config']['quantization_config'] is None
+    assert count_points(collection_name) == points_count
+
+    # Copy is independent of the source
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/delete',
+        method="POST",
+        path_params={'collection_name': clone_name},
+        query_params={'wait': 'true'},
+        body={"points": [1]},
+    )
+    assert response.ok
+    assert count_points(clone_name) == points_count - 1
+    assert count_points(collection_name) == points_count
+
+
+def test_clone_collection_to_existing():
+    response = request_with_validation(
+        api='/collections/{collection_name}/clone',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"new_collection_name": collection_name},
+    )
+    assert response.status_code == 400
@@ -0,0 +1,147 @@
+import pytest
+
+from .helpers.collection_setup import drop_collection, multivec_collection_setup
+from .helpers.helpers import request_with_validation
+
+collection_name = 'test_collection_vectors_update'
+
+
+@pytest.fixture(autouse=True)
+def setup(on_disk_vectors):
+    multivec_collection_setup(collection_name=collection_name, on_disk_vectors=on_disk_vectors)
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def get_collection_params():
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="GET",
+        path_params={'collection_name': collection_name},
+    )
+    assert response.ok
+    return response.json()['result']['config']['params']
+
+
+def test_create_vectors():
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PATCH",
+        path_params={'collection_name': collection_name},
+        body={
+            "create_vectors": {
+                "audio": {
+                    "size": 2,
+                    "distance": "Euclid",
+                },
+            },
+            "create_sparse_vectors": {
+                "sparse-audio": {},
+            },
+        }
+    )
+    assert response.ok
+
+    params = get_collection_params()
+    assert params['vectors']['audio']['size'] == 2
+    assert 'sparse-audio' in params['sparse_vectors']
+
+    # Existing points have no value for the new vector
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/{id}',
+        method="GET",
+        path_params={'collection_name': collection_name, 'id': 1},
+    )
+    assert response.ok
+    assert 'audio' not in response.json()['result']['vector']
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/vectors',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "points": [
+                {"id": 1, "vector": {"audio": [1.0, 1.0]}},
+                {"id": 2, "vector": {"audio": [5.0, 5.0]}},
+            ]
+        }
+    )
+    assert response.ok
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/search',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "vector": {
+                "name": "audio",
+                "vector": [0.0, 0.0],
+            },
+            "limit": 10,
+        }
+    )
+    assert response.ok
+    assert [point['id'] for point in response.json()['result']] == [1, 2]
+
+    # Vector with the same name can't be created twice
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PATCH",
+        path_params={'collection_name': collection_name},
+        body={
+            "create_vectors": {
+                "image": {
+                    "size": 4,
+                    "distance": "Dot",
+                },
+            },
+        }
+    )
+    assert response.status_code == 400
+
+
+def test_delete_vectors():
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PATCH",
+        path_params={'collection_name': collection_name},
+        body={
+            "delete_vectors": ["text", "sparse-text"],
+        }
+    )
+    assert response.ok
+
+    params = get_collection_params()
+    assert list(params['vectors'].keys()) == ["image"]
+    assert list(params['sparse_vectors'].keys()) == ["sparse-image"]
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/{id}',
+        method="GET",
+        path_params={'collection_name': collection_name, 'id': 1},
+    )
+    assert response.ok
+    assert 'text' not in response.json()['result']['vector']
+
+    # Unknown vector can't be deleted
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PATCH",
+        path_params={'collection_name': collection_name},
+        body={
+            "delete_vectors": ["text"],
+        }
+    )
+    assert response.status_code == 400
+
+    # Collection must keep at least one vector
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PATCH",
+        path_params={'collection_name': collection_name},
+        body={
+            "delete_vectors": ["image", "sparse-image"],
+        }
+    )
+    assert response.status_code == 400
@@ -0,0 +1,123 @@
+import time
+
+import pytest
+
+from .helpers.collection_setup import basic_collection_setup, drop_collection
+from .helpers.helpers import request_with_validation
+
+source_collection_name = 'test_copy_points_source'
+collection_name = 'test_copy_points'
+
+
+@pytest.fixture(autouse=True)
+def setup():
+    basic_collection_setup(collection_name=source_collection_name)
+    drop_collection(collection_name=collection_name)
+
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        body={
+            "vectors": {
+                "image": {
+                    "size": 4,
+                    "distance": "Dot",
+                },
+            },
+        }
+    )
+    assert response.ok
+    yield
+    drop_collection(collection_name=source_collection_name)
+    drop_collection(collection_name=collection_name)
+
+
+def wait_copy_job(name, timeout=10):
+    start = time.time()
+    while True:
+        response = request_with_validation(
+            api='/collections/{collection_name}/points/copy',
+            method="GET",
+            path_params={'collection_name': name},
+        )
+        assert response.ok
+        job = response.json()['result']
+        if job['status'] != 'running':
+            return job
+        assert time.time() - start < timeout, f"copying points into {name} did not finish in time"
+        time.sleep(0.2)
+
+
+def test_copy_points():
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/copy',
+        method="GET",
+        path_params={'collection_name': collection_name},
+    )
+    assert response.status_code == 404
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/copy',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "source_collection": source_collection_name,
+            "filter": {"must": [{"key": "city", "match": {"value": "Berlin"}}]},
+            "vectors": {"image": ""},
+            "batch_size": 2,
+        },
+    )
+    assert response.ok
+
+    job = wait_copy_job(collection_name)
+    assert job['status'] == 'finished'
+    assert job['copied_points'] == 3
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/scroll',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"with_vector": True, "with_payload": True},
+    )
+    assert response.ok
+    points = response.json()['result']['points']
+    assert [point['id'] for point in points] == [1, 2, 3]
+    for point in points:
+        assert "Berlin" in point['payload']['city']
+        assert list(point['vector'].keys()) == ["image"]
+
+
+def test_copy_points_validation():
+    # Dense vector can't be copied into missing vector
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/copy',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "source_collection": source_collection_name,
+            "vectors": {"text": ""},
+        },
+    )
+    assert response.status_code == 400
+
+    # Sparse vector can't be copied into dense vector
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/copy',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "source_collection": source_collection_name,
+            "vectors": {"image": "sparse-text"},
+        },
+    )
+    assert response.status_code == 400
+
+    # Collection can't be copied into itself
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/copy',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"source_collection": collection_name},
+    )
+    assert response.status_code == 400
@@ -0,0 +1,121 @@
+import pytest
+
+from .helpers.collection_setup import basic_collection_setup, drop_collection
+from .helpers.helpers import request_with_validation
+
+collection_name = 'test_collection_count_distinct'
+
+
+@pytest.fixture(autouse=True)
+def setup(on_disk_vectors):
+    basic_collection_setup(collection_name=collection_name, on_disk_vectors=on_disk_vectors)
+    response = request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "points": [
+                {"id": 1001, "vector": [0.1, 0.2, 0.3, 0.4], "payload": {"city": "Berlin", "rating": 1}},
+                {"id": 1002, "vector": [0.2, 0.3, 0.4, 0.5], "payload": {"city": "London", "rating": 2}},
+                {"id": 1003, "vector": [0.3, 0.4, 0.5, 0.6], "payload": {"city": ["Berlin", "Paris"], "rating": 2}},
+                {"id": 1004, "vector": [0.4, 0.5, 0.6, 0.7], "payload": {"city": "Paris", "rating": [1, 3]}},
+                {"id": 1005, "vector": [0.5, 0.6, 0.7, 0.8], "payload": {"rating": 2.5}},
+            ]
+        }
+    )
+    assert response.ok
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def count(body):
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/count',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body=body,
+    )
+    assert response.ok, response.text
+    return response.json()['result']['count']
+
+
+def check_distinct_counts():
+    assert count({"distinct": "city"}) == 3
+    # Floats are not counted as distinct values
+    assert count({"distinct": "rating"}) == 3
+    assert count({"distinct": "missing"}) == 0
+
+    assert count({
+        "distinct": "city",
+        "filter": {"must": [{"key": "rating", "match": {"value": 2}}]},
+    }) == 3
+    assert count({
+        "distinct": "city",
+        "filter": {"must": [{"key": "rating", "match": {"value": 1}}]},
+    }) == 2
+
+    # Small sets of values are counted exactly even if approximation is allowed
+    assert count({"distinct": "city", "exact": False}) == 3
+
+
+def test_count_distinct():
+    check_distinct_counts()
+
+
+def test_count_distinct_indexed():
+    for field_name, field_schema in [("city", "keyword"), ("rating", "integer")]:
+        response = request_with_validation(
+            api='/collections/{collection_name}/index',
+            method="PUT",
+            path_params={'collection_name': collection_name},
+            query_params={'wait': 'true'},
+            body={
+                "field_name": field_name,
+                "field_schema": field_schema
+            }
+        )
+        assert response.ok
+
+    check_distinct_counts()
+
+
+def test_count_distinct_after_delete():
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/delete',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={"points": [1002]},
+    )
+    assert response.ok
+
+    assert count({"distinct": "city"}) == 2
+    assert count({}) == 4
+
+
+def test_count_distinct_exact_limit():
+    response = request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "points": [
+                {"id": 2000, "vector": [0.1, 0.2, 0.3, 0.4], "payload": {"user": list(range(5000))}},
+            ]
+        }
+    )
+    assert response.ok
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/count',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"distinct": "user"},
+    )
+    assert response.status_code == 400
+    assert "`exact`" in response.json()['status']['error']
+
+    estimate = count({"distinct": "user", "exact": False})
+    assert abs(estimate - 5000) < 150
@@ -0,0 +1,94 @@
+import pytest
+import requests
+
+from .helpers.collection_setup import basic_collection_setup, drop_collection
+from .helpers.helpers import request_with_validation
+from .helpers.settings import QDRANT_HOST
+
+collection_name = 'test_collection_fuzzy_text_match'
+
+
+@pytest.fixture(autouse=True)
+def setup(on_disk_vectors):
+    basic_collection_setup(collection_name=collection_name, on_disk_vectors=on_disk_vectors)
+    response = request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "points": [
+                {"id": 1001, "vector": [0.1, 0.2, 0.3, 0.4], "payload": {"text": "the robot rebellion"}},
+                {"id": 1002, "vector": [0.2, 0.3, 0.4, 0.5], "payload": {"text": "robots build robots"}},
+                {"id": 1003, "vector": [0.3, 0.4, 0.5, 0.6], "payload": {"text": "reboot the computer"}},
+                {"id": 1004, "vector": [0.4, 0.5, 0.6, 0.7], "payload": {"text": "a rabbit in the garden"}},
+            ]
+        }
+    )
+    assert response.ok
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def create_text_index():
+    response = request_with_validation(
+        api='/collections/{collection_name}/index',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "field_name": "text",
+            "field_schema": {
+                "type": "text",
+                "tokenizer": "word",
+                "lowercase": True,
+            }
+        }
+    )
+    assert response.ok, response.text
+
+
+def scroll_text(match):
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/scroll',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "filter": {"must": [{"key": "text", "match": match}]},
+            "limit": 10,
+        }
+    )
+    assert response.ok, response.text
+    return sorted(point['id'] for point in response.json()['result']['points'])
+
+
+def check_fuzzy_matches():
+    assert scroll_text({"text": "robt"}) == []
+    assert scroll_text({"text": "robt", "fuzzy": 1}) == [1001]
+    assert scroll_text({"text": "robto", "fuzzy": 1}) == []
+    assert scroll_text({"text": "robto", "fuzzy": 2}) == [1001, 1002]
+    assert scroll_text({"text": "robot rebelion", "fuzzy": 1}) == [1001]
+    assert scroll_text({"text": "reboot computr", "fuzzy": 1}) == [1003]
+    assert scroll_text({"text": "Reboot, Computr!", "fuzzy": 1}) == [1003]
+
+
+def test_fuzzy_text_match_indexed():
+    create_text_index()
+    check_fuzzy_matches()
+
+
+def test_fuzzy_text_match_without_index():
+    check_fuzzy_matches()
+
+
+@pytest.mark.parametrize("fuzzy", [0, 3])
+def test_fuzzy_text_match_invalid_distance(fuzzy):
+    # The schema only allows distances 1 and 2, so the request is sent without schema validation
+    response = requests.post(
+        f"{QDRANT_HOST}/collections/{collection_name}/points/scroll",
+        json={
+            "filter": {"must": [{"key": "text", "match": {"text": "robot", "fuzzy": fuzzy}}]},
+            "limit": 10,
+        }
+    )
+    assert response.status_code == 422
@@ -0,0 +1,190 @@
+import pytest
+
+from .helpers.collection_setup import basic_collection_setup, drop_collection
+from .helpers.helpers import request_with_validation
+
+collection_name = 'test_collection_geo_distance'
+
+berlin = {"lon": 13.41053, "lat": 52.52437}
+potsdam = {"lon": 13.064473, "lat": 52.390569}
+hamburg = {"lon": 9.993682, "lat": 53.551086}
+munich = {"lon": 11.581981, "lat": 48.135125}
+tokyo = {"lon": 139.691706, "lat": 35.689487}
+
+
+@pytest.fixture(autouse=True)
+def setup(on_disk_vectors):
+    basic_collection_setup(collection_name=collection_name, on_disk_vectors=on_disk_vectors)
+    response = request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "points": [
+                {
+                    "id": 1001,
+                    "vector": [1.05, 1.61, 1.76, 0.74],
+                    "payload": {"location": tokyo}
+                },
+                {
+                    "id": 1002,
+                    "vector": [1.19, 1.81, 1.75, 0.11],
+                    "payload": {"location": munich}
+                },
+                {
+                    "id": 1003,
+                    "vector": [1.36, 1.55, 1.47, 0.94],
+                    "payload": {"location": [tokyo, potsdam]}
+                },
+                {
+                    "id": 1004,
+                    "vector": [1.18, 1.01, 1.85, 0.80],
+                    "payload": {"location": hamburg}
+                },
+                {
+                    "id": 1005,
+                    "vector": [1.07, 1.20, 1.42, 0.62],
+                    "payload": {"city": "Berlin"}
+                },
+            ]
+        }
+    )
+    assert response.ok
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def scroll_page_by_distance(origin, limit=10, query_filter=None, offset=None, start_from=None):
+    body = {
+        "limit": limit,
+        "order_by": {"geo_distance": {"key": "location", "origin": origin}},
+    }
+    if query_filter is not None:
+        body["filter"] = query_filter
+    if offset is not None:
+        body["offset"] = offset
+    if start_from is not None:
+        body["order_by"]["start_from"] = start_from
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/scroll',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body=body,
+    )
+    assert response.ok
+    result = response.json()['result']
+    return result['points'], result['next_page_offset']
+
+
+def scroll_by_distance(origin, limit=10, query_filter=None):
+    points, next_page_offset = scroll_page_by_distance(origin, limit, query_filter)
+    assert next_page_offset is None
+    return points
+
+
+def scroll_all_pages_by_distance(origin, limit):
+    ids = []
+    offset = None
+    while True:
+        points, offset = scroll_page_by_distance(origin, limit, offset=offset)
+        assert len(points) <= limit
+        ids.extend(point['id'] for point in points)
+        if offset is None:
+            return ids
+
+
+def check_geo_distance_order():
+    points = scroll_by_distance(berlin)
+    # Points without geo values are skipped
+    assert [point['id'] for point in points] == [1003, 1004, 1002, 1001]
+    distances = [point['geo_distance'] for point in points]
+    assert distances == sorted(distances)
+    # Berlin - Potsdam is about 27 km
+    assert 25_000 < distances[0] < 30_000
+
+    points = scroll_by_distance(tokyo, limit=2)
+    assert [point['id'] for point in points] == [1001, 1003]
+    assert points[0]['geo_distance'] == 0.0
+
+    points = scroll_by_distance(berlin, limit=2, query_filter={
+        "must_not": [{"has_id": [1003]}]
+    })
+    assert [point['id'] for point in points] == [1004, 1002]
+
+    # Pages continue from the distance of the offset point, ties are ordered by id
+    assert scroll_all_pages_by_distance(berlin, limit=1) == [1003, 1004, 1002, 1001]
+    assert scroll_all_pages_by_distance(tokyo, limit=1) == [1001, 1003, 1004, 1002]
+    assert scroll_all_pages_by_distance(tokyo, limit=3) == [1001, 1003, 1004, 1002]
+
+    # Points closer than the start distance are skipped
+    points, _ = scroll_page_by_distance(berlin, start_from=100_000)
+    assert [point['id'] for point in points] == [1004, 1002, 1001]
+
+
+def test_geo_distance_scroll():
+    check_geo_distance_order()
+
+
+def test_geo_distance_scroll_indexed():
+    response = request_with_validation(
+        api='/collections/{collection_name}/index',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "field_name": "location",
+            "field_schema": "geo"
+        }
+    )
+    assert response.ok
+
+    check_geo_distance_order()
+
+
+def test_geo_distance_scroll_offset_without_geo_value():
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/scroll',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "offset": 1005,
+            "limit": 2,
+            "order_by": {"geo_distance": {"key": "location", "origin": berlin}},
+        }
+    )
+    assert response.status_code == 400
+
+
+def test_search_with_geo_distance():
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/search',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "vector": [0.2, 0.1, 0.9, 0.7],
+            "limit": 5,
+            "with_geo_distance": {"key": "location", "origin": berlin},
+        }
+    )
+    assert response.ok
+    points = response.json()['result']
+    assert len(points) == 5
+
+    distances = {point['id']: point.get('geo_distance') for point in points}
+    assert distances[1005] is None
+    assert 25_000 < distances[1003] < 30_000
+    assert distances[1003] < distances[1004] < distances[1002] < distances[1001]
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/search',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "vector": [0.2, 0.1, 0.9, 0.7],
+            "limit": 5,
+        }
+    )
+    assert response.ok
+    assert all('geo_distance' not in point for point in response.json()['result'])
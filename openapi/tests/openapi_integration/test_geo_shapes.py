@@ -0,0 +1,142 @@
+import pytest
+
+from .helpers.collection_setup import basic_collection_setup, drop_collection
+from .helpers.helpers import request_with_validation
+
+collection_name = 'test_collection_geo_shapes'
+
+berlin_zone = {
+    "type": "Polygon",
+    "coordinates": [[[13.0, 52.3], [13.8, 52.3], [13.8, 52.7], [13.0, 52.7], [13.0, 52.3]]]
+}
+
+berlin_potsdam_road = {
+    "type": "LineString",
+    "coordinates": [[13.41053, 52.52437], [13.064473, 52.390569]]
+}
+
+tokyo_zones = {
+    "type": "MultiPolygon",
+    "coordinates": [
+        [[[139.5, 35.5], [139.9, 35.5], [139.9, 35.9], [139.5, 35.9], [139.5, 35.5]]],
+    ]
+}
+
+
+@pytest.fixture(autouse=True)
+def setup(on_disk_vectors):
+    basic_collection_setup(collection_name=collection_name, on_disk_vectors=on_disk_vectors)
+    response = request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "points": [
+                {
+                    "id": 1001,
+                    "vector": [1.05, 1.61, 1.76, 0.74],
+                    "payload": {"location": berlin_zone}
+                },
+                {
+                    "id": 1002,
+                    "vector": [1.19, 1.81, 1.75, 0.11],
+                    "payload": {"location": berlin_potsdam_road}
+                },
+                {
+                    "id": 1003,
+                    "vector": [1.36, 1.55, 1.47, 0.94],
+                    "payload": {"location": {"lon": 13.41053, "lat": 52.52437}}
+                },
+                {
+                    "id": 1004,
+                    "vector": [1.18, 1.01, 1.85, 0.80],
+                    "payload": {"location": tokyo_zones}
+                },
+            ]
+        }
+    )
+    assert response.ok
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def scroll_ids(geo_shape):
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/scroll',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "filter": {
+                "must": [
+                    {"key": "location", "geo_shape": geo_shape}
+                ]
+            },
+            "limit": 10,
+        }
+    )
+    assert response.ok
+    return sorted(point['id'] for point in response.json()['result']['points'])
+
+
+def check_geo_shape_filters():
+    intersects_berlin = {
+        "relation": "intersects",
+        "shape": {"point": {"lon": 13.41053, "lat": 52.52437}},
+    }
+    assert scroll_ids(intersects_berlin) == [1001, 1002, 1003]
+
+    within_berlin_area = {
+        "relation": "within",
+        "shape": {
+            "bounding_box": {
+                "top_left": {"lon": 12.9, "lat": 52.8},
+                "bottom_right": {"lon": 13.9, "lat": 52.2},
+            }
+        },
+    }
+    assert scroll_ids(within_berlin_area) == [1001, 1002, 1003]
+
+    contains_berlin_center = {
+        "relation": "contains",
+        "shape": {
+            "polygon": {
+                "exterior": {
+                    "points": [
+                        {"lon": 13.3, "lat": 52.45},
+                        {"lon": 13.5, "lat": 52.45},
+                        {"lon": 13.5, "lat": 52.55},
+                        {"lon": 13.3, "lat": 52.55},
+                        {"lon": 13.3, "lat": 52.45},
+                    ]
+                }
+            }
+        },
+    }
+    assert scroll_ids(contains_berlin_center) == [1001]
+
+    intersects_tokyo = {
+        "relation": "intersects",
+        "shape": {"point": {"lon": 139.691706, "lat": 35.689487}},
+    }
+    assert scroll_ids(intersects_tokyo) == [1004]
+
+
+def test_geo_shape_filter():
+    check_geo_shape_filters()
+
+
+def test_geo_shape_filter_indexed():
+    response = request_with_validation(
+        api='/collections/{collection_name}/index',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "field_name": "location",
+            "field_schema": "geo"
+        }
+    )
+    assert response.ok
+
+    check_geo_shape_filters()
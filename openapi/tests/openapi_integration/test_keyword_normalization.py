@@ -0,0 +1,97 @@
+import pytest
+
+from .helpers.collection_setup import basic_collection_setup, drop_collection
+from .helpers.helpers import request_with_validation
+
+collection_name = 'test_collection_keyword_normalization'
+
+
+@pytest.fixture(autouse=True)
+def setup(on_disk_vectors):
+    basic_collection_setup(collection_name=collection_name, on_disk_vectors=on_disk_vectors)
+    response = request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "points": [
+                {"id": 1001, "vector": [0.1, 0.2, 0.3, 0.4], "payload": {"sku": "Berlin-01"}},
+                {"id": 1002, "vector": [0.2, 0.3, 0.4, 0.5], "payload": {"sku": "ＢＥＲＬＩＮ-02"}},
+                {"id": 1003, "vector": [0.3, 0.4, 0.5, 0.6], "payload": {"sku": ["berlin-03", "Paris-01"]}},
+                {"id": 1004, "vector": [0.4, 0.5, 0.6, 0.7], "payload": {"sku": "ﬁle-01"}},
+            ]
+        }
+    )
+    assert response.ok
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def create_index(field_schema):
+    response = request_with_validation(
+        api='/collections/{collection_name}/index',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "field_name": "sku",
+            "field_schema": field_schema
+        }
+    )
+    assert response.ok, response.text
+
+
+def scroll_ids(match):
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/scroll',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "limit": 10,
+            "filter": {
+                "must": [
+                    {"key": "sku", "match": match}
+                ]
+            }
+        }
+    )
+    assert response.ok, response.text
+    return sorted(point['id'] for point in response.json()['result']['points'])
+
+
+def test_keyword_index_lowercase():
+    create_index({"type": "keyword", "lowercase": True})
+
+    assert scroll_ids({"value": "BERLIN-01"}) == [1001]
+    assert scroll_ids({"any": ["paris-01", "BERLIN-03"]}) == [1003]
+    assert scroll_ids({"prefix": "Berlin"}) == [1001, 1003]
+    # Full-width characters are not folded without NFKC
+    assert scroll_ids({"value": "berlin-02"}) == []
+
+
+def test_keyword_index_nfkc_and_lowercase():
+    create_index({"type": "keyword", "lowercase": True, "nfkc": True})
+
+    assert scroll_ids({"value": "berlin-02"}) == [1002]
+    assert scroll_ids({"value": "FILE-01"}) == [1004]
+    assert scroll_ids({"prefix": "ＢＥＲ"}) == [1001, 1002, 1003]
+    assert scroll_ids({"wildcard": "BERLIN-0?"}) == [1001, 1002, 1003]
+    assert scroll_ids({"except": ["berlin-01", "berlin-02"]}) == [1003, 1004]
+
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="GET",
+        path_params={'collection_name': collection_name},
+    )
+    assert response.ok
+    payload_schema = response.json()['result']['payload_schema']['sku']
+    assert payload_schema['data_type'] == 'keyword'
+    assert payload_schema['params'] == {"type": "keyword", "lowercase": True, "nfkc": True}
+
+
+def test_keyword_index_without_normalization():
+    create_index({"type": "keyword"})
+
+    assert scroll_ids({"value": "Berlin-01"}) == [1001]
+    assert scroll_ids({"value": "berlin-01"}) == []
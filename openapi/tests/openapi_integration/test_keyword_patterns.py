@@ -0,0 +1,79 @@
+import pytest
+
+from .helpers.collection_setup import basic_collection_setup, drop_collection
+from .helpers.helpers import request_with_validation
+
+collection_name = 'test_collection_keyword_patterns'
+
+
+@pytest.fixture(autouse=True)
+def setup(on_disk_vectors):
+    basic_collection_setup(collection_name=collection_name, on_disk_vectors=on_disk_vectors)
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def scroll_ids(match):
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/scroll',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "limit": 10,
+            "filter": {
+                "must": [
+                    {"key": "city", "match": match}
+                ]
+            }
+        }
+    )
+    assert response.ok, response.text
+    return sorted(point['id'] for point in response.json()['result']['points'])
+
+
+def check_keyword_patterns():
+    assert scroll_ids({"prefix": "Lo"}) == [2, 4]
+    assert scroll_ids({"prefix": "lo"}) == []
+    assert scroll_ids({"wildcard": "*o*"}) == [2, 3, 4]
+    assert scroll_ids({"wildcard": "B?rlin"}) == [1, 2, 3]
+    assert scroll_ids({"wildcard": "Ber"}) == []
+    assert scroll_ids({"regex": "(London|Moscow)"}) == [2, 3, 4]
+    # The whole keyword must match the regex
+    assert scroll_ids({"regex": "Mos"}) == []
+    assert scroll_ids({"regex": "Mos.*"}) == [3, 4]
+
+
+def test_keyword_patterns():
+    check_keyword_patterns()
+
+
+def test_keyword_patterns_indexed():
+    response = request_with_validation(
+        api='/collections/{collection_name}/index',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "field_name": "city",
+            "field_schema": "keyword"
+        }
+    )
+    assert response.ok
+
+    check_keyword_patterns()
+
+
+def test_invalid_regex():
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/scroll',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "filter": {
+                "must": [
+                    {"key": "city", "match": {"regex": "(Berlin"}}
+                ]
+            }
+        }
+    )
+    assert response.status_code == 422
@@ -0,0 +1,168 @@
+import pytest
+
+from .helpers.collection_setup import drop_collection
+from .helpers.helpers import request_with_validation
+
+collection_name = 'test_payload_schema'
+
+
+@pytest.fixture(autouse=True)
+def setup():
+    drop_collection(collection_name=collection_name)
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        body={
+            "vectors": {
+                "size": 4,
+                "distance": "Dot",
+            },
+            "payload_schema": {
+                "properties": {
+                    "price": {"type": "integer"},
+                    "color": {"type": "string", "enum": ["red", "green"]},
+                    "tags": {"type": "array", "items": {"type": "string"}},
+                },
+                "required": ["price"],
+                "strict": True,
+            },
+        }
+    )
+    assert response.ok
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/index',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "field_name": "rating",
+            "field_schema": "float",
+        }
+    )
+    assert response.ok
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def upsert(points):
+    return request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={"points": points},
+    )
+
+
+def test_upsert_payload_schema():
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="GET",
+        path_params={'collection_name': collection_name},
+    )
+    assert response.ok
+    schema = response.json()['result']['config']['params']['payload_schema']
+    assert schema['properties']['price'] == {"type": "integer"}
+    assert schema['strict']
+
+    response = upsert([
+        {"id": 1, "vector": [0.1, 0.2, 0.3, 0.4], "payload": {"price": 10, "color": "red", "tags": ["a"]}},
+        {"id": 2, "vector": [0.1, 0.2, 0.3, 0.4], "payload": {"price": 20, "rating": 4.5}},
+    ])
+    assert response.ok
+
+    response = upsert([
+        {"id": 3, "vector": [0.1, 0.2, 0.3, 0.4], "payload": {"price": "30"}},
+        {"id": 4, "vector": [0.1, 0.2, 0.3, 0.4], "payload": {"color": "red"}},
+        {"id": 5, "vector": [0.1, 0.2, 0.3, 0.4], "payload": {"price": 50, "color": "blue"}},
+        {"id": 6, "vector": [0.1, 0.2, 0.3, 0.4], "payload": {"price": 60, "rating": "good"}},
+    ])
+    assert response.status_code == 400
+    error = response.json()['status']['error']
+    assert "point 3: field `price` must be integer, got string" in error
+    assert "point 4: field `price` is required" in error
+    assert "point 5: field `color` must be one of" in error
+    assert "point 6: value \"good\" of field `rating`" in error
+
+    # Invalid batch is rejected as a whole
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/count',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"exact": True},
+    )
+    assert response.ok
+    assert response.json()['result']['count'] == 2
+
+
+def test_set_payload_schema():
+    response = upsert([
+        {"id": 1, "vector": [0.1, 0.2, 0.3, 0.4], "payload": {"price": 10}},
+    ])
+    assert response.ok
+
+    # Partial update doesn't need required fields
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/payload',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={"payload": {"color": "green"}, "points": [1]},
+    )
+    assert response.ok
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/payload',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={"payload": {"tags": ["a", 1]}, "points": [1]},
+    )
+    assert response.status_code == 400
+
+    # Overwrite replaces the whole payload, so required fields must be present
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/payload',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={"payload": {"color": "green"}, "points": [1]},
+    )
+    assert response.status_code == 400
+
+
+def test_update_payload_schema():
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PATCH",
+        path_params={'collection_name': collection_name},
+        body={
+            "params": {
+                "payload_schema": {"properties": {}},
+            },
+        }
+    )
+    assert response.ok
+
+    response = upsert([
+        {"id": 1, "vector": [0.1, 0.2, 0.3, 0.4], "payload": {"price": "free"}},
+    ])
+    assert response.ok
+
+
+def test_invalid_payload_schema():
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PATCH",
+        path_params={'collection_name': collection_name},
+        body={
+            "params": {
+                "payload_schema": {
+                    "properties": {"color": {"type": "string", "enum": [1, 2]}},
+                },
+            },
+        }
+    )
+    assert response.status_code == 422
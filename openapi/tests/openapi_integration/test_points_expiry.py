@@ -0,0 +1,115 @@
+import time
+
+import pytest
+
+from .helpers.collection_setup import drop_collection
+from .helpers.helpers import request_with_validation
+
+collection_name = 'test_collection_expiry'
+
+
+@pytest.fixture(autouse=True)
+def setup():
+    drop_collection(collection_name=collection_name)
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        body={
+            "vectors": {
+                "size": 4,
+                "distance": "Dot",
+            },
+            "expiry": {},
+        }
+    )
+    assert response.ok
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def upsert_points(points):
+    response = request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={"points": points},
+    )
+    assert response.ok
+
+
+def count_points():
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/count',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"exact": True},
+    )
+    assert response.ok
+    return response.json()['result']['count']
+
+
+def test_expired_points_are_excluded():
+    now = int(time.time())
+    upsert_points([
+        {"id": 1, "vector": [0.05, 0.61, 0.76, 0.74], "payload": {"expire_at": now - 60}},
+        {"id": 2, "vector": [0.19, 0.81, 0.75, 0.11], "payload": {"expire_at": now + 3600}},
+        {"id": 3, "vector": [0.36, 0.55, 0.47, 0.94]},
+    ])
+
+    assert count_points() == 2
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/scroll',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"limit": 10},
+    )
+    assert response.ok
+    assert [point['id'] for point in response.json()['result']['points']] == [2, 3]
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/search',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"vector": [0.2, 0.1, 0.9, 0.7], "limit": 10},
+    )
+    assert response.ok
+    assert sorted(point['id'] for point in response.json()['result']) == [2, 3]
+
+
+def test_collection_ttl():
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PATCH",
+        path_params={'collection_name': collection_name},
+        body={"params": {"expiry": {"ttl": 1}}},
+    )
+    assert response.ok
+
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="GET",
+        path_params={'collection_name': collection_name},
+    )
+    assert response.ok
+    assert response.json()['result']['config']['params']['expiry'] == {"ttl": 1}
+
+    now = int(time.time())
+    upsert_points([
+        {"id": 1, "vector": [0.05, 0.61, 0.76, 0.74]},
+        {"id": 2, "vector": [0.19, 0.81, 0.75, 0.11], "payload": {"expire_at": now + 3600}},
+    ])
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/{id}',
+        method="GET",
+        path_params={'collection_name': collection_name, 'id': 1},
+    )
+    assert response.ok
+    assert response.json()['result']['payload']['expire_at'] >= now + 1
+
+    time.sleep(2.5)
+
+    assert count_points() == 1
@@ -0,0 +1,73 @@
+import json
+
+import pytest
+import requests
+
+from .helpers.collection_setup import basic_collection_setup, drop_collection
+from .helpers.helpers import request_with_validation
+from .helpers.settings import QDRANT_HOST
+
+collection_name = 'test_collection_shard_changes'
+
+
+@pytest.fixture(autouse=True)
+def setup(on_disk_vectors):
+    basic_collection_setup(collection_name=collection_name, on_disk_vectors=on_disk_vectors)
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def read_events(params, num):
+    events = []
+    with requests.get(
+        f"{QDRANT_HOST}/collections/{collection_name}/shards/0/changes",
+        params=params,
+        stream=True,
+        timeout=10,
+    ) as response:
+        assert response.ok
+        assert response.headers['content-type'].startswith('text/event-stream')
+
+        event = {}
+        for line in response.iter_lines(decode_unicode=True):
+            if not line:
+                events.append(event)
+                event = {}
+                if len(events) == num:
+                    break
+                continue
+            field, value = line.split(': ', 1)
+            event[field] = value
+    return events
+
+
+def test_shard_changes_stream():
+    events = read_events({}, 1)
+
+    # Collection setup inserts points with the very first operation
+    assert 'event' not in events[0]
+    assert 'upsert_points' in json.loads(events[0]['data'])['operation']
+    last_id = int(events[0]['id'])
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/payload',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "payload": {"cdc": True},
+            "points": [1],
+        }
+    )
+    assert response.ok
+
+    # Resume right after the last seen operation
+    events = read_events({'from': last_id + 1}, 1)
+    change = json.loads(events[0]['data'])
+    assert change['operation_id'] > last_id
+    assert 'set_payload' in change['operation']
+
+
+def test_shard_changes_missing_collection():
+    response = requests.get(f"{QDRANT_HOST}/collections/missing_collection/shards/0/changes")
+    assert response.status_code == 404
@@ -83,3 +83,33 @@ def test_sparse_vector_config_update():
     result = response.json()["result"]
     config = result["config"]
     assert config["params"]["sparse_vectors"]["sparse-text"]["index"]["on_disk"]
+
+
+def test_sparse_vector_compressed_config_update():
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PATCH",
+        path_params={'collection_name': collection_name},
+        body={
+            "sparse_vectors": {
+                "sparse-text": {
+                    "index": {
+                        "compressed": True,
+                    }
+                },
+            },
+        }
+    )
+    assert response.ok
+
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="GET",
+        path_params={'collection_name': collection_name},
+    )
+
+    assert response.ok
+    index_config = response.json()["result"]["config"]["params"]["sparse_vectors"]["sparse-text"]["index"]
+    assert index_config["compressed"]
+    # other params are preserved
+    assert not index_config["on_disk"]
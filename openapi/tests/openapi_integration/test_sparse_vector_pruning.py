@@ -0,0 +1,98 @@
+import pytest
+
+from .helpers.collection_setup import drop_collection
+from .helpers.helpers import request_with_validation
+
+collection_name = 'test_sparse_vector_pruning'
+
+
+@pytest.fixture(autouse=True)
+def setup():
+    sparse_collection_setup(collection_name=collection_name)
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def sparse_collection_setup(collection_name='test_collection'):
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="DELETE",
+        path_params={'collection_name': collection_name},
+    )
+    assert response.ok
+
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        body={
+            "sparse_vectors": {
+                "text": {
+                    "prune_threshold": 0.1,
+                    "max_dims": 3,
+                    "datatype": "uint8",
+                }
+            },
+        }
+    )
+    assert response.ok
+
+
+def test_sparse_vector_pruned_on_upsert():
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="GET",
+        path_params={'collection_name': collection_name},
+    )
+    assert response.ok
+    params = response.json()["result"]["config"]["params"]["sparse_vectors"]["text"]
+    assert params["prune_threshold"] == 0.1
+    assert params["max_dims"] == 3
+    assert params["datatype"] == "uint8"
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "points": [
+                {
+                    "id": 1,
+                    "vector": {
+                        "text": {"indices": [1, 2, 3, 4, 5], "values": [0.05, 1.0, 2.0, -3.0, 0.1]}
+                    }
+                },
+            ]
+        }
+    )
+    assert response.ok
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/{id}',
+        method="GET",
+        path_params={'collection_name': collection_name, 'id': 1},
+    )
+    assert response.ok
+    vector = response.json()["result"]["vector"]["text"]
+    # weights below the threshold are pruned, then only the top 3 dimensions are kept
+    assert vector["indices"] == [2, 3, 4]
+    assert vector["values"] == [1.0, 2.0, -3.0]
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/search',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "vector": {
+                "name": "text",
+                "vector": {"indices": [3, 5], "values": [1.0, 1.0]}
+            },
+            "limit": 3,
+        }
+    )
+    assert response.ok
+    result = response.json()["result"]
+    assert len(result) == 1
+    assert result[0]["score"] == 2.0
+
@@ -0,0 +1,234 @@
+import pytest
+
+from .helpers.collection_setup import basic_collection_setup, drop_collection
+from .helpers.helpers import request_with_validation
+
+collection_name = 'test_strict_mode'
+
+
+def set_strict_mode(strict_mode):
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PATCH",
+        path_params={'collection_name': collection_name},
+        body={
+            "params": {
+                "strict_mode": strict_mode,
+            },
+        }
+    )
+    assert response.ok
+
+
+@pytest.fixture(autouse=True)
+def setup(on_disk_vectors):
+    basic_collection_setup(collection_name=collection_name, on_disk_vectors=on_disk_vectors)
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/index',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "field_name": "city",
+            "field_schema": "keyword",
+        }
+    )
+    assert response.ok
+
+    set_strict_mode({
+        "max_query_limit": 5,
+        "max_query_offset": 2,
+        "max_hnsw_ef": 64,
+        "search_allow_exact": False,
+        "unindexed_filtering": False,
+        "max_search_batch_size": 2,
+        "max_update_points": 2,
+        "max_payload_size": 64,
+        "max_histogram_buckets": 100,
+    })
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def search(body):
+    return request_with_validation(
+        api='/collections/{collection_name}/points/search',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "vector": [0.2, 0.1, 0.9, 0.7],
+            **body,
+        }
+    )
+
+
+def upsert(points):
+    return request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={"points": points},
+    )
+
+
+def test_strict_mode_search():
+    response = search({"limit": 5, "offset": 2, "params": {"hnsw_ef": 64}})
+    assert response.ok
+
+    response = search({"limit": 10})
+    assert response.status_code == 400
+    assert "Query limit is 10" in response.json()['status']['error']
+
+    response = search({"limit": 3, "offset": 3})
+    assert response.status_code == 400
+
+    response = search({"limit": 3, "params": {"hnsw_ef": 128}})
+    assert response.status_code == 400
+
+    response = search({"limit": 3, "params": {"exact": True}})
+    assert response.status_code == 400
+    assert "Exact search" in response.json()['status']['error']
+
+    response = search({
+        "limit": 3,
+        "filter": {"must": [{"key": "city", "match": {"value": "London"}}]},
+    })
+    assert response.ok
+
+    response = search({
+        "limit": 3,
+        "filter": {"must": [{"key": "count", "match": {"value": 0}}]},
+    })
+    assert response.status_code == 400
+    assert "`count`" in response.json()['status']['error']
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/search/batch',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "searches": [
+                {"vector": [0.2, 0.1, 0.9, 0.7], "limit": 3},
+                {"vector": [0.2, 0.1, 0.9, 0.7], "limit": 3},
+                {"vector": [0.2, 0.1, 0.9, 0.7], "limit": 3},
+            ]
+        }
+    )
+    assert response.status_code == 400
+
+
+def test_strict_mode_scroll_and_count():
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/scroll',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"limit": 10},
+    )
+    assert response.status_code == 400
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/count',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"filter": {"must": [{"key": "count", "range": {"gte": 1}}]}},
+    )
+    assert response.status_code == 400
+
+    # Ordering by distance reads the payload of every point without a geo index
+    order_by = {"geo_distance": {"key": "location", "origin": {"lon": 13.4, "lat": 52.5}}}
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/scroll',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"limit": 3, "order_by": order_by},
+    )
+    assert response.status_code == 400
+    assert "`location`" in response.json()['status']['error']
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/index',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={"field_name": "location", "field_schema": "geo"},
+    )
+    assert response.ok
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/scroll',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"limit": 3, "order_by": order_by},
+    )
+    assert response.ok
+
+
+def test_strict_mode_aggregate():
+    def aggregate(histogram):
+        return request_with_validation(
+            api='/collections/{collection_name}/points/aggregate',
+            method="POST",
+            path_params={'collection_name': collection_name},
+            body={"key": "price", "histogram": histogram},
+        )
+
+    # Default max number of buckets is above the limit
+    response = aggregate({"interval": 10})
+    assert response.status_code == 400
+    assert "histogram buckets" in response.json()['status']['error']
+
+    response = aggregate({"interval": 10, "max_buckets": 100})
+    assert response.ok
+
+
+def test_strict_mode_update():
+    response = upsert([
+        {"id": 1, "vector": [0.1, 0.2, 0.3, 0.4], "payload": {"city": "Berlin"}},
+        {"id": 2, "vector": [0.1, 0.2, 0.3, 0.4], "payload": {"city": "Moscow"}},
+    ])
+    assert response.ok
+
+    response = upsert([
+        {"id": 1, "vector": [0.1, 0.2, 0.3, 0.4]},
+        {"id": 2, "vector": [0.1, 0.2, 0.3, 0.4]},
+        {"id": 3, "vector": [0.1, 0.2, 0.3, 0.4]},
+    ])
+    assert response.status_code == 400
+    assert "Number of points is 3" in response.json()['status']['error']
+
+    response = upsert([
+        {"id": 1, "vector": [0.1, 0.2, 0.3, 0.4], "payload": {"description": "x" * 100}},
+    ])
+    assert response.status_code == 400
+    assert "Payload of point 1" in response.json()['status']['error']
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/delete',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={"filter": {"must": [{"key": "count", "match": {"value": 0}}]}},
+    )
+    assert response.status_code == 400
+
+
+def test_strict_mode_write_rate_limit():
+    set_strict_mode({"write_rate_limit": 2})
+
+    for _ in range(2):
+        response = upsert([{"id": 1, "vector": [0.1, 0.2, 0.3, 0.4]}])
+        assert response.ok
+
+    response = upsert([{"id": 1, "vector": [0.1, 0.2, 0.3, 0.4]}])
+    assert response.status_code == 400
+    assert "Write rate limit" in response.json()['status']['error']
+
+
+def test_disable_strict_mode():
+    set_strict_mode({})
+
+    response = search({"limit": 10, "params": {"exact": True}})
+    assert response.ok
+    assert len(response.json()['result']) == 10
@@ -0,0 +1,146 @@
+import pytest
+
+from .helpers.collection_setup import basic_collection_setup, drop_collection
+from .helpers.helpers import request_with_validation
+
+collection_name = 'test_collection_text_search'
+sharded_collection_name = 'test_collection_text_search_sharded'
+
+text_points = [
+    {"id": 1001, "vector": [0.1, 0.2, 0.3, 0.4], "payload": {"lang": "en", "text": "robots and robots and more robots"}},
+    {"id": 1002, "vector": [0.2, 0.3, 0.4, 0.5], "payload": {"lang": "de", "text": "the robot rebellion of the robots"}},
+    {"id": 1003, "vector": [0.3, 0.4, 0.5, 0.6], "payload": {"lang": "en", "text": "a long story about humans, space, stars and a single robots factory"}},
+    {"id": 1004, "vector": [0.4, 0.5, 0.6, 0.7], "payload": {"lang": "en", "text": "humans only"}},
+    {"id": 1005, "vector": [0.5, 0.6, 0.7, 0.8], "payload": {"lang": "en"}},
+]
+
+
+def upsert_text_points(name):
+    response = request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="PUT",
+        path_params={'collection_name': name},
+        query_params={'wait': 'true'},
+        body={"points": text_points}
+    )
+    assert response.ok
+
+
+@pytest.fixture(autouse=True)
+def setup(on_disk_vectors):
+    basic_collection_setup(collection_name=collection_name, on_disk_vectors=on_disk_vectors)
+    upsert_text_points(collection_name)
+    yield
+    drop_collection(collection_name=collection_name)
+    drop_collection(collection_name=sharded_collection_name)
+
+
+def create_text_index(name=collection_name):
+    response = request_with_validation(
+        api='/collections/{collection_name}/index',
+        method="PUT",
+        path_params={'collection_name': name},
+        query_params={'wait': 'true'},
+        body={
+            "field_name": "text",
+            "field_schema": {
+                "type": "text",
+                "tokenizer": "word",
+                "lowercase": True,
+            }
+        }
+    )
+    assert response.ok, response.text
+
+
+def search_text(body, name=collection_name):
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/search/text',
+        method="POST",
+        path_params={'collection_name': name},
+        body=body,
+    )
+    assert response.ok, response.text
+    return response.json()['result']
+
+
+def test_search_text():
+    create_text_index()
+
+    result = search_text({"key": "text", "query": "Robots", "limit": 10})
+    assert [point['id'] for point in result] == [1001, 1002, 1003]
+    scores = [point['score'] for point in result]
+    assert scores == sorted(scores, reverse=True)
+    assert all(score > 0 for score in scores)
+    assert 'payload' not in result[0] or result[0]['payload'] is None
+
+    # Rare tokens weight more than frequent ones
+    result = search_text({"key": "text", "query": "humans robots rebellion", "limit": 1, "with_payload": True})
+    assert result[0]['id'] == 1002
+    assert result[0]['payload']['lang'] == "de"
+
+    result = search_text({"key": "text", "query": "robots", "limit": 1, "offset": 1})
+    assert [point['id'] for point in result] == [1002]
+
+    result = search_text({
+        "key": "text",
+        "query": "robots",
+        "limit": 10,
+        "filter": {"must": [{"key": "lang", "match": {"value": "en"}}]},
+    })
+    assert [point['id'] for point in result] == [1001, 1003]
+
+    threshold = result[1]['score'] + 0.01
+    result = search_text({"key": "text", "query": "robots", "limit": 10, "score_threshold": threshold})
+    assert [point['id'] for point in result] == [1001, 1002]
+
+    assert search_text({"key": "text", "query": "unknown", "limit": 10}) == []
+
+
+def test_search_text_sharded():
+    create_text_index()
+
+    drop_collection(collection_name=sharded_collection_name)
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PUT",
+        path_params={'collection_name': sharded_collection_name},
+        body={
+            "vectors": {"size": 4, "distance": "Dot"},
+            "shard_number": 3,
+        }
+    )
+    assert response.ok
+    upsert_text_points(sharded_collection_name)
+    create_text_index(sharded_collection_name)
+
+    # Shards score with the statistics of the whole collection
+    query = {"key": "text", "query": "humans robots rebellion", "limit": 10}
+    expected = search_text(query)
+    result = search_text(query, sharded_collection_name)
+    assert [point['id'] for point in result] == [point['id'] for point in expected]
+    for point, expected_point in zip(result, expected):
+        assert point['score'] == pytest.approx(expected_point['score'])
+
+
+def test_search_text_requires_index():
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/search/text',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"key": "text", "query": "robots", "limit": 10},
+    )
+    assert response.status_code == 400
+    assert "full-text index" in response.json()['status']['error']
+
+
+def test_search_text_invalid_request():
+    create_text_index()
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/search/text',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"key": "text", "query": "", "limit": 10},
+    )
+    assert response.status_code == 422
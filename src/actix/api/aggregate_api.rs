@@ -0,0 +1,49 @@
+use actix_web::rt::time::Instant;
+use actix_web::{post, web, Responder};
+use actix_web_validator::{Json, Path, Query};
+use collection::operations::shard_selector_internal::ShardSelectorInternal;
+use collection::operations::types::AggregateRequest;
+use storage::content_manager::toc::TableOfContent;
+
+use super::CollectionPath;
+use crate::actix::api::read_params::ReadParams;
+use crate::actix::helpers::process_response;
+use crate::common::points::do_aggregate_points;
+
+#[post("/collections/{name}/points/aggregate")]
+async fn aggregate_points(
+    toc: web::Data<TableOfContent>,
+    collection: Path<CollectionPath>,
+    request: Json<AggregateRequest>,
+    params: Query<ReadParams>,
+) -> impl Responder {
+    let timing = Instant::now();
+
+    if let Err(err) = params
+        .wait_for_consistency_token(toc.get_ref(), &collection.name)
+        .await
+    {
+        return process_response::<()>(Err(err), timing);
+    }
+
+    let AggregateRequest {
+        aggregate_request,
+        shard_key,
+    } = request.into_inner();
+
+    let shard_selector = match shard_key {
+        None => ShardSelectorInternal::All,
+        Some(shard_keys) => ShardSelectorInternal::from(shard_keys),
+    };
+
+    let response = do_aggregate_points(
+        toc.get_ref(),
+        &collection.name,
+        aggregate_request,
+        params.consistency,
+        shard_selector,
+    )
+    .await;
+
+    process_response(response, timing)
+}
@@ -6,9 +6,11 @@ use actix_web_validator::{Json, Path, Query};
 use collection::operations::cluster_ops::ClusterOperations;
 use serde::Deserialize;
 use storage::content_manager::collection_meta_ops::{
-    ChangeAliasesOperation, CollectionMetaOperations, CreateCollection, CreateCollectionOperation,
-    DeleteCollectionOperation, UpdateCollection, UpdateCollectionOperation,
+    ChangeAliasesOperation, CloneCollection, CloneCollectionOperation, CollectionMetaOperations,
+    CreateCollection, CreateCollectionOperation, DeleteCollectionOperation, RenameCollection,
+    RenameCollectionOperation, UpdateCollection, UpdateCollectionOperation,
 };
+use storage::content_manager::copy_jobs::CopyPointsRequest;
 use storage::content_manager::toc::TableOfContent;
 use storage::dispatcher::Dispatcher;
 use validator::Validate;
@@ -123,6 +125,69 @@ async fn delete_collection(
     process_response(response, timing)
 }
 
+#[post("/collections/{name}/rename")]
+async fn rename_collection(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    operation: Json<RenameCollection>,
+    Query(query): Query<WaitTimeout>,
+) -> impl Responder {
+    let timing = Instant::now();
+    let response = dispatcher
+        .submit_collection_meta_op(
+            CollectionMetaOperations::RenameCollection(RenameCollectionOperation {
+                collection_name: collection.name.clone(),
+                rename_collection: operation.into_inner(),
+            }),
+            query.timeout(),
+        )
+        .await;
+    process_response(response, timing)
+}
+
+#[post("/collections/{name}/clone")]
+async fn clone_collection(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    operation: Json<CloneCollection>,
+    Query(query): Query<WaitTimeout>,
+) -> impl Responder {
+    let timing = Instant::now();
+    let response = dispatcher
+        .submit_collection_meta_op(
+            CollectionMetaOperations::CloneCollection(CloneCollectionOperation {
+                collection_name: collection.name.clone(),
+                clone_collection: operation.into_inner(),
+            }),
+            query.timeout(),
+        )
+        .await;
+    process_response(response, timing)
+}
+
+#[post("/collections/{name}/points/copy")]
+async fn copy_points(
+    toc: web::Data<TableOfContent>,
+    collection: Path<CollectionPath>,
+    request: Json<CopyPointsRequest>,
+) -> impl Responder {
+    let timing = Instant::now();
+    let response = toc
+        .start_copy_points(&collection.name, request.into_inner())
+        .await;
+    process_response(response, timing)
+}
+
+#[get("/collections/{name}/points/copy")]
+async fn get_copy_points_job(
+    toc: web::Data<TableOfContent>,
+    collection: Path<CollectionPath>,
+) -> impl Responder {
+    let timing = Instant::now();
+    let response = toc.get_copy_points_job(&collection.name).await;
+    process_response(response, timing)
+}
+
 #[post("/collections/aliases")]
 async fn update_aliases(
     dispatcher: web::Data<Dispatcher>,
@@ -149,6 +214,16 @@ async fn get_cluster_info(
     process_response(response, timing)
 }
 
+#[get("/collections/{name}/cluster/rebalance")]
+async fn get_cluster_rebalance_plan(
+    toc: web::Data<TableOfContent>,
+    collection: Path<CollectionPath>,
+) -> impl Responder {
+    let timing = Instant::now();
+    let response = do_get_collection_rebalance_plan(toc.get_ref(), &collection.name).await;
+    process_response(response, timing)
+}
+
 #[post("/collections/{name}/cluster")]
 async fn update_collection_cluster(
     dispatcher: web::Data<Dispatcher>,
@@ -175,10 +250,15 @@ pub fn config_collections_api(cfg: &mut web::ServiceConfig) {
         .service(create_collection)
         .service(update_collection)
         .service(delete_collection)
+        .service(rename_collection)
+        .service(clone_collection)
+        .service(copy_points)
+        .service(get_copy_points_job)
         .service(get_aliases)
         .service(get_collection_aliases)
         .service(update_aliases)
         .service(get_cluster_info)
+        .service(get_cluster_rebalance_plan)
         .service(update_collection_cluster);
 }
 
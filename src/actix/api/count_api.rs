@@ -19,6 +19,13 @@ async fn count_points(
 ) -> impl Responder {
     let timing = Instant::now();
 
+    if let Err(err) = params
+        .wait_for_consistency_token(toc.get_ref(), &collection.name)
+        .await
+    {
+        return process_response::<()>(Err(err), timing);
+    }
+
     let CountRequest {
         count_request,
         shard_key,
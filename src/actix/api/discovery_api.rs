@@ -19,6 +19,13 @@ async fn discover_points(
 ) -> impl Responder {
     let timing = Instant::now();
 
+    if let Err(err) = params
+        .wait_for_consistency_token(toc.get_ref(), &collection.name)
+        .await
+    {
+        return process_response::<()>(Err(err), timing);
+    }
+
     let DiscoverRequest {
         discover_request,
         shard_key,
@@ -51,6 +58,13 @@ async fn discover_batch_points(
 ) -> impl Responder {
     let timing = Instant::now();
 
+    if let Err(err) = params
+        .wait_for_consistency_token(toc.get_ref(), &collection.name)
+        .await
+    {
+        return process_response::<()>(Err(err), timing);
+    }
+
     let response = do_discover_batch_points(
         toc.get_ref(),
         &collection.name,
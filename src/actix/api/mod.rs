@@ -1,3 +1,4 @@
+pub mod aggregate_api;
 pub mod cluster_api;
 pub mod collections_api;
 pub mod count_api;
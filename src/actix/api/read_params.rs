@@ -1,24 +1,46 @@
 use std::num::NonZeroU64;
 use std::time::Duration;
 
-use collection::operations::consistency_params::ReadConsistency;
+use collection::operations::consistency_params::{ConsistencyToken, ReadConsistency};
 use schemars::JsonSchema;
 use serde::Deserialize;
+use storage::content_manager::errors::StorageError;
+use storage::content_manager::toc::TableOfContent;
 use validator::Validate;
 
-#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Deserialize, JsonSchema, Validate)]
+use crate::common::points::do_wait_for_consistency_token;
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize, JsonSchema, Validate)]
 pub struct ReadParams {
     #[serde(default, deserialize_with = "deserialize_read_consistency")]
     #[validate]
     pub consistency: Option<ReadConsistency>,
     /// If set, overrides global timeout for this request. Unit is seconds.
     pub timeout: Option<NonZeroU64>,
+    /// If set, wait until the update which returned this token is applied on all its replicas
+    #[serde(default)]
+    pub consistency_token: Option<ConsistencyToken>,
 }
 
 impl ReadParams {
     pub fn timeout(&self) -> Option<Duration> {
         self.timeout.map(|num| Duration::from_secs(num.get()))
     }
+
+    /// Wait for the update of `consistency_token`, so the read would see its results
+    pub async fn wait_for_consistency_token(
+        &self,
+        toc: &TableOfContent,
+        collection_name: &str,
+    ) -> Result<(), StorageError> {
+        do_wait_for_consistency_token(
+            toc,
+            collection_name,
+            self.consistency_token.as_ref(),
+            self.timeout(),
+        )
+        .await
+    }
 }
 
 fn deserialize_read_consistency<'de, D>(
@@ -78,6 +100,14 @@ mod test {
         assert!(try_deserialize(&str("0")).is_err());
     }
 
+    #[test]
+    fn deserialize_consistency_token() {
+        let params = deserialize("consistency_token=0%3A1%3D15%2C2%3D17");
+        assert_eq!(params.consistency_token.unwrap().to_string(), "0:1=15,2=17",);
+
+        assert!(try_deserialize("consistency_token=0%3A1").is_err());
+    }
+
     fn test(value: &str, params: ReadParams) {
         test_str(&str(value), params);
     }
@@ -15,6 +15,7 @@ use storage::content_manager::toc::TableOfContent;
 use super::read_params::ReadParams;
 use super::CollectionPath;
 use crate::actix::helpers::process_response;
+use crate::common::points::{do_lookup_batch_scored_points, do_lookup_scored_points};
 
 #[post("/collections/{name}/points/recommend")]
 async fn recommend_points(
@@ -25,9 +26,17 @@ async fn recommend_points(
 ) -> impl Responder {
     let timing = Instant::now();
 
+    if let Err(err) = params
+        .wait_for_consistency_token(toc.get_ref(), &collection.name)
+        .await
+    {
+        return process_response::<()>(Err(err), timing);
+    }
+
     let RecommendRequest {
         recommend_request,
         shard_key,
+        with_lookup,
     } = request.into_inner();
 
     let shard_selection = match shard_key {
@@ -40,11 +49,30 @@ async fn recommend_points(
             &collection.name,
             recommend_request,
             params.consistency,
-            shard_selection,
+            shard_selection.clone(),
             params.timeout(),
         )
         .await;
 
+    if with_lookup.is_none() {
+        return process_response(response, timing);
+    }
+
+    let response = match response {
+        Ok(points) => {
+            do_lookup_scored_points(
+                toc.get_ref(),
+                &collection.name,
+                with_lookup,
+                points,
+                params.consistency,
+                shard_selection,
+            )
+            .await
+        }
+        Err(err) => Err(err),
+    };
+
     process_response(response, timing)
 }
 
@@ -81,15 +109,53 @@ async fn recommend_batch_points(
 ) -> impl Responder {
     let timing = Instant::now();
 
+    if let Err(err) = params
+        .wait_for_consistency_token(toc.get_ref(), &collection.name)
+        .await
+    {
+        return process_response::<()>(Err(err), timing);
+    }
+
+    let request = request.into_inner();
+    let lookups: Vec<_> = request
+        .searches
+        .iter()
+        .map(|req| {
+            let shard_selector = match &req.shard_key {
+                None => ShardSelectorInternal::All,
+                Some(shard_key) => ShardSelectorInternal::from(shard_key.clone()),
+            };
+            (req.with_lookup.clone(), shard_selector)
+        })
+        .collect();
+
     let response = do_recommend_batch_points(
         toc.get_ref(),
         &collection.name,
-        request.into_inner(),
+        request,
         params.consistency,
         params.timeout(),
     )
     .await;
 
+    if lookups.iter().all(|(with_lookup, _)| with_lookup.is_none()) {
+        return process_response(response, timing);
+    }
+
+    let response = match response {
+        Ok(batch_points) => {
+            do_lookup_batch_scored_points(
+                toc.get_ref(),
+                &collection.name,
+                lookups,
+                batch_points,
+                params.consistency,
+            )
+            .await
+        }
+        Err(err) => Err(err),
+    };
+
     process_response(response, timing)
 }
 
@@ -102,6 +168,13 @@ async fn recommend_point_groups(
 ) -> impl Responder {
     let timing = Instant::now();
 
+    if let Err(err) = params
+        .wait_for_consistency_token(toc.get_ref(), &collection.name)
+        .await
+    {
+        return process_response::<()>(Err(err), timing);
+    }
+
     let RecommendGroupsRequest {
         recommend_group_request,
         shard_key,
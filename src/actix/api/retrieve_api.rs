@@ -13,7 +13,7 @@ use validator::Validate;
 use super::read_params::ReadParams;
 use super::CollectionPath;
 use crate::actix::helpers::process_response;
-use crate::common::points::do_get_points;
+use crate::common::points::{do_get_points, do_lookup_scroll_result};
 
 #[derive(Deserialize, Validate)]
 struct PointPath {
@@ -50,6 +50,13 @@ async fn get_point(
 ) -> impl Responder {
     let timing = Instant::now();
 
+    if let Err(err) = params
+        .wait_for_consistency_token(toc.get_ref(), &collection.name)
+        .await
+    {
+        return process_response::<()>(Err(err), timing);
+    }
+
     let point_id: PointIdType = {
         let parse_res = point.id.parse();
         match parse_res {
@@ -92,6 +99,13 @@ async fn get_points(
 ) -> impl Responder {
     let timing = Instant::now();
 
+    if let Err(err) = params
+        .wait_for_consistency_token(toc.get_ref(), &collection.name)
+        .await
+    {
+        return process_response::<()>(Err(err), timing);
+    }
+
     let PointRequest {
         point_request,
         shard_key,
@@ -122,9 +136,17 @@ async fn scroll_points(
 ) -> impl Responder {
     let timing = Instant::now();
 
+    if let Err(err) = params
+        .wait_for_consistency_token(toc.get_ref(), &collection.name)
+        .await
+    {
+        return process_response::<()>(Err(err), timing);
+    }
+
     let ScrollRequest {
         scroll_request,
         shard_key,
+        with_lookup,
     } = request.into_inner();
 
     let shard_selection = match shard_key {
@@ -137,9 +159,28 @@ async fn scroll_points(
             &collection.name,
             scroll_request,
             params.consistency,
-            shard_selection,
+            shard_selection.clone(),
         )
         .await;
 
+    let Some(with_lookup) = with_lookup else {
+        return process_response(response, timing);
+    };
+
+    let response = match response {
+        Ok(scroll_result) => {
+            do_lookup_scroll_result(
+                toc.get_ref(),
+                &collection.name,
+                with_lookup,
+                scroll_result,
+                params.consistency,
+                shard_selection,
+            )
+            .await
+        }
+        Err(err) => Err(err),
+    };
+
     process_response(response, timing)
 }
@@ -3,7 +3,7 @@ use actix_web::{post, web, Responder};
 use actix_web_validator::{Json, Path, Query};
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
 use collection::operations::types::{
-    CoreSearchRequest, SearchGroupsRequest, SearchRequest, SearchRequestBatch,
+    CoreSearchRequest, SearchGroupsRequest, SearchRequest, SearchRequestBatch, TextSearchRequest,
 };
 use storage::content_manager::toc::TableOfContent;
 
@@ -11,7 +11,8 @@ use super::read_params::ReadParams;
 use super::CollectionPath;
 use crate::actix::helpers::process_response;
 use crate::common::points::{
-    do_core_search_points, do_search_batch_points, do_search_point_groups,
+    do_core_search_points, do_lookup_batch_scored_points, do_lookup_scored_points,
+    do_search_batch_points, do_search_point_groups, do_search_text_points,
 };
 
 #[post("/collections/{name}/points/search")]
@@ -23,9 +24,18 @@ async fn search_points(
 ) -> impl Responder {
     let timing = Instant::now();
 
+    if let Err(err) = params
+        .wait_for_consistency_token(toc.get_ref(), &collection.name)
+        .await
+    {
+        return process_response::<()>(Err(err), timing);
+    }
+
     let SearchRequest {
         search_request,
         shard_key,
+        with_lookup,
+        with_geo_distance,
     } = request.into_inner();
 
     let shard_selection = match shard_key {
@@ -33,16 +43,38 @@ async fn search_points(
         Some(shard_keys) => shard_keys.into(),
     };
 
+    let mut core_request: CoreSearchRequest = search_request.into();
+    core_request.with_geo_distance = with_geo_distance;
+
     let response = do_core_search_points(
         toc.get_ref(),
         &collection.name,
-        search_request.into(),
+        core_request,
         params.consistency,
-        shard_selection,
+        shard_selection.clone(),
         params.timeout(),
     )
     .await;
 
+    if with_lookup.is_none() {
+        return process_response(response, timing);
+    }
+
+    let response = match response {
+        Ok(points) => {
+            do_lookup_scored_points(
+                toc.get_ref(),
+                &collection.name,
+                with_lookup,
+                points,
+                params.consistency,
+                shard_selection,
+            )
+            .await
+        }
+        Err(err) => Err(err),
+    };
+
     process_response(response, timing)
 }
 
@@ -55,24 +87,37 @@ async fn batch_search_points(
 ) -> impl Responder {
     let timing = Instant::now();
 
+    if let Err(err) = params
+        .wait_for_consistency_token(toc.get_ref(), &collection.name)
+        .await
+    {
+        return process_response::<()>(Err(err), timing);
+    }
+
     let request = request.into_inner();
-    let requests = request
+    let (requests, lookups): (Vec<_>, Vec<_>) = request
         .searches
         .into_iter()
         .map(|req| {
             let SearchRequest {
                 search_request,
                 shard_key,
+                with_lookup,
+                with_geo_distance,
             } = req;
             let shard_selection = match shard_key {
                 None => ShardSelectorInternal::All,
                 Some(shard_keys) => shard_keys.into(),
             };
-            let core_request: CoreSearchRequest = search_request.into();
+            let mut core_request: CoreSearchRequest = search_request.into();
+            core_request.with_geo_distance = with_geo_distance;
 
-            (core_request, shard_selection)
+            (
+                (core_request, shard_selection.clone()),
+                (with_lookup, shard_selection),
+            )
         })
-        .collect();
+        .unzip();
 
     let response = do_search_batch_points(
         toc.get_ref(),
@@ -83,6 +128,24 @@ async fn batch_search_points(
     )
     .await;
 
+    if lookups.iter().all(|(with_lookup, _)| with_lookup.is_none()) {
+        return process_response(response, timing);
+    }
+
+    let response = match response {
+        Ok(batch_points) => {
+            do_lookup_batch_scored_points(
+                toc.get_ref(),
+                &collection.name,
+                lookups,
+                batch_points,
+                params.consistency,
+            )
+            .await
+        }
+        Err(err) => Err(err),
+    };
+
     process_response(response, timing)
 }
 
@@ -95,6 +158,13 @@ async fn search_point_groups(
 ) -> impl Responder {
     let timing = Instant::now();
 
+    if let Err(err) = params
+        .wait_for_consistency_token(toc.get_ref(), &collection.name)
+        .await
+    {
+        return process_response::<()>(Err(err), timing);
+    }
+
     let SearchGroupsRequest {
         search_group_request,
         shard_key,
@@ -119,8 +189,47 @@ async fn search_point_groups(
 }
 
 // Configure services
+#[post("/collections/{name}/points/search/text")]
+async fn search_text_points(
+    toc: web::Data<TableOfContent>,
+    collection: Path<CollectionPath>,
+    request: Json<TextSearchRequest>,
+    params: Query<ReadParams>,
+) -> impl Responder {
+    let timing = Instant::now();
+
+    if let Err(err) = params
+        .wait_for_consistency_token(toc.get_ref(), &collection.name)
+        .await
+    {
+        return process_response::<()>(Err(err), timing);
+    }
+
+    let TextSearchRequest {
+        text_search_request,
+        shard_key,
+    } = request.into_inner();
+
+    let shard_selection = match shard_key {
+        None => ShardSelectorInternal::All,
+        Some(shard_keys) => shard_keys.into(),
+    };
+
+    let response = do_search_text_points(
+        toc.get_ref(),
+        &collection.name,
+        text_search_request,
+        params.consistency,
+        shard_selection,
+    )
+    .await;
+
+    process_response(response, timing)
+}
+
 pub fn config_search_api(cfg: &mut web::ServiceConfig) {
     cfg.service(search_points)
         .service(batch_search_points)
-        .service(search_point_groups);
+        .service(search_point_groups)
+        .service(search_text_points);
 }
@@ -1,16 +1,33 @@
-use actix_web::{post, put, web, Responder};
+use actix_web::http::header;
+use actix_web::{get, post, put, web, HttpRequest, HttpResponse, Responder};
 use actix_web_validator::{Json, Path, Query};
 use collection::operations::cluster_ops::{
     ClusterOperations, CreateShardingKey, CreateShardingKeyOperation, DropShardingKey,
     DropShardingKeyOperation,
 };
+use collection::shards::shard::ShardId;
+use futures::StreamExt as _;
+use segment::types::SeqNumberType;
+use serde::Deserialize;
+use storage::content_manager::toc::TableOfContent;
 use storage::dispatcher::Dispatcher;
 use tokio::time::Instant;
+use validator::Validate;
 
 use crate::actix::api::collections_api::WaitTimeout;
 use crate::actix::api::CollectionPath;
 use crate::actix::helpers::process_response;
 use crate::common::collections::do_update_collection_cluster;
+use crate::common::shard_changes::stream_shard_changes;
+
+/// Header with the id of the last event received by a reconnecting SSE client
+const LAST_EVENT_ID: &str = "Last-Event-ID";
+
+#[derive(Debug, Deserialize, Validate)]
+struct ShardChangesParams {
+    /// Number of the operation to start streaming from, the oldest retained operation by default
+    from: Option<SeqNumberType>,
+}
 
 // ToDo: introduce API for listing shard keys
 
@@ -70,6 +87,52 @@ async fn delete_shard_key(
     process_response(response, timing)
 }
 
+/// Stream changes applied to the replica of the shard on this peer, as server-sent events
+#[get("/collections/{collection}/shards/{shard}/changes")]
+async fn shard_changes(
+    toc: web::Data<TableOfContent>,
+    path: web::Path<(String, ShardId)>,
+    params: Query<ShardChangesParams>,
+    request: HttpRequest,
+) -> impl Responder {
+    let timing = Instant::now();
+    let (collection, shard) = path.into_inner();
+
+    if let Err(err) = toc.get_collection(&collection).await {
+        return process_response::<()>(Err(err), timing);
+    }
+
+    // Reconnecting clients resume right after the last received operation
+    let last_event_id = request
+        .headers()
+        .get(LAST_EVENT_ID)
+        .and_then(|id| id.to_str().ok()?.parse::<SeqNumberType>().ok());
+    let from = last_event_id.map(|id| id + 1).or(params.from);
+
+    let events = stream_shard_changes(toc.into_inner(), collection, shard, from).map(|change| {
+        let event = match change {
+            Ok(change) => format!(
+                "id: {}\ndata: {}\n\n",
+                change.operation_id,
+                serde_json::to_string(&change)?,
+            ),
+            Err(err) => format!(
+                "event: error\ndata: {}\n\n",
+                serde_json::json!({ "error": err.to_string() }),
+            ),
+        };
+
+        Ok::<_, serde_json::Error>(web::Bytes::from(event))
+    });
+
+    HttpResponse::Ok()
+        .content_type("text/event-stream")
+        .insert_header((header::CACHE_CONTROL, "no-cache"))
+        .streaming(events)
+}
+
 pub fn config_shards_api(cfg: &mut web::ServiceConfig) {
-    cfg.service(create_shard_key).service(delete_shard_key);
+    cfg.service(create_shard_key)
+        .service(delete_shard_key)
+        .service(shard_changes);
 }
@@ -10,13 +10,15 @@ use futures_util::future::LocalBoxFuture;
 
 use crate::common::auth::AuthKeys;
 
-const READ_ONLY_POST_PATTERNS: [&str; 11] = [
+const READ_ONLY_POST_PATTERNS: [&str; 13] = [
     "/collections/{name}/points",
     "/collections/{name}/points/count",
+    "/collections/{name}/points/aggregate",
     "/collections/{name}/points/search",
     "/collections/{name}/points/scroll",
     "/collections/{name}/points/search/groups",
     "/collections/{name}/points/search/batch",
+    "/collections/{name}/points/search/text",
     "/collections/{name}/points/recommend",
     "/collections/{name}/points/recommend/groups",
     "/collections/{name}/points/recommend/batch",
@@ -19,6 +19,7 @@ use actix_web::{error, get, web, App, HttpRequest, HttpResponse, HttpServer, Res
 use collection::operations::validation;
 use storage::dispatcher::Dispatcher;
 
+use crate::actix::api::aggregate_api::aggregate_points;
 use crate::actix::api::cluster_api::config_cluster_api;
 use crate::actix::api::collections_api::config_collections_api;
 use crate::actix::api::count_api::count_points;
@@ -151,7 +152,8 @@ pub fn init(
                 .service(get_point)
                 .service(get_points)
                 .service(scroll_points)
-                .service(count_points);
+                .service(count_points)
+                .service(aggregate_points);
 
             if web_ui_available {
                 app = app.service(
@@ -11,6 +11,7 @@ use collection::operations::snapshot_ops::SnapshotDescription;
 use collection::operations::types::{
     AliasDescription, CollectionClusterInfo, CollectionInfo, CollectionsAliasesResponse,
 };
+use collection::shards::peer_labels::PeerLabelsById;
 use collection::shards::replica_set;
 use collection::shards::shard::{PeerId, ShardId, ShardsPlacement};
 use collection::shards::transfer::{ShardTransfer, ShardTransferKey};
@@ -21,6 +22,7 @@ use storage::content_manager::collection_meta_ops::{
     CollectionMetaOperations, CreateShardKey, DropShardKey, UpdateCollectionOperation,
 };
 use storage::content_manager::errors::StorageError;
+use storage::content_manager::shard_rebalancer::RebalancePlan;
 use storage::content_manager::toc::TableOfContent;
 use storage::dispatcher::Dispatcher;
 
@@ -51,6 +53,10 @@ pub async fn do_list_collections(toc: &TableOfContent) -> CollectionsResponse {
 }
 
 /// Construct shards-replicas layout for the shard from the given scope of peers
+///
+/// Peers are interleaved by zone (or rack), so that replicas of the same shard are located in
+/// different failure domains whenever possible.
+///
 /// Example:
 ///   Shards: 3
 ///   Replicas: 2
@@ -66,10 +72,12 @@ fn generate_even_placement(
     mut pool: Vec<PeerId>,
     shard_number: usize,
     replication_factor: usize,
+    labels_by_id: &PeerLabelsById,
 ) -> ShardsPlacement {
     let mut exact_placement = Vec::new();
     let mut rng = rand::thread_rng();
     pool.shuffle(&mut rng);
+    let pool = interleave_failure_domains(pool, labels_by_id);
     let mut loop_iter = pool.iter().cycle();
 
     // pool: [1,2,3,4]
@@ -89,6 +97,36 @@ fn generate_even_placement(
     exact_placement
 }
 
+/// Reorder peers round-robin by their zone, or rack if there is no zone, keeping the order of
+/// peers within the same failure domain. Peers without labels are a failure domain on their own.
+///
+/// pool: [1a, 2a, 3b, 4c, 5b]
+/// result: [1a, 3b, 4c, 2a, 5b]
+fn interleave_failure_domains(pool: Vec<PeerId>, labels_by_id: &PeerLabelsById) -> Vec<PeerId> {
+    let mut domains: Vec<(Option<&str>, Vec<PeerId>)> = Vec::new();
+    for peer_id in pool {
+        let domain = labels_by_id
+            .get(&peer_id)
+            .and_then(|labels| labels.zone.as_deref().or(labels.rack.as_deref()));
+        match domains
+            .iter_mut()
+            .find(|(other_domain, _)| domain.is_some() && *other_domain == domain)
+        {
+            Some((_, peers)) => peers.push(peer_id),
+            None => domains.push((domain, vec![peer_id])),
+        }
+    }
+
+    let max_domain_size = domains.iter().map(|(_, peers)| peers.len()).max();
+    (0..max_domain_size.unwrap_or(0))
+        .flat_map(|i| {
+            domains
+                .iter()
+                .filter_map(move |(_, peers)| peers.get(i).copied())
+        })
+        .collect()
+}
+
 pub async fn do_list_collection_aliases(
     toc: &TableOfContent,
     collection_name: &str,
@@ -148,6 +186,13 @@ pub async fn do_get_collection_cluster(
     Ok(collection.cluster_info(toc.this_peer_id).await?)
 }
 
+pub async fn do_get_collection_rebalance_plan(
+    toc: &TableOfContent,
+    name: &str,
+) -> Result<RebalancePlan, StorageError> {
+    toc.suggest_shard_rebalance(name).await
+}
+
 pub async fn do_update_collection_cluster(
     dispatcher: &Dispatcher,
     collection_name: String,
@@ -369,8 +414,12 @@ pub async fn do_update_collection_cluster(
                 get_all_peer_ids()
             };
 
-            let exact_placement =
-                generate_even_placement(peers_pool, shard_number, replication_factor);
+            let exact_placement = generate_even_placement(
+                peers_pool,
+                shard_number,
+                replication_factor,
+                &consensus_state.peer_labels_by_id(),
+            );
 
             dispatcher
                 .submit_collection_meta_op(
@@ -427,12 +476,14 @@ pub async fn do_update_collection_cluster(
 mod tests {
     use std::collections::HashSet;
 
+    use collection::shards::peer_labels::PeerLabels;
+
     use super::*;
 
     #[test]
     fn test_generate_even_placement() {
         let pool = vec![1, 2, 3];
-        let placement = generate_even_placement(pool, 3, 2);
+        let placement = generate_even_placement(pool, 3, 2, &PeerLabelsById::new());
 
         assert_eq!(placement.len(), 3);
         for shard_placement in placement {
@@ -441,7 +492,7 @@ mod tests {
         }
 
         let pool = vec![1, 2, 3];
-        let placement = generate_even_placement(pool, 3, 3);
+        let placement = generate_even_placement(pool, 3, 3, &PeerLabelsById::new());
 
         assert_eq!(placement.len(), 3);
         for shard_placement in placement {
@@ -451,7 +502,7 @@ mod tests {
         }
 
         let pool = vec![1, 2, 3, 4, 5, 6];
-        let placement = generate_even_placement(pool, 3, 2);
+        let placement = generate_even_placement(pool, 3, 2, &PeerLabelsById::new());
 
         assert_eq!(placement.len(), 3);
         let flat_placement: Vec<_> = placement.into_iter().flatten().collect();
@@ -459,11 +510,35 @@ mod tests {
         assert_eq!(set.len(), 6);
 
         let pool = vec![1, 2, 3, 4, 5];
-        let placement = generate_even_placement(pool, 3, 10);
+        let placement = generate_even_placement(pool, 3, 10, &PeerLabelsById::new());
 
         assert_eq!(placement.len(), 3);
         for shard_placement in placement {
             assert_eq!(shard_placement.len(), 5);
         }
     }
+
+    #[test]
+    fn test_generate_even_placement_across_zones() {
+        let zone = |zone: &str| PeerLabels {
+            zone: Some(zone.to_string()),
+            rack: None,
+        };
+        let labels_by_id = PeerLabelsById::from([
+            (1, zone("a")),
+            (2, zone("a")),
+            (3, zone("b")),
+            (4, zone("b")),
+        ]);
+
+        for _ in 0..100 {
+            let placement = generate_even_placement(vec![1, 2, 3, 4], 4, 2, &labels_by_id);
+            for shard_placement in placement {
+                assert_ne!(
+                    labels_by_id[&shard_placement[0]],
+                    labels_by_id[&shard_placement[1]],
+                );
+            }
+        }
+    }
 }
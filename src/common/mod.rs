@@ -10,6 +10,7 @@ pub mod http_client;
 pub mod metrics;
 #[allow(dead_code)] // May contain functions used in different binaries. Not actually dead
 pub mod points;
+pub mod shard_changes;
 pub mod snapshots;
 #[allow(dead_code)] // May contain functions used in different binaries. Not actually dead
 pub mod stacktrace;
@@ -1,7 +1,9 @@
 use std::time::Duration;
 
 use collection::common::batching::batch_requests;
-use collection::operations::consistency_params::ReadConsistency;
+use collection::lookup::types::PointWithLookup;
+use collection::lookup::WithLookup;
+use collection::operations::consistency_params::{ConsistencyToken, ReadConsistency};
 use collection::operations::payload_ops::{
     DeletePayload, DeletePayloadOp, PayloadOps, SetPayload, SetPayloadOp,
 };
@@ -12,10 +14,11 @@ use collection::operations::point_ops::{
 use collection::operations::shard_key_selector::ShardKeySelector;
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
 use collection::operations::types::{
-    CoreSearchRequest, CoreSearchRequestBatch, CountRequestInternal, CountResult,
-    DiscoverRequestBatch, DiscoverRequestInternal, GroupsResult, PointRequestInternal,
-    RecommendGroupsRequestInternal, Record, ScrollRequestInternal, ScrollResult,
-    SearchGroupsRequestInternal, UpdateResult,
+    AggregateRequestInternal, AggregateResult, CoreSearchRequest, CoreSearchRequestBatch,
+    CountRequestInternal, CountResult, DiscoverRequestBatch, DiscoverRequestInternal, GroupsResult,
+    PointRequestInternal, RecommendGroupsRequestInternal, Record, ScrollLookupResult,
+    ScrollRequestInternal, ScrollResult, SearchGroupsRequestInternal, TextSearchRequestInternal,
+    UpdateResult,
 };
 use collection::operations::vector_ops::{
     DeleteVectors, UpdateVectors, UpdateVectorsOp, VectorOperations,
@@ -23,7 +26,7 @@ use collection::operations::vector_ops::{
 use collection::operations::{CollectionUpdateOperations, CreateIndex, FieldIndexOperations};
 use collection::shards::shard::ShardId;
 use schemars::JsonSchema;
-use segment::types::{PayloadFieldSchema, PayloadKeyType, ScoredPoint};
+use segment::types::{Payload, PayloadFieldSchema, PayloadKeyType, PointIdType, ScoredPoint};
 use serde::{Deserialize, Serialize};
 use storage::content_manager::collection_meta_ops::{
     CollectionMetaOperations, CreatePayloadIndex, DropPayloadIndex,
@@ -825,6 +828,22 @@ pub async fn do_discover_batch_points(
         .await
 }
 
+/// Wait for the updates of `consistency_token`, if any, before reading from the collection
+pub async fn do_wait_for_consistency_token(
+    toc: &TableOfContent,
+    collection_name: &str,
+    consistency_token: Option<&ConsistencyToken>,
+    timeout: Option<Duration>,
+) -> Result<(), StorageError> {
+    match consistency_token {
+        Some(consistency_token) if !consistency_token.is_empty() => {
+            toc.wait_for_consistency_token(collection_name, consistency_token, timeout)
+                .await
+        }
+        _ => Ok(()),
+    }
+}
+
 pub async fn do_count_points(
     toc: &TableOfContent,
     collection_name: &str,
@@ -836,6 +855,28 @@ pub async fn do_count_points(
         .await
 }
 
+pub async fn do_aggregate_points(
+    toc: &TableOfContent,
+    collection_name: &str,
+    request: AggregateRequestInternal,
+    read_consistency: Option<ReadConsistency>,
+    shard_selection: ShardSelectorInternal,
+) -> Result<AggregateResult, StorageError> {
+    toc.aggregate(collection_name, request, read_consistency, shard_selection)
+        .await
+}
+
+pub async fn do_search_text_points(
+    toc: &TableOfContent,
+    collection_name: &str,
+    request: TextSearchRequestInternal,
+    read_consistency: Option<ReadConsistency>,
+    shard_selection: ShardSelectorInternal,
+) -> Result<Vec<ScoredPoint>, StorageError> {
+    toc.search_text(collection_name, request, read_consistency, shard_selection)
+        .await
+}
+
 pub async fn do_get_points(
     toc: &TableOfContent,
     collection_name: &str,
@@ -857,3 +898,123 @@ pub async fn do_scroll_points(
     toc.scroll(collection_name, request, read_consistency, shard_selection)
         .await
 }
+
+/// Attaches records of another collection, linked through the payload, to each of the points
+async fn do_lookup<T, F>(
+    toc: &TableOfContent,
+    collection_name: &str,
+    request: WithLookup,
+    points: Vec<T>,
+    point_link: F,
+    read_consistency: Option<ReadConsistency>,
+    shard_selection: ShardSelectorInternal,
+) -> Result<Vec<PointWithLookup<T>>, StorageError>
+where
+    F: Fn(&T) -> (PointIdType, Option<&Payload>),
+{
+    let mut lookups = toc
+        .lookup(
+            collection_name,
+            request,
+            points.iter().map(&point_link).collect(),
+            read_consistency,
+            shard_selection,
+        )
+        .await?;
+
+    let result = points
+        .into_iter()
+        .map(|point| {
+            let (point_id, _) = point_link(&point);
+            let lookup = lookups.remove(&point_id);
+            PointWithLookup { point, lookup }
+        })
+        .collect();
+
+    Ok(result)
+}
+
+pub async fn do_lookup_scored_points(
+    toc: &TableOfContent,
+    collection_name: &str,
+    request: Option<WithLookup>,
+    points: Vec<ScoredPoint>,
+    read_consistency: Option<ReadConsistency>,
+    shard_selection: ShardSelectorInternal,
+) -> Result<Vec<PointWithLookup<ScoredPoint>>, StorageError> {
+    let Some(request) = request else {
+        return Ok(points
+            .into_iter()
+            .map(|point| PointWithLookup {
+                point,
+                lookup: None,
+            })
+            .collect());
+    };
+
+    do_lookup(
+        toc,
+        collection_name,
+        request,
+        points,
+        |point| (point.id, point.payload.as_ref()),
+        read_consistency,
+        shard_selection,
+    )
+    .await
+}
+
+pub async fn do_lookup_batch_scored_points(
+    toc: &TableOfContent,
+    collection_name: &str,
+    requests: Vec<(Option<WithLookup>, ShardSelectorInternal)>,
+    batch_points: Vec<Vec<ScoredPoint>>,
+    read_consistency: Option<ReadConsistency>,
+) -> Result<Vec<Vec<PointWithLookup<ScoredPoint>>>, StorageError> {
+    let lookups =
+        requests
+            .into_iter()
+            .zip(batch_points)
+            .map(|((request, shard_selection), points)| {
+                do_lookup_scored_points(
+                    toc,
+                    collection_name,
+                    request,
+                    points,
+                    read_consistency,
+                    shard_selection,
+                )
+            });
+
+    futures::future::try_join_all(lookups).await
+}
+
+pub async fn do_lookup_scroll_result(
+    toc: &TableOfContent,
+    collection_name: &str,
+    request: WithLookup,
+    scroll_result: ScrollResult,
+    read_consistency: Option<ReadConsistency>,
+    shard_selection: ShardSelectorInternal,
+) -> Result<ScrollLookupResult, StorageError> {
+    let ScrollResult {
+        points,
+        next_page_offset,
+    } = scroll_result;
+
+    let points = do_lookup(
+        toc,
+        collection_name,
+        request,
+        points,
+        |point| (point.id, point.payload.as_ref()),
+        read_consistency,
+        shard_selection,
+    )
+    .await?;
+
+    Ok(ScrollLookupResult {
+        points,
+        next_page_offset,
+    })
+}
@@ -0,0 +1,61 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use collection::operations::types::ShardChange;
+use collection::shards::shard::ShardId;
+use futures::{stream, Stream, TryStreamExt as _};
+use segment::types::SeqNumberType;
+use storage::content_manager::errors::StorageError;
+use storage::content_manager::toc::TableOfContent;
+
+/// Max number of operations to read from WAL at once
+const CHANGES_BATCH_SIZE: usize = 64;
+
+/// How long to wait for a new operation before checking the collection again
+const CHANGES_POLL_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Stream changes of the local replica of a shard, starting from operation `from`, or from the
+/// oldest retained one
+///
+/// Tails the WAL of the replica: once all retained operations are streamed, waits for new ones.
+/// The stream ends after the first error, e.g. if `from` is not retained in WAL anymore or the
+/// collection is deleted.
+pub fn stream_shard_changes(
+    toc: Arc<TableOfContent>,
+    collection_name: String,
+    shard_id: ShardId,
+    from: Option<SeqNumberType>,
+) -> impl Stream<Item = Result<ShardChange, StorageError>> {
+    stream::try_unfold(from, move |mut from| {
+        let toc = toc.clone();
+        let collection_name = collection_name.clone();
+
+        async move {
+            let (next, changes) = loop {
+                let collection = toc.get_collection(&collection_name).await?;
+
+                let (next, changes) = collection
+                    .local_shard_changes(shard_id, from, CHANGES_BATCH_SIZE)
+                    .await?;
+
+                if !changes.is_empty() {
+                    break (next, changes);
+                }
+
+                from = Some(next);
+
+                let applied = collection
+                    .watch_local_shard_operation(shard_id, next, CHANGES_POLL_TIMEOUT)
+                    .await?;
+
+                // Don't block collection operations while waiting
+                drop(collection);
+                applied.await;
+            };
+
+            let changes = stream::iter(changes.into_iter().map(Ok));
+            Ok::<_, StorageError>(Some((changes, Some(next))))
+        }
+    })
+    .try_flatten()
+}
@@ -11,6 +11,7 @@ use api::grpc::qdrant::raft_client::RaftClient;
 use api::grpc::qdrant::{AllPeers, PeerId as GrpcPeerId, RaftMessage as GrpcRaftMessage};
 use api::grpc::transport_channel_pool::TransportChannelPool;
 use collection::shards::channel_service::ChannelService;
+use collection::shards::peer_labels::PeerLabels;
 use collection::shards::shard::PeerId;
 use common::defaults;
 use prost::Message as _;
@@ -18,7 +19,9 @@ use raft::eraftpb::Message as RaftMessage;
 use raft::prelude::*;
 use raft::{SoftState, StateRole, INVALID_ID};
 use storage::content_manager::consensus_manager::ConsensusStateRef;
-use storage::content_manager::consensus_ops::{ConsensusOperations, SnapshotStatus};
+use storage::content_manager::consensus_ops::{
+    AddPeerContext, ConsensusOperations, SnapshotStatus,
+};
 use storage::content_manager::toc::TableOfContent;
 use tokio::runtime::Handle;
 use tokio::sync::mpsc::{Receiver, Sender};
@@ -76,12 +79,14 @@ impl Consensus {
         let p2p_host = settings.service.host.clone();
         let p2p_port = settings.cluster.p2p.port.expect("P2P port is not set");
         let config = settings.cluster.consensus.clone();
+        let labels = settings.cluster.labels.clone();
 
         let (mut consensus, message_sender) = Self::new(
             logger,
             state_ref.clone(),
             bootstrap_peer,
             uri,
+            labels,
             p2p_port,
             config,
             tls_client_config,
@@ -155,6 +160,7 @@ impl Consensus {
         state_ref: ConsensusStateRef,
         bootstrap_peer: Option<Uri>,
         uri: Option<String>,
+        labels: PeerLabels,
         p2p_port: u16,
         config: ConsensusConfig,
         tls_config: Option<ClientTlsConfig>,
@@ -189,6 +195,7 @@ impl Consensus {
                 &state_ref,
                 bootstrap_peer.clone(),
                 uri,
+                labels,
                 p2p_port,
                 &config,
                 tls_config.clone(),
@@ -201,6 +208,7 @@ impl Consensus {
                 .block_on(Self::recover(
                     &state_ref,
                     uri.clone(),
+                    labels,
                     p2p_port,
                     &config,
                     tls_config.clone(),
@@ -247,6 +255,7 @@ impl Consensus {
         state_ref: &ConsensusStateRef,
         bootstrap_peer: Option<Uri>,
         uri: Option<String>,
+        labels: PeerLabels,
         p2p_port: u16,
         config: &ConsensusConfig,
         tls_config: Option<ClientTlsConfig>,
@@ -259,6 +268,7 @@ impl Consensus {
                 state_ref,
                 bootstrap_peer,
                 uri,
+                labels,
                 p2p_port,
                 config,
                 tls_config,
@@ -275,6 +285,7 @@ impl Consensus {
                 state_ref.this_peer_id(),
                 uri.ok_or_else(|| anyhow::anyhow!("First peer should specify its uri."))?
                     .parse()?,
+                labels,
             )?;
             Ok(())
         }
@@ -284,6 +295,7 @@ impl Consensus {
         this_peer_id: PeerId,
         cluster_uri: Uri,
         current_uri: Option<String>,
+        labels: &PeerLabels,
         p2p_port: u16,
         config: &ConsensusConfig,
         tls_config: Option<ClientTlsConfig>,
@@ -304,6 +316,7 @@ impl Consensus {
                     uri: current_uri,
                     port: Some(p2p_port as u32),
                     id: this_peer_id,
+                    labels: Some(labels.clone().into()),
                 },
             ))
             .await
@@ -313,10 +326,11 @@ impl Consensus {
     }
 
     // Re-attach peer to the consensus:
-    // Notifies the cluster(any node) that this node changed its address
+    // Notifies the cluster(any node) that this node changed its address or labels
     async fn recover(
         state_ref: &ConsensusStateRef,
         uri: Option<String>,
+        labels: PeerLabels,
         p2p_port: u16,
         config: &ConsensusConfig,
         tls_config: Option<ClientTlsConfig>,
@@ -329,21 +343,38 @@ impl Consensus {
             .read()
             .clone();
         let this_peer_url = peer_to_uri.remove(&this_peer_id);
+        let this_peer_labels = state_ref
+            .peer_labels_by_id()
+            .remove(&this_peer_id)
+            .unwrap_or_default();
         // Recover url if a different one is provided
-        let do_recover = match (&this_peer_url, &uri) {
+        let url_changed = match (&this_peer_url, &uri) {
             (Some(this_peer_url), Some(uri)) => this_peer_url != &Uri::from_str(uri)?,
             _ => false,
         };
+        let labels_changed = this_peer_labels != labels;
+
+        // Single peer has no one to inform, it can update its own labels
+        if labels_changed && peer_to_uri.is_empty() {
+            if let Some(this_peer_url) = &this_peer_url {
+                state_ref.add_peer(this_peer_id, this_peer_url.clone(), labels.clone())?;
+            }
+        }
+
+        let do_recover = url_changed || (labels_changed && !peer_to_uri.is_empty());
+        // Keep the known address if only labels have changed
+        let uri = uri.or_else(|| this_peer_url.as_ref().map(Uri::to_string));
 
         if do_recover {
             let mut tries = RECOVERY_MAX_RETRY_COUNT;
             while tries > 0 {
-                // Try to inform any peer about the change of address
+                // Try to inform any peer about the change of address or labels
                 for (peer_id, peer_uri) in &peer_to_uri {
                     let res = Self::add_peer_to_known_for(
                         this_peer_id,
                         peer_uri.clone(),
                         uri.clone(),
+                        &labels,
                         p2p_port,
                         config,
                         tls_config.clone(),
@@ -390,6 +421,7 @@ impl Consensus {
         state_ref: &ConsensusStateRef,
         bootstrap_peer: Uri,
         uri: Option<String>,
+        labels: PeerLabels,
         p2p_port: u16,
         config: &ConsensusConfig,
         tls_config: Option<ClientTlsConfig>,
@@ -399,6 +431,7 @@ impl Consensus {
             this_peer_id,
             bootstrap_peer,
             uri.clone(),
+            &labels,
             p2p_port,
             config,
             tls_config,
@@ -414,6 +447,7 @@ impl Consensus {
                     peer.uri
                         .parse()
                         .context(format!("Failed to parse peer URI: {}", peer.uri))?,
+                    peer.labels.map(PeerLabels::from).unwrap_or_default(),
                 )
                 .map_err(|err| anyhow!("Failed to add peer: {}", err))?
         }
@@ -514,14 +548,25 @@ impl Consensus {
                         log::debug!("Proposing network configuration change: {:?}", change);
                         self.node.propose_conf_change(vec![], change)
                     }
-                    ConsensusOperations::AddPeer { peer_id, uri } => {
+                    ConsensusOperations::AddPeer {
+                        peer_id,
+                        uri,
+                        labels,
+                    } => {
+                        let context = match (AddPeerContext { uri, labels }).to_bytes() {
+                            Ok(context) => context,
+                            Err(err) => {
+                                log::error!("Failed to serialize peer context: {}", err);
+                                return Ok(true);
+                            }
+                        };
                         let mut change = ConfChangeV2::default();
                         change.set_changes(vec![raft_proto::new_conf_change_single(
                             peer_id,
                             ConfChangeType::AddLearnerNode,
                         )]);
                         log::debug!("Proposing network configuration change: {:?}", change);
-                        self.node.propose_conf_change(uri.into_bytes(), change)
+                        self.node.propose_conf_change(context, change)
                     }
                     ConsensusOperations::RequestSnapshot => self.node.request_snapshot(),
                     ConsensusOperations::ReportSnapshot { peer_id, status } => {
@@ -1120,6 +1165,7 @@ mod tests {
 
     use collection::operations::types::VectorParams;
     use collection::shards::channel_service::ChannelService;
+    use collection::shards::peer_labels::PeerLabels;
     use segment::types::Distance;
     use slog::Drain;
     use storage::content_manager::collection_meta_ops::{
@@ -1181,6 +1227,7 @@ mod tests {
             consensus_state.clone(),
             None,
             Some("http://127.0.0.1:6335".parse().unwrap()),
+            PeerLabels::default(),
             6335,
             ConsensusConfig::default(),
             None,
@@ -1237,6 +1284,9 @@ mod tests {
                             init_from: None,
                             quantization_config: None,
                             sharding_method: None,
+                            expiry: None,
+                            payload_schema: None,
+                            strict_mode: None,
                         },
                     )),
                     None,
@@ -54,6 +54,9 @@ pub async fn handle_existing_collections(
                 optimizers_config: Some(collection_state.config.optimizer_config.into()),
                 init_from: None,
                 quantization_config: collection_state.config.quantization_config,
+                expiry: collection_state.config.params.expiry,
+                payload_schema: collection_state.config.params.payload_schema,
+                strict_mode: collection_state.config.params.strict_mode,
             },
         );
 
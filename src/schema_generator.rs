@@ -7,11 +7,11 @@ use collection::operations::snapshot_ops::{
     ShardSnapshotRecover, SnapshotDescription, SnapshotRecover,
 };
 use collection::operations::types::{
-    AliasDescription, CollectionClusterInfo, CollectionInfo, CollectionsAliasesResponse,
-    CountRequest, CountResult, DiscoverRequest, DiscoverRequestBatch, GroupsResult, PointGroup,
-    PointRequest, RecommendGroupsRequest, RecommendRequest, RecommendRequestBatch, Record,
-    ScrollRequest, ScrollResult, SearchGroupsRequest, SearchRequest, SearchRequestBatch,
-    UpdateResult,
+    AggregateRequest, AggregateResult, AliasDescription, CollectionClusterInfo, CollectionInfo,
+    CollectionsAliasesResponse, CountRequest, CountResult, DiscoverRequest, DiscoverRequestBatch,
+    GroupsResult, PointGroup, PointRequest, RecommendGroupsRequest, RecommendRequest,
+    RecommendRequestBatch, Record, ScrollRequest, ScrollResult, SearchGroupsRequest, SearchRequest,
+    SearchRequestBatch, TextSearchRequest, UpdateResult,
 };
 use collection::operations::vector_ops::{DeleteVectors, UpdateVectors};
 use schemars::gen::SchemaSettings;
@@ -19,8 +19,10 @@ use schemars::JsonSchema;
 use segment::types::ScoredPoint;
 use serde::{Deserialize, Serialize};
 use storage::content_manager::collection_meta_ops::{
-    ChangeAliasesOperation, CreateCollection, UpdateCollection,
+    ChangeAliasesOperation, CloneCollection, CreateCollection, RenameCollection, UpdateCollection,
 };
+use storage::content_manager::copy_jobs::{CopyJob, CopyPointsRequest};
+use storage::content_manager::shard_rebalancer::RebalancePlan;
 use storage::types::ClusterStatus;
 
 use crate::common::helpers::LocksOption;
@@ -78,6 +80,14 @@ struct AllDefinitions {
     b9: ShardSnapshotRecover,
     ba: DiscoverRequest,
     bb: DiscoverRequestBatch,
+    bc: RebalancePlan,
+    bd: RenameCollection,
+    be: CloneCollection,
+    bf: CopyPointsRequest,
+    bg: CopyJob,
+    bh: AggregateRequest,
+    bi: AggregateResult,
+    bj: TextSearchRequest,
 }
 
 fn save_schema<T: JsonSchema>() {
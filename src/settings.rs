@@ -4,6 +4,7 @@ use api::grpc::transport_channel_pool::{
     DEFAULT_CONNECT_TIMEOUT, DEFAULT_GRPC_TIMEOUT, DEFAULT_POOL_SIZE,
 };
 use collection::operations::validation;
+use collection::shards::peer_labels::PeerLabels;
 use config::{Config, ConfigError, Environment, File, FileFormat, Source};
 use segment::common::cpu::get_num_cpus;
 use serde::Deserialize;
@@ -55,6 +56,9 @@ pub struct ClusterConfig {
     #[serde(default)]
     #[validate]
     pub consensus: ConsensusConfig,
+    /// Location of this peer, used to spread shard replicas across failure domains
+    #[serde(default)]
+    pub labels: PeerLabels,
 }
 
 #[derive(Debug, Deserialize, Clone, Validate)]
@@ -3,12 +3,13 @@ use std::time::{Duration, Instant};
 
 use api::grpc::qdrant::collections_server::Collections;
 use api::grpc::qdrant::{
-    AliasDescription, ChangeAliases, CollectionClusterInfoRequest, CollectionClusterInfoResponse,
-    CollectionOperationResponse, CreateCollection, CreateShardKeyRequest, CreateShardKeyResponse,
-    DeleteCollection, DeleteShardKeyRequest, DeleteShardKeyResponse, GetCollectionInfoRequest,
-    GetCollectionInfoResponse, ListAliasesRequest, ListAliasesResponse,
-    ListCollectionAliasesRequest, ListCollectionsRequest, ListCollectionsResponse,
-    UpdateCollection, UpdateCollectionClusterSetupRequest, UpdateCollectionClusterSetupResponse,
+    AliasDescription, ChangeAliases, CloneCollection, CollectionClusterInfoRequest,
+    CollectionClusterInfoResponse, CollectionOperationResponse, CreateCollection,
+    CreateShardKeyRequest, CreateShardKeyResponse, DeleteCollection, DeleteShardKeyRequest,
+    DeleteShardKeyResponse, GetCollectionInfoRequest, GetCollectionInfoResponse,
+    ListAliasesRequest, ListAliasesResponse, ListCollectionAliasesRequest, ListCollectionsRequest,
+    ListCollectionsResponse, RenameCollection, UpdateCollection,
+    UpdateCollectionClusterSetupRequest, UpdateCollectionClusterSetupResponse,
 };
 use collection::operations::cluster_ops::{
     ClusterOperations, CreateShardingKeyOperation, DropShardingKeyOperation,
@@ -148,6 +149,22 @@ impl Collections for CollectionsService {
         self.perform_operation(request).await
     }
 
+    async fn rename_collection(
+        &self,
+        request: Request<RenameCollection>,
+    ) -> Result<Response<CollectionOperationResponse>, Status> {
+        validate(request.get_ref())?;
+        self.perform_operation(request).await
+    }
+
+    async fn clone_collection(
+        &self,
+        request: Request<CloneCollection>,
+    ) -> Result<Response<CollectionOperationResponse>, Status> {
+        validate(request.get_ref())?;
+        self.perform_operation(request).await
+    }
+
     async fn update_aliases(
         &self,
         request: Request<ChangeAliases>,
@@ -296,5 +313,7 @@ macro_rules! impl_with_timeout {
 impl_with_timeout!(CreateCollection);
 impl_with_timeout!(UpdateCollection);
 impl_with_timeout!(DeleteCollection);
+impl_with_timeout!(RenameCollection);
+impl_with_timeout!(CloneCollection);
 impl_with_timeout!(ChangeAliases);
 impl_with_timeout!(UpdateCollectionClusterSetupRequest);
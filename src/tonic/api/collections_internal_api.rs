@@ -4,7 +4,7 @@ use std::time::{Duration, Instant};
 use api::grpc::qdrant::collections_internal_server::CollectionsInternal;
 use api::grpc::qdrant::{
     CollectionOperationResponse, GetCollectionInfoRequestInternal, GetCollectionInfoResponse,
-    InitiateShardTransferRequest, WaitForShardStateRequest,
+    InitiateShardTransferRequest, WaitForOperationRequest, WaitForShardStateRequest,
 };
 use storage::content_manager::conversions::error_to_status;
 use storage::content_manager::toc::TableOfContent;
@@ -115,4 +115,42 @@ impl CollectionsInternal for CollectionsInternalService {
         };
         Ok(Response::new(response))
     }
+
+    async fn wait_for_operation(
+        &self,
+        request: Request<WaitForOperationRequest>,
+    ) -> Result<Response<CollectionOperationResponse>, Status> {
+        let request = request.into_inner();
+        validate_and_log(&request);
+
+        let timing = Instant::now();
+        let WaitForOperationRequest {
+            collection_name,
+            shard_id,
+            operation_id,
+            timeout,
+        } = request;
+        let timeout = Duration::from_secs(timeout);
+
+        let collection_read = self
+            .toc
+            .get_collection(&collection_name)
+            .await
+            .map_err(|err| {
+                Status::not_found(format!(
+                    "Collection {collection_name} could not be found: {err}"
+                ))
+            })?;
+
+        collection_read
+            .wait_local_shard_operation(shard_id, operation_id, timeout)
+            .await
+            .map_err(error_to_status)?;
+
+        let response = CollectionOperationResponse {
+            result: true,
+            time: timing.elapsed().as_secs_f64(),
+        };
+        Ok(Response::new(response))
+    }
 }
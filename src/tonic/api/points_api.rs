@@ -3,28 +3,30 @@ use std::time::Duration;
 
 use api::grpc::qdrant::points_server::Points;
 use api::grpc::qdrant::{
-    ClearPayloadPoints, CountPoints, CountResponse, CreateFieldIndexCollection,
-    DeleteFieldIndexCollection, DeletePayloadPoints, DeletePointVectors, DeletePoints,
-    DiscoverBatchPoints, DiscoverBatchResponse, DiscoverPoints, DiscoverResponse, GetPoints,
-    GetResponse, PointsOperationResponse, RecommendBatchPoints, RecommendBatchResponse,
-    RecommendGroupsResponse, RecommendPointGroups, RecommendPoints, RecommendResponse,
-    ScrollPoints, ScrollResponse, SearchBatchPoints, SearchBatchResponse, SearchGroupsResponse,
-    SearchPointGroups, SearchPoints, SearchResponse, SetPayloadPoints, UpdateBatchPoints,
-    UpdateBatchResponse, UpdatePointVectors, UpsertPoints,
+    AggregatePoints, AggregateResponse, ClearPayloadPoints, CountPoints, CountResponse,
+    CreateFieldIndexCollection, DeleteFieldIndexCollection, DeletePayloadPoints,
+    DeletePointVectors, DeletePoints, DiscoverBatchPoints, DiscoverBatchResponse, DiscoverPoints,
+    DiscoverResponse, GetPoints, GetResponse, PointsOperationResponse, RecommendBatchPoints,
+    RecommendBatchResponse, RecommendGroupsResponse, RecommendPointGroups, RecommendPoints,
+    RecommendResponse, ScrollPoints, ScrollResponse, SearchBatchPoints, SearchBatchResponse,
+    SearchGroupsResponse, SearchPointGroups, SearchPoints, SearchResponse, SearchTextPoints,
+    SetPayloadPoints, StreamShardChanges, UpdateBatchPoints, UpdateBatchResponse,
+    UpdatePointVectors, UpsertPoints,
 };
+use collection::lookup::WithLookup;
 use collection::operations::types::CoreSearchRequest;
 use storage::dispatcher::Dispatcher;
 use tonic::{Request, Response, Status};
 
 use super::points_common::{
-    delete_vectors, discover, discover_batch, recommend_groups, search_groups, update_batch,
-    update_vectors,
+    delete_vectors, discover, discover_batch, recommend_groups, search_groups, stream_changes,
+    update_batch, update_vectors, ShardChangesStream,
 };
 use super::validate;
 use crate::tonic::api::points_common::{
-    clear_payload, convert_shard_selector_for_read, core_search_batch, count, create_field_index,
-    delete, delete_field_index, delete_payload, get, overwrite_payload, recommend, recommend_batch,
-    scroll, search, set_payload, upsert,
+    aggregate, clear_payload, convert_shard_selector_for_read, core_search_batch, count,
+    create_field_index, delete, delete_field_index, delete_payload, get, overwrite_payload,
+    recommend, recommend_batch, scroll, search, search_text, set_payload, upsert,
 };
 
 pub struct PointsService {
@@ -116,6 +118,16 @@ impl Points for PointsService {
         update_batch(self.dispatcher.as_ref(), request.into_inner(), None).await
     }
 
+    type StreamChangesStream = ShardChangesStream;
+
+    async fn stream_changes(
+        &self,
+        request: Request<StreamShardChanges>,
+    ) -> Result<Response<Self::StreamChangesStream>, Status> {
+        validate(request.get_ref())?;
+        stream_changes(self.dispatcher.toc().clone(), request.into_inner()).await
+    }
+
     async fn create_field_index(
         &self,
         request: Request<CreateFieldIndexCollection>,
@@ -155,20 +167,28 @@ impl Points for PointsService {
         let timeout = timeout.map(Duration::from_secs);
 
         let mut requests = Vec::new();
+        let mut lookups = Vec::new();
 
         for mut search_point in search_points {
             let shard_key = search_point.shard_key_selector.take();
+            let with_lookup = search_point
+                .with_lookup
+                .take()
+                .map(WithLookup::try_from)
+                .transpose()?;
 
             let shard_selector = convert_shard_selector_for_read(None, shard_key);
             let core_search_request = CoreSearchRequest::try_from(search_point)?;
 
             requests.push((core_search_request, shard_selector));
+            lookups.push(with_lookup);
         }
 
         core_search_batch(
             self.dispatcher.as_ref(),
             collection_name,
             requests,
+            lookups,
             read_consistency,
             timeout,
         )
@@ -264,4 +284,20 @@ impl Points for PointsService {
         validate(request.get_ref())?;
         count(self.dispatcher.as_ref(), request.into_inner(), None).await
     }
+
+    async fn aggregate(
+        &self,
+        request: Request<AggregatePoints>,
+    ) -> Result<Response<AggregateResponse>, Status> {
+        validate(request.get_ref())?;
+        aggregate(self.dispatcher.as_ref(), request.into_inner(), None).await
+    }
+
+    async fn search_text(
+        &self,
+        request: Request<SearchTextPoints>,
+    ) -> Result<Response<SearchResponse>, Status> {
+        validate(request.get_ref())?;
+        search_text(self.dispatcher.as_ref(), request.into_inner(), None, None).await
+    }
 }
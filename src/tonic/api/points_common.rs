@@ -1,20 +1,24 @@
+use std::pin::Pin;
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use api::grpc::conversions::proto_to_payloads;
 use api::grpc::qdrant::payload_index_params::IndexParams;
 use api::grpc::qdrant::points_update_operation::{ClearPayload, Operation, PointStructList};
 use api::grpc::qdrant::{
-    points_update_operation, BatchResult, ClearPayloadPoints, CoreSearchPoints, CountPoints,
-    CountResponse, CreateFieldIndexCollection, DeleteFieldIndexCollection, DeletePayloadPoints,
+    points_update_operation, AggregatePoints, AggregateResponse, BatchResult, ClearPayloadPoints,
+    CoreSearchPoints, CountDistinctResponse, CountPoints, CountResponse,
+    CreateFieldIndexCollection, DeleteFieldIndexCollection, DeletePayloadPoints,
     DeletePointVectors, DeletePoints, DiscoverBatchResponse, DiscoverPoints, DiscoverResponse,
     FieldType, GetPoints, GetResponse, PayloadIndexParams, PointsOperationResponse, PointsSelector,
     ReadConsistency as ReadConsistencyGrpc, RecommendBatchResponse, RecommendGroupsResponse,
     RecommendPointGroups, RecommendPoints, RecommendResponse, ScrollPoints, ScrollResponse,
     SearchBatchResponse, SearchGroupsResponse, SearchPointGroups, SearchPoints, SearchResponse,
-    SetPayloadPoints, SyncPoints, UpdateBatchPoints, UpdateBatchResponse, UpdatePointVectors,
-    UpsertPoints,
+    SearchTextPoints, SetPayloadPoints, ShardChange, StreamShardChanges, SyncPoints,
+    TextStatsResponse, UpdateBatchPoints, UpdateBatchResponse, UpdatePointVectors, UpsertPoints,
 };
-use collection::operations::consistency_params::ReadConsistency;
+use collection::lookup::WithLookup;
+use collection::operations::consistency_params::{ConsistencyToken, ReadConsistency};
 use collection::operations::conversions::{
     try_discover_request_from_grpc, try_points_selector_from_grpc, write_ordering_from_proto,
 };
@@ -25,12 +29,16 @@ use collection::operations::point_ops::{
 use collection::operations::shard_key_selector::ShardKeySelector;
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
 use collection::operations::types::{
-    default_exact_count, CoreSearchRequest, CoreSearchRequestBatch, PointRequestInternal,
-    QueryEnum, RecommendExample, ScrollRequestInternal,
+    default_exact_count, AggregateRequestInternal, CoreSearchRequest, CoreSearchRequestBatch,
+    PointRequestInternal, QueryEnum, RecommendExample, ScrollRequestInternal,
+    TextSearchRequestInternal,
 };
 use collection::operations::vector_ops::{DeleteVectors, PointVectors, UpdateVectors};
 use collection::operations::CollectionUpdateOperations;
+use collection::shards::conversions::shard_change_to_grpc;
 use collection::shards::shard::ShardId;
+use futures::{Stream, StreamExt as _};
+use segment::data_types::text_index::TextStats;
 use segment::types::{
     ExtendedPointId, Filter, PayloadFieldSchema, PayloadSchemaParams, PayloadSchemaType,
 };
@@ -40,11 +48,16 @@ use storage::dispatcher::Dispatcher;
 use tonic::{Response, Status};
 
 use crate::common::points::{
-    do_clear_payload, do_core_search_points, do_count_points, do_create_index,
+    do_aggregate_points, do_clear_payload, do_core_search_points, do_count_points, do_create_index,
     do_create_index_internal, do_delete_index, do_delete_index_internal, do_delete_payload,
-    do_delete_points, do_delete_vectors, do_get_points, do_overwrite_payload, do_scroll_points,
-    do_search_batch_points, do_set_payload, do_update_vectors, do_upsert_points, CreateFieldIndex,
+    do_delete_points, do_delete_vectors, do_get_points, do_lookup_batch_scored_points,
+    do_lookup_scored_points, do_lookup_scroll_result, do_overwrite_payload, do_scroll_points,
+    do_search_batch_points, do_search_text_points, do_set_payload, do_update_vectors,
+    do_upsert_points, do_wait_for_consistency_token, CreateFieldIndex,
 };
+use crate::common::shard_changes::stream_shard_changes;
+
+pub type ShardChangesStream = Pin<Box<dyn Stream<Item = Result<ShardChange, Status>> + Send>>;
 
 fn extract_points_selector(
     points_selector: Option<PointsSelector>,
@@ -688,6 +701,21 @@ fn convert_field_type(
                 ))
             }
         },
+        (
+            Some(v),
+            Some(PayloadIndexParams {
+                index_params: Some(IndexParams::KeywordIndexParams(keyword_index_params)),
+            }),
+        ) => match v {
+            FieldType::Keyword => Some(PayloadFieldSchema::FieldParams(
+                PayloadSchemaParams::Keyword(keyword_index_params.into()),
+            )),
+            _ => {
+                return Err(Status::invalid_argument(
+                    "field_type and field_index_params do not match",
+                ))
+            }
+        },
         (Some(v), None | Some(PayloadIndexParams { index_params: None })) => match v {
             FieldType::Keyword => Some(PayloadSchemaType::Keyword.into()),
             FieldType::Integer => Some(PayloadSchemaType::Integer.into()),
@@ -829,6 +857,19 @@ pub async fn delete_field_index_internal(
     Ok(Response::new(response))
 }
 
+/// Wait for the updates of the consistency token of the read request, if there is one
+async fn wait_for_consistency_token(
+    toc: &TableOfContent,
+    collection_name: &str,
+    read_consistency: Option<&ReadConsistencyGrpc>,
+) -> Result<(), Status> {
+    let consistency_token = ConsistencyToken::try_from_optional(read_consistency)?;
+
+    do_wait_for_consistency_token(toc, collection_name, consistency_token.as_ref(), None)
+        .await
+        .map_err(error_to_status)
+}
+
 pub async fn search(
     toc: &TableOfContent,
     search_points: SearchPoints,
@@ -849,8 +890,14 @@ pub async fn search(
         timeout,
         shard_key_selector,
         sparse_indices,
+        with_geo_distance,
+        with_lookup,
+        packed_vector,
     } = search_points;
 
+    let with_lookup: Option<WithLookup> = with_lookup.map(TryInto::try_into).transpose()?;
+
+    let vector = api::grpc::conversions::dense_or_packed(vector, packed_vector);
     let vector_struct =
         api::grpc::conversions::into_named_vector_struct(vector_name, vector, sparse_indices)?;
 
@@ -869,8 +916,12 @@ pub async fn search(
                 .unwrap_or_default(),
         ),
         score_threshold,
+        with_geo_distance: with_geo_distance
+            .map(|geo_distance| geo_distance.try_into())
+            .transpose()?,
     };
 
+    wait_for_consistency_token(toc, &collection_name, read_consistency.as_ref()).await?;
     let read_consistency = ReadConsistency::try_from_optional(read_consistency)?;
 
     let timing = Instant::now();
@@ -879,12 +930,23 @@ pub async fn search(
         &collection_name,
         search_request,
         read_consistency,
-        shard_selector,
+        shard_selector.clone(),
         timeout.map(Duration::from_secs),
     )
     .await
     .map_err(error_to_status)?;
 
+    let scored_points = do_lookup_scored_points(
+        toc,
+        &collection_name,
+        with_lookup,
+        scored_points,
+        read_consistency,
+        shard_selector,
+    )
+    .await
+    .map_err(error_to_status)?;
+
     let response = SearchResponse {
         result: scored_points
             .into_iter()
@@ -900,18 +962,39 @@ pub async fn core_search_batch(
     toc: &TableOfContent,
     collection_name: String,
     requests: Vec<(CoreSearchRequest, ShardSelectorInternal)>,
+    lookups: Vec<Option<WithLookup>>,
     read_consistency: Option<ReadConsistencyGrpc>,
     timeout: Option<Duration>,
 ) -> Result<Response<SearchBatchResponse>, Status> {
+    wait_for_consistency_token(toc, &collection_name, read_consistency.as_ref()).await?;
     let read_consistency = ReadConsistency::try_from_optional(read_consistency)?;
 
     let timing = Instant::now();
 
+    let lookups = lookups
+        .into_iter()
+        .zip(
+            requests
+                .iter()
+                .map(|(_, shard_selector)| shard_selector.clone()),
+        )
+        .collect();
+
     let scored_points =
         do_search_batch_points(toc, &collection_name, requests, read_consistency, timeout)
             .await
             .map_err(error_to_status)?;
 
+    let scored_points = do_lookup_batch_scored_points(
+        toc,
+        &collection_name,
+        lookups,
+        scored_points,
+        read_consistency,
+    )
+    .await
+    .map_err(error_to_status)?;
+
     let response = SearchBatchResponse {
         result: scored_points
             .into_iter()
@@ -952,6 +1035,7 @@ pub async fn core_search_list(
         Some(shard_id) => ShardSelectorInternal::ShardId(shard_id),
     };
 
+    wait_for_consistency_token(toc, &collection_name, read_consistency.as_ref()).await?;
     let read_consistency = ReadConsistency::try_from_optional(read_consistency)?;
 
     let scored_points = toc
@@ -993,6 +1077,7 @@ pub async fn search_groups(
         ..
     } = search_point_groups;
 
+    wait_for_consistency_token(toc, &collection_name, read_consistency.as_ref()).await?;
     let read_consistency = ReadConsistency::try_from_optional(read_consistency)?;
 
     let shard_selector = convert_shard_selector_for_read(shard_selection, shard_key_selector);
@@ -1028,6 +1113,8 @@ pub async fn recommend(
         negative,
         positive_vectors,
         negative_vectors,
+        feedback,
+        context,
         strategy,
         filter,
         limit,
@@ -1041,8 +1128,11 @@ pub async fn recommend(
         read_consistency,
         timeout,
         shard_key_selector,
+        with_lookup,
     } = recommend_points;
 
+    let with_lookup: Option<WithLookup> = with_lookup.map(TryInto::try_into).transpose()?;
+
     let timeout = timeout.map(Duration::from_secs);
 
     let positive_ids = positive
@@ -1058,13 +1148,24 @@ pub async fn recommend(
         .collect::<Result<Vec<RecommendExample>, Status>>()?;
     let negative_vectors = negative_vectors
         .into_iter()
-        .map(|v| RecommendExample::Dense(v.data))
+        .map(|v| RecommendExample::Dense(v.into_dense()))
         .collect();
     let negative = [negative_ids, negative_vectors].concat();
 
+    let feedback = feedback
+        .into_iter()
+        .map(TryInto::try_into)
+        .collect::<Result<_, Status>>()?;
+    let context = context
+        .into_iter()
+        .map(TryInto::try_into)
+        .collect::<Result<_, Status>>()?;
+
     let request = collection::operations::types::RecommendRequestInternal {
         positive,
         negative,
+        feedback,
+        context,
         strategy: strategy.map(|s| s.try_into()).transpose()?,
         filter: filter.map(|f| f.try_into()).transpose()?,
         params: params.map(|p| p.into()),
@@ -1081,6 +1182,7 @@ pub async fn recommend(
         lookup_from: lookup_from.map(|l| l.into()),
     };
 
+    wait_for_consistency_token(toc, &collection_name, read_consistency.as_ref()).await?;
     let read_consistency = ReadConsistency::try_from_optional(read_consistency)?;
 
     let shard_selector = convert_shard_selector_for_read(None, shard_key_selector);
@@ -1091,12 +1193,23 @@ pub async fn recommend(
             &collection_name,
             request,
             read_consistency,
-            shard_selector,
+            shard_selector.clone(),
             timeout,
         )
         .await
         .map_err(error_to_status)?;
 
+    let recommended_points = do_lookup_scored_points(
+        toc,
+        &collection_name,
+        with_lookup,
+        recommended_points,
+        read_consistency,
+        shard_selector,
+    )
+    .await
+    .map_err(error_to_status)?;
+
     let response = RecommendResponse {
         result: recommended_points
             .into_iter()
@@ -1116,15 +1229,23 @@ pub async fn recommend_batch(
     timeout: Option<Duration>,
 ) -> Result<Response<RecommendBatchResponse>, Status> {
     let mut requests = Vec::with_capacity(recommend_points.len());
+    let mut lookups = Vec::with_capacity(recommend_points.len());
 
     for mut request in recommend_points {
         let shard_selector =
             convert_shard_selector_for_read(None, request.shard_key_selector.take());
+        let with_lookup: Option<WithLookup> = request
+            .with_lookup
+            .take()
+            .map(TryInto::try_into)
+            .transpose()?;
         let internal_request: collection::operations::types::RecommendRequestInternal =
             request.try_into()?;
+        lookups.push((with_lookup, shard_selector.clone()));
         requests.push((internal_request, shard_selector));
     }
 
+    wait_for_consistency_token(toc, &collection_name, read_consistency.as_ref()).await?;
     let read_consistency = ReadConsistency::try_from_optional(read_consistency)?;
 
     let timing = Instant::now();
@@ -1133,6 +1254,16 @@ pub async fn recommend_batch(
         .await
         .map_err(error_to_status)?;
 
+    let scored_points = do_lookup_batch_scored_points(
+        toc,
+        &collection_name,
+        lookups,
+        scored_points,
+        read_consistency,
+    )
+    .await
+    .map_err(error_to_status)?;
+
     let response = RecommendBatchResponse {
         result: scored_points
             .into_iter()
@@ -1160,6 +1291,7 @@ pub async fn recommend_groups(
         ..
     } = recommend_point_groups;
 
+    wait_for_consistency_token(toc, &collection_name, read_consistency.as_ref()).await?;
     let read_consistency = ReadConsistency::try_from_optional(read_consistency)?;
 
     let shard_selector = convert_shard_selector_for_read(None, shard_key_selector);
@@ -1188,6 +1320,13 @@ pub async fn discover(
     toc: &TableOfContent,
     discover_points: DiscoverPoints,
 ) -> Result<Response<DiscoverResponse>, Status> {
+    wait_for_consistency_token(
+        toc,
+        &discover_points.collection_name,
+        discover_points.read_consistency.as_ref(),
+    )
+    .await?;
+
     let (request, collection_name, read_consistency, timeout, shard_key_selector) =
         try_discover_request_from_grpc(discover_points)?;
 
@@ -1233,6 +1372,7 @@ pub async fn discover_batch(
         requests.push((internal_request, shard_selector));
     }
 
+    wait_for_consistency_token(toc, &collection_name, read_consistency.as_ref()).await?;
     let read_consistency = ReadConsistency::try_from_optional(read_consistency)?;
 
     let timing = Instant::now();
@@ -1267,8 +1407,12 @@ pub async fn scroll(
         with_vectors,
         read_consistency,
         shard_key_selector,
+        order_by,
+        with_lookup,
     } = scroll_points;
 
+    let with_lookup: Option<WithLookup> = with_lookup.map(TryInto::try_into).transpose()?;
+
     let scroll_request = ScrollRequestInternal {
         offset: offset.map(|o| o.try_into()).transpose()?,
         limit: limit.map(|l| l as usize),
@@ -1277,8 +1421,10 @@ pub async fn scroll(
         with_vector: with_vectors
             .map(|selector| selector.into())
             .unwrap_or_default(),
+        order_by: order_by.map(|o| o.try_into()).transpose()?,
     };
 
+    wait_for_consistency_token(toc, &collection_name, read_consistency.as_ref()).await?;
     let read_consistency = ReadConsistency::try_from_optional(read_consistency)?;
 
     let shard_selector = convert_shard_selector_for_read(shard_selection, shard_key_selector);
@@ -1289,19 +1435,42 @@ pub async fn scroll(
         &collection_name,
         scroll_request,
         read_consistency,
-        shard_selector,
+        shard_selector.clone(),
     )
     .await
     .map_err(error_to_status)?;
 
-    let response = ScrollResponse {
-        next_page_offset: scrolled_points.next_page_offset.map(|n| n.into()),
-        result: scrolled_points
-            .points
-            .into_iter()
-            .map(|point| point.into())
-            .collect(),
-        time: timing.elapsed().as_secs_f64(),
+    let response = match with_lookup {
+        None => ScrollResponse {
+            next_page_offset: scrolled_points.next_page_offset.map(|n| n.into()),
+            result: scrolled_points
+                .points
+                .into_iter()
+                .map(|point| point.into())
+                .collect(),
+            time: timing.elapsed().as_secs_f64(),
+        },
+        Some(with_lookup) => {
+            let scrolled_points = do_lookup_scroll_result(
+                toc,
+                &collection_name,
+                with_lookup,
+                scrolled_points,
+                read_consistency,
+                shard_selector,
+            )
+            .await
+            .map_err(error_to_status)?;
+            ScrollResponse {
+                next_page_offset: scrolled_points.next_page_offset.map(|n| n.into()),
+                result: scrolled_points
+                    .points
+                    .into_iter()
+                    .map(|point| point.into())
+                    .collect(),
+                time: timing.elapsed().as_secs_f64(),
+            }
+        }
     };
 
     Ok(Response::new(response))
@@ -1318,13 +1487,16 @@ pub async fn count(
         exact,
         read_consistency,
         shard_key_selector,
+        distinct,
     } = count_points;
 
     let count_request = collection::operations::types::CountRequestInternal {
         filter: filter.map(|f| f.try_into()).transpose()?,
         exact: exact.unwrap_or_else(default_exact_count),
+        distinct,
     };
 
+    wait_for_consistency_token(toc, &collection_name, read_consistency.as_ref()).await?;
     let read_consistency = ReadConsistency::try_from_optional(read_consistency)?;
 
     let shard_selector = convert_shard_selector_for_read(shard_selection, shard_key_selector);
@@ -1348,6 +1520,210 @@ pub async fn count(
     Ok(Response::new(response))
 }
 
+/// Collect distinct values of the `distinct` payload field, used for merging results across shards
+pub async fn count_distinct(
+    toc: &TableOfContent,
+    count_points: CountPoints,
+    shard_selection: Option<ShardId>,
+) -> Result<Response<CountDistinctResponse>, Status> {
+    let CountPoints {
+        collection_name,
+        filter,
+        exact,
+        read_consistency,
+        shard_key_selector,
+        distinct,
+    } = count_points;
+
+    let count_request = collection::operations::types::CountRequestInternal {
+        filter: filter.map(|f| f.try_into()).transpose()?,
+        exact: exact.unwrap_or_else(default_exact_count),
+        distinct,
+    };
+
+    wait_for_consistency_token(toc, &collection_name, read_consistency.as_ref()).await?;
+    let read_consistency = ReadConsistency::try_from_optional(read_consistency)?;
+
+    let shard_selector = convert_shard_selector_for_read(shard_selection, shard_key_selector);
+
+    let timing = Instant::now();
+    let distinct_counter = toc
+        .count_distinct(
+            &collection_name,
+            count_request,
+            read_consistency,
+            shard_selector,
+        )
+        .await
+        .map_err(error_to_status)?;
+
+    let response = CountDistinctResponse {
+        result: Some(distinct_counter.into()),
+        time: timing.elapsed().as_secs_f64(),
+    };
+
+    Ok(Response::new(response))
+}
+
+pub async fn aggregate(
+    toc: &TableOfContent,
+    aggregate_points: AggregatePoints,
+    shard_selection: Option<ShardId>,
+) -> Result<Response<AggregateResponse>, Status> {
+    let AggregatePoints {
+        collection_name,
+        filter,
+        key,
+        histogram,
+        read_consistency,
+        shard_key_selector,
+    } = aggregate_points;
+
+    let aggregate_request = AggregateRequestInternal {
+        filter: filter.map(|f| f.try_into()).transpose()?,
+        key,
+        histogram: histogram.map(|h| h.try_into()).transpose()?,
+    };
+
+    wait_for_consistency_token(toc, &collection_name, read_consistency.as_ref()).await?;
+    let read_consistency = ReadConsistency::try_from_optional(read_consistency)?;
+
+    let shard_selector = convert_shard_selector_for_read(shard_selection, shard_key_selector);
+
+    let timing = Instant::now();
+    let aggregate_result = do_aggregate_points(
+        toc,
+        &collection_name,
+        aggregate_request,
+        read_consistency,
+        shard_selector,
+    )
+    .await
+    .map_err(error_to_status)?;
+
+    let response = AggregateResponse {
+        result: Some(aggregate_result.into()),
+        time: timing.elapsed().as_secs_f64(),
+    };
+
+    Ok(Response::new(response))
+}
+
+/// Collect statistics of the text field of the query, used for scoring with statistics of all shards
+pub async fn text_stats(
+    toc: &TableOfContent,
+    search_text_points: SearchTextPoints,
+    shard_selection: Option<ShardId>,
+) -> Result<Response<TextStatsResponse>, Status> {
+    let SearchTextPoints {
+        collection_name,
+        key,
+        query,
+        filter,
+        limit,
+        offset,
+        with_payload,
+        with_vectors,
+        score_threshold,
+        read_consistency,
+        shard_key_selector,
+    } = search_text_points;
+
+    let search_text_request = TextSearchRequestInternal {
+        key,
+        query,
+        filter: filter.map(|f| f.try_into()).transpose()?,
+        limit: limit as usize,
+        offset: offset.map(|offset| offset as usize),
+        with_payload: with_payload.map(|wp| wp.try_into()).transpose()?,
+        with_vector: with_vectors.map(|selector| selector.into()),
+        score_threshold,
+        stats: None,
+    };
+
+    wait_for_consistency_token(toc, &collection_name, read_consistency.as_ref()).await?;
+    let read_consistency = ReadConsistency::try_from_optional(read_consistency)?;
+
+    let shard_selector = convert_shard_selector_for_read(shard_selection, shard_key_selector);
+
+    let timing = Instant::now();
+    let stats = toc
+        .text_stats(
+            &collection_name,
+            search_text_request,
+            read_consistency,
+            shard_selector,
+        )
+        .await
+        .map_err(error_to_status)?;
+
+    let response = TextStatsResponse {
+        result: Some(stats.into()),
+        time: timing.elapsed().as_secs_f64(),
+    };
+
+    Ok(Response::new(response))
+}
+
+pub async fn search_text(
+    toc: &TableOfContent,
+    search_text_points: SearchTextPoints,
+    stats: Option<TextStats>,
+    shard_selection: Option<ShardId>,
+) -> Result<Response<SearchResponse>, Status> {
+    let SearchTextPoints {
+        collection_name,
+        key,
+        query,
+        filter,
+        limit,
+        offset,
+        with_payload,
+        with_vectors,
+        score_threshold,
+        read_consistency,
+        shard_key_selector,
+    } = search_text_points;
+
+    let search_text_request = TextSearchRequestInternal {
+        key,
+        query,
+        filter: filter.map(|f| f.try_into()).transpose()?,
+        limit: limit as usize,
+        offset: offset.map(|offset| offset as usize),
+        with_payload: with_payload.map(|wp| wp.try_into()).transpose()?,
+        with_vector: with_vectors.map(|selector| selector.into()),
+        score_threshold,
+        stats,
+    };
+
+    wait_for_consistency_token(toc, &collection_name, read_consistency.as_ref()).await?;
+    let read_consistency = ReadConsistency::try_from_optional(read_consistency)?;
+
+    let shard_selector = convert_shard_selector_for_read(shard_selection, shard_key_selector);
+
+    let timing = Instant::now();
+    let scored_points = do_search_text_points(
+        toc,
+        &collection_name,
+        search_text_request,
+        read_consistency,
+        shard_selector,
+    )
+    .await
+    .map_err(error_to_status)?;
+
+    let response = SearchResponse {
+        result: scored_points
+            .into_iter()
+            .map(|point| point.into())
+            .collect(),
+        time: timing.elapsed().as_secs_f64(),
+    };
+
+    Ok(Response::new(response))
+}
+
 pub async fn get(
     toc: &TableOfContent,
     get_points: GetPoints,
@@ -1373,6 +1749,7 @@ pub async fn get(
             .unwrap_or_default(),
     };
 
+    wait_for_consistency_token(toc, &collection_name, read_consistency.as_ref()).await?;
     let read_consistency = ReadConsistency::try_from_optional(read_consistency)?;
 
     let shard_selector = convert_shard_selector_for_read(shard_selection, shard_key_selector);
@@ -1396,3 +1773,29 @@ pub async fn get(
 
     Ok(Response::new(response))
 }
+
+pub async fn stream_changes(
+    toc: Arc<TableOfContent>,
+    request: StreamShardChanges,
+) -> Result<Response<ShardChangesStream>, Status> {
+    let StreamShardChanges {
+        collection_name,
+        shard_id,
+        from,
+    } = request;
+
+    // Fail the request right away if the collection does not exist
+    toc.get_collection(&collection_name)
+        .await
+        .map_err(error_to_status)?;
+
+    let changes =
+        stream_shard_changes(toc, collection_name.clone(), shard_id, from).map(move |change| {
+            let change = change.map_err(error_to_status)?;
+
+            shard_change_to_grpc(collection_name.clone(), change)
+                .map_err(|err| error_to_status(err.into()))
+        });
+
+    Ok(Response::new(Box::pin(changes)))
+}
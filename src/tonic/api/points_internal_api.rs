@@ -3,13 +3,14 @@ use std::time::Duration;
 
 use api::grpc::qdrant::points_internal_server::PointsInternal;
 use api::grpc::qdrant::{
-    ClearPayloadPointsInternal, CoreSearchBatchPointsInternal, CountPointsInternal, CountResponse,
+    AggregatePointsInternal, AggregateResponse, ClearPayloadPointsInternal,
+    CoreSearchBatchPointsInternal, CountDistinctResponse, CountPointsInternal, CountResponse,
     CreateFieldIndexCollectionInternal, DeleteFieldIndexCollectionInternal,
     DeletePayloadPointsInternal, DeletePointsInternal, DeleteVectorsInternal, GetPointsInternal,
     GetResponse, PointsOperationResponse, RecommendPointsInternal, RecommendResponse,
     ScrollPointsInternal, ScrollResponse, SearchBatchPointsInternal, SearchBatchResponse,
-    SearchPointsInternal, SearchResponse, SetPayloadPointsInternal, SyncPointsInternal,
-    UpdateVectorsInternal, UpsertPointsInternal,
+    SearchPointsInternal, SearchResponse, SearchTextPointsInternal, SetPayloadPointsInternal,
+    SyncPointsInternal, TextStatsResponse, UpdateVectorsInternal, UpsertPointsInternal,
 };
 use storage::content_manager::toc::TableOfContent;
 use tonic::{Request, Response, Status};
@@ -17,9 +18,9 @@ use tonic::{Request, Response, Status};
 use super::points_common::core_search_list;
 use super::validate_and_log;
 use crate::tonic::api::points_common::{
-    clear_payload, count, create_field_index_internal, delete, delete_field_index_internal,
-    delete_payload, delete_vectors, get, overwrite_payload, recommend, scroll, set_payload, sync,
-    update_vectors, upsert,
+    aggregate, clear_payload, count, count_distinct, create_field_index_internal, delete,
+    delete_field_index_internal, delete_payload, delete_vectors, get, overwrite_payload, recommend,
+    scroll, search_text, set_payload, sync, text_stats, update_vectors, upsert,
 };
 
 /// This API is intended for P2P communication within a distributed deployment.
@@ -300,6 +301,74 @@ impl PointsInternal for PointsInternalService {
         count(self.toc.as_ref(), count_points, shard_id).await
     }
 
+    async fn aggregate(
+        &self,
+        request: Request<AggregatePointsInternal>,
+    ) -> Result<Response<AggregateResponse>, Status> {
+        validate_and_log(request.get_ref());
+        let AggregatePointsInternal {
+            aggregate_points,
+            shard_id,
+        } = request.into_inner();
+
+        let aggregate_points = aggregate_points
+            .ok_or_else(|| Status::invalid_argument("AggregatePoints is missing"))?;
+        aggregate(self.toc.as_ref(), aggregate_points, shard_id).await
+    }
+
+    async fn count_distinct(
+        &self,
+        request: Request<CountPointsInternal>,
+    ) -> Result<Response<CountDistinctResponse>, Status> {
+        validate_and_log(request.get_ref());
+        let CountPointsInternal {
+            count_points,
+            shard_id,
+        } = request.into_inner();
+
+        let count_points =
+            count_points.ok_or_else(|| Status::invalid_argument("CountPoints is missing"))?;
+        count_distinct(self.toc.as_ref(), count_points, shard_id).await
+    }
+
+    async fn text_stats(
+        &self,
+        request: Request<SearchTextPointsInternal>,
+    ) -> Result<Response<TextStatsResponse>, Status> {
+        validate_and_log(request.get_ref());
+        let SearchTextPointsInternal {
+            search_text_points,
+            shard_id,
+            stats: _,
+        } = request.into_inner();
+
+        let search_text_points = search_text_points
+            .ok_or_else(|| Status::invalid_argument("SearchTextPoints is missing"))?;
+        text_stats(self.toc.as_ref(), search_text_points, shard_id).await
+    }
+
+    async fn search_text(
+        &self,
+        request: Request<SearchTextPointsInternal>,
+    ) -> Result<Response<SearchResponse>, Status> {
+        validate_and_log(request.get_ref());
+        let SearchTextPointsInternal {
+            search_text_points,
+            shard_id,
+            stats,
+        } = request.into_inner();
+
+        let search_text_points = search_text_points
+            .ok_or_else(|| Status::invalid_argument("SearchTextPoints is missing"))?;
+        search_text(
+            self.toc.as_ref(),
+            search_text_points,
+            stats.map(Into::into),
+            shard_id,
+        )
+        .await
+    }
+
     async fn sync(
         &self,
         request: Request<SyncPointsInternal>,
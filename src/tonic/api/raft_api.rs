@@ -2,6 +2,7 @@ use api::grpc::qdrant::raft_server::Raft;
 use api::grpc::qdrant::{
     AddPeerToKnownMessage, AllPeers, Peer, PeerId, RaftMessage as RaftMessageBytes, Uri as UriStr,
 };
+use collection::shards::peer_labels::PeerLabels;
 use itertools::Itertools;
 use raft::eraftpb::Message as RaftMessage;
 use storage::content_manager::consensus_manager::ConsensusStateRef;
@@ -85,6 +86,7 @@ impl Raft for RaftService {
                 ConsensusOperations::AddPeer {
                     peer_id: peer.id,
                     uri: uri.to_string(),
+                    labels: peer.labels.map(PeerLabels::from).unwrap_or_default(),
                 },
                 None,
             )
@@ -97,6 +99,7 @@ impl Raft for RaftService {
                 "Failed to add peer after consensus: {uri}"
             )));
         }
+        let mut labels_by_id = self.consensus_state.peer_labels_by_id();
         let first_peer_id = self.consensus_state.first_voter();
         Ok(Response::new(AllPeers {
             all_peers: addresses
@@ -104,6 +107,7 @@ impl Raft for RaftService {
                 .map(|(id, uri)| Peer {
                     id,
                     uri: uri.to_string(),
+                    labels: labels_by_id.remove(&id).map(Into::into),
                 })
                 .collect(),
             first_peer_id,
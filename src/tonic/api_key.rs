@@ -12,15 +12,17 @@ use tower_layer::Layer;
 use crate::common::auth::AuthKeys;
 use crate::common::strings::ct_eq;
 
-const READ_ONLY_RPC_PATHS: [&str; 13] = [
+const READ_ONLY_RPC_PATHS: [&str; 15] = [
     "/qdrant.Collections/List",
     "/qdrant.Collections/Get",
     "/qdrant.Points/Scroll",
     "/qdrant.Points/Get",
     "/qdrant.Points/Count",
+    "/qdrant.Points/Aggregate",
     "/qdrant.Points/Search",
     "/qdrant.Points/SearchGroups",
     "/qdrant.Points/SearchBatch",
+    "/qdrant.Points/SearchText",
     "/qdrant.Points/Recommend",
     "/qdrant.Points/RecommendGroups",
     "/qdrant.Points/RecommendBatch",
@@ -0,0 +1,66 @@
+import pathlib
+import random
+
+from .fixtures import create_collection, random_dense_vector
+from .utils import *
+
+N_PEERS = 3
+N_SHARDS = 2
+N_REPLICAS = 3
+COLLECTION_NAME = "test_collection"
+
+
+def upsert_points(peer_url, offset, num):
+    res = requests.put(
+        f"{peer_url}/collections/{COLLECTION_NAME}/points?wait=false",
+        json={
+            "points": [
+                {
+                    "id": offset + i,
+                    "vector": {"": random_dense_vector()},
+                    "payload": {"batch": offset},
+                } for i in range(num)
+            ]
+        },
+    )
+    assert_http_ok(res)
+    return res.json()["result"]
+
+
+def test_read_your_writes_on_any_replica(tmp_path: pathlib.Path):
+    assert_project_root()
+
+    peer_api_uris, peer_dirs, bootstrap_uri = start_cluster(tmp_path, N_PEERS)
+
+    create_collection(peer_api_uris[0], shard_number=N_SHARDS, replication_factor=N_REPLICAS)
+    wait_collection_exists_and_active_on_all_peers(collection_name=COLLECTION_NAME, peer_api_uris=peer_api_uris)
+
+    for batch in range(10):
+        offset = batch * 100
+        result = upsert_points(random.choice(peer_api_uris), offset, 50)
+        token = result["consistency_token"]
+
+        # Every shard must be present in the token, written on every replica
+        shards = token.split(";")
+        assert len(shards) == N_SHARDS
+        for shard in shards:
+            _shard_id, operations = shard.split(":")
+            assert len(operations.split(",")) == N_REPLICAS
+
+        # Reading with the token from any peer sees the write
+        for peer_api_uri in peer_api_uris:
+            res = requests.post(
+                f"{peer_api_uri}/collections/{COLLECTION_NAME}/points/count",
+                params={"consistency_token": token},
+                json={"filter": {"must": [{"key": "batch", "match": {"value": offset}}]}, "exact": True},
+            )
+            assert_http_ok(res)
+            assert res.json()["result"]["count"] == 50
+
+    # Malformed token is rejected
+    res = requests.post(
+        f"{peer_api_uris[0]}/collections/{COLLECTION_NAME}/points/count",
+        params={"consistency_token": "not-a-token"},
+        json={"exact": True},
+    )
+    assert res.status_code == 400
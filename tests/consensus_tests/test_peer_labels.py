@@ -0,0 +1,56 @@
+import pathlib
+from collections import defaultdict
+
+from .fixtures import create_collection
+from .utils import *
+
+N_SHARDS = 4
+COLLECTION_NAME = "test_collection"
+PEER_ZONES = ["zone-a", "zone-a", "zone-b", "zone-b"]
+
+
+def zone_env(zone: str) -> dict:
+    return {"QDRANT__CLUSTER__LABELS__ZONE": zone}
+
+
+def test_replicas_spread_across_zones(tmp_path: pathlib.Path):
+    assert_project_root()
+
+    peer_dirs = make_peer_folders(tmp_path, len(PEER_ZONES))
+
+    bootstrap_api_uri, bootstrap_uri = start_first_peer(
+        peer_dirs[0], "peer_0_0.log", extra_env=zone_env(PEER_ZONES[0]))
+    leader = wait_peer_added(bootstrap_api_uri)
+
+    peer_api_uris = [bootstrap_api_uri]
+    for i in range(1, len(peer_dirs)):
+        peer_api_uris.append(
+            start_peer(peer_dirs[i], f"peer_0_{i}.log", bootstrap_uri, extra_env=zone_env(PEER_ZONES[i])))
+    wait_for_uniform_cluster_status(peer_api_uris, leader)
+
+    # Every peer knows the zones of all other peers
+    zone_by_peer = {
+        get_cluster_info(peer_api_uri)["peer_id"]: zone
+        for peer_api_uri, zone in zip(peer_api_uris, PEER_ZONES)
+    }
+    for peer_api_uri in peer_api_uris:
+        peers = get_cluster_info(peer_api_uri)["peers"]
+        for peer_id, peer_info in peers.items():
+            assert peer_info["labels"]["zone"] == zone_by_peer[int(peer_id)]
+
+    create_collection(peer_api_uris[0], shard_number=N_SHARDS, replication_factor=2)
+    wait_collection_exists_and_active_on_all_peers(collection_name=COLLECTION_NAME, peer_api_uris=peer_api_uris)
+
+    cluster_info = get_collection_cluster_info(peer_api_uris[0], COLLECTION_NAME)
+    assert "warnings" not in cluster_info
+
+    replicas = defaultdict(list)
+    for shard in cluster_info["local_shards"]:
+        replicas[shard["shard_id"]].append(cluster_info["peer_id"])
+    for shard in cluster_info["remote_shards"]:
+        replicas[shard["shard_id"]].append(shard["peer_id"])
+
+    assert len(replicas) == N_SHARDS
+    for shard_id, peer_ids in replicas.items():
+        zones = {zone_by_peer[peer_id] for peer_id in peer_ids}
+        assert len(zones) == 2, f"replicas of shard {shard_id} are located in a single zone"
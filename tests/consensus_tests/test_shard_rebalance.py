@@ -0,0 +1,62 @@
+import pathlib
+
+from .fixtures import create_collection, upsert_random_points
+from .utils import *
+
+N_PEERS = 2
+N_SHARDS = 4
+COLLECTION_NAME = "test_collection"
+
+
+def get_rebalance_plan(peer_api_uri: str, collection_name: str) -> dict:
+    r = requests.get(f"{peer_api_uri}/collections/{collection_name}/cluster/rebalance")
+    assert_http_ok(r)
+    return r.json()["result"]
+
+
+def test_shard_rebalance(tmp_path: pathlib.Path):
+    assert_project_root()
+
+    peer_api_uris, peer_dirs, bootstrap_uri = start_cluster(tmp_path, N_PEERS)
+
+    create_collection(peer_api_uris[0], shard_number=N_SHARDS, replication_factor=1)
+    wait_collection_exists_and_active_on_all_peers(collection_name=COLLECTION_NAME, peer_api_uris=peer_api_uris)
+    upsert_random_points(peer_api_uris[0], 200)
+
+    # Balanced cluster, nothing to move
+    plan = get_rebalance_plan(peer_api_uris[0], COLLECTION_NAME)
+    assert len(plan["peers"]) == N_PEERS
+    assert plan["moves"] == []
+
+    # Add a new empty peer
+    peer_dir = make_peer_folder(tmp_path, N_PEERS)
+    new_peer_uri = start_peer(peer_dir, f"peer_0_{N_PEERS}.log", bootstrap_uri)
+    wait_for_uniform_cluster_status(peer_api_uris + [new_peer_uri], get_leader(peer_api_uris[0]))
+    peer_api_uris.append(new_peer_uri)
+    new_peer_id = get_cluster_info(new_peer_uri)["peer_id"]
+
+    # Dry-run suggests to move one shard to the new peer, without moving anything
+    plan = get_rebalance_plan(peer_api_uris[0], COLLECTION_NAME)
+    assert len(plan["peers"]) == N_PEERS + 1
+    assert len(plan["moves"]) == 1
+    assert plan["moves"][0]["to_peer_id"] == new_peer_id
+    assert get_collection_cluster_info(new_peer_uri, COLLECTION_NAME)["local_shards"] == []
+
+    # Enable automatic rebalancing
+    r = requests.patch(
+        f"{peer_api_uris[0]}/collections/{COLLECTION_NAME}",
+        json={
+            "params": {
+                "auto_rebalance": True,
+            }
+        })
+    assert_http_ok(r)
+
+    wait_for_collection_local_shards_count(new_peer_uri, COLLECTION_NAME, 1)
+    wait_for_collection_shard_transfers_count(peer_api_uris[0], COLLECTION_NAME, 0)
+
+    # Collection is balanced again and no points were lost
+    plan = get_rebalance_plan(peer_api_uris[0], COLLECTION_NAME)
+    assert plan["moves"] == []
+    for peer_api_uri in peer_api_uris:
+        wait_collection_points_count(peer_api_uri, COLLECTION_NAME, 200)